@@ -10,10 +10,17 @@ use re_log_types::{ArrowMsg, EntityPath, LogMsg, TimePoint};
 // ----------------------------------------------------------------------------
 
 mod load_file;
+mod load_uri;
 mod loader_archetype;
 mod loader_directory;
+mod loader_geo;
+mod loader_numpy;
+mod loader_ros_bag;
 mod loader_rrd;
+mod loader_tabular;
 mod loader_urdf;
+mod point_cloud;
+mod ros_bag;
 
 #[cfg(not(target_arch = "wasm32"))]
 pub mod lerobot;
@@ -26,14 +33,28 @@ pub mod loader_lerobot;
 mod loader_external;
 
 pub use self::{
-    load_file::load_from_file_contents, loader_archetype::ArchetypeLoader,
-    loader_directory::DirectoryLoader, loader_rrd::RrdLoader, loader_urdf::UrdfDataLoader,
+    load_file::load_from_file_contents,
+    load_uri::{fetch_uri, filepath_from_uri, is_http_uri, load_from_uri},
+    loader_archetype::{ArchetypeLoader, ImageLoaderSettings},
+    loader_directory::{
+        DirectoryLoader, DirectoryLoaderSettings, IMAGE_SEQUENCE_FILE_TIME_TIMELINE,
+        IMAGE_SEQUENCE_FRAME_TIMELINE, ImageSequenceTime,
+    },
+    loader_geo::{GEO_TRACK_TIMELINE, GeoLoader},
+    loader_numpy::NumpyLoader,
+    loader_ros_bag::{ROS_BAG_TIMELINE, RosBagLoader},
+    loader_rrd::RrdLoader,
+    loader_tabular::{
+        MIN_TIMESTAMP_SECS, TIME_COLUMN_CANDIDATES, TabularLoader, TabularLoaderSettings,
+    },
+    loader_urdf::UrdfDataLoader,
     loader_urdf::UrdfTree,
 };
 
 #[cfg(not(target_arch = "wasm32"))]
 pub use self::{
     load_file::load_from_path,
+    load_uri::fetch_uri_blocking,
     loader_external::{
         EXTERNAL_DATA_LOADER_INCOMPATIBLE_EXIT_CODE, EXTERNAL_DATA_LOADER_PREFIX, ExternalLoader,
         iter_external_loaders,
@@ -90,6 +111,22 @@ pub struct DataLoaderSettings {
 
     /// At what time(s) should the data be logged to?
     pub timepoint: Option<TimePoint>,
+
+    /// How should directories be loaded?
+    ///
+    /// Only used by the [`DirectoryLoader`], and never forwarded to external loaders.
+    pub directory: DirectoryLoaderSettings,
+
+    /// How should tables be loaded?
+    ///
+    /// Only used by the [`TabularLoader`], and never forwarded to external loaders.
+    pub tabular: TabularLoaderSettings,
+
+    /// How should images be loaded?
+    ///
+    /// Used by the [`ArchetypeLoader`] and for image sequences in the [`DirectoryLoader`].
+    /// Never forwarded to external loaders.
+    pub image: ImageLoaderSettings,
 }
 
 impl DataLoaderSettings {
@@ -103,6 +140,9 @@ impl DataLoaderSettings {
             force_store_info: false,
             entity_path_prefix: Default::default(),
             timepoint: Default::default(),
+            directory: Default::default(),
+            tabular: Default::default(),
+            image: Default::default(),
         }
     }
 
@@ -116,6 +156,9 @@ impl DataLoaderSettings {
             force_store_info: _,
             entity_path_prefix,
             timepoint,
+            directory: _,
+            tabular: _,
+            image: _,
         } = self;
 
         let mut args = Vec::new();
@@ -198,18 +241,18 @@ pub type DataLoaderName = String;
 
 /// A [`DataLoader`] loads data from a file path and/or a file's contents.
 ///
-/// Files can be loaded in 3 different ways:
+/// Files can be loaded in 4 different ways:
 /// - via the Rerun CLI (`rerun myfile.jpeg`),
 /// - using drag-and-drop,
-/// - using the open dialog in the Rerun Viewer.
+/// - using the open dialog in the Rerun Viewer,
+/// - from a URL (`rerun https://example.com/myfile.mp4`).
 ///
 /// All these file loading methods support loading a single file, many files at once, or even
 /// folders.
 /// ⚠ Drag-and-drop of folders does not yet work on the web version of Rerun Viewer ⚠
 ///
-/// We only support loading files from the local filesystem at the moment, and consequently only
-/// accept filepaths as input.
-/// [There are plans to make this generic over any URI](https://github.com/rerun-io/rerun/issues/4525).
+/// [`DataLoader`]s only ever see filepaths and file contents: remote files (`http://` and
+/// `https://` URIs) are downloaded first and then handed over as contents, see [`load_from_uri`].
 ///
 /// Rerun comes with a few [`DataLoader`]s by default:
 /// - [`RrdLoader`] for [Rerun files].
@@ -218,6 +261,10 @@ pub type DataLoaderName = String;
 ///     - [Images]
 ///     - [Point clouds]
 ///     - [Text files]
+/// - [`TabularLoader`] for [Tables].
+/// - [`NumpyLoader`] for [NumPy arrays].
+/// - [`GeoLoader`] for [geographic data].
+/// - [`RosBagLoader`] for [ROS 1 bags].
 /// - [`DirectoryLoader`] for recursively loading folders.
 /// - [`ExternalLoader`], which looks for user-defined data loaders in $PATH.
 ///
@@ -241,13 +288,14 @@ pub type DataLoaderName = String;
 /// On native, [`DataLoader`]s are executed in parallel.
 ///
 /// [Rerun files]: crate::SUPPORTED_RERUN_EXTENSIONS
+/// [ROS 1 bags]: crate::SUPPORTED_ROS_BAG_EXTENSIONS
 /// [3D models]: crate::SUPPORTED_MESH_EXTENSIONS
 /// [Images]: crate::SUPPORTED_IMAGE_EXTENSIONS
+/// [geographic data]: crate::SUPPORTED_GEO_EXTENSIONS
+/// [NumPy arrays]: crate::SUPPORTED_NUMPY_EXTENSIONS
 /// [Point clouds]: crate::SUPPORTED_POINT_CLOUD_EXTENSIONS
+/// [Tables]: crate::SUPPORTED_TABULAR_EXTENSIONS
 /// [Text files]: crate::SUPPORTED_TEXT_EXTENSIONS
-//
-// TODO(#4525): `DataLoader`s should support arbitrary URIs
-// TODO(#4527): Web Viewer `?url` parameter should accept anything our `DataLoader`s support
 pub trait DataLoader: Send + Sync {
     /// Name of the [`DataLoader`].
     ///
@@ -398,6 +446,10 @@ static BUILTIN_LOADERS: Lazy<Vec<Arc<dyn DataLoader>>> = Lazy::new(|| {
     vec![
         Arc::new(RrdLoader) as Arc<dyn DataLoader>,
         Arc::new(ArchetypeLoader),
+        Arc::new(TabularLoader),
+        Arc::new(NumpyLoader),
+        Arc::new(GeoLoader),
+        Arc::new(RosBagLoader),
         Arc::new(DirectoryLoader),
         #[cfg(not(target_arch = "wasm32"))]
         Arc::new(LeRobotDatasetLoader),
@@ -456,7 +508,15 @@ pub const SUPPORTED_VIDEO_EXTENSIONS: &[&str] = &["mp4"];
 pub const SUPPORTED_MESH_EXTENSIONS: &[&str] = &["glb", "gltf", "obj", "stl"];
 
 // TODO(#4532): `.ply` data loader should support 2D point cloud & meshes
-pub const SUPPORTED_POINT_CLOUD_EXTENSIONS: &[&str] = &["ply"];
+pub const SUPPORTED_POINT_CLOUD_EXTENSIONS: &[&str] = &["e57", "las", "laz", "pcd", "ply"];
+
+pub const SUPPORTED_TABULAR_EXTENSIONS: &[&str] = &["csv", "parquet"];
+
+pub const SUPPORTED_NUMPY_EXTENSIONS: &[&str] = &["npy", "npz"];
+
+pub const SUPPORTED_GEO_EXTENSIONS: &[&str] = &["geojson", "gpx", "kml"];
+
+pub const SUPPORTED_ROS_BAG_EXTENSIONS: &[&str] = &["bag"];
 
 pub const SUPPORTED_RERUN_EXTENSIONS: &[&str] = &["rbl", "rrd"];
 
@@ -472,6 +532,10 @@ pub fn supported_extensions() -> impl Iterator<Item = &'static str> {
         .chain(SUPPORTED_MESH_EXTENSIONS)
         .chain(SUPPORTED_POINT_CLOUD_EXTENSIONS)
         .chain(SUPPORTED_TEXT_EXTENSIONS)
+        .chain(SUPPORTED_TABULAR_EXTENSIONS)
+        .chain(SUPPORTED_NUMPY_EXTENSIONS)
+        .chain(SUPPORTED_GEO_EXTENSIONS)
+        .chain(SUPPORTED_ROS_BAG_EXTENSIONS)
         .copied()
 }
 
@@ -483,4 +547,8 @@ pub fn is_supported_file_extension(extension: &str) -> bool {
         || SUPPORTED_POINT_CLOUD_EXTENSIONS.contains(&extension)
         || SUPPORTED_RERUN_EXTENSIONS.contains(&extension)
         || SUPPORTED_TEXT_EXTENSIONS.contains(&extension)
+        || SUPPORTED_TABULAR_EXTENSIONS.contains(&extension)
+        || SUPPORTED_NUMPY_EXTENSIONS.contains(&extension)
+        || SUPPORTED_GEO_EXTENSIONS.contains(&extension)
+        || SUPPORTED_ROS_BAG_EXTENSIONS.contains(&extension)
 }
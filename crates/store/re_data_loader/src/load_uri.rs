@@ -0,0 +1,232 @@
+use std::{borrow::Cow, cell::RefCell, ops::ControlFlow};
+
+use re_log_types::{FileSource, LogMsg};
+use re_smart_channel::Sender;
+
+use crate::DataLoaderError;
+
+// ---
+
+/// Download progress of remote files is logged whenever this many more bytes have come in.
+const PROGRESS_INTERVAL_BYTES: usize = 16 * 1024 * 1024;
+
+/// Is this an `http://` or `https://` URI?
+pub fn is_http_uri(uri: &str) -> bool {
+    let scheme = uri.split_once("://").map(|(scheme, _)| scheme);
+    scheme.is_some_and(|scheme| {
+        scheme.eq_ignore_ascii_case("http") || scheme.eq_ignore_ascii_case("https")
+    })
+}
+
+/// The name of the file a URI points to, without query or fragment.
+///
+/// Loaders only get to see this, mostly to pick up the file's extension.
+pub fn filepath_from_uri(uri: &str) -> std::path::PathBuf {
+    let path = uri.split(['?', '#']).next().unwrap_or(uri);
+    let name = path
+        .trim_end_matches('/')
+        .rsplit('/')
+        .next()
+        .unwrap_or(path);
+    name.into()
+}
+
+/// Downloads the file at `uri` and loads it using all [`crate::DataLoader`]s available.
+///
+/// Once downloaded, the contents go through [`crate::load_from_file_contents`], so every
+/// loader that supports in-memory contents also supports remote files.
+///
+/// Only checks synchronously that `uri` is an `http://` or `https://` URI: download and
+/// loading errors are logged and forwarded to `tx`.
+///
+/// `on_loaded` is called once the download is complete, e.g. to wake up the UI thread.
+pub fn load_from_uri(
+    settings: &crate::DataLoaderSettings,
+    file_source: FileSource,
+    uri: &str,
+    // NOTE: This channel must be unbounded since we serialize all operations when running on wasm.
+    tx: &Sender<LogMsg>,
+    on_loaded: Option<Box<dyn Fn() + Send + Sync>>,
+) -> Result<(), DataLoaderError> {
+    re_tracing::profile_function!(uri);
+
+    if !is_http_uri(uri) {
+        return Err(DataLoaderError::Incompatible(uri.into()));
+    }
+
+    re_log::info!("Downloading {uri}…");
+
+    let settings = settings.clone();
+    let filepath = filepath_from_uri(uri);
+    let tx = tx.clone();
+
+    fetch_uri(uri.to_owned(), move |contents| {
+        let result = contents
+            .map_err(|err| DataLoaderError::Other(anyhow::anyhow!(err)))
+            .and_then(|contents| {
+                crate::load_from_file_contents(
+                    &settings,
+                    file_source,
+                    &filepath,
+                    Cow::Owned(contents),
+                    &tx,
+                )
+            });
+
+        if let Err(err) = result {
+            re_log::error!(?filepath, %err, "Failed to load remote file");
+            tx.quit(Some(Box::new(err))).ok();
+        }
+
+        if let Some(on_loaded) = on_loaded {
+            on_loaded();
+        }
+    });
+
+    Ok(())
+}
+
+/// Downloads the file at `uri`, blocking until it is complete.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn fetch_uri_blocking(uri: &str) -> Result<Vec<u8>, DataLoaderError> {
+    re_tracing::profile_function!(uri);
+
+    let (tx, rx) = std::sync::mpsc::channel();
+    fetch_uri(uri.to_owned(), move |contents| {
+        tx.send(contents).ok();
+    });
+
+    rx.recv()
+        .map_err(|_err| anyhow::anyhow!("Download of {uri} was interrupted"))?
+        .map_err(|err| DataLoaderError::Other(anyhow::anyhow!(err)))
+}
+
+/// Downloads the file at `uri` in a single request, then calls `on_done` with its contents.
+///
+/// The response is streamed in, logging the progress of large downloads along the way.
+pub fn fetch_uri(uri: String, on_done: impl FnOnce(Result<Vec<u8>, String>) + Send + 'static) {
+    let download = RefCell::new(Download {
+        contents: Vec::new(),
+        total_size: None,
+        next_progress_report: PROGRESS_INTERVAL_BYTES,
+        on_done: Some(Box::new(on_done)),
+    });
+
+    ehttp::streaming::fetch(ehttp::Request::get(&uri), move |part| {
+        let mut download = download.borrow_mut();
+        match part {
+            Ok(ehttp::streaming::Part::Response(response)) => {
+                if !response.ok {
+                    return download.finish(Err(format!(
+                        "Failed to download {uri}: {} {}",
+                        response.status, response.status_text
+                    )));
+                }
+
+                download.total_size = response
+                    .headers
+                    .headers
+                    .iter()
+                    .find(|(key, _)| key.eq_ignore_ascii_case("content-length"))
+                    .and_then(|(_, value)| value.trim().parse().ok());
+                if let Some(total_size) = download.total_size {
+                    download.contents.reserve(total_size);
+                }
+                ControlFlow::Continue(())
+            }
+
+            Ok(ehttp::streaming::Part::Chunk(chunk)) => {
+                if chunk.is_empty() {
+                    let contents = std::mem::take(&mut download.contents);
+                    return download.finish(Ok(contents));
+                }
+
+                download.contents.extend_from_slice(&chunk);
+                if download.next_progress_report <= download.contents.len() {
+                    download.next_progress_report += PROGRESS_INTERVAL_BYTES;
+                    re_log::info!(
+                        "Downloaded {} of {uri}",
+                        progress_text(download.contents.len(), download.total_size)
+                    );
+                }
+                ControlFlow::Continue(())
+            }
+
+            Err(err) => download.finish(Err(format!("Failed to download {uri}: {err}"))),
+        }
+    });
+}
+
+type OnFetched = Box<dyn FnOnce(Result<Vec<u8>, String>) + Send>;
+
+/// State of an ongoing [`fetch_uri`].
+struct Download {
+    contents: Vec<u8>,
+
+    /// Size of the whole file, if the server told us via `Content-Length`.
+    total_size: Option<usize>,
+
+    /// Progress is logged once [`Self::contents`] reaches this many bytes.
+    next_progress_report: usize,
+
+    /// Taken once the download is done or failed.
+    on_done: Option<OnFetched>,
+}
+
+impl Download {
+    fn finish(&mut self, result: Result<Vec<u8>, String>) -> ControlFlow<()> {
+        if let Some(on_done) = self.on_done.take() {
+            on_done(result);
+        }
+        ControlFlow::Break(())
+    }
+}
+
+/// E.g. `32 MiB of 100 MiB (32%)`, or just `32 MiB` if the total size is unknown.
+fn progress_text(num_bytes_downloaded: usize, total_size: Option<usize>) -> String {
+    let downloaded = re_format::format_bytes(num_bytes_downloaded as f64);
+    match total_size {
+        Some(total_size) if 0 < total_size => format!(
+            "{downloaded} of {} ({:.0}%)",
+            re_format::format_bytes(total_size as f64),
+            100.0 * num_bytes_downloaded as f64 / total_size as f64
+        ),
+        _ => downloaded,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_uri_helpers() {
+        assert!(is_http_uri("https://example.com/video.mp4"));
+        assert!(is_http_uri("HTTP://example.com/video.mp4"));
+        assert!(!is_http_uri("rerun+http://127.0.0.1:9876/proxy"));
+        assert!(!is_http_uri("/home/user/video.mp4"));
+
+        assert_eq!(
+            filepath_from_uri("https://example.com/assets/mesh.glb?token=abc#frag"),
+            std::path::PathBuf::from("mesh.glb")
+        );
+    }
+
+    #[test]
+    fn test_progress_text() {
+        let mib = 1024 * 1024;
+        assert_eq!(
+            progress_text(32 * mib, Some(128 * mib)),
+            format!(
+                "{} of {} (25%)",
+                re_format::format_bytes((32 * mib) as f64),
+                re_format::format_bytes((128 * mib) as f64)
+            )
+        );
+        assert_eq!(
+            progress_text(32 * mib, None),
+            re_format::format_bytes((32 * mib) as f64)
+        );
+        assert_eq!(progress_text(0, Some(0)), re_format::format_bytes(0.0));
+    }
+}
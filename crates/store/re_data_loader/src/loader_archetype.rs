@@ -11,6 +11,19 @@ use crate::{DataLoader, DataLoaderError, LoadedData};
 
 // ---
 
+/// How the [`ArchetypeLoader`] interprets image files.
+#[derive(Debug, Clone, Default)]
+pub struct ImageLoaderSettings {
+    /// Log 16-bit single-channel PNGs as [`re_types::archetypes::EncodedDepthImage`]s instead
+    /// of grayscale [`re_types::archetypes::EncodedImage`]s.
+    ///
+    /// Depth cameras commonly store millimeters this way, so the depth meter is left to its
+    /// default of 1000.
+    pub png16_as_depth: bool,
+}
+
+// ---
+
 /// Loads data from any supported file or in-memory contents as native [`re_types::Archetype`]s.
 ///
 /// This is a simple generic [`DataLoader`] for filetypes that match 1-to-1 with our builtin
@@ -98,6 +111,7 @@ impl DataLoader for ArchetypeLoader {
         if crate::SUPPORTED_IMAGE_EXTENSIONS.contains(&extension.as_str()) {
             re_log::debug!(?filepath, loader = self.name(), "Loading image…",);
             rows.extend(load_image(
+                &settings.image,
                 &filepath,
                 timepoint,
                 entity_path,
@@ -121,7 +135,12 @@ impl DataLoader for ArchetypeLoader {
             )?);
         } else if crate::SUPPORTED_POINT_CLOUD_EXTENSIONS.contains(&extension.as_str()) {
             re_log::debug!(?filepath, loader = self.name(), "Loading 3D point cloud…",);
-            rows.extend(load_point_cloud(timepoint, entity_path, &contents)?);
+            rows.extend(load_point_cloud(
+                &extension,
+                timepoint,
+                entity_path,
+                &contents,
+            )?);
         } else if crate::SUPPORTED_TEXT_EXTENSIONS.contains(&extension.as_str()) {
             re_log::debug!(?filepath, loader = self.name(), "Loading text document…",);
             rows.extend(load_text_document(
@@ -149,7 +168,8 @@ impl DataLoader for ArchetypeLoader {
 
 // ---
 
-fn load_image(
+pub(crate) fn load_image(
+    settings: &ImageLoaderSettings,
     filepath: &std::path::Path,
     timepoint: TimePoint,
     entity_path: EntityPath,
@@ -157,12 +177,26 @@ fn load_image(
 ) -> Result<impl ExactSizeIterator<Item = Chunk> + use<>, DataLoaderError> {
     re_tracing::profile_function!();
 
+    let media_type = image::ImageFormat::from_path(filepath)
+        .ok()
+        .map(|format| format.to_mime_type());
+
     let rows = [
-        {
+        if settings.png16_as_depth && is_png16_grayscale(&contents) {
+            let mut arch = re_types::archetypes::EncodedDepthImage::from_file_contents(contents);
+
+            if let Some(media_type) = media_type {
+                arch = arch.with_media_type(media_type);
+            }
+
+            Chunk::builder(entity_path)
+                .with_archetype(RowId::new(), timepoint, &arch)
+                .build()?
+        } else {
             let mut arch = re_types::archetypes::EncodedImage::from_file_contents(contents);
 
-            if let Ok(format) = image::ImageFormat::from_path(filepath) {
-                arch = arch.with_media_type(format.to_mime_type());
+            if let Some(media_type) = media_type {
+                arch = arch.with_media_type(media_type);
             }
 
             Chunk::builder(entity_path)
@@ -175,6 +209,23 @@ fn load_image(
     Ok(rows.into_iter())
 }
 
+/// Whether the bytes are a PNG with a single 16-bit channel, judging by its `IHDR` header.
+fn is_png16_grayscale(contents: &[u8]) -> bool {
+    const PNG_SIGNATURE: &[u8] = b"\x89PNG\r\n\x1a\n";
+
+    // Signature (8), chunk length (4), chunk type (4), width (4), height (4), bit depth, color type.
+    let Some(header) = contents.get(..26) else {
+        return false;
+    };
+    let bit_depth = header[24];
+    let color_type = header[25];
+
+    header.starts_with(PNG_SIGNATURE)
+        && &header[12..16] == b"IHDR"
+        && bit_depth == 16
+        && color_type == 0
+}
+
 fn load_video(
     filepath: &std::path::Path,
     mut timepoint: TimePoint,
@@ -288,6 +339,7 @@ fn load_mesh(
 }
 
 fn load_point_cloud(
+    extension: &str,
     timepoint: TimePoint,
     entity_path: EntityPath,
     contents: &[u8],
@@ -297,10 +349,18 @@ fn load_point_cloud(
     let rows = [
         {
             // TODO(#4532): `.ply` data loader should support 2D point cloud & meshes
-            let points3d = re_types::archetypes::Points3D::from_file_contents(contents)?;
-            Chunk::builder(entity_path)
-                .with_archetype(RowId::new(), timepoint, &points3d)
-                .build()?
+            let builder = Chunk::builder(entity_path);
+            if extension != "ply" {
+                let batches = crate::point_cloud::load(extension, contents)?;
+                builder.with_serialized_batches(RowId::new(), timepoint, batches)
+            } else if re_types::archetypes::GaussianSplats3D::is_splat_ply(contents) {
+                let splats = re_types::archetypes::GaussianSplats3D::from_file_contents(contents)?;
+                builder.with_archetype(RowId::new(), timepoint, &splats)
+            } else {
+                let points3d = re_types::archetypes::Points3D::from_file_contents(contents)?;
+                builder.with_archetype(RowId::new(), timepoint, &points3d)
+            }
+            .build()?
         },
         //
     ];
@@ -331,3 +391,27 @@ fn load_text_document(
 
     Ok(rows.into_iter())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn png_header(bit_depth: u8, color_type: u8) -> Vec<u8> {
+        let mut header = b"\x89PNG\r\n\x1a\n".to_vec();
+        header.extend_from_slice(&13_u32.to_be_bytes());
+        header.extend_from_slice(b"IHDR");
+        header.extend_from_slice(&640_u32.to_be_bytes());
+        header.extend_from_slice(&480_u32.to_be_bytes());
+        header.extend_from_slice(&[bit_depth, color_type, 0, 0, 0]);
+        header
+    }
+
+    #[test]
+    fn test_is_png16_grayscale() {
+        assert!(is_png16_grayscale(&png_header(16, 0)));
+        assert!(!is_png16_grayscale(&png_header(8, 0)));
+        assert!(!is_png16_grayscale(&png_header(16, 2)));
+        assert!(!is_png16_grayscale(&png_header(16, 0)[..20]));
+        assert!(!is_png16_grayscale(b"not a png at all, but long enough"));
+    }
+}
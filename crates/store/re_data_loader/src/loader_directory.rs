@@ -1,9 +1,40 @@
+#[cfg(not(target_arch = "wasm32"))]
+use re_log_types::EntityPath;
+
+/// How the [`DirectoryLoader`] treats the files within a directory.
+#[derive(Debug, Clone, Default)]
+pub struct DirectoryLoaderSettings {
+    /// Log numbered images such as `frame_000123.png` as a single entity over time,
+    /// instead of one entity per file.
+    pub image_sequence: Option<ImageSequenceTime>,
+
+    /// Keep watching the directory once it has been loaded, and load new files as they appear.
+    pub watch: bool,
+}
+
+/// Where the frames of an image sequence get their time from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageSequenceTime {
+    /// The number at the end of the file name, on the [`IMAGE_SEQUENCE_FRAME_TIMELINE`] timeline.
+    FrameNumber,
+
+    /// The last modification time of the file, on the [`IMAGE_SEQUENCE_FILE_TIME_TIMELINE`] timeline.
+    ModificationTime,
+}
+
+/// Sequence timeline used for [`ImageSequenceTime::FrameNumber`].
+pub const IMAGE_SEQUENCE_FRAME_TIMELINE: &str = "frame";
+
+/// Timestamp timeline used for [`ImageSequenceTime::ModificationTime`].
+pub const IMAGE_SEQUENCE_FILE_TIME_TIMELINE: &str = "file_time";
+
 /// Recursively loads entire directories, using the appropriate [`crate::DataLoader`]:s for each
 /// files within.
+///
+/// See [`DirectoryLoaderSettings`] for loading image sequences and watching for new files.
 //
 // TODO(cmc): There are a lot more things than can be done be done when it comes to the semantics
-// of a folder, e.g.: HIVE-like partitioning, similarly named files with different indices and/or
-// timestamps (e.g. a folder of video frames), etc.
+// of a folder, e.g.: HIVE-like partitioning, etc.
 // We could support some of those at some point, or at least add examples to show users how.
 pub struct DirectoryLoader;
 
@@ -20,6 +51,8 @@ impl crate::DataLoader for DirectoryLoader {
         dirpath: std::path::PathBuf,
         tx: std::sync::mpsc::Sender<crate::LoadedData>,
     ) -> Result<(), crate::DataLoaderError> {
+        use anyhow::Context as _;
+
         if dirpath.is_file() {
             return Err(crate::DataLoaderError::Incompatible(dirpath.clone()));
         }
@@ -33,6 +66,9 @@ impl crate::DataLoader for DirectoryLoader {
 
         re_log::debug!(?dirpath, loader = self.name(), "Loading directory…",);
 
+        let mut known_files = ahash::HashSet::default();
+        let mut sequence_frames = Vec::new();
+
         for entry in walkdir::WalkDir::new(&dirpath) {
             let entry = match entry {
                 Ok(entry) => entry,
@@ -43,34 +79,51 @@ impl crate::DataLoader for DirectoryLoader {
             };
 
             let filepath = entry.path();
-            if filepath.is_file() {
-                let settings = settings.clone();
-                let filepath = filepath.to_owned();
-                let tx = tx.clone();
-
-                // NOTE(1): `spawn` is fine, this whole function is native-only.
-                // NOTE(2): this must spawned on a dedicated thread to avoid a deadlock!
-                // `load` will spawn a bunch of loaders on the common rayon thread pool and wait for
-                // their response via channels: we cannot be waiting for these responses on the
-                // common rayon thread pool.
-                _ = std::thread::Builder::new()
-                    .name(format!("load_dir_entry({filepath:?})"))
-                    .spawn(move || {
-                        let data = match crate::load_file::load(&settings, &filepath, None) {
-                            Ok(data) => data,
-                            Err(err) => {
-                                re_log::error!(?filepath, %err, "Failed to load directory entry");
-                                return;
-                            }
-                        };
+            if !filepath.is_file() {
+                continue;
+            }
+            known_files.insert(filepath.to_owned());
 
-                        for datum in data {
-                            if tx.send(datum).is_err() {
-                                break;
-                            }
-                        }
-                    });
+            if let Some(frame) = SequenceFrame::from_path(&settings.directory, filepath) {
+                sequence_frames.push(frame);
+                continue;
             }
+
+            let settings = settings.clone();
+            let filepath = filepath.to_owned();
+            let tx = tx.clone();
+
+            // NOTE(1): `spawn` is fine, this whole function is native-only.
+            // NOTE(2): this must spawned on a dedicated thread to avoid a deadlock!
+            // `load` will spawn a bunch of loaders on the common rayon thread pool and wait for
+            // their response via channels: we cannot be waiting for these responses on the
+            // common rayon thread pool.
+            _ = std::thread::Builder::new()
+                .name(format!("load_dir_entry({filepath:?})"))
+                .spawn(move || {
+                    load_entry(&settings, &filepath, &tx);
+                });
+        }
+
+        if !sequence_frames.is_empty() {
+            // Frames are small and many, so they share a single thread rather than one each.
+            let settings = settings.clone();
+            let tx = tx.clone();
+            std::thread::Builder::new()
+                .name(format!("load_image_sequence({dirpath:?})"))
+                .spawn(move || {
+                    sequence_frames.sort_by_key(|frame| frame.frame_nr);
+                    for frame in sequence_frames {
+                        if !frame.load(&settings, &tx) {
+                            break; // The other end has decided to hang up, not our problem.
+                        }
+                    }
+                })
+                .with_context(|| format!("Failed to spawn IO thread for {dirpath:?}"))?;
+        }
+
+        if settings.directory.watch {
+            watch_directory(settings, dirpath, tx, known_files)?;
         }
 
         Ok(())
@@ -88,3 +141,281 @@ impl crate::DataLoader for DirectoryLoader {
         Err(crate::DataLoaderError::Incompatible(path))
     }
 }
+
+/// Loads a single file of the directory using all available [`crate::DataLoader`]s.
+///
+/// Returns `false` if the other end has hung up.
+///
+/// Must not be called from the common rayon thread pool, see [`crate::load_file::load`].
+#[cfg(not(target_arch = "wasm32"))]
+fn load_entry(
+    settings: &crate::DataLoaderSettings,
+    filepath: &std::path::Path,
+    tx: &std::sync::mpsc::Sender<crate::LoadedData>,
+) -> bool {
+    if let Some(frame) = SequenceFrame::from_path(&settings.directory, filepath) {
+        return frame.load(settings, tx);
+    }
+
+    let data = match crate::load_file::load(settings, filepath, None) {
+        Ok(data) => data,
+        Err(err) => {
+            re_log::error!(?filepath, %err, "Failed to load directory entry");
+            return true;
+        }
+    };
+
+    for datum in data {
+        if tx.send(datum).is_err() {
+            return false;
+        }
+    }
+
+    true
+}
+
+// ---
+
+/// An image that is part of a numbered sequence, e.g. `frames/frame_000123.png`.
+#[cfg(not(target_arch = "wasm32"))]
+struct SequenceFrame {
+    filepath: std::path::PathBuf,
+
+    /// Shared by all frames of the sequence, e.g. `frames/frame`.
+    entity_path: EntityPath,
+
+    frame_nr: i64,
+    time: ImageSequenceTime,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl SequenceFrame {
+    /// Returns `None` if image sequences are disabled, or if this isn't a numbered image.
+    fn from_path(settings: &DirectoryLoaderSettings, filepath: &std::path::Path) -> Option<Self> {
+        let time = settings.image_sequence?;
+
+        let extension = crate::extension(filepath);
+        if !crate::SUPPORTED_IMAGE_EXTENSIONS.contains(&extension.as_str()) {
+            return None;
+        }
+
+        let (name, frame_nr) = split_frame_number(filepath.file_stem()?.to_str()?)?;
+        let dirpath = filepath.parent()?;
+        let entity_path = if name.is_empty() {
+            EntityPath::from_file_path(dirpath)
+        } else {
+            EntityPath::from_file_path(&dirpath.join(name))
+        };
+
+        Some(Self {
+            filepath: filepath.to_owned(),
+            entity_path,
+            frame_nr,
+            time,
+        })
+    }
+
+    /// Returns `false` if the other end has hung up.
+    fn load(
+        self,
+        settings: &crate::DataLoaderSettings,
+        tx: &std::sync::mpsc::Sender<crate::LoadedData>,
+    ) -> bool {
+        use crate::DataLoader as _;
+
+        let filepath = self.filepath.clone();
+        let chunks = match self.into_chunks(&settings.image) {
+            Ok(chunks) => chunks,
+            Err(err) => {
+                re_log::error!(?filepath, %err, "Failed to load image sequence frame");
+                return true;
+            }
+        };
+
+        let store_id = settings
+            .opened_store_id
+            .clone()
+            .unwrap_or_else(|| settings.store_id.clone());
+        for chunk in chunks {
+            let data = crate::LoadedData::Chunk(DirectoryLoader.name(), store_id.clone(), chunk);
+            if tx.send(data).is_err() {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    fn into_chunks(
+        self,
+        image_settings: &crate::ImageLoaderSettings,
+    ) -> Result<impl ExactSizeIterator<Item = re_chunk::Chunk>, crate::DataLoaderError> {
+        use anyhow::Context as _;
+        use re_log_types::{TimeCell, TimePoint};
+
+        re_tracing::profile_function!();
+
+        let mut timepoint = TimePoint::default();
+        match self.time {
+            ImageSequenceTime::FrameNumber => {
+                timepoint.insert_cell(
+                    IMAGE_SEQUENCE_FRAME_TIMELINE,
+                    TimeCell::from_sequence(self.frame_nr),
+                );
+            }
+            ImageSequenceTime::ModificationTime => {
+                let modified = std::fs::metadata(&self.filepath)
+                    .and_then(|metadata| metadata.modified())
+                    .with_context(|| {
+                        format!("Failed to read modification time of {:?}", self.filepath)
+                    })?;
+                let modified = TimeCell::try_from(modified)
+                    .ok()
+                    .with_context(|| format!("Invalid modification time of {:?}", self.filepath))?;
+                timepoint.insert_cell(IMAGE_SEQUENCE_FILE_TIME_TIMELINE, modified);
+            }
+        }
+
+        let contents = {
+            re_tracing::profile_scope!("fs::read");
+            std::fs::read(&self.filepath)
+                .with_context(|| format!("Failed to read file {:?}", self.filepath))?
+        };
+
+        crate::loader_archetype::load_image(
+            image_settings,
+            &self.filepath,
+            timepoint,
+            self.entity_path,
+            contents,
+        )
+    }
+}
+
+/// Splits a file stem like `frame_000123` into the name of its sequence (`frame`) and its
+/// frame number (`123`).
+///
+/// Returns `None` if the stem doesn't end with a number.
+#[cfg(not(target_arch = "wasm32"))]
+fn split_frame_number(stem: &str) -> Option<(&str, i64)> {
+    let name = stem.trim_end_matches(|c: char| c.is_ascii_digit());
+    let frame_nr = stem[name.len()..].parse().ok()?;
+    let name = name.trim_end_matches(['_', '-', '.', ' ']);
+    Some((name, frame_nr))
+}
+
+// ---
+
+/// How long a new file has to be left alone before it gets loaded, so that we don't pick up
+/// files that are still being written.
+#[cfg(not(target_arch = "wasm32"))]
+const WATCH_SETTLE_TIME: std::time::Duration = std::time::Duration::from_millis(250);
+
+/// Loads every file that appears in `dirpath` from now on, on a dedicated thread.
+///
+/// Watching stops once the other end of `tx` hangs up, or on `SIGINT`.
+#[cfg(not(target_arch = "wasm32"))]
+fn watch_directory(
+    settings: &crate::DataLoaderSettings,
+    dirpath: std::path::PathBuf,
+    tx: std::sync::mpsc::Sender<crate::LoadedData>,
+    mut known_files: ahash::HashSet<std::path::PathBuf>,
+) -> Result<(), crate::DataLoaderError> {
+    use anyhow::Context as _;
+    use notify::{RecursiveMode, Watcher as _};
+
+    #[cfg(not(any(target_os = "windows", target_arch = "wasm32")))]
+    re_crash_handler::sigint::track_sigint();
+
+    let (tx_file_notifs, rx_file_notifs) = crossbeam::channel::unbounded();
+    let mut watcher = notify::recommended_watcher(tx_file_notifs)
+        .with_context(|| format!("failed to create file watcher for {dirpath:?}"))?;
+    watcher
+        .watch(&dirpath, RecursiveMode::Recursive)
+        .with_context(|| format!("failed to watch file changes in {dirpath:?}"))?;
+
+    re_log::info!("Watching {dirpath:?} for new files…");
+
+    let settings = settings.clone();
+    std::thread::Builder::new()
+        .name(format!("watch_dir({dirpath:?})"))
+        .spawn(move || {
+            // Dropping the watcher would stop the notifications.
+            let _watcher = watcher;
+
+            let rx_ticker = crossbeam::channel::tick(std::time::Duration::from_millis(50));
+
+            // New files, and when they were last touched.
+            let mut new_files: ahash::HashMap<std::path::PathBuf, std::time::Instant> =
+                Default::default();
+
+            loop {
+                crossbeam::select! {
+                    recv(rx_ticker) -> _ => {
+                        if re_crash_handler::sigint::was_sigint_ever_caught() {
+                            break;
+                        }
+
+                        let mut settled: Vec<_> = new_files
+                            .iter()
+                            .filter(|(_, last_touched)| {
+                                last_touched.elapsed() >= WATCH_SETTLE_TIME
+                            })
+                            .map(|(filepath, _)| filepath.clone())
+                            .collect();
+                        settled.sort();
+
+                        for filepath in settled {
+                            new_files.remove(&filepath);
+                            if !filepath.is_file() || !known_files.insert(filepath.clone()) {
+                                continue;
+                            }
+
+                            re_log::debug!(?filepath, "Loading new file from watched directory");
+                            if !load_entry(&settings, &filepath, &tx) {
+                                return; // The other end has decided to hang up, not our problem.
+                            }
+                        }
+                    }
+
+                    recv(rx_file_notifs) -> res => {
+                        match res {
+                            Ok(Ok(event)) => {
+                                use notify::EventKind;
+                                if matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_)) {
+                                    for path in event.paths {
+                                        if !known_files.contains(&path) {
+                                            new_files.insert(path, std::time::Instant::now());
+                                        }
+                                    }
+                                }
+                            }
+                            Ok(Err(err)) => {
+                                re_log::warn!(?dirpath, %err, "Error while watching directory");
+                            }
+                            Err(_) => break,
+                        }
+                    }
+                }
+            }
+
+            re_log::debug!(?dirpath, "Stopped watching directory");
+        })
+        .with_context(|| format!("Failed to spawn watcher thread for {dirpath:?}"))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_frame_number() {
+        assert_eq!(split_frame_number("frame_000123"), Some(("frame", 123)));
+        assert_eq!(split_frame_number("cam-left.0042"), Some(("cam-left", 42)));
+        assert_eq!(split_frame_number("0007"), Some(("", 7)));
+        assert_eq!(split_frame_number("img2d_10"), Some(("img2d", 10)));
+        assert_eq!(split_frame_number("frame"), None);
+    }
+}
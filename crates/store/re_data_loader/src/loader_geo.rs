@@ -0,0 +1,529 @@
+use anyhow::Context as _;
+
+use re_chunk::{Chunk, ChunkId, EntityPath, RowId, TimeColumn, TimePoint, Timeline};
+use re_log_types::{EntityPathPart, Timestamp};
+use re_types::archetypes::{GeoLineStrings, GeoPoints};
+
+use crate::{DataLoader, DataLoaderError, LoadedData};
+
+// ---
+
+/// The timestamp timeline that timed GPS tracks are logged against.
+pub const GEO_TRACK_TIMELINE: &str = "time";
+
+/// Loads GeoJSON, GPX and KML files for display in the map view.
+///
+/// Standalone points end up as [`GeoPoints`] under `<file>/points`, lines and polygon outlines
+/// as [`GeoLineStrings`] under `<file>/lines`.
+///
+/// Tracks (GPX `<trk>`s and KML `<gx:Track>`s) are logged to their own entity: their full path
+/// is static, and if their points carry timestamps, the current position is logged on the
+/// [`GEO_TRACK_TIMELINE`].
+pub struct GeoLoader;
+
+impl DataLoader for GeoLoader {
+    #[inline]
+    fn name(&self) -> String {
+        "rerun.data_loaders.Geo".into()
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn load_from_path(
+        &self,
+        settings: &crate::DataLoaderSettings,
+        filepath: std::path::PathBuf,
+        tx: std::sync::mpsc::Sender<LoadedData>,
+    ) -> Result<(), DataLoaderError> {
+        if filepath.is_dir() || !is_geo_file(&filepath) {
+            return Err(DataLoaderError::Incompatible(filepath.clone()));
+        }
+
+        re_tracing::profile_function!(filepath.display().to_string());
+
+        let contents = {
+            re_tracing::profile_scope!("fs::read");
+            std::fs::read(&filepath).with_context(|| format!("Failed to read file {filepath:?}"))?
+        };
+        let contents = std::borrow::Cow::Owned(contents);
+
+        self.load_from_file_contents(settings, filepath, contents, tx)
+    }
+
+    fn load_from_file_contents(
+        &self,
+        settings: &crate::DataLoaderSettings,
+        filepath: std::path::PathBuf,
+        contents: std::borrow::Cow<'_, [u8]>,
+        tx: std::sync::mpsc::Sender<LoadedData>,
+    ) -> Result<(), DataLoaderError> {
+        if !is_geo_file(&filepath) {
+            return Err(DataLoaderError::Incompatible(filepath.clone()));
+        }
+
+        re_tracing::profile_function!(filepath.display().to_string());
+
+        re_log::debug!(?filepath, loader = self.name(), "Loading geographic data…",);
+
+        let text = std::str::from_utf8(&contents)
+            .with_context(|| format!("{filepath:?} is not valid UTF-8"))?;
+        let geo = match crate::extension(&filepath).as_str() {
+            "gpx" => GeoData::from_gpx(text)?,
+            "kml" => GeoData::from_kml(text)?,
+            _ => GeoData::from_geojson(text)?,
+        };
+
+        let store_id = settings
+            .opened_store_id
+            .clone()
+            .unwrap_or_else(|| settings.store_id.clone());
+        for chunk in geo.into_chunks(&EntityPath::from_file_path(&filepath))? {
+            let data = LoadedData::Chunk(Self::name(&Self), store_id.clone(), chunk);
+            if tx.send(data).is_err() {
+                break; // The other end has decided to hang up, not our problem.
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn is_geo_file(filepath: &std::path::Path) -> bool {
+    crate::SUPPORTED_GEO_EXTENSIONS.contains(&crate::extension(filepath).as_str())
+}
+
+// ---
+
+/// A latitude/longitude pair, in degrees.
+type LatLon = (f64, f64);
+
+/// A named GPS track, made of one or more segments.
+#[derive(Debug, Default)]
+struct Track {
+    name: String,
+
+    /// Each point is paired with its timestamp, in nanoseconds since the epoch, if it has one.
+    segments: Vec<Vec<(LatLon, Option<i64>)>>,
+}
+
+/// Everything we could extract from a geographic file.
+#[derive(Debug, Default)]
+struct GeoData {
+    points: Vec<LatLon>,
+    line_strings: Vec<Vec<LatLon>>,
+    tracks: Vec<Track>,
+}
+
+impl GeoData {
+    fn from_geojson(text: &str) -> anyhow::Result<Self> {
+        let json: serde_json::Value = serde_json::from_str(text).context("Invalid GeoJSON")?;
+
+        let mut geo = Self::default();
+        geo.add_geojson_object(&json)?;
+        Ok(geo)
+    }
+
+    /// Adds a GeoJSON object, see <https://datatracker.ietf.org/doc/html/rfc7946#section-3>.
+    fn add_geojson_object(&mut self, object: &serde_json::Value) -> anyhow::Result<()> {
+        let kind = object["type"].as_str().unwrap_or_default();
+        let coordinates = &object["coordinates"];
+
+        match kind {
+            "FeatureCollection" => {
+                for feature in object["features"].as_array().into_iter().flatten() {
+                    self.add_geojson_object(feature)?;
+                }
+            }
+
+            "Feature" => {
+                // Features without a location have a `null` geometry.
+                if !object["geometry"].is_null() {
+                    self.add_geojson_object(&object["geometry"])?;
+                }
+            }
+
+            "GeometryCollection" => {
+                for geometry in object["geometries"].as_array().into_iter().flatten() {
+                    self.add_geojson_object(geometry)?;
+                }
+            }
+
+            "Point" => self.points.push(geojson_position(coordinates)?),
+
+            "MultiPoint" => {
+                for position in geojson_array(coordinates)? {
+                    self.points.push(geojson_position(position)?);
+                }
+            }
+
+            "LineString" => self.line_strings.push(geojson_positions(coordinates)?),
+
+            // Polygons are drawn as the outlines of their outer ring and holes.
+            "MultiLineString" | "Polygon" => {
+                for line in geojson_array(coordinates)? {
+                    self.line_strings.push(geojson_positions(line)?);
+                }
+            }
+
+            "MultiPolygon" => {
+                for polygon in geojson_array(coordinates)? {
+                    for ring in geojson_array(polygon)? {
+                        self.line_strings.push(geojson_positions(ring)?);
+                    }
+                }
+            }
+
+            _ => anyhow::bail!("Unknown GeoJSON type {kind:?}"),
+        }
+
+        Ok(())
+    }
+
+    /// Parses a GPX file, see <https://www.topografix.com/GPX/1/1/>.
+    fn from_gpx(text: &str) -> anyhow::Result<Self> {
+        let doc = roxmltree::Document::parse(text).context("Invalid GPX")?;
+
+        let mut geo = Self::default();
+        for node in doc
+            .root_element()
+            .children()
+            .filter(|node| node.is_element())
+        {
+            match node.tag_name().name() {
+                "wpt" => geo.points.push(gpx_position(node)?),
+
+                "rte" => {
+                    let route = elements(node, "rtept")
+                        .map(gpx_position)
+                        .collect::<anyhow::Result<_>>()?;
+                    geo.line_strings.push(route);
+                }
+
+                "trk" => {
+                    let name = child_text(node, "name")
+                        .map_or_else(|| format!("track_{}", geo.tracks.len()), ToOwned::to_owned);
+
+                    let segments = elements(node, "trkseg")
+                        .map(|segment| {
+                            elements(segment, "trkpt")
+                                .map(|point| {
+                                    let time = child_text(point, "time")
+                                        .map(parse_timestamp)
+                                        .transpose()?;
+                                    Ok((gpx_position(point)?, time))
+                                })
+                                .collect::<anyhow::Result<Vec<_>>>()
+                        })
+                        .collect::<anyhow::Result<_>>()?;
+
+                    geo.tracks.push(Track { name, segments });
+                }
+
+                _ => {}
+            }
+        }
+
+        Ok(geo)
+    }
+
+    /// Parses the geometries of a KML file, see <https://developers.google.com/kml/documentation/kmlreference>.
+    fn from_kml(text: &str) -> anyhow::Result<Self> {
+        let doc = roxmltree::Document::parse(text).context("Invalid KML")?;
+
+        let mut geo = Self::default();
+        for node in doc.descendants().filter(|node| node.is_element()) {
+            match node.tag_name().name() {
+                "Point" => {
+                    let coordinates = child_text(node, "coordinates").unwrap_or_default();
+                    geo.points.extend(kml_coordinates(coordinates)?);
+                }
+
+                // `LinearRing`s are the outer and inner boundaries of polygons.
+                "LineString" | "LinearRing" => {
+                    let coordinates = child_text(node, "coordinates").unwrap_or_default();
+                    geo.line_strings.push(kml_coordinates(coordinates)?);
+                }
+
+                // `<gx:Track>`: parallel lists of `<when>` and `<gx:coord>`.
+                "Track" => {
+                    let name = node
+                        .ancestors()
+                        .find(|node| node.has_tag_name("Placemark"))
+                        .and_then(|placemark| child_text(placemark, "name"))
+                        .map_or_else(|| format!("track_{}", geo.tracks.len()), ToOwned::to_owned);
+
+                    let mut times = elements(node, "when").map(|when| {
+                        when.text()
+                            .filter(|text| !text.trim().is_empty())
+                            .map(parse_timestamp)
+                            .transpose()
+                    });
+                    let segment = elements(node, "coord")
+                        .map(|coord| {
+                            let position = kml_position(coord.text().unwrap_or_default(), ' ')?;
+                            let time = times.next().transpose()?.flatten();
+                            Ok((position, time))
+                        })
+                        .collect::<anyhow::Result<_>>()?;
+
+                    geo.tracks.push(Track {
+                        name,
+                        segments: vec![segment],
+                    });
+                }
+
+                _ => {}
+            }
+        }
+
+        Ok(geo)
+    }
+
+    fn into_chunks(self, entity_path: &EntityPath) -> anyhow::Result<Vec<Chunk>> {
+        let Self {
+            points,
+            line_strings,
+            tracks,
+        } = self;
+
+        let mut chunks = Vec::new();
+
+        if !points.is_empty() {
+            chunks.push(
+                Chunk::builder(entity_path / EntityPathPart::from("points"))
+                    .with_archetype(
+                        RowId::new(),
+                        TimePoint::default(),
+                        &GeoPoints::from_lat_lon(points),
+                    )
+                    .build()?,
+            );
+        }
+
+        if !line_strings.is_empty() {
+            chunks.push(
+                Chunk::builder(entity_path / EntityPathPart::from("lines"))
+                    .with_archetype(
+                        RowId::new(),
+                        TimePoint::default(),
+                        &GeoLineStrings::from_lat_lon(line_strings),
+                    )
+                    .build()?,
+            );
+        }
+
+        for track in tracks {
+            let track_path = entity_path / EntityPathPart::from(track.name.as_str());
+
+            let path = track.segments.iter().map(|segment| {
+                segment
+                    .iter()
+                    .map(|&(position, _)| position)
+                    .collect::<Vec<_>>()
+            });
+            chunks.push(
+                Chunk::builder(track_path.clone())
+                    .with_archetype(
+                        RowId::new(),
+                        TimePoint::default(),
+                        &GeoLineStrings::from_lat_lon(path),
+                    )
+                    .build()?,
+            );
+
+            let (positions, times): (Vec<_>, Vec<_>) = track
+                .segments
+                .into_iter()
+                .flatten()
+                .filter_map(|(position, time)| Some((position, time?)))
+                .unzip();
+            if positions.is_empty() {
+                continue;
+            }
+
+            let timeline = Timeline::new_timestamp(GEO_TRACK_TIMELINE);
+            let time_column = TimeColumn::new(None, timeline, times.into());
+            chunks.push(Chunk::from_auto_row_ids(
+                ChunkId::new(),
+                track_path,
+                std::iter::once((*timeline.name(), time_column)).collect(),
+                GeoPoints::update_fields()
+                    .with_positions(positions)
+                    .columns_of_unit_batches()?
+                    .map(|column| (column.descriptor, column.list_array))
+                    .collect(),
+            )?);
+        }
+
+        Ok(chunks)
+    }
+}
+
+fn parse_timestamp(text: &str) -> anyhow::Result<i64> {
+    let timestamp: Timestamp = text
+        .trim()
+        .parse()
+        .with_context(|| format!("Invalid timestamp {text:?}"))?;
+    Ok(timestamp.nanos_since_epoch())
+}
+
+// --- GeoJSON
+
+fn geojson_array(value: &serde_json::Value) -> anyhow::Result<&Vec<serde_json::Value>> {
+    value
+        .as_array()
+        .with_context(|| format!("Expected a GeoJSON array, got {value}"))
+}
+
+/// GeoJSON positions are `[longitude, latitude, altitude?]`.
+fn geojson_position(value: &serde_json::Value) -> anyhow::Result<LatLon> {
+    match geojson_array(value)?.as_slice() {
+        [lon, lat, ..] => Ok((
+            lat.as_f64().context("Invalid latitude")?,
+            lon.as_f64().context("Invalid longitude")?,
+        )),
+        _ => anyhow::bail!("Invalid GeoJSON position {value}"),
+    }
+}
+
+fn geojson_positions(value: &serde_json::Value) -> anyhow::Result<Vec<LatLon>> {
+    geojson_array(value)?.iter().map(geojson_position).collect()
+}
+
+// --- GPX & KML
+
+fn elements<'a, 'input: 'a>(
+    node: roxmltree::Node<'a, 'input>,
+    name: &'a str,
+) -> impl Iterator<Item = roxmltree::Node<'a, 'input>> + 'a {
+    node.children()
+        .filter(move |child| child.has_tag_name(name))
+}
+
+fn child_text<'a>(node: roxmltree::Node<'a, '_>, name: &str) -> Option<&'a str> {
+    node.children()
+        .find(|child| child.has_tag_name(name))
+        .and_then(|child| child.text())
+}
+
+fn gpx_position(node: roxmltree::Node<'_, '_>) -> anyhow::Result<LatLon> {
+    let coordinate = |name: &str| -> anyhow::Result<f64> {
+        node.attribute(name)
+            .with_context(|| format!("<{}> without {name}", node.tag_name().name()))?
+            .parse()
+            .with_context(|| format!("Invalid {name} in <{}>", node.tag_name().name()))
+    };
+
+    Ok((coordinate("lat")?, coordinate("lon")?))
+}
+
+/// KML positions are `longitude,latitude[,altitude]`, or space separated within `<gx:coord>`.
+fn kml_position(text: &str, separator: char) -> anyhow::Result<LatLon> {
+    let mut values = text.trim().split(separator).map(str::parse::<f64>);
+    match (values.next(), values.next()) {
+        (Some(Ok(lon)), Some(Ok(lat))) => Ok((lat, lon)),
+        _ => anyhow::bail!("Invalid KML coordinates {text:?}"),
+    }
+}
+
+fn kml_coordinates(text: &str) -> anyhow::Result<Vec<LatLon>> {
+    text.split_whitespace()
+        .map(|tuple| kml_position(tuple, ','))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_geojson() {
+        let geo = GeoData::from_geojson(
+            r#"{
+                "type": "FeatureCollection",
+                "features": [
+                    { "type": "Feature", "geometry": { "type": "Point", "coordinates": [18.07, 59.31] } },
+                    { "type": "Feature", "geometry": null },
+                    { "type": "Feature", "geometry": {
+                        "type": "Polygon",
+                        "coordinates": [[[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 0.0]]]
+                    } }
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(geo.points, vec![(59.31, 18.07)]);
+        assert_eq!(
+            geo.line_strings,
+            vec![vec![(0.0, 0.0), (0.0, 1.0), (1.0, 1.0), (0.0, 0.0)]]
+        );
+    }
+
+    #[test]
+    fn test_gpx() {
+        let geo = GeoData::from_gpx(
+            r#"<?xml version="1.0"?>
+            <gpx version="1.1" xmlns="http://www.topografix.com/GPX/1/1">
+                <wpt lat="1.5" lon="2.5"><name>Camp</name></wpt>
+                <trk>
+                    <name>Morning run</name>
+                    <trkseg>
+                        <trkpt lat="10.0" lon="20.0"><time>2024-01-01T00:00:00Z</time></trkpt>
+                        <trkpt lat="10.5" lon="20.5"><time>2024-01-01T00:00:01Z</time></trkpt>
+                    </trkseg>
+                </trk>
+            </gpx>"#,
+        )
+        .unwrap();
+
+        assert_eq!(geo.points, vec![(1.5, 2.5)]);
+        assert_eq!(geo.tracks.len(), 1);
+
+        let track = &geo.tracks[0];
+        assert_eq!(track.name, "Morning run");
+        assert_eq!(
+            track.segments,
+            vec![vec![
+                ((10.0, 20.0), Some(1_704_067_200_000_000_000)),
+                ((10.5, 20.5), Some(1_704_067_201_000_000_000)),
+            ]]
+        );
+    }
+
+    #[test]
+    fn test_kml() {
+        let geo = GeoData::from_kml(
+            r#"<?xml version="1.0"?>
+            <kml xmlns="http://www.opengis.net/kml/2.2" xmlns:gx="http://www.google.com/kml/ext/2.2">
+                <Document>
+                    <Placemark><Point><coordinates>18.07,59.31,0</coordinates></Point></Placemark>
+                    <Placemark>
+                        <LineString><coordinates>
+                            0,0 1,0
+                            1,1
+                        </coordinates></LineString>
+                    </Placemark>
+                    <Placemark>
+                        <name>Drive</name>
+                        <gx:Track>
+                            <when>2024-01-01T00:00:00Z</when>
+                            <gx:coord>20.0 10.0 0</gx:coord>
+                        </gx:Track>
+                    </Placemark>
+                </Document>
+            </kml>"#,
+        )
+        .unwrap();
+
+        assert_eq!(geo.points, vec![(59.31, 18.07)]);
+        assert_eq!(
+            geo.line_strings,
+            vec![vec![(0.0, 0.0), (0.0, 1.0), (1.0, 1.0)]]
+        );
+        assert_eq!(geo.tracks[0].name, "Drive");
+        assert_eq!(
+            geo.tracks[0].segments,
+            vec![vec![((10.0, 20.0), Some(1_704_067_200_000_000_000))]]
+        );
+    }
+}
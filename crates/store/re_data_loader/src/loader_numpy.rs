@@ -0,0 +1,514 @@
+use std::io::Read as _;
+
+use anyhow::Context as _;
+use arrow::buffer::{Buffer, ScalarBuffer};
+
+use re_chunk::{Chunk, RowId};
+use re_log_types::{EntityPath, EntityPathPart, TimePoint};
+use re_types::{
+    archetypes::{DepthImage, Image, Tensor},
+    datatypes::{ChannelDatatype, ColorModel, TensorBuffer, TensorData},
+};
+
+use crate::{DataLoader, DataLoaderError, LoadedData};
+
+// ---
+
+/// Loads NumPy arrays from `.npy` and `.npz` files.
+///
+/// Arrays that look like images are logged as such:
+/// - 2D arrays of `u16`, or of any number type if their name contains `depth`, become [`DepthImage`]s.
+/// - 2D arrays of `u8`, and `height x width x channels` arrays with 1, 3 or 4 channels, become [`Image`]s.
+/// - 2D arrays of `bool` become black & white [`Image`]s.
+///
+/// All other arrays become [`Tensor`]s.
+///
+/// Each member of an `.npz` archive is logged to its own entity under the file's.
+pub struct NumpyLoader;
+
+impl DataLoader for NumpyLoader {
+    #[inline]
+    fn name(&self) -> String {
+        "rerun.data_loaders.NumPy".into()
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn load_from_path(
+        &self,
+        settings: &crate::DataLoaderSettings,
+        filepath: std::path::PathBuf,
+        tx: std::sync::mpsc::Sender<LoadedData>,
+    ) -> Result<(), DataLoaderError> {
+        if filepath.is_dir() || !is_numpy_file(&filepath) {
+            return Err(DataLoaderError::Incompatible(filepath.clone()));
+        }
+
+        re_tracing::profile_function!(filepath.display().to_string());
+
+        let contents = {
+            re_tracing::profile_scope!("fs::read");
+            std::fs::read(&filepath).with_context(|| format!("Failed to read file {filepath:?}"))?
+        };
+        let contents = std::borrow::Cow::Owned(contents);
+
+        self.load_from_file_contents(settings, filepath, contents, tx)
+    }
+
+    fn load_from_file_contents(
+        &self,
+        settings: &crate::DataLoaderSettings,
+        filepath: std::path::PathBuf,
+        contents: std::borrow::Cow<'_, [u8]>,
+        tx: std::sync::mpsc::Sender<LoadedData>,
+    ) -> Result<(), DataLoaderError> {
+        if !is_numpy_file(&filepath) {
+            return Err(DataLoaderError::Incompatible(filepath.clone()));
+        }
+
+        re_tracing::profile_function!(filepath.display().to_string());
+
+        re_log::debug!(?filepath, loader = self.name(), "Loading NumPy arrays…",);
+
+        let entity_path = EntityPath::from_file_path(&filepath);
+        let arrays = if crate::extension(&filepath) == "npz" {
+            read_npz(&contents)?
+                .into_iter()
+                .map(|(name, array)| {
+                    (
+                        &entity_path / EntityPathPart::from(name.as_str()),
+                        name,
+                        array,
+                    )
+                })
+                .collect()
+        } else {
+            let name = filepath
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().to_string())
+                .unwrap_or_default();
+            vec![(entity_path, name, NpyArray::from_bytes(&contents)?)]
+        };
+
+        let store_id = settings
+            .opened_store_id
+            .clone()
+            .unwrap_or_else(|| settings.store_id.clone());
+        for (entity_path, name, array) in arrays {
+            let chunk = match array.into_chunk(entity_path, &name) {
+                Ok(chunk) => chunk,
+                Err(err) => {
+                    re_log::warn!(?filepath, %err, "Failed to load array {name:?}");
+                    continue;
+                }
+            };
+
+            let data = LoadedData::Chunk(Self::name(&Self), store_id.clone(), chunk);
+            if tx.send(data).is_err() {
+                break; // The other end has decided to hang up, not our problem.
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn is_numpy_file(filepath: &std::path::Path) -> bool {
+    crate::SUPPORTED_NUMPY_EXTENSIONS.contains(&crate::extension(filepath).as_str())
+}
+
+/// Reads all `.npy` members of an `.npz` archive, named after their file stem.
+fn read_npz(contents: &[u8]) -> anyhow::Result<Vec<(String, NpyArray)>> {
+    re_tracing::profile_function!();
+
+    let mut archive =
+        zip::ZipArchive::new(std::io::Cursor::new(contents)).context("Invalid .npz archive")?;
+
+    let mut arrays = Vec::with_capacity(archive.len());
+    for index in 0..archive.len() {
+        let mut file = archive.by_index(index)?;
+        let Some(name) = file.name().strip_suffix(".npy").map(ToOwned::to_owned) else {
+            re_log::warn!("Ignoring {:?} in .npz archive", file.name());
+            continue;
+        };
+
+        let mut bytes = Vec::with_capacity(file.size() as usize);
+        file.read_to_end(&mut bytes)
+            .with_context(|| format!("Failed to decompress {name:?}"))?;
+
+        let array =
+            NpyArray::from_bytes(&bytes).with_context(|| format!("Invalid array {name:?}"))?;
+        arrays.push((name, array));
+    }
+
+    Ok(arrays)
+}
+
+// ---
+
+/// Element type of a NumPy array.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NpyDtype {
+    Bool,
+    U8,
+    U16,
+    U32,
+    U64,
+    I8,
+    I16,
+    I32,
+    I64,
+    F16,
+    F32,
+    F64,
+}
+
+impl NpyDtype {
+    /// Parses a `descr` such as `<f4`, returning the dtype and whether it is big-endian.
+    fn parse(descr: &str) -> anyhow::Result<(Self, bool)> {
+        let (is_big_endian, typestr) = match descr.as_bytes().first() {
+            Some(b'>') => (true, &descr[1..]),
+            Some(b'<' | b'|' | b'=') => (false, &descr[1..]),
+            _ => (false, descr),
+        };
+
+        let dtype = match typestr {
+            "b1" | "?" => Self::Bool,
+            "u1" => Self::U8,
+            "u2" => Self::U16,
+            "u4" => Self::U32,
+            "u8" => Self::U64,
+            "i1" => Self::I8,
+            "i2" => Self::I16,
+            "i4" => Self::I32,
+            "i8" => Self::I64,
+            "f2" => Self::F16,
+            "f4" => Self::F32,
+            "f8" => Self::F64,
+            _ => anyhow::bail!("Unsupported dtype {descr:?}"),
+        };
+
+        Ok((dtype, is_big_endian))
+    }
+
+    fn size(self) -> usize {
+        match self {
+            Self::Bool | Self::U8 | Self::I8 => 1,
+            Self::U16 | Self::I16 | Self::F16 => 2,
+            Self::U32 | Self::I32 | Self::F32 => 4,
+            Self::U64 | Self::I64 | Self::F64 => 8,
+        }
+    }
+
+    fn channel_datatype(self) -> ChannelDatatype {
+        match self {
+            Self::Bool | Self::U8 => ChannelDatatype::U8,
+            Self::U16 => ChannelDatatype::U16,
+            Self::U32 => ChannelDatatype::U32,
+            Self::U64 => ChannelDatatype::U64,
+            Self::I8 => ChannelDatatype::I8,
+            Self::I16 => ChannelDatatype::I16,
+            Self::I32 => ChannelDatatype::I32,
+            Self::I64 => ChannelDatatype::I64,
+            Self::F16 => ChannelDatatype::F16,
+            Self::F32 => ChannelDatatype::F32,
+            Self::F64 => ChannelDatatype::F64,
+        }
+    }
+}
+
+/// A NumPy array, with its elements in little-endian C order.
+#[derive(Debug)]
+struct NpyArray {
+    dtype: NpyDtype,
+    shape: Vec<u64>,
+    data: Vec<u8>,
+}
+
+impl NpyArray {
+    /// Parses the contents of a `.npy` file.
+    ///
+    /// See <https://numpy.org/doc/stable/reference/generated/numpy.lib.format.html>.
+    fn from_bytes(bytes: &[u8]) -> anyhow::Result<Self> {
+        const MAGIC: &[u8] = b"\x93NUMPY";
+
+        anyhow::ensure!(bytes.starts_with(MAGIC), "Not a .npy file");
+        let major_version = *bytes.get(MAGIC.len()).context("Truncated .npy header")?;
+
+        let (header_len, header_start) = if major_version == 1 {
+            let len = bytes.get(8..10).context("Truncated .npy header")?;
+            (u16::from_le_bytes([len[0], len[1]]) as usize, 10)
+        } else {
+            let len = bytes.get(8..12).context("Truncated .npy header")?;
+            (
+                u32::from_le_bytes([len[0], len[1], len[2], len[3]]) as usize,
+                12,
+            )
+        };
+
+        let data_start = header_start
+            .checked_add(header_len)
+            .context("Truncated .npy header")?;
+        let header = bytes
+            .get(header_start..data_start)
+            .context("Truncated .npy header")?;
+        let header = std::str::from_utf8(header).context("Invalid .npy header")?;
+
+        let descr = header_value(header, "descr").context("Missing descr in .npy header")?;
+        let descr = descr
+            .strip_prefix(['\'', '"'])
+            .and_then(|descr| descr.split(['\'', '"']).next())
+            .context("Structured arrays are not supported")?;
+        let (dtype, is_big_endian) = NpyDtype::parse(descr)?;
+
+        let fortran_order =
+            header_value(header, "fortran_order").is_some_and(|value| value.starts_with("True"));
+
+        let shape = header_value(header, "shape").context("Missing shape in .npy header")?;
+        let shape = shape
+            .strip_prefix('(')
+            .and_then(|shape| shape.split(')').next())
+            .context("Invalid shape in .npy header")?;
+        let shape = shape
+            .split(',')
+            .map(str::trim)
+            .filter(|dim| !dim.is_empty())
+            .map(|dim| dim.parse::<u64>())
+            .collect::<Result<Vec<_>, _>>()
+            .context("Invalid shape in .npy header")?;
+
+        // The shape comes straight from the file, so it may well be bogus.
+        let num_bytes = shape
+            .iter()
+            .try_fold(dtype.size() as u64, |num_bytes, &dim| {
+                num_bytes.checked_mul(dim)
+            })
+            .and_then(|num_bytes| usize::try_from(num_bytes).ok())
+            .context("Shape in .npy header is too large")?;
+        let data_end = data_start
+            .checked_add(num_bytes)
+            .context("Shape in .npy header is too large")?;
+        let mut data = bytes
+            .get(data_start..data_end)
+            .context("Truncated .npy data")?
+            .to_vec();
+
+        if is_big_endian {
+            for element in data.chunks_exact_mut(dtype.size()) {
+                element.reverse();
+            }
+        }
+        if fortran_order {
+            data = fortran_to_c_order(&data, &shape, dtype.size());
+        }
+
+        Ok(Self { dtype, shape, data })
+    }
+
+    fn into_chunk(self, entity_path: EntityPath, name: &str) -> anyhow::Result<Chunk> {
+        let Self { dtype, shape, data } = self;
+
+        let builder = Chunk::builder(entity_path);
+        let builder = match (shape.as_slice(), dtype) {
+            (&[height, width], _) if dtype == NpyDtype::U16 || is_depth_name(name) => {
+                let depth = DepthImage::from_data_type_and_bytes(
+                    data,
+                    [width as u32, height as u32],
+                    dtype.channel_datatype(),
+                );
+                builder.with_archetype(RowId::new(), TimePoint::default(), &depth)
+            }
+
+            (&[height, width], NpyDtype::U8) => {
+                let image = Image::from_l8(data, [width as u32, height as u32]);
+                builder.with_archetype(RowId::new(), TimePoint::default(), &image)
+            }
+
+            (&[height, width], NpyDtype::Bool) => {
+                // Masks: show `true` as white rather than as a barely visible 1.
+                let data = data
+                    .into_iter()
+                    .map(|value| if value == 0 { 0 } else { 255 })
+                    .collect::<Vec<u8>>();
+                let image = Image::from_l8(data, [width as u32, height as u32]);
+                builder.with_archetype(RowId::new(), TimePoint::default(), &image)
+            }
+
+            (&[height, width, channels @ (1 | 3 | 4)], _) if dtype != NpyDtype::Bool => {
+                let color_model = match channels {
+                    1 => ColorModel::L,
+                    3 => ColorModel::RGB,
+                    _ => ColorModel::RGBA,
+                };
+                let image = Image::from_color_model_and_bytes(
+                    data,
+                    [width as u32, height as u32],
+                    color_model,
+                    dtype.channel_datatype(),
+                );
+                builder.with_archetype(RowId::new(), TimePoint::default(), &image)
+            }
+
+            _ => {
+                let tensor = Tensor::new(TensorData::new(shape, tensor_buffer(dtype, &data)));
+                builder.with_archetype(RowId::new(), TimePoint::default(), &tensor)
+            }
+        };
+
+        Ok(builder.build()?)
+    }
+}
+
+fn is_depth_name(name: &str) -> bool {
+    name.to_ascii_lowercase().contains("depth")
+}
+
+/// Returns the raw value of `key` in the Python dict literal of a `.npy` header.
+fn header_value<'a>(header: &'a str, key: &str) -> Option<&'a str> {
+    let key_start = header
+        .find(&format!("'{key}'"))
+        .or_else(|| header.find(&format!("\"{key}\"")))?;
+    let rest = &header[key_start + key.len() + 2..];
+    let (_, value) = rest.split_once(':')?;
+    Some(value.trim_start())
+}
+
+/// Reorders the elements of a column-major (Fortran) array to row-major (C) order.
+fn fortran_to_c_order(data: &[u8], shape: &[u64], element_size: usize) -> Vec<u8> {
+    let shape: Vec<usize> = shape.iter().map(|&dim| dim as usize).collect();
+
+    let mut fortran_strides = Vec::with_capacity(shape.len());
+    let mut stride = 1;
+    for &dim in &shape {
+        fortran_strides.push(stride);
+        // Only overflows if another dimension is zero, in which case there's nothing to reorder.
+        stride = stride.saturating_mul(dim);
+    }
+
+    let mut reordered = Vec::with_capacity(data.len());
+    let num_elements = data.len() / element_size;
+    for c_index in 0..num_elements {
+        let mut remainder = c_index;
+        let mut fortran_index = 0;
+        for (&dim, &stride) in shape.iter().zip(&fortran_strides).rev() {
+            fortran_index += (remainder % dim) * stride;
+            remainder /= dim;
+        }
+
+        let offset = fortran_index * element_size;
+        reordered.extend_from_slice(&data[offset..offset + element_size]);
+    }
+
+    reordered
+}
+
+fn tensor_buffer(dtype: NpyDtype, data: &[u8]) -> TensorBuffer {
+    // Copying into an arrow `Buffer` takes care of the alignment.
+    let buffer = Buffer::from(data);
+    let len = data.len() / dtype.size();
+
+    match dtype {
+        NpyDtype::Bool | NpyDtype::U8 => TensorBuffer::U8(ScalarBuffer::new(buffer, 0, len)),
+        NpyDtype::U16 => TensorBuffer::U16(ScalarBuffer::new(buffer, 0, len)),
+        NpyDtype::U32 => TensorBuffer::U32(ScalarBuffer::new(buffer, 0, len)),
+        NpyDtype::U64 => TensorBuffer::U64(ScalarBuffer::new(buffer, 0, len)),
+        NpyDtype::I8 => TensorBuffer::I8(ScalarBuffer::new(buffer, 0, len)),
+        NpyDtype::I16 => TensorBuffer::I16(ScalarBuffer::new(buffer, 0, len)),
+        NpyDtype::I32 => TensorBuffer::I32(ScalarBuffer::new(buffer, 0, len)),
+        NpyDtype::I64 => TensorBuffer::I64(ScalarBuffer::new(buffer, 0, len)),
+        NpyDtype::F16 => TensorBuffer::F16(ScalarBuffer::new(buffer, 0, len)),
+        NpyDtype::F32 => TensorBuffer::F32(ScalarBuffer::new(buffer, 0, len)),
+        NpyDtype::F64 => TensorBuffer::F64(ScalarBuffer::new(buffer, 0, len)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use re_types::components::{ImageBuffer, ImageFormat};
+
+    use super::*;
+
+    fn npy(header: &str, data: &[u8]) -> Vec<u8> {
+        let mut bytes = b"\x93NUMPY\x01\x00".to_vec();
+        bytes.extend((header.len() as u16).to_le_bytes());
+        bytes.extend(header.as_bytes());
+        bytes.extend(data);
+        bytes
+    }
+
+    #[test]
+    fn test_read_npy() {
+        let array = NpyArray::from_bytes(&npy(
+            "{'descr': '>u2', 'fortran_order': False, 'shape': (3,), }\n",
+            &[0, 1, 0, 2, 1, 0],
+        ))
+        .unwrap();
+        assert_eq!(array.dtype, NpyDtype::U16);
+        assert_eq!(array.shape, vec![3]);
+        assert_eq!(array.data, vec![1, 0, 2, 0, 0, 1]);
+
+        // [[1, 2, 3], [4, 5, 6]] stored column by column.
+        let array = NpyArray::from_bytes(&npy(
+            "{'descr': '|u1', 'fortran_order': True, 'shape': (2, 3), }\n",
+            &[1, 4, 2, 5, 3, 6],
+        ))
+        .unwrap();
+        assert_eq!(array.shape, vec![2, 3]);
+        assert_eq!(array.data, vec![1, 2, 3, 4, 5, 6]);
+
+        assert!(
+            NpyArray::from_bytes(&npy(
+                "{'descr': [('x', '<f4')], 'fortran_order': False, 'shape': (1,), }\n",
+                &[0; 4],
+            ))
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_bool_mask_to_image() {
+        let array = NpyArray::from_bytes(&npy(
+            "{'descr': '|b1', 'fortran_order': False, 'shape': (2, 2), }\n",
+            &[1, 0, 0, 1],
+        ))
+        .unwrap();
+        let chunk = array.into_chunk("mask".into(), "mask").unwrap();
+
+        let buffer = chunk
+            .component_mono::<ImageBuffer>(&Image::descriptor_buffer(), 0)
+            .unwrap()
+            .unwrap();
+        assert_eq!(&buffer.0.0[..], &[255, 0, 0, 255]);
+
+        let format = chunk
+            .component_mono::<ImageFormat>(&Image::descriptor_format(), 0)
+            .unwrap()
+            .unwrap();
+        assert_eq!(format.color_model, Some(ColorModel::L));
+        assert_eq!(format.channel_datatype, Some(ChannelDatatype::U8));
+    }
+
+    #[test]
+    fn test_read_npy_bogus_shape() {
+        for shape in [
+            "(18446744073709551615, 2)",
+            "(4294967296, 4294967296)",
+            "(9223372036854775807,)",
+            "(3,)",
+        ] {
+            let header =
+                format!("{{'descr': '<f8', 'fortran_order': False, 'shape': {shape}, }}\n");
+            assert!(
+                NpyArray::from_bytes(&npy(&header, &[0; 16])).is_err(),
+                "{shape}"
+            );
+        }
+
+        // Empty arrays are fine, no matter how large the other dimensions are.
+        let array = NpyArray::from_bytes(&npy(
+            "{'descr': '<f8', 'fortran_order': True, 'shape': (0, 18446744073709551615, 2), }\n",
+            &[],
+        ))
+        .unwrap();
+        assert!(array.data.is_empty());
+    }
+}
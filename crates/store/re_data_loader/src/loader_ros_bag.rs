@@ -0,0 +1,621 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use anyhow::Context as _;
+
+use re_chunk::{ChunkBuilder, ChunkId, EntityPath, RowId, TimePoint, Timeline};
+use re_log_types::EntityPathPart;
+use re_types::{
+    AsComponents as _, SerializedComponentBatch,
+    archetypes::{DepthImage, EncodedImage, GeoPoints, Image, Points3D, Scalars, Transform3D},
+    datatypes::{ChannelDatatype, ColorModel, Quaternion},
+};
+
+use crate::{
+    DataLoader, DataLoaderError, LoadedData,
+    point_cloud::{PointField, load_point_records},
+    ros_bag::{Connection, Record, Value, read_bag},
+};
+
+// ---
+
+/// The timestamp timeline that messages are logged against, based on when they were recorded.
+pub const ROS_BAG_TIMELINE: &str = "bag_time";
+
+/// Topic of static transforms, which are logged as static data.
+const TF_STATIC_TOPIC: &str = "/tf_static";
+
+/// Chunks are sent once they reach either of these sizes.
+const MAX_ROWS_PER_CHUNK: usize = 1024;
+const MAX_BYTES_PER_CHUNK: usize = 8 * 1024 * 1024;
+
+/// Loads ROS 1 `.bag` files.
+///
+/// Messages of each topic are logged to the topic's entity, on the [`ROS_BAG_TIMELINE`]:
+/// - `sensor_msgs/Image` & `sensor_msgs/CompressedImage` as [`Image`]s, [`DepthImage`]s or
+///   [`EncodedImage`]s.
+/// - `sensor_msgs/PointCloud2` & `sensor_msgs/LaserScan` as [`Points3D`].
+/// - `sensor_msgs/Imu` as [`Scalars`], with the orientation as a [`Transform3D`].
+/// - `sensor_msgs/NavSatFix` as [`GeoPoints`].
+/// - `tf/tfMessage` & `tf2_msgs/TFMessage` as [`Transform3D`]s, under `tf/` in a hierarchy
+///   following the transform tree.
+///
+/// Topics of other types are ignored.
+pub struct RosBagLoader;
+
+impl DataLoader for RosBagLoader {
+    #[inline]
+    fn name(&self) -> String {
+        "rerun.data_loaders.RosBag".into()
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn load_from_path(
+        &self,
+        settings: &crate::DataLoaderSettings,
+        filepath: std::path::PathBuf,
+        tx: std::sync::mpsc::Sender<LoadedData>,
+    ) -> Result<(), DataLoaderError> {
+        if filepath.is_dir() || !is_ros_bag(&filepath) {
+            return Err(DataLoaderError::Incompatible(filepath.clone()));
+        }
+
+        re_tracing::profile_function!(filepath.display().to_string());
+
+        let contents = {
+            re_tracing::profile_scope!("fs::read");
+            std::fs::read(&filepath).with_context(|| format!("Failed to read file {filepath:?}"))?
+        };
+        let contents = std::borrow::Cow::Owned(contents);
+
+        self.load_from_file_contents(settings, filepath, contents, tx)
+    }
+
+    fn load_from_file_contents(
+        &self,
+        settings: &crate::DataLoaderSettings,
+        filepath: std::path::PathBuf,
+        contents: std::borrow::Cow<'_, [u8]>,
+        tx: std::sync::mpsc::Sender<LoadedData>,
+    ) -> Result<(), DataLoaderError> {
+        if !is_ros_bag(&filepath) {
+            return Err(DataLoaderError::Incompatible(filepath.clone()));
+        }
+
+        re_tracing::profile_function!(filepath.display().to_string());
+
+        re_log::debug!(?filepath, loader = self.name(), "Loading ROS bag…",);
+
+        let store_id = settings
+            .opened_store_id
+            .clone()
+            .unwrap_or_else(|| settings.store_id.clone());
+        let mut sender = ChunkSender {
+            loader_name: self.name(),
+            store_id,
+            tx,
+            pending: BTreeMap::new(),
+            has_hung_up: false,
+        };
+
+        let mut topics = BagTopics::new(EntityPath::from_file_path(&filepath));
+        read_bag(&contents, &mut |record| {
+            if !sender.has_hung_up {
+                topics.on_record(record, &mut sender);
+            }
+            Ok(())
+        })?;
+
+        sender.flush_all();
+
+        Ok(())
+    }
+}
+
+fn is_ros_bag(filepath: &std::path::Path) -> bool {
+    crate::SUPPORTED_ROS_BAG_EXTENSIONS.contains(&crate::extension(filepath).as_str())
+}
+
+// ---
+
+/// Rows of an entity that haven't been sent yet.
+#[derive(Default)]
+struct PendingChunk {
+    rows: Vec<(TimePoint, Vec<SerializedComponentBatch>)>,
+    num_bytes: usize,
+}
+
+/// Batches rows into chunks, per entity, and sends them as they fill up.
+struct ChunkSender {
+    loader_name: String,
+    store_id: re_log_types::StoreId,
+    tx: std::sync::mpsc::Sender<LoadedData>,
+
+    /// Static and temporal data can't share a chunk, hence the `bool`.
+    pending: BTreeMap<(EntityPath, bool), PendingChunk>,
+
+    has_hung_up: bool,
+}
+
+impl ChunkSender {
+    fn add_row(
+        &mut self,
+        entity_path: EntityPath,
+        timepoint: &TimePoint,
+        batches: Vec<SerializedComponentBatch>,
+        num_bytes: usize,
+    ) {
+        let key = (entity_path, timepoint.is_static());
+        let pending = self.pending.entry(key.clone()).or_default();
+        pending.rows.push((timepoint.clone(), batches));
+        pending.num_bytes += num_bytes;
+
+        if pending.rows.len() >= MAX_ROWS_PER_CHUNK || pending.num_bytes >= MAX_BYTES_PER_CHUNK {
+            if let Some(pending) = self.pending.remove(&key) {
+                self.send(key.0, pending);
+            }
+        }
+    }
+
+    fn flush_all(&mut self) {
+        for ((entity_path, _), pending) in std::mem::take(&mut self.pending) {
+            self.send(entity_path, pending);
+        }
+    }
+
+    fn send(&mut self, entity_path: EntityPath, pending: PendingChunk) {
+        let builder = pending.rows.into_iter().fold(
+            ChunkBuilder::new(ChunkId::new(), entity_path),
+            |builder, (timepoint, batches)| {
+                builder.with_serialized_batches(RowId::new(), timepoint, batches)
+            },
+        );
+
+        let chunk = match builder.build() {
+            Ok(chunk) => chunk,
+            Err(err) => {
+                re_log::warn_once!("Failed to build chunk: {err}");
+                return;
+            }
+        };
+
+        let data = LoadedData::Chunk(self.loader_name.clone(), self.store_id.clone(), chunk);
+        if self.tx.send(data).is_err() {
+            self.has_hung_up = true; // The other end has decided to hang up, not our problem.
+        }
+    }
+}
+
+// ---
+
+/// Keeps track of the bag's connections, and of everything needed to interpret their messages.
+struct BagTopics {
+    root: EntityPath,
+    connections: HashMap<u32, Connection>,
+
+    /// Connections we've already warned about.
+    ignored_connections: HashSet<u32>,
+
+    /// Where each `tf` frame seen so far is logged.
+    tf_paths: HashMap<String, EntityPath>,
+}
+
+impl BagTopics {
+    fn new(root: EntityPath) -> Self {
+        Self {
+            root,
+            connections: HashMap::new(),
+            ignored_connections: HashSet::new(),
+            tf_paths: HashMap::new(),
+        }
+    }
+
+    fn on_record(&mut self, record: Record<'_>, sender: &mut ChunkSender) {
+        match record {
+            Record::Connection(connection) => {
+                self.connections.insert(connection.id, connection);
+            }
+
+            Record::Message {
+                connection_id,
+                time,
+                data,
+            } => {
+                if self.ignored_connections.contains(&connection_id) {
+                    return;
+                }
+                if let Err(err) = self.on_message(connection_id, time, data, sender) {
+                    let topic = self
+                        .connections
+                        .get(&connection_id)
+                        .map(|connection| connection.topic.as_str())
+                        .unwrap_or_default();
+                    re_log::warn!("Ignoring topic {topic:?}: {err}");
+                    self.ignored_connections.insert(connection_id);
+                }
+            }
+        }
+    }
+
+    fn on_message(
+        &mut self,
+        connection_id: u32,
+        time: i64,
+        data: &[u8],
+        sender: &mut ChunkSender,
+    ) -> anyhow::Result<()> {
+        let connection = self
+            .connections
+            .get(&connection_id)
+            .context("Message of unknown connection")?;
+
+        let timepoint = if connection.topic == TF_STATIC_TOPIC {
+            TimePoint::default()
+        } else {
+            TimePoint::default().with(Timeline::new_timestamp(ROS_BAG_TIMELINE), time)
+        };
+        let topic_path = &self.root / &EntityPath::parse_forgiving(&connection.topic);
+
+        let message = connection.schema.decode(data)?;
+        let rows = match connection.message_type.as_str() {
+            "sensor_msgs/Image" => vec![(topic_path, image(&message)?)],
+            "sensor_msgs/CompressedImage" => vec![(topic_path, compressed_image(&message)?)],
+            "sensor_msgs/PointCloud2" => vec![(topic_path, point_cloud(&message)?)],
+            "sensor_msgs/LaserScan" => vec![(topic_path, laser_scan(&message)?)],
+            "sensor_msgs/Imu" => imu(&topic_path, &message)?,
+            "sensor_msgs/NavSatFix" => nav_sat_fix(&message)?
+                .map(|batches| vec![(topic_path, batches)])
+                .unwrap_or_default(),
+            "tf/tfMessage" | "tf2_msgs/TFMessage" => {
+                let tf_root = &self.root / EntityPathPart::from("tf");
+                tf_message(&tf_root, &mut self.tf_paths, &message)?
+            }
+            message_type => anyhow::bail!("unsupported message type {message_type:?}"),
+        };
+
+        for (entity_path, batches) in rows {
+            sender.add_row(entity_path, &timepoint, batches, data.len());
+        }
+
+        Ok(())
+    }
+}
+
+// --- Messages
+
+fn field<T>(value: Option<T>, name: &str) -> anyhow::Result<T> {
+    value.with_context(|| format!("Missing or invalid field {name:?}"))
+}
+
+/// `sensor_msgs/Image`, see <https://docs.ros.org/en/noetic/api/sensor_msgs/html/msg/Image.html>.
+fn image(message: &Value<'_>) -> anyhow::Result<Vec<SerializedComponentBatch>> {
+    enum Kind {
+        Color(ColorModel),
+        Depth { meter: f32 },
+    }
+
+    let width = field(message.u64("width"), "width")? as usize;
+    let height = field(message.u64("height"), "height")? as usize;
+    let step = field(message.u64("step"), "step")? as usize;
+    let encoding = field(message.str("encoding"), "encoding")?;
+    let is_big_endian = message.u64("is_bigendian").unwrap_or(0) != 0;
+    let data = field(message.bytes("data"), "data")?;
+
+    // See `sensor_msgs/image_encodings.h`.
+    let (kind, datatype, num_channels) = match encoding {
+        "rgb8" => (Kind::Color(ColorModel::RGB), ChannelDatatype::U8, 3),
+        "rgba8" => (Kind::Color(ColorModel::RGBA), ChannelDatatype::U8, 4),
+        "bgr8" | "8UC3" => (Kind::Color(ColorModel::BGR), ChannelDatatype::U8, 3),
+        "bgra8" | "8UC4" => (Kind::Color(ColorModel::BGRA), ChannelDatatype::U8, 4),
+        "rgb16" => (Kind::Color(ColorModel::RGB), ChannelDatatype::U16, 3),
+        "rgba16" => (Kind::Color(ColorModel::RGBA), ChannelDatatype::U16, 4),
+        "bgr16" | "16UC3" => (Kind::Color(ColorModel::BGR), ChannelDatatype::U16, 3),
+        "bgra16" | "16UC4" => (Kind::Color(ColorModel::BGRA), ChannelDatatype::U16, 4),
+        "mono8" | "8UC1" => (Kind::Color(ColorModel::L), ChannelDatatype::U8, 1),
+        "mono16" => (Kind::Color(ColorModel::L), ChannelDatatype::U16, 1),
+
+        // Depth cameras publish millimeters as `16UC1`, and meters as `32FC1`.
+        "16UC1" => (Kind::Depth { meter: 1000.0 }, ChannelDatatype::U16, 1),
+        "32FC1" => (Kind::Depth { meter: 1.0 }, ChannelDatatype::F32, 1),
+
+        _ => anyhow::bail!("unsupported image encoding {encoding:?}"),
+    };
+
+    let bytes_per_channel = datatype.bits() / 8;
+    let row_len = width * num_channels * bytes_per_channel;
+    anyhow::ensure!(
+        step >= row_len && data.len() >= step * height.saturating_sub(1) + row_len,
+        "Image data is too short for its size"
+    );
+
+    // Rows may be padded.
+    let mut bytes: Vec<u8> = (0..height)
+        .flat_map(|row| &data[row * step..row * step + row_len])
+        .copied()
+        .collect();
+    if is_big_endian && bytes_per_channel > 1 {
+        for value in bytes.chunks_exact_mut(bytes_per_channel) {
+            value.reverse();
+        }
+    }
+
+    let resolution = [width as u32, height as u32];
+    Ok(match kind {
+        Kind::Color(color_model) => {
+            Image::from_color_model_and_bytes(bytes, resolution, color_model, datatype)
+                .as_serialized_batches()
+        }
+        Kind::Depth { meter } => DepthImage::from_data_type_and_bytes(bytes, resolution, datatype)
+            .with_meter(meter)
+            .as_serialized_batches(),
+    })
+}
+
+/// `sensor_msgs/CompressedImage`, see <https://docs.ros.org/en/noetic/api/sensor_msgs/html/msg/CompressedImage.html>.
+fn compressed_image(message: &Value<'_>) -> anyhow::Result<Vec<SerializedComponentBatch>> {
+    let format = message.str("format").unwrap_or_default();
+    anyhow::ensure!(
+        !format.contains("compressedDepth"),
+        "compressedDepth images are not supported"
+    );
+
+    let data = field(message.bytes("data"), "data")?;
+    Ok(EncodedImage::from_file_contents(data.to_vec()).as_serialized_batches())
+}
+
+/// `sensor_msgs/PointCloud2`, see <https://docs.ros.org/en/noetic/api/sensor_msgs/html/msg/PointCloud2.html>.
+fn point_cloud(message: &Value<'_>) -> anyhow::Result<Vec<SerializedComponentBatch>> {
+    let fields = field(message.array("fields"), "fields")?
+        .iter()
+        .filter_map(|point_field| {
+            // See `sensor_msgs/PointField`.
+            let (kind, size) = match point_field.u64("datatype")? {
+                1 => (b'I', 1),
+                2 => (b'U', 1),
+                3 => (b'I', 2),
+                4 => (b'U', 2),
+                5 => (b'I', 4),
+                6 => (b'U', 4),
+                7 => (b'F', 4),
+                8 => (b'F', 8),
+                _ => return None,
+            };
+            Some(PointField {
+                name: point_field.str("name")?.to_owned(),
+                offset: point_field.u64("offset")? as usize,
+                kind,
+                size,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    load_point_records(
+        &fields,
+        field(message.bytes("data"), "data")?,
+        field(message.u64("width"), "width")? as usize,
+        field(message.u64("point_step"), "point_step")? as usize,
+        field(message.u64("row_step"), "row_step")? as usize,
+        message.u64("is_bigendian").unwrap_or(0) != 0,
+    )
+}
+
+/// `sensor_msgs/LaserScan`, see <https://docs.ros.org/en/noetic/api/sensor_msgs/html/msg/LaserScan.html>.
+fn laser_scan(message: &Value<'_>) -> anyhow::Result<Vec<SerializedComponentBatch>> {
+    let angle_min = field(message.f64("angle_min"), "angle_min")?;
+    let angle_increment = field(message.f64("angle_increment"), "angle_increment")?;
+    let range_min = message.f64("range_min").unwrap_or(0.0);
+    let range_max = message.f64("range_max").unwrap_or(f64::INFINITY);
+
+    // Out of range measurements are reported as NaNs, infinities, or values outside of the range.
+    let positions = field(message.array("ranges"), "ranges")?
+        .iter()
+        .enumerate()
+        .filter_map(|(i, range)| {
+            let range = range.as_f64()?;
+            (range.is_finite() && range_min <= range && range <= range_max).then(|| {
+                let angle = angle_min + i as f64 * angle_increment;
+                [
+                    (range * angle.cos()) as f32,
+                    (range * angle.sin()) as f32,
+                    0.0,
+                ]
+            })
+        })
+        .collect::<Vec<_>>();
+
+    Ok(Points3D::new(positions).as_serialized_batches())
+}
+
+/// `sensor_msgs/Imu`, see <https://docs.ros.org/en/noetic/api/sensor_msgs/html/msg/Imu.html>.
+fn imu(
+    topic_path: &EntityPath,
+    message: &Value<'_>,
+) -> anyhow::Result<Vec<(EntityPath, Vec<SerializedComponentBatch>)>> {
+    let mut rows = Vec::new();
+
+    for vector in ["angular_velocity", "linear_acceleration"] {
+        for axis in ["x", "y", "z"] {
+            let value = field(message.f64(&format!("{vector}.{axis}")), vector)?;
+            rows.push((
+                topic_path / EntityPathPart::from(vector) / EntityPathPart::from(axis),
+                Scalars::new([value]).as_serialized_batches(),
+            ));
+        }
+    }
+
+    let [x, y, z, w] = ["x", "y", "z", "w"].map(|axis| message.f64(&format!("orientation.{axis}")));
+    if let (Some(x), Some(y), Some(z), Some(w)) = (x, y, z, w) {
+        // An all-zero quaternion means that the orientation is unknown.
+        if [x, y, z, w].iter().any(|&v| v != 0.0) {
+            let rotation = Quaternion::from_xyzw([x as f32, y as f32, z as f32, w as f32]);
+            rows.push((
+                topic_path.clone(),
+                Transform3D::from_rotation(rotation).as_serialized_batches(),
+            ));
+        }
+    }
+
+    Ok(rows)
+}
+
+/// `sensor_msgs/NavSatFix`, see <https://docs.ros.org/en/noetic/api/sensor_msgs/html/msg/NavSatFix.html>.
+///
+/// Returns `None` when there is no fix.
+fn nav_sat_fix(message: &Value<'_>) -> anyhow::Result<Option<Vec<SerializedComponentBatch>>> {
+    const STATUS_NO_FIX: f64 = -1.0;
+
+    let latitude = field(message.f64("latitude"), "latitude")?;
+    let longitude = field(message.f64("longitude"), "longitude")?;
+    let has_fix = message
+        .f64("status.status")
+        .is_none_or(|status| status > STATUS_NO_FIX);
+
+    Ok((has_fix && latitude.is_finite() && longitude.is_finite())
+        .then(|| GeoPoints::from_lat_lon([(latitude, longitude)]).as_serialized_batches()))
+}
+
+/// `tf/tfMessage` & `tf2_msgs/TFMessage`, which hold a list of `geometry_msgs/TransformStamped`.
+///
+/// Each frame is logged under its ancestors, as far as they are known when it is first seen.
+/// It then stays there, even if more of its ancestors show up later (e.g. from `/tf_static`),
+/// so that all of its transforms end up on the same entity.
+fn tf_message(
+    tf_root: &EntityPath,
+    tf_paths: &mut HashMap<String, EntityPath>,
+    message: &Value<'_>,
+) -> anyhow::Result<Vec<(EntityPath, Vec<SerializedComponentBatch>)>> {
+    let mut rows = Vec::new();
+    for transform in field(message.array("transforms"), "transforms")? {
+        let frame = |name| {
+            transform
+                .str(name)
+                .map(|frame| frame.trim_start_matches('/'))
+        };
+        let parent = field(frame("header.frame_id"), "header.frame_id")?;
+        let child = field(frame("child_frame_id"), "child_frame_id")?;
+
+        let parent_path = tf_paths
+            .entry(parent.to_owned())
+            .or_insert_with(|| tf_root / EntityPathPart::from(parent))
+            .clone();
+        let entity_path = tf_paths
+            .entry(child.to_owned())
+            .or_insert_with(|| &parent_path / EntityPathPart::from(child))
+            .clone();
+
+        let [tx, ty, tz] = ["x", "y", "z"].map(|axis| {
+            transform
+                .f64(&format!("transform.translation.{axis}"))
+                .unwrap_or(0.0)
+        });
+        let [qx, qy, qz, qw] = ["x", "y", "z", "w"].map(|axis| {
+            transform
+                .f64(&format!("transform.rotation.{axis}"))
+                .unwrap_or(0.0)
+        });
+        let transform = Transform3D::from_translation_rotation(
+            [tx as f32, ty as f32, tz as f32],
+            Quaternion::from_xyzw([qx as f32, qy as f32, qz as f32, qw as f32]),
+        );
+
+        rows.push((entity_path, transform.as_serialized_batches()));
+    }
+
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TRANSFORM_STAMPED: &str = "\
+geometry_msgs/TransformStamped[] transforms
+================================================================================
+MSG: geometry_msgs/TransformStamped
+Header header
+string child_frame_id
+Transform transform
+================================================================================
+MSG: std_msgs/Header
+uint32 seq
+time stamp
+string frame_id
+================================================================================
+MSG: geometry_msgs/Transform
+Vector3 translation
+Quaternion rotation
+================================================================================
+MSG: geometry_msgs/Vector3
+float64 x
+float64 y
+float64 z
+================================================================================
+MSG: geometry_msgs/Quaternion
+float64 x
+float64 y
+float64 z
+float64 w
+";
+
+    fn transform_stamped(parent: &str, child: &str) -> Vec<u8> {
+        let mut data = Vec::new();
+        data.extend([0; 12]); // seq & stamp
+        for frame in [parent, child] {
+            data.extend((frame.len() as u32).to_le_bytes());
+            data.extend(frame.as_bytes());
+        }
+        for v in [1.0f64, 2.0, 3.0, 0.0, 0.0, 0.0, 1.0] {
+            data.extend(v.to_le_bytes());
+        }
+        data
+    }
+
+    #[test]
+    fn test_tf_hierarchy() {
+        let schema =
+            crate::ros_bag::MessageSchema::parse("tf2_msgs/TFMessage", TRANSFORM_STAMPED).unwrap();
+
+        let mut data = 2u32.to_le_bytes().to_vec();
+        data.extend(transform_stamped("/map", "odom"));
+        data.extend(transform_stamped("odom", "base_link"));
+
+        let message = schema.decode(&data).unwrap();
+        let mut tf_paths = HashMap::new();
+        let rows = tf_message(&EntityPath::from("tf"), &mut tf_paths, &message).unwrap();
+
+        let entity_paths: Vec<_> = rows.iter().map(|(path, _)| path.to_string()).collect();
+        assert_eq!(entity_paths, vec!["/tf/map/odom", "/tf/map/odom/base_link"]);
+    }
+
+    #[test]
+    fn test_tf_late_parent() {
+        let schema =
+            crate::ros_bag::MessageSchema::parse("tf2_msgs/TFMessage", TRANSFORM_STAMPED).unwrap();
+        let mut tf_paths = HashMap::new();
+        let mut entity_paths = |transforms: &[(&str, &str)]| {
+            let mut data = (transforms.len() as u32).to_le_bytes().to_vec();
+            for (parent, child) in transforms {
+                data.extend(transform_stamped(parent, child));
+            }
+            let message = schema.decode(&data).unwrap();
+            tf_message(&EntityPath::from("tf"), &mut tf_paths, &message)
+                .unwrap()
+                .into_iter()
+                .map(|(path, _)| path.to_string())
+                .collect::<Vec<_>>()
+        };
+
+        // Dynamic `/tf` comes in before the static `/tf_static` that knows about `map`:
+        assert_eq!(
+            entity_paths(&[("odom", "base_link")]),
+            ["/tf/odom/base_link"]
+        );
+        assert_eq!(entity_paths(&[("map", "odom")]), ["/tf/odom"]);
+        assert_eq!(
+            entity_paths(&[("odom", "base_link"), ("base_link", "laser")]),
+            ["/tf/odom/base_link", "/tf/odom/base_link/laser"]
+        );
+
+        // Frames that are only seen later still go under their (already placed) parents.
+        assert_eq!(entity_paths(&[("map", "gps")]), ["/tf/map/gps"]);
+    }
+}
@@ -0,0 +1,576 @@
+use std::collections::BTreeMap;
+
+use arrow::{
+    array::{Array, ArrayRef, AsArray as _, RecordBatch},
+    compute::cast,
+    datatypes::{DataType, Float64Type, Int64Type, TimeUnit},
+};
+
+use re_chunk::{Chunk, ChunkId, EntityPath, TimeColumn, Timeline};
+use re_log_types::EntityPathPart;
+use re_types::{
+    SerializedComponentColumn,
+    archetypes::{Points3D, Scalars, TextLog, Transform3D},
+};
+
+use crate::{DataLoader, DataLoaderError, LoadedData};
+
+// ---
+
+/// How the [`TabularLoader`] interprets tables.
+#[derive(Debug, Clone, Default)]
+pub struct TabularLoaderSettings {
+    /// Name of the column to use as time.
+    ///
+    /// If unset, the first column named like one of [`TIME_COLUMN_CANDIDATES`] is used, then
+    /// the first timestamp column. Tables without any of those are logged against their row number.
+    pub time_column: Option<String>,
+
+    /// Whether a floating point time column holds seconds since the Unix epoch (`true`),
+    /// or durations in seconds (`false`).
+    ///
+    /// If unset, it is a timestamp if all its values are recent enough to be one,
+    /// see [`MIN_TIMESTAMP_SECS`].
+    pub float_time_is_timestamp: Option<bool>,
+}
+
+/// Column names that are picked as the time column when none is configured, in order of preference.
+///
+/// Matching is case-insensitive.
+pub const TIME_COLUMN_CANDIDATES: &[&str] = &[
+    "timestamp",
+    "time",
+    "log_time",
+    "t",
+    "frame",
+    "frame_index",
+    "frame_nr",
+    "step",
+    "tick",
+];
+
+/// Sequence timeline used for tables that have no time column.
+const ROW_NUMBER_TIMELINE: &str = "row_nr";
+
+/// Floating point time columns are in seconds: if all their values are above this, they are
+/// taken as seconds since the Unix epoch rather than durations (about 3 years).
+pub const MIN_TIMESTAMP_SECS: f64 = 1e8;
+
+/// Loads tabular data from `.csv` and `.parquet` files.
+///
+/// Every row is logged at the time found in its time column (see [`TabularLoaderSettings`]):
+/// - Column groups such as `pos_x, pos_y, pos_z` become [`Points3D`], or
+///   [`Transform3D`] translations if their name contains `translation`.
+/// - Other numeric and boolean columns become [`Scalars`].
+/// - String columns become [`TextLog`]s.
+///
+/// Each column is logged to its own entity under the file's, as a single columnar chunk.
+pub struct TabularLoader;
+
+impl DataLoader for TabularLoader {
+    #[inline]
+    fn name(&self) -> String {
+        "rerun.data_loaders.Tabular".into()
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn load_from_path(
+        &self,
+        settings: &crate::DataLoaderSettings,
+        filepath: std::path::PathBuf,
+        tx: std::sync::mpsc::Sender<LoadedData>,
+    ) -> Result<(), DataLoaderError> {
+        use anyhow::Context as _;
+
+        if filepath.is_dir() || !is_tabular_file(&filepath) {
+            return Err(DataLoaderError::Incompatible(filepath.clone()));
+        }
+
+        re_tracing::profile_function!(filepath.display().to_string());
+
+        let contents = {
+            re_tracing::profile_scope!("fs::read");
+            std::fs::read(&filepath).with_context(|| format!("Failed to read file {filepath:?}"))?
+        };
+        let contents = std::borrow::Cow::Owned(contents);
+
+        self.load_from_file_contents(settings, filepath, contents, tx)
+    }
+
+    fn load_from_file_contents(
+        &self,
+        settings: &crate::DataLoaderSettings,
+        filepath: std::path::PathBuf,
+        contents: std::borrow::Cow<'_, [u8]>,
+        tx: std::sync::mpsc::Sender<LoadedData>,
+    ) -> Result<(), DataLoaderError> {
+        if !is_tabular_file(&filepath) {
+            return Err(DataLoaderError::Incompatible(filepath.clone()));
+        }
+
+        re_tracing::profile_function!(filepath.display().to_string());
+
+        re_log::debug!(?filepath, loader = self.name(), "Loading table…",);
+
+        let table = match crate::extension(&filepath).as_str() {
+            "csv" => read_csv(&contents)?,
+            _ => read_parquet(contents.into_owned())?,
+        };
+
+        let entity_path = EntityPath::from_file_path(&filepath);
+        let chunks = table_to_chunks(&settings.tabular, &entity_path, &table)?;
+
+        let store_id = settings
+            .opened_store_id
+            .clone()
+            .unwrap_or_else(|| settings.store_id.clone());
+        for chunk in chunks {
+            let data = LoadedData::Chunk(Self::name(&Self), store_id.clone(), chunk);
+            if tx.send(data).is_err() {
+                break; // The other end has decided to hang up, not our problem.
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn is_tabular_file(filepath: &std::path::Path) -> bool {
+    crate::SUPPORTED_TABULAR_EXTENSIONS.contains(&crate::extension(filepath).as_str())
+}
+
+fn read_csv(contents: &[u8]) -> anyhow::Result<RecordBatch> {
+    re_tracing::profile_function!();
+
+    use arrow::csv::{ReaderBuilder, reader::Format};
+
+    let format = Format::default().with_header(true);
+    let (schema, _num_records) =
+        format.infer_schema(std::io::Cursor::new(contents), None /* all records */)?;
+    let schema = std::sync::Arc::new(schema);
+
+    let batches = ReaderBuilder::new(schema.clone())
+        .with_format(format)
+        .build(std::io::Cursor::new(contents))?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(arrow::compute::concat_batches(&schema, &batches)?)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn read_parquet(contents: Vec<u8>) -> anyhow::Result<RecordBatch> {
+    re_tracing::profile_function!();
+
+    use anyhow::Context as _;
+    use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+
+    let builder = ParquetRecordBatchReaderBuilder::try_new(bytes::Bytes::from(contents))
+        .context("Failed to read Parquet metadata")?;
+    let schema = builder.schema().clone();
+    let batches = builder
+        .build()
+        .context("Failed to read Parquet data")?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(arrow::compute::concat_batches(&schema, &batches)?)
+}
+
+#[cfg(target_arch = "wasm32")]
+fn read_parquet(_contents: Vec<u8>) -> anyhow::Result<RecordBatch> {
+    anyhow::bail!("Parquet files are not yet supported on the web")
+}
+
+// ---
+
+/// The time of every row of a table.
+struct RowTimes {
+    timeline: Timeline,
+
+    /// `None` for rows without a time, which are skipped.
+    times: Vec<Option<i64>>,
+}
+
+impl RowTimes {
+    fn from_table(
+        settings: &TabularLoaderSettings,
+        table: &RecordBatch,
+    ) -> anyhow::Result<(Option<usize>, Self)> {
+        let schema = table.schema();
+
+        let time_column_index = if let Some(name) = &settings.time_column {
+            Some(
+                schema
+                    .index_of(name)
+                    .map_err(|_err| anyhow::anyhow!("No time column named {name:?}"))?,
+            )
+        } else {
+            TIME_COLUMN_CANDIDATES
+                .iter()
+                .find_map(|candidate| {
+                    schema
+                        .fields()
+                        .iter()
+                        .position(|field| field.name().eq_ignore_ascii_case(candidate))
+                })
+                .or_else(|| {
+                    schema
+                        .fields()
+                        .iter()
+                        .position(|field| matches!(field.data_type(), DataType::Timestamp(..)))
+                })
+        };
+
+        let Some(index) = time_column_index else {
+            let times = (0..table.num_rows() as i64).map(Some).collect();
+            return Ok((
+                None,
+                Self {
+                    timeline: Timeline::new_sequence(ROW_NUMBER_TIMELINE),
+                    times,
+                },
+            ));
+        };
+
+        let name = schema.field(index).name();
+        let this = Self::from_column(settings, name, table.column(index))
+            .map_err(|err| anyhow::anyhow!("Invalid time column {name:?}: {err}"))?;
+
+        Ok((Some(index), this))
+    }
+
+    fn from_column(
+        settings: &TabularLoaderSettings,
+        name: &str,
+        array: &ArrayRef,
+    ) -> anyhow::Result<Self> {
+        let (timeline, nanos_or_seq) = match array.data_type() {
+            DataType::Timestamp(_, tz) => {
+                // Keep the timezone, otherwise the cast shifts values to wall-clock time.
+                let nanos = cast(
+                    array,
+                    &DataType::Timestamp(TimeUnit::Nanosecond, tz.clone()),
+                )?;
+                (
+                    Timeline::new_timestamp(name),
+                    cast(&nanos, &DataType::Int64)?,
+                )
+            }
+
+            DataType::Date32 | DataType::Date64 => {
+                let nanos = cast(array, &DataType::Timestamp(TimeUnit::Nanosecond, None))?;
+                (
+                    Timeline::new_timestamp(name),
+                    cast(&nanos, &DataType::Int64)?,
+                )
+            }
+
+            DataType::Duration(_) => {
+                let nanos = cast(array, &DataType::Duration(TimeUnit::Nanosecond))?;
+                (
+                    Timeline::new_duration(name),
+                    cast(&nanos, &DataType::Int64)?,
+                )
+            }
+
+            data_type if data_type.is_integer() => {
+                (Timeline::new_sequence(name), cast(array, &DataType::Int64)?)
+            }
+
+            data_type if data_type.is_floating() => {
+                let secs = cast(array, &DataType::Float64)?;
+                let secs = secs.as_primitive::<Float64Type>();
+
+                let is_timestamp = settings
+                    .float_time_is_timestamp
+                    .unwrap_or_else(|| looks_like_timestamps(name, secs));
+                let timeline = if is_timestamp {
+                    Timeline::new_timestamp(name)
+                } else {
+                    Timeline::new_duration(name)
+                };
+
+                let times = secs
+                    .iter()
+                    .map(|secs| secs.map(|secs| (secs * 1e9).round() as i64))
+                    .collect();
+                return Ok(Self { timeline, times });
+            }
+
+            data_type => {
+                anyhow::bail!("unsupported datatype {data_type}");
+            }
+        };
+
+        let times = nanos_or_seq.as_primitive::<Int64Type>().iter().collect();
+        Ok(Self { timeline, times })
+    }
+
+    /// Rows that have a time and a value in all `columns`.
+    fn valid_rows(&self, columns: &[&dyn Array]) -> Vec<usize> {
+        (0..self.times.len())
+            .filter(|&row| {
+                self.times[row].is_some() && columns.iter().all(|column| column.is_valid(row))
+            })
+            .collect()
+    }
+
+    /// Builds a chunk out of `columns`, which hold one value for each of `rows`.
+    fn chunk(
+        &self,
+        entity_path: EntityPath,
+        rows: &[usize],
+        columns: impl Iterator<Item = SerializedComponentColumn>,
+    ) -> anyhow::Result<Chunk> {
+        let times: Vec<i64> = rows.iter().filter_map(|&row| self.times[row]).collect();
+        let time_column = TimeColumn::new(None, self.timeline, times.into());
+
+        Ok(Chunk::from_auto_row_ids(
+            ChunkId::new(),
+            entity_path,
+            std::iter::once((*self.timeline.name(), time_column)).collect(),
+            columns
+                .map(|column| (column.descriptor, column.list_array))
+                .collect(),
+        )?)
+    }
+}
+
+/// Whether a floating point time column holds seconds since the Unix epoch rather than durations.
+///
+/// Considers the whole column rather than only its first value, and only says yes if all values
+/// are recent enough to be timestamps.
+fn looks_like_timestamps(name: &str, secs: &arrow::array::Float64Array) -> bool {
+    let Some((min, max)) = secs
+        .iter()
+        .flatten()
+        .fold(None, |range: Option<(f64, f64)>, secs| {
+            Some(range.map_or((secs, secs), |(min, max)| (min.min(secs), max.max(secs))))
+        })
+    else {
+        return false;
+    };
+
+    if min < MIN_TIMESTAMP_SECS && MIN_TIMESTAMP_SECS <= max {
+        re_log::warn_once!(
+            "Time column {name:?} ranges from {min} to {max}, which looks like neither timestamps \
+             nor durations. Treating it as durations."
+        );
+    }
+
+    MIN_TIMESTAMP_SECS <= min
+}
+
+/// Splits a column name like `pos_x` into its group (`pos`) and axis (`0`).
+///
+/// The axis must be separated from the group by one of `_`, `.`, `-`, `/` or a space, unless
+/// the name is just the axis.
+fn split_axis(name: &str) -> Option<(&str, usize)> {
+    let (last_index, last) = name.char_indices().next_back()?;
+    let axis = match last.to_ascii_lowercase() {
+        'x' => 0,
+        'y' => 1,
+        'z' => 2,
+        _ => return None,
+    };
+
+    let group = &name[..last_index];
+    if group.is_empty() {
+        return Some((group, axis));
+    }
+
+    let group = group.strip_suffix(['_', '.', '-', '/', ' '])?;
+    (!group.is_empty()).then_some((group, axis))
+}
+
+fn is_numeric(data_type: &DataType) -> bool {
+    data_type.is_numeric() || *data_type == DataType::Boolean
+}
+
+fn is_string(data_type: &DataType) -> bool {
+    matches!(
+        data_type,
+        DataType::Utf8 | DataType::LargeUtf8 | DataType::Utf8View
+    )
+}
+
+fn table_to_chunks(
+    settings: &TabularLoaderSettings,
+    entity_path: &EntityPath,
+    table: &RecordBatch,
+) -> anyhow::Result<Vec<Chunk>> {
+    re_tracing::profile_function!();
+
+    let schema = table.schema();
+    let (time_column_index, row_times) = RowTimes::from_table(settings, table)?;
+
+    let as_f64 = |index: usize| -> anyhow::Result<ArrayRef> {
+        Ok(cast(table.column(index), &DataType::Float64)?)
+    };
+
+    // Group `x, y, z` columns first, so that they aren't logged as individual scalars.
+    let mut axis_groups: BTreeMap<&str, [Option<usize>; 3]> = BTreeMap::new();
+    for (index, field) in schema.fields().iter().enumerate() {
+        if Some(index) == time_column_index || !is_numeric(field.data_type()) {
+            continue;
+        }
+        if let Some((group, axis)) = split_axis(field.name()) {
+            axis_groups.entry(group).or_default()[axis] = Some(index);
+        }
+    }
+    let axis_groups: Vec<(&str, [usize; 3])> = axis_groups
+        .into_iter()
+        .filter_map(|(group, [x, y, z])| Some((group, [x?, y?, z?])))
+        .collect();
+
+    let mut chunks = Vec::new();
+
+    for (group, axes) in &axis_groups {
+        let [x, y, z] = axes.map(as_f64);
+        let (x, y, z) = (x?, y?, z?);
+        let rows = row_times.valid_rows(&[&x, &y, &z]);
+        if rows.is_empty() {
+            continue;
+        }
+
+        let [x, y, z] = [&x, &y, &z].map(|array| array.as_primitive::<Float64Type>());
+        let xyz = rows.iter().map(|&row| {
+            [
+                x.value(row) as f32,
+                y.value(row) as f32,
+                z.value(row) as f32,
+            ]
+        });
+
+        let group_path = if group.is_empty() {
+            entity_path.clone()
+        } else {
+            entity_path / EntityPathPart::from(*group)
+        };
+
+        let columns: Vec<_> = if group.to_ascii_lowercase().contains("translation") {
+            Transform3D::update_fields()
+                .with_many_translation(xyz)
+                .columns_of_unit_batches()?
+                .collect()
+        } else {
+            Points3D::new(xyz).columns_of_unit_batches()?.collect()
+        };
+        chunks.push(row_times.chunk(group_path, &rows, columns.into_iter())?);
+    }
+
+    for (index, field) in schema.fields().iter().enumerate() {
+        let is_grouped = axis_groups.iter().any(|(_, axes)| axes.contains(&index));
+        if Some(index) == time_column_index || is_grouped {
+            continue;
+        }
+
+        let column_path = entity_path / EntityPathPart::from(field.name().as_str());
+
+        let (rows, columns): (Vec<usize>, Vec<_>) = if is_numeric(field.data_type()) {
+            let values = as_f64(index)?;
+            let rows = row_times.valid_rows(&[&values]);
+            let values = values.as_primitive::<Float64Type>();
+            let columns = Scalars::new(rows.iter().map(|&row| values.value(row)))
+                .columns_of_unit_batches()?
+                .collect();
+            (rows, columns)
+        } else if is_string(field.data_type()) {
+            let values = cast(table.column(index), &DataType::Utf8)?;
+            let rows = row_times.valid_rows(&[&values]);
+            let values = values.as_string::<i32>();
+            let columns = TextLog::update_fields()
+                .with_many_text(rows.iter().map(|&row| values.value(row)))
+                .columns_of_unit_batches()?
+                .collect();
+            (rows, columns)
+        } else {
+            re_log::warn_once!(
+                "Ignoring column {:?} of unsupported datatype {}",
+                field.name(),
+                field.data_type()
+            );
+            continue;
+        };
+
+        if !rows.is_empty() {
+            chunks.push(row_times.chunk(column_path, &rows, columns.into_iter())?);
+        }
+    }
+
+    Ok(chunks)
+}
+
+#[cfg(test)]
+mod tests {
+    use re_log_types::TimeType;
+
+    use super::*;
+
+    #[test]
+    fn test_split_axis() {
+        assert_eq!(split_axis("pos_x"), Some(("pos", 0)));
+        assert_eq!(split_axis("accel.Y"), Some(("accel", 1)));
+        assert_eq!(split_axis("z"), Some(("", 2)));
+        assert_eq!(split_axis("index"), None);
+        assert_eq!(split_axis("_x"), None);
+        assert_eq!(split_axis("speed"), None);
+    }
+
+    #[test]
+    fn test_csv_to_chunks() {
+        let csv = "time,temperature,pos_x,pos_y,pos_z,status\n\
+                   0.0,21.5,1,2,3,ok\n\
+                   0.5,,1,2,4,\n\
+                   1.0,22.0,1,2,5,overheating\n";
+
+        let table = read_csv(csv.as_bytes()).unwrap();
+        let entity_path = EntityPath::from("table.csv");
+        let chunks =
+            table_to_chunks(&TabularLoaderSettings::default(), &entity_path, &table).unwrap();
+
+        let num_rows: BTreeMap<String, usize> = chunks
+            .iter()
+            .map(|chunk| (chunk.entity_path().to_string(), chunk.num_rows()))
+            .collect();
+        assert_eq!(
+            num_rows,
+            [
+                ("/table.csv/pos".to_owned(), 3),
+                ("/table.csv/status".to_owned(), 2),
+                ("/table.csv/temperature".to_owned(), 2),
+            ]
+            .into_iter()
+            .collect()
+        );
+
+        for chunk in &chunks {
+            assert!(chunk.timelines().contains_key(&"time".into()));
+        }
+    }
+
+    #[test]
+    fn test_float_time_type() {
+        let time_type = |csv: &str, settings: &TabularLoaderSettings| {
+            let table = read_csv(csv.as_bytes()).unwrap();
+            let (_, row_times) = RowTimes::from_table(settings, &table).unwrap();
+            row_times.timeline.typ()
+        };
+        let auto = TabularLoaderSettings::default();
+
+        let timestamps = "time,value\n1700000000.0,1\n1700000000.5,2\n";
+        assert_eq!(time_type(timestamps, &auto), TimeType::TimestampNs);
+
+        // Only the first value is large, e.g. a sensor that reports garbage on startup.
+        let durations = "time,value\n1700000000.0,1\n0.5,2\n1.0,3\n";
+        assert_eq!(time_type(durations, &auto), TimeType::DurationNs);
+
+        let durations = "time,value\n0.0,1\n0.5,2\n";
+        assert_eq!(time_type(durations, &auto), TimeType::DurationNs);
+
+        let forced = TabularLoaderSettings {
+            float_time_is_timestamp: Some(true),
+            ..Default::default()
+        };
+        assert_eq!(time_type(durations, &forced), TimeType::TimestampNs);
+    }
+}
@@ -0,0 +1,818 @@
+//! Readers for lidar & survey point cloud formats: `.pcd`, `.las`, `.laz` and `.e57`, as well as
+//! raw point records such as those of ROS `PointCloud2` messages.
+//!
+//! `.ply` files are handled by [`re_types::archetypes::Points3D::from_file_contents`].
+
+use std::sync::Arc;
+
+use anyhow::Context as _;
+use arrow::array::{ArrayRef, Float64Array};
+
+use re_types::{
+    AnyValues, AsComponents as _, SerializedComponentBatch,
+    archetypes::{Points3D, Transform3D},
+    components::Color,
+};
+
+/// Archetype name of the per-point fields that have no dedicated Rerun component.
+const EXTRA_FIELDS_ARCHETYPE: &str = "rerun.data_loaders.PointCloudFields";
+
+/// Coordinates further than this from the origin are re-centered, since positions are logged
+/// as `f32`s which can't represent e.g. UTM coordinates precisely.
+const MAX_COORDINATE_BEFORE_RECENTERING: f64 = 1e5;
+
+/// Reads a point cloud in any of the formats of this module, based on its `extension`.
+pub fn load(extension: &str, contents: &[u8]) -> anyhow::Result<Vec<SerializedComponentBatch>> {
+    let point_cloud = match extension {
+        "pcd" => read_pcd(contents)?,
+        "las" | "laz" => read_las(contents)?,
+        "e57" => read_e57(contents)?,
+        _ => anyhow::bail!("Unsupported point cloud format: {extension:?}"),
+    };
+    Ok(point_cloud.into_batches())
+}
+
+/// A field of fixed-size point records, see [`load_point_records`].
+pub struct PointField {
+    pub name: String,
+
+    /// Offset of the field within a point record, in bytes.
+    pub offset: usize,
+
+    /// `I` (signed), `U` (unsigned) or `F` (floating point).
+    pub kind: u8,
+
+    /// Size of the field, in bytes.
+    pub size: usize,
+}
+
+/// Reads a point cloud stored as rows of `row_step` bytes, each holding `width` point records
+/// of `point_step` bytes.
+///
+/// This is the layout of e.g. ROS `sensor_msgs/PointCloud2` messages.
+pub fn load_point_records(
+    fields: &[PointField],
+    data: &[u8],
+    width: usize,
+    point_step: usize,
+    row_step: usize,
+    is_big_endian: bool,
+) -> anyhow::Result<Vec<SerializedComponentBatch>> {
+    re_tracing::profile_function!();
+
+    anyhow::ensure!(
+        point_step > 0 && row_step >= point_step,
+        "Invalid point cloud layout"
+    );
+
+    let fields: Vec<(usize, PcdField)> = fields
+        .iter()
+        .map(|field| {
+            anyhow::ensure!(
+                is_valid_field_size(field.size)
+                    && field
+                        .offset
+                        .checked_add(field.size)
+                        .is_some_and(|end| end <= point_step),
+                "Invalid point field {:?}",
+                field.name
+            );
+            Ok((
+                field.offset,
+                PcdField {
+                    name: field.name.clone(),
+                    size: field.size,
+                    kind: field.kind,
+                    count: 1,
+                },
+            ))
+        })
+        .collect::<anyhow::Result<_>>()?;
+
+    let mut columns: Vec<Vec<f64>> = fields.iter().map(|_| Vec::new()).collect();
+    let mut packed: Vec<Vec<u32>> = fields.iter().map(|_| Vec::new()).collect();
+    for row in data.chunks(row_step) {
+        for point in row.chunks_exact(point_step).take(width) {
+            for (i, (offset, field)) in fields.iter().enumerate() {
+                let mut value = [0; 8];
+                let value = &mut value[..field.size];
+                value.copy_from_slice(&point[*offset..*offset + field.size]);
+                if is_big_endian {
+                    value.reverse();
+                }
+
+                if field.is_packed_color() {
+                    packed[i].push(PcdField::decode_packed(value));
+                } else {
+                    columns[i].push(field.decode(value)?);
+                }
+            }
+        }
+    }
+
+    let fields: Vec<PcdField> = fields.into_iter().map(|(_, field)| field).collect();
+    Ok(PointCloud::from_columns(&fields, &columns, &packed)?.into_batches())
+}
+
+/// A point cloud, before it is turned into components.
+#[derive(Default)]
+struct PointCloud {
+    /// Added to all positions, see [`MAX_COORDINATE_BEFORE_RECENTERING`].
+    origin: [f64; 3],
+
+    positions: Vec<[f32; 3]>,
+
+    /// Colors of the points, if the file has them.
+    colors: Option<Vec<Color>>,
+
+    /// Used for coloring the points if there are no `colors`.
+    intensities: Option<Vec<f64>>,
+
+    /// All other per-point fields, including `intensity`.
+    extra_fields: Vec<(String, Vec<f64>)>,
+}
+
+impl PointCloud {
+    /// Keeps `positions` as close to the origin as possible.
+    fn from_positions(positions: &[[f64; 3]]) -> Self {
+        let is_far = positions.iter().any(|position| {
+            position
+                .iter()
+                .any(|&v| v.abs() > MAX_COORDINATE_BEFORE_RECENTERING)
+        });
+        let origin = match positions.first() {
+            Some(first) if is_far => first.map(f64::round),
+            _ => [0.0; 3],
+        };
+
+        Self {
+            origin,
+            positions: positions
+                .iter()
+                .map(|[x, y, z]| {
+                    [
+                        (x - origin[0]) as f32,
+                        (y - origin[1]) as f32,
+                        (z - origin[2]) as f32,
+                    ]
+                })
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    /// Builds a point cloud out of per-field `columns` of values, with packed colors on the side.
+    fn from_columns(
+        fields: &[PcdField],
+        columns: &[Vec<f64>],
+        packed: &[Vec<u32>],
+    ) -> anyhow::Result<Self> {
+        let column = |name: &str| {
+            fields
+                .iter()
+                .position(|field| field.name == name)
+                .map(|i| &columns[i])
+        };
+        let (Some(xs), Some(ys), Some(zs)) = (column("x"), column("y"), column("z")) else {
+            anyhow::bail!("Point cloud has no x, y & z fields");
+        };
+
+        // Organized point clouds mark missing points with NaNs.
+        let keep: Vec<usize> = (0..xs.len().min(ys.len()).min(zs.len()))
+            .filter(|&i| xs[i].is_finite() && ys[i].is_finite() && zs[i].is_finite())
+            .collect();
+
+        let positions: Vec<[f64; 3]> = keep.iter().map(|&i| [xs[i], ys[i], zs[i]]).collect();
+        let mut point_cloud = Self::from_positions(&positions);
+
+        for (i, field) in fields.iter().enumerate() {
+            if field.is_packed_color() {
+                let has_alpha = field.name == "rgba";
+                point_cloud.colors = Some(
+                    keep.iter()
+                        .map(|&p| {
+                            let bits = packed[i].get(p).copied().unwrap_or(0);
+                            let [b, g, r, a] = bits.to_le_bytes();
+                            Color::from_unmultiplied_rgba(r, g, b, if has_alpha { a } else { 255 })
+                        })
+                        .collect(),
+                );
+            } else if !["x", "y", "z", "_"].contains(&field.name.as_str()) {
+                let values = keep
+                    .iter()
+                    .map(|&p| columns[i].get(p).copied().unwrap_or(f64::NAN))
+                    .collect();
+                point_cloud.add_field(field.name.clone(), values);
+            }
+        }
+
+        Ok(point_cloud)
+    }
+
+    fn add_field(&mut self, name: impl Into<String>, values: Vec<f64>) {
+        let name = name.into();
+        if name.eq_ignore_ascii_case("intensity") && self.intensities.is_none() {
+            self.intensities = Some(values.clone());
+        }
+        self.extra_fields.push((name, values));
+    }
+
+    fn into_batches(self) -> Vec<SerializedComponentBatch> {
+        let Self {
+            origin,
+            positions,
+            colors,
+            intensities,
+            extra_fields,
+        } = self;
+
+        let colors = colors.or_else(|| intensities.map(|intensities| colormap(&intensities)));
+
+        let mut points = Points3D::new(positions);
+        if let Some(colors) = colors {
+            points = points.with_colors(colors);
+        }
+
+        let mut batches = points.as_serialized_batches();
+
+        if origin != [0.0; 3] {
+            let translation = origin.map(|v| v as f32);
+            batches.extend(Transform3D::from_translation(translation).as_serialized_batches());
+        }
+
+        if !extra_fields.is_empty() {
+            let extra_fields = extra_fields.into_iter().fold(
+                AnyValues::new(EXTRA_FIELDS_ARCHETYPE),
+                |any, (name, values)| {
+                    any.with_field(
+                        name.as_str(),
+                        Arc::new(Float64Array::from(values)) as ArrayRef,
+                    )
+                },
+            );
+            batches.extend(extra_fields.as_serialized_batches());
+        }
+
+        batches
+    }
+}
+
+/// Maps intensities to colors, normalized over the range of the cloud's intensities.
+fn colormap(intensities: &[f64]) -> Vec<Color> {
+    let (min, max) = intensities
+        .iter()
+        .filter(|v| v.is_finite())
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), &v| {
+            (min.min(v), max.max(v))
+        });
+    let range = (max - min).max(f64::EPSILON);
+
+    intensities
+        .iter()
+        .map(|&v| {
+            let t = ((v - min) / range).clamp(0.0, 1.0) as f32;
+            let [r, g, b] = colormap_turbo_srgb(if t.is_nan() { 0.0 } else { t });
+            Color::from_rgb(r, g, b)
+        })
+        .collect()
+}
+
+/// Returns sRGB polynomial approximation from Turbo color map, assuming `t` is normalized.
+fn colormap_turbo_srgb(t: f32) -> [u8; 3] {
+    #![allow(clippy::excessive_precision)]
+
+    const R4: [f32; 4] = [0.13572138, 4.61539260, -42.66032258, 132.13108234];
+    const G4: [f32; 4] = [0.09140261, 2.19418839, 4.84296658, -14.18503333];
+    const B4: [f32; 4] = [0.10667330, 12.64194608, -60.58204836, 110.36276771];
+
+    const R2: [f32; 2] = [-152.94239396, 59.28637943];
+    const G2: [f32; 2] = [4.27729857, 2.82956604];
+    const B2: [f32; 2] = [-89.90310912, 27.34824973];
+
+    let v4 = [1.0, t, t * t, t * t * t];
+    let v2 = [v4[2] * v4[2], v4[3] * v4[2]];
+    let channel = |c4: [f32; 4], c2: [f32; 2]| {
+        let v = v4.iter().zip(c4).map(|(a, b)| a * b).sum::<f32>()
+            + v2.iter().zip(c2).map(|(a, b)| a * b).sum::<f32>();
+        (v.clamp(0.0, 1.0) * 255.0) as u8
+    };
+
+    [channel(R4, R2), channel(G4, G2), channel(B4, B2)]
+}
+
+/// Fields are single numbers of one of these sizes, in bytes.
+fn is_valid_field_size(size: usize) -> bool {
+    matches!(size, 1 | 2 | 4 | 8)
+}
+
+// --- PCD ---
+
+/// A field of a `.pcd` file, as declared in its header.
+struct PcdField {
+    name: String,
+    size: usize,
+
+    /// `I` (signed), `U` (unsigned) or `F` (floating point).
+    kind: u8,
+    count: usize,
+}
+
+impl PcdField {
+    /// `rgb` & `rgba` pack 8-bit channels into a single 4 byte value.
+    fn is_packed_color(&self) -> bool {
+        self.name == "rgb" || self.name == "rgba"
+    }
+
+    fn decode(&self, bytes: &[u8]) -> anyhow::Result<f64> {
+        let value = match (self.kind, bytes) {
+            (b'F', &[a, b, c, d]) => f32::from_le_bytes([a, b, c, d]) as f64,
+            (b'F', bytes) if bytes.len() == 8 => f64::from_le_bytes(bytes.try_into()?),
+            (b'I', &[a]) => i8::from_le_bytes([a]) as f64,
+            (b'I', &[a, b]) => i16::from_le_bytes([a, b]) as f64,
+            (b'I', &[a, b, c, d]) => i32::from_le_bytes([a, b, c, d]) as f64,
+            (b'I', bytes) if bytes.len() == 8 => i64::from_le_bytes(bytes.try_into()?) as f64,
+            (b'U', &[a]) => a as f64,
+            (b'U', &[a, b]) => u16::from_le_bytes([a, b]) as f64,
+            (b'U', &[a, b, c, d]) => u32::from_le_bytes([a, b, c, d]) as f64,
+            (b'U', bytes) if bytes.len() == 8 => u64::from_le_bytes(bytes.try_into()?) as f64,
+            _ => anyhow::bail!(
+                "Unsupported type {:?} of size {} for field {:?}",
+                self.kind as char,
+                self.size,
+                self.name
+            ),
+        };
+        Ok(value)
+    }
+
+    /// The raw bits of a [`Self::is_packed_color`] field.
+    fn decode_packed(bytes: &[u8]) -> u32 {
+        match bytes {
+            &[a, b, c, d] => u32::from_le_bytes([a, b, c, d]),
+            _ => 0,
+        }
+    }
+
+    /// Same as [`Self::decode_packed`], for `.pcd` files in ASCII.
+    fn parse_packed(&self, value: &str) -> Option<u32> {
+        if self.kind == b'F' {
+            value.parse::<f32>().ok().map(f32::to_bits)
+        } else {
+            value.parse::<u32>().ok()
+        }
+    }
+}
+
+/// Reads a `.pcd` file, as written by the Point Cloud Library.
+///
+/// Supports `ascii`, `binary` and `binary_compressed` data.
+fn read_pcd(contents: &[u8]) -> anyhow::Result<PointCloud> {
+    re_tracing::profile_function!();
+
+    let mut fields: Vec<PcdField> = Vec::new();
+    let mut num_points = None;
+    let mut offset = 0;
+
+    let data_kind = loop {
+        let line_end = contents[offset..]
+            .iter()
+            .position(|&b| b == b'\n')
+            .context("Unexpected end of .pcd header")?;
+        let line = std::str::from_utf8(&contents[offset..offset + line_end])
+            .context("Invalid .pcd header")?
+            .trim();
+        offset += line_end + 1;
+
+        let mut words = line.split_whitespace();
+        let Some(keyword) = words.next() else {
+            continue;
+        };
+        let values: Vec<&str> = words.collect();
+
+        match keyword.to_ascii_uppercase().as_str() {
+            "FIELDS" => {
+                fields = values
+                    .iter()
+                    .map(|name| PcdField {
+                        name: (*name).to_owned(),
+                        size: 4,
+                        kind: b'F',
+                        count: 1,
+                    })
+                    .collect();
+            }
+            "SIZE" => {
+                for (field, size) in fields.iter_mut().zip(&values) {
+                    field.size = size.parse().context("Invalid SIZE in .pcd header")?;
+                }
+            }
+            "TYPE" => {
+                for (field, kind) in fields.iter_mut().zip(&values) {
+                    field.kind = kind.bytes().next().unwrap_or(b'F');
+                }
+            }
+            "COUNT" => {
+                for (field, count) in fields.iter_mut().zip(&values) {
+                    field.count = count.parse().context("Invalid COUNT in .pcd header")?;
+                }
+            }
+            "POINTS" => {
+                num_points = Some(
+                    values
+                        .first()
+                        .and_then(|n| n.parse::<usize>().ok())
+                        .context("Invalid POINTS in .pcd header")?,
+                );
+            }
+            "DATA" => break values.first().map(|kind| kind.to_ascii_lowercase()),
+            _ => {} // Comments, VERSION, WIDTH, HEIGHT & VIEWPOINT.
+        }
+    }
+    .context("Missing DATA kind in .pcd header")?;
+
+    anyhow::ensure!(!fields.is_empty(), "Missing FIELDS in .pcd header");
+    for field in &fields {
+        anyhow::ensure!(
+            is_valid_field_size(field.size) && field.count > 0,
+            "Invalid SIZE {} or COUNT {} of field {:?} in .pcd header",
+            field.size,
+            field.count,
+            field.name
+        );
+    }
+
+    let num_points = num_points.context("Missing POINTS in .pcd header")?;
+    let data = &contents[offset..];
+
+    // Every point takes at least a byte, so the data size limits how much is worth reserving.
+    let mut columns: Vec<Vec<f64>> = fields
+        .iter()
+        .map(|_| Vec::with_capacity(num_points.min(data.len())))
+        .collect();
+    let mut packed: Vec<Vec<u32>> = fields.iter().map(|_| Vec::new()).collect();
+
+    match data_kind.as_str() {
+        "ascii" => {
+            let text = std::str::from_utf8(data).context("Invalid .pcd ASCII data")?;
+            for line in text
+                .lines()
+                .filter(|line| !line.trim().is_empty())
+                .take(num_points)
+            {
+                let mut values = line.split_whitespace();
+                for (i, field) in fields.iter().enumerate() {
+                    for element in 0..field.count {
+                        let value = values.next().context("Truncated .pcd ASCII data")?;
+                        if element > 0 {
+                            continue;
+                        }
+                        if field.is_packed_color() {
+                            packed[i].push(field.parse_packed(value).unwrap_or(0));
+                        } else {
+                            columns[i].push(value.parse().unwrap_or(f64::NAN));
+                        }
+                    }
+                }
+            }
+        }
+
+        "binary" => {
+            let point_size = fields
+                .iter()
+                .try_fold(0_usize, |point_size, field| {
+                    point_size.checked_add(field.size.checked_mul(field.count)?)
+                })
+                .context("Invalid .pcd header: points are too large")?;
+            let data_size = point_size
+                .checked_mul(num_points)
+                .context("Invalid POINTS in .pcd header")?;
+            anyhow::ensure!(data.len() >= data_size, "Truncated .pcd binary data");
+            for point in data.chunks_exact(point_size).take(num_points) {
+                let mut offset = 0;
+                for (i, field) in fields.iter().enumerate() {
+                    let bytes = &point[offset..offset + field.size];
+                    if field.is_packed_color() {
+                        packed[i].push(PcdField::decode_packed(bytes));
+                    } else {
+                        columns[i].push(field.decode(bytes)?);
+                    }
+                    offset += field.size * field.count;
+                }
+            }
+        }
+
+        "binary_compressed" => {
+            anyhow::ensure!(data.len() >= 8, "Truncated .pcd compressed data");
+            let compressed_size = u32::from_le_bytes([data[0], data[1], data[2], data[3]]) as usize;
+            let uncompressed_size =
+                u32::from_le_bytes([data[4], data[5], data[6], data[7]]) as usize;
+            let compressed = data
+                .get(8..8 + compressed_size)
+                .context("Truncated .pcd compressed data")?;
+            let uncompressed = lzf_decompress(compressed, uncompressed_size)?;
+
+            // Compressed data is stored field by field rather than point by point.
+            let mut offset = 0;
+            for (i, field) in fields.iter().enumerate() {
+                let field_size = field
+                    .size
+                    .checked_mul(field.count)
+                    .context("Invalid .pcd header: fields are too large")?;
+                let end = field_size
+                    .checked_mul(num_points)
+                    .and_then(|size| offset.checked_add(size))
+                    .context("Invalid POINTS in .pcd header")?;
+                let bytes = uncompressed
+                    .get(offset..end)
+                    .context("Truncated .pcd compressed data")?;
+                for value in bytes.chunks_exact(field_size) {
+                    let value = &value[..field.size];
+                    if field.is_packed_color() {
+                        packed[i].push(PcdField::decode_packed(value));
+                    } else {
+                        columns[i].push(field.decode(value)?);
+                    }
+                }
+                offset = end;
+            }
+        }
+
+        kind => anyhow::bail!("Unsupported .pcd DATA kind: {kind:?}"),
+    }
+
+    PointCloud::from_columns(&fields, &columns, &packed)
+}
+
+/// Decompresses LZF data, as used by `binary_compressed` `.pcd` files.
+fn lzf_decompress(input: &[u8], uncompressed_size: usize) -> anyhow::Result<Vec<u8>> {
+    // The size comes from the file. Every 3 input bytes produce at most 264 output bytes.
+    let mut output = Vec::with_capacity(uncompressed_size.min(input.len().saturating_mul(88)));
+    let mut i = 0;
+
+    while i < input.len() {
+        let ctrl = input[i] as usize;
+        i += 1;
+
+        if ctrl < 32 {
+            // Literal run.
+            let len = ctrl + 1;
+            let literal = input.get(i..i + len).context("Corrupt LZF data")?;
+            output.extend_from_slice(literal);
+            i += len;
+        } else {
+            // Back-reference, which may overlap with the bytes it produces.
+            let mut len = ctrl >> 5;
+            if len == 7 {
+                len += *input.get(i).context("Corrupt LZF data")? as usize;
+                i += 1;
+            }
+            len += 2;
+
+            let distance =
+                ((ctrl & 0x1f) << 8) + *input.get(i).context("Corrupt LZF data")? as usize + 1;
+            i += 1;
+
+            anyhow::ensure!(distance <= output.len(), "Corrupt LZF data");
+            for _ in 0..len {
+                output.push(output[output.len() - distance]);
+            }
+        }
+    }
+
+    anyhow::ensure!(
+        output.len() == uncompressed_size,
+        "Corrupt LZF data: expected {uncompressed_size} bytes, got {}",
+        output.len()
+    );
+    Ok(output)
+}
+
+// --- LAS/LAZ ---
+
+/// Reads a `.las` or `.laz` file, as used for airborne lidar.
+fn read_las(contents: &[u8]) -> anyhow::Result<PointCloud> {
+    re_tracing::profile_function!();
+
+    let mut reader = las::Reader::new(std::io::Cursor::new(contents.to_vec()))
+        .context("Failed to read LAS header")?;
+    let num_points = reader.header().number_of_points() as usize;
+
+    let mut positions = Vec::with_capacity(num_points);
+    let mut colors = Vec::new();
+    let mut intensity = Vec::with_capacity(num_points);
+    let mut classification = Vec::with_capacity(num_points);
+    let mut return_number = Vec::with_capacity(num_points);
+    let mut gps_time = Vec::new();
+
+    for point in reader.points() {
+        let point = point.context("Failed to read LAS point")?;
+        positions.push([point.x, point.y, point.z]);
+        intensity.push(point.intensity as f64);
+        classification.push(u8::from(point.classification) as f64);
+        return_number.push(point.return_number as f64);
+        if let Some(time) = point.gps_time {
+            gps_time.push(time);
+        }
+        if let Some(color) = point.color {
+            colors.push([color.red, color.green, color.blue]);
+        }
+    }
+
+    let mut point_cloud = PointCloud::from_positions(&positions);
+
+    if colors.len() == positions.len() {
+        // The spec asks for 16-bit colors, but plenty of writers use 8 bits.
+        let is_16_bit = colors.iter().flatten().any(|&c| c > 255);
+        point_cloud.colors = Some(
+            colors
+                .into_iter()
+                .map(|rgb| {
+                    let [r, g, b] = rgb.map(|c| if is_16_bit { (c >> 8) as u8 } else { c as u8 });
+                    Color::from_rgb(r, g, b)
+                })
+                .collect(),
+        );
+    }
+
+    point_cloud.add_field("intensity", intensity);
+    point_cloud.add_field("classification", classification);
+    point_cloud.add_field("return_number", return_number);
+    if gps_time.len() == positions.len() {
+        point_cloud.add_field("gps_time", gps_time);
+    }
+
+    Ok(point_cloud)
+}
+
+// --- E57 ---
+
+/// Reads all point clouds of an `.e57` file, as used for terrestrial laser scans.
+///
+/// Scans are merged into a single cloud, with their poses applied.
+fn read_e57(contents: &[u8]) -> anyhow::Result<PointCloud> {
+    re_tracing::profile_function!();
+
+    let mut reader =
+        e57::E57Reader::new(std::io::Cursor::new(contents)).context("Failed to read E57 header")?;
+
+    let mut positions = Vec::new();
+    let mut colors = Vec::new();
+    let mut intensities = Vec::new();
+    let mut scan_index = Vec::new();
+    let mut has_color = true;
+    let mut has_intensity = true;
+
+    for (scan, pointcloud) in reader.pointclouds().iter().enumerate() {
+        has_color &= pointcloud.has_color();
+        has_intensity &= pointcloud.has_intensity();
+
+        let mut points = reader
+            .pointcloud_simple(pointcloud)
+            .context("Failed to read E57 point cloud")?;
+        points.spherical_to_cartesian(true);
+
+        for point in points {
+            let point = point.context("Failed to read E57 point")?;
+            let e57::CartesianCoordinate::Valid { x, y, z } = point.cartesian else {
+                continue;
+            };
+            positions.push([x, y, z]);
+            colors.push(point.color);
+            intensities.push(point.intensity as f64);
+            scan_index.push(scan as f64);
+        }
+    }
+
+    let mut point_cloud = PointCloud::from_positions(&positions);
+
+    if has_color {
+        point_cloud.colors = Some(
+            colors
+                .into_iter()
+                .map(|color| {
+                    let [r, g, b] = [color.red, color.green, color.blue]
+                        .map(|c| (c.clamp(0.0, 1.0) * 255.0).round() as u8);
+                    Color::from_rgb(r, g, b)
+                })
+                .collect(),
+        );
+    }
+    if has_intensity {
+        point_cloud.add_field("intensity", intensities);
+    }
+    point_cloud.add_field("scan_index", scan_index);
+
+    Ok(point_cloud)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIELDS_HEADER: &str = "# .PCD v0.7 - Point Cloud Data file format
+VERSION 0.7
+FIELDS x y z intensity rgb
+SIZE 4 4 4 4 4
+TYPE F F F F U
+COUNT 1 1 1 1 1
+WIDTH 2
+HEIGHT 1
+VIEWPOINT 0 0 0 1 0 0 0
+POINTS 2
+";
+
+    fn assert_expected(point_cloud: &PointCloud) {
+        assert_eq!(
+            point_cloud.positions,
+            vec![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]
+        );
+        assert_eq!(
+            point_cloud.colors,
+            Some(vec![
+                Color::from_rgb(255, 0, 0),
+                Color::from_rgb(0, 128, 255)
+            ])
+        );
+        assert_eq!(point_cloud.intensities, Some(vec![0.5, 1.0]));
+        assert_eq!(point_cloud.extra_fields.len(), 1);
+    }
+
+    #[test]
+    fn pcd_ascii() {
+        let contents = format!(
+            "{FIELDS_HEADER}DATA ascii\n1 2 3 0.5 {}\n4 5 6 1.0 {}\n",
+            0xff0000, 0x0080ff
+        );
+        assert_expected(&read_pcd(contents.as_bytes()).unwrap());
+    }
+
+    #[test]
+    fn pcd_binary() {
+        let mut contents = format!("{FIELDS_HEADER}DATA binary\n").into_bytes();
+        for (xyz, intensity, rgb) in [
+            ([1.0f32, 2.0, 3.0], 0.5f32, 0xff0000u32),
+            ([4.0, 5.0, 6.0], 1.0, 0x0080ff),
+        ] {
+            for v in xyz {
+                contents.extend(v.to_le_bytes());
+            }
+            contents.extend(intensity.to_le_bytes());
+            contents.extend(rgb.to_le_bytes());
+        }
+        assert_expected(&read_pcd(&contents).unwrap());
+    }
+
+    #[test]
+    fn pcd_bogus_header() {
+        let header = |size: &str, count: &str, points: &str| {
+            format!(
+                "FIELDS x y z\nSIZE {size}\nTYPE F F F\nCOUNT {count}\n\
+                POINTS {points}\nDATA binary\n"
+            )
+        };
+        for contents in [
+            header("0 4 4", "1 1 1", "1"),
+            header("4 4 3", "1 1 1", "1"),
+            header("4 4 4", "1 0 1", "1"),
+            header("8 8 8", "1 1 1", &usize::MAX.to_string()),
+            header("8 8 8", &usize::MAX.to_string(), "1"),
+        ] {
+            assert!(read_pcd(contents.as_bytes()).is_err(), "{contents}");
+        }
+
+        let mut compressed = header("4 4 4", "1 1 1", &usize::MAX.to_string())
+            .replace("binary", "binary_compressed")
+            .into_bytes();
+        // A single literal byte.
+        compressed.extend([2, 0, 0, 0, 1, 0, 0, 0, 0, 7]);
+        assert!(read_pcd(&compressed).is_err());
+    }
+
+    #[test]
+    fn point_records_bogus_fields() {
+        let field = |offset, size| PointField {
+            name: "x".to_owned(),
+            offset,
+            kind: b'F',
+            size,
+        };
+        for field in [field(0, 0), field(0, 3), field(usize::MAX, 4), field(4, 4)] {
+            assert!(load_point_records(&[field], &[0; 16], 2, 4, 8, false).is_err());
+        }
+    }
+
+    #[test]
+    fn lzf() {
+        // "abcabcabc": 3 literals, then a back-reference of 6 bytes at distance 3.
+        let compressed = [2, b'a', b'b', b'c', (4 << 5), 2];
+        assert_eq!(lzf_decompress(&compressed, 9).unwrap(), b"abcabcabc");
+        assert!(lzf_decompress(&compressed, 10).is_err());
+    }
+
+    #[test]
+    fn recentering() {
+        let point_cloud = PointCloud::from_positions(&[[500_000.25, 4_000_000.5, 10.0]]);
+        assert_eq!(point_cloud.origin, [500_000.0, 4_000_001.0, 10.0]);
+        assert_eq!(point_cloud.positions, vec![[0.25, -0.5, 0.0]]);
+    }
+}
@@ -0,0 +1,695 @@
+//! Reader for ROS 1 bag files, see <https://wiki.ros.org/Bags/Format/2.0>.
+//!
+//! Messages are decoded dynamically, based on the message definitions stored in the bag's
+//! connection headers, see <https://wiki.ros.org/msg>.
+
+use std::collections::HashMap;
+
+use anyhow::Context as _;
+
+const BAG_MAGIC: &[u8] = b"#ROSBAG V2.0\n";
+
+/// Record opcodes, see <https://wiki.ros.org/Bags/Format/2.0#Records>.
+const OP_MESSAGE_DATA: u8 = 0x02;
+const OP_CHUNK: u8 = 0x05;
+const OP_CONNECTION: u8 = 0x07;
+
+/// A topic in a bag, along with the schema of its messages.
+pub struct Connection {
+    pub id: u32,
+    pub topic: String,
+
+    /// E.g. `sensor_msgs/Image`.
+    pub message_type: String,
+
+    pub schema: MessageSchema,
+}
+
+/// A record of interest in a bag file.
+pub enum Record<'a> {
+    Connection(Connection),
+
+    Message {
+        connection_id: u32,
+
+        /// Time at which the message was recorded, in nanoseconds since the epoch.
+        time: i64,
+
+        data: &'a [u8],
+    },
+}
+
+/// Calls `on_record` for every connection and message in the bag, in file order.
+///
+/// Connections always come before their first message.
+pub fn read_bag(
+    contents: &[u8],
+    on_record: &mut dyn FnMut(Record<'_>) -> anyhow::Result<()>,
+) -> anyhow::Result<()> {
+    re_tracing::profile_function!();
+
+    let records = contents
+        .strip_prefix(BAG_MAGIC)
+        .context("Not a ROS bag, or not of version 2.0")?;
+    read_records(records, false, on_record)
+}
+
+fn read_records(
+    mut bytes: &[u8],
+    in_chunk: bool,
+    on_record: &mut dyn FnMut(Record<'_>) -> anyhow::Result<()>,
+) -> anyhow::Result<()> {
+    while !bytes.is_empty() {
+        let header_len = read_u32(&mut bytes)? as usize;
+        let header = take(&mut bytes, header_len)?;
+        let data_len = read_u32(&mut bytes)? as usize;
+        let data = take(&mut bytes, data_len)?;
+
+        let header = Header::parse(header)?;
+        match header.get("op") {
+            Some(&[OP_CHUNK]) => {
+                anyhow::ensure!(!in_chunk, "Chunks can't be nested");
+                let compression = header.get_str("compression")?;
+                let size = header.get_u32("size")? as usize;
+                let decompressed = decompress(compression, data, size)?;
+                read_records(&decompressed, true, on_record)?;
+            }
+
+            Some(&[OP_CONNECTION]) => {
+                let connection_header = Header::parse(data)?;
+                let message_type = connection_header.get_str("type")?.to_owned();
+                let schema = MessageSchema::parse(
+                    &message_type,
+                    connection_header.get_str("message_definition")?,
+                )?;
+
+                on_record(Record::Connection(Connection {
+                    id: header.get_u32("conn")?,
+                    topic: header.get_str("topic")?.to_owned(),
+                    message_type,
+                    schema,
+                }))?;
+            }
+
+            Some(&[OP_MESSAGE_DATA]) => {
+                let mut time = header.get("time").context("Message without time")?;
+                let secs = read_u32(&mut time)?;
+                let nsecs = read_u32(&mut time)?;
+
+                on_record(Record::Message {
+                    connection_id: header.get_u32("conn")?,
+                    time: secs as i64 * 1_000_000_000 + nsecs as i64,
+                    data,
+                })?;
+            }
+
+            // Bag headers, indices and chunk infos are only needed for random access.
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+/// Decompresses a chunk of the given uncompressed `size`, as stated in its header.
+///
+/// The size comes straight from the file, so it's only used to limit how much is decompressed.
+fn decompress(compression: &str, data: &[u8], size: usize) -> anyhow::Result<Vec<u8>> {
+    use std::io::Read as _;
+
+    /// Chunks are 768 KiB by default, so there's no need to reserve more than this up front.
+    const MAX_RESERVED_BYTES: usize = 16 * 1024 * 1024;
+
+    re_tracing::profile_function!(compression);
+
+    let mut decompressed = Vec::with_capacity(size.min(MAX_RESERVED_BYTES));
+    match compression {
+        "none" => decompressed.extend_from_slice(data),
+
+        "lz4" => {
+            lz4_flex::frame::FrameDecoder::new(data)
+                .take(size as u64)
+                .read_to_end(&mut decompressed)
+                .context("Failed to decompress lz4 chunk")?;
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        "bz2" => {
+            bzip2::read::BzDecoder::new(data)
+                .take(size as u64)
+                .read_to_end(&mut decompressed)
+                .context("Failed to decompress bz2 chunk")?;
+        }
+
+        #[cfg(target_arch = "wasm32")]
+        "bz2" => anyhow::bail!("bz2 compressed bags are not supported on web"),
+
+        _ => anyhow::bail!("Unknown chunk compression {compression:?}"),
+    }
+
+    Ok(decompressed)
+}
+
+/// The `name=value` fields of a record or connection header.
+struct Header<'a> {
+    fields: Vec<(&'a [u8], &'a [u8])>,
+}
+
+impl<'a> Header<'a> {
+    fn parse(mut bytes: &'a [u8]) -> anyhow::Result<Self> {
+        let mut fields = Vec::new();
+        while !bytes.is_empty() {
+            let len = read_u32(&mut bytes)? as usize;
+            let field = take(&mut bytes, len)?;
+            let separator = field
+                .iter()
+                .position(|&b| b == b'=')
+                .context("Header field without '='")?;
+            fields.push((&field[..separator], &field[separator + 1..]));
+        }
+        Ok(Self { fields })
+    }
+
+    fn get(&self, name: &str) -> Option<&'a [u8]> {
+        self.fields
+            .iter()
+            .find(|(field, _)| *field == name.as_bytes())
+            .map(|(_, value)| *value)
+    }
+
+    fn get_str(&self, name: &str) -> anyhow::Result<&'a str> {
+        let value = self
+            .get(name)
+            .with_context(|| format!("Missing header field {name:?}"))?;
+        std::str::from_utf8(value).with_context(|| format!("Invalid header field {name:?}"))
+    }
+
+    fn get_u32(&self, name: &str) -> anyhow::Result<u32> {
+        let mut value = self
+            .get(name)
+            .with_context(|| format!("Missing header field {name:?}"))?;
+        read_u32(&mut value)
+    }
+}
+
+fn take<'a>(bytes: &mut &'a [u8], len: usize) -> anyhow::Result<&'a [u8]> {
+    anyhow::ensure!(bytes.len() >= len, "Unexpected end of data");
+    let (head, tail) = bytes.split_at(len);
+    *bytes = tail;
+    Ok(head)
+}
+
+fn read_u32(bytes: &mut &[u8]) -> anyhow::Result<u32> {
+    let value = take(bytes, 4)?;
+    Ok(u32::from_le_bytes([value[0], value[1], value[2], value[3]]))
+}
+
+// --- Message definitions
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Primitive {
+    Bool,
+    Int8,
+    UInt8,
+    Int16,
+    UInt16,
+    Int32,
+    UInt32,
+    Int64,
+    UInt64,
+    Float32,
+    Float64,
+    String,
+    Time,
+    Duration,
+}
+
+impl Primitive {
+    fn parse(name: &str) -> Option<Self> {
+        Some(match name {
+            "bool" => Self::Bool,
+            "int8" | "byte" => Self::Int8,
+            "uint8" | "char" => Self::UInt8,
+            "int16" => Self::Int16,
+            "uint16" => Self::UInt16,
+            "int32" => Self::Int32,
+            "uint32" => Self::UInt32,
+            "int64" => Self::Int64,
+            "uint64" => Self::UInt64,
+            "float32" => Self::Float32,
+            "float64" => Self::Float64,
+            "string" => Self::String,
+            "time" => Self::Time,
+            "duration" => Self::Duration,
+            _ => return None,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum FieldType {
+    Primitive(Primitive),
+
+    /// Full name of a nested message type, e.g. `std_msgs/Header`.
+    Message(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum FieldArity {
+    Single,
+    Variable,
+    Fixed(usize),
+}
+
+#[derive(Debug, Clone)]
+struct Field {
+    name: String,
+    ty: FieldType,
+    arity: FieldArity,
+}
+
+/// A message type along with all the types it depends on.
+pub struct MessageSchema {
+    root: String,
+    types: HashMap<String, Vec<Field>>,
+}
+
+impl MessageSchema {
+    /// Parses the `message_definition` of a connection header.
+    ///
+    /// It holds the definition of `message_type`, followed by all its dependencies, each in their
+    /// own section starting with `MSG: <type>`.
+    pub fn parse(message_type: &str, definition: &str) -> anyhow::Result<Self> {
+        let mut types = HashMap::new();
+
+        let mut name = message_type.to_owned();
+        let mut fields = Vec::new();
+        for line in definition.lines().map(str::trim) {
+            if line.starts_with("==") {
+                types.insert(std::mem::take(&mut name), std::mem::take(&mut fields));
+                continue;
+            }
+            if let Some(msg) = line.strip_prefix("MSG:") {
+                name = msg.trim().to_owned();
+                continue;
+            }
+            if let Some(field) = parse_field(&name, line)? {
+                fields.push(field);
+            }
+        }
+        types.insert(name, fields);
+
+        Ok(Self {
+            root: message_type.to_owned(),
+            types,
+        })
+    }
+
+    /// Decodes a message of this schema's type.
+    pub fn decode<'a>(&self, mut data: &'a [u8]) -> anyhow::Result<Value<'a>> {
+        self.decode_message(&self.root, &mut data, 0)
+    }
+
+    /// Decodes a message of type `name`, nested `depth` levels deep into the root message.
+    fn decode_message<'a>(
+        &self,
+        name: &str,
+        data: &mut &'a [u8],
+        depth: usize,
+    ) -> anyhow::Result<Value<'a>> {
+        /// Real messages are only nested a few levels deep, but a malformed definition
+        /// could refer to itself.
+        const MAX_DEPTH: usize = 32;
+
+        anyhow::ensure!(depth < MAX_DEPTH, "{name:?} is nested too deeply");
+        let fields = self
+            .types
+            .get(name)
+            .with_context(|| format!("Missing definition of {name:?}"))?;
+
+        let mut values = Vec::with_capacity(fields.len());
+        for field in fields {
+            let value = match field.arity {
+                FieldArity::Single => self.decode_value(&field.ty, data, depth)?,
+                FieldArity::Variable => {
+                    let len = read_u32(data)? as usize;
+                    self.decode_array(&field.ty, len, data, depth)?
+                }
+                FieldArity::Fixed(len) => self.decode_array(&field.ty, len, data, depth)?,
+            };
+            values.push((field.name.clone(), value));
+        }
+
+        Ok(Value::Message(values))
+    }
+
+    fn decode_array<'a>(
+        &self,
+        ty: &FieldType,
+        len: usize,
+        data: &mut &'a [u8],
+        depth: usize,
+    ) -> anyhow::Result<Value<'a>> {
+        // Byte arrays hold e.g. image data, and are kept as is.
+        if let FieldType::Primitive(Primitive::UInt8 | Primitive::Int8) = ty {
+            return Ok(Value::Bytes(take(data, len)?));
+        }
+
+        // Guards against allocating huge arrays because of corrupt lengths.
+        let mut values = Vec::with_capacity(len.min(data.len()));
+        for _ in 0..len {
+            values.push(self.decode_value(ty, data, depth)?);
+        }
+        Ok(Value::Array(values))
+    }
+
+    fn decode_value<'a>(
+        &self,
+        ty: &FieldType,
+        data: &mut &'a [u8],
+        depth: usize,
+    ) -> anyhow::Result<Value<'a>> {
+        fn bytes<const N: usize>(data: &mut &[u8]) -> anyhow::Result<[u8; N]> {
+            Ok(take(data, N)?.try_into()?)
+        }
+
+        let primitive = match ty {
+            FieldType::Primitive(primitive) => *primitive,
+            FieldType::Message(name) => return self.decode_message(name, data, depth + 1),
+        };
+
+        Ok(match primitive {
+            Primitive::Bool => Value::Bool(bytes::<1>(data)?[0] != 0),
+            Primitive::Int8 => Value::Int(i8::from_le_bytes(bytes(data)?) as i64),
+            Primitive::UInt8 => Value::UInt(u8::from_le_bytes(bytes(data)?) as u64),
+            Primitive::Int16 => Value::Int(i16::from_le_bytes(bytes(data)?) as i64),
+            Primitive::UInt16 => Value::UInt(u16::from_le_bytes(bytes(data)?) as u64),
+            Primitive::Int32 => Value::Int(i32::from_le_bytes(bytes(data)?) as i64),
+            Primitive::UInt32 => Value::UInt(u32::from_le_bytes(bytes(data)?) as u64),
+            Primitive::Int64 => Value::Int(i64::from_le_bytes(bytes(data)?)),
+            Primitive::UInt64 => Value::UInt(u64::from_le_bytes(bytes(data)?)),
+            Primitive::Float32 => Value::Float(f32::from_le_bytes(bytes(data)?) as f64),
+            Primitive::Float64 => Value::Float(f64::from_le_bytes(bytes(data)?)),
+            Primitive::String => {
+                let len = read_u32(data)? as usize;
+                Value::String(std::str::from_utf8(take(data, len)?).context("Invalid string")?)
+            }
+            Primitive::Time => {
+                let secs = u32::from_le_bytes(bytes(data)?);
+                let nsecs = u32::from_le_bytes(bytes(data)?);
+                Value::Time(secs as i64 * 1_000_000_000 + nsecs as i64)
+            }
+            Primitive::Duration => {
+                let secs = i32::from_le_bytes(bytes(data)?);
+                let nsecs = i32::from_le_bytes(bytes(data)?);
+                Value::Time(secs as i64 * 1_000_000_000 + nsecs as i64)
+            }
+        })
+    }
+}
+
+/// Parses a line of a message definition, returning `None` for comments and constants.
+fn parse_field(message_type: &str, line: &str) -> anyhow::Result<Option<Field>> {
+    let line = line.split('#').next().unwrap_or_default().trim();
+    let Some((ty, name)) = line.split_once(char::is_whitespace) else {
+        return Ok(None);
+    };
+    let name = name.trim();
+    if name.contains('=') {
+        return Ok(None); // Constant
+    }
+
+    let (ty, arity) = match ty.split_once('[') {
+        Some((ty, "]")) => (ty, FieldArity::Variable),
+        Some((ty, len)) => {
+            let len = len
+                .trim_end_matches(']')
+                .parse()
+                .with_context(|| format!("Invalid array length in {line:?}"))?;
+            (ty, FieldArity::Fixed(len))
+        }
+        None => (ty, FieldArity::Single),
+    };
+
+    let ty = if let Some(primitive) = Primitive::parse(ty) {
+        FieldType::Primitive(primitive)
+    } else if ty == "Header" {
+        FieldType::Message("std_msgs/Header".to_owned())
+    } else if ty.contains('/') {
+        FieldType::Message(ty.to_owned())
+    } else {
+        // Types without a package are relative to the package of the message using them.
+        let package = message_type.split('/').next().unwrap_or_default();
+        FieldType::Message(format!("{package}/{ty}"))
+    };
+
+    Ok(Some(Field {
+        name: name.to_owned(),
+        ty,
+        arity,
+    }))
+}
+
+// --- Decoded messages
+
+/// A dynamically decoded message, or one of its fields.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value<'a> {
+    Bool(bool),
+    Int(i64),
+    UInt(u64),
+    Float(f64),
+    String(&'a str),
+
+    /// A `time` or `duration`, in nanoseconds.
+    Time(i64),
+
+    /// An `uint8[]` or `int8[]`.
+    Bytes(&'a [u8]),
+
+    Array(Vec<Value<'a>>),
+    Message(Vec<(String, Value<'a>)>),
+}
+
+impl<'a> Value<'a> {
+    /// Looks up a field by its dot-separated `path`, e.g. `header.stamp`.
+    pub fn get(&self, path: &str) -> Option<&Self> {
+        path.split('.').try_fold(self, |value, name| match value {
+            Self::Message(fields) => fields
+                .iter()
+                .find(|(field, _)| field == name)
+                .map(|(_, value)| value),
+            _ => None,
+        })
+    }
+
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Self::Int(value) => Some(*value as f64),
+            Self::UInt(value) => Some(*value as f64),
+            Self::Float(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    pub fn f64(&self, path: &str) -> Option<f64> {
+        self.get(path)?.as_f64()
+    }
+
+    pub fn u64(&self, path: &str) -> Option<u64> {
+        match self.get(path)? {
+            Self::Bool(value) => Some(*value as u64),
+            Self::Int(value) => u64::try_from(*value).ok(),
+            Self::UInt(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    pub fn str(&self, path: &str) -> Option<&'a str> {
+        match self.get(path)? {
+            Self::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn bytes(&self, path: &str) -> Option<&'a [u8]> {
+        match self.get(path)? {
+            Self::Bytes(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn array(&self, path: &str) -> Option<&[Self]> {
+        match self.get(path)? {
+            Self::Array(values) => Some(values),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const POINT_STAMPED: &str = "\
+# A point with a header
+Header header
+Point point
+
+================================================================================
+MSG: std_msgs/Header
+uint32 seq
+time stamp
+string frame_id
+
+================================================================================
+MSG: geometry_msgs/Point
+float64 x
+float64 y
+float64 z
+uint8 UNUSED_CONSTANT=3
+";
+
+    #[test]
+    fn test_decode_message() {
+        let schema = MessageSchema::parse("geometry_msgs/PointStamped", POINT_STAMPED).unwrap();
+
+        let mut data = Vec::new();
+        data.extend(7u32.to_le_bytes());
+        data.extend(2u32.to_le_bytes());
+        data.extend(500u32.to_le_bytes());
+        data.extend(3u32.to_le_bytes());
+        data.extend(b"map");
+        for v in [1.0f64, 2.0, 3.0] {
+            data.extend(v.to_le_bytes());
+        }
+
+        let message = schema.decode(&data).unwrap();
+        assert_eq!(message.u64("header.seq"), Some(7));
+        assert_eq!(
+            message.get("header.stamp"),
+            Some(&Value::Time(2_000_000_500))
+        );
+        assert_eq!(message.str("header.frame_id"), Some("map"));
+        assert_eq!(message.f64("point.y"), Some(2.0));
+        assert_eq!(message.get("point.UNUSED_CONSTANT"), None);
+    }
+
+    #[test]
+    fn test_decode_recursive_message() {
+        let schema = MessageSchema::parse(
+            "test_msgs/Node",
+            "\
+test_msgs/Node next
+================================================================================
+MSG: test_msgs/Node
+Node next
+",
+        )
+        .unwrap();
+
+        assert!(schema.decode(&[]).is_err());
+        assert!(schema.decode(&[0; 1024]).is_err());
+    }
+
+    #[test]
+    fn test_decompress_bogus_size() {
+        // The uncompressed size from the chunk header must not be trusted.
+        assert_eq!(decompress("none", b"abc", usize::MAX).unwrap(), b"abc");
+
+        let mut compressed = Vec::new();
+        {
+            use std::io::Write as _;
+            let mut encoder = lz4_flex::frame::FrameEncoder::new(&mut compressed);
+            encoder.write_all(&[7; 1000]).unwrap();
+            encoder.finish().unwrap();
+        }
+        assert_eq!(decompress("lz4", &compressed, 10).unwrap(), vec![7; 10]);
+        assert_eq!(
+            decompress("lz4", &compressed, usize::MAX).unwrap(),
+            vec![7; 1000]
+        );
+    }
+
+    #[test]
+    fn test_read_bag() {
+        fn record(header: &[(&str, &[u8])], data: &[u8]) -> Vec<u8> {
+            let mut fields = Vec::new();
+            for (name, value) in header {
+                fields.extend((name.len() as u32 + 1 + value.len() as u32).to_le_bytes());
+                fields.extend(name.as_bytes());
+                fields.push(b'=');
+                fields.extend(*value);
+            }
+
+            let mut bytes = Vec::new();
+            bytes.extend((fields.len() as u32).to_le_bytes());
+            bytes.extend(fields);
+            bytes.extend((data.len() as u32).to_le_bytes());
+            bytes.extend(data);
+            bytes
+        }
+
+        let connection_header = record(
+            &[
+                ("topic", b"/value"),
+                ("type", b"std_msgs/Float32"),
+                ("message_definition", b"float32 data\n"),
+            ],
+            &[],
+        );
+        // Connection headers have no data of their own, strip the empty data length.
+        let connection_header = &connection_header[4..connection_header.len() - 4];
+
+        let mut chunk = record(
+            &[
+                ("op", &[OP_CONNECTION]),
+                ("conn", &0u32.to_le_bytes()),
+                ("topic", b"/value"),
+            ],
+            connection_header,
+        );
+        let mut time = 1u32.to_le_bytes().to_vec();
+        time.extend(2u32.to_le_bytes());
+        chunk.extend(record(
+            &[
+                ("op", &[OP_MESSAGE_DATA]),
+                ("conn", &0u32.to_le_bytes()),
+                ("time", &time),
+            ],
+            &0.5f32.to_le_bytes(),
+        ));
+
+        let mut bag = BAG_MAGIC.to_vec();
+        bag.extend(record(
+            &[
+                ("op", &[OP_CHUNK]),
+                ("compression", b"none"),
+                ("size", &(chunk.len() as u32).to_le_bytes()),
+            ],
+            &chunk,
+        ));
+
+        let mut connections = Vec::new();
+        let mut messages = Vec::new();
+        read_bag(&bag, &mut |record| {
+            match record {
+                Record::Connection(connection) => connections.push(connection),
+                Record::Message {
+                    connection_id,
+                    time,
+                    data,
+                } => {
+                    let value = connections[connection_id as usize].schema.decode(data)?;
+                    messages.push((time, value.f64("data")));
+                }
+            }
+            Ok(())
+        })
+        .unwrap();
+
+        assert_eq!(connections[0].topic, "/value");
+        assert_eq!(connections[0].message_type, "std_msgs/Float32");
+        assert_eq!(messages, vec![(1_000_000_002, Some(0.5))]);
+    }
+}
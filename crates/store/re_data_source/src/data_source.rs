@@ -21,6 +21,11 @@ pub enum DataSource {
         follow: bool,
     },
 
+    /// A remote file of any other supported format, served over http.
+    ///
+    /// It is downloaded in full, then loaded by the same data loaders as local files.
+    FileHttpUrl(re_log_types::FileSource, String),
+
     /// A path to a local file.
     #[cfg(not(target_arch = "wasm32"))]
     FilePath(re_log_types::FileSource, std::path::PathBuf),
@@ -55,8 +60,20 @@ impl DataSource {
     ///
     /// Tries to figure out if it looks like a local path,
     /// a web-socket address, or a http url.
-    #[cfg_attr(target_arch = "wasm32", allow(clippy::needless_pass_by_value))]
-    pub fn from_uri(_file_source: re_log_types::FileSource, uri: String) -> Self {
+    pub fn from_uri(file_source: re_log_types::FileSource, uri: String) -> Self {
+        // Checked first, since a url like `https://localhost/video.mp4` can look like a file path.
+        if re_data_loader::is_http_uri(&uri) {
+            let extension = re_data_loader::filepath_from_uri(&uri)
+                .extension()
+                .map(|extension| extension.to_string_lossy().to_ascii_lowercase())
+                .unwrap_or_default();
+            let is_rrd = re_data_loader::SUPPORTED_RERUN_EXTENSIONS.contains(&extension.as_str());
+            if !is_rrd && re_data_loader::is_supported_file_extension(&extension) {
+                return Self::FileHttpUrl(file_source, uri);
+            }
+            return Self::RrdHttpUrl { uri, follow: false };
+        }
+
         #[cfg(not(target_arch = "wasm32"))]
         {
             use itertools::Itertools as _;
@@ -104,11 +121,11 @@ impl DataSource {
             let path = std::path::Path::new(&uri).to_path_buf();
 
             if uri.starts_with("file://") || path.exists() {
-                return Self::FilePath(_file_source, path);
+                return Self::FilePath(file_source, path);
             }
 
             if looks_like_a_file_path(&uri) {
-                return Self::FilePath(_file_source, path);
+                return Self::FilePath(file_source, path);
             }
         }
 
@@ -126,6 +143,9 @@ impl DataSource {
     pub fn file_name(&self) -> Option<String> {
         match self {
             Self::RrdHttpUrl { uri: url, .. } => url.split('/').last().map(|r| r.to_owned()),
+            Self::FileHttpUrl(_, uri) => re_data_loader::filepath_from_uri(uri)
+                .file_name()
+                .map(|s| s.to_string_lossy().to_string()),
             #[cfg(not(target_arch = "wasm32"))]
             Self::FilePath(_, path) => path.file_name().map(|s| s.to_string_lossy().to_string()),
             Self::FileContents(_, file_contents) => Some(file_contents.name.clone()),
@@ -139,6 +159,48 @@ impl DataSource {
         self.file_name().map(|name| name.ends_with(".rbl"))
     }
 
+    /// Stream the data from a local file or directory.
+    ///
+    /// This is what [`Self::stream`] does for [`Self::FilePath`], but lets the caller decide
+    /// how directories and images are loaded, e.g. as image sequences, while watching for new
+    /// files, or with 16-bit PNGs as depth images.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn stream_file_path(
+        file_source: re_log_types::FileSource,
+        path: std::path::PathBuf,
+        directory: re_data_loader::DirectoryLoaderSettings,
+        image: re_data_loader::ImageLoaderSettings,
+        on_msg: Option<Box<dyn Fn() + Send + Sync>>,
+    ) -> anyhow::Result<Receiver<LogMsg>> {
+        re_tracing::profile_function!();
+
+        let (tx, rx) = re_smart_channel::smart_channel(
+            SmartMessageSource::File(path.clone()),
+            SmartChannelSource::File(path.clone()),
+        );
+
+        // This `StoreId` will be communicated to all `DataLoader`s, which may or may not
+        // decide to use it depending on whether they want to share a common recording
+        // or not.
+        let shared_store_id = re_log_types::StoreId::random(re_log_types::StoreKind::Recording);
+        let settings = re_data_loader::DataLoaderSettings {
+            opened_application_id: file_source.recommended_application_id().cloned(),
+            opened_store_id: file_source.recommended_recording_id().cloned(),
+            force_store_info: file_source.force_store_info(),
+            directory,
+            image,
+            ..re_data_loader::DataLoaderSettings::recommended(shared_store_id)
+        };
+        re_data_loader::load_from_path(&settings, file_source, &path, &tx)
+            .with_context(|| format!("{path:?}"))?;
+
+        if let Some(on_msg) = on_msg {
+            on_msg();
+        }
+
+        Ok(rx)
+    }
+
     /// Stream the data from the given data source.
     ///
     /// Will do minimal checks (e.g. that the file exists), for synchronous errors,
@@ -162,16 +224,13 @@ impl DataSource {
                 ),
             )),
 
-            #[cfg(not(target_arch = "wasm32"))]
-            Self::FilePath(file_source, path) => {
+            Self::FileHttpUrl(file_source, uri) => {
                 let (tx, rx) = re_smart_channel::smart_channel(
-                    SmartMessageSource::File(path.clone()),
-                    SmartChannelSource::File(path.clone()),
+                    SmartMessageSource::File(uri.clone().into()),
+                    SmartChannelSource::File(uri.clone().into()),
                 );
 
-                // This `StoreId` will be communicated to all `DataLoader`s, which may or may not
-                // decide to use it depending on whether they want to share a common recording
-                // or not.
+                // See `Self::stream_file_path`.
                 let shared_store_id =
                     re_log_types::StoreId::random(re_log_types::StoreKind::Recording);
                 let settings = re_data_loader::DataLoaderSettings {
@@ -180,16 +239,22 @@ impl DataSource {
                     force_store_info: file_source.force_store_info(),
                     ..re_data_loader::DataLoaderSettings::recommended(shared_store_id)
                 };
-                re_data_loader::load_from_path(&settings, file_source, &path, &tx)
-                    .with_context(|| format!("{path:?}"))?;
-
-                if let Some(on_msg) = on_msg {
-                    on_msg();
-                }
+                re_data_loader::load_from_uri(&settings, file_source, &uri, &tx, on_msg)?;
 
                 Ok(StreamSource::LogMessages(rx))
             }
 
+            #[cfg(not(target_arch = "wasm32"))]
+            Self::FilePath(file_source, path) => {
+                Ok(StreamSource::LogMessages(Self::stream_file_path(
+                    file_source,
+                    path,
+                    Default::default(),
+                    Default::default(),
+                    on_msg,
+                )?))
+            }
+
             // When loading a file on Web, or when using drag-n-drop.
             Self::FileContents(file_source, file_contents) => {
                 let name = file_contents.name.clone();
@@ -356,6 +421,12 @@ fn test_data_source_from_uri() {
         "example.zip/foo.rrd",
         "www.foo.zip/foo.rrd",
         "www.foo.zip/blueprint.rbl",
+        "https://example.com/recording.rrd",
+    ];
+    let http_file = [
+        "https://example.com/video.mp4",
+        "http://localhost/mesh.glb",
+        "https://example.com/image.png?raw=true",
     ];
     let grpc = [
         "rerun://foo.zip",
@@ -393,6 +464,16 @@ fn test_data_source_from_uri() {
         }
     }
 
+    for uri in http_file {
+        if !matches!(
+            DataSource::from_uri(file_source.clone(), uri.to_owned()),
+            DataSource::FileHttpUrl { .. }
+        ) {
+            eprintln!("Expected {uri:?} to be categorized as FileHttpUrl");
+            failed = true;
+        }
+    }
+
     for uri in grpc {
         if !matches!(
             DataSource::from_uri(file_source.clone(), uri.to_owned()),
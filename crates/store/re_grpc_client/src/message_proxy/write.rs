@@ -1,9 +1,14 @@
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, VecDeque};
+use std::path::PathBuf;
 use std::thread;
 use std::thread::JoinHandle;
 use std::time::Duration;
 
-use re_log_encoding::Compression;
-use re_log_types::LogMsg;
+use re_chunk::ChunkId;
+use re_log_encoding::{Compression, FileSink};
+use re_log_types::external::re_types_core::ComponentDescriptor;
+use re_log_types::{ArrowMsg, EntityPath, LogMsg, StoreId, StoreKind};
 use re_protos::sdk_comms::v1alpha1::WriteMessagesRequest;
 use re_protos::sdk_comms::v1alpha1::message_proxy_service_client::MessageProxyServiceClient;
 use re_uri::ProxyUri;
@@ -25,6 +30,7 @@ enum Cmd {
 pub struct Options {
     pub compression: Compression,
     pub flush_timeout: Option<Duration>,
+    pub reconnect: ReconnectOptions,
 }
 
 impl Default for Options {
@@ -32,6 +38,48 @@ impl Default for Options {
         Self {
             compression: Compression::LZ4,
             flush_timeout: Default::default(),
+            reconnect: Default::default(),
+        }
+    }
+}
+
+/// What the client does when it cannot reach the message proxy server,
+/// e.g. because the viewer hasn't been started yet or was restarted.
+#[derive(Clone, Debug)]
+pub struct ReconnectOptions {
+    /// How long to wait before the first attempt to reconnect.
+    ///
+    /// The delay doubles with every failed attempt, up to [`Self::max_backoff`].
+    pub initial_backoff: Duration,
+
+    /// Upper bound on the delay between two attempts to reconnect.
+    pub max_backoff: Duration,
+
+    /// How many bytes worth of logged data to hold on to while there is no connection.
+    ///
+    /// Once this is exceeded, the oldest data is dropped, or written to [`Self::spill_path`].
+    /// Store infos, blueprints and static data don't count towards this budget: the latest of them
+    /// are kept for the lifetime of the client, and sent again every time it reconnects.
+    pub max_buffer_bytes: u64,
+
+    /// Write data that doesn't fit into the buffer to this `.rrd` file instead of dropping it.
+    pub spill_path: Option<PathBuf>,
+}
+
+impl ReconnectOptions {
+    /// The delay before the next attempt to reconnect, after waiting `backoff` before the last one.
+    fn next_backoff(&self, backoff: Duration) -> Duration {
+        (backoff * 2).min(self.max_backoff)
+    }
+}
+
+impl Default for ReconnectOptions {
+    fn default() -> Self {
+        Self {
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(5),
+            max_buffer_bytes: 256 * 1024 * 1024,
+            spill_path: None,
         }
     }
 }
@@ -45,11 +93,11 @@ pub struct Client {
 }
 
 impl Client {
-    #[expect(clippy::needless_pass_by_value)]
     pub fn new(uri: ProxyUri, options: Options) -> Self {
         let (cmd_tx, cmd_rx) = mpsc::unbounded_channel();
         let (shutdown_tx, shutdown_rx) = mpsc::channel(1);
 
+        let flush_timeout = options.flush_timeout;
         let thread = thread::Builder::new()
             .name("message_proxy_client".to_owned())
             .spawn(move || {
@@ -63,6 +111,7 @@ impl Client {
                         cmd_rx,
                         shutdown_rx,
                         options.compression,
+                        options.reconnect,
                     ));
             })
             .expect("Failed to spawn message proxy client thread");
@@ -71,7 +120,7 @@ impl Client {
             thread: Some(thread),
             cmd_tx,
             shutdown_tx,
-            flush_timeout: options.flush_timeout,
+            flush_timeout,
         }
     }
 
@@ -143,6 +192,7 @@ async fn message_proxy_client(
     mut cmd_rx: UnboundedReceiver<Cmd>,
     mut shutdown_rx: Receiver<()>,
     compression: Compression,
+    reconnect: ReconnectOptions,
 ) {
     let endpoint = match Endpoint::from_shared(uri.origin.as_url()) {
         Ok(endpoint) => endpoint,
@@ -152,79 +202,877 @@ async fn message_proxy_client(
         }
     };
 
-    let channel = loop {
-        match endpoint.connect().await {
-            Ok(channel) => break channel,
-            Err(err) => {
-                re_log::debug!("failed to connect to message proxy server: {err}");
-                tokio::select! {
-                    _ = shutdown_rx.recv() => {
-                        re_log::debug!("shutting down client without flush");
-                        return;
-                    }
-                    _ = tokio::time::sleep(Duration::from_millis(100)) => {
-                        continue;
-                    }
+    let mut queue = MessageQueue::new(&reconnect);
+    let mut has_connected_before = false;
+
+    loop {
+        let mut backoff = reconnect.initial_backoff;
+        let channel = loop {
+            let connection = queue_until(
+                endpoint.connect(),
+                &mut cmd_rx,
+                &mut shutdown_rx,
+                &mut queue,
+            )
+            .await;
+            match connection {
+                Some(Ok(channel)) => break channel,
+                Some(Err(err)) => {
+                    re_log::debug!("failed to connect to message proxy server: {err}");
+                }
+                None => {
+                    re_log::debug!("shutting down client without flush");
+                    queue.spill_remaining();
+                    return;
                 }
             }
+
+            let sleep = tokio::time::sleep(backoff);
+            if queue_until(sleep, &mut cmd_rx, &mut shutdown_rx, &mut queue)
+                .await
+                .is_none()
+            {
+                re_log::debug!("shutting down client without flush");
+                queue.spill_remaining();
+                return;
+            }
+            backoff = reconnect.next_backoff(backoff);
+        };
+
+        if has_connected_before {
+            re_log::info!("Reconnected to message proxy server at {uri}");
+            queue.prepare_replay();
         }
-    };
-    let mut client = MessageProxyServiceClient::new(channel)
-        .max_decoding_message_size(crate::MAX_DECODING_MESSAGE_SIZE);
+        has_connected_before = true;
 
-    let stream = async_stream::stream! {
-        loop {
-            tokio::select! {
-                cmd = cmd_rx.recv() => {
-                    match cmd {
-                        Some(Cmd::LogMsg(log_msg)) => {
-                            // Insert the timestamp metadata into the Arrow message for accurate e2e latency measurements:
-                            let log_msg = log_msg.with_record_batch_metadata(
-                                re_sorbet::timestamp_metadata::KEY_TIMESTAMP_SDK_IPC_ENCODE.to_owned(),
-                                re_sorbet::timestamp_metadata::now_timestamp(),
-                            );
+        let mut client = MessageProxyServiceClient::new(channel)
+            .max_decoding_message_size(crate::MAX_DECODING_MESSAGE_SIZE);
 
-                            let msg = match re_log_encoding::protobuf_conversions::log_msg_to_proto(log_msg, compression) {
-                                Ok(msg) => msg,
-                                Err(err) => {
-                                    re_log::error!("Failed to encode message: {err}");
-                                    break;
-                                }
-                            };
+        // Messages are handed over one at a time, so that whatever hasn't been picked up yet
+        // by the time the connection drops stays in the queue for the next connection.
+        // The ones that have been picked up are put back with `requeue_in_flight`.
+        let (msg_tx, msg_rx) = mpsc::channel(1);
+        let call = client.write_messages(tokio_stream::wrappers::ReceiverStream::new(msg_rx));
+        tokio::pin!(call);
 
-                            let msg = WriteMessagesRequest {
-                                log_msg: Some(msg),
-                            };
+        let mut cmd_rx_closed = false;
+        let result = loop {
+            if cmd_rx_closed && !queue.has_next() {
+                break None;
+            }
+
+            tokio::select! {
+                result = &mut call => break Some(result),
 
-                            yield msg;
+                permit = msg_tx.reserve(), if queue.has_next() => {
+                    let Ok(permit) = permit else {
+                        continue;
+                    };
+                    match queue.pop() {
+                        Some(Cmd::LogMsg(log_msg)) => {
+                            if let Some(msg) = encode(log_msg, compression) {
+                                permit.send(msg);
+                            }
                         }
 
                         Some(Cmd::Flush(tx)) => {
-                            // Messages are received in order, so once we receive a `flush`
+                            // Messages are queued in order, so once we reach a `flush`
                             // we know we've sent all messages before that flush through already.
                             re_log::debug!("Flush requested");
                             if tx.send(()).is_err() {
                                 re_log::debug!("Failed to respond to flush: channel is closed");
-                                return;
                             };
                         }
 
+                        None => {}
+                    }
+                }
+
+                cmd = cmd_rx.recv(), if !cmd_rx_closed => {
+                    match cmd {
+                        Some(cmd) => queue.push(cmd),
                         None => {
                             re_log::debug!("Channel closed");
-                            break;
+                            cmd_rx_closed = true;
                         }
                     }
                 }
 
                 _ = shutdown_rx.recv() => {
                     re_log::debug!("Shutting down client without flush");
+                    queue.spill_remaining();
                     return;
                 }
             }
+        };
+
+        match result {
+            None => {
+                // Everything has been handed over, let the call finish.
+                drop(msg_tx);
+                if let Err(err) = call.await {
+                    re_log::error!("Write messages call failed: {err}");
+                }
+                return;
+            }
+
+            Some(Ok(_)) => {
+                re_log::warn!(
+                    "Message proxy server at {uri} closed the connection, trying to reconnect…"
+                );
+            }
+
+            Some(Err(err)) => {
+                re_log::warn!(
+                    "Lost connection to message proxy server at {uri}, trying to reconnect… ({})",
+                    crate::TonicStatusError(err)
+                );
+            }
         }
-    };
 
-    if let Err(err) = client.write_messages(stream).await {
-        re_log::error!("Write messages call failed: {err}");
+        queue.requeue_in_flight();
+    }
+}
+
+/// Drives `future` to completion, queueing up any commands that arrive in the meantime.
+///
+/// Returns `None` if the client was asked to shut down first.
+async fn queue_until<F: Future>(
+    future: F,
+    cmd_rx: &mut UnboundedReceiver<Cmd>,
+    shutdown_rx: &mut Receiver<()>,
+    queue: &mut MessageQueue,
+) -> Option<F::Output> {
+    tokio::pin!(future);
+
+    let mut cmd_rx_closed = false;
+    loop {
+        tokio::select! {
+            output = &mut future => return Some(output),
+
+            cmd = cmd_rx.recv(), if !cmd_rx_closed => {
+                match cmd {
+                    Some(cmd) => queue.push(cmd),
+                    None => cmd_rx_closed = true,
+                }
+            }
+
+            _ = shutdown_rx.recv() => return None,
+        }
+    }
+}
+
+fn encode(log_msg: LogMsg, compression: Compression) -> Option<WriteMessagesRequest> {
+    // Insert the timestamp metadata into the Arrow message for accurate e2e latency measurements:
+    let log_msg = log_msg.with_record_batch_metadata(
+        re_sorbet::timestamp_metadata::KEY_TIMESTAMP_SDK_IPC_ENCODE.to_owned(),
+        re_sorbet::timestamp_metadata::now_timestamp(),
+    );
+
+    match re_log_encoding::protobuf_conversions::log_msg_to_proto(log_msg, compression) {
+        Ok(msg) => Some(WriteMessagesRequest { log_msg: Some(msg) }),
+        Err(err) => {
+            re_log::error!("Failed to encode message: {err}");
+            None
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// How many bytes worth of messages that have been handed over to a connection to hold on to,
+/// in case the connection drops before they've made it to the server.
+///
+/// This covers the messages buffered by the channel, `tonic` and the OS.
+const MAX_IN_FLIGHT_BYTES: u64 = 16 * 1024 * 1024;
+
+/// The most recently handed over messages are always held on to, no matter their size.
+const MIN_IN_FLIGHT_MESSAGES: usize = 2;
+
+enum Queued {
+    Msg {
+        msg: LogMsg,
+
+        /// Set for messages a viewer needs no matter when it connects, see [`persistent_key`].
+        persistent: Option<PersistentKey>,
+
+        /// Approximate size, only tracked for messages that aren't persistent.
+        size_bytes: u64,
+    },
+
+    /// A persistent message that is sent again after reconnecting.
+    Replay(LogMsg),
+
+    Flush(oneshot::Sender<()>),
+}
+
+/// Identifies what a persistent message is about.
+///
+/// A newer message with the same key supersedes the older one, so only the latest is replayed.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+enum PersistentKey {
+    StoreInfo(StoreId),
+
+    BlueprintActivation(StoreId),
+
+    /// Static data, or blueprint data, for the given components of an entity.
+    Chunk {
+        store_id: StoreId,
+        entity_path: EntityPath,
+        components: Vec<ComponentDescriptor>,
+    },
+
+    /// A blueprint chunk whose schema couldn't be read, which is never superseded.
+    UnknownChunk(ChunkId),
+}
+
+/// Everything that still has to be sent, in order.
+///
+/// Also remembers the messages needed to bring a newly connected viewer up to speed.
+struct MessageQueue {
+    pending: VecDeque<Queued>,
+
+    /// Total size of the messages in `pending` that may be dropped.
+    pending_bytes: u64,
+    max_bytes: u64,
+
+    /// Copies of the most recent messages handed over to the current connection, oldest first.
+    ///
+    /// The server doesn't acknowledge messages, so these are put back into the queue if the
+    /// connection drops. Sending a chunk twice is harmless: the viewer ignores chunks it has.
+    in_flight: VecDeque<(LogMsg, u64)>,
+    in_flight_bytes: u64,
+
+    /// The latest persistent messages that have been sent over a previous connection,
+    /// in the order they were first sent.
+    sent_persistent: Vec<LogMsg>,
+
+    /// Where the message for each key is in `sent_persistent`.
+    sent_persistent_index: HashMap<PersistentKey, usize>,
+
+    spill_path: Option<PathBuf>,
+
+    /// Created once the first message doesn't fit into the buffer anymore.
+    spill: Option<FileSink>,
+
+    num_dropped: u64,
+}
+
+impl MessageQueue {
+    fn new(options: &ReconnectOptions) -> Self {
+        Self {
+            pending: VecDeque::new(),
+            pending_bytes: 0,
+            max_bytes: options.max_buffer_bytes,
+            in_flight: VecDeque::new(),
+            in_flight_bytes: 0,
+            sent_persistent: Vec::new(),
+            sent_persistent_index: HashMap::new(),
+            spill_path: options.spill_path.clone(),
+            spill: None,
+            num_dropped: 0,
+        }
+    }
+
+    fn has_next(&self) -> bool {
+        !self.pending.is_empty()
+    }
+
+    fn push(&mut self, cmd: Cmd) {
+        match cmd {
+            Cmd::LogMsg(msg) => {
+                let persistent = persistent_key(&msg);
+                let size_bytes = if persistent.is_some() {
+                    if let Some(spill) = &self.spill {
+                        spill.send(without_release_callback(&msg));
+                    }
+                    0
+                } else {
+                    approx_size_bytes(&msg)
+                };
+
+                self.pending_bytes += size_bytes;
+                self.pending.push_back(Queued::Msg {
+                    msg,
+                    persistent,
+                    size_bytes,
+                });
+                self.enforce_budget();
+            }
+
+            Cmd::Flush(tx) => self.pending.push_back(Queued::Flush(tx)),
+        }
+    }
+
+    /// Takes the next command to hand over to the connection.
+    fn pop(&mut self) -> Option<Cmd> {
+        match self.pending.pop_front()? {
+            Queued::Msg {
+                msg,
+                persistent,
+                size_bytes,
+            } => {
+                self.pending_bytes -= size_bytes;
+                match persistent {
+                    Some(key) => self.remember_persistent(key, without_release_callback(&msg)),
+                    None => self.remember_in_flight(without_release_callback(&msg), size_bytes),
+                }
+                Some(Cmd::LogMsg(msg))
+            }
+
+            Queued::Replay(msg) => Some(Cmd::LogMsg(msg)),
+
+            Queued::Flush(tx) => Some(Cmd::Flush(tx)),
+        }
+    }
+
+    fn remember_persistent(&mut self, key: PersistentKey, msg: LogMsg) {
+        match self.sent_persistent_index.entry(key) {
+            Entry::Occupied(entry) => self.sent_persistent[*entry.get()] = msg,
+            Entry::Vacant(entry) => {
+                entry.insert(self.sent_persistent.len());
+                self.sent_persistent.push(msg);
+            }
+        }
+    }
+
+    fn remember_in_flight(&mut self, msg: LogMsg, size_bytes: u64) {
+        self.in_flight.push_back((msg, size_bytes));
+        self.in_flight_bytes += size_bytes;
+
+        let max_in_flight_bytes = self.max_bytes.min(MAX_IN_FLIGHT_BYTES);
+        while self.in_flight_bytes > max_in_flight_bytes
+            && self.in_flight.len() > MIN_IN_FLIGHT_MESSAGES
+        {
+            if let Some((_, size_bytes)) = self.in_flight.pop_front() {
+                self.in_flight_bytes -= size_bytes;
+            }
+        }
+    }
+
+    /// Puts the messages that were handed over to a connection that has since dropped back at the
+    /// front of the queue, since there's no telling whether they made it to the server.
+    fn requeue_in_flight(&mut self) {
+        let in_flight = std::mem::take(&mut self.in_flight);
+        self.in_flight_bytes = 0;
+
+        for (msg, size_bytes) in in_flight.into_iter().rev() {
+            self.pending_bytes += size_bytes;
+            self.pending.push_front(Queued::Msg {
+                msg,
+                persistent: None,
+                size_bytes,
+            });
+        }
+        self.enforce_budget();
+    }
+
+    /// Puts everything that was sent over previous connections and is needed to make sense of
+    /// the rest of the data back at the front of the queue.
+    ///
+    /// The viewer might have been restarted, in which case it has lost all of it.
+    fn prepare_replay(&mut self) {
+        if self.num_dropped > 0 {
+            re_log::warn!(
+                "Dropped {} messages that didn't fit into the buffer while disconnected",
+                self.num_dropped
+            );
+            self.num_dropped = 0;
+        }
+
+        // Left-overs from a connection that dropped while replaying.
+        self.pending
+            .retain(|queued| !matches!(queued, Queued::Replay(_)));
+
+        for msg in self.sent_persistent.iter().rev() {
+            self.pending
+                .push_front(Queued::Replay(without_release_callback(msg)));
+        }
+    }
+
+    fn enforce_budget(&mut self) {
+        while self.pending_bytes > self.max_bytes {
+            let Some(index) = self.pending.iter().position(|queued| {
+                matches!(
+                    queued,
+                    Queued::Msg {
+                        persistent: None,
+                        ..
+                    }
+                )
+            }) else {
+                break;
+            };
+            let Some(Queued::Msg {
+                msg, size_bytes, ..
+            }) = self.pending.remove(index)
+            else {
+                break;
+            };
+
+            self.pending_bytes -= size_bytes;
+            self.spill_or_drop(msg);
+        }
+    }
+
+    fn spill_or_drop(&mut self, msg: LogMsg) {
+        if self.spill.is_none() {
+            if let Some(path) = self.spill_path.take() {
+                match FileSink::new(path.clone()) {
+                    Ok(spill) => {
+                        re_log::warn!(
+                            "Message buffer is full, writing the oldest messages to {path:?} instead"
+                        );
+
+                        // Make sure the file can be opened on its own.
+                        let pending_persistent =
+                            self.pending.iter().filter_map(|queued| match queued {
+                                Queued::Msg {
+                                    msg,
+                                    persistent: Some(_),
+                                    ..
+                                } => Some(msg),
+                                Queued::Msg { .. } | Queued::Replay(_) | Queued::Flush(_) => None,
+                            });
+                        for msg in self.sent_persistent.iter().chain(pending_persistent) {
+                            spill.send(without_release_callback(msg));
+                        }
+
+                        self.spill = Some(spill);
+                    }
+                    Err(err) => {
+                        re_log::error!("Failed to create spill file for message buffer: {err}");
+                    }
+                }
+            }
+        }
+
+        if let Some(spill) = &self.spill {
+            spill.send(msg);
+        } else {
+            if self.num_dropped == 0 {
+                re_log::warn!(
+                    "Message buffer is full ({} bytes), dropping the oldest messages",
+                    self.max_bytes
+                );
+            }
+            self.num_dropped += 1;
+        }
+    }
+
+    /// Saves whatever couldn't be sent to the spill file, if there is one.
+    fn spill_remaining(&mut self) {
+        if self.spill.is_none() && self.spill_path.is_none() {
+            return;
+        }
+
+        let pending = std::mem::take(&mut self.pending);
+        for queued in pending {
+            if let Queued::Msg {
+                msg,
+                persistent: None,
+                ..
+            } = queued
+            {
+                self.spill_or_drop(msg);
+            }
+        }
+        self.pending_bytes = 0;
+    }
+}
+
+/// Store infos, blueprints and static data are needed by a viewer no matter when it connects,
+/// so they are never dropped.
+///
+/// Returns `None` for all other messages.
+fn persistent_key(msg: &LogMsg) -> Option<PersistentKey> {
+    match msg {
+        LogMsg::SetStoreInfo(msg) => Some(PersistentKey::StoreInfo(msg.info.store_id.clone())),
+
+        LogMsg::BlueprintActivationCommand(cmd) => {
+            Some(PersistentKey::BlueprintActivation(cmd.blueprint_id.clone()))
+        }
+
+        LogMsg::ArrowMsg(store_id, arrow_msg) => {
+            match re_sorbet::ChunkSchema::try_from(arrow_msg.batch.schema_ref().as_ref()) {
+                Ok(schema) if store_id.kind == StoreKind::Blueprint || schema.is_static() => {
+                    Some(PersistentKey::Chunk {
+                        store_id: store_id.clone(),
+                        entity_path: schema.entity_path().clone(),
+                        components: schema
+                            .columns
+                            .component_columns()
+                            .map(|column| column.component_descriptor())
+                            .collect(),
+                    })
+                }
+                Ok(_) => None,
+                Err(_) => (store_id.kind == StoreKind::Blueprint)
+                    .then(|| PersistentKey::UnknownChunk(ChunkId::from_tuid(arrow_msg.chunk_id))),
+            }
+        }
+    }
+}
+
+fn approx_size_bytes(msg: &LogMsg) -> u64 {
+    match msg {
+        LogMsg::ArrowMsg(_, arrow_msg) => arrow_msg.batch.get_array_memory_size() as u64,
+        LogMsg::SetStoreInfo(_) | LogMsg::BlueprintActivationCommand(_) => 0,
+    }
+}
+
+/// Copies are kept around for much longer than the original, which must stay the only one
+/// to release the batch back to its owner.
+fn without_release_callback(msg: &LogMsg) -> LogMsg {
+    match msg {
+        LogMsg::ArrowMsg(store_id, arrow_msg) => LogMsg::ArrowMsg(
+            store_id.clone(),
+            ArrowMsg {
+                chunk_id: arrow_msg.chunk_id,
+                batch: arrow_msg.batch.clone(),
+                on_release: None,
+            },
+        ),
+        LogMsg::SetStoreInfo(_) | LogMsg::BlueprintActivationCommand(_) => msg.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use arrow::array::{ArrayRef, Int32Array, RecordBatch};
+    use re_chunk::{Chunk, RowId, TimePoint, Timeline};
+    use re_log_types::{
+        ApplicationId, ArrowRecordBatchReleaseCallback, BlueprintActivationCommand, SetStoreInfo,
+        StoreInfo, StoreSource,
     };
+
+    use super::*;
+
+    fn recording() -> StoreId {
+        StoreId::from_string(StoreKind::Recording, "recording".to_owned())
+    }
+
+    fn blueprint() -> StoreId {
+        StoreId::from_string(StoreKind::Blueprint, "blueprint".to_owned())
+    }
+
+    fn store_info(store_id: &StoreId) -> LogMsg {
+        LogMsg::SetStoreInfo(SetStoreInfo {
+            row_id: *RowId::new(),
+            info: StoreInfo {
+                application_id: ApplicationId("test".to_owned()),
+                store_id: store_id.clone(),
+                cloned_from: None,
+                store_source: StoreSource::Unknown,
+                store_version: None,
+            },
+        })
+    }
+
+    fn activation(store_id: &StoreId) -> LogMsg {
+        LogMsg::BlueprintActivationCommand(BlueprintActivationCommand {
+            blueprint_id: store_id.clone(),
+            make_active: true,
+            make_default: true,
+        })
+    }
+
+    /// A chunk with a single `value` for `entity_path`, static if there's no `frame`.
+    fn chunk(store_id: &StoreId, entity_path: &str, frame: Option<i64>, value: i32) -> LogMsg {
+        let timepoint = frame.map_or_else(TimePoint::default, |frame| {
+            TimePoint::default().with(Timeline::new_sequence("frame"), frame)
+        });
+        let values: ArrayRef = Arc::new(Int32Array::from(vec![value; 64]));
+        let chunk = Chunk::builder(entity_path.into())
+            .with_row(
+                RowId::new(),
+                timepoint,
+                [(ComponentDescriptor::partial("value"), values)],
+            )
+            .build()
+            .unwrap();
+        LogMsg::ArrowMsg(store_id.clone(), chunk.to_arrow_msg().unwrap())
+    }
+
+    /// Something to tell messages apart by.
+    fn label(msg: &LogMsg) -> String {
+        match msg {
+            LogMsg::SetStoreInfo(msg) => format!("info:{}", msg.info.store_id),
+            LogMsg::BlueprintActivationCommand(cmd) => format!("activate:{}", cmd.blueprint_id),
+            LogMsg::ArrowMsg(_, arrow_msg) => arrow_msg.chunk_id.to_string(),
+        }
+    }
+
+    /// Pops everything that is currently queued, as if handed over to a connection.
+    fn pop_all(queue: &mut MessageQueue) -> Vec<String> {
+        std::iter::from_fn(|| queue.pop())
+            .map(|cmd| match cmd {
+                Cmd::LogMsg(msg) => label(&msg),
+                Cmd::Flush(_) => "flush".to_owned(),
+            })
+            .collect()
+    }
+
+    fn new_queue(max_buffer_bytes: u64) -> MessageQueue {
+        MessageQueue::new(&ReconnectOptions {
+            max_buffer_bytes,
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn backoff_doubles_up_to_max() {
+        let options = ReconnectOptions::default();
+        let backoffs = std::iter::successors(Some(options.initial_backoff), |backoff| {
+            Some(options.next_backoff(*backoff))
+        })
+        .take(8)
+        .map(|backoff| backoff.as_millis())
+        .collect::<Vec<_>>();
+        assert_eq!(backoffs, [100, 200, 400, 800, 1600, 3200, 5000, 5000]);
+    }
+
+    #[test]
+    fn persistent_messages() {
+        let static_a = chunk(&recording(), "a", None, 1);
+        let static_a_again = chunk(&recording(), "a", None, 2);
+        let static_b = chunk(&recording(), "b", None, 1);
+        let temporal_a = chunk(&recording(), "a", Some(0), 1);
+        let blueprint_a = chunk(&blueprint(), "a", Some(0), 1);
+
+        assert_eq!(
+            persistent_key(&store_info(&recording())),
+            Some(PersistentKey::StoreInfo(recording()))
+        );
+        assert_eq!(
+            persistent_key(&activation(&blueprint())),
+            Some(PersistentKey::BlueprintActivation(blueprint()))
+        );
+        assert!(persistent_key(&static_a).is_some());
+        assert!(persistent_key(&blueprint_a).is_some());
+        assert!(persistent_key(&temporal_a).is_none());
+
+        // Newer static data for the same components supersedes the older:
+        assert_eq!(persistent_key(&static_a), persistent_key(&static_a_again));
+        assert_ne!(persistent_key(&static_a), persistent_key(&static_b));
+    }
+
+    #[test]
+    fn copies_dont_release_the_batch() {
+        let num_released = Arc::new(AtomicUsize::new(0));
+        let LogMsg::ArrowMsg(store_id, arrow_msg) = chunk(&recording(), "a", Some(0), 1) else {
+            unreachable!();
+        };
+        let original = LogMsg::ArrowMsg(
+            store_id,
+            ArrowMsg {
+                chunk_id: arrow_msg.chunk_id,
+                batch: arrow_msg.batch.clone(),
+                on_release: Some({
+                    let num_released = num_released.clone();
+                    ArrowRecordBatchReleaseCallback::from(move |_: RecordBatch| {
+                        num_released.fetch_add(1, Ordering::Relaxed);
+                    })
+                }),
+            },
+        );
+
+        let copy = without_release_callback(&original);
+        assert_eq!(label(&copy), label(&original));
+        drop(copy);
+        assert_eq!(num_released.load(Ordering::Relaxed), 0);
+        drop(original);
+        assert_eq!(num_released.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn keeps_order() {
+        let mut queue = new_queue(u64::MAX);
+        let msgs = [
+            store_info(&recording()),
+            chunk(&recording(), "a", None, 1),
+            chunk(&recording(), "a", Some(0), 1),
+            chunk(&recording(), "a", Some(1), 1),
+        ];
+        let labels = msgs.iter().map(label).collect::<Vec<_>>();
+
+        let (flush_tx, _flush_rx) = oneshot::channel();
+        for msg in msgs {
+            queue.push(Cmd::LogMsg(msg));
+        }
+        queue.push(Cmd::Flush(flush_tx));
+
+        assert_eq!(
+            pop_all(&mut queue),
+            [&labels[..], &["flush".to_owned()]].concat()
+        );
+        assert!(!queue.has_next());
+    }
+
+    #[test]
+    fn replays_after_reconnect() {
+        let mut queue = new_queue(u64::MAX);
+        let info = store_info(&recording());
+        let static_a = chunk(&recording(), "a", None, 1);
+        let first = chunk(&recording(), "a", Some(0), 1);
+        let second = chunk(&recording(), "a", Some(1), 1);
+        let third = chunk(&recording(), "a", Some(2), 1);
+        let [info, static_a, first, second, third] =
+            [info, static_a, first, second, third].map(|msg| {
+                let msg_label = label(&msg);
+                queue.push(Cmd::LogMsg(msg));
+                msg_label
+            });
+
+        // First connection: everything except the last message is handed over before it drops.
+        let handed_over = std::iter::from_fn(|| queue.pop())
+            .take(4)
+            .collect::<Vec<_>>();
+        assert_eq!(handed_over.len(), 4);
+        queue.requeue_in_flight();
+        queue.prepare_replay();
+
+        // The persistent messages come first, then the ones that were in flight, then the rest.
+        assert_eq!(
+            pop_all(&mut queue),
+            [&info, &static_a, &first, &second, &third].map(String::clone)
+        );
+
+        // Second connection drops after everything was handed over.
+        queue.requeue_in_flight();
+        queue.prepare_replay();
+        assert_eq!(
+            pop_all(&mut queue),
+            [&info, &static_a, &first, &second, &third].map(String::clone)
+        );
+    }
+
+    #[test]
+    fn replays_only_the_latest_persistent_messages() {
+        let mut queue = new_queue(u64::MAX);
+        let msgs = [
+            store_info(&recording()),
+            chunk(&recording(), "a", None, 1),
+            chunk(&recording(), "b", None, 1),
+            store_info(&recording()),
+            chunk(&recording(), "a", None, 2),
+        ];
+        let labels = msgs.iter().map(label).collect::<Vec<_>>();
+        for msg in msgs {
+            queue.push(Cmd::LogMsg(msg));
+        }
+        assert_eq!(pop_all(&mut queue), labels);
+
+        for _ in 0..3 {
+            queue.requeue_in_flight();
+            queue.prepare_replay();
+            assert_eq!(
+                pop_all(&mut queue),
+                [&labels[3], &labels[4], &labels[2]].map(String::clone),
+                "newer messages take the place of the ones they supersede"
+            );
+        }
+        assert_eq!(queue.sent_persistent.len(), 3);
+    }
+
+    #[test]
+    fn drops_oldest_when_over_budget() {
+        let size_bytes = approx_size_bytes(&chunk(&recording(), "a", Some(0), 1));
+        let mut queue = new_queue(3 * size_bytes);
+
+        let info = label(&store_info(&recording()));
+        queue.push(Cmd::LogMsg(store_info(&recording())));
+        let labels = (0..5)
+            .map(|frame| {
+                let msg = chunk(&recording(), "a", Some(frame), 1);
+                let msg_label = label(&msg);
+                queue.push(Cmd::LogMsg(msg));
+                msg_label
+            })
+            .collect::<Vec<_>>();
+
+        assert!(queue.pending_bytes <= queue.max_bytes);
+        assert_eq!(queue.num_dropped, 2);
+        assert_eq!(
+            pop_all(&mut queue),
+            [&info, &labels[2], &labels[3], &labels[4]].map(String::clone)
+        );
+    }
+
+    #[test]
+    fn bounds_in_flight_messages() {
+        let size_bytes = approx_size_bytes(&chunk(&recording(), "a", Some(0), 1));
+        let mut queue = new_queue(3 * size_bytes);
+
+        for frame in 0..10 {
+            queue.push(Cmd::LogMsg(chunk(&recording(), "a", Some(frame), 1)));
+            queue.pop();
+        }
+        assert_eq!(queue.in_flight.len(), 3);
+        assert_eq!(queue.in_flight_bytes, 3 * size_bytes);
+
+        // The most recent messages are kept even if they're larger than the budget.
+        let mut queue = new_queue(size_bytes);
+        for frame in 0..10 {
+            queue.push(Cmd::LogMsg(chunk(&recording(), "a", Some(frame), 1)));
+            queue.pop();
+        }
+        assert_eq!(queue.in_flight.len(), MIN_IN_FLIGHT_MESSAGES);
+    }
+
+    fn block_on<F: Future>(future: F) -> F::Output {
+        runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(future)
+    }
+
+    #[test]
+    fn queues_commands_until_done() {
+        let (cmd_tx, mut cmd_rx) = mpsc::unbounded_channel();
+        let (_shutdown_tx, mut shutdown_rx) = mpsc::channel(1);
+        let mut queue = new_queue(u64::MAX);
+
+        let labels = (0..3)
+            .map(|frame| {
+                let msg = chunk(&recording(), "a", Some(frame), 1);
+                let msg_label = label(&msg);
+                cmd_tx.send(Cmd::LogMsg(msg)).ok();
+                msg_label
+            })
+            .collect::<Vec<_>>();
+        drop(cmd_tx);
+
+        let output = block_on(queue_until(
+            async {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+                42
+            },
+            &mut cmd_rx,
+            &mut shutdown_rx,
+            &mut queue,
+        ));
+        assert_eq!(output, Some(42));
+        assert_eq!(pop_all(&mut queue), labels);
+    }
+
+    #[test]
+    fn queue_until_stops_on_shutdown() {
+        let (_cmd_tx, mut cmd_rx) = mpsc::unbounded_channel();
+        let (shutdown_tx, mut shutdown_rx) = mpsc::channel(1);
+        let mut queue = new_queue(u64::MAX);
+
+        shutdown_tx.try_send(()).unwrap();
+        let output = block_on(queue_until(
+            std::future::pending::<()>(),
+            &mut cmd_rx,
+            &mut shutdown_rx,
+            &mut queue,
+        ));
+        assert_eq!(output, None);
+    }
 }
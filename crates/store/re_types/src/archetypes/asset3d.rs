@@ -26,6 +26,9 @@ use ::re_types_core::{DeserializationError, DeserializationResult};
 /// If there are multiple [`archetypes::InstancePoses3D`][crate::archetypes::InstancePoses3D] instances logged to the same entity as a mesh,
 /// an instance of the mesh will be drawn for each transform.
 ///
+/// If the asset contains animations, its first animation is played back in a loop on temporal timelines,
+/// starting at the time the asset was logged. Any further animations in the asset are ignored.
+///
 /// ## Example
 ///
 /// ### Simple 3D asset
@@ -0,0 +1,658 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/archetypes/encoded_depth_image.fbs".
+
+#![allow(unused_braces)]
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::SerializationResult;
+use ::re_types_core::{ComponentBatch as _, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentName};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Archetype**: A depth image encoded as e.g. a 16-bit PNG or an EXR.
+///
+/// Each pixel corresponds to a depth value in units specified by [`components::DepthMeter`][crate::components::DepthMeter].
+/// The decoded image must have a single channel.
+///
+/// Rerun also supports uncompressed depth images with the [`archetypes::DepthImage`][crate::archetypes::DepthImage].
+///
+/// ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct EncodedDepthImage {
+    /// The encoded content of some image file, e.g. a PNG or EXR.
+    pub blob: Option<SerializedComponentBatch>,
+
+    /// The Media Type of the asset.
+    ///
+    /// Supported values:
+    /// * `image/png`
+    /// * `image/x-exr`
+    ///
+    /// If omitted, the viewer will try to guess from the data blob.
+    /// If it cannot guess, it won't be able to render the asset.
+    pub media_type: Option<SerializedComponentBatch>,
+
+    /// An optional floating point value that specifies how long a meter is in the native depth units.
+    ///
+    /// For instance: with a 16-bit PNG, perhaps meter=1000 which would mean you have millimeter precision
+    /// and a range of up to ~65 meters (2^16 / 1000).
+    ///
+    /// Note that the only effect on 2D views is the physical depth values shown when hovering the image.
+    /// In 3D views on the other hand, this affects where the points of the point cloud are placed.
+    pub meter: Option<SerializedComponentBatch>,
+
+    /// Colormap to use for rendering the depth image.
+    ///
+    /// If not set, the depth image will be rendered using the Turbo colormap.
+    pub colormap: Option<SerializedComponentBatch>,
+
+    /// The expected range of depth values.
+    ///
+    /// This is typically the expected range of valid values.
+    /// Everything outside of the range is clamped to the range for the purpose of colormpaping.
+    /// Note that point clouds generated from this image will still display all points, regardless of this range.
+    ///
+    /// If not specified, the range will be automatically estimated from the data.
+    pub depth_range: Option<SerializedComponentBatch>,
+
+    /// Scale the radii of the points in the point cloud generated from this image.
+    ///
+    /// A fill ratio of 1.0 (the default) means that each point is as big as to touch the center of its neighbor
+    /// if it is at the same depth, leaving no gaps.
+    /// A fill ratio of 0.5 means that each point touches the edge of its neighbor if it has the same depth.
+    ///
+    /// TODO(#6744): This applies only to 3D views!
+    pub point_fill_ratio: Option<SerializedComponentBatch>,
+
+    /// An optional floating point value that specifies the 2D drawing order, used only if the depth image is shown as a 2D image.
+    ///
+    /// Objects with higher values are drawn on top of those with lower values.
+    /// Defaults to `-20.0`.
+    pub draw_order: Option<SerializedComponentBatch>,
+}
+
+impl EncodedDepthImage {
+    /// Returns the [`ComponentDescriptor`] for [`Self::blob`].
+    ///
+    /// The corresponding component is [`crate::components::Blob`].
+    #[inline]
+    pub fn descriptor_blob() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.archetypes.EncodedDepthImage".into()),
+            component_name: Some("rerun.components.Blob".into()),
+            archetype_field_name: "blob".into(),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::media_type`].
+    ///
+    /// The corresponding component is [`crate::components::MediaType`].
+    #[inline]
+    pub fn descriptor_media_type() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.archetypes.EncodedDepthImage".into()),
+            component_name: Some("rerun.components.MediaType".into()),
+            archetype_field_name: "media_type".into(),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::meter`].
+    ///
+    /// The corresponding component is [`crate::components::DepthMeter`].
+    #[inline]
+    pub fn descriptor_meter() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.archetypes.EncodedDepthImage".into()),
+            component_name: Some("rerun.components.DepthMeter".into()),
+            archetype_field_name: "meter".into(),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::colormap`].
+    ///
+    /// The corresponding component is [`crate::components::Colormap`].
+    #[inline]
+    pub fn descriptor_colormap() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.archetypes.EncodedDepthImage".into()),
+            component_name: Some("rerun.components.Colormap".into()),
+            archetype_field_name: "colormap".into(),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::depth_range`].
+    ///
+    /// The corresponding component is [`crate::components::ValueRange`].
+    #[inline]
+    pub fn descriptor_depth_range() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.archetypes.EncodedDepthImage".into()),
+            component_name: Some("rerun.components.ValueRange".into()),
+            archetype_field_name: "depth_range".into(),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::point_fill_ratio`].
+    ///
+    /// The corresponding component is [`crate::components::FillRatio`].
+    #[inline]
+    pub fn descriptor_point_fill_ratio() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.archetypes.EncodedDepthImage".into()),
+            component_name: Some("rerun.components.FillRatio".into()),
+            archetype_field_name: "point_fill_ratio".into(),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::draw_order`].
+    ///
+    /// The corresponding component is [`crate::components::DrawOrder`].
+    #[inline]
+    pub fn descriptor_draw_order() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.archetypes.EncodedDepthImage".into()),
+            component_name: Some("rerun.components.DrawOrder".into()),
+            archetype_field_name: "draw_order".into(),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for the associated indicator component.
+    #[inline]
+    pub fn descriptor_indicator() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: None,
+            component_name: None,
+            archetype_field_name: "rerun.components.EncodedDepthImageIndicator".into(),
+        }
+    }
+}
+
+static REQUIRED_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 1usize]> =
+    once_cell::sync::Lazy::new(|| [EncodedDepthImage::descriptor_blob()]);
+
+static RECOMMENDED_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 2usize]> =
+    once_cell::sync::Lazy::new(|| {
+        [
+            EncodedDepthImage::descriptor_media_type(),
+            EncodedDepthImage::descriptor_indicator(),
+        ]
+    });
+
+static OPTIONAL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 5usize]> =
+    once_cell::sync::Lazy::new(|| {
+        [
+            EncodedDepthImage::descriptor_meter(),
+            EncodedDepthImage::descriptor_colormap(),
+            EncodedDepthImage::descriptor_depth_range(),
+            EncodedDepthImage::descriptor_point_fill_ratio(),
+            EncodedDepthImage::descriptor_draw_order(),
+        ]
+    });
+
+static ALL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 8usize]> =
+    once_cell::sync::Lazy::new(|| {
+        [
+            EncodedDepthImage::descriptor_blob(),
+            EncodedDepthImage::descriptor_media_type(),
+            EncodedDepthImage::descriptor_indicator(),
+            EncodedDepthImage::descriptor_meter(),
+            EncodedDepthImage::descriptor_colormap(),
+            EncodedDepthImage::descriptor_depth_range(),
+            EncodedDepthImage::descriptor_point_fill_ratio(),
+            EncodedDepthImage::descriptor_draw_order(),
+        ]
+    });
+
+impl EncodedDepthImage {
+    /// The total number of components in the archetype: 1 required, 2 recommended, 5 optional
+    pub const NUM_COMPONENTS: usize = 8usize;
+}
+
+/// Indicator component for the [`EncodedDepthImage`] [`::re_types_core::Archetype`]
+pub type EncodedDepthImageIndicator = ::re_types_core::GenericIndicatorComponent<EncodedDepthImage>;
+
+impl ::re_types_core::Archetype for EncodedDepthImage {
+    type Indicator = EncodedDepthImageIndicator;
+
+    #[inline]
+    fn name() -> ::re_types_core::ArchetypeName {
+        "rerun.archetypes.EncodedDepthImage".into()
+    }
+
+    #[inline]
+    fn display_name() -> &'static str {
+        "Encoded depth image"
+    }
+
+    #[inline]
+    fn indicator() -> SerializedComponentBatch {
+        #[allow(clippy::unwrap_used)]
+        EncodedDepthImageIndicator::DEFAULT
+            .serialized(Self::descriptor_indicator())
+            .unwrap()
+    }
+
+    #[inline]
+    fn required_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        REQUIRED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn recommended_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        RECOMMENDED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn optional_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        OPTIONAL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn all_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        ALL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn from_arrow_components(
+        arrow_data: impl IntoIterator<Item = (ComponentDescriptor, arrow::array::ArrayRef)>,
+    ) -> DeserializationResult<Self> {
+        re_tracing::profile_function!();
+        use ::re_types_core::{Loggable as _, ResultExt as _};
+        let arrays_by_descr: ::nohash_hasher::IntMap<_, _> = arrow_data.into_iter().collect();
+        let blob = arrays_by_descr
+            .get(&Self::descriptor_blob())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_blob()));
+        let media_type = arrays_by_descr
+            .get(&Self::descriptor_media_type())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_media_type())
+            });
+        let meter = arrays_by_descr
+            .get(&Self::descriptor_meter())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_meter()));
+        let colormap = arrays_by_descr
+            .get(&Self::descriptor_colormap())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_colormap()));
+        let depth_range = arrays_by_descr
+            .get(&Self::descriptor_depth_range())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_depth_range())
+            });
+        let point_fill_ratio = arrays_by_descr
+            .get(&Self::descriptor_point_fill_ratio())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_point_fill_ratio())
+            });
+        let draw_order = arrays_by_descr
+            .get(&Self::descriptor_draw_order())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_draw_order())
+            });
+        Ok(Self {
+            blob,
+            media_type,
+            meter,
+            colormap,
+            depth_range,
+            point_fill_ratio,
+            draw_order,
+        })
+    }
+}
+
+impl ::re_types_core::AsComponents for EncodedDepthImage {
+    #[inline]
+    fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
+        use ::re_types_core::Archetype as _;
+        [
+            Some(Self::indicator()),
+            self.blob.clone(),
+            self.media_type.clone(),
+            self.meter.clone(),
+            self.colormap.clone(),
+            self.depth_range.clone(),
+            self.point_fill_ratio.clone(),
+            self.draw_order.clone(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+impl ::re_types_core::ArchetypeReflectionMarker for EncodedDepthImage {}
+
+impl EncodedDepthImage {
+    /// Create a new `EncodedDepthImage`.
+    #[inline]
+    pub fn new(blob: impl Into<crate::components::Blob>) -> Self {
+        Self {
+            blob: try_serialize_field(Self::descriptor_blob(), [blob]),
+            media_type: None,
+            meter: None,
+            colormap: None,
+            depth_range: None,
+            point_fill_ratio: None,
+            draw_order: None,
+        }
+    }
+
+    /// Update only some specific fields of a `EncodedDepthImage`.
+    #[inline]
+    pub fn update_fields() -> Self {
+        Self::default()
+    }
+
+    /// Clear all the fields of a `EncodedDepthImage`.
+    #[inline]
+    pub fn clear_fields() -> Self {
+        use ::re_types_core::Loggable as _;
+        Self {
+            blob: Some(SerializedComponentBatch::new(
+                crate::components::Blob::arrow_empty(),
+                Self::descriptor_blob(),
+            )),
+            media_type: Some(SerializedComponentBatch::new(
+                crate::components::MediaType::arrow_empty(),
+                Self::descriptor_media_type(),
+            )),
+            meter: Some(SerializedComponentBatch::new(
+                crate::components::DepthMeter::arrow_empty(),
+                Self::descriptor_meter(),
+            )),
+            colormap: Some(SerializedComponentBatch::new(
+                crate::components::Colormap::arrow_empty(),
+                Self::descriptor_colormap(),
+            )),
+            depth_range: Some(SerializedComponentBatch::new(
+                crate::components::ValueRange::arrow_empty(),
+                Self::descriptor_depth_range(),
+            )),
+            point_fill_ratio: Some(SerializedComponentBatch::new(
+                crate::components::FillRatio::arrow_empty(),
+                Self::descriptor_point_fill_ratio(),
+            )),
+            draw_order: Some(SerializedComponentBatch::new(
+                crate::components::DrawOrder::arrow_empty(),
+                Self::descriptor_draw_order(),
+            )),
+        }
+    }
+
+    /// Partitions the component data into multiple sub-batches.
+    ///
+    /// Specifically, this transforms the existing [`SerializedComponentBatch`]es data into [`SerializedComponentColumn`]s
+    /// instead, via [`SerializedComponentBatch::partitioned`].
+    ///
+    /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+    ///
+    /// The specified `lengths` must sum to the total length of the component batch.
+    ///
+    /// [`SerializedComponentColumn`]: [::re_types_core::SerializedComponentColumn]
+    #[inline]
+    pub fn columns<I>(
+        self,
+        _lengths: I,
+    ) -> SerializationResult<impl Iterator<Item = ::re_types_core::SerializedComponentColumn>>
+    where
+        I: IntoIterator<Item = usize> + Clone,
+    {
+        let columns = [
+            self.blob
+                .map(|blob| blob.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.media_type
+                .map(|media_type| media_type.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.meter
+                .map(|meter| meter.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.colormap
+                .map(|colormap| colormap.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.depth_range
+                .map(|depth_range| depth_range.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.point_fill_ratio
+                .map(|point_fill_ratio| point_fill_ratio.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.draw_order
+                .map(|draw_order| draw_order.partitioned(_lengths.clone()))
+                .transpose()?,
+        ];
+        Ok(columns
+            .into_iter()
+            .flatten()
+            .chain([::re_types_core::indicator_column::<Self>(
+                _lengths.into_iter().count(),
+            )?]))
+    }
+
+    /// Helper to partition the component data into unit-length sub-batches.
+    ///
+    /// This is semantically similar to calling [`Self::columns`] with `std::iter::take(1).repeat(n)`,
+    /// where `n` is automatically guessed.
+    #[inline]
+    pub fn columns_of_unit_batches(
+        self,
+    ) -> SerializationResult<impl Iterator<Item = ::re_types_core::SerializedComponentColumn>> {
+        let len_blob = self.blob.as_ref().map(|b| b.array.len());
+        let len_media_type = self.media_type.as_ref().map(|b| b.array.len());
+        let len_meter = self.meter.as_ref().map(|b| b.array.len());
+        let len_colormap = self.colormap.as_ref().map(|b| b.array.len());
+        let len_depth_range = self.depth_range.as_ref().map(|b| b.array.len());
+        let len_point_fill_ratio = self.point_fill_ratio.as_ref().map(|b| b.array.len());
+        let len_draw_order = self.draw_order.as_ref().map(|b| b.array.len());
+        let len = None
+            .or(len_blob)
+            .or(len_media_type)
+            .or(len_meter)
+            .or(len_colormap)
+            .or(len_depth_range)
+            .or(len_point_fill_ratio)
+            .or(len_draw_order)
+            .unwrap_or(0);
+        self.columns(std::iter::repeat(1).take(len))
+    }
+
+    /// The encoded content of some image file, e.g. a PNG or EXR.
+    #[inline]
+    pub fn with_blob(mut self, blob: impl Into<crate::components::Blob>) -> Self {
+        self.blob = try_serialize_field(Self::descriptor_blob(), [blob]);
+        self
+    }
+
+    /// This method makes it possible to pack multiple [`crate::components::Blob`] in a single component batch.
+    ///
+    /// This only makes sense when used in conjunction with [`Self::columns`]. [`Self::with_blob`] should
+    /// be used when logging a single row's worth of data.
+    #[inline]
+    pub fn with_many_blob(
+        mut self,
+        blob: impl IntoIterator<Item = impl Into<crate::components::Blob>>,
+    ) -> Self {
+        self.blob = try_serialize_field(Self::descriptor_blob(), blob);
+        self
+    }
+
+    /// The Media Type of the asset.
+    ///
+    /// Supported values:
+    /// * `image/png`
+    /// * `image/x-exr`
+    ///
+    /// If omitted, the viewer will try to guess from the data blob.
+    /// If it cannot guess, it won't be able to render the asset.
+    #[inline]
+    pub fn with_media_type(mut self, media_type: impl Into<crate::components::MediaType>) -> Self {
+        self.media_type = try_serialize_field(Self::descriptor_media_type(), [media_type]);
+        self
+    }
+
+    /// This method makes it possible to pack multiple [`crate::components::MediaType`] in a single component batch.
+    ///
+    /// This only makes sense when used in conjunction with [`Self::columns`]. [`Self::with_media_type`] should
+    /// be used when logging a single row's worth of data.
+    #[inline]
+    pub fn with_many_media_type(
+        mut self,
+        media_type: impl IntoIterator<Item = impl Into<crate::components::MediaType>>,
+    ) -> Self {
+        self.media_type = try_serialize_field(Self::descriptor_media_type(), media_type);
+        self
+    }
+
+    /// An optional floating point value that specifies how long a meter is in the native depth units.
+    ///
+    /// For instance: with a 16-bit PNG, perhaps meter=1000 which would mean you have millimeter precision
+    /// and a range of up to ~65 meters (2^16 / 1000).
+    ///
+    /// Note that the only effect on 2D views is the physical depth values shown when hovering the image.
+    /// In 3D views on the other hand, this affects where the points of the point cloud are placed.
+    #[inline]
+    pub fn with_meter(mut self, meter: impl Into<crate::components::DepthMeter>) -> Self {
+        self.meter = try_serialize_field(Self::descriptor_meter(), [meter]);
+        self
+    }
+
+    /// This method makes it possible to pack multiple [`crate::components::DepthMeter`] in a single component batch.
+    ///
+    /// This only makes sense when used in conjunction with [`Self::columns`]. [`Self::with_meter`] should
+    /// be used when logging a single row's worth of data.
+    #[inline]
+    pub fn with_many_meter(
+        mut self,
+        meter: impl IntoIterator<Item = impl Into<crate::components::DepthMeter>>,
+    ) -> Self {
+        self.meter = try_serialize_field(Self::descriptor_meter(), meter);
+        self
+    }
+
+    /// Colormap to use for rendering the depth image.
+    ///
+    /// If not set, the depth image will be rendered using the Turbo colormap.
+    #[inline]
+    pub fn with_colormap(mut self, colormap: impl Into<crate::components::Colormap>) -> Self {
+        self.colormap = try_serialize_field(Self::descriptor_colormap(), [colormap]);
+        self
+    }
+
+    /// This method makes it possible to pack multiple [`crate::components::Colormap`] in a single component batch.
+    ///
+    /// This only makes sense when used in conjunction with [`Self::columns`]. [`Self::with_colormap`] should
+    /// be used when logging a single row's worth of data.
+    #[inline]
+    pub fn with_many_colormap(
+        mut self,
+        colormap: impl IntoIterator<Item = impl Into<crate::components::Colormap>>,
+    ) -> Self {
+        self.colormap = try_serialize_field(Self::descriptor_colormap(), colormap);
+        self
+    }
+
+    /// The expected range of depth values.
+    ///
+    /// This is typically the expected range of valid values.
+    /// Everything outside of the range is clamped to the range for the purpose of colormpaping.
+    /// Note that point clouds generated from this image will still display all points, regardless of this range.
+    ///
+    /// If not specified, the range will be automatically estimated from the data.
+    #[inline]
+    pub fn with_depth_range(
+        mut self,
+        depth_range: impl Into<crate::components::ValueRange>,
+    ) -> Self {
+        self.depth_range = try_serialize_field(Self::descriptor_depth_range(), [depth_range]);
+        self
+    }
+
+    /// This method makes it possible to pack multiple [`crate::components::ValueRange`] in a single component batch.
+    ///
+    /// This only makes sense when used in conjunction with [`Self::columns`]. [`Self::with_depth_range`] should
+    /// be used when logging a single row's worth of data.
+    #[inline]
+    pub fn with_many_depth_range(
+        mut self,
+        depth_range: impl IntoIterator<Item = impl Into<crate::components::ValueRange>>,
+    ) -> Self {
+        self.depth_range = try_serialize_field(Self::descriptor_depth_range(), depth_range);
+        self
+    }
+
+    /// Scale the radii of the points in the point cloud generated from this image.
+    ///
+    /// A fill ratio of 1.0 (the default) means that each point is as big as to touch the center of its neighbor
+    /// if it is at the same depth, leaving no gaps.
+    /// A fill ratio of 0.5 means that each point touches the edge of its neighbor if it has the same depth.
+    ///
+    /// TODO(#6744): This applies only to 3D views!
+    #[inline]
+    pub fn with_point_fill_ratio(
+        mut self,
+        point_fill_ratio: impl Into<crate::components::FillRatio>,
+    ) -> Self {
+        self.point_fill_ratio =
+            try_serialize_field(Self::descriptor_point_fill_ratio(), [point_fill_ratio]);
+        self
+    }
+
+    /// This method makes it possible to pack multiple [`crate::components::FillRatio`] in a single component batch.
+    ///
+    /// This only makes sense when used in conjunction with [`Self::columns`]. [`Self::with_point_fill_ratio`] should
+    /// be used when logging a single row's worth of data.
+    #[inline]
+    pub fn with_many_point_fill_ratio(
+        mut self,
+        point_fill_ratio: impl IntoIterator<Item = impl Into<crate::components::FillRatio>>,
+    ) -> Self {
+        self.point_fill_ratio =
+            try_serialize_field(Self::descriptor_point_fill_ratio(), point_fill_ratio);
+        self
+    }
+
+    /// An optional floating point value that specifies the 2D drawing order, used only if the depth image is shown as a 2D image.
+    ///
+    /// Objects with higher values are drawn on top of those with lower values.
+    /// Defaults to `-20.0`.
+    #[inline]
+    pub fn with_draw_order(mut self, draw_order: impl Into<crate::components::DrawOrder>) -> Self {
+        self.draw_order = try_serialize_field(Self::descriptor_draw_order(), [draw_order]);
+        self
+    }
+
+    /// This method makes it possible to pack multiple [`crate::components::DrawOrder`] in a single component batch.
+    ///
+    /// This only makes sense when used in conjunction with [`Self::columns`]. [`Self::with_draw_order`] should
+    /// be used when logging a single row's worth of data.
+    #[inline]
+    pub fn with_many_draw_order(
+        mut self,
+        draw_order: impl IntoIterator<Item = impl Into<crate::components::DrawOrder>>,
+    ) -> Self {
+        self.draw_order = try_serialize_field(Self::descriptor_draw_order(), draw_order);
+        self
+    }
+}
+
+impl ::re_byte_size::SizeBytes for EncodedDepthImage {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.blob.heap_size_bytes()
+            + self.media_type.heap_size_bytes()
+            + self.meter.heap_size_bytes()
+            + self.colormap.heap_size_bytes()
+            + self.depth_range.heap_size_bytes()
+            + self.point_fill_ratio.heap_size_bytes()
+            + self.draw_order.heap_size_bytes()
+    }
+}
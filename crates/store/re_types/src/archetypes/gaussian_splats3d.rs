@@ -0,0 +1,451 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/archetypes/gaussian_splats3d.fbs".
+
+#![allow(unused_braces)]
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::SerializationResult;
+use ::re_types_core::{ComponentBatch as _, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentName};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Archetype**: A cloud of 3D Gaussian splats, as produced by Gaussian splatting reconstructions.
+///
+/// Each splat is an anisotropic 3D Gaussian, defined by its center, the standard deviations
+/// along its three local axes and the rotation of those axes.
+/// Together, scales and rotations describe the covariance of the Gaussian.
+///
+/// Splats are rendered as camera-facing quads, sorted back-to-front and alpha-blended.
+/// Unlike [`archetypes::Ellipsoids3D`][crate::archetypes::Ellipsoids3D], this scales to millions of instances.
+///
+/// Spherical harmonics are not supported yet: only the view-independent base color is shown.
+/// Loaders convert the zeroth-order coefficients to [`components::Color`][crate::components::Color].
+///
+/// ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct GaussianSplats3D {
+    /// The center of each splat.
+    pub centers: Option<SerializedComponentBatch>,
+
+    /// The standard deviation of each splat along its three local axes.
+    pub scales: Option<SerializedComponentBatch>,
+
+    /// The orientation of each splat's local axes.
+    ///
+    /// If not specified, the local axes align with the axes of the entity's coordinate system.
+    pub quaternions: Option<SerializedComponentBatch>,
+
+    /// The base color of each splat.
+    ///
+    /// The alpha channel is ignored, use [`components::Opacity`][crate::components::Opacity] instead.
+    pub colors: Option<SerializedComponentBatch>,
+
+    /// The opacity of each splat at its center, in the range `[0, 1]`.
+    ///
+    /// If not specified, splats are fully opaque at their centers.
+    pub opacities: Option<SerializedComponentBatch>,
+}
+
+impl GaussianSplats3D {
+    /// Returns the [`ComponentDescriptor`] for [`Self::centers`].
+    ///
+    /// The corresponding component is [`crate::components::Position3D`].
+    #[inline]
+    pub fn descriptor_centers() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.archetypes.GaussianSplats3D".into()),
+            component_name: Some("rerun.components.Position3D".into()),
+            archetype_field_name: "centers".into(),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::scales`].
+    ///
+    /// The corresponding component is [`crate::components::Scale3D`].
+    #[inline]
+    pub fn descriptor_scales() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.archetypes.GaussianSplats3D".into()),
+            component_name: Some("rerun.components.Scale3D".into()),
+            archetype_field_name: "scales".into(),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::quaternions`].
+    ///
+    /// The corresponding component is [`crate::components::RotationQuat`].
+    #[inline]
+    pub fn descriptor_quaternions() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.archetypes.GaussianSplats3D".into()),
+            component_name: Some("rerun.components.RotationQuat".into()),
+            archetype_field_name: "quaternions".into(),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::colors`].
+    ///
+    /// The corresponding component is [`crate::components::Color`].
+    #[inline]
+    pub fn descriptor_colors() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.archetypes.GaussianSplats3D".into()),
+            component_name: Some("rerun.components.Color".into()),
+            archetype_field_name: "colors".into(),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::opacities`].
+    ///
+    /// The corresponding component is [`crate::components::Opacity`].
+    #[inline]
+    pub fn descriptor_opacities() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.archetypes.GaussianSplats3D".into()),
+            component_name: Some("rerun.components.Opacity".into()),
+            archetype_field_name: "opacities".into(),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for the associated indicator component.
+    #[inline]
+    pub fn descriptor_indicator() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: None,
+            component_name: None,
+            archetype_field_name: "rerun.components.GaussianSplats3DIndicator".into(),
+        }
+    }
+}
+
+static REQUIRED_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 2usize]> =
+    once_cell::sync::Lazy::new(|| {
+        [
+            GaussianSplats3D::descriptor_centers(),
+            GaussianSplats3D::descriptor_scales(),
+        ]
+    });
+
+static RECOMMENDED_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 4usize]> =
+    once_cell::sync::Lazy::new(|| {
+        [
+            GaussianSplats3D::descriptor_quaternions(),
+            GaussianSplats3D::descriptor_colors(),
+            GaussianSplats3D::descriptor_opacities(),
+            GaussianSplats3D::descriptor_indicator(),
+        ]
+    });
+
+static OPTIONAL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 0usize]> =
+    once_cell::sync::Lazy::new(|| []);
+
+static ALL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 6usize]> =
+    once_cell::sync::Lazy::new(|| {
+        [
+            GaussianSplats3D::descriptor_centers(),
+            GaussianSplats3D::descriptor_scales(),
+            GaussianSplats3D::descriptor_quaternions(),
+            GaussianSplats3D::descriptor_colors(),
+            GaussianSplats3D::descriptor_opacities(),
+            GaussianSplats3D::descriptor_indicator(),
+        ]
+    });
+
+impl GaussianSplats3D {
+    /// The total number of components in the archetype: 2 required, 4 recommended, 0 optional
+    pub const NUM_COMPONENTS: usize = 6usize;
+}
+
+/// Indicator component for the [`GaussianSplats3D`] [`::re_types_core::Archetype`]
+pub type GaussianSplats3DIndicator = ::re_types_core::GenericIndicatorComponent<GaussianSplats3D>;
+
+impl ::re_types_core::Archetype for GaussianSplats3D {
+    type Indicator = GaussianSplats3DIndicator;
+
+    #[inline]
+    fn name() -> ::re_types_core::ArchetypeName {
+        "rerun.archetypes.GaussianSplats3D".into()
+    }
+
+    #[inline]
+    fn display_name() -> &'static str {
+        "Gaussian splats 3D"
+    }
+
+    #[inline]
+    fn indicator() -> SerializedComponentBatch {
+        #[allow(clippy::unwrap_used)]
+        GaussianSplats3DIndicator::DEFAULT
+            .serialized(Self::descriptor_indicator())
+            .unwrap()
+    }
+
+    #[inline]
+    fn required_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        REQUIRED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn recommended_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        RECOMMENDED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn optional_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        OPTIONAL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn all_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        ALL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn from_arrow_components(
+        arrow_data: impl IntoIterator<Item = (ComponentDescriptor, arrow::array::ArrayRef)>,
+    ) -> DeserializationResult<Self> {
+        re_tracing::profile_function!();
+        use ::re_types_core::{Loggable as _, ResultExt as _};
+        let arrays_by_descr: ::nohash_hasher::IntMap<_, _> = arrow_data.into_iter().collect();
+        let centers = arrays_by_descr
+            .get(&Self::descriptor_centers())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_centers()));
+        let scales = arrays_by_descr
+            .get(&Self::descriptor_scales())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_scales()));
+        let quaternions = arrays_by_descr
+            .get(&Self::descriptor_quaternions())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_quaternions())
+            });
+        let colors = arrays_by_descr
+            .get(&Self::descriptor_colors())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_colors()));
+        let opacities = arrays_by_descr
+            .get(&Self::descriptor_opacities())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_opacities())
+            });
+        Ok(Self {
+            centers,
+            scales,
+            quaternions,
+            colors,
+            opacities,
+        })
+    }
+}
+
+impl ::re_types_core::AsComponents for GaussianSplats3D {
+    #[inline]
+    fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
+        use ::re_types_core::Archetype as _;
+        [
+            Some(Self::indicator()),
+            self.centers.clone(),
+            self.scales.clone(),
+            self.quaternions.clone(),
+            self.colors.clone(),
+            self.opacities.clone(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+impl ::re_types_core::ArchetypeReflectionMarker for GaussianSplats3D {}
+
+impl GaussianSplats3D {
+    /// Create a new `GaussianSplats3D`.
+    #[inline]
+    pub fn new(
+        centers: impl IntoIterator<Item = impl Into<crate::components::Position3D>>,
+        scales: impl IntoIterator<Item = impl Into<crate::components::Scale3D>>,
+    ) -> Self {
+        Self {
+            centers: try_serialize_field(Self::descriptor_centers(), centers),
+            scales: try_serialize_field(Self::descriptor_scales(), scales),
+            quaternions: None,
+            colors: None,
+            opacities: None,
+        }
+    }
+
+    /// Update only some specific fields of a `GaussianSplats3D`.
+    #[inline]
+    pub fn update_fields() -> Self {
+        Self::default()
+    }
+
+    /// Clear all the fields of a `GaussianSplats3D`.
+    #[inline]
+    pub fn clear_fields() -> Self {
+        use ::re_types_core::Loggable as _;
+        Self {
+            centers: Some(SerializedComponentBatch::new(
+                crate::components::Position3D::arrow_empty(),
+                Self::descriptor_centers(),
+            )),
+            scales: Some(SerializedComponentBatch::new(
+                crate::components::Scale3D::arrow_empty(),
+                Self::descriptor_scales(),
+            )),
+            quaternions: Some(SerializedComponentBatch::new(
+                crate::components::RotationQuat::arrow_empty(),
+                Self::descriptor_quaternions(),
+            )),
+            colors: Some(SerializedComponentBatch::new(
+                crate::components::Color::arrow_empty(),
+                Self::descriptor_colors(),
+            )),
+            opacities: Some(SerializedComponentBatch::new(
+                crate::components::Opacity::arrow_empty(),
+                Self::descriptor_opacities(),
+            )),
+        }
+    }
+
+    /// Partitions the component data into multiple sub-batches.
+    ///
+    /// Specifically, this transforms the existing [`SerializedComponentBatch`]es data into [`SerializedComponentColumn`]s
+    /// instead, via [`SerializedComponentBatch::partitioned`].
+    ///
+    /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+    ///
+    /// The specified `lengths` must sum to the total length of the component batch.
+    ///
+    /// [`SerializedComponentColumn`]: [::re_types_core::SerializedComponentColumn]
+    #[inline]
+    pub fn columns<I>(
+        self,
+        _lengths: I,
+    ) -> SerializationResult<impl Iterator<Item = ::re_types_core::SerializedComponentColumn>>
+    where
+        I: IntoIterator<Item = usize> + Clone,
+    {
+        let columns = [
+            self.centers
+                .map(|centers| centers.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.scales
+                .map(|scales| scales.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.quaternions
+                .map(|quaternions| quaternions.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.colors
+                .map(|colors| colors.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.opacities
+                .map(|opacities| opacities.partitioned(_lengths.clone()))
+                .transpose()?,
+        ];
+        Ok(columns
+            .into_iter()
+            .flatten()
+            .chain([::re_types_core::indicator_column::<Self>(
+                _lengths.into_iter().count(),
+            )?]))
+    }
+
+    /// Helper to partition the component data into unit-length sub-batches.
+    ///
+    /// This is semantically similar to calling [`Self::columns`] with `std::iter::take(1).repeat(n)`,
+    /// where `n` is automatically guessed.
+    #[inline]
+    pub fn columns_of_unit_batches(
+        self,
+    ) -> SerializationResult<impl Iterator<Item = ::re_types_core::SerializedComponentColumn>> {
+        let len_centers = self.centers.as_ref().map(|b| b.array.len());
+        let len_scales = self.scales.as_ref().map(|b| b.array.len());
+        let len_quaternions = self.quaternions.as_ref().map(|b| b.array.len());
+        let len_colors = self.colors.as_ref().map(|b| b.array.len());
+        let len_opacities = self.opacities.as_ref().map(|b| b.array.len());
+        let len = None
+            .or(len_centers)
+            .or(len_scales)
+            .or(len_quaternions)
+            .or(len_colors)
+            .or(len_opacities)
+            .unwrap_or(0);
+        self.columns(std::iter::repeat(1).take(len))
+    }
+
+    /// The center of each splat.
+    #[inline]
+    pub fn with_centers(
+        mut self,
+        centers: impl IntoIterator<Item = impl Into<crate::components::Position3D>>,
+    ) -> Self {
+        self.centers = try_serialize_field(Self::descriptor_centers(), centers);
+        self
+    }
+
+    /// The standard deviation of each splat along its three local axes.
+    #[inline]
+    pub fn with_scales(
+        mut self,
+        scales: impl IntoIterator<Item = impl Into<crate::components::Scale3D>>,
+    ) -> Self {
+        self.scales = try_serialize_field(Self::descriptor_scales(), scales);
+        self
+    }
+
+    /// The orientation of each splat's local axes.
+    ///
+    /// If not specified, the local axes align with the axes of the entity's coordinate system.
+    #[inline]
+    pub fn with_quaternions(
+        mut self,
+        quaternions: impl IntoIterator<Item = impl Into<crate::components::RotationQuat>>,
+    ) -> Self {
+        self.quaternions = try_serialize_field(Self::descriptor_quaternions(), quaternions);
+        self
+    }
+
+    /// The base color of each splat.
+    ///
+    /// The alpha channel is ignored, use [`components::Opacity`][crate::components::Opacity] instead.
+    #[inline]
+    pub fn with_colors(
+        mut self,
+        colors: impl IntoIterator<Item = impl Into<crate::components::Color>>,
+    ) -> Self {
+        self.colors = try_serialize_field(Self::descriptor_colors(), colors);
+        self
+    }
+
+    /// The opacity of each splat at its center, in the range `[0, 1]`.
+    ///
+    /// If not specified, splats are fully opaque at their centers.
+    #[inline]
+    pub fn with_opacities(
+        mut self,
+        opacities: impl IntoIterator<Item = impl Into<crate::components::Opacity>>,
+    ) -> Self {
+        self.opacities = try_serialize_field(Self::descriptor_opacities(), opacities);
+        self
+    }
+}
+
+impl ::re_byte_size::SizeBytes for GaussianSplats3D {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.centers.heap_size_bytes()
+            + self.scales.heap_size_bytes()
+            + self.quaternions.heap_size_bytes()
+            + self.colors.heap_size_bytes()
+            + self.opacities.heap_size_bytes()
+    }
+}
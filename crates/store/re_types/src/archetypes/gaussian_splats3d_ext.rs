@@ -0,0 +1,333 @@
+use std::collections::BTreeSet;
+
+use super::GaussianSplats3D;
+
+/// Zeroth order spherical harmonics basis constant, used to turn the DC coefficients into colors.
+const SH_C0: f32 = 0.282_094_8;
+
+impl GaussianSplats3D {
+    /// Whether the contents of a `.ply` file describe Gaussian splats rather than a plain point cloud.
+    ///
+    /// Only the header is read, which makes this cheap even for large files.
+    pub fn is_splat_ply(contents: &[u8]) -> bool {
+        re_tracing::profile_function!();
+        let parser = ply_rs::parser::Parser::<ply_rs::ply::DefaultElement>::new();
+        let mut contents = std::io::Cursor::new(contents);
+        let Ok(header) = parser.read_header(&mut contents) else {
+            return false;
+        };
+
+        header.elements.get("vertex").is_some_and(|vertex| {
+            ["scale_0", "scale_1", "scale_2"]
+                .iter()
+                .all(|prop| vertex.properties.contains_key(*prop))
+        })
+    }
+
+    /// Creates a new [`GaussianSplats3D`] from the contents of a `.ply` file.
+    ///
+    /// ## Supported properties
+    ///
+    /// This expects the layout written by the reference 3D Gaussian Splatting implementation
+    /// and most tools derived from it:
+    /// - (Required) Centers of the splats: `"x"`, `"y"` & `"z"`.
+    /// - (Required) Logarithm of the standard deviations: `"scale_0"`, `"scale_1"` & `"scale_2"`.
+    /// - (Optional) Rotation quaternion in WXYZ order: `"rot_0"`, `"rot_1"`, `"rot_2"` & `"rot_3"`.
+    /// - (Optional) Colors as zeroth order spherical harmonics: `"f_dc_0"`, `"f_dc_1"` & `"f_dc_2"`.
+    ///   Plain `"red"`, `"green"` & `"blue"` are used if those are missing.
+    /// - (Optional) Opacity before the sigmoid activation: `"opacity"`.
+    ///
+    /// Higher order spherical harmonics (`"f_rest_*"`) are not supported and ignored.
+    pub fn from_file_contents(contents: &[u8]) -> anyhow::Result<Self> {
+        re_tracing::profile_function!();
+        let parser = ply_rs::parser::Parser::<ply_rs::ply::DefaultElement>::new();
+        let mut contents = std::io::Cursor::new(contents);
+        let ply = {
+            re_tracing::profile_scope!("read_ply");
+            parser.read_ply(&mut contents)?
+        };
+        Ok(from_ply(ply))
+    }
+}
+
+fn from_ply(ply: ply_rs::ply::Ply<ply_rs::ply::DefaultElement>) -> GaussianSplats3D {
+    re_tracing::profile_function!();
+
+    use linked_hash_map::LinkedHashMap;
+    use ply_rs::ply::Property;
+
+    use crate::components::{Color, Opacity, Position3D, RotationQuat, Scale3D};
+    use crate::datatypes::Quaternion;
+
+    fn f32(prop: &Property) -> Option<f32> {
+        match *prop {
+            Property::Char(v) => Some(v as f32),
+            Property::UChar(v) => Some(v as f32),
+            Property::Short(v) => Some(v as f32),
+            Property::UShort(v) => Some(v as f32),
+            Property::Int(v) => Some(v as f32),
+            Property::UInt(v) => Some(v as f32),
+            Property::Float(v) => Some(v),
+            Property::Double(v) => Some(v as f32),
+            Property::ListChar(_)
+            | Property::ListUChar(_)
+            | Property::ListShort(_)
+            | Property::ListUShort(_)
+            | Property::ListInt(_)
+            | Property::ListUInt(_)
+            | Property::ListFloat(_)
+            | Property::ListDouble(_) => None,
+        }
+    }
+
+    fn u8(prop: &Property) -> Option<u8> {
+        match *prop {
+            Property::Float(v) => Some((v * 255.0) as u8),
+            Property::Double(v) => Some((v * 255.0) as u8),
+            Property::UChar(v) => Some(v),
+            _ => f32(prop).map(|v| v as u8),
+        }
+    }
+
+    fn sh_to_u8(dc: f32) -> u8 {
+        ((0.5 + SH_C0 * dc).clamp(0.0, 1.0) * 255.0).round() as u8
+    }
+
+    struct Splat {
+        center: Position3D,
+        scale: Scale3D,
+        quaternion: Option<RotationQuat>,
+        color: Option<Color>,
+        opacity: Option<Opacity>,
+    }
+
+    impl Splat {
+        fn from_props(
+            mut props: LinkedHashMap<String, Property>,
+            ignored_props: &mut BTreeSet<String>,
+        ) -> Option<Self> {
+            const PROP_X: &str = "x";
+            const PROP_Y: &str = "y";
+            const PROP_Z: &str = "z";
+            const PROPS_SCALE: [&str; 3] = ["scale_0", "scale_1", "scale_2"];
+            const PROPS_ROT: [&str; 4] = ["rot_0", "rot_1", "rot_2", "rot_3"];
+            const PROPS_DC: [&str; 3] = ["f_dc_0", "f_dc_1", "f_dc_2"];
+            const PROPS_RGB: [&str; 3] = ["red", "green", "blue"];
+            const PROP_OPACITY: &str = "opacity";
+
+            let get3 = |props: &LinkedHashMap<String, Property>, names: [&str; 3]| {
+                Some([
+                    props.get(names[0]).and_then(f32)?,
+                    props.get(names[1]).and_then(f32)?,
+                    props.get(names[2]).and_then(f32)?,
+                ])
+            };
+
+            let (Some([x, y, z]), Some(log_scale)) = (
+                get3(&props, [PROP_X, PROP_Y, PROP_Z]),
+                get3(&props, PROPS_SCALE),
+            ) else {
+                // All splats must have positions & sizes.
+                for (key, _value) in props {
+                    ignored_props.insert(key);
+                }
+                return None;
+            };
+
+            // We remove properties as they are read so we can warn about the ones we don't recognize.
+            for prop in [PROP_X, PROP_Y, PROP_Z].into_iter().chain(PROPS_SCALE) {
+                props.remove(prop);
+            }
+
+            let mut this = Self {
+                center: Position3D::new(x, y, z),
+                scale: Scale3D::from(log_scale.map(f32::exp)),
+                quaternion: None,
+                color: None,
+                opacity: None,
+            };
+
+            if let (Some(w), Some(x), Some(y), Some(z)) = (
+                props.get(PROPS_ROT[0]).and_then(f32),
+                props.get(PROPS_ROT[1]).and_then(f32),
+                props.get(PROPS_ROT[2]).and_then(f32),
+                props.get(PROPS_ROT[3]).and_then(f32),
+            ) {
+                for prop in PROPS_ROT {
+                    props.remove(prop);
+                }
+
+                // Trainers don't keep their quaternions normalized.
+                let length = (w * w + x * x + y * y + z * z).sqrt();
+                let wxyz = if length > 0.0 {
+                    [w, x, y, z].map(|v| v / length)
+                } else {
+                    [1.0, 0.0, 0.0, 0.0]
+                };
+                this.quaternion = Some(RotationQuat(Quaternion::from_wxyz(wxyz)));
+            }
+
+            if let Some(dc) = get3(&props, PROPS_DC) {
+                for prop in PROPS_DC {
+                    props.remove(prop);
+                }
+                let [r, g, b] = dc.map(sh_to_u8);
+                this.color = Some(Color::from_rgb(r, g, b));
+            } else if let (Some(r), Some(g), Some(b)) = (
+                props.get(PROPS_RGB[0]).and_then(u8),
+                props.get(PROPS_RGB[1]).and_then(u8),
+                props.get(PROPS_RGB[2]).and_then(u8),
+            ) {
+                for prop in PROPS_RGB {
+                    props.remove(prop);
+                }
+                this.color = Some(Color::from_rgb(r, g, b));
+            }
+
+            if let Some(logit) = props.get(PROP_OPACITY).and_then(f32) {
+                props.remove(PROP_OPACITY);
+                this.opacity = Some(Opacity::from(1.0 / (1.0 + (-logit).exp())));
+            }
+
+            for (key, _value) in props {
+                // Higher order spherical harmonics are reported once below, not one by one.
+                if !key.starts_with("f_rest_") {
+                    ignored_props.insert(key);
+                }
+            }
+
+            Some(this)
+        }
+    }
+
+    let mut centers = Vec::new();
+    let mut scales = Vec::new();
+    let mut quaternions = Vec::new();
+    let mut colors = Vec::new();
+    let mut opacities = Vec::new();
+
+    let mut ignored_props = BTreeSet::new();
+    let mut has_higher_order_sh = false;
+
+    for (key, all_props) in ply.payload {
+        if key == "vertex" {
+            for props in all_props {
+                has_higher_order_sh |= props.contains_key("f_rest_0");
+                if let Some(splat) = Splat::from_props(props, &mut ignored_props) {
+                    let Splat {
+                        center,
+                        scale,
+                        quaternion,
+                        color,
+                        opacity,
+                    } = splat;
+                    centers.push(center);
+                    scales.push(scale);
+                    quaternions.push(quaternion); // opt
+                    colors.push(color); // opt
+                    opacities.push(opacity); // opt
+                }
+            }
+        } else {
+            re_log::warn!("Ignoring {key:?} in .ply file");
+        }
+    }
+
+    if has_higher_order_sh {
+        re_log::warn!(
+            "Ignoring higher order spherical harmonics of .ply file, splats are shown with their base color only"
+        );
+    }
+    if !ignored_props.is_empty() {
+        re_log::warn!("Ignored properties of .ply file: {ignored_props:?}");
+    }
+
+    re_tracing::profile_scope!("fill-in");
+
+    let mut arch = GaussianSplats3D::new(centers, scales);
+    if quaternions.iter().any(|opt| opt.is_some()) {
+        let quaternions = quaternions
+            .into_iter()
+            .map(|opt| opt.unwrap_or(RotationQuat::IDENTITY));
+        arch = arch.with_quaternions(quaternions);
+    }
+    if colors.iter().any(|opt| opt.is_some()) {
+        // If some colors have been specified but not others, default the unspecified ones to white.
+        let colors = colors
+            .into_iter()
+            .map(|opt| opt.unwrap_or(Color::from_rgb(255, 255, 255)));
+        arch = arch.with_colors(colors);
+    }
+    if opacities.iter().any(|opt| opt.is_some()) {
+        let opacities = opacities
+            .into_iter()
+            .map(|opt| opt.unwrap_or(Opacity::from(1.0)));
+        arch = arch.with_opacities(opacities);
+    }
+
+    arch
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        components::{Color, RotationQuat},
+        datatypes::Quaternion,
+    };
+
+    const SPLAT_PLY: &str = "ply
+format ascii 1.0
+element vertex 2
+property float x
+property float y
+property float z
+property float f_dc_0
+property float f_dc_1
+property float f_dc_2
+property float opacity
+property float scale_0
+property float scale_1
+property float scale_2
+property float rot_0
+property float rot_1
+property float rot_2
+property float rot_3
+end_header
+0 1 2 0 0 0 0 0 0 0 2 0 0 0
+1 2 3 10 -10 0 100 -1 -1 -1 0 0 0 1
+";
+
+    #[test]
+    fn splats_from_ply() {
+        let contents = SPLAT_PLY.as_bytes();
+        assert!(GaussianSplats3D::is_splat_ply(contents));
+
+        let splats = GaussianSplats3D::from_file_contents(contents).unwrap();
+        let expected = GaussianSplats3D::new(
+            [(0.0, 1.0, 2.0), (1.0, 2.0, 3.0)],
+            [[1.0, 1.0, 1.0], [(-1.0f32).exp(); 3]],
+        )
+        .with_quaternions([
+            RotationQuat(Quaternion::from_wxyz([1.0, 0.0, 0.0, 0.0])),
+            RotationQuat(Quaternion::from_wxyz([0.0, 0.0, 0.0, 1.0])),
+        ])
+        .with_colors([Color::from_rgb(128, 128, 128), Color::from_rgb(255, 0, 128)])
+        .with_opacities([0.5, 1.0]);
+        assert_eq!(splats, expected);
+    }
+
+    #[test]
+    fn point_cloud_ply_is_not_splats() {
+        let contents = "ply
+format ascii 1.0
+element vertex 1
+property float x
+property float y
+property float z
+end_header
+0 0 0
+";
+        assert!(!GaussianSplats3D::is_splat_ply(contents.as_bytes()));
+    }
+}
@@ -0,0 +1,390 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/archetypes/geo_polygons.fbs".
+
+#![allow(unused_braces)]
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::SerializationResult;
+use ::re_types_core::{ComponentBatch as _, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentName};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Archetype**: Geospatial polygons with positions expressed in [EPSG:4326](https://epsg.io/4326) altitude and longitude (North/East-positive degrees), and optional fill and outline colors.
+///
+/// Useful for geofences, coverage areas and other regions on a map.
+///
+/// ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct GeoPolygons {
+    /// The polygons, expressed in [EPSG:4326](https://epsg.io/4326) coordinates (North/East-positive degrees).
+    pub polygons: Option<SerializedComponentBatch>,
+
+    /// Optional radii for the outlines of the polygons.
+    ///
+    /// *Note*: scene units radiii are interpreted as meters. Currently, the display scale only considers the latitude of
+    /// the first vertex of each polygon (see [this issue](https://github.com/rerun-io/rerun/issues/8013)).
+    pub radii: Option<SerializedComponentBatch>,
+
+    /// Optional colors for the outlines of the polygons.
+    pub colors: Option<SerializedComponentBatch>,
+
+    /// Optional colors for the area enclosed by the polygons.
+    ///
+    /// If not specified, the outline color is used with reduced opacity.
+    pub fill_colors: Option<SerializedComponentBatch>,
+}
+
+impl GeoPolygons {
+    /// Returns the [`ComponentDescriptor`] for [`Self::polygons`].
+    ///
+    /// The corresponding component is [`crate::components::GeoPolygon`].
+    #[inline]
+    pub fn descriptor_polygons() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.archetypes.GeoPolygons".into()),
+            component_name: Some("rerun.components.GeoPolygon".into()),
+            archetype_field_name: "polygons".into(),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::radii`].
+    ///
+    /// The corresponding component is [`crate::components::Radius`].
+    #[inline]
+    pub fn descriptor_radii() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.archetypes.GeoPolygons".into()),
+            component_name: Some("rerun.components.Radius".into()),
+            archetype_field_name: "radii".into(),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::colors`].
+    ///
+    /// The corresponding component is [`crate::components::Color`].
+    #[inline]
+    pub fn descriptor_colors() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.archetypes.GeoPolygons".into()),
+            component_name: Some("rerun.components.Color".into()),
+            archetype_field_name: "colors".into(),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::fill_colors`].
+    ///
+    /// The corresponding component is [`crate::components::Color`].
+    #[inline]
+    pub fn descriptor_fill_colors() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.archetypes.GeoPolygons".into()),
+            component_name: Some("rerun.components.Color".into()),
+            archetype_field_name: "fill_colors".into(),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for the associated indicator component.
+    #[inline]
+    pub fn descriptor_indicator() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: None,
+            component_name: None,
+            archetype_field_name: "rerun.components.GeoPolygonsIndicator".into(),
+        }
+    }
+}
+
+static REQUIRED_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 1usize]> =
+    once_cell::sync::Lazy::new(|| [GeoPolygons::descriptor_polygons()]);
+
+static RECOMMENDED_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 3usize]> =
+    once_cell::sync::Lazy::new(|| {
+        [
+            GeoPolygons::descriptor_radii(),
+            GeoPolygons::descriptor_colors(),
+            GeoPolygons::descriptor_indicator(),
+        ]
+    });
+
+static OPTIONAL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 1usize]> =
+    once_cell::sync::Lazy::new(|| [GeoPolygons::descriptor_fill_colors()]);
+
+static ALL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 5usize]> =
+    once_cell::sync::Lazy::new(|| {
+        [
+            GeoPolygons::descriptor_polygons(),
+            GeoPolygons::descriptor_radii(),
+            GeoPolygons::descriptor_colors(),
+            GeoPolygons::descriptor_indicator(),
+            GeoPolygons::descriptor_fill_colors(),
+        ]
+    });
+
+impl GeoPolygons {
+    /// The total number of components in the archetype: 1 required, 3 recommended, 1 optional
+    pub const NUM_COMPONENTS: usize = 5usize;
+}
+
+/// Indicator component for the [`GeoPolygons`] [`::re_types_core::Archetype`]
+pub type GeoPolygonsIndicator = ::re_types_core::GenericIndicatorComponent<GeoPolygons>;
+
+impl ::re_types_core::Archetype for GeoPolygons {
+    type Indicator = GeoPolygonsIndicator;
+
+    #[inline]
+    fn name() -> ::re_types_core::ArchetypeName {
+        "rerun.archetypes.GeoPolygons".into()
+    }
+
+    #[inline]
+    fn display_name() -> &'static str {
+        "Geo polygons"
+    }
+
+    #[inline]
+    fn indicator() -> SerializedComponentBatch {
+        #[allow(clippy::unwrap_used)]
+        GeoPolygonsIndicator::DEFAULT
+            .serialized(Self::descriptor_indicator())
+            .unwrap()
+    }
+
+    #[inline]
+    fn required_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        REQUIRED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn recommended_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        RECOMMENDED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn optional_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        OPTIONAL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn all_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        ALL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn from_arrow_components(
+        arrow_data: impl IntoIterator<Item = (ComponentDescriptor, arrow::array::ArrayRef)>,
+    ) -> DeserializationResult<Self> {
+        re_tracing::profile_function!();
+        use ::re_types_core::{Loggable as _, ResultExt as _};
+        let arrays_by_descr: ::nohash_hasher::IntMap<_, _> = arrow_data.into_iter().collect();
+        let polygons = arrays_by_descr
+            .get(&Self::descriptor_polygons())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_polygons()));
+        let radii = arrays_by_descr
+            .get(&Self::descriptor_radii())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_radii()));
+        let colors = arrays_by_descr
+            .get(&Self::descriptor_colors())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_colors()));
+        let fill_colors = arrays_by_descr
+            .get(&Self::descriptor_fill_colors())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_fill_colors())
+            });
+        Ok(Self {
+            polygons,
+            radii,
+            colors,
+            fill_colors,
+        })
+    }
+}
+
+impl ::re_types_core::AsComponents for GeoPolygons {
+    #[inline]
+    fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
+        use ::re_types_core::Archetype as _;
+        [
+            Some(Self::indicator()),
+            self.polygons.clone(),
+            self.radii.clone(),
+            self.colors.clone(),
+            self.fill_colors.clone(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+impl ::re_types_core::ArchetypeReflectionMarker for GeoPolygons {}
+
+impl GeoPolygons {
+    /// Create a new `GeoPolygons`.
+    #[inline]
+    pub(crate) fn new(
+        polygons: impl IntoIterator<Item = impl Into<crate::components::GeoPolygon>>,
+    ) -> Self {
+        Self {
+            polygons: try_serialize_field(Self::descriptor_polygons(), polygons),
+            radii: None,
+            colors: None,
+            fill_colors: None,
+        }
+    }
+
+    /// Update only some specific fields of a `GeoPolygons`.
+    #[inline]
+    pub fn update_fields() -> Self {
+        Self::default()
+    }
+
+    /// Clear all the fields of a `GeoPolygons`.
+    #[inline]
+    pub fn clear_fields() -> Self {
+        use ::re_types_core::Loggable as _;
+        Self {
+            polygons: Some(SerializedComponentBatch::new(
+                crate::components::GeoPolygon::arrow_empty(),
+                Self::descriptor_polygons(),
+            )),
+            radii: Some(SerializedComponentBatch::new(
+                crate::components::Radius::arrow_empty(),
+                Self::descriptor_radii(),
+            )),
+            colors: Some(SerializedComponentBatch::new(
+                crate::components::Color::arrow_empty(),
+                Self::descriptor_colors(),
+            )),
+            fill_colors: Some(SerializedComponentBatch::new(
+                crate::components::Color::arrow_empty(),
+                Self::descriptor_fill_colors(),
+            )),
+        }
+    }
+
+    /// Partitions the component data into multiple sub-batches.
+    ///
+    /// Specifically, this transforms the existing [`SerializedComponentBatch`]es data into [`SerializedComponentColumn`]s
+    /// instead, via [`SerializedComponentBatch::partitioned`].
+    ///
+    /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+    ///
+    /// The specified `lengths` must sum to the total length of the component batch.
+    ///
+    /// [`SerializedComponentColumn`]: [::re_types_core::SerializedComponentColumn]
+    #[inline]
+    pub fn columns<I>(
+        self,
+        _lengths: I,
+    ) -> SerializationResult<impl Iterator<Item = ::re_types_core::SerializedComponentColumn>>
+    where
+        I: IntoIterator<Item = usize> + Clone,
+    {
+        let columns = [
+            self.polygons
+                .map(|polygons| polygons.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.radii
+                .map(|radii| radii.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.colors
+                .map(|colors| colors.partitioned(_lengths.clone()))
+                .transpose()?,
+            self.fill_colors
+                .map(|fill_colors| fill_colors.partitioned(_lengths.clone()))
+                .transpose()?,
+        ];
+        Ok(columns
+            .into_iter()
+            .flatten()
+            .chain([::re_types_core::indicator_column::<Self>(
+                _lengths.into_iter().count(),
+            )?]))
+    }
+
+    /// Helper to partition the component data into unit-length sub-batches.
+    ///
+    /// This is semantically similar to calling [`Self::columns`] with `std::iter::take(1).repeat(n)`,
+    /// where `n` is automatically guessed.
+    #[inline]
+    pub fn columns_of_unit_batches(
+        self,
+    ) -> SerializationResult<impl Iterator<Item = ::re_types_core::SerializedComponentColumn>> {
+        let len_polygons = self.polygons.as_ref().map(|b| b.array.len());
+        let len_radii = self.radii.as_ref().map(|b| b.array.len());
+        let len_colors = self.colors.as_ref().map(|b| b.array.len());
+        let len_fill_colors = self.fill_colors.as_ref().map(|b| b.array.len());
+        let len = None
+            .or(len_polygons)
+            .or(len_radii)
+            .or(len_colors)
+            .or(len_fill_colors)
+            .unwrap_or(0);
+        self.columns(std::iter::repeat(1).take(len))
+    }
+
+    /// The polygons, expressed in [EPSG:4326](https://epsg.io/4326) coordinates (North/East-positive degrees).
+    #[inline]
+    pub fn with_polygons(
+        mut self,
+        polygons: impl IntoIterator<Item = impl Into<crate::components::GeoPolygon>>,
+    ) -> Self {
+        self.polygons = try_serialize_field(Self::descriptor_polygons(), polygons);
+        self
+    }
+
+    /// Optional radii for the outlines of the polygons.
+    ///
+    /// *Note*: scene units radiii are interpreted as meters. Currently, the display scale only considers the latitude of
+    /// the first vertex of each polygon (see [this issue](https://github.com/rerun-io/rerun/issues/8013)).
+    #[inline]
+    pub fn with_radii(
+        mut self,
+        radii: impl IntoIterator<Item = impl Into<crate::components::Radius>>,
+    ) -> Self {
+        self.radii = try_serialize_field(Self::descriptor_radii(), radii);
+        self
+    }
+
+    /// Optional colors for the outlines of the polygons.
+    #[inline]
+    pub fn with_colors(
+        mut self,
+        colors: impl IntoIterator<Item = impl Into<crate::components::Color>>,
+    ) -> Self {
+        self.colors = try_serialize_field(Self::descriptor_colors(), colors);
+        self
+    }
+
+    /// Optional colors for the area enclosed by the polygons.
+    ///
+    /// If not specified, the outline color is used with reduced opacity.
+    #[inline]
+    pub fn with_fill_colors(
+        mut self,
+        fill_colors: impl IntoIterator<Item = impl Into<crate::components::Color>>,
+    ) -> Self {
+        self.fill_colors = try_serialize_field(Self::descriptor_fill_colors(), fill_colors);
+        self
+    }
+}
+
+impl ::re_byte_size::SizeBytes for GeoPolygons {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.polygons.heap_size_bytes()
+            + self.radii.heap_size_bytes()
+            + self.colors.heap_size_bytes()
+            + self.fill_colors.heap_size_bytes()
+    }
+}
@@ -0,0 +1,11 @@
+use super::GeoPolygons;
+
+impl GeoPolygons {
+    /// Create a new `GeoPolygons` from [EPSG:4326](https://epsg.io/4326) latitude and longitude (North/East-positive degrees).
+    #[inline]
+    pub fn from_lat_lon(
+        polygons: impl IntoIterator<Item = impl Into<crate::components::GeoPolygon>>,
+    ) -> Self {
+        Self::new(polygons)
+    }
+}
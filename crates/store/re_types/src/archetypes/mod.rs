@@ -22,12 +22,18 @@ mod depth_image;
 mod depth_image_ext;
 mod ellipsoids3d;
 mod ellipsoids3d_ext;
+mod encoded_depth_image;
+mod encoded_depth_image_ext;
 mod encoded_image;
 mod encoded_image_ext;
+mod gaussian_splats3d;
+mod gaussian_splats3d_ext;
 mod geo_line_strings;
 mod geo_line_strings_ext;
 mod geo_points;
 mod geo_points_ext;
+mod geo_polygons;
+mod geo_polygons_ext;
 mod graph_edges;
 mod graph_edges_ext;
 mod graph_nodes;
@@ -74,9 +80,12 @@ pub use self::capsules3d::Capsules3D;
 pub use self::cylinders3d::Cylinders3D;
 pub use self::depth_image::DepthImage;
 pub use self::ellipsoids3d::Ellipsoids3D;
+pub use self::encoded_depth_image::EncodedDepthImage;
 pub use self::encoded_image::EncodedImage;
+pub use self::gaussian_splats3d::GaussianSplats3D;
 pub use self::geo_line_strings::GeoLineStrings;
 pub use self::geo_points::GeoPoints;
+pub use self::geo_polygons::GeoPolygons;
 pub use self::graph_edges::GraphEdges;
 pub use self::graph_nodes::GraphNodes;
 pub use self::image::Image;
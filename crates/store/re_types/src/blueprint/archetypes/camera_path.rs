@@ -0,0 +1,407 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/camera_path.fbs".
+
+#![allow(unused_braces)]
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::SerializationResult;
+use ::re_types_core::{ComponentBatch as _, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentName};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Archetype**: A keyframed path for the eye of a 3D view.
+///
+/// When enabled, the eye is interpolated between the keyframes as the time cursor moves along
+/// the timeline, both when scrubbing and during playback.
+/// Before the first and after the last keyframe the eye holds still.
+///
+/// The n-th keyframe is at `times[n]`, where the eye is at `positions[n]` looking at `look_targets[n]`.
+///
+/// ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+#[derive(Clone, Debug, Default)]
+pub struct CameraPath {
+    /// Whether the eye of the view follows the path. Defaults to true.
+    pub enabled: Option<SerializedComponentBatch>,
+
+    /// The timeline along which the keyframes are placed.
+    pub timeline: Option<SerializedComponentBatch>,
+
+    /// The time of each keyframe.
+    pub times: Option<SerializedComponentBatch>,
+
+    /// Where the eye is at each keyframe.
+    pub positions: Option<SerializedComponentBatch>,
+
+    /// What the eye looks at, at each keyframe.
+    pub look_targets: Option<SerializedComponentBatch>,
+
+    /// An entity the path is attached to, e.g. a vehicle followed by a chase cam.
+    ///
+    /// If set, positions and look targets are in the space of this entity rather than that of the view.
+    pub chase_entity: Option<SerializedComponentBatch>,
+}
+
+impl CameraPath {
+    /// Returns the [`ComponentDescriptor`] for [`Self::enabled`].
+    ///
+    /// The corresponding component is [`crate::blueprint::components::Enabled`].
+    #[inline]
+    pub fn descriptor_enabled() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.blueprint.archetypes.CameraPath".into()),
+            component_name: Some("rerun.blueprint.components.Enabled".into()),
+            archetype_field_name: "enabled".into(),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::timeline`].
+    ///
+    /// The corresponding component is [`crate::blueprint::components::TimelineName`].
+    #[inline]
+    pub fn descriptor_timeline() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.blueprint.archetypes.CameraPath".into()),
+            component_name: Some("rerun.blueprint.components.TimelineName".into()),
+            archetype_field_name: "timeline".into(),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::times`].
+    ///
+    /// The corresponding component is [`crate::blueprint::components::KeyframeTime`].
+    #[inline]
+    pub fn descriptor_times() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.blueprint.archetypes.CameraPath".into()),
+            component_name: Some("rerun.blueprint.components.KeyframeTime".into()),
+            archetype_field_name: "times".into(),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::positions`].
+    ///
+    /// The corresponding component is [`crate::components::Position3D`].
+    #[inline]
+    pub fn descriptor_positions() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.blueprint.archetypes.CameraPath".into()),
+            component_name: Some("rerun.components.Position3D".into()),
+            archetype_field_name: "positions".into(),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::look_targets`].
+    ///
+    /// The corresponding component is [`crate::components::Position3D`].
+    #[inline]
+    pub fn descriptor_look_targets() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.blueprint.archetypes.CameraPath".into()),
+            component_name: Some("rerun.components.Position3D".into()),
+            archetype_field_name: "look_targets".into(),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::chase_entity`].
+    ///
+    /// The corresponding component is [`crate::components::EntityPath`].
+    #[inline]
+    pub fn descriptor_chase_entity() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.blueprint.archetypes.CameraPath".into()),
+            component_name: Some("rerun.components.EntityPath".into()),
+            archetype_field_name: "chase_entity".into(),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for the associated indicator component.
+    #[inline]
+    pub fn descriptor_indicator() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: None,
+            component_name: None,
+            archetype_field_name: "rerun.blueprint.components.CameraPathIndicator".into(),
+        }
+    }
+}
+
+static REQUIRED_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 0usize]> =
+    once_cell::sync::Lazy::new(|| []);
+
+static RECOMMENDED_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 1usize]> =
+    once_cell::sync::Lazy::new(|| [CameraPath::descriptor_indicator()]);
+
+static OPTIONAL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 6usize]> =
+    once_cell::sync::Lazy::new(|| {
+        [
+            CameraPath::descriptor_enabled(),
+            CameraPath::descriptor_timeline(),
+            CameraPath::descriptor_times(),
+            CameraPath::descriptor_positions(),
+            CameraPath::descriptor_look_targets(),
+            CameraPath::descriptor_chase_entity(),
+        ]
+    });
+
+static ALL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 7usize]> =
+    once_cell::sync::Lazy::new(|| {
+        [
+            CameraPath::descriptor_indicator(),
+            CameraPath::descriptor_enabled(),
+            CameraPath::descriptor_timeline(),
+            CameraPath::descriptor_times(),
+            CameraPath::descriptor_positions(),
+            CameraPath::descriptor_look_targets(),
+            CameraPath::descriptor_chase_entity(),
+        ]
+    });
+
+impl CameraPath {
+    /// The total number of components in the archetype: 0 required, 1 recommended, 6 optional
+    pub const NUM_COMPONENTS: usize = 7usize;
+}
+
+/// Indicator component for the [`CameraPath`] [`::re_types_core::Archetype`]
+pub type CameraPathIndicator = ::re_types_core::GenericIndicatorComponent<CameraPath>;
+
+impl ::re_types_core::Archetype for CameraPath {
+    type Indicator = CameraPathIndicator;
+
+    #[inline]
+    fn name() -> ::re_types_core::ArchetypeName {
+        "rerun.blueprint.archetypes.CameraPath".into()
+    }
+
+    #[inline]
+    fn display_name() -> &'static str {
+        "Camera path"
+    }
+
+    #[inline]
+    fn indicator() -> SerializedComponentBatch {
+        #[allow(clippy::unwrap_used)]
+        CameraPathIndicator::DEFAULT
+            .serialized(Self::descriptor_indicator())
+            .unwrap()
+    }
+
+    #[inline]
+    fn required_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        REQUIRED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn recommended_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        RECOMMENDED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn optional_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        OPTIONAL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn all_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        ALL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn from_arrow_components(
+        arrow_data: impl IntoIterator<Item = (ComponentDescriptor, arrow::array::ArrayRef)>,
+    ) -> DeserializationResult<Self> {
+        re_tracing::profile_function!();
+        use ::re_types_core::{Loggable as _, ResultExt as _};
+        let arrays_by_descr: ::nohash_hasher::IntMap<_, _> = arrow_data.into_iter().collect();
+
+        let enabled = arrays_by_descr
+            .get(&Self::descriptor_enabled())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_enabled()));
+        let timeline = arrays_by_descr
+            .get(&Self::descriptor_timeline())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_timeline()));
+        let times = arrays_by_descr
+            .get(&Self::descriptor_times())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_times()));
+        let positions = arrays_by_descr
+            .get(&Self::descriptor_positions())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_positions())
+            });
+        let look_targets = arrays_by_descr
+            .get(&Self::descriptor_look_targets())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_look_targets())
+            });
+        let chase_entity = arrays_by_descr
+            .get(&Self::descriptor_chase_entity())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_chase_entity())
+            });
+        Ok(Self {
+            enabled,
+            timeline,
+            times,
+            positions,
+            look_targets,
+            chase_entity,
+        })
+    }
+}
+
+impl ::re_types_core::AsComponents for CameraPath {
+    #[inline]
+    fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
+        use ::re_types_core::Archetype as _;
+        [
+            Some(Self::indicator()),
+            self.enabled.clone(),
+            self.timeline.clone(),
+            self.times.clone(),
+            self.positions.clone(),
+            self.look_targets.clone(),
+            self.chase_entity.clone(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+impl ::re_types_core::ArchetypeReflectionMarker for CameraPath {}
+
+impl CameraPath {
+    /// Create a new `CameraPath`.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            enabled: None,
+            timeline: None,
+            times: None,
+            positions: None,
+            look_targets: None,
+            chase_entity: None,
+        }
+    }
+
+    /// Update only some specific fields of a `CameraPath`.
+    #[inline]
+    pub fn update_fields() -> Self {
+        Self::default()
+    }
+
+    /// Clear all the fields of a `CameraPath`.
+    #[inline]
+    pub fn clear_fields() -> Self {
+        use ::re_types_core::Loggable as _;
+        Self {
+            enabled: Some(SerializedComponentBatch::new(
+                crate::blueprint::components::Enabled::arrow_empty(),
+                Self::descriptor_enabled(),
+            )),
+            timeline: Some(SerializedComponentBatch::new(
+                crate::blueprint::components::TimelineName::arrow_empty(),
+                Self::descriptor_timeline(),
+            )),
+            times: Some(SerializedComponentBatch::new(
+                crate::blueprint::components::KeyframeTime::arrow_empty(),
+                Self::descriptor_times(),
+            )),
+            positions: Some(SerializedComponentBatch::new(
+                crate::components::Position3D::arrow_empty(),
+                Self::descriptor_positions(),
+            )),
+            look_targets: Some(SerializedComponentBatch::new(
+                crate::components::Position3D::arrow_empty(),
+                Self::descriptor_look_targets(),
+            )),
+            chase_entity: Some(SerializedComponentBatch::new(
+                crate::components::EntityPath::arrow_empty(),
+                Self::descriptor_chase_entity(),
+            )),
+        }
+    }
+
+    /// Whether the eye of the view follows the path. Defaults to true.
+    #[inline]
+    pub fn with_enabled(
+        mut self,
+        enabled: impl Into<crate::blueprint::components::Enabled>,
+    ) -> Self {
+        self.enabled = try_serialize_field(Self::descriptor_enabled(), [enabled]);
+        self
+    }
+
+    /// The timeline along which the keyframes are placed.
+    #[inline]
+    pub fn with_timeline(
+        mut self,
+        timeline: impl Into<crate::blueprint::components::TimelineName>,
+    ) -> Self {
+        self.timeline = try_serialize_field(Self::descriptor_timeline(), [timeline]);
+        self
+    }
+
+    /// The time of each keyframe.
+    #[inline]
+    pub fn with_times(
+        mut self,
+        times: impl IntoIterator<Item = impl Into<crate::blueprint::components::KeyframeTime>>,
+    ) -> Self {
+        self.times = try_serialize_field(Self::descriptor_times(), times);
+        self
+    }
+
+    /// Where the eye is at each keyframe.
+    #[inline]
+    pub fn with_positions(
+        mut self,
+        positions: impl IntoIterator<Item = impl Into<crate::components::Position3D>>,
+    ) -> Self {
+        self.positions = try_serialize_field(Self::descriptor_positions(), positions);
+        self
+    }
+
+    /// What the eye looks at, at each keyframe.
+    #[inline]
+    pub fn with_look_targets(
+        mut self,
+        look_targets: impl IntoIterator<Item = impl Into<crate::components::Position3D>>,
+    ) -> Self {
+        self.look_targets = try_serialize_field(Self::descriptor_look_targets(), look_targets);
+        self
+    }
+
+    /// An entity the path is attached to, e.g. a vehicle followed by a chase cam.
+    ///
+    /// If set, positions and look targets are in the space of this entity rather than that of the view.
+    #[inline]
+    pub fn with_chase_entity(
+        mut self,
+        chase_entity: impl Into<crate::components::EntityPath>,
+    ) -> Self {
+        self.chase_entity = try_serialize_field(Self::descriptor_chase_entity(), [chase_entity]);
+        self
+    }
+}
+
+impl ::re_byte_size::SizeBytes for CameraPath {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.enabled.heap_size_bytes()
+            + self.timeline.heap_size_bytes()
+            + self.times.heap_size_bytes()
+            + self.positions.heap_size_bytes()
+            + self.look_targets.heap_size_bytes()
+            + self.chase_entity.heap_size_bytes()
+    }
+}
@@ -0,0 +1,291 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/histogram_bins.fbs".
+
+#![allow(unused_braces)]
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::SerializationResult;
+use ::re_types_core::{ComponentBatch as _, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentName};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Archetype**: Configuration for how a histogram sorts values into bins, and how it shows their counts.
+///
+/// ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+#[derive(Clone, Debug, Default)]
+pub struct HistogramBins {
+    /// How many bins the value range is divided into.
+    ///
+    /// Defaults to 64.
+    pub bin_count: Option<SerializedComponentBatch>,
+
+    /// The range of values that is divided into bins.
+    ///
+    /// Values outside of it aren't counted.
+    /// If unset, the range spans all values shown in the view.
+    pub range: Option<SerializedComponentBatch>,
+
+    /// Whether the bin counts are shown on a logarithmic scale.
+    ///
+    /// Defaults to false.
+    pub log_scale: Option<SerializedComponentBatch>,
+}
+
+impl HistogramBins {
+    /// Returns the [`ComponentDescriptor`] for [`Self::bin_count`].
+    ///
+    /// The corresponding component is [`crate::blueprint::components::BinCount`].
+    #[inline]
+    pub fn descriptor_bin_count() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.blueprint.archetypes.HistogramBins".into()),
+            component_name: Some("rerun.blueprint.components.BinCount".into()),
+            archetype_field_name: "bin_count".into(),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::range`].
+    ///
+    /// The corresponding component is [`crate::components::Range1D`].
+    #[inline]
+    pub fn descriptor_range() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.blueprint.archetypes.HistogramBins".into()),
+            component_name: Some("rerun.components.Range1D".into()),
+            archetype_field_name: "range".into(),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::log_scale`].
+    ///
+    /// The corresponding component is [`crate::blueprint::components::Enabled`].
+    #[inline]
+    pub fn descriptor_log_scale() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.blueprint.archetypes.HistogramBins".into()),
+            component_name: Some("rerun.blueprint.components.Enabled".into()),
+            archetype_field_name: "log_scale".into(),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for the associated indicator component.
+    #[inline]
+    pub fn descriptor_indicator() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: None,
+            component_name: None,
+            archetype_field_name: "rerun.blueprint.components.HistogramBinsIndicator".into(),
+        }
+    }
+}
+
+static REQUIRED_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 0usize]> =
+    once_cell::sync::Lazy::new(|| []);
+
+static RECOMMENDED_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 1usize]> =
+    once_cell::sync::Lazy::new(|| [HistogramBins::descriptor_indicator()]);
+
+static OPTIONAL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 3usize]> =
+    once_cell::sync::Lazy::new(|| {
+        [
+            HistogramBins::descriptor_bin_count(),
+            HistogramBins::descriptor_range(),
+            HistogramBins::descriptor_log_scale(),
+        ]
+    });
+
+static ALL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 4usize]> =
+    once_cell::sync::Lazy::new(|| {
+        [
+            HistogramBins::descriptor_indicator(),
+            HistogramBins::descriptor_bin_count(),
+            HistogramBins::descriptor_range(),
+            HistogramBins::descriptor_log_scale(),
+        ]
+    });
+
+impl HistogramBins {
+    /// The total number of components in the archetype: 0 required, 1 recommended, 3 optional
+    pub const NUM_COMPONENTS: usize = 4usize;
+}
+
+/// Indicator component for the [`HistogramBins`] [`::re_types_core::Archetype`]
+pub type HistogramBinsIndicator = ::re_types_core::GenericIndicatorComponent<HistogramBins>;
+
+impl ::re_types_core::Archetype for HistogramBins {
+    type Indicator = HistogramBinsIndicator;
+
+    #[inline]
+    fn name() -> ::re_types_core::ArchetypeName {
+        "rerun.blueprint.archetypes.HistogramBins".into()
+    }
+
+    #[inline]
+    fn display_name() -> &'static str {
+        "Histogram bins"
+    }
+
+    #[inline]
+    fn indicator() -> SerializedComponentBatch {
+        #[allow(clippy::unwrap_used)]
+        HistogramBinsIndicator::DEFAULT
+            .serialized(Self::descriptor_indicator())
+            .unwrap()
+    }
+
+    #[inline]
+    fn required_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        REQUIRED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn recommended_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        RECOMMENDED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn optional_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        OPTIONAL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn all_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        ALL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn from_arrow_components(
+        arrow_data: impl IntoIterator<Item = (ComponentDescriptor, arrow::array::ArrayRef)>,
+    ) -> DeserializationResult<Self> {
+        re_tracing::profile_function!();
+        use ::re_types_core::{Loggable as _, ResultExt as _};
+        let arrays_by_descr: ::nohash_hasher::IntMap<_, _> = arrow_data.into_iter().collect();
+        let bin_count = arrays_by_descr
+            .get(&Self::descriptor_bin_count())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_bin_count())
+            });
+        let range = arrays_by_descr
+            .get(&Self::descriptor_range())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_range()));
+        let log_scale = arrays_by_descr
+            .get(&Self::descriptor_log_scale())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_log_scale())
+            });
+        Ok(Self {
+            bin_count,
+            range,
+            log_scale,
+        })
+    }
+}
+
+impl ::re_types_core::AsComponents for HistogramBins {
+    #[inline]
+    fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
+        use ::re_types_core::Archetype as _;
+        [
+            Some(Self::indicator()),
+            self.bin_count.clone(),
+            self.range.clone(),
+            self.log_scale.clone(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+impl ::re_types_core::ArchetypeReflectionMarker for HistogramBins {}
+
+impl HistogramBins {
+    /// Create a new `HistogramBins`.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            bin_count: None,
+            range: None,
+            log_scale: None,
+        }
+    }
+
+    /// Update only some specific fields of a `HistogramBins`.
+    #[inline]
+    pub fn update_fields() -> Self {
+        Self::default()
+    }
+
+    /// Clear all the fields of a `HistogramBins`.
+    #[inline]
+    pub fn clear_fields() -> Self {
+        use ::re_types_core::Loggable as _;
+        Self {
+            bin_count: Some(SerializedComponentBatch::new(
+                crate::blueprint::components::BinCount::arrow_empty(),
+                Self::descriptor_bin_count(),
+            )),
+            range: Some(SerializedComponentBatch::new(
+                crate::components::Range1D::arrow_empty(),
+                Self::descriptor_range(),
+            )),
+            log_scale: Some(SerializedComponentBatch::new(
+                crate::blueprint::components::Enabled::arrow_empty(),
+                Self::descriptor_log_scale(),
+            )),
+        }
+    }
+
+    /// How many bins the value range is divided into.
+    ///
+    /// Defaults to 64.
+    #[inline]
+    pub fn with_bin_count(
+        mut self,
+        bin_count: impl Into<crate::blueprint::components::BinCount>,
+    ) -> Self {
+        self.bin_count = try_serialize_field(Self::descriptor_bin_count(), [bin_count]);
+        self
+    }
+
+    /// The range of values that is divided into bins.
+    ///
+    /// Values outside of it aren't counted.
+    /// If unset, the range spans all values shown in the view.
+    #[inline]
+    pub fn with_range(mut self, range: impl Into<crate::components::Range1D>) -> Self {
+        self.range = try_serialize_field(Self::descriptor_range(), [range]);
+        self
+    }
+
+    /// Whether the bin counts are shown on a logarithmic scale.
+    ///
+    /// Defaults to false.
+    #[inline]
+    pub fn with_log_scale(
+        mut self,
+        log_scale: impl Into<crate::blueprint::components::Enabled>,
+    ) -> Self {
+        self.log_scale = try_serialize_field(Self::descriptor_log_scale(), [log_scale]);
+        self
+    }
+}
+
+impl ::re_byte_size::SizeBytes for HistogramBins {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.bin_count.heap_size_bytes()
+            + self.range.heap_size_bytes()
+            + self.log_scale.heap_size_bytes()
+    }
+}
@@ -28,6 +28,12 @@ pub struct MapBackground {
     ///
     /// **Note**: Requires a Mapbox API key in the `RERUN_MAPBOX_ACCESS_TOKEN` environment variable.
     pub provider: Option<SerializedComponentBatch>,
+
+    /// Custom map tiles to show instead of the provider's.
+    ///
+    /// Tiles downloaded from a URL are kept in an on-disk cache, so areas that were viewed once
+    /// remain available without network access.
+    pub tile_source: Option<SerializedComponentBatch>,
 }
 
 impl MapBackground {
@@ -43,6 +49,18 @@ impl MapBackground {
         }
     }
 
+    /// Returns the [`ComponentDescriptor`] for [`Self::tile_source`].
+    ///
+    /// The corresponding component is [`crate::blueprint::components::MapTileSource`].
+    #[inline]
+    pub fn descriptor_tile_source() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.blueprint.archetypes.MapBackground".into()),
+            component_name: Some("rerun.blueprint.components.MapTileSource".into()),
+            archetype_field_name: "tile_source".into(),
+        }
+    }
+
     /// Returns the [`ComponentDescriptor`] for the associated indicator component.
     #[inline]
     pub fn descriptor_indicator() -> ComponentDescriptor {
@@ -60,20 +78,26 @@ static REQUIRED_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 0usize]>
 static RECOMMENDED_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 1usize]> =
     once_cell::sync::Lazy::new(|| [MapBackground::descriptor_indicator()]);
 
-static OPTIONAL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 1usize]> =
-    once_cell::sync::Lazy::new(|| [MapBackground::descriptor_provider()]);
+static OPTIONAL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 2usize]> =
+    once_cell::sync::Lazy::new(|| {
+        [
+            MapBackground::descriptor_provider(),
+            MapBackground::descriptor_tile_source(),
+        ]
+    });
 
-static ALL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 2usize]> =
+static ALL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 3usize]> =
     once_cell::sync::Lazy::new(|| {
         [
             MapBackground::descriptor_indicator(),
             MapBackground::descriptor_provider(),
+            MapBackground::descriptor_tile_source(),
         ]
     });
 
 impl MapBackground {
-    /// The total number of components in the archetype: 0 required, 1 recommended, 1 optional
-    pub const NUM_COMPONENTS: usize = 2usize;
+    /// The total number of components in the archetype: 0 required, 1 recommended, 2 optional
+    pub const NUM_COMPONENTS: usize = 3usize;
 }
 
 /// Indicator component for the [`MapBackground`] [`::re_types_core::Archetype`]
@@ -130,7 +154,15 @@ impl ::re_types_core::Archetype for MapBackground {
         let provider = arrays_by_descr
             .get(&Self::descriptor_provider())
             .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_provider()));
-        Ok(Self { provider })
+        let tile_source = arrays_by_descr
+            .get(&Self::descriptor_tile_source())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_tile_source())
+            });
+        Ok(Self {
+            provider,
+            tile_source,
+        })
     }
 }
 
@@ -138,10 +170,14 @@ impl ::re_types_core::AsComponents for MapBackground {
     #[inline]
     fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
         use ::re_types_core::Archetype as _;
-        [Some(Self::indicator()), self.provider.clone()]
-            .into_iter()
-            .flatten()
-            .collect()
+        [
+            Some(Self::indicator()),
+            self.provider.clone(),
+            self.tile_source.clone(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
     }
 }
 
@@ -153,6 +189,7 @@ impl MapBackground {
     pub fn new(provider: impl Into<crate::blueprint::components::MapProvider>) -> Self {
         Self {
             provider: try_serialize_field(Self::descriptor_provider(), [provider]),
+            tile_source: None,
         }
     }
 
@@ -171,6 +208,10 @@ impl MapBackground {
                 crate::blueprint::components::MapProvider::arrow_empty(),
                 Self::descriptor_provider(),
             )),
+            tile_source: Some(SerializedComponentBatch::new(
+                crate::blueprint::components::MapTileSource::arrow_empty(),
+                Self::descriptor_tile_source(),
+            )),
         }
     }
 
@@ -185,11 +226,24 @@ impl MapBackground {
         self.provider = try_serialize_field(Self::descriptor_provider(), [provider]);
         self
     }
+
+    /// Custom map tiles to show instead of the provider's.
+    ///
+    /// Tiles downloaded from a URL are kept in an on-disk cache, so areas that were viewed once
+    /// remain available without network access.
+    #[inline]
+    pub fn with_tile_source(
+        mut self,
+        tile_source: impl Into<crate::blueprint::components::MapTileSource>,
+    ) -> Self {
+        self.tile_source = try_serialize_field(Self::descriptor_tile_source(), [tile_source]);
+        self
+    }
 }
 
 impl ::re_byte_size::SizeBytes for MapBackground {
     #[inline]
     fn heap_size_bytes(&self) -> u64 {
-        self.provider.heap_size_bytes()
+        self.provider.heap_size_bytes() + self.tile_source.heap_size_bytes()
     }
 }
@@ -0,0 +1,234 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/map_follow.fbs".
+
+#![allow(unused_braces)]
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::SerializationResult;
+use ::re_types_core::{ComponentBatch as _, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentName};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Archetype**: Configuration of the map view camera following an entity.
+///
+/// ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+#[derive(Clone, Debug, Default)]
+pub struct MapFollow {
+    /// Whether and how the map follows the entity.
+    pub mode: Option<SerializedComponentBatch>,
+
+    /// The entity to follow.
+    ///
+    /// Its latest geospatial position is used, taken from either `GeoPoints` or the last vertex
+    /// of `GeoLineStrings`.
+    /// If not set, the origin of the view is followed.
+    pub entity: Option<SerializedComponentBatch>,
+}
+
+impl MapFollow {
+    /// Returns the [`ComponentDescriptor`] for [`Self::mode`].
+    ///
+    /// The corresponding component is [`crate::blueprint::components::MapFollowMode`].
+    #[inline]
+    pub fn descriptor_mode() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.blueprint.archetypes.MapFollow".into()),
+            component_name: Some("rerun.blueprint.components.MapFollowMode".into()),
+            archetype_field_name: "mode".into(),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::entity`].
+    ///
+    /// The corresponding component is [`crate::components::EntityPath`].
+    #[inline]
+    pub fn descriptor_entity() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.blueprint.archetypes.MapFollow".into()),
+            component_name: Some("rerun.components.EntityPath".into()),
+            archetype_field_name: "entity".into(),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for the associated indicator component.
+    #[inline]
+    pub fn descriptor_indicator() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: None,
+            component_name: None,
+            archetype_field_name: "rerun.blueprint.components.MapFollowIndicator".into(),
+        }
+    }
+}
+
+static REQUIRED_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 0usize]> =
+    once_cell::sync::Lazy::new(|| []);
+
+static RECOMMENDED_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 1usize]> =
+    once_cell::sync::Lazy::new(|| [MapFollow::descriptor_indicator()]);
+
+static OPTIONAL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 2usize]> =
+    once_cell::sync::Lazy::new(|| [MapFollow::descriptor_mode(), MapFollow::descriptor_entity()]);
+
+static ALL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 3usize]> =
+    once_cell::sync::Lazy::new(|| {
+        [
+            MapFollow::descriptor_indicator(),
+            MapFollow::descriptor_mode(),
+            MapFollow::descriptor_entity(),
+        ]
+    });
+
+impl MapFollow {
+    /// The total number of components in the archetype: 0 required, 1 recommended, 2 optional
+    pub const NUM_COMPONENTS: usize = 3usize;
+}
+
+/// Indicator component for the [`MapFollow`] [`::re_types_core::Archetype`]
+pub type MapFollowIndicator = ::re_types_core::GenericIndicatorComponent<MapFollow>;
+
+impl ::re_types_core::Archetype for MapFollow {
+    type Indicator = MapFollowIndicator;
+
+    #[inline]
+    fn name() -> ::re_types_core::ArchetypeName {
+        "rerun.blueprint.archetypes.MapFollow".into()
+    }
+
+    #[inline]
+    fn display_name() -> &'static str {
+        "Map follow"
+    }
+
+    #[inline]
+    fn indicator() -> SerializedComponentBatch {
+        #[allow(clippy::unwrap_used)]
+        MapFollowIndicator::DEFAULT
+            .serialized(Self::descriptor_indicator())
+            .unwrap()
+    }
+
+    #[inline]
+    fn required_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        REQUIRED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn recommended_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        RECOMMENDED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn optional_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        OPTIONAL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn all_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        ALL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn from_arrow_components(
+        arrow_data: impl IntoIterator<Item = (ComponentDescriptor, arrow::array::ArrayRef)>,
+    ) -> DeserializationResult<Self> {
+        re_tracing::profile_function!();
+        use ::re_types_core::{Loggable as _, ResultExt as _};
+        let arrays_by_descr: ::nohash_hasher::IntMap<_, _> = arrow_data.into_iter().collect();
+        let mode = arrays_by_descr
+            .get(&Self::descriptor_mode())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_mode()));
+        let entity = arrays_by_descr
+            .get(&Self::descriptor_entity())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_entity()));
+        Ok(Self { mode, entity })
+    }
+}
+
+impl ::re_types_core::AsComponents for MapFollow {
+    #[inline]
+    fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
+        use ::re_types_core::Archetype as _;
+        [
+            Some(Self::indicator()),
+            self.mode.clone(),
+            self.entity.clone(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+impl ::re_types_core::ArchetypeReflectionMarker for MapFollow {}
+
+impl MapFollow {
+    /// Create a new `MapFollow`.
+    #[inline]
+    pub fn new(mode: impl Into<crate::blueprint::components::MapFollowMode>) -> Self {
+        Self {
+            mode: try_serialize_field(Self::descriptor_mode(), [mode]),
+            entity: None,
+        }
+    }
+
+    /// Update only some specific fields of a `MapFollow`.
+    #[inline]
+    pub fn update_fields() -> Self {
+        Self::default()
+    }
+
+    /// Clear all the fields of a `MapFollow`.
+    #[inline]
+    pub fn clear_fields() -> Self {
+        use ::re_types_core::Loggable as _;
+        Self {
+            mode: Some(SerializedComponentBatch::new(
+                crate::blueprint::components::MapFollowMode::arrow_empty(),
+                Self::descriptor_mode(),
+            )),
+            entity: Some(SerializedComponentBatch::new(
+                crate::components::EntityPath::arrow_empty(),
+                Self::descriptor_entity(),
+            )),
+        }
+    }
+
+    /// Whether and how the map follows the entity.
+    #[inline]
+    pub fn with_mode(
+        mut self,
+        mode: impl Into<crate::blueprint::components::MapFollowMode>,
+    ) -> Self {
+        self.mode = try_serialize_field(Self::descriptor_mode(), [mode]);
+        self
+    }
+
+    /// The entity to follow.
+    ///
+    /// Its latest geospatial position is used, taken from either `GeoPoints` or the last vertex
+    /// of `GeoLineStrings`.
+    /// If not set, the origin of the view is followed.
+    #[inline]
+    pub fn with_entity(mut self, entity: impl Into<crate::components::EntityPath>) -> Self {
+        self.entity = try_serialize_field(Self::descriptor_entity(), [entity]);
+        self
+    }
+}
+
+impl ::re_byte_size::SizeBytes for MapFollow {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.mode.heap_size_bytes() + self.entity.heap_size_bytes()
+    }
+}
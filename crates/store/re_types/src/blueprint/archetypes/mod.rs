@@ -1,6 +1,7 @@
 // DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
 
 mod background;
+mod camera_path;
 mod container_blueprint;
 mod dataframe_query;
 mod entity_behavior;
@@ -9,25 +10,32 @@ mod force_collision_radius;
 mod force_link;
 mod force_many_body;
 mod force_position;
+mod histogram_bins;
 mod line_grid3d;
 mod map_background;
+mod map_follow;
 mod map_zoom;
 mod near_clip_plane;
 mod panel_blueprint;
 mod plot_legend;
 mod scalar_axis;
+mod spectrogram_window;
 mod tensor_scalar_mapping;
 mod tensor_slice_selection;
 mod tensor_view_fit;
+mod tensor_volume;
 mod time_axis;
+mod timeline_bookmarks;
 mod view_blueprint;
 mod view_contents;
 mod viewport_blueprint;
 mod visible_time_ranges;
 mod visual_bounds2d;
 mod visualizer_overrides;
+mod x_axis;
 
 pub use self::background::Background;
+pub use self::camera_path::CameraPath;
 pub use self::container_blueprint::ContainerBlueprint;
 pub use self::dataframe_query::DataframeQuery;
 pub use self::entity_behavior::EntityBehavior;
@@ -36,20 +44,26 @@ pub use self::force_collision_radius::ForceCollisionRadius;
 pub use self::force_link::ForceLink;
 pub use self::force_many_body::ForceManyBody;
 pub use self::force_position::ForcePosition;
+pub use self::histogram_bins::HistogramBins;
 pub use self::line_grid3d::LineGrid3D;
 pub use self::map_background::MapBackground;
+pub use self::map_follow::MapFollow;
 pub use self::map_zoom::MapZoom;
 pub use self::near_clip_plane::NearClipPlane;
 pub use self::panel_blueprint::PanelBlueprint;
 pub use self::plot_legend::PlotLegend;
 pub use self::scalar_axis::ScalarAxis;
+pub use self::spectrogram_window::SpectrogramWindow;
 pub use self::tensor_scalar_mapping::TensorScalarMapping;
 pub use self::tensor_slice_selection::TensorSliceSelection;
 pub use self::tensor_view_fit::TensorViewFit;
+pub use self::tensor_volume::TensorVolume;
 pub use self::time_axis::TimeAxis;
+pub use self::timeline_bookmarks::TimelineBookmarks;
 pub use self::view_blueprint::ViewBlueprint;
 pub use self::view_contents::ViewContents;
 pub use self::viewport_blueprint::ViewportBlueprint;
 pub use self::visible_time_ranges::VisibleTimeRanges;
 pub use self::visual_bounds2d::VisualBounds2D;
 pub use self::visualizer_overrides::VisualizerOverrides;
+pub use self::x_axis::XAxis;
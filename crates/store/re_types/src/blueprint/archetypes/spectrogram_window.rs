@@ -0,0 +1,283 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/spectrogram_window.fbs".
+
+#![allow(unused_braces)]
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::SerializationResult;
+use ::re_types_core::{ComponentBatch as _, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentName};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Archetype**: Configuration for how a spectrogram splits a signal into windows and weights their samples.
+///
+/// ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+#[derive(Clone, Debug, Default)]
+pub struct SpectrogramWindow {
+    /// How many samples each window spans.
+    ///
+    /// Rounded up to the next power of two. Defaults to 256.
+    pub length: Option<SerializedComponentBatch>,
+
+    /// How many samples a window advances by between two columns of the spectrogram.
+    ///
+    /// Defaults to a quarter of the window length.
+    pub hop: Option<SerializedComponentBatch>,
+
+    /// The function the samples of each window are weighted with.
+    ///
+    /// Defaults to a Hann window.
+    pub function: Option<SerializedComponentBatch>,
+}
+
+impl SpectrogramWindow {
+    /// Returns the [`ComponentDescriptor`] for [`Self::length`].
+    ///
+    /// The corresponding component is [`crate::blueprint::components::WindowLength`].
+    #[inline]
+    pub fn descriptor_length() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.blueprint.archetypes.SpectrogramWindow".into()),
+            component_name: Some("rerun.blueprint.components.WindowLength".into()),
+            archetype_field_name: "length".into(),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::hop`].
+    ///
+    /// The corresponding component is [`crate::blueprint::components::HopLength`].
+    #[inline]
+    pub fn descriptor_hop() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.blueprint.archetypes.SpectrogramWindow".into()),
+            component_name: Some("rerun.blueprint.components.HopLength".into()),
+            archetype_field_name: "hop".into(),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::function`].
+    ///
+    /// The corresponding component is [`crate::blueprint::components::WindowFunction`].
+    #[inline]
+    pub fn descriptor_function() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.blueprint.archetypes.SpectrogramWindow".into()),
+            component_name: Some("rerun.blueprint.components.WindowFunction".into()),
+            archetype_field_name: "function".into(),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for the associated indicator component.
+    #[inline]
+    pub fn descriptor_indicator() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: None,
+            component_name: None,
+            archetype_field_name: "rerun.blueprint.components.SpectrogramWindowIndicator".into(),
+        }
+    }
+}
+
+static REQUIRED_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 0usize]> =
+    once_cell::sync::Lazy::new(|| []);
+
+static RECOMMENDED_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 1usize]> =
+    once_cell::sync::Lazy::new(|| [SpectrogramWindow::descriptor_indicator()]);
+
+static OPTIONAL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 3usize]> =
+    once_cell::sync::Lazy::new(|| {
+        [
+            SpectrogramWindow::descriptor_length(),
+            SpectrogramWindow::descriptor_hop(),
+            SpectrogramWindow::descriptor_function(),
+        ]
+    });
+
+static ALL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 4usize]> =
+    once_cell::sync::Lazy::new(|| {
+        [
+            SpectrogramWindow::descriptor_indicator(),
+            SpectrogramWindow::descriptor_length(),
+            SpectrogramWindow::descriptor_hop(),
+            SpectrogramWindow::descriptor_function(),
+        ]
+    });
+
+impl SpectrogramWindow {
+    /// The total number of components in the archetype: 0 required, 1 recommended, 3 optional
+    pub const NUM_COMPONENTS: usize = 4usize;
+}
+
+/// Indicator component for the [`SpectrogramWindow`] [`::re_types_core::Archetype`]
+pub type SpectrogramWindowIndicator = ::re_types_core::GenericIndicatorComponent<SpectrogramWindow>;
+
+impl ::re_types_core::Archetype for SpectrogramWindow {
+    type Indicator = SpectrogramWindowIndicator;
+
+    #[inline]
+    fn name() -> ::re_types_core::ArchetypeName {
+        "rerun.blueprint.archetypes.SpectrogramWindow".into()
+    }
+
+    #[inline]
+    fn display_name() -> &'static str {
+        "Spectrogram window"
+    }
+
+    #[inline]
+    fn indicator() -> SerializedComponentBatch {
+        #[allow(clippy::unwrap_used)]
+        SpectrogramWindowIndicator::DEFAULT
+            .serialized(Self::descriptor_indicator())
+            .unwrap()
+    }
+
+    #[inline]
+    fn required_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        REQUIRED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn recommended_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        RECOMMENDED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn optional_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        OPTIONAL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn all_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        ALL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn from_arrow_components(
+        arrow_data: impl IntoIterator<Item = (ComponentDescriptor, arrow::array::ArrayRef)>,
+    ) -> DeserializationResult<Self> {
+        re_tracing::profile_function!();
+        use ::re_types_core::{Loggable as _, ResultExt as _};
+        let arrays_by_descr: ::nohash_hasher::IntMap<_, _> = arrow_data.into_iter().collect();
+        let length = arrays_by_descr
+            .get(&Self::descriptor_length())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_length()));
+        let hop = arrays_by_descr
+            .get(&Self::descriptor_hop())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_hop()));
+        let function = arrays_by_descr
+            .get(&Self::descriptor_function())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_function()));
+        Ok(Self {
+            length,
+            hop,
+            function,
+        })
+    }
+}
+
+impl ::re_types_core::AsComponents for SpectrogramWindow {
+    #[inline]
+    fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
+        use ::re_types_core::Archetype as _;
+        [
+            Some(Self::indicator()),
+            self.length.clone(),
+            self.hop.clone(),
+            self.function.clone(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+impl ::re_types_core::ArchetypeReflectionMarker for SpectrogramWindow {}
+
+impl SpectrogramWindow {
+    /// Create a new `SpectrogramWindow`.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            length: None,
+            hop: None,
+            function: None,
+        }
+    }
+
+    /// Update only some specific fields of a `SpectrogramWindow`.
+    #[inline]
+    pub fn update_fields() -> Self {
+        Self::default()
+    }
+
+    /// Clear all the fields of a `SpectrogramWindow`.
+    #[inline]
+    pub fn clear_fields() -> Self {
+        use ::re_types_core::Loggable as _;
+        Self {
+            length: Some(SerializedComponentBatch::new(
+                crate::blueprint::components::WindowLength::arrow_empty(),
+                Self::descriptor_length(),
+            )),
+            hop: Some(SerializedComponentBatch::new(
+                crate::blueprint::components::HopLength::arrow_empty(),
+                Self::descriptor_hop(),
+            )),
+            function: Some(SerializedComponentBatch::new(
+                crate::blueprint::components::WindowFunction::arrow_empty(),
+                Self::descriptor_function(),
+            )),
+        }
+    }
+
+    /// How many samples each window spans.
+    ///
+    /// Rounded up to the next power of two. Defaults to 256.
+    #[inline]
+    pub fn with_length(
+        mut self,
+        length: impl Into<crate::blueprint::components::WindowLength>,
+    ) -> Self {
+        self.length = try_serialize_field(Self::descriptor_length(), [length]);
+        self
+    }
+
+    /// How many samples a window advances by between two columns of the spectrogram.
+    ///
+    /// Defaults to a quarter of the window length.
+    #[inline]
+    pub fn with_hop(mut self, hop: impl Into<crate::blueprint::components::HopLength>) -> Self {
+        self.hop = try_serialize_field(Self::descriptor_hop(), [hop]);
+        self
+    }
+
+    /// The function the samples of each window are weighted with.
+    ///
+    /// Defaults to a Hann window.
+    #[inline]
+    pub fn with_function(
+        mut self,
+        function: impl Into<crate::blueprint::components::WindowFunction>,
+    ) -> Self {
+        self.function = try_serialize_field(Self::descriptor_function(), [function]);
+        self
+    }
+}
+
+impl ::re_byte_size::SizeBytes for SpectrogramWindow {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.length.heap_size_bytes() + self.hop.heap_size_bytes() + self.function.heap_size_bytes()
+    }
+}
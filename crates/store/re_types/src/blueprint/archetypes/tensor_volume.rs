@@ -0,0 +1,283 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/tensor_volume.fbs".
+
+#![allow(unused_braces)]
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::SerializationResult;
+use ::re_types_core::{ComponentBatch as _, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentName};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Archetype**: Configuration for how three-dimensional tensors are shown as volumes or iso-surfaces in 3D views.
+///
+/// ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+#[derive(Clone, Debug, Default)]
+pub struct TensorVolume {
+    /// The colormap applied to the tensor's values.
+    ///
+    /// Defaults to viridis.
+    pub colormap: Option<SerializedComponentBatch>,
+
+    /// Opacity contributed by each voxel when shown as a volume.
+    ///
+    /// Since it accumulates along every ray, even small values can make the volume opaque.
+    /// Defaults to 0.05.
+    pub opacity: Option<SerializedComponentBatch>,
+
+    /// The value at which the iso-surface is extracted when shown as an iso-surface.
+    ///
+    /// Defaults to the center of the tensor's value range.
+    pub iso_value: Option<SerializedComponentBatch>,
+}
+
+impl TensorVolume {
+    /// Returns the [`ComponentDescriptor`] for [`Self::colormap`].
+    ///
+    /// The corresponding component is [`crate::components::Colormap`].
+    #[inline]
+    pub fn descriptor_colormap() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.blueprint.archetypes.TensorVolume".into()),
+            component_name: Some("rerun.components.Colormap".into()),
+            archetype_field_name: "colormap".into(),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::opacity`].
+    ///
+    /// The corresponding component is [`crate::components::Opacity`].
+    #[inline]
+    pub fn descriptor_opacity() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.blueprint.archetypes.TensorVolume".into()),
+            component_name: Some("rerun.components.Opacity".into()),
+            archetype_field_name: "opacity".into(),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::iso_value`].
+    ///
+    /// The corresponding component is [`crate::components::Scalar`].
+    #[inline]
+    pub fn descriptor_iso_value() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.blueprint.archetypes.TensorVolume".into()),
+            component_name: Some("rerun.components.Scalar".into()),
+            archetype_field_name: "iso_value".into(),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for the associated indicator component.
+    #[inline]
+    pub fn descriptor_indicator() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: None,
+            component_name: None,
+            archetype_field_name: "rerun.blueprint.components.TensorVolumeIndicator".into(),
+        }
+    }
+}
+
+static REQUIRED_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 0usize]> =
+    once_cell::sync::Lazy::new(|| []);
+
+static RECOMMENDED_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 1usize]> =
+    once_cell::sync::Lazy::new(|| [TensorVolume::descriptor_indicator()]);
+
+static OPTIONAL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 3usize]> =
+    once_cell::sync::Lazy::new(|| {
+        [
+            TensorVolume::descriptor_colormap(),
+            TensorVolume::descriptor_opacity(),
+            TensorVolume::descriptor_iso_value(),
+        ]
+    });
+
+static ALL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 4usize]> =
+    once_cell::sync::Lazy::new(|| {
+        [
+            TensorVolume::descriptor_indicator(),
+            TensorVolume::descriptor_colormap(),
+            TensorVolume::descriptor_opacity(),
+            TensorVolume::descriptor_iso_value(),
+        ]
+    });
+
+impl TensorVolume {
+    /// The total number of components in the archetype: 0 required, 1 recommended, 3 optional
+    pub const NUM_COMPONENTS: usize = 4usize;
+}
+
+/// Indicator component for the [`TensorVolume`] [`::re_types_core::Archetype`]
+pub type TensorVolumeIndicator = ::re_types_core::GenericIndicatorComponent<TensorVolume>;
+
+impl ::re_types_core::Archetype for TensorVolume {
+    type Indicator = TensorVolumeIndicator;
+
+    #[inline]
+    fn name() -> ::re_types_core::ArchetypeName {
+        "rerun.blueprint.archetypes.TensorVolume".into()
+    }
+
+    #[inline]
+    fn display_name() -> &'static str {
+        "Tensor volume"
+    }
+
+    #[inline]
+    fn indicator() -> SerializedComponentBatch {
+        #[allow(clippy::unwrap_used)]
+        TensorVolumeIndicator::DEFAULT
+            .serialized(Self::descriptor_indicator())
+            .unwrap()
+    }
+
+    #[inline]
+    fn required_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        REQUIRED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn recommended_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        RECOMMENDED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn optional_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        OPTIONAL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn all_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        ALL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn from_arrow_components(
+        arrow_data: impl IntoIterator<Item = (ComponentDescriptor, arrow::array::ArrayRef)>,
+    ) -> DeserializationResult<Self> {
+        re_tracing::profile_function!();
+        use ::re_types_core::{Loggable as _, ResultExt as _};
+        let arrays_by_descr: ::nohash_hasher::IntMap<_, _> = arrow_data.into_iter().collect();
+        let colormap = arrays_by_descr
+            .get(&Self::descriptor_colormap())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_colormap()));
+        let opacity = arrays_by_descr
+            .get(&Self::descriptor_opacity())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_opacity()));
+        let iso_value = arrays_by_descr
+            .get(&Self::descriptor_iso_value())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_iso_value())
+            });
+        Ok(Self {
+            colormap,
+            opacity,
+            iso_value,
+        })
+    }
+}
+
+impl ::re_types_core::AsComponents for TensorVolume {
+    #[inline]
+    fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
+        use ::re_types_core::Archetype as _;
+        [
+            Some(Self::indicator()),
+            self.colormap.clone(),
+            self.opacity.clone(),
+            self.iso_value.clone(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+impl ::re_types_core::ArchetypeReflectionMarker for TensorVolume {}
+
+impl TensorVolume {
+    /// Create a new `TensorVolume`.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            colormap: None,
+            opacity: None,
+            iso_value: None,
+        }
+    }
+
+    /// Update only some specific fields of a `TensorVolume`.
+    #[inline]
+    pub fn update_fields() -> Self {
+        Self::default()
+    }
+
+    /// Clear all the fields of a `TensorVolume`.
+    #[inline]
+    pub fn clear_fields() -> Self {
+        use ::re_types_core::Loggable as _;
+        Self {
+            colormap: Some(SerializedComponentBatch::new(
+                crate::components::Colormap::arrow_empty(),
+                Self::descriptor_colormap(),
+            )),
+            opacity: Some(SerializedComponentBatch::new(
+                crate::components::Opacity::arrow_empty(),
+                Self::descriptor_opacity(),
+            )),
+            iso_value: Some(SerializedComponentBatch::new(
+                crate::components::Scalar::arrow_empty(),
+                Self::descriptor_iso_value(),
+            )),
+        }
+    }
+
+    /// The colormap applied to the tensor's values.
+    ///
+    /// Defaults to viridis.
+    #[inline]
+    pub fn with_colormap(mut self, colormap: impl Into<crate::components::Colormap>) -> Self {
+        self.colormap = try_serialize_field(Self::descriptor_colormap(), [colormap]);
+        self
+    }
+
+    /// Opacity contributed by each voxel when shown as a volume.
+    ///
+    /// Since it accumulates along every ray, even small values can make the volume opaque.
+    /// Defaults to 0.05.
+    #[inline]
+    pub fn with_opacity(mut self, opacity: impl Into<crate::components::Opacity>) -> Self {
+        self.opacity = try_serialize_field(Self::descriptor_opacity(), [opacity]);
+        self
+    }
+
+    /// The value at which the iso-surface is extracted when shown as an iso-surface.
+    ///
+    /// Defaults to the center of the tensor's value range.
+    #[inline]
+    pub fn with_iso_value(mut self, iso_value: impl Into<crate::components::Scalar>) -> Self {
+        self.iso_value = try_serialize_field(Self::descriptor_iso_value(), [iso_value]);
+        self
+    }
+}
+
+impl ::re_byte_size::SizeBytes for TensorVolume {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.colormap.heap_size_bytes()
+            + self.opacity.heap_size_bytes()
+            + self.iso_value.heap_size_bytes()
+    }
+}
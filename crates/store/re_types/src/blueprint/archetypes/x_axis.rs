@@ -0,0 +1,285 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/x_axis.fbs".
+
+#![allow(unused_braces)]
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::SerializationResult;
+use ::re_types_core::{ComponentBatch as _, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentName};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Archetype**: Configuration for the X axis of an XY plot.
+///
+/// ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+#[derive(Clone, Debug, Default)]
+pub struct XAxis {
+    /// The scalar column plotted along the X axis.
+    ///
+    /// Every other scalar in the view is plotted against it. Only the first series of the entity is used.
+    /// If unset, the first entity in the view is used.
+    pub source: Option<SerializedComponentBatch>,
+
+    /// The range of the axis.
+    ///
+    /// If unset, the range will be automatically determined based on the queried data.
+    pub range: Option<SerializedComponentBatch>,
+
+    /// If enabled, the X axis range will remain locked to the specified range when zooming.
+    pub zoom_lock: Option<SerializedComponentBatch>,
+}
+
+impl XAxis {
+    /// Returns the [`ComponentDescriptor`] for [`Self::source`].
+    ///
+    /// The corresponding component is [`crate::blueprint::components::ComponentColumnSelector`].
+    #[inline]
+    pub fn descriptor_source() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.blueprint.archetypes.XAxis".into()),
+            component_name: Some("rerun.blueprint.components.ComponentColumnSelector".into()),
+            archetype_field_name: "source".into(),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::range`].
+    ///
+    /// The corresponding component is [`crate::components::Range1D`].
+    #[inline]
+    pub fn descriptor_range() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.blueprint.archetypes.XAxis".into()),
+            component_name: Some("rerun.components.Range1D".into()),
+            archetype_field_name: "range".into(),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::zoom_lock`].
+    ///
+    /// The corresponding component is [`crate::blueprint::components::LockRangeDuringZoom`].
+    #[inline]
+    pub fn descriptor_zoom_lock() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: Some("rerun.blueprint.archetypes.XAxis".into()),
+            component_name: Some("rerun.blueprint.components.LockRangeDuringZoom".into()),
+            archetype_field_name: "zoom_lock".into(),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for the associated indicator component.
+    #[inline]
+    pub fn descriptor_indicator() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype_name: None,
+            component_name: None,
+            archetype_field_name: "rerun.blueprint.components.XAxisIndicator".into(),
+        }
+    }
+}
+
+static REQUIRED_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 0usize]> =
+    once_cell::sync::Lazy::new(|| []);
+
+static RECOMMENDED_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 1usize]> =
+    once_cell::sync::Lazy::new(|| [XAxis::descriptor_indicator()]);
+
+static OPTIONAL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 3usize]> =
+    once_cell::sync::Lazy::new(|| {
+        [
+            XAxis::descriptor_source(),
+            XAxis::descriptor_range(),
+            XAxis::descriptor_zoom_lock(),
+        ]
+    });
+
+static ALL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 4usize]> =
+    once_cell::sync::Lazy::new(|| {
+        [
+            XAxis::descriptor_indicator(),
+            XAxis::descriptor_source(),
+            XAxis::descriptor_range(),
+            XAxis::descriptor_zoom_lock(),
+        ]
+    });
+
+impl XAxis {
+    /// The total number of components in the archetype: 0 required, 1 recommended, 3 optional
+    pub const NUM_COMPONENTS: usize = 4usize;
+}
+
+/// Indicator component for the [`XAxis`] [`::re_types_core::Archetype`]
+pub type XAxisIndicator = ::re_types_core::GenericIndicatorComponent<XAxis>;
+
+impl ::re_types_core::Archetype for XAxis {
+    type Indicator = XAxisIndicator;
+
+    #[inline]
+    fn name() -> ::re_types_core::ArchetypeName {
+        "rerun.blueprint.archetypes.XAxis".into()
+    }
+
+    #[inline]
+    fn display_name() -> &'static str {
+        "X axis"
+    }
+
+    #[inline]
+    fn indicator() -> SerializedComponentBatch {
+        #[allow(clippy::unwrap_used)]
+        XAxisIndicator::DEFAULT
+            .serialized(Self::descriptor_indicator())
+            .unwrap()
+    }
+
+    #[inline]
+    fn required_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        REQUIRED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn recommended_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        RECOMMENDED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn optional_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        OPTIONAL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn all_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        ALL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn from_arrow_components(
+        arrow_data: impl IntoIterator<Item = (ComponentDescriptor, arrow::array::ArrayRef)>,
+    ) -> DeserializationResult<Self> {
+        re_tracing::profile_function!();
+        use ::re_types_core::{Loggable as _, ResultExt as _};
+        let arrays_by_descr: ::nohash_hasher::IntMap<_, _> = arrow_data.into_iter().collect();
+        let source = arrays_by_descr
+            .get(&Self::descriptor_source())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_source()));
+        let range = arrays_by_descr
+            .get(&Self::descriptor_range())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_range()));
+        let zoom_lock = arrays_by_descr
+            .get(&Self::descriptor_zoom_lock())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_zoom_lock())
+            });
+        Ok(Self {
+            source,
+            range,
+            zoom_lock,
+        })
+    }
+}
+
+impl ::re_types_core::AsComponents for XAxis {
+    #[inline]
+    fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
+        use ::re_types_core::Archetype as _;
+        [
+            Some(Self::indicator()),
+            self.source.clone(),
+            self.range.clone(),
+            self.zoom_lock.clone(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+impl ::re_types_core::ArchetypeReflectionMarker for XAxis {}
+
+impl XAxis {
+    /// Create a new `XAxis`.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            source: None,
+            range: None,
+            zoom_lock: None,
+        }
+    }
+
+    /// Update only some specific fields of a `XAxis`.
+    #[inline]
+    pub fn update_fields() -> Self {
+        Self::default()
+    }
+
+    /// Clear all the fields of a `XAxis`.
+    #[inline]
+    pub fn clear_fields() -> Self {
+        use ::re_types_core::Loggable as _;
+        Self {
+            source: Some(SerializedComponentBatch::new(
+                crate::blueprint::components::ComponentColumnSelector::arrow_empty(),
+                Self::descriptor_source(),
+            )),
+            range: Some(SerializedComponentBatch::new(
+                crate::components::Range1D::arrow_empty(),
+                Self::descriptor_range(),
+            )),
+            zoom_lock: Some(SerializedComponentBatch::new(
+                crate::blueprint::components::LockRangeDuringZoom::arrow_empty(),
+                Self::descriptor_zoom_lock(),
+            )),
+        }
+    }
+
+    /// The scalar column plotted along the X axis.
+    ///
+    /// Every other scalar in the view is plotted against it. Only the first series of the entity is used.
+    /// If unset, the first entity in the view is used.
+    #[inline]
+    pub fn with_source(
+        mut self,
+        source: impl Into<crate::blueprint::components::ComponentColumnSelector>,
+    ) -> Self {
+        self.source = try_serialize_field(Self::descriptor_source(), [source]);
+        self
+    }
+
+    /// The range of the axis.
+    ///
+    /// If unset, the range will be automatically determined based on the queried data.
+    #[inline]
+    pub fn with_range(mut self, range: impl Into<crate::components::Range1D>) -> Self {
+        self.range = try_serialize_field(Self::descriptor_range(), [range]);
+        self
+    }
+
+    /// If enabled, the X axis range will remain locked to the specified range when zooming.
+    #[inline]
+    pub fn with_zoom_lock(
+        mut self,
+        zoom_lock: impl Into<crate::blueprint::components::LockRangeDuringZoom>,
+    ) -> Self {
+        self.zoom_lock = try_serialize_field(Self::descriptor_zoom_lock(), [zoom_lock]);
+        self
+    }
+}
+
+impl ::re_byte_size::SizeBytes for XAxis {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.source.heap_size_bytes()
+            + self.range.heap_size_bytes()
+            + self.zoom_lock.heap_size_bytes()
+    }
+}
@@ -6,6 +6,8 @@ mod auto_layout;
 mod auto_layout_ext;
 mod auto_views;
 mod background_kind;
+mod bin_count;
+mod bookmark_time;
 mod column_share;
 mod component_column_selector;
 mod component_column_selector_ext;
@@ -24,10 +26,14 @@ mod force_strength;
 mod grid_columns;
 mod grid_spacing;
 mod grid_spacing_ext;
+mod hop_length;
 mod included_content;
+mod keyframe_time;
 mod link_axis;
 mod lock_range_during_zoom;
+mod map_follow_mode;
 mod map_provider;
+mod map_tile_source;
 mod near_clip_plane;
 mod near_clip_plane_ext;
 mod panel_state;
@@ -52,6 +58,8 @@ mod visible_time_range;
 mod visual_bounds2d;
 mod visual_bounds2d_ext;
 mod visualizer_override;
+mod window_function;
+mod window_length;
 mod zoom_level;
 
 pub use self::active_tab::ActiveTab;
@@ -59,6 +67,8 @@ pub use self::apply_latest_at::ApplyLatestAt;
 pub use self::auto_layout::AutoLayout;
 pub use self::auto_views::AutoViews;
 pub use self::background_kind::BackgroundKind;
+pub use self::bin_count::BinCount;
+pub use self::bookmark_time::BookmarkTime;
 pub use self::column_share::ColumnShare;
 pub use self::component_column_selector::ComponentColumnSelector;
 pub use self::container_kind::ContainerKind;
@@ -71,10 +81,14 @@ pub use self::force_iterations::ForceIterations;
 pub use self::force_strength::ForceStrength;
 pub use self::grid_columns::GridColumns;
 pub use self::grid_spacing::GridSpacing;
+pub use self::hop_length::HopLength;
 pub use self::included_content::IncludedContent;
+pub use self::keyframe_time::KeyframeTime;
 pub use self::link_axis::LinkAxis;
 pub use self::lock_range_during_zoom::LockRangeDuringZoom;
+pub use self::map_follow_mode::MapFollowMode;
 pub use self::map_provider::MapProvider;
+pub use self::map_tile_source::MapTileSource;
 pub use self::near_clip_plane::NearClipPlane;
 pub use self::panel_state::PanelState;
 pub use self::query_expression::QueryExpression;
@@ -91,4 +105,6 @@ pub use self::viewer_recommendation_hash::ViewerRecommendationHash;
 pub use self::visible_time_range::VisibleTimeRange;
 pub use self::visual_bounds2d::VisualBounds2D;
 pub use self::visualizer_override::VisualizerOverride;
+pub use self::window_function::WindowFunction;
+pub use self::window_length::WindowLength;
 pub use self::zoom_level::ZoomLevel;
@@ -0,0 +1,181 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/components/window_function.fbs".
+
+#![allow(unused_braces)]
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+#![allow(non_camel_case_types)]
+
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::SerializationResult;
+use ::re_types_core::{ComponentBatch as _, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentName};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Component**: Tapers a window of samples before its spectrum is computed, trading frequency resolution for less spectral leakage.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, Default)]
+#[repr(u8)]
+pub enum WindowFunction {
+    /// No tapering, all samples are weighted equally.
+    ///
+    /// Gives the sharpest peaks, but the most leakage between frequencies.
+    Rectangular = 1,
+
+    /// Raised cosine that falls to zero at both ends of the window.
+    ///
+    /// A good default for most signals.
+    #[default]
+    Hann = 2,
+
+    /// Raised cosine that stays slightly above zero at the ends of the window.
+    ///
+    /// Suppresses the frequencies closest to a peak better than `Hann`, but those far away less so.
+    Hamming = 3,
+
+    /// Sum of cosines with very low leakage, at the cost of wider peaks.
+    Blackman = 4,
+}
+
+impl ::re_types_core::Component for WindowFunction {
+    #[inline]
+    fn name() -> ComponentName {
+        "rerun.blueprint.components.WindowFunction".into()
+    }
+}
+
+::re_types_core::macros::impl_into_cow!(WindowFunction);
+
+impl ::re_types_core::Loggable for WindowFunction {
+    #[inline]
+    fn arrow_datatype() -> arrow::datatypes::DataType {
+        #![allow(clippy::wildcard_imports)]
+        use arrow::datatypes::*;
+        DataType::UInt8
+    }
+
+    fn to_arrow_opt<'a>(
+        data: impl IntoIterator<Item = Option<impl Into<::std::borrow::Cow<'a, Self>>>>,
+    ) -> SerializationResult<arrow::array::ArrayRef>
+    where
+        Self: Clone + 'a,
+    {
+        #![allow(clippy::wildcard_imports)]
+        #![allow(clippy::manual_is_variant_and)]
+        use ::re_types_core::{arrow_helpers::as_array_ref, Loggable as _, ResultExt as _};
+        use arrow::{array::*, buffer::*, datatypes::*};
+        Ok({
+            let (somes, data0): (Vec<_>, Vec<_>) = data
+                .into_iter()
+                .map(|datum| {
+                    let datum: Option<::std::borrow::Cow<'a, Self>> = datum.map(Into::into);
+                    let datum = datum.map(|datum| *datum as u8);
+                    (datum.is_some(), datum)
+                })
+                .unzip();
+            let data0_validity: Option<arrow::buffer::NullBuffer> = {
+                let any_nones = somes.iter().any(|some| !*some);
+                any_nones.then(|| somes.into())
+            };
+            as_array_ref(PrimitiveArray::<UInt8Type>::new(
+                ScalarBuffer::from(
+                    data0
+                        .into_iter()
+                        .map(|v| v.unwrap_or_default())
+                        .collect::<Vec<_>>(),
+                ),
+                data0_validity,
+            ))
+        })
+    }
+
+    fn from_arrow_opt(
+        arrow_data: &dyn arrow::array::Array,
+    ) -> DeserializationResult<Vec<Option<Self>>>
+    where
+        Self: Sized,
+    {
+        #![allow(clippy::wildcard_imports)]
+        use ::re_types_core::{arrow_zip_validity::ZipValidity, Loggable as _, ResultExt as _};
+        use arrow::{array::*, buffer::*, datatypes::*};
+        Ok(arrow_data
+            .as_any()
+            .downcast_ref::<UInt8Array>()
+            .ok_or_else(|| {
+                let expected = Self::arrow_datatype();
+                let actual = arrow_data.data_type().clone();
+                DeserializationError::datatype_mismatch(expected, actual)
+            })
+            .with_context("rerun.blueprint.components.WindowFunction#enum")?
+            .into_iter()
+            .map(|typ| match typ {
+                Some(1) => Ok(Some(Self::Rectangular)),
+                Some(2) => Ok(Some(Self::Hann)),
+                Some(3) => Ok(Some(Self::Hamming)),
+                Some(4) => Ok(Some(Self::Blackman)),
+                None => Ok(None),
+                Some(invalid) => Err(DeserializationError::missing_union_arm(
+                    Self::arrow_datatype(),
+                    "<invalid>",
+                    invalid as _,
+                )),
+            })
+            .collect::<DeserializationResult<Vec<Option<_>>>>()
+            .with_context("rerun.blueprint.components.WindowFunction")?)
+    }
+}
+
+impl std::fmt::Display for WindowFunction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Rectangular => write!(f, "Rectangular"),
+            Self::Hann => write!(f, "Hann"),
+            Self::Hamming => write!(f, "Hamming"),
+            Self::Blackman => write!(f, "Blackman"),
+        }
+    }
+}
+
+impl ::re_types_core::reflection::Enum for WindowFunction {
+    #[inline]
+    fn variants() -> &'static [Self] {
+        &[Self::Rectangular, Self::Hann, Self::Hamming, Self::Blackman]
+    }
+
+    #[inline]
+    fn docstring_md(self) -> &'static str {
+        match self {
+            Self::Rectangular => {
+                "No tapering, all samples are weighted equally.\n\nGives the sharpest peaks, but the most leakage between frequencies."
+            }
+            Self::Hann => {
+                "Raised cosine that falls to zero at both ends of the window.\n\nA good default for most signals."
+            }
+            Self::Hamming => {
+                "Raised cosine that stays slightly above zero at the ends of the window.\n\nSuppresses the frequencies closest to a peak better than `Hann`, but those far away less so."
+            }
+            Self::Blackman => {
+                "Sum of cosines with very low leakage, at the cost of wider peaks."
+            }
+        }
+    }
+}
+
+impl ::re_byte_size::SizeBytes for WindowFunction {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        0
+    }
+
+    #[inline]
+    fn is_pod() -> bool {
+        true
+    }
+}
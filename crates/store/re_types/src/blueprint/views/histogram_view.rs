@@ -0,0 +1,66 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/views/histogram.fbs".
+
+#![allow(unused_braces)]
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::SerializationResult;
+use ::re_types_core::{ComponentBatch as _, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentName};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **View**: A view that shows the distribution of values as a histogram.
+///
+/// Values of [`archetypes::Scalars`][crate::archetypes::Scalars] are collected over the visible time range,
+/// while [`archetypes::Tensor`][crate::archetypes::Tensor], [`archetypes::Image`][crate::archetypes::Image] and [`archetypes::DepthImage`][crate::archetypes::DepthImage] contribute all their elements at the current time.
+/// Each entity is shown as its own overlaid histogram.
+///
+/// ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+#[derive(Clone, Debug)]
+pub struct HistogramView {
+    /// Configures how values are sorted into bins.
+    pub bins: crate::blueprint::archetypes::HistogramBins,
+
+    /// Configures the legend of the plot.
+    pub plot_legend: crate::blueprint::archetypes::PlotLegend,
+
+    /// Configures which range on each timeline is shown by this view (unless specified differently per entity).
+    ///
+    /// Only scalars are affected, tensors and images are always shown at the current time.
+    /// If not specified, the default is to show the entire timeline.
+    /// If a timeline is specified more than once, the first entry will be used.
+    pub time_ranges: crate::blueprint::archetypes::VisibleTimeRanges,
+}
+
+impl ::re_types_core::View for HistogramView {
+    #[inline]
+    fn identifier() -> ::re_types_core::ViewClassIdentifier {
+        "Histogram".into()
+    }
+}
+
+impl ::re_byte_size::SizeBytes for HistogramView {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.bins.heap_size_bytes()
+            + self.plot_legend.heap_size_bytes()
+            + self.time_ranges.heap_size_bytes()
+    }
+
+    #[inline]
+    fn is_pod() -> bool {
+        <crate::blueprint::archetypes::HistogramBins>::is_pod()
+            && <crate::blueprint::archetypes::PlotLegend>::is_pod()
+            && <crate::blueprint::archetypes::VisibleTimeRanges>::is_pod()
+    }
+}
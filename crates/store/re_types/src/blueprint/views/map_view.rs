@@ -29,6 +29,9 @@ pub struct MapView {
 
     /// Configuration for the background map of the map view.
     pub background: crate::blueprint::archetypes::MapBackground,
+
+    /// Whether the map follows an entity, and how.
+    pub follow: crate::blueprint::archetypes::MapFollow,
 }
 
 impl ::re_types_core::View for MapView {
@@ -41,12 +44,15 @@ impl ::re_types_core::View for MapView {
 impl ::re_byte_size::SizeBytes for MapView {
     #[inline]
     fn heap_size_bytes(&self) -> u64 {
-        self.zoom.heap_size_bytes() + self.background.heap_size_bytes()
+        self.zoom.heap_size_bytes()
+            + self.background.heap_size_bytes()
+            + self.follow.heap_size_bytes()
     }
 
     #[inline]
     fn is_pod() -> bool {
         <crate::blueprint::archetypes::MapZoom>::is_pod()
             && <crate::blueprint::archetypes::MapBackground>::is_pod()
+            && <crate::blueprint::archetypes::MapFollow>::is_pod()
     }
 }
@@ -0,0 +1,66 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/views/spectrogram.fbs".
+
+#![allow(unused_braces)]
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::SerializationResult;
+use ::re_types_core::{ComponentBatch as _, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentName};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **View**: A view that shows how the frequency content of a signal changes over time, as a heatmap.
+///
+/// Every [`archetypes::Scalars`][crate::archetypes::Scalars] entity is split into overlapping windows of samples,
+/// the spectrum of each window is computed with a short-time Fourier transform,
+/// and shown as one column of the heatmap, aligned with the timeline.
+/// Samples are assumed to be evenly spaced in time.
+///
+/// ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+#[derive(Clone, Debug)]
+pub struct SpectrogramView {
+    /// Configures how the signal is split into windows.
+    pub window: crate::blueprint::archetypes::SpectrogramWindow,
+
+    /// Configures how the magnitude of each frequency is mapped to color.
+    pub scalar_mapping: crate::blueprint::archetypes::TensorScalarMapping,
+
+    /// Configures which range on each timeline is shown by this view (unless specified differently per entity).
+    ///
+    /// If not specified, the default is to show the entire timeline.
+    /// If a timeline is specified more than once, the first entry will be used.
+    pub time_ranges: crate::blueprint::archetypes::VisibleTimeRanges,
+}
+
+impl ::re_types_core::View for SpectrogramView {
+    #[inline]
+    fn identifier() -> ::re_types_core::ViewClassIdentifier {
+        "Spectrogram".into()
+    }
+}
+
+impl ::re_byte_size::SizeBytes for SpectrogramView {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.window.heap_size_bytes()
+            + self.scalar_mapping.heap_size_bytes()
+            + self.time_ranges.heap_size_bytes()
+    }
+
+    #[inline]
+    fn is_pod() -> bool {
+        <crate::blueprint::archetypes::SpectrogramWindow>::is_pod()
+            && <crate::blueprint::archetypes::TensorScalarMapping>::is_pod()
+            && <crate::blueprint::archetypes::VisibleTimeRanges>::is_pod()
+    }
+}
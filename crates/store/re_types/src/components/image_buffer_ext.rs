@@ -94,4 +94,46 @@ impl ImageBuffer {
             }
         }
     }
+
+    /// Construct a depth image buffer & image format from [`image::DynamicImage`].
+    ///
+    /// Single-channel 8-bit and 16-bit images are kept as-is.
+    /// Float images (e.g. from EXR files, which the `image` crate always decodes to RGB(A))
+    /// use their first channel as depth.
+    ///
+    /// Requires the `image` feature.
+    pub fn from_dynamic_depth_image(
+        image: image::DynamicImage,
+    ) -> Result<(Self, ImageFormat), crate::image::ImageConversionError> {
+        re_tracing::profile_function!();
+
+        fn first_channel(elements: &[f32], num_channels: usize) -> Vec<f32> {
+            elements.iter().step_by(num_channels).copied().collect()
+        }
+
+        let res = [image.width(), image.height()];
+
+        let (bytes, datatype) = match image {
+            image::DynamicImage::ImageLuma8(image) => (image.into_raw(), u8::CHANNEL_TYPE),
+            image::DynamicImage::ImageLuma16(image) => (
+                bytemuck::cast_slice(image.as_raw()).to_vec(),
+                u16::CHANNEL_TYPE,
+            ),
+            image::DynamicImage::ImageRgb32F(image) => (
+                bytemuck::cast_slice(&first_channel(image.as_raw(), 3)).to_vec(),
+                f32::CHANNEL_TYPE,
+            ),
+            image::DynamicImage::ImageRgba32F(image) => (
+                bytemuck::cast_slice(&first_channel(image.as_raw(), 4)).to_vec(),
+                f32::CHANNEL_TYPE,
+            ),
+            _ => {
+                return Err(
+                    crate::image::ImageConversionError::UnsupportedImageColorType(image.color()),
+                );
+            }
+        };
+
+        Ok((Self(bytes.into()), ImageFormat::depth(res, datatype)))
+    }
 }
@@ -22,6 +22,11 @@ impl MediaType {
     /// <https://www.iana.org/assignments/media-types/image/png>
     pub const PNG: &'static str = "image/png";
 
+    /// [OpenEXR image](https://en.wikipedia.org/wiki/OpenEXR): `image/x-exr`.
+    ///
+    /// Not registered with IANA, but the de-facto standard used by most tools.
+    pub const EXR: &'static str = "image/x-exr";
+
     // -------------------------------------------------------
     // Meshes:
 
@@ -83,6 +88,12 @@ impl MediaType {
         Self(Self::PNG.into())
     }
 
+    /// `image/x-exr`
+    #[inline]
+    pub fn exr() -> Self {
+        Self(Self::EXR.into())
+    }
+
     // -------------------------------------------------------
     // Meshes:
 
@@ -146,6 +157,10 @@ impl MediaType {
             Some("stl") => {
                 return Some(Self::stl());
             }
+            // `image/x-exr` isn't registered with IANA, so don't rely on `mime_guess2` for it.
+            Some("exr") => {
+                return Some(Self::exr());
+            }
             _ => {}
         }
 
@@ -162,6 +177,11 @@ impl MediaType {
             buf.len() >= 4 && buf[0] == b'g' && buf[1] == b'l' && buf[2] == b'T' && buf[3] == b'F'
         }
 
+        fn exr_matcher(buf: &[u8]) -> bool {
+            // https://openexr.com/en/latest/OpenEXRFileLayout.html#magic-number
+            buf.starts_with(&[0x76, 0x2f, 0x31, 0x01])
+        }
+
         fn stl_matcher(buf: &[u8]) -> bool {
             // ASCII STL
             buf.len() >= 5
@@ -181,6 +201,7 @@ impl MediaType {
         // - obj is simply text, so no magic byte
 
         let mut inferer = infer::Infer::new();
+        inferer.add(Self::EXR, "exr", exr_matcher);
         inferer.add(Self::GLB, "glb", glb_matcher);
         inferer.add(Self::STL, "stl", stl_matcher);
 
@@ -210,6 +231,7 @@ impl MediaType {
     pub fn file_extension(&self) -> Option<&'static str> {
         match self.as_str() {
             // Special-case some where there are multiple extensions:
+            Self::EXR => Some("exr"),
             Self::JPEG => Some("jpg"),
             Self::MARKDOWN => Some("md"),
             Self::STL => Some("stl"),
@@ -253,6 +275,7 @@ impl Default for MediaType {
 
 #[test]
 fn test_media_type_extension() {
+    assert_eq!(MediaType::exr().file_extension(), Some("exr"));
     assert_eq!(MediaType::glb().file_extension(), Some("glb"));
     assert_eq!(MediaType::gltf().file_extension(), Some("gltf"));
     assert_eq!(MediaType::jpeg().file_extension(), Some("jpg"));
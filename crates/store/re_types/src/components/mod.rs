@@ -26,6 +26,8 @@ mod gamma_correction;
 mod gamma_correction_ext;
 mod geo_line_string;
 mod geo_line_string_ext;
+mod geo_polygon;
+mod geo_polygon_ext;
 mod graph_edge;
 mod graph_node;
 mod graph_node_ext;
@@ -150,6 +152,7 @@ pub use self::fill_mode::FillMode;
 pub use self::fill_ratio::FillRatio;
 pub use self::gamma_correction::GammaCorrection;
 pub use self::geo_line_string::GeoLineString;
+pub use self::geo_polygon::GeoPolygon;
 pub use self::graph_edge::GraphEdge;
 pub use self::graph_node::GraphNode;
 pub use self::graph_type::GraphType;
@@ -86,6 +86,26 @@ fn generate_component_reflection() -> Result<ComponentReflectionMap, Serializati
                 verify_arrow_array: BackgroundKind::verify_arrow_array,
             },
         ),
+        (
+            <BinCount as Component>::name(),
+            ComponentReflection {
+                docstring_md: "The number of bins a histogram sorts its values into.\n\n⚠\u{fe0f} **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**",
+                deprecation_summary: None,
+                custom_placeholder: Some(BinCount::default().to_arrow()?),
+                datatype: BinCount::arrow_datatype(),
+                verify_arrow_array: BinCount::verify_arrow_array,
+            },
+        ),
+        (
+            <BookmarkTime as Component>::name(),
+            ComponentReflection {
+                docstring_md: "The time of a bookmark on its timeline.\n\n⚠\u{fe0f} **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**",
+                deprecation_summary: None,
+                custom_placeholder: None,
+                datatype: BookmarkTime::arrow_datatype(),
+                verify_arrow_array: BookmarkTime::verify_arrow_array,
+            },
+        ),
         (
             <ColumnShare as Component>::name(),
             ComponentReflection {
@@ -206,6 +226,16 @@ fn generate_component_reflection() -> Result<ComponentReflectionMap, Serializati
                 verify_arrow_array: GridSpacing::verify_arrow_array,
             },
         ),
+        (
+            <HopLength as Component>::name(),
+            ComponentReflection {
+                docstring_md: "The number of samples between the starts of two consecutive analysis windows.\n\n⚠\u{fe0f} **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**",
+                deprecation_summary: None,
+                custom_placeholder: Some(HopLength::default().to_arrow()?),
+                datatype: HopLength::arrow_datatype(),
+                verify_arrow_array: HopLength::verify_arrow_array,
+            },
+        ),
         (
             <IncludedContent as Component>::name(),
             ComponentReflection {
@@ -216,6 +246,16 @@ fn generate_component_reflection() -> Result<ComponentReflectionMap, Serializati
                 verify_arrow_array: IncludedContent::verify_arrow_array,
             },
         ),
+        (
+            <KeyframeTime as Component>::name(),
+            ComponentReflection {
+                docstring_md: "The time of a keyframe on its timeline.\n\n⚠\u{fe0f} **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**",
+                deprecation_summary: None,
+                custom_placeholder: None,
+                datatype: KeyframeTime::arrow_datatype(),
+                verify_arrow_array: KeyframeTime::verify_arrow_array,
+            },
+        ),
         (
             <LinkAxis as Component>::name(),
             ComponentReflection {
@@ -236,6 +276,16 @@ fn generate_component_reflection() -> Result<ComponentReflectionMap, Serializati
                 verify_arrow_array: LockRangeDuringZoom::verify_arrow_array,
             },
         ),
+        (
+            <MapFollowMode as Component>::name(),
+            ComponentReflection {
+                docstring_md: "How the map view follows an entity.",
+                deprecation_summary: None,
+                custom_placeholder: Some(MapFollowMode::default().to_arrow()?),
+                datatype: MapFollowMode::arrow_datatype(),
+                verify_arrow_array: MapFollowMode::verify_arrow_array,
+            },
+        ),
         (
             <MapProvider as Component>::name(),
             ComponentReflection {
@@ -246,6 +296,16 @@ fn generate_component_reflection() -> Result<ComponentReflectionMap, Serializati
                 verify_arrow_array: MapProvider::verify_arrow_array,
             },
         ),
+        (
+            <MapTileSource as Component>::name(),
+            ComponentReflection {
+                docstring_md: "A custom source of map tiles, used instead of one of the built-in map providers.\n\nThis is one of:\n* An XYZ tile URL template, e.g. `https://tiles.example.com/{z}/{x}/{y}.png`.\n  `{z}` is replaced by the zoom level, `{x}` and `{y}` by the tile's column and row.\n* The path to an MBTiles or PMTiles file containing raster tiles.\n* The path to a directory of raster tiles laid out as `{z}/{x}/{y}.png`.\n\n⚠\u{fe0f} **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**",
+                deprecation_summary: None,
+                custom_placeholder: Some(MapTileSource::default().to_arrow()?),
+                datatype: MapTileSource::arrow_datatype(),
+                verify_arrow_array: MapTileSource::verify_arrow_array,
+            },
+        ),
         (
             <NearClipPlane as Component>::name(),
             ComponentReflection {
@@ -410,6 +470,26 @@ fn generate_component_reflection() -> Result<ComponentReflectionMap, Serializati
                 verify_arrow_array: VisualizerOverride::verify_arrow_array,
             },
         ),
+        (
+            <WindowFunction as Component>::name(),
+            ComponentReflection {
+                docstring_md: "Tapers a window of samples before its spectrum is computed, trading frequency resolution for less spectral leakage.",
+                deprecation_summary: None,
+                custom_placeholder: Some(WindowFunction::default().to_arrow()?),
+                datatype: WindowFunction::arrow_datatype(),
+                verify_arrow_array: WindowFunction::verify_arrow_array,
+            },
+        ),
+        (
+            <WindowLength as Component>::name(),
+            ComponentReflection {
+                docstring_md: "The number of consecutive samples that are analyzed together, e.g. by a short-time Fourier transform.\n\n⚠\u{fe0f} **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**",
+                deprecation_summary: None,
+                custom_placeholder: Some(WindowLength::default().to_arrow()?),
+                datatype: WindowLength::arrow_datatype(),
+                verify_arrow_array: WindowLength::verify_arrow_array,
+            },
+        ),
         (
             <ZoomLevel as Component>::name(),
             ComponentReflection {
@@ -580,6 +660,16 @@ fn generate_component_reflection() -> Result<ComponentReflectionMap, Serializati
                 verify_arrow_array: GeoLineString::verify_arrow_array,
             },
         ),
+        (
+            <GeoPolygon as Component>::name(),
+            ComponentReflection {
+                docstring_md: "A geospatial polygon expressed in [EPSG:4326](https://epsg.io/4326) latitude and longitude (North/East-positive degrees).\n\nThe vertices describe the outer boundary of the polygon, which is implicitly closed.\nHoles are not supported.",
+                deprecation_summary: None,
+                custom_placeholder: Some(GeoPolygon::default().to_arrow()?),
+                datatype: GeoPolygon::arrow_datatype(),
+                verify_arrow_array: GeoPolygon::verify_arrow_array,
+            },
+        ),
         (
             <GraphEdge as Component>::name(),
             ComponentReflection {
@@ -1572,7 +1662,7 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                 display_name: "Depth image",
                 deprecation_summary: None,
                 scope: None,
-                view_types: &["Spatial2DView", "Spatial3DView"],
+                view_types: &["Spatial2DView", "Spatial3DView", "HistogramView"],
                 fields: vec![
                     ArchetypeFieldReflection { name : "buffer".into(), display_name :
                     "Buffer", component_name : "rerun.components.ImageBuffer".into(),
@@ -1657,6 +1747,52 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                 ],
             },
         ),
+        (
+            ArchetypeName::new("rerun.archetypes.EncodedDepthImage"),
+            ArchetypeReflection {
+                display_name: "Encoded depth image",
+                deprecation_summary: None,
+                scope: None,
+                view_types: &["Spatial2DView", "Spatial3DView"],
+                fields: vec![
+                    ArchetypeFieldReflection { name : "blob".into(), display_name :
+                    "Blob", component_name : "rerun.components.Blob".into(),
+                    docstring_md :
+                    "The encoded content of some image file, e.g. a PNG or EXR.",
+                    is_required : true, },
+                    ArchetypeFieldReflection { name : "media_type".into(), display_name :
+                    "Media type", component_name : "rerun.components.MediaType".into(),
+                    docstring_md :
+                    "The Media Type of the asset.\n\nSupported values:\n* `image/png`\n* `image/x-exr`\n\nIf omitted, the viewer will try to guess from the data blob.\nIf it cannot guess, it won't be able to render the asset.",
+                    is_required : false, },
+                    ArchetypeFieldReflection { name : "meter".into(), display_name :
+                    "Meter", component_name : "rerun.components.DepthMeter".into(),
+                    docstring_md :
+                    "An optional floating point value that specifies how long a meter is in the native depth units.\n\nFor instance: with a 16-bit PNG, perhaps meter=1000 which would mean you have millimeter precision\nand a range of up to ~65 meters (2^16 / 1000).\n\nNote that the only effect on 2D views is the physical depth values shown when hovering the image.\nIn 3D views on the other hand, this affects where the points of the point cloud are placed.",
+                    is_required : false, },
+                    ArchetypeFieldReflection { name : "colormap".into(), display_name :
+                    "Colormap", component_name : "rerun.components.Colormap".into(),
+                    docstring_md :
+                    "Colormap to use for rendering the depth image.\n\nIf not set, the depth image will be rendered using the Turbo colormap.",
+                    is_required : false, },
+                    ArchetypeFieldReflection { name : "depth_range".into(), display_name :
+                    "Depth range", component_name : "rerun.components.ValueRange".into(),
+                    docstring_md :
+                    "The expected range of depth values.\n\nThis is typically the expected range of valid values.\nEverything outside of the range is clamped to the range for the purpose of colormpaping.\nNote that point clouds generated from this image will still display all points, regardless of this range.\n\nIf not specified, the range will be automatically estimated from the data.",
+                    is_required : false, },
+                    ArchetypeFieldReflection { name : "point_fill_ratio".into(), display_name :
+                    "Point fill ratio", component_name : "rerun.components.FillRatio".into(),
+                    docstring_md :
+                    "Scale the radii of the points in the point cloud generated from this image.\n\nA fill ratio of 1.0 (the default) means that each point is as big as to touch the center of its neighbor\nif it is at the same depth, leaving no gaps.\nA fill ratio of 0.5 means that each point touches the edge of its neighbor if it has the same depth.\n\nTODO(#6744): This applies only to 3D views!",
+                    is_required : false, },
+                    ArchetypeFieldReflection { name : "draw_order".into(), display_name :
+                    "Draw order", component_name : "rerun.components.DrawOrder".into(),
+                    docstring_md :
+                    "An optional floating point value that specifies the 2D drawing order, used only if the depth image is shown as a 2D image.\n\nObjects with higher values are drawn on top of those with lower values.\nDefaults to `-20.0`.",
+                    is_required : false, },
+                ],
+            },
+        ),
         (
             ArchetypeName::new("rerun.archetypes.EncodedImage"),
             ArchetypeReflection {
@@ -1684,6 +1820,37 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                 ],
             },
         ),
+        (
+            ArchetypeName::new("rerun.archetypes.GaussianSplats3D"),
+            ArchetypeReflection {
+                display_name: "Gaussian splats 3D",
+                deprecation_summary: None,
+                scope: None,
+                view_types: &["Spatial3DView"],
+                fields: vec![
+                    ArchetypeFieldReflection { name : "centers".into(), display_name :
+                    "Centers", component_name : "rerun.components.Position3D".into(),
+                    docstring_md : "The center of each splat.", is_required : true, },
+                    ArchetypeFieldReflection { name : "scales".into(), display_name :
+                    "Scales", component_name : "rerun.components.Scale3D".into(),
+                    docstring_md :
+                    "The standard deviation of each splat along its three local axes.",
+                    is_required : true, }, ArchetypeFieldReflection { name :
+                    "quaternions".into(), display_name : "Quaternions", component_name :
+                    "rerun.components.RotationQuat".into(), docstring_md :
+                    "The orientation of each splat's local axes.\n\nIf not specified, the local axes align with the axes of the entity's coordinate system.",
+                    is_required : false, }, ArchetypeFieldReflection { name : "colors"
+                    .into(), display_name : "Colors", component_name :
+                    "rerun.components.Color".into(), docstring_md :
+                    "The base color of each splat.\n\nThe alpha channel is ignored, use [`components.Opacity`](https://rerun.io/docs/reference/types/components/opacity) instead.",
+                    is_required : false, }, ArchetypeFieldReflection { name :
+                    "opacities".into(), display_name : "Opacities", component_name :
+                    "rerun.components.Opacity".into(), docstring_md :
+                    "The opacity of each splat at its center, in the range `[0, 1]`.\n\nIf not specified, splats are fully opaque at their centers.",
+                    is_required : false, },
+                ],
+            },
+        ),
         (
             ArchetypeName::new("rerun.archetypes.GeoLineStrings"),
             ArchetypeReflection {
@@ -1735,6 +1902,34 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                 ],
             },
         ),
+        (
+            ArchetypeName::new("rerun.archetypes.GeoPolygons"),
+            ArchetypeReflection {
+                display_name: "Geo polygons",
+                deprecation_summary: None,
+                scope: None,
+                view_types: &["MapView"],
+                fields: vec![
+                    ArchetypeFieldReflection { name : "polygons".into(), display_name :
+                    "Polygons", component_name : "rerun.components.GeoPolygon".into(),
+                    docstring_md :
+                    "The polygons, expressed in [EPSG:4326](https://epsg.io/4326) coordinates (North/East-positive degrees).",
+                    is_required : true, }, ArchetypeFieldReflection { name : "radii"
+                    .into(), display_name : "Radii", component_name :
+                    "rerun.components.Radius".into(), docstring_md :
+                    "Optional radii for the outlines of the polygons.\n\n*Note*: scene units radiii are interpreted as meters. Currently, the display scale only considers the latitude of\nthe first vertex of each polygon (see [this issue](https://github.com/rerun-io/rerun/issues/8013)).",
+                    is_required : false, }, ArchetypeFieldReflection { name : "colors"
+                    .into(), display_name : "Colors", component_name :
+                    "rerun.components.Color".into(), docstring_md :
+                    "Optional colors for the outlines of the polygons.", is_required :
+                    false, }, ArchetypeFieldReflection { name : "fill_colors".into(),
+                    display_name : "Fill colors", component_name :
+                    "rerun.components.Color".into(), docstring_md :
+                    "Optional colors for the area enclosed by the polygons.\n\nIf not specified, the outline color is used with reduced opacity.",
+                    is_required : false, },
+                ],
+            },
+        ),
         (
             ArchetypeName::new("rerun.archetypes.GraphEdges"),
             ArchetypeReflection {
@@ -1791,7 +1986,7 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                 display_name: "Image",
                 deprecation_summary: None,
                 scope: None,
-                view_types: &["Spatial2DView", "Spatial3DView"],
+                view_types: &["Spatial2DView", "Spatial3DView", "HistogramView"],
                 fields: vec![
                     ArchetypeFieldReflection { name : "buffer".into(), display_name :
                     "Buffer", component_name : "rerun.components.ImageBuffer".into(),
@@ -2102,7 +2297,7 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                 display_name: "Scalars",
                 deprecation_summary: None,
                 scope: None,
-                view_types: &["TimeSeriesView"],
+                view_types: &["TimeSeriesView", "XYPlotView", "HistogramView", "SpectrogramView"],
                 fields: vec![
                     ArchetypeFieldReflection { name : "scalars".into(), display_name :
                     "Scalars", component_name : "rerun.components.Scalar".into(),
@@ -2142,7 +2337,7 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                 display_name: "Series lines",
                 deprecation_summary: None,
                 scope: None,
-                view_types: &["TimeSeriesView"],
+                view_types: &["TimeSeriesView", "XYPlotView"],
                 fields: vec![
                     ArchetypeFieldReflection { name : "colors".into(), display_name :
                     "Colors", component_name : "rerun.components.Color".into(),
@@ -2176,7 +2371,7 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                 display_name: "Series points",
                 deprecation_summary: None,
                 scope: None,
-                view_types: &["TimeSeriesView"],
+                view_types: &["TimeSeriesView", "XYPlotView"],
                 fields: vec![
                     ArchetypeFieldReflection { name : "colors".into(), display_name :
                     "Colors", component_name : "rerun.components.Color".into(),
@@ -2209,7 +2404,7 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                 display_name: "Tensor",
                 deprecation_summary: None,
                 scope: None,
-                view_types: &["TensorView", "BarChartView"],
+                view_types: &["TensorView", "BarChartView", "HistogramView"],
                 fields: vec![
                     ArchetypeFieldReflection { name : "data".into(), display_name :
                     "Data", component_name : "rerun.components.TensorData".into(),
@@ -2381,6 +2576,41 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                 ],
             },
         ),
+        (
+            ArchetypeName::new("rerun.blueprint.archetypes.CameraPath"),
+            ArchetypeReflection {
+                display_name: "Camera path",
+                deprecation_summary: None,
+                scope: Some("blueprint"),
+                view_types: &[],
+                fields: vec![
+                    ArchetypeFieldReflection { name : "enabled".into(), display_name :
+                    "Enabled", component_name : "rerun.blueprint.components.Enabled"
+                    .into(), docstring_md :
+                    "Whether the eye of the view follows the path. Defaults to true.",
+                    is_required : false, }, ArchetypeFieldReflection { name : "timeline"
+                    .into(), display_name : "Timeline", component_name :
+                    "rerun.blueprint.components.TimelineName".into(), docstring_md :
+                    "The timeline along which the keyframes are placed.", is_required :
+                    false, }, ArchetypeFieldReflection { name : "times".into(),
+                    display_name : "Times", component_name :
+                    "rerun.blueprint.components.KeyframeTime".into(), docstring_md :
+                    "The time of each keyframe.", is_required : false, },
+                    ArchetypeFieldReflection { name : "positions".into(), display_name :
+                    "Positions", component_name : "rerun.components.Position3D".into(),
+                    docstring_md : "Where the eye is at each keyframe.", is_required :
+                    false, }, ArchetypeFieldReflection { name : "look_targets".into(),
+                    display_name : "Look targets", component_name :
+                    "rerun.components.Position3D".into(), docstring_md :
+                    "What the eye looks at, at each keyframe.", is_required : false, },
+                    ArchetypeFieldReflection { name : "chase_entity".into(), display_name
+                    : "Chase entity", component_name : "rerun.components.EntityPath"
+                    .into(), docstring_md :
+                    "An entity the path is attached to, e.g. a vehicle followed by a chase cam.\n\nIf set, positions and look targets are in the space of this entity rather than that of the view.",
+                    is_required : false, },
+                ],
+            },
+        ),
         (
             ArchetypeName::new("rerun.blueprint.archetypes.ContainerBlueprint"),
             ArchetypeReflection {
@@ -2590,6 +2820,30 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                 ],
             },
         ),
+        (
+            ArchetypeName::new("rerun.blueprint.archetypes.HistogramBins"),
+            ArchetypeReflection {
+                display_name: "Histogram bins",
+                deprecation_summary: None,
+                scope: Some("blueprint"),
+                view_types: &[],
+                fields: vec![
+                    ArchetypeFieldReflection { name : "bin_count".into(), display_name :
+                    "Bin count", component_name : "rerun.blueprint.components.BinCount"
+                    .into(), docstring_md :
+                    "How many bins the value range is divided into.\n\nDefaults to 64.",
+                    is_required : false, }, ArchetypeFieldReflection { name : "range"
+                    .into(), display_name : "Range", component_name :
+                    "rerun.components.Range1D".into(), docstring_md :
+                    "The range of values that is divided into bins.\n\nValues outside of it aren't counted.\nIf unset, the range spans all values shown in the view.",
+                    is_required : false, }, ArchetypeFieldReflection { name : "log_scale"
+                    .into(), display_name : "Log scale", component_name :
+                    "rerun.blueprint.components.Enabled".into(), docstring_md :
+                    "Whether the bin counts are shown on a logarithmic scale.\n\nDefaults to false.",
+                    is_required : false, },
+                ],
+            },
+        ),
         (
             ArchetypeName::new("rerun.blueprint.archetypes.LineGrid3D"),
             ArchetypeReflection {
@@ -2634,6 +2888,30 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                     .into(), docstring_md :
                     "Map provider and style to use.\n\n**Note**: Requires a Mapbox API key in the `RERUN_MAPBOX_ACCESS_TOKEN` environment variable.",
                     is_required : false, },
+                    ArchetypeFieldReflection { name : "tile_source".into(), display_name
+                    : "Tile source", component_name :
+                    "rerun.blueprint.components.MapTileSource".into(), docstring_md :
+                    "Custom map tiles to show instead of the provider's.\n\nTiles downloaded from a URL are kept in an on-disk cache, so areas that were viewed once\nremain available without network access.",
+                    is_required : false, },
+                ],
+            },
+        ),
+        (
+            ArchetypeName::new("rerun.blueprint.archetypes.MapFollow"),
+            ArchetypeReflection {
+                display_name: "Map follow",
+                deprecation_summary: None,
+                scope: Some("blueprint"),
+                view_types: &[],
+                fields: vec![
+                    ArchetypeFieldReflection { name : "mode".into(), display_name :
+                    "Mode", component_name : "rerun.blueprint.components.MapFollowMode"
+                    .into(), docstring_md : "Whether and how the map follows the entity.",
+                    is_required : false, }, ArchetypeFieldReflection { name : "entity"
+                    .into(), display_name : "Entity", component_name :
+                    "rerun.components.EntityPath".into(), docstring_md :
+                    "The entity to follow.\n\nIts latest geospatial position is used, taken from either `GeoPoints` or the last vertex\nof `GeoLineStrings`.\nIf not set, the origin of the view is followed.",
+                    is_required : false, },
                 ],
             },
         ),
@@ -2725,6 +3003,30 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                 ],
             },
         ),
+        (
+            ArchetypeName::new("rerun.blueprint.archetypes.SpectrogramWindow"),
+            ArchetypeReflection {
+                display_name: "Spectrogram window",
+                deprecation_summary: None,
+                scope: Some("blueprint"),
+                view_types: &[],
+                fields: vec![
+                    ArchetypeFieldReflection { name : "length".into(), display_name :
+                    "Length", component_name : "rerun.blueprint.components.WindowLength"
+                    .into(), docstring_md :
+                    "How many samples each window spans.\n\nRounded up to the next power of two. Defaults to 256.",
+                    is_required : false, }, ArchetypeFieldReflection { name : "hop"
+                    .into(), display_name : "Hop", component_name :
+                    "rerun.blueprint.components.HopLength".into(), docstring_md :
+                    "How many samples a window advances by between two columns of the spectrogram.\n\nDefaults to a quarter of the window length.",
+                    is_required : false, }, ArchetypeFieldReflection { name : "function"
+                    .into(), display_name : "Function", component_name :
+                    "rerun.blueprint.components.WindowFunction".into(), docstring_md :
+                    "The function the samples of each window are weighted with.\n\nDefaults to a Hann window.",
+                    is_required : false, },
+                ],
+            },
+        ),
         (
             ArchetypeName::new("rerun.blueprint.archetypes.TensorScalarMapping"),
             ArchetypeReflection {
@@ -2794,6 +3096,30 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                 ],
             },
         ),
+        (
+            ArchetypeName::new("rerun.blueprint.archetypes.TensorVolume"),
+            ArchetypeReflection {
+                display_name: "Tensor volume",
+                deprecation_summary: None,
+                scope: Some("blueprint"),
+                view_types: &[],
+                fields: vec![
+                    ArchetypeFieldReflection { name : "colormap".into(), display_name :
+                    "Colormap", component_name : "rerun.components.Colormap".into(),
+                    docstring_md :
+                    "The colormap applied to the tensor's values.\n\nDefaults to viridis.",
+                    is_required : false, }, ArchetypeFieldReflection { name : "opacity"
+                    .into(), display_name : "Opacity", component_name :
+                    "rerun.components.Opacity".into(), docstring_md :
+                    "Opacity contributed by each voxel when shown as a volume.\n\nSince it accumulates along every ray, even small values can make the volume opaque.\nDefaults to 0.05.",
+                    is_required : false, }, ArchetypeFieldReflection { name : "iso_value"
+                    .into(), display_name : "Iso value", component_name :
+                    "rerun.components.Scalar".into(), docstring_md :
+                    "The value at which the iso-surface is extracted when shown as an iso-surface.\n\nDefaults to the center of the tensor's value range.",
+                    is_required : false, },
+                ],
+            },
+        ),
         (
             ArchetypeName::new("rerun.blueprint.archetypes.TimeAxis"),
             ArchetypeReflection {
@@ -2810,6 +3136,28 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                 ],
             },
         ),
+        (
+            ArchetypeName::new("rerun.blueprint.archetypes.TimelineBookmarks"),
+            ArchetypeReflection {
+                display_name: "Timeline bookmarks",
+                deprecation_summary: None,
+                scope: Some("blueprint"),
+                view_types: &[],
+                fields: vec![
+                    ArchetypeFieldReflection { name : "timelines".into(), display_name :
+                    "Timelines", component_name :
+                    "rerun.blueprint.components.TimelineName".into(), docstring_md :
+                    "The timeline of each bookmark.", is_required : false, },
+                    ArchetypeFieldReflection { name : "times".into(), display_name :
+                    "Times", component_name : "rerun.blueprint.components.BookmarkTime"
+                    .into(), docstring_md : "The time of each bookmark on its timeline.",
+                    is_required : false, }, ArchetypeFieldReflection { name : "names"
+                    .into(), display_name : "Names", component_name :
+                    "rerun.components.Name".into(), docstring_md :
+                    "The name of each bookmark.", is_required : false, },
+                ],
+            },
+        ),
         (
             ArchetypeName::new("rerun.blueprint.archetypes.ViewBlueprint"),
             ArchetypeReflection {
@@ -2935,6 +3283,32 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                 ],
             },
         ),
+        (
+            ArchetypeName::new("rerun.blueprint.archetypes.XAxis"),
+            ArchetypeReflection {
+                display_name: "X axis",
+                deprecation_summary: None,
+                scope: Some("blueprint"),
+                view_types: &[],
+                fields: vec![
+                    ArchetypeFieldReflection { name : "source".into(), display_name :
+                    "Source", component_name :
+                    "rerun.blueprint.components.ComponentColumnSelector".into(),
+                    docstring_md :
+                    "The scalar column plotted along the X axis.\n\nEvery other scalar in the view is plotted against it. Only the first series of the entity is used.\nIf unset, the first entity in the view is used.",
+                    is_required : false, }, ArchetypeFieldReflection { name : "range"
+                    .into(), display_name : "Range", component_name :
+                    "rerun.components.Range1D".into(), docstring_md :
+                    "The range of the axis.\n\nIf unset, the range will be automatically determined based on the queried data.",
+                    is_required : false, }, ArchetypeFieldReflection { name : "zoom_lock"
+                    .into(), display_name : "Zoom lock", component_name :
+                    "rerun.blueprint.components.LockRangeDuringZoom".into(), docstring_md
+                    :
+                    "If enabled, the X axis range will remain locked to the specified range when zooming.",
+                    is_required : false, },
+                ],
+            },
+        ),
     ];
     ArchetypeReflectionMap::from_iter(array)
 }
@@ -83,11 +83,12 @@ impl crate::sink::LogSink for re_log_encoding::FileSink {
 pub mod sink {
     pub use crate::binary_stream_sink::{BinaryStreamSink, BinaryStreamStorage};
     pub use crate::log_sink::{
-        BufferedSink, CallbackSink, IntoMultiSink, LogSink, MemorySink, MemorySinkStorage,
-        MultiSink,
+        BufferedSink, CallbackSink, DecimatingSink, Decimation, DecimationRule, IntoMultiSink,
+        LogSink, MemorySink, MemorySinkStorage, MultiSink,
     };
 
     pub use crate::log_sink::GrpcSink;
+    pub use re_grpc_client::message_proxy::write::ReconnectOptions;
 
     #[cfg(not(target_arch = "wasm32"))]
     pub use re_log_encoding::{FileSink, FileSinkError};
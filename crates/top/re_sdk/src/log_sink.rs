@@ -1,12 +1,20 @@
+use std::collections::HashMap;
 use std::fmt;
 use std::sync::Arc;
 use std::time::Duration;
 
 use parking_lot::Mutex;
-use re_grpc_client::message_proxy::write::{Client as MessageProxyClient, Options};
+use re_chunk::external::arrow::array::BooleanArray as ArrowBooleanArray;
+use re_chunk::{Chunk, ChunkId, TimelineName};
+use re_grpc_client::message_proxy::write::{
+    Client as MessageProxyClient, Options, ReconnectOptions,
+};
 use re_log_encoding::encoder::encode_as_bytes_local;
 use re_log_encoding::encoder::{EncodeError, local_raw_encoder};
-use re_log_types::{BlueprintActivationCommand, LogMsg, StoreId};
+use re_log_types::{
+    BlueprintActivationCommand, EntityPath, EntityPathFilter, LogMsg, ResolvedEntityPathFilter,
+    StoreId, StoreKind,
+};
 
 use crate::RecordingStream;
 
@@ -163,6 +171,10 @@ impl private::Sealed for crate::sink::GrpcSink {}
 
 impl MultiSinkCompatible for crate::sink::GrpcSink {}
 
+impl<S: LogSink> private::Sealed for DecimatingSink<S> {}
+
+impl<S: LogSink> MultiSinkCompatible for DecimatingSink<S> {}
+
 // ----------------------------------------------------------------------------
 
 /// Store log messages in memory until you call [`LogSink::drain_backlog`].
@@ -424,6 +436,274 @@ impl LogSink for CallbackSink {
 
 // ----------------------------------------------------------------------------
 
+/// How a [`DecimatingSink`] thins out the rows of the entities matched by a [`DecimationRule`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum Decimation {
+    /// Keep at most `hz` rows per second of `timeline`, dropping the rows in between.
+    ///
+    /// The timeline is expected to be a temporal one (e.g. `log_time`), whose values are
+    /// nanoseconds. Rows that aren't indexed by `timeline` are kept as-is.
+    /// Rows that are older than the latest row kept so far are dropped.
+    MaxRate { timeline: TimelineName, hz: f64 },
+
+    /// Keep every `n`th row, starting with the first one.
+    EveryNth(usize),
+}
+
+/// Applies a [`Decimation`] to all entities matched by an entity path filter.
+#[derive(Clone, Debug)]
+pub struct DecimationRule {
+    filter: ResolvedEntityPathFilter,
+    decimation: Decimation,
+}
+
+impl DecimationRule {
+    /// Decimate the entities matched by `filter`, e.g. `/camera/**` or `/imu`.
+    ///
+    /// See [`EntityPathFilter`] for the filter syntax.
+    pub fn new(filter: &str, decimation: Decimation) -> Self {
+        Self {
+            filter: EntityPathFilter::parse_forgiving(filter).resolve_without_substitutions(),
+            decimation,
+        }
+    }
+
+    /// Keep at most `hz` rows per second of `timeline` for the entities matched by `filter`.
+    #[inline]
+    pub fn max_rate(filter: &str, timeline: impl Into<TimelineName>, hz: f64) -> Self {
+        Self::new(
+            filter,
+            Decimation::MaxRate {
+                timeline: timeline.into(),
+                hz,
+            },
+        )
+    }
+
+    /// Keep every `n`th row of the entities matched by `filter`.
+    #[inline]
+    pub fn every_nth(filter: &str, n: usize) -> Self {
+        Self::new(filter, Decimation::EveryNth(n))
+    }
+}
+
+/// What a [`DecimatingSink`] remembers about each entity across chunks.
+#[derive(Default)]
+struct DecimationState {
+    /// Latest time of all rows that were kept, for [`Decimation::MaxRate`].
+    last_kept_time: Option<i64>,
+
+    /// Number of rows seen so far, for [`Decimation::EveryNth`].
+    num_rows_seen: usize,
+}
+
+/// Wraps another sink and rate-limits or decimates the data of some entities before
+/// forwarding it.
+///
+/// Chunks are sliced row by row, so the rows that are kept always make it through even when
+/// the batcher packs many rows into a single chunk.
+/// Static data, blueprints and entities not matched by any rule are forwarded untouched.
+///
+/// Combine it with a [`MultiSink`] to send the full data to disk and a thinned stream to a
+/// remote viewer:
+///
+/// ```ignore
+/// let sink = MultiSink::new(vec![
+///     Box::new(FileSink::new("full.rrd")?),
+///     Box::new(DecimatingSink::new(
+///         GrpcSink::default(),
+///         vec![
+///             DecimationRule::max_rate("/camera/**", "log_time", 10.0),
+///             DecimationRule::every_nth("/imu", 10),
+///         ],
+///     )),
+/// ]);
+/// ```
+pub struct DecimatingSink<S: LogSink> {
+    inner: S,
+
+    /// The first matching rule wins.
+    rules: Vec<DecimationRule>,
+
+    states: Mutex<HashMap<(StoreId, EntityPath), DecimationState>>,
+}
+
+impl<S: LogSink> DecimatingSink<S> {
+    /// Decimate the data sent to `inner` according to `rules`.
+    ///
+    /// Rules are tried in order, and the first one matching an entity applies to it.
+    pub fn new(inner: S, rules: Vec<DecimationRule>) -> Self {
+        Self {
+            inner,
+            rules,
+            states: Mutex::new(HashMap::default()),
+        }
+    }
+
+    /// The sink the decimated data is forwarded to.
+    #[inline]
+    pub fn inner(&self) -> &S {
+        &self.inner
+    }
+
+    /// Returns `None` if the whole message should be dropped.
+    fn decimate(&self, msg: LogMsg) -> Option<LogMsg> {
+        let LogMsg::ArrowMsg(store_id, arrow_msg) = &msg else {
+            return Some(msg);
+        };
+
+        if store_id.kind == StoreKind::Blueprint {
+            return Some(msg);
+        }
+
+        let chunk = match Chunk::from_arrow_msg(arrow_msg) {
+            Ok(chunk) => chunk,
+            Err(err) => {
+                re_log::warn_once!("Failed to decimate chunk, forwarding it as-is: {err}");
+                return Some(msg);
+            }
+        };
+
+        if chunk.is_static() || chunk.is_empty() {
+            return Some(msg);
+        }
+
+        let Some(rule) = self
+            .rules
+            .iter()
+            .find(|rule| rule.filter.matches(chunk.entity_path()))
+        else {
+            return Some(msg);
+        };
+
+        let keep = {
+            let mut states = self.states.lock();
+            let state = states
+                .entry((store_id.clone(), chunk.entity_path().clone()))
+                .or_default();
+            rows_to_keep(&chunk, &rule.decimation, state)
+        };
+
+        let num_kept = keep.iter().filter(|&&keep| keep).count();
+        if num_kept == 0 {
+            return None;
+        }
+        if num_kept == chunk.num_rows() {
+            return Some(msg);
+        }
+
+        let filtered = chunk
+            .filtered(&ArrowBooleanArray::from(keep))?
+            .with_id(ChunkId::new());
+        match filtered.to_arrow_msg() {
+            Ok(arrow_msg) => Some(LogMsg::ArrowMsg(store_id.clone(), arrow_msg)),
+            Err(err) => {
+                re_log::warn_once!("Failed to decimate chunk, forwarding it as-is: {err}");
+                Some(msg)
+            }
+        }
+    }
+}
+
+/// Decides, row by row, which rows of `chunk` survive the `decimation`.
+fn rows_to_keep(chunk: &Chunk, decimation: &Decimation, state: &mut DecimationState) -> Vec<bool> {
+    match decimation {
+        Decimation::MaxRate { timeline, hz } => {
+            let Some(time_column) = chunk.timelines().get(timeline) else {
+                return vec![true; chunk.num_rows()];
+            };
+
+            let min_interval_nanos = if *hz > 0.0 {
+                (1e9 / hz) as i64
+            } else {
+                i64::MAX
+            };
+
+            // Walk the rows in time order, so that the rows that are kept are at least
+            // `min_interval_nanos` apart even if they were logged out of order.
+            let times = time_column.times_raw();
+            let mut row_indices: Vec<usize> = (0..times.len()).collect();
+            if !time_column.is_sorted() {
+                row_indices.sort_by_key(|&row| times[row]);
+            }
+
+            // Since rows are only kept going forward in time, `last_kept_time` is the latest
+            // time kept so far, and anything before it is dropped.
+            let mut keep = vec![false; times.len()];
+            for row in row_indices {
+                let time = times[row];
+                if state
+                    .last_kept_time
+                    .is_none_or(|last| time.saturating_sub(last) >= min_interval_nanos)
+                {
+                    keep[row] = true;
+                    state.last_kept_time = Some(time);
+                }
+            }
+            keep
+        }
+
+        Decimation::EveryNth(n) => {
+            let n = (*n).max(1);
+            (0..chunk.num_rows())
+                .map(|_| {
+                    let keep = state.num_rows_seen % n == 0;
+                    state.num_rows_seen += 1;
+                    keep
+                })
+                .collect()
+        }
+    }
+}
+
+impl<S: LogSink> LogSink for DecimatingSink<S> {
+    #[inline]
+    fn send(&self, msg: LogMsg) {
+        if let Some(msg) = self.decimate(msg) {
+            self.inner.send(msg);
+        }
+    }
+
+    #[inline]
+    fn send_all(&self, messages: Vec<LogMsg>) {
+        let messages = messages
+            .into_iter()
+            .filter_map(|msg| self.decimate(msg))
+            .collect();
+        self.inner.send_all(messages);
+    }
+
+    #[inline]
+    fn drain_backlog(&self) -> Vec<LogMsg> {
+        self.inner.drain_backlog()
+    }
+
+    #[inline]
+    fn flush_blocking(&self) {
+        self.inner.flush_blocking();
+    }
+
+    #[inline]
+    fn drop_if_disconnected(&self) {
+        self.inner.drop_if_disconnected();
+    }
+
+    #[inline]
+    fn send_blueprint(&self, blueprint: Vec<LogMsg>, activation_cmd: BlueprintActivationCommand) {
+        self.inner.send_blueprint(blueprint, activation_cmd);
+    }
+}
+
+impl<S: LogSink> fmt::Debug for DecimatingSink<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DecimatingSink")
+            .field("rules", &self.rules)
+            .finish_non_exhaustive()
+    }
+}
+
+// ----------------------------------------------------------------------------
+
 /// Stream log messages to an a remote Rerun server.
 pub struct GrpcSink {
     client: MessageProxyClient,
@@ -441,10 +721,38 @@ impl GrpcSink {
     /// ```ignore
     /// GrpcSink::new("rerun+http://127.0.0.1:9434/proxy");
     /// ```
+    ///
+    /// If the connection is lost, e.g. because the viewer was restarted, the sink keeps trying to
+    /// reconnect and buffers everything logged in the meantime.
+    /// Use [`Self::with_reconnect_options`] to control how much is buffered.
     #[inline]
     pub fn new(uri: re_uri::ProxyUri, flush_timeout: Option<Duration>) -> Self {
+        Self::with_reconnect_options(uri, flush_timeout, ReconnectOptions::default())
+    }
+
+    /// Like [`Self::new`], but with control over reconnecting and buffering while disconnected.
+    ///
+    /// ### Example
+    ///
+    /// ```ignore
+    /// GrpcSink::with_reconnect_options(
+    ///     "rerun+http://127.0.0.1:9434/proxy".parse()?,
+    ///     re_sdk::default_flush_timeout(),
+    ///     ReconnectOptions {
+    ///         max_buffer_bytes: 64 * 1024 * 1024,
+    ///         spill_path: Some("offline.rrd".into()),
+    ///         ..Default::default()
+    ///     },
+    /// );
+    /// ```
+    pub fn with_reconnect_options(
+        uri: re_uri::ProxyUri,
+        flush_timeout: Option<Duration>,
+        reconnect: ReconnectOptions,
+    ) -> Self {
         let options = Options {
             flush_timeout,
+            reconnect,
             ..Default::default()
         };
         Self {
@@ -472,3 +780,131 @@ impl LogSink for GrpcSink {
         self.client.flush();
     }
 }
+
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use re_chunk::RowId;
+    use re_log_types::TimeCell;
+    use re_types::archetypes::Points3D;
+
+    use super::*;
+
+    /// One row every 10ms, i.e. 100 Hz.
+    fn chunk_msg(store_id: &StoreId, entity_path: &str, num_rows: i64) -> LogMsg {
+        chunk_msg_at(store_id, entity_path, (0..num_rows).map(|i| i * 10))
+    }
+
+    fn chunk_msg_at(
+        store_id: &StoreId,
+        entity_path: &str,
+        times_ms: impl IntoIterator<Item = i64>,
+    ) -> LogMsg {
+        let mut builder = Chunk::builder(entity_path);
+        for (i, time_ms) in times_ms.into_iter().enumerate() {
+            let timepoint = [(
+                "log_time",
+                TimeCell::from_duration_nanos(time_ms * 1_000_000),
+            )];
+            builder = builder.with_archetype(
+                RowId::new(),
+                timepoint,
+                &Points3D::new([(i as f32, 0.0, 0.0)]),
+            );
+        }
+        let chunk = builder.build().unwrap();
+        LogMsg::ArrowMsg(store_id.clone(), chunk.to_arrow_msg().unwrap())
+    }
+
+    fn chunks(messages: &[LogMsg], entity_path: &str) -> Vec<Chunk> {
+        messages
+            .iter()
+            .filter_map(|msg| match msg {
+                LogMsg::ArrowMsg(_, arrow_msg) => Chunk::from_arrow_msg(arrow_msg).ok(),
+                _ => None,
+            })
+            .filter(|chunk| chunk.entity_path() == &EntityPath::from(entity_path))
+            .collect()
+    }
+
+    fn num_rows(messages: &[LogMsg], entity_path: &str) -> usize {
+        chunks(messages, entity_path)
+            .iter()
+            .map(|chunk| chunk.num_rows())
+            .sum()
+    }
+
+    fn log_times_ms(messages: &[LogMsg], entity_path: &str) -> Vec<i64> {
+        let mut times: Vec<i64> = chunks(messages, entity_path)
+            .iter()
+            .flat_map(|chunk| {
+                chunk.timelines()[&TimelineName::log_time()]
+                    .times_raw()
+                    .to_vec()
+            })
+            .map(|nanos| nanos / 1_000_000)
+            .collect();
+        times.sort_unstable();
+        times
+    }
+
+    #[test]
+    fn decimating_sink() {
+        let store_id = StoreId::random(StoreKind::Recording);
+        let sink = DecimatingSink::new(
+            BufferedSink::new(),
+            vec![
+                DecimationRule::max_rate("/camera/**", "log_time", 10.0),
+                DecimationRule::every_nth("/imu", 4),
+            ],
+        );
+
+        // Send the data in two halves to check that state is kept across chunks.
+        for _ in 0..2 {
+            sink.send_all(vec![
+                chunk_msg(&store_id, "/camera/left", 50),
+                chunk_msg(&store_id, "/imu", 50),
+                chunk_msg(&store_id, "/gps", 50),
+            ]);
+        }
+
+        let messages = sink.drain_backlog();
+
+        // Time restarts at 0 with the second half, which is older than anything kept already.
+        assert_eq!(
+            log_times_ms(&messages, "/camera/left"),
+            [0, 100, 200, 300, 400]
+        );
+        assert_eq!(num_rows(&messages, "/imu"), 25);
+        assert_eq!(num_rows(&messages, "/gps"), 100);
+    }
+
+    #[test]
+    fn decimating_sink_out_of_order() {
+        let store_id = StoreId::random(StoreKind::Recording);
+        let sink = DecimatingSink::new(
+            BufferedSink::new(),
+            vec![DecimationRule::max_rate("/camera", "log_time", 10.0)],
+        );
+
+        // Rows at 100 Hz, shuffled within each chunk and overlapping across chunks.
+        sink.send(chunk_msg_at(
+            &store_id,
+            "/camera",
+            (0..50).rev().map(|i| i * 10),
+        ));
+        sink.send(chunk_msg_at(
+            &store_id,
+            "/camera",
+            (25..75).map(|i| (i * 37 % 50 + 25) * 10),
+        ));
+        sink.send(chunk_msg_at(&store_id, "/camera", (0..100).map(|i| i * 10)));
+
+        let times = log_times_ms(&sink.drain_backlog(), "/camera");
+        assert_eq!(times, (0..10).map(|i| i * 100).collect::<Vec<_>>());
+        for pair in times.windows(2) {
+            assert!(pair[1] - pair[0] >= 100, "{times:?} exceeds 10 Hz");
+        }
+    }
+}
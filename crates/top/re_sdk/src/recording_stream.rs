@@ -397,6 +397,9 @@ impl RecordingStreamBuilder {
     /// wait during a flush before potentially dropping data. Note: Passing `None` here can cause a
     /// call to `flush` to block indefinitely if a connection cannot be established.
     ///
+    /// The sink reconnects automatically if the connection is lost, buffering data meanwhile,
+    /// see [`GrpcSink::with_reconnect_options`][`crate::log_sink::GrpcSink::with_reconnect_options`].
+    ///
     /// ## Example
     ///
     /// ```no_run
@@ -583,6 +586,9 @@ impl RecordingStreamBuilder {
     /// wait during a flush before potentially dropping data. Note: Passing `None` here can cause a
     /// call to `flush` to block indefinitely if a connection cannot be established.
     ///
+    /// The sink reconnects automatically if the connection is lost, buffering data meanwhile,
+    /// see [`GrpcSink::with_reconnect_options`][`crate::log_sink::GrpcSink::with_reconnect_options`].
+    ///
     /// ## Example
     ///
     /// ```no_run
@@ -1367,6 +1373,36 @@ impl RecordingStream {
         self.log_file(filepath, Some(contents), entity_path_prefix, static_, true)
     }
 
+    /// Downloads the file at the given `http://` or `https://` `uri` and logs it using all
+    /// [`re_data_loader::DataLoader`]s available.
+    ///
+    /// The progress of large downloads is logged along the way.
+    ///
+    /// This method blocks until the download is complete, and then until either at least one
+    /// [`re_data_loader::DataLoader`] starts streaming data in or all of them fail.
+    ///
+    /// See <https://www.rerun.io/docs/reference/data-loaders/overview> for more information.
+    #[cfg(feature = "data_loaders")]
+    pub fn log_file_from_uri(
+        &self,
+        uri: &str,
+        entity_path_prefix: Option<EntityPath>,
+        static_: bool,
+    ) -> RecordingStreamResult<()> {
+        if !re_data_loader::is_http_uri(uri) {
+            return Err(re_data_loader::DataLoaderError::Incompatible(uri.into()).into());
+        }
+
+        let contents = re_data_loader::fetch_uri_blocking(uri)?;
+        self.log_file(
+            re_data_loader::filepath_from_uri(uri),
+            Some(std::borrow::Cow::Owned(contents)),
+            entity_path_prefix,
+            static_,
+            true,
+        )
+    }
+
     /// If `prefer_current_recording` is set (which is always the case for now), the dataloader settings
     /// will be configured as if the current SDK recording is the currently opened recording.
     /// Most dataloaders prefer logging to the currently opened recording if one is set.
@@ -1417,6 +1453,9 @@ impl RecordingStream {
                 })
                 .unwrap_or_default()
             }),
+            directory: Default::default(),
+            tabular: Default::default(),
+            image: Default::default(),
         };
 
         if prefer_current_recording {
@@ -1932,6 +1971,9 @@ impl RecordingStream {
     /// `flush_timeout` is the minimum time the [`GrpcSink`][`crate::log_sink::GrpcSink`] will
     /// wait during a flush before potentially dropping data. Note: Passing `None` here can cause a
     /// call to `flush` to block indefinitely if a connection cannot be established.
+    ///
+    /// The sink reconnects automatically if the connection is lost, buffering data meanwhile,
+    /// see [`GrpcSink::with_reconnect_options`][`crate::log_sink::GrpcSink::with_reconnect_options`].
     pub fn connect_grpc_opts(
         &self,
         url: impl Into<String>,
@@ -2032,6 +2074,9 @@ impl RecordingStream {
     /// `flush_timeout` is the minimum time the [`GrpcSink`][`crate::log_sink::GrpcSink`] will
     /// wait during a flush before potentially dropping data. Note: Passing `None` here can cause a
     /// call to `flush` to block indefinitely if a connection cannot be established.
+    ///
+    /// The sink reconnects automatically if the connection is lost, buffering data meanwhile,
+    /// see [`GrpcSink::with_reconnect_options`][`crate::log_sink::GrpcSink::with_reconnect_options`].
     pub fn spawn_opts(
         &self,
         opts: &crate::SpawnOptions,
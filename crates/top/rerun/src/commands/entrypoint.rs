@@ -21,6 +21,9 @@ use re_web_viewer_server::WebViewerServerPort;
 #[cfg(feature = "analytics")]
 use crate::commands::AnalyticsCommands;
 
+#[cfg(feature = "render")]
+use crate::commands::RenderCommand;
+
 // ---
 
 const LONG_ABOUT: &str = r#"
@@ -208,6 +211,26 @@ When persisted, the state will be stored at the following locations:
     )]
     threads: i32,
 
+    /// Load numbered images in directories (e.g. `frame_000123.png`) as one entity over time.
+    ///
+    /// Possible values:
+    ///
+    /// * `frame`
+    ///   Use the number in the file name, on the `frame` timeline.
+    ///
+    /// * `mtime`
+    ///   Use the modification time of the file, on the `file_time` timeline.
+    #[clap(long, verbatim_doc_comment)]
+    image_sequence: Option<String>,
+
+    /// Keep watching the given directories, and load new files as they appear.
+    #[clap(long)]
+    watch: bool,
+
+    /// Load 16-bit single-channel PNGs as depth images (in millimeters) rather than grayscale images.
+    #[clap(long)]
+    png16_as_depth: bool,
+
     #[clap(long_help = r"Any combination of:
 - A gRPC url to a Rerun server
 - A path to a Rerun .rrd recording
@@ -526,6 +549,18 @@ enum Command {
     #[command(subcommand)]
     Analytics(AnalyticsCommands),
 
+    /// Render the views of a recording to images or a video, without opening a window.
+    ///
+    /// Examples:
+    ///
+    /// * `rerun render recording.rrd --blueprint layout.rbl --time frame=42 --out frame.png`
+    ///
+    /// * `rerun render recording.rrd --time-range sim_time=0s..10s --fps 30 --out video.mp4`
+    ///
+    /// * `rerun render recording.rrd --software --time-range frame=.. --out frames/{}.png`
+    #[cfg(feature = "render")]
+    Render(RenderCommand),
+
     #[command(subcommand)]
     Rrd(RrdCommands),
 
@@ -605,6 +640,14 @@ where
             #[cfg(feature = "analytics")]
             Command::Analytics(analytics) => analytics.run().map_err(Into::into),
 
+            #[cfg(feature = "render")]
+            Command::Render(render) => render.run(
+                main_thread_token,
+                build_info,
+                &call_source.app_env(),
+                tokio_runtime.handle(),
+            ),
+
             Command::Rrd(rrd) => rrd.run(),
 
             #[cfg(feature = "native_viewer")]
@@ -780,10 +823,35 @@ fn run_impl(
 
         #[allow(unused_mut)]
         let mut rxs_table = Vec::new();
+        let directory = re_data_source::DirectoryLoaderSettings {
+            image_sequence: match args.image_sequence.as_deref() {
+                None => None,
+                Some("frame") => Some(re_data_source::ImageSequenceTime::FrameNumber),
+                Some("mtime") => Some(re_data_source::ImageSequenceTime::ModificationTime),
+                Some(other) => anyhow::bail!(
+                    "Unknown --image-sequence value: {other:?}, expected `frame` or `mtime`"
+                ),
+            },
+            watch: args.watch,
+        };
+        let image = re_data_source::ImageLoaderSettings {
+            png16_as_depth: args.png16_as_depth,
+        };
+
         #[allow(unused_mut)]
         let mut rxs_logs = data_sources
             .into_iter()
             .filter_map(|data_source| {
+                if let DataSource::FilePath(file_source, path) = data_source {
+                    return Some(DataSource::stream_file_path(
+                        file_source,
+                        path,
+                        directory.clone(),
+                        image.clone(),
+                        None,
+                    ));
+                }
+
                 // TODO(#10093): this is problematic because the connection registry's token have
                 // not yet been deserialized from persistence (this is done later by `App`. So if
                 // this requires such a token, it will fail even though it'd succeed later.
@@ -1158,7 +1226,7 @@ fn run_profiler(args: &Args) -> re_tracing::Profiler {
 }
 
 #[cfg(feature = "native_viewer")]
-fn parse_size(size: &str) -> anyhow::Result<[f32; 2]> {
+pub(super) fn parse_size(size: &str) -> anyhow::Result<[f32; 2]> {
     fn parse_size_inner(size: &str) -> Option<[f32; 2]> {
         let (w, h) = size.split_once('x')?;
         let w = w.parse().ok()?;
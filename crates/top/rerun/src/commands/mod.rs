@@ -28,9 +28,15 @@ mod stdio;
 #[cfg(feature = "analytics")]
 mod analytics;
 
+#[cfg(feature = "render")]
+mod render;
+
 pub use self::entrypoint::run;
 pub use self::rrd::RrdCommands;
 pub use self::stdio::read_rrd_streams_from_file_or_stdin;
 
 #[cfg(feature = "analytics")]
 pub(crate) use self::analytics::AnalyticsCommands;
+
+#[cfg(feature = "render")]
+pub(crate) use self::render::RenderCommand;
@@ -0,0 +1,551 @@
+use std::{
+    io::Write as _,
+    path::{Path, PathBuf},
+    process::{Child, Stdio},
+    time::Duration,
+};
+
+use anyhow::Context as _;
+use itertools::Itertools as _;
+
+use re_data_source::DataSource;
+use re_log_types::{ResolvedTimeRange, TimeInt, TimeType, Timeline, TimelineName, TimestampFormat};
+use re_viewer::{
+    external::image::RgbaImage,
+    headless::{HeadlessOptions, HeadlessViewer},
+};
+
+// ---
+
+#[derive(Debug, Clone, clap::Parser)]
+pub struct RenderCommand {
+    /// Paths or URLs of the recordings to render, e.g. `.rrd` files.
+    #[clap(required = true)]
+    url_or_paths: Vec<String>,
+
+    /// A blueprint (`.rbl`) that lays out the views to render.
+    ///
+    /// The blueprint must have been saved for the same application id as the recording.
+    /// If not specified, the views are picked by the same heuristics the viewer uses.
+    #[clap(long)]
+    blueprint: Option<String>,
+
+    /// Render a single image at this time, e.g. `frame=42` or `log_time=2025-06-01T12:00:00Z`.
+    ///
+    /// If neither `--time` nor `--time-range` are specified, the latest state of the recording is rendered.
+    #[clap(long, conflicts_with = "time_range")]
+    time: Option<String>,
+
+    /// Render one image per step in this range, e.g. `frame=0..100` or `sim_time=0s..10s`.
+    ///
+    /// Either end of the range may be left out to use the start or end of the recording.
+    #[clap(long)]
+    time_range: Option<String>,
+
+    /// Number of images per second of timeline time, used with `--time-range` on temporal timelines.
+    ///
+    /// This is also the frame rate of encoded videos.
+    #[clap(long, default_value_t = 30.0)]
+    fps: f64,
+
+    /// Distance between images, used with `--time-range` on sequence timelines.
+    #[clap(long, default_value_t = 1)]
+    step: u64,
+
+    /// Where to write the output.
+    ///
+    /// A `.png` file for single images.
+    ///
+    /// With `--time-range`, either an `.mp4` file (requires `ffmpeg`), or a `.png` path
+    /// in which `{}` is replaced with the image number (e.g. `frames/{}.png`).
+    /// If there's no `{}`, the image number is appended to the file name.
+    #[clap(long, short)]
+    out: PathBuf,
+
+    /// Size of the rendered images in logical points, e.g. "1920x1080".
+    #[clap(long, default_value = "1280x720")]
+    size: String,
+
+    /// Number of pixels per logical point. Use 2 for high-dpi images.
+    #[clap(long, default_value_t = 1.0)]
+    pixels_per_point: f32,
+
+    /// Show the blueprint, selection and time panels around the views.
+    #[clap(long, default_value_t = false)]
+    show_panels: bool,
+
+    /// Prefer a software rasterizer (e.g. lavapipe or llvmpipe) over any GPU.
+    ///
+    /// Software rendering is slower, but works on machines without a GPU
+    /// and produces the same output regardless of the graphics hardware.
+    #[clap(long, default_value_t = false)]
+    software: bool,
+
+    /// Set the graphics backend, e.g. `vulkan`, `metal` or `gl`.
+    #[clap(long)]
+    renderer: Option<String>,
+
+    /// The `ffmpeg` executable used to encode videos.
+    #[clap(long, default_value = "ffmpeg")]
+    ffmpeg: String,
+
+    /// How long to wait for all data to be loaded.
+    #[clap(long, default_value_t = 60)]
+    timeout_secs: u64,
+}
+
+impl RenderCommand {
+    pub fn run(
+        &self,
+        main_thread_token: crate::MainThreadToken,
+        build_info: re_build_info::BuildInfo,
+        app_env: &re_viewer::AppEnvironment,
+        tokio_runtime_handle: &tokio::runtime::Handle,
+    ) -> anyhow::Result<()> {
+        let Self {
+            url_or_paths,
+            blueprint,
+            time,
+            time_range,
+            fps,
+            step,
+            out,
+            size,
+            pixels_per_point,
+            show_panels,
+            software,
+            renderer,
+            ffmpeg,
+            timeout_secs,
+        } = self;
+
+        anyhow::ensure!(*fps > 0.0, "--fps must be positive");
+        anyhow::ensure!(*step > 0, "--step must be positive");
+
+        let options = HeadlessOptions {
+            size_in_points: super::entrypoint::parse_size(size)?,
+            pixels_per_point: *pixels_per_point,
+            force_wgpu_backend: renderer.clone(),
+            prefer_software_adapter: *software,
+            show_panels: *show_panels,
+            ..Default::default()
+        };
+
+        let mut viewer = HeadlessViewer::new(
+            main_thread_token,
+            build_info,
+            app_env,
+            options,
+            re_viewer::AsyncRuntimeHandle::new_native(tokio_runtime_handle.clone()),
+        )?;
+
+        let connection_registry = re_grpc_client::ConnectionRegistry::new();
+        for uri in url_or_paths.iter().chain(blueprint) {
+            let data_source = DataSource::from_uri(re_log_types::FileSource::Cli, uri.clone());
+            match data_source.stream(
+                &connection_registry,
+                Box::new(|_: re_data_source::DataSourceCommand| {}),
+                None,
+            )? {
+                re_data_source::StreamSource::LogMessages(rx) => viewer.add_log_receiver(rx),
+                re_data_source::StreamSource::CatalogUri(_)
+                | re_data_source::StreamSource::EntryUri(_) => {
+                    anyhow::bail!("`rerun render` does not support catalogs: {uri:?}");
+                }
+            }
+        }
+
+        viewer.wait_for_data(Duration::from_secs(*timeout_secs))?;
+
+        if let Some(time) = time {
+            let (timeline, time) = parse_time_arg(&viewer.timelines(), time)?;
+            viewer.set_time(timeline, time)?;
+        }
+
+        let Some(time_range) = time_range else {
+            let image = viewer.render()?;
+            image
+                .save(out)
+                .with_context(|| format!("Failed to write {out:?}"))?;
+            re_log::info!("Wrote {out:?}");
+            return Ok(());
+        };
+
+        let (timeline, times) = parse_time_range_arg(
+            &viewer.timelines(),
+            |timeline| viewer.time_range(timeline),
+            time_range,
+            *fps,
+            *step,
+        )?;
+        anyhow::ensure!(!times.is_empty(), "--time-range {time_range:?} is empty");
+
+        let mut sink = if has_extension(out, "mp4") {
+            FrameSink::Video {
+                ffmpeg: ffmpeg.clone(),
+                out: out.clone(),
+                fps: *fps,
+                process: None,
+            }
+        } else {
+            FrameSink::Images { out: out.clone() }
+        };
+
+        for (index, time) in times.iter().enumerate() {
+            viewer.set_time(timeline, *time)?;
+            let image = viewer.render()?;
+            sink.write_frame(index, &image)?;
+        }
+
+        sink.finish()?;
+        re_log::info!("Wrote {} images to {out:?}", times.len());
+
+        Ok(())
+    }
+}
+
+/// Where the images of a time range go.
+enum FrameSink {
+    /// One image file per frame.
+    Images { out: PathBuf },
+
+    /// Raw frames piped into an `ffmpeg` process, started on the first frame.
+    Video {
+        ffmpeg: String,
+        out: PathBuf,
+        fps: f64,
+        process: Option<Child>,
+    },
+}
+
+impl FrameSink {
+    fn write_frame(&mut self, index: usize, image: &RgbaImage) -> anyhow::Result<()> {
+        match self {
+            Self::Images { out } => {
+                let path = frame_path(out, index);
+                if let Some(parent) = path.parent() {
+                    std::fs::create_dir_all(parent)?;
+                }
+                image
+                    .save(&path)
+                    .with_context(|| format!("Failed to write {path:?}"))?;
+            }
+
+            Self::Video {
+                ffmpeg,
+                out,
+                fps,
+                process,
+            } => {
+                if process.is_none() {
+                    *process = Some(spawn_ffmpeg(
+                        ffmpeg,
+                        out,
+                        *fps,
+                        image.width(),
+                        image.height(),
+                    )?);
+                }
+                let Some(stdin) = process.as_mut().and_then(|process| process.stdin.as_mut())
+                else {
+                    anyhow::bail!("ffmpeg closed its input");
+                };
+                stdin
+                    .write_all(image.as_raw())
+                    .context("Failed to pipe frame to ffmpeg")?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn finish(self) -> anyhow::Result<()> {
+        match self {
+            Self::Images { .. } => Ok(()),
+
+            Self::Video { process, out, .. } => {
+                let Some(mut process) = process else {
+                    return Ok(());
+                };
+
+                // Closing stdin tells ffmpeg that there are no more frames.
+                drop(process.stdin.take());
+                let status = process.wait()?;
+                anyhow::ensure!(
+                    status.success(),
+                    "ffmpeg failed to encode {out:?}: {status}"
+                );
+                Ok(())
+            }
+        }
+    }
+}
+
+fn spawn_ffmpeg(
+    ffmpeg: &str,
+    out: &Path,
+    fps: f64,
+    width: u32,
+    height: u32,
+) -> anyhow::Result<Child> {
+    std::process::Command::new(ffmpeg)
+        .args(["-y", "-loglevel", "error"])
+        .args(["-f", "rawvideo", "-pixel_format", "rgba"])
+        .args(["-video_size", &format!("{width}x{height}")])
+        .args(["-framerate", &fps.to_string()])
+        .args(["-i", "-"])
+        // H.264 with yuv420p requires even dimensions.
+        .args(["-vf", "pad=ceil(iw/2)*2:ceil(ih/2)*2"])
+        .args(["-c:v", "libx264", "-pix_fmt", "yuv420p"])
+        .arg(out)
+        .stdin(Stdio::piped())
+        .spawn()
+        .with_context(|| format!("Failed to run {ffmpeg:?} - is ffmpeg installed?"))
+}
+
+fn has_extension(path: &Path, extension: &str) -> bool {
+    path.extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case(extension))
+}
+
+/// Path of the image with the given index in an image sequence.
+fn frame_path(out: &Path, index: usize) -> PathBuf {
+    let file_name = out
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+
+    let file_name = if file_name.contains("{}") {
+        file_name.replace("{}", &format!("{index:05}"))
+    } else {
+        let stem = out
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let extension = out
+            .extension()
+            .map(|ext| ext.to_string_lossy().into_owned())
+            .unwrap_or_else(|| "png".to_owned());
+        format!("{stem}_{index:05}.{extension}")
+    };
+
+    out.with_file_name(file_name)
+}
+
+/// Looks up the timeline of a `timeline=value` argument among the timelines of the recording.
+fn find_timeline<'a>(timelines: &[Timeline], arg: &'a str) -> anyhow::Result<(Timeline, &'a str)> {
+    let (name, value) = arg
+        .split_once('=')
+        .ok_or_else(|| anyhow::anyhow!("Expected `<timeline>=<time>`, got {arg:?}"))?;
+
+    let timeline = timelines
+        .iter()
+        .find(|timeline| timeline.name().as_str() == name)
+        .copied()
+        .ok_or_else(|| {
+            anyhow::anyhow!(
+                "Unknown timeline {name:?}. The recording has: {}",
+                timelines
+                    .iter()
+                    .map(|timeline| format!("{:?}", timeline.name().as_str()))
+                    .join(", ")
+            )
+        })?;
+
+    Ok((timeline, value.trim()))
+}
+
+fn parse_time(timeline: Timeline, value: &str) -> anyhow::Result<TimeInt> {
+    timeline
+        .typ()
+        .parse_time(value, TimestampFormat::Utc)
+        .ok_or_else(|| {
+            anyhow::anyhow!(
+                "Failed to parse {value:?} as a time on timeline {:?}",
+                timeline.name().as_str()
+            )
+        })
+}
+
+fn parse_time_arg(timelines: &[Timeline], arg: &str) -> anyhow::Result<(Timeline, TimeInt)> {
+    let (timeline, value) = find_timeline(timelines, arg)?;
+    Ok((timeline, parse_time(timeline, value)?))
+}
+
+/// Parses a `timeline=start..end` argument into all the times that should be rendered.
+///
+/// `recording_range` returns the time range the recording covers on a timeline,
+/// which is used for left out ends of the range.
+fn parse_time_range_arg(
+    timelines: &[Timeline],
+    recording_range: impl Fn(&TimelineName) -> Option<ResolvedTimeRange>,
+    arg: &str,
+    fps: f64,
+    step: u64,
+) -> anyhow::Result<(Timeline, Vec<TimeInt>)> {
+    let (timeline, value) = find_timeline(timelines, arg)?;
+    let (start, end) = value
+        .split_once("..")
+        .ok_or_else(|| anyhow::anyhow!("Expected `<timeline>=<start>..<end>`, got {arg:?}"))?;
+
+    let recording_range = || {
+        recording_range(timeline.name())
+            .ok_or_else(|| anyhow::anyhow!("Timeline {:?} is empty", timeline.name().as_str()))
+    };
+    let start = if start.trim().is_empty() {
+        recording_range()?.min()
+    } else {
+        parse_time(timeline, start.trim())?
+    };
+    let end = if end.trim().is_empty() {
+        recording_range()?.max()
+    } else {
+        parse_time(timeline, end.trim())?
+    };
+
+    let step = match timeline.typ() {
+        TimeType::Sequence => {
+            anyhow::ensure!(step > 0, "--step must be positive");
+            step
+        }
+        TimeType::DurationNs | TimeType::TimestampNs => {
+            anyhow::ensure!(
+                fps.is_finite() && fps > 0.0,
+                "--fps must be positive, got {fps}"
+            );
+            ((1e9 / fps).round() as u64).max(1)
+        }
+    };
+
+    let times = (start.as_i64()..=end.as_i64())
+        .step_by(usize::try_from(step).unwrap_or(usize::MAX))
+        .map(TimeInt::new_temporal)
+        .collect();
+
+    Ok((timeline, times))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timelines() -> Vec<Timeline> {
+        vec![
+            Timeline::new_sequence("frame"),
+            Timeline::new_duration("sim_time"),
+        ]
+    }
+
+    fn recording_range(_: &TimelineName) -> Option<ResolvedTimeRange> {
+        Some(ResolvedTimeRange::new(
+            TimeInt::new_temporal(3),
+            TimeInt::new_temporal(7),
+        ))
+    }
+
+    fn times(values: impl IntoIterator<Item = i64>) -> Vec<TimeInt> {
+        values.into_iter().map(TimeInt::new_temporal).collect()
+    }
+
+    #[test]
+    fn test_frame_path() {
+        assert_eq!(
+            frame_path(Path::new("frames/{}.png"), 7),
+            PathBuf::from("frames/00007.png")
+        );
+        assert_eq!(
+            frame_path(Path::new("out/shot_{}_left.jpg"), 123),
+            PathBuf::from("out/shot_00123_left.jpg")
+        );
+        assert_eq!(
+            frame_path(Path::new("out/frame.png"), 0),
+            PathBuf::from("out/frame_00000.png")
+        );
+        assert_eq!(
+            frame_path(Path::new("frame"), 42),
+            PathBuf::from("frame_00042.png")
+        );
+        assert_eq!(
+            frame_path(Path::new("{}.png"), 100_000),
+            PathBuf::from("100000.png")
+        );
+    }
+
+    #[test]
+    fn test_parse_time_arg() {
+        let timelines = timelines();
+
+        let (timeline, time) = parse_time_arg(&timelines, "frame=42").unwrap();
+        assert_eq!(timeline, timelines[0]);
+        assert_eq!(time, TimeInt::new_temporal(42));
+
+        let (timeline, time) = parse_time_arg(&timelines, "sim_time= 2s ").unwrap();
+        assert_eq!(timeline, timelines[1]);
+        assert_eq!(time, TimeInt::new_temporal(2_000_000_000));
+
+        // Malformed input:
+        assert!(parse_time_arg(&timelines, "").is_err());
+        assert!(parse_time_arg(&timelines, "frame").is_err());
+        assert!(parse_time_arg(&timelines, "frame=").is_err());
+        assert!(parse_time_arg(&timelines, "frame=forty-two").is_err());
+        assert!(parse_time_arg(&timelines, "=42").is_err());
+        assert!(parse_time_arg(&timelines, "log_time=42").is_err());
+        assert!(parse_time_arg(&[], "frame=42").is_err());
+    }
+
+    #[test]
+    fn test_parse_time_range_arg() {
+        let timelines = timelines();
+
+        let (timeline, range) =
+            parse_time_range_arg(&timelines, recording_range, "frame=2..5", 30.0, 1).unwrap();
+        assert_eq!(timeline, timelines[0]);
+        assert_eq!(range, times([2, 3, 4, 5]));
+
+        let (_, range) =
+            parse_time_range_arg(&timelines, recording_range, "frame=0..10", 30.0, 4).unwrap();
+        assert_eq!(range, times([0, 4, 8]));
+
+        // Left out ends use the range of the recording:
+        let (_, range) =
+            parse_time_range_arg(&timelines, recording_range, "frame=..", 30.0, 1).unwrap();
+        assert_eq!(range, times(3..=7));
+        let (_, range) =
+            parse_time_range_arg(&timelines, recording_range, "frame=5..", 30.0, 1).unwrap();
+        assert_eq!(range, times(5..=7));
+        let (_, range) =
+            parse_time_range_arg(&timelines, recording_range, "frame=..4", 30.0, 1).unwrap();
+        assert_eq!(range, times(3..=4));
+
+        // Temporal timelines step by `1/fps`:
+        let (timeline, range) =
+            parse_time_range_arg(&timelines, recording_range, "sim_time=0s..1s", 4.0, 1).unwrap();
+        assert_eq!(timeline, timelines[1]);
+        assert_eq!(
+            range,
+            times([0, 250_000_000, 500_000_000, 750_000_000, 1_000_000_000])
+        );
+
+        // An inverted range is empty rather than an error, so the caller can report it:
+        let (_, range) =
+            parse_time_range_arg(&timelines, recording_range, "frame=5..2", 30.0, 1).unwrap();
+        assert!(range.is_empty());
+
+        // Malformed input:
+        let parse =
+            |arg, fps, step| parse_time_range_arg(&timelines, recording_range, arg, fps, step);
+        assert!(parse("frame", 30.0, 1).is_err());
+        assert!(parse("frame=5", 30.0, 1).is_err());
+        assert!(parse("frame=a..b", 30.0, 1).is_err());
+        assert!(parse("frame=0...5", 30.0, 1).is_err());
+        assert!(parse("log_time=0..5", 30.0, 1).is_err());
+        assert!(parse("frame=0..5", 30.0, 0).is_err());
+        assert!(parse("sim_time=0s..1s", 0.0, 1).is_err());
+        assert!(parse("sim_time=0s..1s", -30.0, 1).is_err());
+        assert!(parse("sim_time=0s..1s", f64::NAN, 1).is_err());
+        assert!(
+            parse_time_range_arg(&timelines, |_| None, "frame=..5", 30.0, 1).is_err(),
+            "left out ends need a non-empty recording"
+        );
+    }
+}
@@ -35,9 +35,10 @@ use datatype_uis::{
 
 use re_types::{
     blueprint::components::{
-        BackgroundKind, Corner2D, Enabled, ForceDistance, ForceIterations, ForceStrength,
-        GridSpacing, LinkAxis, LockRangeDuringZoom, MapProvider, NearClipPlane, RootContainer,
-        ViewFit, ViewMaximized,
+        BackgroundKind, BinCount, Corner2D, Enabled, ForceDistance, ForceIterations, ForceStrength,
+        GridSpacing, HopLength, LinkAxis, LockRangeDuringZoom, MapFollowMode, MapProvider,
+        MapTileSource, NearClipPlane, RootContainer, ViewFit, ViewMaximized, WindowFunction,
+        WindowLength,
     },
     components::{
         AggregationPolicy, AlbedoFactor, AxisLength, Color, DepthMeter, DrawOrder, FillMode,
@@ -95,6 +96,15 @@ pub fn create_component_ui_registry() -> re_viewer_context::ComponentUiRegistry
     registry.add_singleline_edit_or_view::<ForceIterations>(|ctx, ui, value| {
         edit_u64_range(ctx, ui, value, 1..=5)
     });
+    registry.add_singleline_edit_or_view::<BinCount>(|ctx, ui, value| {
+        edit_u64_range(ctx, ui, value, 1..=4096)
+    });
+    registry.add_singleline_edit_or_view::<WindowLength>(|ctx, ui, value| {
+        edit_u64_range(ctx, ui, value, 2..=65536)
+    });
+    registry.add_singleline_edit_or_view::<HopLength>(|ctx, ui, value| {
+        edit_u64_range(ctx, ui, value, 1..=65536)
+    });
 
     // Bool components:
     registry.add_singleline_edit_or_view::<Enabled>(edit_bool);
@@ -111,6 +121,7 @@ pub fn create_component_ui_registry() -> re_viewer_context::ComponentUiRegistry
     registry.add_multiline_edit_or_view::<Text>(edit_multiline_string);
     registry.add_singleline_edit_or_view::<Name>(edit_singleline_string);
     registry.add_multiline_edit_or_view::<Name>(edit_multiline_string);
+    registry.add_singleline_edit_or_view::<MapTileSource>(edit_singleline_string);
 
     // Enums:
     // TODO(#6974): Enums editors trivial and always the same, provide them automatically!
@@ -120,6 +131,7 @@ pub fn create_component_ui_registry() -> re_viewer_context::ComponentUiRegistry
     registry.add_singleline_edit_or_view::<FillMode>(edit_view_enum);
     registry.add_singleline_edit_or_view::<GraphType>(edit_view_enum);
     registry.add_singleline_edit_or_view::<LinkAxis>(edit_view_enum);
+    registry.add_singleline_edit_or_view::<MapFollowMode>(edit_view_enum);
     registry.add_singleline_edit_or_view::<MapProvider>(
         edit_view_enum_with_variant_available::<
             MapProvider,
@@ -140,6 +152,7 @@ pub fn create_component_ui_registry() -> re_viewer_context::ComponentUiRegistry
         )
     });
     registry.add_singleline_edit_or_view::<ViewFit>(edit_view_enum);
+    registry.add_singleline_edit_or_view::<WindowFunction>(edit_view_enum);
 
     // Vec2 components:
     registry.add_singleline_edit_or_view::<Position2D>(edit_or_view_vec2d);
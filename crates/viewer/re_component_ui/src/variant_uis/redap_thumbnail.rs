@@ -1,5 +1,5 @@
 use re_log_types::TimelineName;
-use re_types::components::MediaType;
+use re_types::{components::MediaType, image::ImageKind};
 use re_types_core::{ComponentDescriptor, Loggable as _, RowId};
 use re_ui::UiLayout;
 use re_viewer_context::ViewerContext;
@@ -27,7 +27,13 @@ pub fn redap_thumbnail(
         .store_context
         .caches
         .entry(|c: &mut re_viewer_context::ImageDecodeCache| {
-            c.entry(row_id, component_descriptor, slice, media_type.as_ref())
+            c.entry(
+                row_id,
+                component_descriptor,
+                slice,
+                media_type.as_ref(),
+                ImageKind::Color,
+            )
         })?;
 
     re_data_ui::image_preview_ui(
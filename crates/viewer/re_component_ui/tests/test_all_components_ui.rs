@@ -128,8 +128,10 @@ fn test_cases(reflection: &Reflection) -> Vec<TestCase> {
         re_types::blueprint::components::ActiveTab::name(),
         re_types::blueprint::components::AutoLayout::name(),
         re_types::blueprint::components::AutoViews::name(),
+        re_types::blueprint::components::BookmarkTime::name(),
         re_types::blueprint::components::ColumnShare::name(),
         re_types::blueprint::components::IncludedContent::name(),
+        re_types::blueprint::components::KeyframeTime::name(),
         re_types::blueprint::components::PanelState::name(),
         re_types::blueprint::components::RootContainer::name(),
         re_types::blueprint::components::RowShare::name(),
@@ -4,6 +4,7 @@ use re_log_types::EntityPath;
 use re_types::{
     ComponentDescriptor, RowId,
     components::{Blob, MediaType, VideoTimestamp},
+    image::ImageKind,
 };
 use re_ui::{
     UiExt as _, icons,
@@ -134,7 +135,13 @@ pub fn blob_preview_and_save_ui(
             .store_context
             .caches
             .entry(|c: &mut re_viewer_context::ImageDecodeCache| {
-                c.entry(blob_row_id, blob_component_descriptor, blob, media_type)
+                c.entry(
+                    blob_row_id,
+                    blob_component_descriptor,
+                    blob,
+                    media_type,
+                    ImageKind::from_archetype_name(blob_component_descriptor.archetype_name),
+                )
             })
             .ok();
 
@@ -191,6 +191,9 @@ impl crate::DataUi for EntityDb {
                 if store_id.as_ref() == hub.active_recording_id() {
                     ui.add_space(8.0);
                     ui.label("This is the active recording.");
+                } else if store_id.as_ref() == hub.comparison_recording_id() {
+                    ui.add_space(8.0);
+                    ui.label("This recording is compared against the active recording.");
                 }
             }
             StoreKind::Blueprint => {
@@ -0,0 +1,13 @@
+use re_log_types::{StoreId, StoreKind};
+
+/// The id of the recording that annotations made in the viewer on the given recording go to.
+///
+/// Annotations are kept in a recording of their own so that the annotated recording, which we
+/// generally regard as immutable, stays untouched. The id is derived from the annotated
+/// recording's id, so all annotations on a recording end up in the same annotation recording.
+pub fn annotation_recording_id(annotated_recording: &StoreId) -> StoreId {
+    StoreId::from_string(
+        StoreKind::Recording,
+        format!("{}_annotations", annotated_recording.as_str()),
+    )
+}
@@ -62,6 +62,9 @@ pub enum SystemCommand {
     /// Close all stores and show the welcome screen again.
     CloseAllEntries,
 
+    /// Start or stop comparing a second recording with the active one, or change how it's compared.
+    SetRecordingComparison(Option<crate::RecordingComparison>),
+
     /// Add more data to a store (blueprint or recording).
     ///
     /// Edit recordings with case: we generally regard recordings as immutable.
@@ -71,6 +74,14 @@ pub enum SystemCommand {
     /// Instead of using this directly, consider using `save_blueprint_archetype` or similar.
     AppendToStore(StoreId, Vec<Chunk>),
 
+    /// Add annotations made in the viewer to the annotation recording of a recording.
+    ///
+    /// The annotation recording is created on first use, see [`crate::annotation_recording_id`].
+    AppendToAnnotationRecording {
+        annotated_recording: StoreId,
+        chunks: Vec<Chunk>,
+    },
+
     UndoBlueprint {
         blueprint_id: StoreId,
     },
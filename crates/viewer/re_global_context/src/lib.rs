@@ -1,14 +1,17 @@
 //! State that is shared with most top-level crates.
 
+mod annotation_recording;
 mod app_options;
 mod blueprint_id;
 mod command_sender;
 mod contents;
 mod file_dialog;
 mod item;
+mod recording_comparison;
 mod recording_or_table;
 
 pub use self::{
+    annotation_recording::annotation_recording_id,
     app_options::AppOptions,
     blueprint_id::{BlueprintId, BlueprintIdRegistry, ContainerId, ViewId},
     command_sender::{
@@ -17,6 +20,7 @@ pub use self::{
     contents::{Contents, ContentsName, blueprint_id_to_tile_id},
     file_dialog::santitize_file_name,
     item::{Item, resolve_mono_instance_path, resolve_mono_instance_path_item},
+    recording_comparison::RecordingComparison,
     recording_or_table::RecordingOrTable,
 };
 
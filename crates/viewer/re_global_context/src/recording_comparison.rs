@@ -0,0 +1,126 @@
+use std::collections::BTreeMap;
+
+use re_log_types::{ResolvedTimeRange, StoreId, TimeInt, TimelineName};
+
+/// A second recording whose data is shown on top of the active recording's, e.g. to compare a
+/// baseline run with a regression run.
+///
+/// Both recordings share the active recording's time cursor. The compared recording is shifted
+/// by a per-timeline offset so that the two runs can be lined up.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RecordingComparison {
+    /// The recording that is compared against the active one.
+    pub store_id: StoreId,
+
+    /// How much later the compared recording's data shows up, per timeline, in raw time units
+    /// (i.e. nanoseconds for temporal timelines, steps for sequence timelines).
+    ///
+    /// Timelines without an entry aren't shifted.
+    pub time_offsets: BTreeMap<TimelineName, i64>,
+
+    /// Color the compared recording's data is blended towards, if any.
+    pub tint: Option<egui::Color32>,
+
+    /// Opacity of the compared recording's data, in `0.0..=1.0`.
+    pub opacity: f32,
+}
+
+impl RecordingComparison {
+    pub fn new(store_id: StoreId) -> Self {
+        Self {
+            store_id,
+            time_offsets: Default::default(),
+            tint: None,
+            opacity: 0.5,
+        }
+    }
+
+    /// The offset of the compared recording on the given timeline.
+    #[inline]
+    pub fn time_offset(&self, timeline: &TimelineName) -> i64 {
+        self.time_offsets.get(timeline).copied().unwrap_or_default()
+    }
+
+    /// Converts a time of the active recording to the matching time in the compared recording.
+    #[inline]
+    pub fn to_compared_time(&self, timeline: &TimelineName, time: TimeInt) -> TimeInt {
+        if time.is_static() {
+            return time;
+        }
+        TimeInt::saturated_temporal_i64(time.as_i64().saturating_sub(self.time_offset(timeline)))
+    }
+
+    /// Converts a time of the compared recording to the matching time in the active recording.
+    #[inline]
+    pub fn from_compared_time(&self, timeline: &TimelineName, time: TimeInt) -> TimeInt {
+        if time.is_static() {
+            return time;
+        }
+        TimeInt::saturated_temporal_i64(time.as_i64().saturating_add(self.time_offset(timeline)))
+    }
+
+    /// Converts a time range of the active recording to the matching range in the compared recording.
+    pub fn to_compared_range(
+        &self,
+        timeline: &TimelineName,
+        range: ResolvedTimeRange,
+    ) -> ResolvedTimeRange {
+        // The unbounded ends of a range must stay unbounded.
+        let shift = |time: TimeInt| {
+            if time == TimeInt::MIN || time == TimeInt::MAX {
+                time
+            } else {
+                self.to_compared_time(timeline, time)
+            }
+        };
+        ResolvedTimeRange::new(shift(range.min()), shift(range.max()))
+    }
+
+    /// The color to draw the compared recording's data with, given the color it was logged with.
+    pub fn apply_to_color(&self, color: egui::Color32) -> egui::Color32 {
+        let color = match self.tint {
+            Some(tint) => color.lerp_to_gamma(tint.to_opaque(), 0.5),
+            None => color,
+        };
+        color.gamma_multiply(self.opacity.clamp(0.0, 1.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use re_log_types::StoreKind;
+
+    use super::*;
+
+    #[test]
+    fn compared_time_roundtrip() {
+        let timeline = TimelineName::new("frame");
+        let mut comparison = RecordingComparison::new(StoreId::random(StoreKind::Recording));
+        comparison.time_offsets.insert(timeline, 10);
+
+        let time = TimeInt::new_temporal(25);
+        assert_eq!(
+            comparison.to_compared_time(&timeline, time),
+            TimeInt::new_temporal(15)
+        );
+        assert_eq!(
+            comparison.from_compared_time(&timeline, comparison.to_compared_time(&timeline, time)),
+            time
+        );
+
+        // Other timelines aren't shifted, and neither is static data.
+        let other = TimelineName::new("log_time");
+        assert_eq!(comparison.to_compared_time(&other, time), time);
+        assert_eq!(
+            comparison.to_compared_time(&timeline, TimeInt::STATIC),
+            TimeInt::STATIC
+        );
+
+        let range = comparison.to_compared_range(
+            &timeline,
+            ResolvedTimeRange::new(TimeInt::MIN, TimeInt::new_temporal(30)),
+        );
+        assert_eq!(range.min(), TimeInt::MIN);
+        assert_eq!(range.max(), TimeInt::new_temporal(20));
+    }
+}
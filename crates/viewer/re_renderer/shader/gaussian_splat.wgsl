@@ -0,0 +1,194 @@
+#import <./global_bindings.wgsl>
+#import <./types.wgsl>
+#import <./utils/camera.wgsl>
+
+struct SplatCloudInfo {
+    world_from_obj: mat4x4f,
+    outline_mask: vec2u,
+    picking_layer_object_id: vec2u,
+};
+@group(1) @binding(0)
+var<uniform> cloud: SplatCloudInfo;
+
+/// Center in object space & opacity.
+@group(1) @binding(1)
+var center_opacity_texture: texture_2d<f32>;
+
+/// Rotation quaternion, xyzw.
+@group(1) @binding(2)
+var rotation_texture: texture_2d<f32>;
+
+/// Standard deviation along each local axis, w is unused.
+@group(1) @binding(3)
+var scale_texture: texture_2d<f32>;
+
+@group(1) @binding(4)
+var color_texture: texture_2d<f32>;
+
+/// Splat indices, sorted back-to-front.
+@group(1) @binding(5)
+var draw_order_texture: texture_2d<u32>;
+
+// How many standard deviations a splat's quad spans in each direction.
+// At this distance, a Gaussian has dropped to exp(-4.5) ≈ 1% of its peak.
+const QUAD_EXTENT_IN_SIGMA: f32 = 3.0;
+
+// Variance added to each projected splat, in pixels², so that splats never get smaller than a pixel.
+const ANTI_ALIASING_VARIANCE_IN_PIXELS: f32 = 0.3;
+
+struct VertexOut {
+    @builtin(position)
+    position: vec4f,
+
+    /// Position within the splat in multiples of the standard deviation.
+    @location(0) @interpolate(perspective)
+    pos_in_sigma: vec2f,
+
+    @location(1) @interpolate(flat)
+    color: vec4f, // linear RGB, alpha is the splat's peak opacity.
+
+    @location(2) @interpolate(flat)
+    picking_instance_id: vec2u,
+};
+
+fn load_texel(texture: texture_2d<f32>, idx: u32) -> vec4f {
+    let size = textureDimensions(texture);
+    return textureLoad(texture, vec2u(idx % size.x, idx / size.x), 0);
+}
+
+fn load_splat_index(draw_idx: u32) -> u32 {
+    let size = textureDimensions(draw_order_texture);
+    return textureLoad(draw_order_texture, vec2u(draw_idx % size.x, draw_idx / size.x), 0).x;
+}
+
+fn mat3_from_quat(q: vec4f) -> mat3x3f {
+    let x = q.x;
+    let y = q.y;
+    let z = q.z;
+    let w = q.w;
+    return mat3x3f(
+        vec3f(1.0 - 2.0 * (y * y + z * z), 2.0 * (x * y + w * z), 2.0 * (x * z - w * y)),
+        vec3f(2.0 * (x * y - w * z), 1.0 - 2.0 * (x * x + z * z), 2.0 * (y * z + w * x)),
+        vec3f(2.0 * (x * z + w * y), 2.0 * (y * z - w * x), 1.0 - 2.0 * (x * x + y * y)),
+    );
+}
+
+fn degenerate_vertex() -> VertexOut {
+    var out: VertexOut;
+    out.position = vec4f(0.0, 0.0, 0.0, 1.0);
+    return out;
+}
+
+@vertex
+fn vs_main(@builtin(vertex_index) vertex_idx: u32) -> VertexOut {
+    let splat_idx = load_splat_index(vertex_idx / 6u);
+    let center_opacity = load_texel(center_opacity_texture, splat_idx);
+    let rotation = load_texel(rotation_texture, splat_idx);
+    let scale = load_texel(scale_texture, splat_idx).xyz;
+
+    let world_from_obj = mat3x3f(cloud.world_from_obj[0].xyz, cloud.world_from_obj[1].xyz, cloud.world_from_obj[2].xyz);
+    let view_from_world = mat3x3f(frame.view_from_world[0], frame.view_from_world[1], frame.view_from_world[2]);
+
+    let center_world = (cloud.world_from_obj * vec4f(center_opacity.xyz, 1.0)).xyz;
+    let center_view = frame.view_from_world * vec4f(center_world, 1.0);
+    let center_clip = frame.projection_from_world * vec4f(center_world, 1.0);
+    if center_clip.w <= 0.0 {
+        return degenerate_vertex();
+    }
+
+    // Covariance of the Gaussian in view space: Σ = M Mᵀ with M = R S.
+    let obj_from_splat = mat3_from_quat(normalize(rotation)) * mat3x3f(
+        vec3f(scale.x, 0.0, 0.0),
+        vec3f(0.0, scale.y, 0.0),
+        vec3f(0.0, 0.0, scale.z),
+    );
+    let m = view_from_world * world_from_obj * obj_from_splat;
+    let cov_view = m * transpose(m);
+
+    // Project the covariance to normalized device coordinates using the Jacobian of the projection
+    // at the splat's center (local affine approximation, as in EWA splatting).
+    let fx = frame.projection_from_view[0][0];
+    let fy = frame.projection_from_view[1][1];
+    var j: mat3x2f;
+    if is_camera_perspective() {
+        // View space looks along -z.
+        let inv_depth = 1.0 / -center_view.z;
+        j = mat3x2f(
+            vec2f(fx * inv_depth, 0.0),
+            vec2f(0.0, fy * inv_depth),
+            vec2f(fx * center_view.x, fy * center_view.y) * (inv_depth * inv_depth),
+        );
+    } else {
+        j = mat3x2f(vec2f(fx, 0.0), vec2f(0.0, fy), vec2f(0.0, 0.0));
+    }
+    let cov_ndc_full = j * cov_view * transpose(j);
+
+    // Make sure splats cover at least about a pixel.
+    let ndc_per_pixel = frame.pixel_world_size_from_camera_distance * vec2f(fx, fy);
+    let aa_variance = ANTI_ALIASING_VARIANCE_IN_PIXELS * ndc_per_pixel * ndc_per_pixel;
+    let a = cov_ndc_full[0][0] + aa_variance.x;
+    let b = cov_ndc_full[0][1];
+    let c = cov_ndc_full[1][1] + aa_variance.y;
+
+    // Eigen decomposition of the symmetric 2x2 covariance.
+    let mid = 0.5 * (a + c);
+    let radius = sqrt(max(0.25 * (a - c) * (a - c) + b * b, 0.0));
+    let lambda_major = mid + radius;
+    let lambda_minor = max(mid - radius, 0.0);
+    if lambda_major <= 0.0 {
+        return degenerate_vertex();
+    }
+    var axis_major = vec2f(1.0, 0.0);
+    if abs(b) > f32eps * lambda_major {
+        axis_major = normalize(vec2f(b, lambda_major - a));
+    } else if c > a {
+        axis_major = vec2f(0.0, 1.0);
+    }
+    let axis_minor = vec2f(-axis_major.y, axis_major.x);
+
+    // Two triangles per splat.
+    var corners = array<vec2f, 6>(
+        vec2f(-1.0, -1.0), vec2f(1.0, -1.0), vec2f(-1.0, 1.0),
+        vec2f(-1.0, 1.0), vec2f(1.0, -1.0), vec2f(1.0, 1.0),
+    );
+    let pos_in_sigma = corners[vertex_idx % 6u] * QUAD_EXTENT_IN_SIGMA;
+    let offset_ndc = pos_in_sigma.x * sqrt(lambda_major) * axis_major +
+                     pos_in_sigma.y * sqrt(lambda_minor) * axis_minor;
+
+    var out: VertexOut;
+    out.position = center_clip + vec4f(offset_ndc * center_clip.w, 0.0, 0.0);
+    out.pos_in_sigma = pos_in_sigma;
+    out.color = vec4f(load_texel(color_texture, splat_idx).rgb, center_opacity.w);
+    out.picking_instance_id = vec2u(splat_idx, 0u);
+    return out;
+}
+
+fn splat_alpha(in: VertexOut) -> f32 {
+    return in.color.a * exp(-0.5 * dot(in.pos_in_sigma, in.pos_in_sigma));
+}
+
+@fragment
+fn fs_main(in: VertexOut) -> @location(0) vec4f {
+    let alpha = min(splat_alpha(in), 0.99);
+    if alpha < 1.0 / 255.0 {
+        discard;
+    }
+    // Premultiplied alpha.
+    return vec4f(in.color.rgb * alpha, alpha);
+}
+
+@fragment
+fn fs_main_picking_layer(in: VertexOut) -> @location(0) vec4u {
+    if splat_alpha(in) < 0.5 {
+        discard;
+    }
+    return vec4u(cloud.picking_layer_object_id, in.picking_instance_id);
+}
+
+@fragment
+fn fs_main_outline_mask(in: VertexOut) -> @location(0) vec2u {
+    if splat_alpha(in) < 0.5 {
+        discard;
+    }
+    return cloud.outline_mask;
+}
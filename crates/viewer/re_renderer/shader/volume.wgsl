@@ -0,0 +1,360 @@
+//! Renders scalar volumes by ray-marching through a 3D texture.
+//!
+//! See `src/renderer/volumes.rs` for more documentation.
+
+#import <./colormap.wgsl>
+#import <./global_bindings.wgsl>
+#import <./types.wgsl>
+#import <./utils/camera.wgsl>
+
+// ---
+
+// Keep in sync with `VolumeInfoUBO` in `volumes.rs`.
+
+const MODE_ACCUMULATE: u32 = 0u;
+const MODE_ISO_SURFACE: u32 = 1u;
+
+/// Upper limit for the number of samples along a single ray.
+const MAX_NUM_STEPS: u32 = 1024u;
+
+/// Accumulation stops once a ray is this opaque.
+const OPACITY_EARLY_OUT: f32 = 0.99;
+
+/// Accumulated opacity above which a ray counts as "hitting" the volume for picking & outlines.
+const OPACITY_HIT_THRESHOLD: f32 = 0.5;
+
+struct VolumeInfo {
+    /// Transforms the unit cube to world space.
+    world_from_volume: mat4x4f,
+
+    /// Inverse of `world_from_volume`.
+    volume_from_world: mat4x4f,
+
+    /// Number of voxels along each axis.
+    dimensions: vec3u,
+
+    /// One of `MODE_*`.
+    mode: u32,
+
+    /// Outline mask id for the outline mask pass.
+    outline_mask_id: vec2u,
+
+    /// Picking object id that applies for the entire volume.
+    picking_layer_object_id: vec2u,
+
+    /// Values in this range are mapped to the full colormap.
+    value_range: vec2f,
+
+    /// Iso-surface mode only: the value at which the surface is extracted.
+    iso_value: f32,
+
+    /// Accumulate mode only: opacity of a single voxel with the maximum value.
+    opacity: f32,
+
+    /// Configures color mapping mode, see `colormap.wgsl`.
+    colormap: u32,
+
+    /// How many samples are taken per voxel along a ray.
+    samples_per_voxel: f32,
+};
+
+@group(1) @binding(0)
+var<uniform> volume_info: VolumeInfo;
+
+@group(1) @binding(1)
+var volume_texture: texture_3d<f32>;
+
+struct VertexOut {
+    @builtin(position)
+    position: vec4f,
+
+    @location(0) @interpolate(perspective)
+    pos_in_world: vec3f,
+
+    /// Outward facing normal of the cube face in volume space.
+    @location(1) @interpolate(flat)
+    face_normal: vec3f,
+};
+
+// Corners of a quad as two triangles, in the face's local uv space.
+var<private> QUAD_CORNERS: array<vec2f, 6> = array<vec2f, 6>(
+    vec2f(0.0, 0.0),
+    vec2f(1.0, 0.0),
+    vec2f(0.0, 1.0),
+    vec2f(0.0, 1.0),
+    vec2f(1.0, 0.0),
+    vec2f(1.0, 1.0),
+);
+
+/// Draws the 6 faces of the unit cube, i.e. 36 vertices.
+@vertex
+fn vs_main(@builtin(vertex_index) vertex_idx: u32) -> VertexOut {
+    let face = vertex_idx / 6u;
+    let axis = face / 2u;
+    let side = f32(face % 2u);
+    let uv = QUAD_CORNERS[vertex_idx % 6u];
+
+    var pos_in_volume: vec3f;
+    var face_normal: vec3f;
+    if axis == 0u {
+        pos_in_volume = vec3f(side, uv.x, uv.y);
+        face_normal = vec3f(side * 2.0 - 1.0, 0.0, 0.0);
+    } else if axis == 1u {
+        pos_in_volume = vec3f(uv.y, side, uv.x);
+        face_normal = vec3f(0.0, side * 2.0 - 1.0, 0.0);
+    } else {
+        pos_in_volume = vec3f(uv.x, uv.y, side);
+        face_normal = vec3f(0.0, 0.0, side * 2.0 - 1.0);
+    }
+
+    let pos_in_world = (volume_info.world_from_volume * vec4f(pos_in_volume, 1.0)).xyz;
+
+    var out: VertexOut;
+    out.position = frame.projection_from_world * vec4f(pos_in_world, 1.0);
+    out.pos_in_world = pos_in_world;
+    out.face_normal = face_normal;
+    return out;
+}
+
+// ---
+
+/// Reads the value of a single voxel, clamping to the volume's bounds.
+fn load_voxel(coord: vec3i) -> f32 {
+    let max_coord = vec3i(volume_info.dimensions) - vec3i(1);
+    return textureLoad(volume_texture, clamp(coord, vec3i(0), max_coord), 0).r;
+}
+
+/// Samples the volume at a position in volume space ([0, 1] on every axis).
+///
+/// Float textures are in general not filterable, so we do the trilinear interpolation ourselves.
+fn sample_volume(pos_in_volume: vec3f) -> f32 {
+    let pos_in_voxels = pos_in_volume * vec3f(volume_info.dimensions) - vec3f(0.5);
+    let base = floor(pos_in_voxels);
+    let t = pos_in_voxels - base;
+    let c = vec3i(base);
+
+    let v000 = load_voxel(c + vec3i(0, 0, 0));
+    let v100 = load_voxel(c + vec3i(1, 0, 0));
+    let v010 = load_voxel(c + vec3i(0, 1, 0));
+    let v110 = load_voxel(c + vec3i(1, 1, 0));
+    let v001 = load_voxel(c + vec3i(0, 0, 1));
+    let v101 = load_voxel(c + vec3i(1, 0, 1));
+    let v011 = load_voxel(c + vec3i(0, 1, 1));
+    let v111 = load_voxel(c + vec3i(1, 1, 1));
+
+    let v00 = mix(v000, v100, t.x);
+    let v10 = mix(v010, v110, t.x);
+    let v01 = mix(v001, v101, t.x);
+    let v11 = mix(v011, v111, t.x);
+    return mix(mix(v00, v10, t.y), mix(v01, v11, t.y), t.z);
+}
+
+/// Gradient of the volume in volume space, via central differences.
+fn volume_gradient(pos_in_volume: vec3f) -> vec3f {
+    let h = 1.0 / vec3f(volume_info.dimensions);
+    return vec3f(
+        sample_volume(pos_in_volume + vec3f(h.x, 0.0, 0.0)) - sample_volume(pos_in_volume - vec3f(h.x, 0.0, 0.0)),
+        sample_volume(pos_in_volume + vec3f(0.0, h.y, 0.0)) - sample_volume(pos_in_volume - vec3f(0.0, h.y, 0.0)),
+        sample_volume(pos_in_volume + vec3f(0.0, 0.0, h.z)) - sample_volume(pos_in_volume - vec3f(0.0, 0.0, h.z)),
+    ) / h;
+}
+
+fn normalized_value(value: f32) -> f32 {
+    let range = volume_info.value_range;
+    return saturate((value - range.x) / max(range.y - range.x, f32min_normal));
+}
+
+/// Linear index of the voxel at a position in volume space.
+fn voxel_index(pos_in_volume: vec3f) -> u32 {
+    let dims = volume_info.dimensions;
+    let coord = min(vec3u(saturate(pos_in_volume) * vec3f(dims)), dims - vec3u(1u));
+    return coord.x + dims.x * (coord.y + dims.y * coord.z);
+}
+
+fn depth_at(pos_in_volume: vec3f) -> f32 {
+    let pos_in_world = (volume_info.world_from_volume * vec4f(pos_in_volume, 1.0)).xyz;
+    let pos_in_clip = frame.projection_from_world * vec4f(pos_in_world, 1.0);
+    return saturate(pos_in_clip.z / pos_in_clip.w);
+}
+
+struct MarchResult {
+    /// Premultiplied linear color.
+    color: vec4f,
+
+    /// Depth of the first position along the ray that is considered part of the surface.
+    depth: f32,
+
+    /// Linear index of the voxel that was hit, only valid if `hit` is true.
+    voxel_index: u32,
+
+    /// Whether the ray hit an iso-surface, or accumulated enough opacity to be pickable.
+    hit: bool,
+};
+
+fn march(in: VertexOut) -> MarchResult {
+    var result: MarchResult;
+    result.color = vec4f(0.0);
+    result.depth = 0.0;
+    result.voxel_index = 0u;
+    result.hit = false;
+
+    let ray_in_world = camera_ray_to_world_pos(in.pos_in_world);
+
+    // Since the world space direction is normalized, ray distances are in world units in either space.
+    let origin = (volume_info.volume_from_world * vec4f(ray_in_world.origin, 1.0)).xyz;
+    let direction = (volume_info.volume_from_world * vec4f(ray_in_world.direction, 0.0)).xyz;
+
+    // Only back faces start a ray, that way there's exactly one fragment per pixel,
+    // even if the camera is inside the volume.
+    if dot(in.face_normal, direction) <= 0.0 {
+        return result;
+    }
+
+    // Ray vs. unit cube.
+    let inv_direction = 1.0 / direction;
+    let t_a = -origin * inv_direction;
+    let t_b = (vec3f(1.0) - origin) * inv_direction;
+    let t_min = min(t_a, t_b);
+    let t_max = max(t_a, t_b);
+    let t_enter = max(max(max(t_min.x, t_min.y), t_min.z), 0.0);
+    let t_exit = min(min(t_max.x, t_max.y), t_max.z);
+    if t_exit <= t_enter {
+        return result;
+    }
+
+    let length_in_voxels = length((t_exit - t_enter) * direction * vec3f(volume_info.dimensions));
+    let num_steps = clamp(u32(ceil(length_in_voxels * volume_info.samples_per_voxel)), 1u, MAX_NUM_STEPS);
+    let step_t = (t_exit - t_enter) / f32(num_steps);
+    let step_in_voxels = length_in_voxels / f32(num_steps);
+
+    if volume_info.mode == MODE_ISO_SURFACE {
+        var prev_t = t_enter;
+        var prev_value = sample_volume(origin + direction * t_enter);
+        if prev_value >= volume_info.iso_value {
+            // Already inside when entering the volume, show the cut surface.
+            result.hit = true;
+        } else {
+            for (var i = 1u; i <= num_steps; i += 1u) {
+                let t = t_enter + f32(i) * step_t;
+                let value = sample_volume(origin + direction * t);
+                if value >= volume_info.iso_value {
+                    // Refine linearly between the last two samples.
+                    let f = (volume_info.iso_value - prev_value) / max(value - prev_value, f32min_normal);
+                    prev_t = mix(prev_t, t, saturate(f));
+                    result.hit = true;
+                    break;
+                }
+                prev_t = t;
+                prev_value = value;
+            }
+        }
+
+        if !result.hit {
+            return result;
+        }
+
+        let pos_in_volume = origin + direction * prev_t;
+        let gradient = volume_gradient(pos_in_volume);
+
+        // Gradients are co-vectors, so they transform with the transpose of the inverse.
+        let m = volume_info.volume_from_world;
+        let gradient_in_world = transpose(mat3x3f(m[0].xyz, m[1].xyz, m[2].xyz)) * gradient;
+        var shading = 1.0;
+        if dot(gradient_in_world, gradient_in_world) > 0.0 {
+            // Simple head light.
+            shading = 0.3 + 0.7 * abs(dot(normalize(gradient_in_world), ray_in_world.direction));
+        }
+
+        let albedo = colormap_linear(volume_info.colormap, normalized_value(volume_info.iso_value));
+        result.color = vec4f(albedo * shading, 1.0);
+        result.depth = depth_at(pos_in_volume);
+        result.voxel_index = voxel_index(pos_in_volume);
+        return result;
+    }
+
+    // Emission-absorption model, front to back.
+    var first_visible = true;
+    for (var i = 0u; i < num_steps; i += 1u) {
+        let pos_in_volume = origin + direction * (t_enter + (f32(i) + 0.5) * step_t);
+        let t = normalized_value(sample_volume(pos_in_volume));
+
+        // Opacity is defined per voxel, correct it for the actual step length.
+        let voxel_alpha = clamp(volume_info.opacity * t, 0.0, 0.999);
+        let alpha = 1.0 - pow(1.0 - voxel_alpha, step_in_voxels);
+        if alpha <= 0.0 {
+            continue;
+        }
+
+        if first_visible {
+            result.depth = depth_at(pos_in_volume);
+            first_visible = false;
+        }
+
+        let color = colormap_linear(volume_info.colormap, t);
+        result.color += (1.0 - result.color.a) * vec4f(color * alpha, alpha);
+
+        if !result.hit && result.color.a >= OPACITY_HIT_THRESHOLD {
+            result.hit = true;
+            result.voxel_index = voxel_index(pos_in_volume);
+        }
+        if result.color.a >= OPACITY_EARLY_OUT {
+            break;
+        }
+    }
+
+    return result;
+}
+
+struct FragmentOut {
+    @location(0) color: vec4f,
+    @builtin(frag_depth) depth: f32,
+};
+
+@fragment
+fn fs_main(in: VertexOut) -> FragmentOut {
+    let result = march(in);
+    if result.color.a <= 0.0 {
+        discard;
+    }
+
+    var out: FragmentOut;
+    out.color = result.color;
+    out.depth = result.depth;
+    return out;
+}
+
+struct FragmentOutPicking {
+    @location(0) picking_id: vec4u,
+    @builtin(frag_depth) depth: f32,
+};
+
+@fragment
+fn fs_main_picking_layer(in: VertexOut) -> FragmentOutPicking {
+    let result = march(in);
+    if !result.hit {
+        discard;
+    }
+
+    var out: FragmentOutPicking;
+    out.picking_id = vec4u(volume_info.picking_layer_object_id, result.voxel_index, 0u);
+    out.depth = result.depth;
+    return out;
+}
+
+struct FragmentOutOutline {
+    @location(0) mask: vec2u,
+    @builtin(frag_depth) depth: f32,
+};
+
+@fragment
+fn fs_main_outline_mask(in: VertexOut) -> FragmentOutOutline {
+    let result = march(in);
+    if !result.hit {
+        discard;
+    }
+
+    var out: FragmentOutOutline;
+    out.mask = volume_info.outline_mask_id;
+    out.depth = result.depth;
+    return out;
+}
@@ -407,8 +407,25 @@ pub fn testing_instance_descriptor() -> wgpu::InstanceDescriptor {
 /// Panics if no adapter was found.
 #[cfg(native)]
 pub fn select_testing_adapter(instance: &wgpu::Instance) -> wgpu::Adapter {
+    select_headless_adapter(instance, true).expect("No graphics adapter found!")
+}
+
+/// Selects an adapter for rendering without a window or surface.
+///
+/// If `prefer_software` is set, CPU adapters (like lavapipe, llvmpipe or WARP) are picked over
+/// any GPU, which makes the output independent of the graphics hardware of the machine.
+/// Otherwise discrete GPUs are preferred, falling back to software rendering if nothing else is available.
+///
+/// Returns `None` if no adapter was found.
+#[cfg(native)]
+pub fn select_headless_adapter(
+    instance: &wgpu::Instance,
+    prefer_software: bool,
+) -> Option<wgpu::Adapter> {
     let mut adapters = instance.enumerate_adapters(wgpu::Backends::all());
-    assert!(!adapters.is_empty(), "No graphics adapter found!");
+    if adapters.is_empty() {
+        return None;
+    }
 
     re_log::debug!("Found the following adapters:");
     for adapter in &adapters {
@@ -425,9 +442,14 @@ pub fn select_testing_adapter(instance: &wgpu::Instance) -> wgpu::Adapter {
         wgpu::Backend::Noop => 7,
     });
 
-    // Prefer CPU adapters, otherwise if we can't, prefer discrete GPU over integrated GPU.
     adapters.sort_by_key(|a| match a.get_info().device_type {
-        wgpu::DeviceType::Cpu => 0, // CPU is the best for our purposes!
+        wgpu::DeviceType::Cpu => {
+            if prefer_software {
+                0
+            } else {
+                3
+            }
+        }
         wgpu::DeviceType::DiscreteGpu => 1,
         wgpu::DeviceType::Other
         | wgpu::DeviceType::IntegratedGpu
@@ -437,7 +459,7 @@ pub fn select_testing_adapter(instance: &wgpu::Instance) -> wgpu::Adapter {
     let adapter = adapters.remove(0);
     re_log::info!("Picked adapter: {:?}", adapter.get_info());
 
-    adapter
+    Some(adapter)
 }
 
 /// Backends that are officially supported by `re_renderer`.
@@ -0,0 +1,893 @@
+//! Node hierarchies, skins, morph targets & keyframe animations of a [`CpuModel`].
+//!
+//! All animation is evaluated on the CPU:
+//! node animations only change instance transforms, whereas skinned and/or morphed meshes
+//! are deformed on the CPU and their vertex buffers rewritten whenever the requested pose changes.
+
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+use slotmap::{SecondaryMap, SlotMap};
+use smallvec::SmallVec;
+
+use crate::{
+    CpuModel, CpuModelMeshKey, RenderContext,
+    mesh::{CpuMesh, GpuMesh, MeshError},
+    renderer::GpuMeshInstance,
+};
+
+/// Translation, rotation & scale of a node relative to its parent.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NodeTransform {
+    pub translation: glam::Vec3,
+    pub rotation: glam::Quat,
+    pub scale: glam::Vec3,
+}
+
+impl NodeTransform {
+    pub const IDENTITY: Self = Self {
+        translation: glam::Vec3::ZERO,
+        rotation: glam::Quat::IDENTITY,
+        scale: glam::Vec3::ONE,
+    };
+
+    #[inline]
+    pub fn to_affine(&self) -> glam::Affine3A {
+        glam::Affine3A::from_scale_rotation_translation(self.scale, self.rotation, self.translation)
+    }
+}
+
+impl Default for NodeTransform {
+    #[inline]
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+/// A node in the hierarchy of a [`CpuModel`].
+#[derive(Clone, Debug, Default)]
+pub struct CpuModelNode {
+    /// Indices of the child nodes in [`CpuModelRig::nodes`].
+    pub children: Vec<usize>,
+
+    /// Transform relative to the parent node if the node isn't animated.
+    pub rest_transform: NodeTransform,
+
+    /// Mesh instantiated by this node, if any.
+    pub mesh: Option<CpuModelMeshKey>,
+
+    /// Index into [`CpuModelRig::skins`] used to deform [`Self::mesh`].
+    pub skin: Option<usize>,
+
+    /// Morph target weights of the node if the node isn't animated.
+    ///
+    /// If empty, the default weights of the mesh are used instead.
+    pub morph_weights: Vec<f32>,
+}
+
+/// A skin, binding the vertices of a mesh to a set of joint nodes.
+#[derive(Clone, Debug, Default)]
+pub struct CpuSkin {
+    /// Index into [`CpuModelRig::nodes`] for every joint.
+    pub joints: Vec<usize>,
+
+    /// For every joint, transforms from mesh space into the joint's local space in the bind pose.
+    pub inverse_bind_matrices: Vec<glam::Affine3A>,
+}
+
+/// A morph target, displacing the vertices of a mesh.
+#[derive(Clone, Debug, Default)]
+pub struct CpuMorphTarget {
+    /// Either empty or the same length as the vertex positions of the mesh.
+    pub position_offsets: Vec<glam::Vec3>,
+
+    /// Either empty or the same length as the vertex normals of the mesh.
+    pub normal_offsets: Vec<glam::Vec3>,
+}
+
+/// Per-vertex data required to deform a mesh.
+#[derive(Clone, Debug, Default)]
+pub struct CpuMeshDeformation {
+    /// Up to four joint indices per vertex, indexing into [`CpuSkin::joints`].
+    ///
+    /// Either empty (mesh isn't skinned) or the same length as the vertex positions of the mesh.
+    pub joint_indices: Vec<[u16; 4]>,
+
+    /// Weight for each of the joints in [`Self::joint_indices`].
+    ///
+    /// Same length as [`Self::joint_indices`].
+    pub joint_weights: Vec<glam::Vec4>,
+
+    pub morph_targets: Vec<CpuMorphTarget>,
+
+    /// Morph target weights used unless overwritten by the node or an animation.
+    pub default_morph_weights: Vec<f32>,
+}
+
+impl CpuMeshDeformation {
+    pub fn is_skinned(&self) -> bool {
+        !self.joint_indices.is_empty()
+    }
+
+    pub fn has_morph_targets(&self) -> bool {
+        !self.morph_targets.is_empty()
+    }
+
+    /// Applies morph targets & skinning to a mesh.
+    ///
+    /// `joint_matrices` are the model-space transforms of the skin joints multiplied with their
+    /// inverse bind matrices. Skinning is skipped if `None`.
+    pub fn deform(
+        &self,
+        mesh: &CpuMesh,
+        morph_weights: &[f32],
+        joint_matrices: Option<&[glam::Affine3A]>,
+    ) -> CpuMesh {
+        re_tracing::profile_function!();
+
+        let mut mesh = mesh.clone();
+
+        for (target, &weight) in self.morph_targets.iter().zip(morph_weights) {
+            if weight == 0.0 {
+                continue;
+            }
+            for (position, offset) in mesh
+                .vertex_positions
+                .iter_mut()
+                .zip(&target.position_offsets)
+            {
+                *position += weight * *offset;
+            }
+            for (normal, offset) in mesh.vertex_normals.iter_mut().zip(&target.normal_offsets) {
+                *normal += weight * *offset;
+            }
+        }
+
+        if let Some(joint_matrices) = joint_matrices {
+            let joint_matrix = |index: u16| {
+                joint_matrices
+                    .get(index as usize)
+                    .map_or(glam::Mat4::IDENTITY, |m| glam::Mat4::from(*m))
+            };
+
+            for (vertex_index, (indices, weights)) in self
+                .joint_indices
+                .iter()
+                .zip(&self.joint_weights)
+                .enumerate()
+            {
+                let weight_sum = weights.element_sum();
+                if weight_sum <= 0.0 {
+                    // Not bound to any joint, leave as is.
+                    continue;
+                }
+
+                let skin_matrix = (joint_matrix(indices[0]) * weights.x
+                    + joint_matrix(indices[1]) * weights.y
+                    + joint_matrix(indices[2]) * weights.z
+                    + joint_matrix(indices[3]) * weights.w)
+                    * (1.0 / weight_sum);
+
+                if let Some(position) = mesh.vertex_positions.get_mut(vertex_index) {
+                    *position = skin_matrix.transform_point3(*position);
+                }
+                if let Some(normal) = mesh.vertex_normals.get_mut(vertex_index) {
+                    *normal = skin_matrix.transform_vector3(*normal).normalize_or_zero();
+                }
+            }
+        } else if !morph_weights.is_empty() {
+            for normal in &mut mesh.vertex_normals {
+                *normal = normal.normalize_or_zero();
+            }
+        }
+
+        mesh
+    }
+}
+
+/// How values between two keyframes are computed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AnimationInterpolation {
+    /// Keeps the value of the previous keyframe.
+    Step,
+
+    /// Linear interpolation, spherical linear for rotations.
+    Linear,
+
+    /// Cubic hermite spline with explicit in & out tangents per keyframe.
+    CubicSpline,
+}
+
+/// The node property targeted by an [`AnimationChannel`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AnimationProperty {
+    Translation,
+    Rotation,
+    Scale,
+    MorphWeights,
+}
+
+/// A single animated property of a node.
+#[derive(Clone, Debug)]
+pub struct AnimationChannel {
+    /// Index into [`CpuModelRig::nodes`].
+    pub node: usize,
+
+    pub property: AnimationProperty,
+
+    pub interpolation: AnimationInterpolation,
+
+    /// Keyframe times in seconds, strictly increasing.
+    pub times: Vec<f32>,
+
+    /// Flattened keyframe values.
+    ///
+    /// For [`AnimationInterpolation::CubicSpline`], every keyframe consists of
+    /// in-tangent, value & out-tangent (in that order).
+    pub values: Vec<f32>,
+}
+
+impl AnimationChannel {
+    /// Number of scalars making up a single sampled value.
+    fn value_size(&self) -> usize {
+        let keyframe_size = self.values.len() / self.times.len().max(1);
+        if self.interpolation == AnimationInterpolation::CubicSpline {
+            keyframe_size / 3
+        } else {
+            keyframe_size
+        }
+    }
+
+    /// Samples the channel at a given time in seconds.
+    ///
+    /// Times outside of the keyframe range are clamped to the first/last keyframe.
+    pub fn sample(&self, time: f32) -> SmallVec<[f32; 4]> {
+        let value_size = self.value_size();
+        if value_size == 0 {
+            return SmallVec::new();
+        }
+
+        let is_cubic = self.interpolation == AnimationInterpolation::CubicSpline;
+        let keyframe_size = if is_cubic { value_size * 3 } else { value_size };
+        let slice_at = |keyframe: usize, element: usize| {
+            let start = keyframe * keyframe_size + element * value_size;
+            &self.values[start..start + value_size]
+        };
+        let value_at = |keyframe: usize| slice_at(keyframe, usize::from(is_cubic));
+
+        let next = self.times.partition_point(|&t| t <= time);
+        let mut sampled: SmallVec<[f32; 4]> = if next == 0 {
+            value_at(0).into()
+        } else if next == self.times.len() {
+            value_at(next - 1).into()
+        } else {
+            let prev = next - 1;
+            let dt = self.times[next] - self.times[prev];
+            let s = if dt > 0.0 {
+                (time - self.times[prev]) / dt
+            } else {
+                0.0
+            };
+
+            match self.interpolation {
+                AnimationInterpolation::Step => value_at(prev).into(),
+
+                AnimationInterpolation::Linear => {
+                    if self.property == AnimationProperty::Rotation && value_size == 4 {
+                        let from = glam::Quat::from_slice(value_at(prev));
+                        let to = glam::Quat::from_slice(value_at(next));
+                        from.slerp(to, s).to_array().into()
+                    } else {
+                        value_at(prev)
+                            .iter()
+                            .zip(value_at(next))
+                            .map(|(a, b)| a + (b - a) * s)
+                            .collect()
+                    }
+                }
+
+                AnimationInterpolation::CubicSpline => {
+                    // https://registry.khronos.org/glTF/specs/2.0/glTF-2.0.html#interpolation-cubic
+                    let s2 = s * s;
+                    let s3 = s2 * s;
+                    let h00 = 2.0 * s3 - 3.0 * s2 + 1.0;
+                    let h10 = s3 - 2.0 * s2 + s;
+                    let h01 = -2.0 * s3 + 3.0 * s2;
+                    let h11 = s3 - s2;
+
+                    let v0 = value_at(prev);
+                    let out_tangent0 = slice_at(prev, 2);
+                    let v1 = value_at(next);
+                    let in_tangent1 = slice_at(next, 0);
+
+                    (0..value_size)
+                        .map(|i| {
+                            h00 * v0[i]
+                                + h10 * dt * out_tangent0[i]
+                                + h01 * v1[i]
+                                + h11 * dt * in_tangent1[i]
+                        })
+                        .collect()
+                }
+            }
+        };
+
+        if self.property == AnimationProperty::Rotation && sampled.len() == 4 {
+            let rotation = glam::Quat::from_slice(&sampled).normalize();
+            sampled.copy_from_slice(&rotation.to_array());
+        }
+
+        sampled
+    }
+}
+
+/// A named set of channels that are played back together.
+#[derive(Clone, Debug, Default)]
+pub struct Animation {
+    pub name: Option<String>,
+    pub channels: Vec<AnimationChannel>,
+}
+
+impl Animation {
+    /// Time of the last keyframe of any channel in seconds.
+    pub fn duration(&self) -> f32 {
+        self.channels
+            .iter()
+            .filter_map(|channel| channel.times.last().copied())
+            .fold(0.0, f32::max)
+    }
+}
+
+/// Node hierarchy, skins & animations of a [`CpuModel`].
+#[derive(Clone, Debug, Default)]
+pub struct CpuModelRig {
+    pub nodes: Vec<CpuModelNode>,
+
+    /// Nodes without a parent that are part of any scene.
+    pub root_nodes: Vec<usize>,
+
+    pub skins: Vec<CpuSkin>,
+
+    /// Deformation data for all meshes that are skinned and/or have morph targets.
+    pub deformations: SecondaryMap<CpuModelMeshKey, CpuMeshDeformation>,
+
+    pub animations: Vec<Animation>,
+}
+
+/// State of all nodes of a [`CpuModelRig`] at a point in time.
+pub struct RigPose {
+    /// Model-space transform of every node.
+    pub model_from_node: Vec<glam::Affine3A>,
+
+    /// Morph target weights of every node, empty if not overwritten by node or animation.
+    pub morph_weights: Vec<Vec<f32>>,
+}
+
+impl CpuModelRig {
+    /// Computes the pose of all nodes at a given time of an animation.
+    ///
+    /// Uses the rest pose if the animation doesn't exist.
+    pub fn pose(&self, animation_index: usize, time: f32) -> RigPose {
+        re_tracing::profile_function!();
+
+        let mut local_transforms = self
+            .nodes
+            .iter()
+            .map(|node| node.rest_transform)
+            .collect::<Vec<_>>();
+        let mut morph_weights = self
+            .nodes
+            .iter()
+            .map(|node| node.morph_weights.clone())
+            .collect::<Vec<_>>();
+
+        if let Some(animation) = self.animations.get(animation_index) {
+            for channel in &animation.channels {
+                let (Some(transform), Some(weights)) = (
+                    local_transforms.get_mut(channel.node),
+                    morph_weights.get_mut(channel.node),
+                ) else {
+                    continue;
+                };
+
+                let value = channel.sample(time);
+                match channel.property {
+                    AnimationProperty::Translation if value.len() == 3 => {
+                        transform.translation = glam::Vec3::from_slice(&value);
+                    }
+                    AnimationProperty::Rotation if value.len() == 4 => {
+                        transform.rotation = glam::Quat::from_slice(&value);
+                    }
+                    AnimationProperty::Scale if value.len() == 3 => {
+                        transform.scale = glam::Vec3::from_slice(&value);
+                    }
+                    AnimationProperty::MorphWeights => {
+                        *weights = value.to_vec();
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        let mut model_from_node = vec![glam::Affine3A::IDENTITY; self.nodes.len()];
+        let mut stack = self
+            .root_nodes
+            .iter()
+            .map(|&root| (root, glam::Affine3A::IDENTITY))
+            .collect::<Vec<_>>();
+        let mut visited = vec![false; self.nodes.len()];
+        while let Some((node_index, model_from_parent)) = stack.pop() {
+            // Guard against malformed hierarchies with cycles.
+            if visited.get(node_index).copied().unwrap_or(true) {
+                continue;
+            }
+            visited[node_index] = true;
+
+            let transform = model_from_parent * local_transforms[node_index].to_affine();
+            model_from_node[node_index] = transform;
+            stack.extend(
+                self.nodes[node_index]
+                    .children
+                    .iter()
+                    .map(|&child| (child, transform)),
+            );
+        }
+
+        RigPose {
+            model_from_node,
+            morph_weights,
+        }
+    }
+
+    /// Joint matrices of a skin for a given pose, see [`CpuMeshDeformation::deform`].
+    fn joint_matrices(&self, skin: &CpuSkin, pose: &RigPose) -> Vec<glam::Affine3A> {
+        skin.joints
+            .iter()
+            .enumerate()
+            .map(|(joint_index, &node_index)| {
+                let model_from_joint = pose
+                    .model_from_node
+                    .get(node_index)
+                    .copied()
+                    .unwrap_or(glam::Affine3A::IDENTITY);
+                let joint_from_mesh = skin
+                    .inverse_bind_matrices
+                    .get(joint_index)
+                    .copied()
+                    .unwrap_or(glam::Affine3A::IDENTITY);
+                model_from_joint * joint_from_mesh
+            })
+            .collect()
+    }
+}
+
+/// Number of times at which each animation is sampled to determine [`AnimatedModel::bounding_box`].
+const NUM_BOUNDING_BOX_SAMPLES: usize = 16;
+
+/// A mesh of a posed model, either moved rigidly or deformed on the CPU.
+enum PosedCpuMesh {
+    Rigid(CpuModelMeshKey, glam::Affine3A),
+    Deformed(CpuMesh, glam::Affine3A),
+}
+
+/// GPU meshes of a single pose.
+struct PosedGpuMeshes {
+    /// Animation index & time of the pose.
+    key: (usize, f32),
+
+    /// Frame index this pose was last requested in.
+    last_used_frame: u64,
+
+    meshes: Vec<(Arc<GpuMesh>, glam::Affine3A)>,
+}
+
+/// A model that can be posed at arbitrary times of its animations.
+///
+/// Meshes that are only moved rigidly are uploaded once.
+/// Skinned and/or morphed meshes are deformed on the CPU whenever the pose changes
+/// and written into the vertex buffers of a previous pose that is no longer in use.
+pub struct AnimatedModel {
+    meshes: SlotMap<CpuModelMeshKey, CpuMesh>,
+    rig: CpuModelRig,
+    rigid_gpu_meshes: SecondaryMap<CpuModelMeshKey, Arc<GpuMesh>>,
+
+    /// Poses requested in the current or the previous frame.
+    ///
+    /// Several views may show the same model at different times within a single frame,
+    /// each of them needs its own set of deformed meshes.
+    /// Poses that weren't requested in the current frame are overwritten in place
+    /// by the next differing request, those that weren't requested in the previous frame are dropped.
+    poses: Mutex<Vec<PosedGpuMeshes>>,
+}
+
+impl AnimatedModel {
+    pub fn new(model: CpuModel, ctx: &RenderContext) -> Result<Self, MeshError> {
+        re_tracing::profile_function!();
+
+        let CpuModel {
+            meshes,
+            instances: _,
+            rig,
+        } = model;
+
+        let mut rigid_gpu_meshes = SecondaryMap::with_capacity(meshes.len());
+        for (mesh_key, mesh) in &meshes {
+            if !rig.deformations.contains_key(mesh_key) {
+                rigid_gpu_meshes.insert(mesh_key, Arc::new(GpuMesh::new(ctx, mesh)?));
+            }
+        }
+
+        Ok(Self {
+            meshes,
+            rig,
+            rigid_gpu_meshes,
+            poses: Mutex::new(Vec::new()),
+        })
+    }
+
+    pub fn animations(&self) -> &[Animation] {
+        &self.rig.animations
+    }
+
+    /// Bounding box enclosing the model over the course of all its animations.
+    ///
+    /// Every animation is sampled at a fixed number of evenly spaced times,
+    /// so extreme poses in between samples may slightly exceed the box.
+    pub fn bounding_box(&self) -> macaw::BoundingBox {
+        re_tracing::profile_function!();
+
+        let mut bbox = macaw::BoundingBox::nothing();
+        for (animation_index, animation) in self.rig.animations.iter().enumerate() {
+            let duration = animation.duration();
+            for sample in 0..NUM_BOUNDING_BOX_SAMPLES {
+                let time = duration * sample as f32 / (NUM_BOUNDING_BOX_SAMPLES - 1) as f32;
+                for posed_mesh in self.pose_cpu_meshes(animation_index, time) {
+                    let (positions, world_from_mesh) = match &posed_mesh {
+                        PosedCpuMesh::Rigid(mesh_key, world_from_mesh) => {
+                            let Some(mesh) = self.meshes.get(*mesh_key) else {
+                                continue;
+                            };
+                            (&mesh.vertex_positions, world_from_mesh)
+                        }
+                        PosedCpuMesh::Deformed(mesh, world_from_mesh) => {
+                            (&mesh.vertex_positions, world_from_mesh)
+                        }
+                    };
+                    for position in positions {
+                        bbox.extend(world_from_mesh.transform_point3(*position));
+                    }
+                }
+            }
+        }
+        bbox
+    }
+
+    /// Mesh instances for the given animation at the given time in seconds.
+    ///
+    /// Times beyond the animation's duration wrap around, i.e. animations are looped.
+    pub fn mesh_instances(
+        &self,
+        ctx: &RenderContext,
+        animation_index: usize,
+        time: f32,
+    ) -> Result<Vec<GpuMeshInstance>, MeshError> {
+        let duration = self
+            .rig
+            .animations
+            .get(animation_index)
+            .map_or(0.0, |animation| animation.duration());
+        let time = if duration > 0.0 {
+            time.rem_euclid(duration)
+        } else {
+            0.0
+        };
+
+        let key = (animation_index, time);
+        let frame_idx = ctx.active_frame_idx();
+
+        let mut poses = self.poses.lock();
+        poses.retain(|pose| pose.last_used_frame + 1 >= frame_idx);
+
+        let pose_index = if let Some(index) = poses.iter().position(|pose| pose.key == key) {
+            index
+        } else if let Some(index) = poses
+            .iter()
+            .position(|pose| pose.last_used_frame < frame_idx)
+        {
+            let pose = &mut poses[index];
+            pose.meshes = self.pose_gpu_meshes(ctx, animation_index, time, &pose.meshes)?;
+            pose.key = key;
+            index
+        } else {
+            poses.push(PosedGpuMeshes {
+                key,
+                last_used_frame: frame_idx,
+                meshes: self.pose_gpu_meshes(ctx, animation_index, time, &[])?,
+            });
+            poses.len() - 1
+        };
+
+        let pose = &mut poses[pose_index];
+        pose.last_used_frame = frame_idx;
+
+        Ok(pose
+            .meshes
+            .iter()
+            .map(|(gpu_mesh, world_from_mesh)| GpuMeshInstance {
+                world_from_mesh: *world_from_mesh,
+                ..GpuMeshInstance::new(gpu_mesh.clone())
+            })
+            .collect())
+    }
+
+    /// Poses all meshes on the CPU, in the order of the nodes they're attached to.
+    fn pose_cpu_meshes(&self, animation_index: usize, time: f32) -> Vec<PosedCpuMesh> {
+        re_tracing::profile_function!();
+
+        let pose = self.rig.pose(animation_index, time);
+        let mut posed_meshes = Vec::new();
+
+        for (node_index, node) in self.rig.nodes.iter().enumerate() {
+            let Some(mesh_key) = node.mesh else {
+                continue;
+            };
+            let model_from_node = pose.model_from_node[node_index];
+
+            let (Some(mesh), Some(deformation)) = (
+                self.meshes.get(mesh_key),
+                self.rig.deformations.get(mesh_key),
+            ) else {
+                posed_meshes.push(PosedCpuMesh::Rigid(mesh_key, model_from_node));
+                continue;
+            };
+
+            let morph_weights = if pose.morph_weights[node_index].is_empty() {
+                &deformation.default_morph_weights
+            } else {
+                &pose.morph_weights[node_index]
+            };
+
+            let skin = node
+                .skin
+                .and_then(|skin_index| self.rig.skins.get(skin_index))
+                .filter(|_| deformation.is_skinned());
+            let joint_matrices = skin.map(|skin| self.rig.joint_matrices(skin, &pose));
+
+            let deformed_mesh = deformation.deform(mesh, morph_weights, joint_matrices.as_deref());
+
+            // Skinned vertices are already in model space, the node's own transform doesn't apply.
+            let world_from_mesh = if joint_matrices.is_some() {
+                glam::Affine3A::IDENTITY
+            } else {
+                model_from_node
+            };
+
+            posed_meshes.push(PosedCpuMesh::Deformed(deformed_mesh, world_from_mesh));
+        }
+
+        posed_meshes
+    }
+
+    /// Poses all meshes and uploads the deformed ones.
+    ///
+    /// If `previous` meshes of another pose are passed, their deformed meshes are overwritten
+    /// instead of allocating new ones.
+    fn pose_gpu_meshes(
+        &self,
+        ctx: &RenderContext,
+        animation_index: usize,
+        time: f32,
+        previous: &[(Arc<GpuMesh>, glam::Affine3A)],
+    ) -> Result<Vec<(Arc<GpuMesh>, glam::Affine3A)>, MeshError> {
+        re_tracing::profile_function!();
+
+        let posed_cpu_meshes = self.pose_cpu_meshes(animation_index, time);
+        let mut posed_meshes = Vec::with_capacity(posed_cpu_meshes.len());
+
+        for posed_mesh in posed_cpu_meshes {
+            match posed_mesh {
+                PosedCpuMesh::Rigid(mesh_key, world_from_mesh) => {
+                    if let Some(gpu_mesh) = self.rigid_gpu_meshes.get(mesh_key) {
+                        posed_meshes.push((gpu_mesh.clone(), world_from_mesh));
+                    }
+                }
+                PosedCpuMesh::Deformed(mesh, world_from_mesh) => {
+                    // Poses always consist of the same meshes in the same order.
+                    let gpu_mesh = if let Some((gpu_mesh, _)) = previous.get(posed_meshes.len()) {
+                        gpu_mesh.update_vertex_positions_and_normals(
+                            ctx,
+                            &mesh.vertex_positions,
+                            &mesh.vertex_normals,
+                        )?;
+                        gpu_mesh.clone()
+                    } else {
+                        Arc::new(GpuMesh::new(ctx, &mesh)?)
+                    };
+                    posed_meshes.push((gpu_mesh, world_from_mesh));
+                }
+            }
+        }
+
+        Ok(posed_meshes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn channel(
+        property: AnimationProperty,
+        interpolation: AnimationInterpolation,
+        times: Vec<f32>,
+        values: Vec<f32>,
+    ) -> AnimationChannel {
+        AnimationChannel {
+            node: 0,
+            property,
+            interpolation,
+            times,
+            values,
+        }
+    }
+
+    #[test]
+    fn sample_step_and_linear() {
+        let times = vec![1.0, 2.0];
+        let values = vec![0.0, 0.0, 0.0, 2.0, 4.0, 6.0];
+
+        let step = channel(
+            AnimationProperty::Translation,
+            AnimationInterpolation::Step,
+            times.clone(),
+            values.clone(),
+        );
+        assert_eq!(step.sample(1.5).as_slice(), &[0.0, 0.0, 0.0]);
+
+        let linear = channel(
+            AnimationProperty::Translation,
+            AnimationInterpolation::Linear,
+            times,
+            values,
+        );
+        assert_eq!(linear.sample(0.0).as_slice(), &[0.0, 0.0, 0.0]);
+        assert_eq!(linear.sample(1.5).as_slice(), &[1.0, 2.0, 3.0]);
+        assert_eq!(linear.sample(3.0).as_slice(), &[2.0, 4.0, 6.0]);
+    }
+
+    #[test]
+    fn sample_rotation_slerp() {
+        let to = glam::Quat::from_rotation_z(std::f32::consts::FRAC_PI_2);
+        let rotation = channel(
+            AnimationProperty::Rotation,
+            AnimationInterpolation::Linear,
+            vec![0.0, 1.0],
+            [glam::Quat::IDENTITY.to_array(), to.to_array()].concat(),
+        );
+
+        let halfway = glam::Quat::from_slice(&rotation.sample(0.5));
+        let expected = glam::Quat::from_rotation_z(std::f32::consts::FRAC_PI_4);
+        assert!(halfway.abs_diff_eq(expected, 1e-5));
+    }
+
+    #[test]
+    fn sample_cubic_spline_hits_keyframes() {
+        // Per keyframe: in-tangent, value, out-tangent.
+        let weights = channel(
+            AnimationProperty::MorphWeights,
+            AnimationInterpolation::CubicSpline,
+            vec![0.0, 1.0],
+            vec![0.0, 0.0, 1.0, 1.0, 1.0, 0.0],
+        );
+
+        assert_eq!(weights.sample(0.0).as_slice(), &[0.0]);
+        assert_eq!(weights.sample(1.0).as_slice(), &[1.0]);
+        let halfway = weights.sample(0.5)[0];
+        assert!(0.0 < halfway && halfway < 1.0);
+    }
+
+    #[test]
+    fn pose_propagates_through_hierarchy() {
+        let rig = CpuModelRig {
+            nodes: vec![
+                CpuModelNode {
+                    children: vec![1],
+                    ..Default::default()
+                },
+                CpuModelNode {
+                    rest_transform: NodeTransform {
+                        translation: glam::Vec3::X,
+                        ..NodeTransform::IDENTITY
+                    },
+                    ..Default::default()
+                },
+            ],
+            root_nodes: vec![0],
+            animations: vec![Animation {
+                name: None,
+                channels: vec![channel(
+                    AnimationProperty::Translation,
+                    AnimationInterpolation::Linear,
+                    vec![0.0, 1.0],
+                    vec![0.0, 0.0, 0.0, 0.0, 2.0, 0.0],
+                )],
+            }],
+            ..Default::default()
+        };
+
+        let pose = rig.pose(0, 0.5);
+        assert_eq!(
+            pose.model_from_node[1].transform_point3(glam::Vec3::ZERO),
+            glam::vec3(1.0, 1.0, 0.0)
+        );
+
+        let rest_pose = rig.pose(1, 0.5);
+        assert_eq!(
+            rest_pose.model_from_node[1].transform_point3(glam::Vec3::ZERO),
+            glam::Vec3::X
+        );
+    }
+
+    #[test]
+    fn bounding_box_covers_all_poses() {
+        let mut meshes = SlotMap::with_key();
+        let mesh_key = meshes.insert(CpuMesh {
+            label: "vertex".into(),
+            triangle_indices: vec![glam::UVec3::ZERO],
+            vertex_positions: vec![glam::Vec3::ZERO],
+            vertex_colors: Vec::new(),
+            vertex_normals: vec![glam::Vec3::Z],
+            vertex_texcoords: Vec::new(),
+            materials: SmallVec::new(),
+        });
+
+        let mut deformations = SecondaryMap::new();
+        deformations.insert(
+            mesh_key,
+            CpuMeshDeformation {
+                morph_targets: vec![CpuMorphTarget {
+                    position_offsets: vec![glam::Vec3::Z],
+                    normal_offsets: Vec::new(),
+                }],
+                default_morph_weights: vec![0.0],
+                ..Default::default()
+            },
+        );
+
+        let model = AnimatedModel {
+            meshes,
+            rig: CpuModelRig {
+                nodes: vec![CpuModelNode {
+                    mesh: Some(mesh_key),
+                    ..Default::default()
+                }],
+                root_nodes: vec![0],
+                deformations,
+                animations: vec![Animation {
+                    name: None,
+                    channels: vec![
+                        channel(
+                            AnimationProperty::Translation,
+                            AnimationInterpolation::Linear,
+                            vec![0.0, 1.0],
+                            vec![0.0, 0.0, 0.0, 2.0, 0.0, 0.0],
+                        ),
+                        channel(
+                            AnimationProperty::MorphWeights,
+                            AnimationInterpolation::Linear,
+                            vec![0.0, 1.0],
+                            vec![0.0, 1.0],
+                        ),
+                    ],
+                }],
+                ..Default::default()
+            },
+            rigid_gpu_meshes: SecondaryMap::new(),
+            poses: Mutex::new(Vec::new()),
+        };
+
+        let bbox = model.bounding_box();
+        assert!(bbox.min.abs_diff_eq(glam::Vec3::ZERO, 1e-5));
+        assert!(bbox.max.abs_diff_eq(glam::vec3(2.0, 0.0, 1.0), 1e-5));
+    }
+}
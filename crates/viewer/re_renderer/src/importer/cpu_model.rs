@@ -4,6 +4,7 @@ use slotmap::{SecondaryMap, SlotMap};
 
 use crate::{
     RenderContext,
+    importer::animation::{AnimatedModel, CpuModelRig},
     mesh::{CpuMesh, GpuMesh, MeshError},
     renderer::GpuMeshInstance,
 };
@@ -33,6 +34,12 @@ pub struct CpuMeshInstance {
 pub struct CpuModel {
     pub meshes: SlotMap<CpuModelMeshKey, CpuMesh>,
     pub instances: Vec<CpuMeshInstance>,
+
+    /// Node hierarchy, skins & animations.
+    ///
+    /// Empty for formats that don't support animation.
+    /// [`Self::instances`] always describe the rest pose.
+    pub rig: CpuModelRig,
 }
 
 impl CpuModel {
@@ -67,6 +74,16 @@ impl CpuModel {
         )
     }
 
+    /// Whether the model has any animations that can be played back via [`AnimatedModel`].
+    pub fn has_animations(&self) -> bool {
+        !self.rig.animations.is_empty()
+    }
+
+    /// Converts the model into an [`AnimatedModel`] that can be posed at any time of its animations.
+    pub fn into_animated_model(self, ctx: &RenderContext) -> Result<AnimatedModel, MeshError> {
+        AnimatedModel::new(self, ctx)
+    }
+
     /// Converts the entire model into a serious of mesh instances that can be rendered.
     ///
     /// Silently ignores:
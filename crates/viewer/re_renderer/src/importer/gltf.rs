@@ -5,6 +5,10 @@ use smallvec::SmallVec;
 
 use crate::{
     CpuMeshInstance, CpuModel, CpuModelMeshKey, RenderContext, Rgba32Unmul,
+    importer::animation::{
+        Animation, AnimationChannel, AnimationInterpolation, AnimationProperty, CpuMeshDeformation,
+        CpuModelNode, CpuMorphTarget, CpuSkin, NodeTransform,
+    },
     mesh::{CpuMesh, Material, MeshError},
     resource_managers::{GpuTexture2D, ImageDataDesc, TextureManager2D},
 };
@@ -109,9 +113,13 @@ pub fn load_gltf_from_buffer(
     for ref mesh in doc.meshes() {
         re_tracing::profile_scope!("mesh");
 
-        let re_mesh = import_mesh(mesh, &buffers, &images_as_textures, &ctx.texture_manager_2d)?;
+        let (re_mesh, deformation) =
+            import_mesh(mesh, &buffers, &images_as_textures, &ctx.texture_manager_2d)?;
         let re_mesh_key = re_model.meshes.insert(re_mesh);
         mesh_keys.insert(mesh.index(), re_mesh_key);
+        if let Some(deformation) = deformation {
+            re_model.rig.deformations.insert(re_mesh_key, deformation);
+        }
     }
 
     for scene in doc.scenes() {
@@ -125,6 +133,35 @@ pub fn load_gltf_from_buffer(
         }
     }
 
+    {
+        re_tracing::profile_scope!("rig");
+
+        re_model.rig.nodes = doc
+            .nodes()
+            .map(|node| CpuModelNode {
+                children: node.children().map(|child| child.index()).collect(),
+                rest_transform: node_transform(&node),
+                mesh: node
+                    .mesh()
+                    .and_then(|mesh| mesh_keys.get(&mesh.index()).copied()),
+                skin: node.skin().map(|skin| skin.index()),
+                morph_weights: node.weights().map(<[f32]>::to_vec).unwrap_or_default(),
+            })
+            .collect();
+        re_model.rig.root_nodes = doc
+            .scenes()
+            .flat_map(|scene| scene.nodes().map(|node| node.index()))
+            .collect();
+        re_model.rig.skins = doc
+            .skins()
+            .map(|skin| import_skin(&skin, &buffers))
+            .collect();
+        re_model.rig.animations = doc
+            .animations()
+            .map(|animation| import_animation(&animation, &buffers))
+            .collect();
+    }
+
     Ok(re_model)
 }
 
@@ -156,7 +193,7 @@ fn import_mesh(
     buffers: &[gltf::buffer::Data],
     gpu_image_handles: &[GpuTexture2D],
     texture_manager: &TextureManager2D, //imported_materials: HashMap<usize, Material>,
-) -> Result<CpuMesh, GltfImportError> {
+) -> Result<(CpuMesh, Option<CpuMeshDeformation>), GltfImportError> {
     re_tracing::profile_function!();
 
     let mesh_name = mesh.name().map_or("<unknown", |f| f).to_owned();
@@ -167,6 +204,9 @@ fn import_mesh(
     let mut vertex_normals = Vec::new();
     let mut vertex_texcoords = Vec::new();
     let mut materials = SmallVec::new();
+    let mut joint_indices = Vec::new();
+    let mut joint_weights = Vec::new();
+    let mut morph_targets: Vec<CpuMorphTarget> = Vec::new();
 
     // A GLTF mesh consists of several primitives, each with their own material.
     // Primitives map to vertex/index ranges for us as we store all vertices/indices into the same vertex/index buffer.
@@ -175,6 +215,7 @@ fn import_mesh(
         let set = 0;
 
         let reader = primitive.reader(|buffer| Some(&*buffers[buffer.index()]));
+        let base_vertex = vertex_positions.len();
 
         let index_offset = triangle_indices.len() as u32 * 3;
         if let Some(primitive_indices) = reader.read_indices() {
@@ -220,6 +261,35 @@ fn import_mesh(
             vertex_texcoords.resize(vertex_positions.len(), glam::Vec2::ZERO);
         }
 
+        if let (Some(joints), Some(weights)) = (reader.read_joints(set), reader.read_weights(set)) {
+            // Earlier primitives may not have been skinned.
+            joint_indices.resize(base_vertex, [0; 4]);
+            joint_weights.resize(base_vertex, glam::Vec4::ZERO);
+            joint_indices.extend(joints.into_u16());
+            joint_weights.extend(weights.into_f32().map(glam::Vec4::from));
+        }
+
+        for (target_index, (positions, normals, _tangents)) in
+            reader.read_morph_targets().enumerate()
+        {
+            if morph_targets.len() <= target_index {
+                morph_targets.resize_with(target_index + 1, Default::default);
+            }
+            let target = &mut morph_targets[target_index];
+            if let Some(positions) = positions {
+                target
+                    .position_offsets
+                    .resize(base_vertex, glam::Vec3::ZERO);
+                target
+                    .position_offsets
+                    .extend(positions.map(glam::Vec3::from));
+            }
+            if let Some(normals) = normals {
+                target.normal_offsets.resize(base_vertex, glam::Vec3::ZERO);
+                target.normal_offsets.extend(normals.map(glam::Vec3::from));
+            }
+        }
+
         let primitive_material = primitive.material();
         let pbr_material = primitive_material.pbr_metallic_roughness();
 
@@ -279,6 +349,34 @@ fn import_mesh(
         return Err(GltfImportError::NoTrianglePrimitives { mesh_name });
     }
 
+    // Later primitives may not have been skinned or morphed, pad out with neutral values.
+    let num_vertices = vertex_positions.len();
+    let deformation = if !joint_indices.is_empty() || !morph_targets.is_empty() {
+        if !joint_indices.is_empty() {
+            joint_indices.resize(num_vertices, [0; 4]);
+            joint_weights.resize(num_vertices, glam::Vec4::ZERO);
+        }
+        for target in &mut morph_targets {
+            if !target.position_offsets.is_empty() {
+                target
+                    .position_offsets
+                    .resize(num_vertices, glam::Vec3::ZERO);
+            }
+            if !target.normal_offsets.is_empty() {
+                target.normal_offsets.resize(num_vertices, glam::Vec3::ZERO);
+            }
+        }
+
+        Some(CpuMeshDeformation {
+            joint_indices,
+            joint_weights,
+            morph_targets,
+            default_morph_weights: mesh.weights().map(<[f32]>::to_vec).unwrap_or_default(),
+        })
+    } else {
+        None
+    };
+
     let mesh = CpuMesh {
         label: mesh.name().into(),
         triangle_indices,
@@ -291,15 +389,88 @@ fn import_mesh(
 
     mesh.sanity_check()?;
 
-    Ok(mesh)
+    Ok((mesh, deformation))
 }
 
-fn gather_instances_recursive(
-    instances: &mut Vec<CpuMeshInstance>,
-    node: &gltf::Node<'_>,
-    transform: &glam::Affine3A,
-    meshes: &HashMap<usize, CpuModelMeshKey>,
-) {
+fn import_skin(skin: &gltf::Skin<'_>, buffers: &[gltf::buffer::Data]) -> CpuSkin {
+    let joints = skin.joints().map(|joint| joint.index()).collect::<Vec<_>>();
+
+    // Inverse bind matrices default to identity if not specified.
+    let inverse_bind_matrices = skin
+        .reader(|buffer| Some(&*buffers[buffer.index()]))
+        .read_inverse_bind_matrices()
+        .map(|matrices| {
+            matrices
+                .map(|m| glam::Affine3A::from_mat4(glam::Mat4::from_cols_array_2d(&m)))
+                .collect()
+        })
+        .unwrap_or_else(|| vec![glam::Affine3A::IDENTITY; joints.len()]);
+
+    CpuSkin {
+        joints,
+        inverse_bind_matrices,
+    }
+}
+
+fn import_animation(animation: &gltf::Animation<'_>, buffers: &[gltf::buffer::Data]) -> Animation {
+    re_tracing::profile_function!();
+
+    let channels = animation
+        .channels()
+        .filter_map(|channel| {
+            let reader = channel.reader(|buffer| Some(&*buffers[buffer.index()]));
+            let times = reader.read_inputs()?.collect::<Vec<f32>>();
+
+            use gltf::animation::util::ReadOutputs;
+            let (property, values) = match reader.read_outputs()? {
+                ReadOutputs::Translations(translations) => (
+                    AnimationProperty::Translation,
+                    translations.flatten().collect(),
+                ),
+                ReadOutputs::Rotations(rotations) => (
+                    AnimationProperty::Rotation,
+                    rotations.into_f32().flatten().collect(),
+                ),
+                ReadOutputs::Scales(scales) => {
+                    (AnimationProperty::Scale, scales.flatten().collect())
+                }
+                ReadOutputs::MorphTargetWeights(weights) => (
+                    AnimationProperty::MorphWeights,
+                    weights.into_f32().collect(),
+                ),
+            };
+
+            let interpolation = match channel.sampler().interpolation() {
+                gltf::animation::Interpolation::Step => AnimationInterpolation::Step,
+                gltf::animation::Interpolation::Linear => AnimationInterpolation::Linear,
+                gltf::animation::Interpolation::CubicSpline => AnimationInterpolation::CubicSpline,
+            };
+
+            if times.is_empty() {
+                re_log::warn_once!(
+                    "Ignoring animation channel without keyframes in animation {:?}",
+                    animation.name()
+                );
+                return None;
+            }
+
+            Some(AnimationChannel {
+                node: channel.target().node().index(),
+                property,
+                interpolation,
+                times,
+                values,
+            })
+        })
+        .collect();
+
+    Animation {
+        name: animation.name().map(ToOwned::to_owned),
+        channels,
+    }
+}
+
+fn node_transform(node: &gltf::Node<'_>) -> NodeTransform {
     let (scale, rotation, translation) = match node.transform() {
         gltf::scene::Transform::Matrix { matrix } => {
             let matrix = glam::Mat4::from_cols_array_2d(&matrix);
@@ -317,9 +488,20 @@ fn gather_instances_recursive(
         ),
     };
 
-    let node_transform =
-        glam::Affine3A::from_scale_rotation_translation(scale, rotation, translation);
-    let transform = *transform * node_transform;
+    NodeTransform {
+        translation,
+        rotation,
+        scale,
+    }
+}
+
+fn gather_instances_recursive(
+    instances: &mut Vec<CpuMeshInstance>,
+    node: &gltf::Node<'_>,
+    transform: &glam::Affine3A,
+    meshes: &HashMap<usize, CpuModelMeshKey>,
+) {
+    let transform = *transform * node_transform(node).to_affine();
 
     for child in node.children() {
         gather_instances_recursive(instances, &child, &transform, meshes);
@@ -1,3 +1,4 @@
+pub mod animation;
 mod cpu_model;
 
 #[cfg(feature = "import-obj")]
@@ -9,4 +10,5 @@ pub mod gltf;
 #[cfg(feature = "import-stl")]
 pub mod stl;
 
+pub use animation::AnimatedModel;
 pub use cpu_model::{CpuMeshInstance, CpuModel, CpuModelMeshKey};
@@ -72,7 +72,7 @@ pub use draw_phases::{
     PickingLayerInstanceId, PickingLayerObjectId, PickingLayerProcessor, ScreenshotProcessor,
 };
 pub use global_bindings::GlobalBindings;
-pub use importer::{CpuMeshInstance, CpuModel, CpuModelMeshKey};
+pub use importer::{AnimatedModel, CpuMeshInstance, CpuModel, CpuModelMeshKey};
 pub use line_drawable_builder::{LineBatchBuilder, LineDrawableBuilder, LineStripBuilder};
 pub use point_cloud_builder::{PointCloudBatchBuilder, PointCloudBuilder};
 pub use queueable_draw_data::QueueableDrawData;
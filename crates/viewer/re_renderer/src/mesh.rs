@@ -156,6 +156,11 @@ pub enum MeshError {
     #[error("Index {index} was out of bounds for {num_pos} vertex positions")]
     IndexOutOfBounds { num_pos: usize, index: u32 },
 
+    #[error(
+        "Vertex data of {num_bytes} bytes doesn't fit the existing range of {range_size} bytes"
+    )]
+    VertexDataSizeMismatch { num_bytes: u64, range_size: u64 },
+
     #[error(transparent)]
     CpuWriteGpuReadError(#[from] crate::allocator::CpuWriteGpuReadError),
 }
@@ -353,4 +358,60 @@ impl GpuMesh {
             materials,
         })
     }
+
+    /// Overwrites vertex positions & normals of an existing mesh, leaving all other data as is.
+    ///
+    /// Used for meshes that are deformed on the CPU, avoiding a new allocation every time.
+    /// The number of positions & normals must be the same as when the mesh was created.
+    pub fn update_vertex_positions_and_normals(
+        &self,
+        ctx: &RenderContext,
+        vertex_positions: &[glam::Vec3],
+        vertex_normals: &[glam::Vec3],
+    ) -> Result<(), MeshError> {
+        re_tracing::profile_function!();
+
+        let sections: [(&[u8], &Range<u64>); 2] = [
+            (
+                bytemuck::cast_slice(vertex_positions),
+                &self.vertex_buffer_positions_range,
+            ),
+            (
+                bytemuck::cast_slice(vertex_normals),
+                &self.vertex_buffer_normals_range,
+            ),
+        ];
+
+        // Validate everything before writing anything.
+        for (bytes, range) in sections {
+            let num_bytes = bytes.len() as u64;
+            let range_size = range.end - range.start;
+            if num_bytes != range_size {
+                return Err(MeshError::VertexDataSizeMismatch {
+                    num_bytes,
+                    range_size,
+                });
+            }
+        }
+
+        for (bytes, range) in sections {
+            if bytes.is_empty() {
+                continue;
+            }
+
+            let mut staging_buffer = ctx.cpu_write_gpu_read_belt.lock().allocate::<u8>(
+                &ctx.device,
+                &ctx.gpu_resources.buffers,
+                bytes.len(),
+            )?;
+            staging_buffer.extend_from_slice(bytes)?;
+            staging_buffer.copy_to_buffer(
+                ctx.active_frame.before_view_builder_encoder.lock().get(),
+                &self.vertex_buffer_combined,
+                range.start,
+            )?;
+        }
+
+        Ok(())
+    }
 }
@@ -0,0 +1,559 @@
+//! Renderer for 3D Gaussian splats.
+//!
+//! ## Implementation details
+//!
+//! The attributes of a splat cloud are uploaded once into data textures, see [`GaussianSplatCloud`].
+//! Every splat is drawn as a quad spanned in the vertex shader:
+//! its 3D covariance is projected to the screen with the local affine approximation of the projection
+//! (as done by EWA splatting) and the quad is aligned with the axes of the resulting 2D Gaussian.
+//!
+//! Splats are alpha-blended in the transparent phase, which requires them to be drawn back-to-front.
+//! Sorting happens on the CPU, see [`GaussianSplatCloud::draw_order_back_to_front`].
+//! Since the sort only depends on the camera, the resulting [`GaussianSplatDrawOrder`] is uploaded
+//! separately so that it can be reused for as long as the camera doesn't move.
+//!
+//! Picking & outlines treat every fragment with at least half opacity as part of the splat.
+//! The picked instance id is the index of the splat within its cloud.
+
+use std::sync::Arc;
+
+use smallvec::smallvec;
+
+use crate::{
+    Color32, DebugLabel, OutlineMaskPreference, PickingLayerObjectId, PickingLayerProcessor,
+    allocator::{
+        CpuWriteGpuReadError, DataTextureSource, DataTextureSourceWriteError,
+        create_and_fill_uniform_buffer_batch,
+    },
+    draw_phases::{DrawPhase, OutlineMaskProcessor},
+    include_shader_module,
+    view_builder::ViewBuilder,
+    wgpu_resources::{
+        BindGroupDesc, BindGroupEntry, BindGroupLayoutDesc, GpuBindGroup, GpuBindGroupLayoutHandle,
+        GpuRenderPipelineHandle, GpuRenderPipelinePoolAccessor, GpuTexture, PipelineLayoutDesc,
+        RenderPipelineDesc,
+    },
+};
+
+use super::{DrawData, DrawError, RenderContext, Renderer};
+
+// ---
+
+mod gpu_data {
+    use crate::{PickingLayerObjectId, wgpu_buffer_types};
+
+    // Keep in sync with mirror in `gaussian_splat.wgsl.`
+
+    #[repr(C)]
+    #[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+    pub struct SplatCloudInfoUBO {
+        pub world_from_obj: wgpu_buffer_types::Mat4,
+
+        pub outline_mask_ids: wgpu_buffer_types::UVec2,
+        pub picking_object_id: PickingLayerObjectId,
+
+        pub end_padding: [wgpu_buffer_types::PaddingRow; 16 - 4 - 1],
+    }
+}
+
+/// A single 3D Gaussian.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GaussianSplat {
+    pub center: glam::Vec3,
+
+    /// Standard deviation along each of the splat's local axes.
+    pub scale: glam::Vec3,
+
+    /// Orientation of the splat's local axes.
+    pub rotation: glam::Quat,
+
+    /// Color of the splat, alpha is ignored.
+    pub color: Color32,
+
+    /// Opacity at the center of the splat.
+    pub opacity: f32,
+}
+
+/// A cloud of Gaussian splats uploaded to the GPU.
+///
+/// Cheap to clone, all data is reference counted.
+#[derive(Clone)]
+pub struct GaussianSplatCloud {
+    center_opacity_texture: GpuTexture,
+    rotation_texture: GpuTexture,
+    scale_texture: GpuTexture,
+    color_texture: GpuTexture,
+
+    /// Kept on the CPU for sorting.
+    centers: Arc<[glam::Vec3]>,
+}
+
+impl GaussianSplatCloud {
+    /// Uploads the attributes of all splats.
+    pub fn new(
+        ctx: &RenderContext,
+        label: &DebugLabel,
+        splats: &[GaussianSplat],
+    ) -> Result<Self, GaussianSplatDrawDataError> {
+        re_tracing::profile_function!();
+
+        let mut center_opacity = DataTextureSource::<glam::Vec4>::new(ctx);
+        let mut rotation = DataTextureSource::<glam::Vec4>::new(ctx);
+        let mut scale = DataTextureSource::<glam::Vec4>::new(ctx);
+        let mut color = DataTextureSource::<Color32>::new(ctx);
+
+        center_opacity.extend_from_slice(
+            &splats
+                .iter()
+                .map(|splat| splat.center.extend(splat.opacity))
+                .collect::<Vec<_>>(),
+        )?;
+        rotation.extend_from_slice(
+            &splats
+                .iter()
+                .map(|splat| glam::Vec4::from(splat.rotation))
+                .collect::<Vec<_>>(),
+        )?;
+        scale.extend_from_slice(
+            &splats
+                .iter()
+                .map(|splat| splat.scale.extend(0.0))
+                .collect::<Vec<_>>(),
+        )?;
+        color.extend_from_slice(&splats.iter().map(|splat| splat.color).collect::<Vec<_>>())?;
+
+        Ok(Self {
+            center_opacity_texture: center_opacity.finish(
+                wgpu::TextureFormat::Rgba32Float,
+                format!("{label:?} - center & opacity"),
+            )?,
+            rotation_texture: rotation.finish(
+                wgpu::TextureFormat::Rgba32Float,
+                format!("{label:?} - rotation"),
+            )?,
+            scale_texture: scale.finish(
+                wgpu::TextureFormat::Rgba32Float,
+                format!("{label:?} - scale"),
+            )?,
+            color_texture: color.finish(
+                wgpu::TextureFormat::Rgba8UnormSrgb,
+                format!("{label:?} - color"),
+            )?,
+            centers: splats.iter().map(|splat| splat.center).collect(),
+        })
+    }
+
+    /// Number of splats in the cloud.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.centers.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.centers.is_empty()
+    }
+
+    /// Bounding box of all splat centers, in the cloud's local space.
+    pub fn bbox(&self) -> macaw::BoundingBox {
+        macaw::BoundingBox::from_points(self.centers.iter().copied())
+    }
+
+    /// Splat indices ordered from back to front, as seen from the given eye.
+    ///
+    /// Eye position & forward direction are expected in the cloud's local space.
+    pub fn draw_order_back_to_front(
+        &self,
+        eye_position: glam::Vec3,
+        eye_forward: glam::Vec3,
+    ) -> Vec<u32> {
+        sort_back_to_front(&self.centers, eye_position, eye_forward)
+    }
+}
+
+/// Sorts by distance along the view direction with a counting sort on quantized depth.
+///
+/// Quantization makes this linear in the number of splats, which matters for clouds with millions of them.
+/// Splats that end up in the same bucket are too close to each other for their order to be noticeable.
+fn sort_back_to_front(
+    centers: &[glam::Vec3],
+    eye_position: glam::Vec3,
+    eye_forward: glam::Vec3,
+) -> Vec<u32> {
+    re_tracing::profile_function!();
+
+    const NUM_BUCKETS: usize = 1 << 16;
+
+    let depths: Vec<f32> = centers
+        .iter()
+        .map(|center| (*center - eye_position).dot(eye_forward))
+        .collect();
+    let (min_depth, max_depth) = depths
+        .iter()
+        .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), &depth| {
+            (min.min(depth), max.max(depth))
+        });
+    let buckets_per_depth = if max_depth > min_depth {
+        (NUM_BUCKETS - 1) as f32 / (max_depth - min_depth)
+    } else {
+        0.0
+    };
+
+    // The farthest splats get the lowest keys, so they end up first.
+    let keys: Vec<u16> = depths
+        .iter()
+        .map(|&depth| ((max_depth - depth) * buckets_per_depth) as u16)
+        .collect();
+
+    let mut bucket_offsets = vec![0u32; NUM_BUCKETS];
+    for &key in &keys {
+        bucket_offsets[key as usize] += 1;
+    }
+    let mut offset = 0;
+    for bucket_offset in &mut bucket_offsets {
+        let count = *bucket_offset;
+        *bucket_offset = offset;
+        offset += count;
+    }
+
+    let mut order = vec![0; centers.len()];
+    for (splat_index, &key) in keys.iter().enumerate() {
+        let slot = &mut bucket_offsets[key as usize];
+        order[*slot as usize] = splat_index as u32;
+        *slot += 1;
+    }
+    order
+}
+
+/// The order in which the splats of a [`GaussianSplatCloud`] are drawn, uploaded to the GPU.
+///
+/// Cheap to clone, the texture itself is reference counted.
+#[derive(Clone)]
+pub struct GaussianSplatDrawOrder {
+    texture: GpuTexture,
+    num_splats: u32,
+}
+
+impl GaussianSplatDrawOrder {
+    /// Uploads a list of splat indices, typically from [`GaussianSplatCloud::draw_order_back_to_front`].
+    pub fn new(
+        ctx: &RenderContext,
+        splat_indices: &[u32],
+    ) -> Result<Self, GaussianSplatDrawDataError> {
+        re_tracing::profile_function!();
+
+        let mut indices = DataTextureSource::<u32>::new(ctx);
+        indices.extend_from_slice(splat_indices)?;
+
+        Ok(Self {
+            texture: indices.finish(wgpu::TextureFormat::R32Uint, "splat draw order")?,
+            num_splats: splat_indices.len() as u32,
+        })
+    }
+}
+
+/// A splat cloud placed in the world.
+pub struct GaussianSplats {
+    pub cloud: GaussianSplatCloud,
+
+    /// Order in which splats are drawn, expected to be sorted back-to-front for the current camera.
+    pub draw_order: GaussianSplatDrawOrder,
+
+    pub world_from_obj: glam::Affine3A,
+
+    /// Optional outline mask setting for the entire cloud.
+    pub outline_mask_ids: OutlineMaskPreference,
+
+    /// Picking object id that applies for the entire cloud.
+    pub picking_object_id: PickingLayerObjectId,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum GaussianSplatDrawDataError {
+    #[error(transparent)]
+    DataTextureSourceWriteError(#[from] DataTextureSourceWriteError),
+
+    #[error("Failed to transfer data to the GPU: {0}")]
+    FailedTransferringDataToGpu(#[from] CpuWriteGpuReadError),
+}
+
+#[derive(Clone)]
+struct GaussianSplatDrawInstance {
+    bind_group: GpuBindGroup,
+    num_splats: u32,
+    render_outline_mask: bool,
+}
+
+#[derive(Clone)]
+pub struct GaussianSplatDrawData {
+    instances: Vec<GaussianSplatDrawInstance>,
+}
+
+impl DrawData for GaussianSplatDrawData {
+    type Renderer = GaussianSplatRenderer;
+}
+
+impl GaussianSplatDrawData {
+    pub fn new(ctx: &RenderContext, splats: &[GaussianSplats]) -> Self {
+        re_tracing::profile_function!();
+
+        let renderer = ctx.renderer::<GaussianSplatRenderer>();
+
+        if splats.is_empty() {
+            return Self {
+                instances: Vec::new(),
+            };
+        }
+
+        let ubo_bindings = create_and_fill_uniform_buffer_batch(
+            ctx,
+            "gaussian_splat_ubos".into(),
+            splats.iter().map(|splats| gpu_data::SplatCloudInfoUBO {
+                world_from_obj: splats.world_from_obj.into(),
+                outline_mask_ids: splats.outline_mask_ids.0.unwrap_or_default().into(),
+                picking_object_id: splats.picking_object_id,
+                end_padding: Default::default(),
+            }),
+        );
+
+        let instances = splats
+            .iter()
+            .zip(ubo_bindings)
+            .map(|(splats, ubo)| GaussianSplatDrawInstance {
+                bind_group: ctx.gpu_resources.bind_groups.alloc(
+                    &ctx.device,
+                    &ctx.gpu_resources,
+                    &BindGroupDesc {
+                        label: "gaussian_splats".into(),
+                        entries: smallvec![
+                            ubo,
+                            BindGroupEntry::DefaultTextureView(
+                                splats.cloud.center_opacity_texture.handle
+                            ),
+                            BindGroupEntry::DefaultTextureView(
+                                splats.cloud.rotation_texture.handle
+                            ),
+                            BindGroupEntry::DefaultTextureView(splats.cloud.scale_texture.handle),
+                            BindGroupEntry::DefaultTextureView(splats.cloud.color_texture.handle),
+                            BindGroupEntry::DefaultTextureView(splats.draw_order.texture.handle),
+                        ],
+                        layout: renderer.bind_group_layout,
+                    },
+                ),
+                // An outdated draw order may refer to fewer splats than the cloud has, never more.
+                num_splats: splats.draw_order.num_splats.min(splats.cloud.len() as u32),
+                render_outline_mask: splats.outline_mask_ids.is_some(),
+            })
+            .collect();
+
+        Self { instances }
+    }
+}
+
+pub struct GaussianSplatRenderer {
+    render_pipeline_color: GpuRenderPipelineHandle,
+    render_pipeline_picking_layer: GpuRenderPipelineHandle,
+    render_pipeline_outline_mask: GpuRenderPipelineHandle,
+    bind_group_layout: GpuBindGroupLayoutHandle,
+}
+
+impl Renderer for GaussianSplatRenderer {
+    type RendererDrawData = GaussianSplatDrawData;
+
+    fn participated_phases() -> &'static [DrawPhase] {
+        &[
+            DrawPhase::Transparent,
+            DrawPhase::PickingLayer,
+            DrawPhase::OutlineMask,
+        ]
+    }
+
+    fn create_renderer(ctx: &RenderContext) -> Self {
+        re_tracing::profile_function!();
+
+        let render_pipelines = &ctx.gpu_resources.render_pipelines;
+
+        let data_texture_entry = |binding, sample_type| wgpu::BindGroupLayoutEntry {
+            binding,
+            visibility: wgpu::ShaderStages::VERTEX,
+            ty: wgpu::BindingType::Texture {
+                sample_type,
+                view_dimension: wgpu::TextureViewDimension::D2,
+                multisampled: false,
+            },
+            count: None,
+        };
+        let float_data = wgpu::TextureSampleType::Float { filterable: false };
+
+        let bind_group_layout = ctx.gpu_resources.bind_group_layouts.get_or_create(
+            &ctx.device,
+            &BindGroupLayoutDesc {
+                label: "gaussian_splat_bg_layout".into(),
+                entries: vec![
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: wgpu::ShaderStages::VERTEX | wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Uniform,
+                            has_dynamic_offset: false,
+                            min_binding_size: (std::mem::size_of::<gpu_data::SplatCloudInfoUBO>()
+                                as u64)
+                                .try_into()
+                                .ok(),
+                        },
+                        count: None,
+                    },
+                    data_texture_entry(1, float_data),
+                    data_texture_entry(2, float_data),
+                    data_texture_entry(3, float_data),
+                    data_texture_entry(4, float_data),
+                    data_texture_entry(5, wgpu::TextureSampleType::Uint),
+                ],
+            },
+        );
+
+        let pipeline_layout = ctx.gpu_resources.pipeline_layouts.get_or_create(
+            ctx,
+            &PipelineLayoutDesc {
+                label: "gaussian_splat_rp_layout".into(),
+                entries: vec![ctx.global_bindings.layout, bind_group_layout],
+            },
+        );
+
+        let shader_module = ctx.gpu_resources.shader_modules.get_or_create(
+            ctx,
+            &include_shader_module!("../../shader/gaussian_splat.wgsl"),
+        );
+
+        let render_pipeline_desc_color = RenderPipelineDesc {
+            label: "GaussianSplatRenderer::render_pipeline_color".into(),
+            pipeline_layout,
+            vertex_entrypoint: "vs_main".into(),
+            vertex_handle: shader_module,
+            fragment_entrypoint: "fs_main".into(),
+            fragment_handle: shader_module,
+            vertex_buffers: smallvec![],
+            render_targets: smallvec![Some(wgpu::ColorTargetState {
+                format: ViewBuilder::MAIN_TARGET_COLOR_FORMAT,
+                blend: Some(wgpu::BlendState::PREMULTIPLIED_ALPHA_BLENDING),
+                write_mask: wgpu::ColorWrites::ALL,
+            })],
+            // Quads are spanned in screen space, their winding depends on the splat's orientation.
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleList,
+                cull_mode: None,
+                ..Default::default()
+            },
+            // Splats are sorted, so they have to be tested against, but must not write depth.
+            depth_stencil: Some(wgpu::DepthStencilState {
+                format: ViewBuilder::MAIN_TARGET_DEPTH_FORMAT,
+                depth_compare: wgpu::CompareFunction::GreaterEqual,
+                depth_write_enabled: false,
+                stencil: wgpu::StencilState::default(),
+                bias: wgpu::DepthBiasState::default(),
+            }),
+            multisample: ViewBuilder::main_target_default_msaa_state(ctx.render_config(), false),
+        };
+        let render_pipeline_color =
+            render_pipelines.get_or_create(ctx, &render_pipeline_desc_color);
+        let render_pipeline_picking_layer = render_pipelines.get_or_create(
+            ctx,
+            &RenderPipelineDesc {
+                label: "GaussianSplatRenderer::render_pipeline_picking_layer".into(),
+                fragment_entrypoint: "fs_main_picking_layer".into(),
+                render_targets: smallvec![Some(PickingLayerProcessor::PICKING_LAYER_FORMAT.into())],
+                depth_stencil: PickingLayerProcessor::PICKING_LAYER_DEPTH_STATE,
+                multisample: PickingLayerProcessor::PICKING_LAYER_MSAA_STATE,
+                ..render_pipeline_desc_color.clone()
+            },
+        );
+        let render_pipeline_outline_mask = render_pipelines.get_or_create(
+            ctx,
+            &RenderPipelineDesc {
+                label: "GaussianSplatRenderer::render_pipeline_outline_mask".into(),
+                fragment_entrypoint: "fs_main_outline_mask".into(),
+                render_targets: smallvec![Some(OutlineMaskProcessor::MASK_FORMAT.into())],
+                depth_stencil: OutlineMaskProcessor::MASK_DEPTH_STATE,
+                multisample: OutlineMaskProcessor::mask_default_msaa_state(ctx.device_caps().tier),
+                ..render_pipeline_desc_color
+            },
+        );
+
+        Self {
+            render_pipeline_color,
+            render_pipeline_picking_layer,
+            render_pipeline_outline_mask,
+            bind_group_layout,
+        }
+    }
+
+    fn draw(
+        &self,
+        render_pipelines: &GpuRenderPipelinePoolAccessor<'_>,
+        phase: DrawPhase,
+        pass: &mut wgpu::RenderPass<'_>,
+        draw_data: &Self::RendererDrawData,
+    ) -> Result<(), DrawError> {
+        re_tracing::profile_function!();
+        if draw_data.instances.is_empty() {
+            return Ok(());
+        }
+
+        let pipeline_handle = match phase {
+            DrawPhase::Transparent => self.render_pipeline_color,
+            DrawPhase::PickingLayer => self.render_pipeline_picking_layer,
+            DrawPhase::OutlineMask => self.render_pipeline_outline_mask,
+            _ => unreachable!("We were called on a phase we weren't subscribed to: {phase:?}"),
+        };
+        let pipeline = render_pipelines.get(pipeline_handle)?;
+
+        pass.set_pipeline(pipeline);
+
+        for instance in &draw_data.instances {
+            if phase == DrawPhase::OutlineMask && !instance.render_outline_mask {
+                continue;
+            }
+
+            pass.set_bind_group(1, &instance.bind_group, &[]);
+
+            // Two triangles per splat.
+            pass.draw(0..instance.num_splats * 6, 0..1);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use glam::Vec3;
+
+    use super::sort_back_to_front;
+
+    #[test]
+    fn splats_are_sorted_back_to_front() {
+        let centers = [
+            Vec3::new(0.0, 0.0, -2.0),
+            Vec3::new(1.0, 0.0, -10.0),
+            Vec3::new(0.0, 5.0, -5.0),
+            Vec3::new(0.0, 0.0, 3.0), // Behind the eye.
+        ];
+
+        let order = sort_back_to_front(&centers, Vec3::ZERO, -Vec3::Z);
+        assert_eq!(order, vec![1, 2, 0, 3]);
+
+        // Looking the other way around reverses the order.
+        let order = sort_back_to_front(&centers, Vec3::ZERO, Vec3::Z);
+        assert_eq!(order, vec![3, 0, 2, 1]);
+    }
+
+    #[test]
+    fn sorting_handles_degenerate_input() {
+        assert!(sort_back_to_front(&[], Vec3::ZERO, -Vec3::Z).is_empty());
+
+        let same_depth = [Vec3::ONE; 3];
+        let mut order = sort_back_to_front(&same_depth, Vec3::ZERO, -Vec3::Z);
+        order.sort_unstable();
+        assert_eq!(order, vec![0, 1, 2]);
+    }
+}
@@ -34,6 +34,17 @@ pub use debug_overlay::{DebugOverlayDrawData, DebugOverlayError, DebugOverlayRen
 mod world_grid;
 pub use world_grid::{WorldGridConfiguration, WorldGridDrawData, WorldGridRenderer};
 
+mod volumes;
+pub use volumes::{
+    Volume, VolumeDrawData, VolumeDrawDataError, VolumeRenderMode, VolumeRenderer, VolumeTexture,
+};
+
+mod gaussian_splats;
+pub use gaussian_splats::{
+    GaussianSplat, GaussianSplatCloud, GaussianSplatDrawData, GaussianSplatDrawDataError,
+    GaussianSplatDrawOrder, GaussianSplatRenderer, GaussianSplats,
+};
+
 pub mod gpu_data {
     pub use super::lines::gpu_data::{LineStripInfo, LineVertex};
     pub use super::point_cloud::gpu_data::PositionRadius;
@@ -0,0 +1,508 @@
+//! Renderer for scalar volumes, e.g. occupancy grids or CT scans.
+//!
+//! ## Implementation details
+//!
+//! Each volume is a single channel float 3D texture.
+//! We rasterize the back faces of the volume's bounding box and march a ray through the texture
+//! for every fragment, from where the camera ray enters the box up to the rasterized back face.
+//!
+//! There are two modes:
+//! * [`VolumeRenderMode::Accumulate`] integrates color & opacity along the ray using an emission-absorption model.
+//!   This happens in the transparent phase, i.e. it is blended on top of everything opaque.
+//!   Depth is tested against the first non-transparent sample along the ray.
+//! * [`VolumeRenderMode::IsoSurface`] renders the surface at which the volume reaches a given value.
+//!   This happens in the opaque phase and writes the depth of the surface.
+//!
+//! Picking & outlines treat a ray as hitting the volume if it either hits the iso-surface
+//! or accumulates at least half opacity. The picked instance id is the linear index of the voxel that was hit.
+
+use smallvec::smallvec;
+
+use crate::{
+    Colormap, OutlineMaskPreference, PickingLayerObjectId, PickingLayerProcessor,
+    allocator::create_and_fill_uniform_buffer_batch,
+    draw_phases::{DrawPhase, OutlineMaskProcessor},
+    include_shader_module,
+    view_builder::ViewBuilder,
+    wgpu_resources::{
+        BindGroupDesc, BindGroupEntry, BindGroupLayoutDesc, GpuBindGroup, GpuBindGroupLayoutHandle,
+        GpuRenderPipelineHandle, GpuRenderPipelinePoolAccessor, GpuTexture, PipelineLayoutDesc,
+        RenderPipelineDesc, TextureDesc,
+    },
+};
+
+use super::{DrawData, DrawError, RenderContext, Renderer};
+
+// ---
+
+mod gpu_data {
+    use crate::{PickingLayerObjectId, wgpu_buffer_types};
+
+    // Keep in sync with mirror in `volume.wgsl.`
+
+    const MODE_ACCUMULATE: u32 = 0;
+    const MODE_ISO_SURFACE: u32 = 1;
+
+    #[repr(C)]
+    #[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+    pub struct VolumeInfoUBO {
+        pub world_from_volume: wgpu_buffer_types::Mat4,
+        pub volume_from_world: wgpu_buffer_types::Mat4,
+
+        pub dimensions: [u32; 3],
+
+        /// One of `MODE_*`.
+        pub mode: u32,
+
+        pub outline_mask_id: wgpu_buffer_types::UVec2,
+        pub picking_layer_object_id: PickingLayerObjectId,
+
+        // ---
+        pub value_range: [f32; 2],
+        pub iso_value: f32,
+        pub opacity: f32,
+
+        // ---
+        pub colormap: u32,
+        pub samples_per_voxel: f32,
+        pub _row_padding: [f32; 2],
+
+        // ---
+        pub _end_padding: [wgpu_buffer_types::PaddingRow; 16 - 4 - 4 - 1 - 1 - 1 - 1],
+    }
+
+    impl VolumeInfoUBO {
+        pub fn from_volume(volume: &super::Volume) -> Self {
+            let super::Volume {
+                world_from_volume,
+                texture,
+                value_range,
+                colormap,
+                mode,
+                samples_per_voxel,
+                outline_mask_id,
+                picking_object_id,
+            } = volume;
+
+            let (mode, iso_value, opacity) = match *mode {
+                super::VolumeRenderMode::Accumulate { opacity } => (MODE_ACCUMULATE, 0.0, opacity),
+                super::VolumeRenderMode::IsoSurface { iso_value } => {
+                    (MODE_ISO_SURFACE, iso_value, 1.0)
+                }
+            };
+
+            let world_from_volume_mat = glam::Mat4::from(*world_from_volume);
+
+            Self {
+                world_from_volume: world_from_volume_mat.into(),
+                volume_from_world: world_from_volume_mat.inverse().into(),
+                dimensions: texture.dimensions.to_array(),
+                mode,
+                outline_mask_id: outline_mask_id.0.unwrap_or_default().into(),
+                picking_layer_object_id: *picking_object_id,
+                value_range: *value_range,
+                iso_value,
+                opacity,
+                colormap: *colormap as u32,
+                samples_per_voxel: *samples_per_voxel,
+                _row_padding: Default::default(),
+                _end_padding: Default::default(),
+            }
+        }
+    }
+}
+
+/// A scalar volume uploaded to the GPU.
+///
+/// Cheap to clone, the texture itself is reference counted.
+#[derive(Clone)]
+pub struct VolumeTexture {
+    texture: GpuTexture,
+    dimensions: glam::UVec3,
+}
+
+impl VolumeTexture {
+    /// Uploads a dense volume of scalar values.
+    ///
+    /// Values are laid out with x varying fastest, then y, then z.
+    pub fn new(
+        ctx: &RenderContext,
+        label: &str,
+        dimensions: glam::UVec3,
+        values: &[f32],
+    ) -> Result<Self, VolumeDrawDataError> {
+        re_tracing::profile_function!();
+
+        let num_voxels = dimensions.x as usize * dimensions.y as usize * dimensions.z as usize;
+        if num_voxels == 0 || values.len() != num_voxels {
+            return Err(VolumeDrawDataError::InvalidDataLength {
+                dimensions,
+                num_values: values.len(),
+            });
+        }
+
+        let max_dimension = ctx.device.limits().max_texture_dimension_3d;
+        if dimensions.max_element() > max_dimension {
+            return Err(VolumeDrawDataError::TooLarge {
+                dimensions,
+                max_dimension,
+            });
+        }
+
+        let size = wgpu::Extent3d {
+            width: dimensions.x,
+            height: dimensions.y,
+            depth_or_array_layers: dimensions.z,
+        };
+        let texture = ctx.gpu_resources.textures.alloc(
+            &ctx.device,
+            &TextureDesc {
+                label: label.into(),
+                size,
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: wgpu::TextureDimension::D3,
+                format: wgpu::TextureFormat::R32Float,
+                usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+            },
+        );
+
+        ctx.queue.write_texture(
+            wgpu::TexelCopyTextureInfo {
+                texture: &texture.texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d::ZERO,
+                aspect: wgpu::TextureAspect::All,
+            },
+            bytemuck::cast_slice(values),
+            wgpu::TexelCopyBufferLayout {
+                offset: 0,
+                bytes_per_row: Some(dimensions.x * std::mem::size_of::<f32>() as u32),
+                rows_per_image: Some(dimensions.y),
+            },
+            size,
+        );
+
+        Ok(Self {
+            texture,
+            dimensions,
+        })
+    }
+
+    /// Number of voxels along each axis.
+    pub fn dimensions(&self) -> glam::UVec3 {
+        self.dimensions
+    }
+}
+
+/// How the values of a [`Volume`] are turned into pixels.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum VolumeRenderMode {
+    /// Integrate color & opacity along each ray.
+    ///
+    /// Each sample is colored by the colormap and its opacity grows linearly with its normalized value,
+    /// i.e. values at or below the lower end of the value range are fully transparent.
+    Accumulate {
+        /// Opacity of a single voxel with a value at the upper end of the value range.
+        opacity: f32,
+    },
+
+    /// Render the surface where the volume first reaches the given value.
+    IsoSurface { iso_value: f32 },
+}
+
+pub struct Volume {
+    /// Transforms the unit cube `[0, 1]³` to world space.
+    ///
+    /// The volume's texture is stretched across the entire unit cube.
+    pub world_from_volume: glam::Affine3A,
+
+    /// The scalar values of the volume.
+    pub texture: VolumeTexture,
+
+    /// Values in this range are mapped to the full colormap.
+    pub value_range: [f32; 2],
+
+    /// Colormap used for both modes.
+    pub colormap: Colormap,
+
+    pub mode: VolumeRenderMode,
+
+    /// How many samples are taken per voxel along a ray.
+    ///
+    /// Rays are capped to a fixed number of samples, so large volumes may be sampled more coarsely.
+    pub samples_per_voxel: f32,
+
+    /// Option outline mask id preference.
+    pub outline_mask_id: OutlineMaskPreference,
+
+    /// Picking object id that applies for the entire volume.
+    pub picking_object_id: PickingLayerObjectId,
+}
+
+impl Volume {
+    /// World-space bounding-box.
+    pub fn world_space_bbox(&self) -> macaw::BoundingBox {
+        macaw::BoundingBox::from_min_max(glam::Vec3::ZERO, glam::Vec3::ONE)
+            .transform_affine3(&self.world_from_volume)
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum VolumeDrawDataError {
+    #[error("Got {num_values} values, which doesn't match a volume of size {dimensions}")]
+    InvalidDataLength {
+        dimensions: glam::UVec3,
+        num_values: usize,
+    },
+
+    #[error("Volume of size {dimensions} exceeds the maximum 3D texture size of {max_dimension}")]
+    TooLarge {
+        dimensions: glam::UVec3,
+        max_dimension: u32,
+    },
+}
+
+#[derive(Clone)]
+struct VolumeDrawInstance {
+    bind_group: GpuBindGroup,
+    is_iso_surface: bool,
+    render_outline_mask: bool,
+}
+
+#[derive(Clone)]
+pub struct VolumeDrawData {
+    instances: Vec<VolumeDrawInstance>,
+}
+
+impl DrawData for VolumeDrawData {
+    type Renderer = VolumeRenderer;
+}
+
+impl VolumeDrawData {
+    pub fn new(ctx: &RenderContext, volumes: &[Volume]) -> Self {
+        re_tracing::profile_function!();
+
+        let renderer = ctx.renderer::<VolumeRenderer>();
+
+        if volumes.is_empty() {
+            return Self {
+                instances: Vec::new(),
+            };
+        }
+
+        let ubo_bindings = create_and_fill_uniform_buffer_batch(
+            ctx,
+            "volume_ubos".into(),
+            volumes.iter().map(gpu_data::VolumeInfoUBO::from_volume),
+        );
+
+        let instances = volumes
+            .iter()
+            .zip(ubo_bindings)
+            .map(|(volume, ubo)| VolumeDrawInstance {
+                bind_group: ctx.gpu_resources.bind_groups.alloc(
+                    &ctx.device,
+                    &ctx.gpu_resources,
+                    &BindGroupDesc {
+                        label: "volume".into(),
+                        entries: smallvec![
+                            ubo,
+                            BindGroupEntry::DefaultTextureView(volume.texture.texture.handle),
+                        ],
+                        layout: renderer.bind_group_layout,
+                    },
+                ),
+                is_iso_surface: matches!(volume.mode, VolumeRenderMode::IsoSurface { .. }),
+                render_outline_mask: volume.outline_mask_id.is_some(),
+            })
+            .collect();
+
+        Self { instances }
+    }
+}
+
+pub struct VolumeRenderer {
+    render_pipeline_iso_surface: GpuRenderPipelineHandle,
+    render_pipeline_accumulate: GpuRenderPipelineHandle,
+    render_pipeline_picking_layer: GpuRenderPipelineHandle,
+    render_pipeline_outline_mask: GpuRenderPipelineHandle,
+    bind_group_layout: GpuBindGroupLayoutHandle,
+}
+
+impl Renderer for VolumeRenderer {
+    type RendererDrawData = VolumeDrawData;
+
+    fn participated_phases() -> &'static [DrawPhase] {
+        &[
+            DrawPhase::Opaque,
+            DrawPhase::Transparent,
+            DrawPhase::PickingLayer,
+            DrawPhase::OutlineMask,
+        ]
+    }
+
+    fn create_renderer(ctx: &RenderContext) -> Self {
+        re_tracing::profile_function!();
+
+        let render_pipelines = &ctx.gpu_resources.render_pipelines;
+
+        let bind_group_layout = ctx.gpu_resources.bind_group_layouts.get_or_create(
+            &ctx.device,
+            &BindGroupLayoutDesc {
+                label: "volume_bg_layout".into(),
+                entries: vec![
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: wgpu::ShaderStages::VERTEX | wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Uniform,
+                            has_dynamic_offset: false,
+                            min_binding_size: (std::mem::size_of::<gpu_data::VolumeInfoUBO>()
+                                as u64)
+                                .try_into()
+                                .ok(),
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Texture {
+                            sample_type: wgpu::TextureSampleType::Float { filterable: false },
+                            view_dimension: wgpu::TextureViewDimension::D3,
+                            multisampled: false,
+                        },
+                        count: None,
+                    },
+                ],
+            },
+        );
+
+        let pipeline_layout = ctx.gpu_resources.pipeline_layouts.get_or_create(
+            ctx,
+            &PipelineLayoutDesc {
+                label: "volume_rp_layout".into(),
+                entries: vec![ctx.global_bindings.layout, bind_group_layout],
+            },
+        );
+
+        let shader_module = ctx
+            .gpu_resources
+            .shader_modules
+            .get_or_create(ctx, &include_shader_module!("../../shader/volume.wgsl"));
+
+        let render_pipeline_desc_iso_surface = RenderPipelineDesc {
+            label: "VolumeRenderer::render_pipeline_iso_surface".into(),
+            pipeline_layout,
+            vertex_entrypoint: "vs_main".into(),
+            vertex_handle: shader_module,
+            fragment_entrypoint: "fs_main".into(),
+            fragment_handle: shader_module,
+            vertex_buffers: smallvec![],
+            render_targets: smallvec![Some(ViewBuilder::MAIN_TARGET_COLOR_FORMAT.into())],
+            // The shader discards all front faces, so we don't rely on any specific winding order.
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleList,
+                cull_mode: None,
+                ..Default::default()
+            },
+            depth_stencil: ViewBuilder::MAIN_TARGET_DEFAULT_DEPTH_STATE,
+            multisample: ViewBuilder::main_target_default_msaa_state(ctx.render_config(), false),
+        };
+        let render_pipeline_iso_surface =
+            render_pipelines.get_or_create(ctx, &render_pipeline_desc_iso_surface);
+        let render_pipeline_accumulate = render_pipelines.get_or_create(
+            ctx,
+            &RenderPipelineDesc {
+                label: "VolumeRenderer::render_pipeline_accumulate".into(),
+                render_targets: smallvec![Some(wgpu::ColorTargetState {
+                    format: ViewBuilder::MAIN_TARGET_COLOR_FORMAT,
+                    blend: Some(wgpu::BlendState::PREMULTIPLIED_ALPHA_BLENDING),
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                depth_stencil: Some(wgpu::DepthStencilState {
+                    format: ViewBuilder::MAIN_TARGET_DEPTH_FORMAT,
+                    depth_compare: wgpu::CompareFunction::GreaterEqual,
+                    depth_write_enabled: false,
+                    stencil: wgpu::StencilState::default(),
+                    bias: wgpu::DepthBiasState::default(),
+                }),
+                ..render_pipeline_desc_iso_surface.clone()
+            },
+        );
+        let render_pipeline_picking_layer = render_pipelines.get_or_create(
+            ctx,
+            &RenderPipelineDesc {
+                label: "VolumeRenderer::render_pipeline_picking_layer".into(),
+                fragment_entrypoint: "fs_main_picking_layer".into(),
+                render_targets: smallvec![Some(PickingLayerProcessor::PICKING_LAYER_FORMAT.into())],
+                depth_stencil: PickingLayerProcessor::PICKING_LAYER_DEPTH_STATE,
+                multisample: PickingLayerProcessor::PICKING_LAYER_MSAA_STATE,
+                ..render_pipeline_desc_iso_surface.clone()
+            },
+        );
+        let render_pipeline_outline_mask = render_pipelines.get_or_create(
+            ctx,
+            &RenderPipelineDesc {
+                label: "VolumeRenderer::render_pipeline_outline_mask".into(),
+                fragment_entrypoint: "fs_main_outline_mask".into(),
+                render_targets: smallvec![Some(OutlineMaskProcessor::MASK_FORMAT.into())],
+                depth_stencil: OutlineMaskProcessor::MASK_DEPTH_STATE,
+                multisample: OutlineMaskProcessor::mask_default_msaa_state(ctx.device_caps().tier),
+                ..render_pipeline_desc_iso_surface
+            },
+        );
+
+        Self {
+            render_pipeline_iso_surface,
+            render_pipeline_accumulate,
+            render_pipeline_picking_layer,
+            render_pipeline_outline_mask,
+            bind_group_layout,
+        }
+    }
+
+    fn draw(
+        &self,
+        render_pipelines: &GpuRenderPipelinePoolAccessor<'_>,
+        phase: DrawPhase,
+        pass: &mut wgpu::RenderPass<'_>,
+        draw_data: &Self::RendererDrawData,
+    ) -> Result<(), DrawError> {
+        re_tracing::profile_function!();
+        if draw_data.instances.is_empty() {
+            return Ok(());
+        }
+
+        let pipeline_handle = match phase {
+            DrawPhase::Opaque => self.render_pipeline_iso_surface,
+            DrawPhase::Transparent => self.render_pipeline_accumulate,
+            DrawPhase::PickingLayer => self.render_pipeline_picking_layer,
+            DrawPhase::OutlineMask => self.render_pipeline_outline_mask,
+            _ => unreachable!("We were called on a phase we weren't subscribed to: {phase:?}"),
+        };
+        let pipeline = render_pipelines.get(pipeline_handle)?;
+
+        pass.set_pipeline(pipeline);
+
+        for instance in &draw_data.instances {
+            let participates = match phase {
+                DrawPhase::Opaque => instance.is_iso_surface,
+                DrawPhase::Transparent => !instance.is_iso_surface,
+                DrawPhase::OutlineMask => instance.render_outline_mask,
+                _ => true,
+            };
+            if !participates {
+                continue;
+            }
+
+            pass.set_bind_group(1, &instance.bind_group, &[]);
+
+            // Two triangles for each of the six faces of the unit cube.
+            pass.draw(0..36, 0..1);
+        }
+
+        Ok(())
+    }
+}
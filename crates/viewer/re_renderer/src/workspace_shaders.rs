@@ -49,6 +49,12 @@ pub fn init() {
         fs.create_file(virtpath, content).unwrap();
     }
 
+    {
+        let virtpath = Path::new("shader/gaussian_splat.wgsl");
+        let content = include_str!("../shader/gaussian_splat.wgsl").into();
+        fs.create_file(virtpath, content).unwrap();
+    }
+
     {
         let virtpath = Path::new("shader/generic_skybox.wgsl");
         let content = include_str!("../shader/generic_skybox.wgsl").into();
@@ -211,6 +217,12 @@ pub fn init() {
         fs.create_file(virtpath, content).unwrap();
     }
 
+    {
+        let virtpath = Path::new("shader/volume.wgsl");
+        let content = include_str!("../shader/volume.wgsl").into();
+        fs.create_file(virtpath, content).unwrap();
+    }
+
     {
         let virtpath = Path::new("shader/world_grid.wgsl");
         let content = include_str!("../shader/world_grid.wgsl").into();
@@ -4,6 +4,7 @@ mod defaults_ui;
 mod item_heading_no_breadcrumbs;
 mod item_heading_with_breadcrumbs;
 mod item_title;
+mod recording_comparison_ui;
 mod selection_panel;
 mod view_entity_picker;
 mod view_space_origin_ui;
@@ -0,0 +1,82 @@
+use re_log_types::{StoreId, TimeType};
+use re_ui::{UiExt as _, list_item::PropertyContent};
+use re_viewer_context::{
+    RecordingComparison, SystemCommand, SystemCommandSender as _, ViewerContext,
+};
+
+/// Lets the user compare the selected recording with the active one, and line the two up.
+pub fn recording_comparison_ui(ctx: &ViewerContext<'_>, ui: &mut egui::Ui, store_id: &StoreId) {
+    let current = ctx
+        .storage_context
+        .hub
+        .recording_comparison()
+        .filter(|comparison| &comparison.store_id == store_id);
+
+    let mut is_compared = current.is_some();
+    if ui
+        .re_checkbox(&mut is_compared, "Compare with the active recording")
+        .on_hover_text(
+            "Show this recording's data on top of the active recording's in time series, \
+            spatial and text log views",
+        )
+        .changed()
+    {
+        let comparison = is_compared.then(|| RecordingComparison::new(store_id.clone()));
+        ctx.command_sender()
+            .send_system(SystemCommand::SetRecordingComparison(comparison));
+    }
+
+    let Some(current) = current else {
+        return;
+    };
+    let mut comparison = current.clone();
+
+    let timeline = *ctx.rec_cfg.time_ctrl.read().timeline();
+    ui.list_item_flat_noninteractive(
+        PropertyContent::new("Time offset")
+            .value_fn(|ui, _| {
+                let mut offset = comparison.time_offset(timeline.name());
+                let response = match timeline.typ() {
+                    TimeType::Sequence => ui.add(egui::DragValue::new(&mut offset)),
+                    TimeType::DurationNs | TimeType::TimestampNs => {
+                        let mut seconds = offset as f64 * 1e-9;
+                        let response =
+                            ui.add(egui::DragValue::new(&mut seconds).speed(0.01).suffix("s"));
+                        offset = (seconds * 1e9).round() as i64;
+                        response
+                    }
+                };
+                if response.changed() {
+                    comparison.time_offsets.insert(*timeline.name(), offset);
+                }
+            })
+            .min_desired_width(150.0),
+    )
+    .on_hover_text(format!(
+        "How much later this recording's data shows up on the '{}' timeline",
+        timeline.name()
+    ));
+
+    ui.list_item_flat_noninteractive(PropertyContent::new("Opacity").value_fn(|ui, _| {
+        ui.add(egui::Slider::new(&mut comparison.opacity, 0.0..=1.0));
+    }));
+
+    ui.list_item_flat_noninteractive(PropertyContent::new("Tint").value_fn(|ui, _| {
+        let mut is_tinted = comparison.tint.is_some();
+        if ui.re_checkbox(&mut is_tinted, "").changed() {
+            comparison.tint = is_tinted.then(|| ui.visuals().warn_fg_color);
+        }
+        if let Some(tint) = &mut comparison.tint {
+            egui::color_picker::color_edit_button_srgba(
+                ui,
+                tint,
+                egui::color_picker::Alpha::Opaque,
+            );
+        }
+    }));
+
+    if &comparison != current {
+        ctx.command_sender()
+            .send_system(SystemCommand::SetRecordingComparison(Some(comparison)));
+    }
+}
@@ -23,6 +23,7 @@ use crate::{
     defaults_ui::view_components_defaults_section_ui,
     item_heading_no_breadcrumbs::item_title_list_item,
     item_heading_with_breadcrumbs::item_heading_with_breadcrumbs,
+    recording_comparison_ui::recording_comparison_ui,
     view_entity_picker::ViewEntityPicker,
     visible_time_range_ui::{
         visible_time_range_ui_for_data_result, visible_time_range_ui_for_view,
@@ -351,10 +352,18 @@ impl SelectionPanel {
         }
 
         match item {
-            Item::StoreId(_) => {
+            Item::StoreId(store_id) => {
                 ui.section_collapsing_header("Properties").show(ui, |ui| {
                     show_recording_properties(ctx, db, &query, ui, ui_layout);
                 });
+
+                let is_other_recording = store_id.kind == re_log_types::StoreKind::Recording
+                    && ctx.storage_context.hub.active_recording_id() != Some(store_id);
+                if is_other_recording {
+                    ui.section_collapsing_header("Comparison").show(ui, |ui| {
+                        recording_comparison_ui(ctx, ui, store_id);
+                    });
+                }
             }
 
             Item::View(view_id) => {
@@ -0,0 +1,248 @@
+use std::sync::Arc;
+
+use egui::ahash::HashMap;
+use nohash_hasher::IntMap;
+use once_cell::sync::OnceCell;
+
+use re_chunk_store::{
+    Chunk, ChunkId, ChunkStore, ChunkStoreEvent, ChunkStoreSubscriberHandle,
+    PerStoreChunkSubscriber, RowId,
+};
+use re_log_types::{EntityPath, StoreId, TimeInt, TimelineName};
+use re_types::{archetypes::TextLog, components::TextLogLevel};
+
+/// How important an [`EventMarker`] is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum EventSeverity {
+    Warning,
+    Error,
+}
+
+impl EventSeverity {
+    /// The severity of a [`TextLog`] entry with the given level, if it is worth a marker.
+    pub fn from_level(level: &str) -> Option<Self> {
+        match level {
+            TextLogLevel::WARN => Some(Self::Warning),
+            TextLogLevel::ERROR | TextLogLevel::CRITICAL => Some(Self::Error),
+            _ => None,
+        }
+    }
+}
+
+/// A point in time derived from the data that deserves attention, e.g. a logged warning.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EventMarker {
+    pub time: TimeInt,
+    pub severity: EventSeverity,
+    pub entity_path: EntityPath,
+    pub text: String,
+}
+
+/// Keeps track of all [`EventMarker`]s in a store, for each timeline.
+///
+/// Markers are derived from [`TextLog`] entries at warning level or above.
+#[derive(Default)]
+pub struct EventMarkersStoreSubscriber {
+    markers_per_chunk: HashMap<ChunkId, IntMap<TimelineName, Vec<EventMarker>>>,
+}
+
+impl EventMarkersStoreSubscriber {
+    pub fn ensure_registered() {
+        Self::subscription_handle();
+    }
+
+    /// Accesses the global store subscriber.
+    ///
+    /// Lazily registers the subscriber if it hasn't been registered yet.
+    pub fn subscription_handle() -> ChunkStoreSubscriberHandle {
+        static SUBSCRIPTION: OnceCell<ChunkStoreSubscriberHandle> = OnceCell::new();
+        *SUBSCRIPTION.get_or_init(ChunkStore::register_per_store_subscriber::<Self>)
+    }
+
+    /// Accesses the event markers of a given store.
+    #[inline]
+    pub fn access<T>(store_id: &StoreId, f: impl FnOnce(&Self) -> T) -> Option<T> {
+        ChunkStore::with_per_store_subscriber_once(Self::subscription_handle(), store_id, f)
+    }
+
+    /// All markers on the given timeline, sorted by time.
+    pub fn markers(&self, timeline: &TimelineName) -> Vec<&EventMarker> {
+        let mut markers: Vec<&EventMarker> = self
+            .markers_per_chunk
+            .values()
+            .filter_map(|markers_per_timeline| markers_per_timeline.get(timeline))
+            .flatten()
+            .collect();
+        markers.sort_by_key(|marker| (marker.time, marker.severity));
+        markers
+    }
+
+    fn add_chunk(&mut self, chunk: &Arc<Chunk>) {
+        let level_descr = TextLog::descriptor_level();
+        if chunk.is_static() || !chunk.components().contains_key(&level_descr) {
+            return;
+        }
+
+        re_tracing::profile_function!();
+
+        let text_descr = TextLog::descriptor_text();
+
+        let mut markers_per_timeline = IntMap::<TimelineName, Vec<EventMarker>>::default();
+
+        for timeline in chunk.timelines().keys() {
+            let texts: HashMap<RowId, String> = itertools::izip!(
+                chunk.iter_component_indices(timeline, &text_descr),
+                chunk.iter_slices::<String>(text_descr.clone())
+            )
+            .filter_map(|((_time, row_id), texts)| {
+                texts.first().map(|text| (row_id, text.to_string()))
+            })
+            .collect();
+
+            let markers: Vec<EventMarker> = itertools::izip!(
+                chunk.iter_component_indices(timeline, &level_descr),
+                chunk.iter_slices::<String>(level_descr.clone())
+            )
+            .filter_map(|((time, row_id), levels)| {
+                let severity = EventSeverity::from_level(levels.first()?.as_str())?;
+                Some(EventMarker {
+                    time,
+                    severity,
+                    entity_path: chunk.entity_path().clone(),
+                    text: texts.get(&row_id).cloned().unwrap_or_default(),
+                })
+            })
+            .collect();
+
+            if !markers.is_empty() {
+                markers_per_timeline.insert(*timeline, markers);
+            }
+        }
+
+        if !markers_per_timeline.is_empty() {
+            self.markers_per_chunk
+                .insert(chunk.id(), markers_per_timeline);
+        }
+    }
+
+    fn remove_chunk(&mut self, chunk: &Chunk) {
+        self.markers_per_chunk.remove(&chunk.id());
+    }
+}
+
+impl PerStoreChunkSubscriber for EventMarkersStoreSubscriber {
+    #[inline]
+    fn name() -> String {
+        "rerun.store_subscriber.EventMarkers".into()
+    }
+
+    #[inline]
+    fn on_events<'a>(&mut self, events: impl Iterator<Item = &'a ChunkStoreEvent>) {
+        re_tracing::profile_function!();
+
+        for event in events {
+            if let Some(re_chunk_store::ChunkCompactionReport {
+                srcs: compacted_chunks,
+                new_chunk,
+            }) = &event.diff.compacted
+            {
+                for removed_chunk in compacted_chunks.values() {
+                    self.remove_chunk(removed_chunk);
+                }
+                self.add_chunk(new_chunk);
+            } else {
+                match event.diff.kind {
+                    re_chunk_store::ChunkStoreDiffKind::Addition => {
+                        self.add_chunk(&event.chunk);
+                    }
+                    re_chunk_store::ChunkStoreDiffKind::Deletion => {
+                        self.remove_chunk(&event.chunk);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use re_chunk_store::{Chunk, ChunkStore, ChunkStoreConfig, RowId};
+    use re_log_types::{StoreId, TimeInt, Timeline};
+    use re_types::{archetypes::TextLog, components::TextLogLevel};
+
+    use super::{EventMarkersStoreSubscriber, EventSeverity};
+
+    #[test]
+    fn event_markers_from_text_logs() -> anyhow::Result<()> {
+        let mut store = ChunkStore::new(
+            StoreId::random(re_log_types::StoreKind::Recording),
+            ChunkStoreConfig::COMPACTION_DISABLED,
+        );
+        // Initialize the store subscriber. Need to do this ahead of time, otherwise it will miss on events.
+        let _subscriber = EventMarkersStoreSubscriber::subscription_handle();
+
+        let frame = Timeline::new_sequence("frame");
+
+        store.insert_chunk(&Arc::new(
+            Chunk::builder("logs".into())
+                .with_archetype(
+                    RowId::new(),
+                    [(frame, 3)],
+                    &TextLog::new("overheating").with_level(TextLogLevel::WARN),
+                )
+                .with_archetype(
+                    RowId::new(),
+                    [(frame, 1)],
+                    &TextLog::new("all good").with_level(TextLogLevel::INFO),
+                )
+                .with_archetype(
+                    RowId::new(),
+                    [(frame, 2)],
+                    &TextLog::new("motor failure").with_level(TextLogLevel::CRITICAL),
+                )
+                .build()?,
+        ))?;
+
+        // Entries without a level never become markers.
+        store.insert_chunk(&Arc::new(
+            Chunk::builder("logs/unleveled".into())
+                .with_archetype(RowId::new(), [(frame, 4)], &TextLog::new("hello"))
+                .build()?,
+        ))?;
+
+        let markers = EventMarkersStoreSubscriber::access(&store.id(), |subs| {
+            subs.markers(frame.name())
+                .into_iter()
+                .map(|marker| (marker.time, marker.severity, marker.text.clone()))
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+
+        assert_eq!(
+            markers,
+            vec![
+                (
+                    TimeInt::new_temporal(2),
+                    EventSeverity::Error,
+                    "motor failure".to_owned()
+                ),
+                (
+                    TimeInt::new_temporal(3),
+                    EventSeverity::Warning,
+                    "overheating".to_owned()
+                ),
+            ]
+        );
+
+        assert_eq!(
+            EventMarkersStoreSubscriber::access(&store.id(), |subs| subs
+                .markers(&"other".into())
+                .len()),
+            Some(0)
+        );
+
+        Ok(())
+    }
+}
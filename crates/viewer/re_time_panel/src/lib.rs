@@ -4,11 +4,13 @@
 //! timelines, as well as all necessary ui elements that make it up.
 
 mod data_density_graph;
+mod event_markers_subscriber;
 mod paint_ticks;
 mod recursive_chunks_per_timeline_subscriber;
 mod streams_tree_data;
 mod time_axis;
 mod time_control_ui;
+mod time_markers;
 mod time_panel;
 mod time_ranges_ui;
 mod time_selection_ui;
@@ -0,0 +1,404 @@
+//! Bookmarks and event markers on the time axis.
+//!
+//! Bookmarks are named moments that the user adds from the time panel. They are stored in the
+//! blueprint, so they survive restarts and can be shared along with it.
+//! Event markers are derived from the data instead, see [`EventMarkersStoreSubscriber`].
+
+use egui::{Rect, Sense, Stroke};
+use itertools::Itertools as _;
+
+use re_entity_db::EntityDb;
+use re_log_types::{EntityPath, TimeInt, TimeReal, TimelineName};
+use re_types::blueprint::archetypes::TimelineBookmarks;
+use re_types::blueprint::components::{BookmarkTime, TimelineName as TimelineNameComponent};
+use re_types::components::Name;
+use re_viewer_context::{TimeControl, ViewerContext};
+
+use crate::event_markers_subscriber::{EventMarker, EventMarkersStoreSubscriber, EventSeverity};
+use crate::time_panel::time_drag_id;
+use crate::time_ranges_ui::TimeRangesUi;
+
+/// Blueprint entity under which the bookmarks are stored.
+const BOOKMARKS_PATH: &str = "time_panel/bookmarks";
+
+/// Markers closer than this (in ui points) are drawn and hovered as one.
+const MARKER_HOVER_RADIUS: f32 = 4.0;
+
+/// Maximum number of events listed in the tooltip of an event marker.
+const MAX_EVENTS_IN_TOOLTIP: usize = 8;
+
+/// A named moment on a timeline.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bookmark {
+    pub timeline: TimelineName,
+    pub time: TimeInt,
+    pub name: String,
+}
+
+/// Read all bookmarks, of all timelines, from the blueprint.
+pub fn load_bookmarks(ctx: &ViewerContext<'_>) -> Vec<Bookmark> {
+    let results = ctx.blueprint_db().latest_at(
+        ctx.blueprint_query,
+        &EntityPath::from(BOOKMARKS_PATH),
+        [
+            &TimelineBookmarks::descriptor_timelines(),
+            &TimelineBookmarks::descriptor_times(),
+            &TimelineBookmarks::descriptor_names(),
+        ],
+    );
+
+    let timelines = results
+        .component_batch::<TimelineNameComponent>(&TimelineBookmarks::descriptor_timelines())
+        .unwrap_or_default();
+    let times = results
+        .component_batch::<BookmarkTime>(&TimelineBookmarks::descriptor_times())
+        .unwrap_or_default();
+    let names = results
+        .component_batch::<Name>(&TimelineBookmarks::descriptor_names())
+        .unwrap_or_default();
+
+    itertools::izip!(timelines, times)
+        .enumerate()
+        .map(|(index, (timeline, time))| Bookmark {
+            timeline: timeline.into(),
+            time: TimeInt::from(time.0),
+            name: names.get(index).map_or_else(
+                || default_bookmark_name(index),
+                |name| name.as_str().to_owned(),
+            ),
+        })
+        .collect()
+}
+
+/// Replace all bookmarks in the blueprint.
+pub fn save_bookmarks(ctx: &ViewerContext<'_>, bookmarks: &[Bookmark]) {
+    ctx.save_blueprint_archetype(
+        EntityPath::from(BOOKMARKS_PATH),
+        &TimelineBookmarks::new()
+            .with_timelines(
+                bookmarks
+                    .iter()
+                    .map(|bookmark| TimelineNameComponent::from(bookmark.timeline.as_str())),
+            )
+            .with_times(bookmarks.iter().map(|bookmark| bookmark.time))
+            .with_names(
+                bookmarks
+                    .iter()
+                    .map(|bookmark| Name::from(bookmark.name.as_str())),
+            ),
+    );
+}
+
+fn default_bookmark_name(index: usize) -> String {
+    format!("Bookmark {}", index + 1)
+}
+
+fn add_bookmark(ctx: &ViewerContext<'_>, timeline: TimelineName, time: TimeInt) {
+    let mut bookmarks = load_bookmarks(ctx);
+    if bookmarks
+        .iter()
+        .any(|bookmark| bookmark.timeline == timeline && bookmark.time == time)
+    {
+        return;
+    }
+
+    let name = default_bookmark_name(bookmarks.len());
+    bookmarks.push(Bookmark {
+        timeline,
+        time,
+        name,
+    });
+    save_bookmarks(ctx, &bookmarks);
+}
+
+/// Context menu entries for adding bookmarks on the current timeline.
+pub fn add_bookmark_context_menu(
+    ctx: &ViewerContext<'_>,
+    ui: &mut egui::Ui,
+    timeline: TimelineName,
+    hovered_time: Option<TimeReal>,
+    current_time: Option<TimeInt>,
+) {
+    if let Some(time) = hovered_time {
+        if ui.button("Add bookmark here").clicked() {
+            add_bookmark(ctx, timeline, time.round());
+            ui.close_menu();
+        }
+    }
+
+    if let Some(time) = current_time {
+        if ui.button("Add bookmark at current time").clicked() {
+            add_bookmark(ctx, timeline, time);
+            ui.close_menu();
+        }
+    }
+}
+
+/// Times of all bookmarks and event markers on the given timeline, sorted and deduplicated.
+fn marker_times(
+    ctx: &ViewerContext<'_>,
+    entity_db: &EntityDb,
+    timeline: &TimelineName,
+) -> Vec<TimeInt> {
+    let bookmark_times = load_bookmarks(ctx)
+        .into_iter()
+        .filter(|bookmark| &bookmark.timeline == timeline)
+        .map(|bookmark| bookmark.time);
+
+    let event_times = EventMarkersStoreSubscriber::access(&entity_db.store_id(), |subscriber| {
+        subscriber
+            .markers(timeline)
+            .into_iter()
+            .map(|marker| marker.time)
+            .collect_vec()
+    })
+    .unwrap_or_default();
+
+    bookmark_times.chain(event_times).sorted().dedup().collect()
+}
+
+/// Jump to the previous/next bookmark or event marker with `[` and `]`.
+pub fn marker_navigation_shortcuts(
+    ctx: &ViewerContext<'_>,
+    entity_db: &EntityDb,
+    time_ctrl: &mut TimeControl,
+) {
+    let egui_ctx = ctx.egui_ctx();
+    if egui_ctx.wants_keyboard_input() {
+        return;
+    }
+
+    let previous =
+        egui_ctx.input_mut(|i| i.consume_key(egui::Modifiers::NONE, egui::Key::OpenBracket));
+    let next =
+        egui_ctx.input_mut(|i| i.consume_key(egui::Modifiers::NONE, egui::Key::CloseBracket));
+    if !previous && !next {
+        return;
+    }
+
+    let Some(current_time) = time_ctrl.time_int() else {
+        return;
+    };
+
+    let times = marker_times(ctx, entity_db, time_ctrl.timeline().name());
+    let target = if next {
+        times.into_iter().find(|time| *time > current_time)
+    } else {
+        times.into_iter().rev().find(|time| *time < current_time)
+    };
+
+    if let Some(time) = target {
+        time_ctrl.set_time(time);
+        time_ctrl.pause();
+    }
+}
+
+/// Paint the bookmarks and event markers of the current timeline onto the timeline ruler.
+///
+/// Hovering a marker shows what it is about, and scrubbing the time cursor over it snaps to it.
+pub fn time_markers_ui(
+    ctx: &ViewerContext<'_>,
+    entity_db: &EntityDb,
+    time_ranges_ui: &TimeRangesUi,
+    time_ctrl: &mut TimeControl,
+    ui: &egui::Ui,
+    painter: &egui::Painter,
+    timeline_rect: &Rect,
+) {
+    re_tracing::profile_function!();
+
+    let timeline = *time_ctrl.timeline().name();
+    let x_from_time = |time: TimeInt| {
+        time_ranges_ui
+            .x_from_time_f32(TimeReal::from(time))
+            .filter(|x| timeline_rect.x_range().contains(*x))
+    };
+
+    // Event markers: small triangles along the bottom of the ruler.
+    let events = EventMarkersStoreSubscriber::access(&entity_db.store_id(), |subscriber| {
+        subscriber
+            .markers(&timeline)
+            .into_iter()
+            .cloned()
+            .collect_vec()
+    })
+    .unwrap_or_default();
+
+    let event_groups = events
+        .iter()
+        .filter_map(|event| Some((x_from_time(event.time)?, event)))
+        .chunk_by(|(x, _)| (x / (2.0 * MARKER_HOVER_RADIUS)).round() as i64);
+
+    for (_, group) in &event_groups {
+        let group = group.collect_vec();
+        let Some((x, first_event)) = group.first().copied() else {
+            continue;
+        };
+        let events_in_group = group.iter().map(|(_, event)| *event).collect_vec();
+        let severity = events_in_group
+            .iter()
+            .map(|event| event.severity)
+            .max()
+            .unwrap_or(EventSeverity::Warning);
+
+        let color = match severity {
+            EventSeverity::Warning => ui.visuals().warn_fg_color,
+            EventSeverity::Error => ui.visuals().error_fg_color,
+        };
+
+        let size = MARKER_HOVER_RADIUS;
+        let bottom = timeline_rect.bottom();
+        painter.add(egui::Shape::convex_polygon(
+            vec![
+                egui::pos2(x - size, bottom),
+                egui::pos2(x, bottom - 1.5 * size),
+                egui::pos2(x + size, bottom),
+            ],
+            color,
+            Stroke::NONE,
+        ));
+
+        let rect = Rect::from_x_y_ranges(
+            (x - MARKER_HOVER_RADIUS)..=(x + MARKER_HOVER_RADIUS),
+            (bottom - 2.0 * size)..=bottom,
+        );
+        let response = ui
+            .interact(
+                rect,
+                ui.id().with(("event_marker", x as i64)),
+                Sense::hover(),
+            )
+            .on_hover_ui(|ui| events_tooltip_ui(ctx, ui, time_ctrl, &events_in_group));
+
+        snap_time_cursor(ui, &response, time_ctrl, first_event.time);
+    }
+
+    // Bookmarks: flags that reach across the whole ruler.
+    let bookmarks = load_bookmarks(ctx);
+    let bookmark_color = ui.visuals().strong_text_color();
+
+    for (index, bookmark) in bookmarks.iter().enumerate() {
+        if bookmark.timeline != timeline {
+            continue;
+        }
+        let Some(x) = x_from_time(bookmark.time) else {
+            continue;
+        };
+
+        let top = timeline_rect.top();
+        let size = MARKER_HOVER_RADIUS;
+        painter.vline(x, timeline_rect.y_range(), Stroke::new(1.0, bookmark_color));
+        painter.add(egui::Shape::convex_polygon(
+            vec![
+                egui::pos2(x, top),
+                egui::pos2(x + 2.0 * size, top + size),
+                egui::pos2(x, top + 2.0 * size),
+            ],
+            bookmark_color,
+            Stroke::NONE,
+        ));
+
+        let rect = Rect::from_x_y_ranges(
+            (x - MARKER_HOVER_RADIUS)..=(x + 2.0 * size),
+            top..=(top + 2.0 * size + MARKER_HOVER_RADIUS),
+        );
+        let response = ui
+            .interact(rect, ui.id().with(("bookmark", index)), Sense::click())
+            .on_hover_ui(|ui| {
+                ui.strong(&bookmark.name);
+                ui.label(format_time(ctx, time_ctrl, bookmark.time));
+            });
+
+        response.context_menu(|ui| bookmark_context_menu(ctx, ui, &bookmarks, index));
+
+        snap_time_cursor(ui, &response, time_ctrl, bookmark.time);
+    }
+}
+
+fn format_time(ctx: &ViewerContext<'_>, time_ctrl: &TimeControl, time: TimeInt) -> String {
+    time_ctrl
+        .time_type()
+        .format(time, ctx.app_options().timestamp_format)
+}
+
+fn events_tooltip_ui(
+    ctx: &ViewerContext<'_>,
+    ui: &mut egui::Ui,
+    time_ctrl: &TimeControl,
+    events: &[&EventMarker],
+) {
+    for event in events.iter().take(MAX_EVENTS_IN_TOOLTIP) {
+        let color = match event.severity {
+            EventSeverity::Warning => ui.visuals().warn_fg_color,
+            EventSeverity::Error => ui.visuals().error_fg_color,
+        };
+        ui.horizontal(|ui| {
+            ui.label(format_time(ctx, time_ctrl, event.time));
+            ui.colored_label(color, &event.text);
+        });
+        ui.weak(event.entity_path.to_string());
+    }
+
+    if events.len() > MAX_EVENTS_IN_TOOLTIP {
+        ui.weak(format!(
+            "…and {} more",
+            re_format::format_uint(events.len() - MAX_EVENTS_IN_TOOLTIP)
+        ));
+    }
+}
+
+fn bookmark_context_menu(
+    ctx: &ViewerContext<'_>,
+    ui: &mut egui::Ui,
+    bookmarks: &[Bookmark],
+    index: usize,
+) {
+    let Some(bookmark) = bookmarks.get(index) else {
+        return;
+    };
+
+    // The name being edited lives in egui memory until it is committed with enter.
+    let edit_id = ui.id().with(("bookmark_name", index));
+    let mut name = ui
+        .data(|data| data.get_temp::<String>(edit_id))
+        .unwrap_or_else(|| bookmark.name.clone());
+
+    let response = ui.text_edit_singleline(&mut name);
+    if response.lost_focus() {
+        ui.data_mut(|data| data.remove::<String>(edit_id));
+        if !name.is_empty() && name != bookmark.name {
+            let mut bookmarks = bookmarks.to_vec();
+            bookmarks[index].name = name;
+            save_bookmarks(ctx, &bookmarks);
+        }
+    } else {
+        ui.data_mut(|data| data.insert_temp(edit_id, name));
+    }
+
+    if ui.button("Remove bookmark").clicked() {
+        let mut bookmarks = bookmarks.to_vec();
+        bookmarks.remove(index);
+        save_bookmarks(ctx, &bookmarks);
+        ui.close_menu();
+    }
+}
+
+/// While the time cursor is pressed or dragged over a marker, move it exactly onto the marker.
+fn snap_time_cursor(
+    ui: &egui::Ui,
+    response: &egui::Response,
+    time_ctrl: &mut TimeControl,
+    time: TimeInt,
+) {
+    let is_pointer_over_marker = ui
+        .input(|i| i.pointer.hover_pos())
+        .is_some_and(|pos| response.rect.contains(pos));
+
+    if is_pointer_over_marker
+        && ui.input(|i| i.pointer.primary_down())
+        && ui.ctx().dragged_id() == Some(time_drag_id(ui))
+    {
+        time_ctrl.set_time(time);
+        time_ctrl.pause();
+    }
+}
@@ -25,10 +25,12 @@ use re_viewer_context::{
 use re_viewport_blueprint::ViewportBlueprint;
 
 use crate::{
+    event_markers_subscriber::EventMarkersStoreSubscriber,
     recursive_chunks_per_timeline_subscriber::PathRecursiveChunksPerTimelineStoreSubscriber,
     streams_tree_data::{EntityData, StreamsTreeData, components_for_entity},
     time_axis::TimelineAxis,
     time_control_ui::TimeControlUi,
+    time_markers,
     time_ranges_ui::TimeRangesUi,
     {data_density_graph, paint_ticks, time_ranges_ui, time_selection_ui},
 };
@@ -172,6 +174,7 @@ impl TimePanel {
     /// e.g., testing context.
     pub fn ensure_registered_subscribers() {
         PathRecursiveChunksPerTimelineStoreSubscriber::ensure_registered();
+        EventMarkersStoreSubscriber::ensure_registered();
     }
 
     pub fn new_blueprint_panel() -> Self {
@@ -274,6 +277,11 @@ impl TimePanel {
             },
         );
 
+        // Handled here rather than in the expanded ui, so that it also works when collapsed.
+        if self.source == TimePanelSource::Recording {
+            time_markers::marker_navigation_shortcuts(ctx, entity_db, &mut time_ctrl_after);
+        }
+
         // Apply time control if there were any changes.
         // This means that if anyone else meanwhile changed the time control, these changes are lost now.
         // At least though we don't overwrite them if we didn't change anything at all.
@@ -565,6 +573,8 @@ impl TimePanel {
             ui.draw_shadow_line(rect, egui::Direction::LeftToRight);
         }
 
+        let is_recording = self.source == TimePanelSource::Recording;
+
         // Put time-marker on top and last, so that you can always drag it
         time_marker_ui(
             &self.time_ranges_ui,
@@ -573,8 +583,22 @@ impl TimePanel {
             Some(&time_area_response),
             &time_area_painter,
             &timeline_rect,
+            is_recording.then_some(ctx),
         );
 
+        // Bookmarks and event markers come after the time-marker so they can be hovered on the ruler.
+        if is_recording {
+            time_markers::time_markers_ui(
+                ctx,
+                entity_db,
+                &self.time_ranges_ui,
+                time_ctrl,
+                ui,
+                &time_area_painter,
+                &timeline_rect,
+            );
+        }
+
         self.time_ranges_ui.snap_time_control(time_ctrl);
 
         // remember where to show the time for next frame:
@@ -1391,6 +1415,7 @@ impl TimePanel {
                     None,
                     &painter,
                     &time_range_rect,
+                    None,
                 );
             }
         }
@@ -1790,6 +1815,12 @@ fn copy_time_properties_context_menu(
 }
 
 /// A vertical line that shows the current time.
+/// Id of the time cursor while it is being dragged in the given ui.
+pub(crate) fn time_drag_id(ui: &egui::Ui) -> egui::Id {
+    ui.id().with("time_drag_id")
+}
+
+/// If `bookmarks_ctx` is set, the context menu of the time area also offers to add bookmarks.
 fn time_marker_ui(
     time_ranges_ui: &TimeRangesUi,
     time_ctrl: &mut TimeControl,
@@ -1797,11 +1828,12 @@ fn time_marker_ui(
     time_area_response: Option<&egui::Response>,
     time_area_painter: &egui::Painter,
     timeline_rect: &Rect,
+    bookmarks_ctx: Option<&ViewerContext<'_>>,
 ) {
     // timeline_rect: top part with the second ticks and time marker
 
     let pointer_pos = ui.input(|i| i.pointer.hover_pos());
-    let time_drag_id = ui.id().with("time_drag_id");
+    let time_drag_id = time_drag_id(ui);
     let timeline_cursor_icon = CursorIcon::ResizeHorizontal;
     let is_hovering_the_loop_selection = ui.output(|o| o.cursor_icon) != CursorIcon::Default; // A kind of hacky proxy
     let is_anything_being_dragged = ui.ctx().dragged_id().is_some();
@@ -1910,8 +1942,20 @@ fn time_marker_ui(
             }
         }
 
-        time_area_response
-            .context_menu(|ui| copy_time_properties_context_menu(ui, time_ctrl, hovered_time));
+        time_area_response.context_menu(|ui| {
+            copy_time_properties_context_menu(ui, time_ctrl, hovered_time);
+
+            if let Some(ctx) = bookmarks_ctx {
+                ui.separator();
+                time_markers::add_bookmark_context_menu(
+                    ctx,
+                    ui,
+                    *time_ctrl.timeline().name(),
+                    hovered_time,
+                    time_ctrl.time_int(),
+                );
+            }
+        });
     }
 }
 
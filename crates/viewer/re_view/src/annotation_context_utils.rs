@@ -12,6 +12,32 @@ pub fn process_color_slice<'a>(
     num_instances: usize,
     annotation_infos: &'a ResolvedAnnotationInfos,
     colors: &'a [Color],
+) -> Vec<egui::Color32> {
+    let mut colors = process_logged_color_slice(
+        ctx,
+        fallback_provider,
+        num_instances,
+        annotation_infos,
+        colors,
+    );
+
+    // Set apart data from the compared recording.
+    let store_ctx = ctx.store_ctx();
+    if store_ctx.is_compared_recording {
+        for color in &mut colors {
+            *color = store_ctx.recording_color(*color);
+        }
+    }
+
+    colors
+}
+
+fn process_logged_color_slice<'a>(
+    ctx: &QueryContext<'_>,
+    fallback_provider: &'a dyn re_viewer_context::TypedComponentFallbackProvider<Color>,
+    num_instances: usize,
+    annotation_infos: &'a ResolvedAnnotationInfos,
+    colors: &'a [Color],
 ) -> Vec<egui::Color32> {
     re_tracing::profile_function_if!(10_000 < num_instances);
 
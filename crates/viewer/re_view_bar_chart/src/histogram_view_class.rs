@@ -0,0 +1,380 @@
+use egui::ahash::HashMap;
+use egui_plot::{Bar, BarChart, ColorConflictHandling, Legend, Plot};
+
+use re_log_types::EntityPath;
+use re_types::{
+    View as _, ViewClassIdentifier,
+    blueprint::{
+        archetypes::{HistogramBins, PlotLegend},
+        components::{BinCount, Corner2D, Enabled},
+    },
+    components::{Range1D, Visible},
+    datatypes::TimeRange,
+};
+use re_ui::{Help, IconText, MouseButtonText, icons, list_item};
+use re_view::{controls::SELECTION_RECT_ZOOM_BUTTON, view_property_ui};
+use re_viewer_context::{
+    IdentifiedViewSystem as _, QueryContext, QueryRange, SystemExecutionOutput,
+    TypedComponentFallbackProvider, ViewClass, ViewClassExt as _, ViewClassRegistryError, ViewId,
+    ViewQuery, ViewSpawnHeuristics, ViewState, ViewStateExt as _, ViewSystemExecutionError,
+    ViewerContext,
+};
+use re_viewport_blueprint::ViewProperty;
+
+use crate::histogram_visualizers::{
+    HistogramDepthImageSystem, HistogramImageSystem, HistogramInput, HistogramScalarsSystem,
+    HistogramTensorSystem,
+};
+
+/// Bin count used unless the blueprint specifies one.
+const DEFAULT_BIN_COUNT: u64 = 64;
+
+/// Upper limit for the bin count, to keep the number of bars drawn per entity reasonable.
+const MAX_BIN_COUNT: u64 = 4096;
+
+#[derive(Default)]
+pub struct HistogramViewState {
+    /// The range spanned by the values of all entities in the view, as of the last frame.
+    ///
+    /// Used as the default bin range.
+    value_range: Option<(f64, f64)>,
+}
+
+impl ViewState for HistogramViewState {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+/// Shows how the values of scalars, tensors and images are distributed.
+#[derive(Default)]
+pub struct HistogramView;
+
+type ViewType = re_types::blueprint::views::HistogramView;
+
+impl ViewClass for HistogramView {
+    fn identifier() -> ViewClassIdentifier {
+        ViewType::identifier()
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Histogram"
+    }
+
+    fn icon(&self) -> &'static re_ui::Icon {
+        &re_ui::icons::VIEW_HISTOGRAM
+    }
+
+    fn new_state(&self) -> Box<dyn ViewState> {
+        Box::<HistogramViewState>::default()
+    }
+
+    fn help(&self, os: egui::os::OperatingSystem) -> Help {
+        let egui::InputOptions { zoom_modifier, .. } = egui::InputOptions::default(); // This is OK, since we don't allow the user to change these modifiers.
+
+        Help::new("Histogram view")
+            .docs_link("https://rerun.io/docs/reference/types/views/histogram_view")
+            .control("Pan", (icons::LEFT_MOUSE_CLICK, "+", "drag"))
+            .control(
+                "Zoom",
+                IconText::from_modifiers_and(os, zoom_modifier, icons::SCROLL),
+            )
+            .control(
+                "Zoom to selection",
+                (MouseButtonText(SELECTION_RECT_ZOOM_BUTTON), "+", "drag"),
+            )
+            .control("Reset view", ("double", icons::LEFT_MOUSE_CLICK))
+            .control_separator()
+            .control("Hide/show entity", (icons::LEFT_MOUSE_CLICK, "legend"))
+    }
+
+    fn on_register(
+        &self,
+        system_registry: &mut re_viewer_context::ViewSystemRegistrator<'_>,
+    ) -> Result<(), ViewClassRegistryError> {
+        system_registry.register_visualizer::<HistogramScalarsSystem>()?;
+        system_registry.register_visualizer::<HistogramTensorSystem>()?;
+        system_registry.register_visualizer::<HistogramImageSystem>()?;
+        system_registry.register_visualizer::<HistogramDepthImageSystem>()?;
+        Ok(())
+    }
+
+    fn preferred_tile_aspect_ratio(&self, _state: &dyn ViewState) -> Option<f32> {
+        None
+    }
+
+    fn layout_priority(&self) -> re_viewer_context::ViewClassLayoutPriority {
+        re_viewer_context::ViewClassLayoutPriority::Low
+    }
+
+    fn supports_visible_time_range(&self) -> bool {
+        true
+    }
+
+    fn default_query_range(&self, _view_state: &dyn ViewState) -> QueryRange {
+        QueryRange::TimeRange(TimeRange::EVERYTHING)
+    }
+
+    fn spawn_heuristics(
+        &self,
+        _ctx: &ViewerContext<'_>,
+        _include_entity: &dyn Fn(&EntityPath) -> bool,
+    ) -> ViewSpawnHeuristics {
+        // A histogram is a second look at data that already has a view of its own,
+        // so these views are only ever created by the user.
+        ViewSpawnHeuristics::empty()
+    }
+
+    fn selection_ui(
+        &self,
+        ctx: &ViewerContext<'_>,
+        ui: &mut egui::Ui,
+        state: &mut dyn ViewState,
+        _space_origin: &EntityPath,
+        view_id: ViewId,
+    ) -> Result<(), ViewSystemExecutionError> {
+        list_item::list_item_scope(ui, "histogram_selection_ui", |ui| {
+            let ctx = self.view_context(ctx, view_id, state);
+            view_property_ui::<HistogramBins>(&ctx, ui, self);
+            view_property_ui::<PlotLegend>(&ctx, ui, self);
+        });
+
+        Ok(())
+    }
+
+    fn ui(
+        &self,
+        ctx: &ViewerContext<'_>,
+        ui: &mut egui::Ui,
+        state: &mut dyn ViewState,
+        query: &ViewQuery<'_>,
+        system_output: SystemExecutionOutput,
+    ) -> Result<(), ViewSystemExecutionError> {
+        re_tracing::profile_function!();
+
+        let state = state.downcast_mut::<HistogramViewState>()?;
+
+        let blueprint_db = ctx.blueprint_db();
+        let view_id = query.view_id;
+
+        let systems = &system_output.view_systems;
+        let inputs: Vec<&HistogramInput> = std::iter::empty()
+            .chain(&systems.get::<HistogramScalarsSystem>()?.inputs)
+            .chain(&systems.get::<HistogramTensorSystem>()?.inputs)
+            .chain(&systems.get::<HistogramImageSystem>()?.inputs)
+            .chain(&systems.get::<HistogramDepthImageSystem>()?.inputs)
+            .collect();
+
+        // Has to be known before querying the bin range, since it's its fallback.
+        state.value_range = inputs
+            .iter()
+            .filter_map(|input| input.value_range)
+            .reduce(|(min_a, max_a), (min_b, max_b)| (min_a.min(min_b), max_a.max(max_b)));
+
+        let view_ctx = self.view_context(ctx, view_id, state);
+
+        let bins = ViewProperty::from_archetype::<HistogramBins>(
+            blueprint_db,
+            ctx.blueprint_query,
+            view_id,
+        );
+        let bin_count = bins.component_or_fallback::<BinCount>(
+            &view_ctx,
+            self,
+            &HistogramBins::descriptor_bin_count(),
+        )?;
+        let bin_range = bins.component_or_fallback::<Range1D>(
+            &view_ctx,
+            self,
+            &HistogramBins::descriptor_range(),
+        )?;
+        let log_scale = bins.component_or_fallback::<Enabled>(
+            &view_ctx,
+            self,
+            &HistogramBins::descriptor_log_scale(),
+        )?;
+
+        let plot_legend =
+            ViewProperty::from_archetype::<PlotLegend>(blueprint_db, ctx.blueprint_query, view_id);
+        let legend_visible = plot_legend.component_or_fallback::<Visible>(
+            &view_ctx,
+            self,
+            &PlotLegend::descriptor_visible(),
+        )?;
+        let legend_corner = plot_legend.component_or_fallback::<Corner2D>(
+            &view_ctx,
+            self,
+            &PlotLegend::descriptor_corner(),
+        )?;
+
+        let num_bins = bin_count.0.0.clamp(1, MAX_BIN_COUNT) as usize;
+        let bin_range = (bin_range.start(), bin_range.end());
+        let log_scale = *log_scale.0;
+
+        let min_axis_thickness = ui.tokens().small_icon_size.y;
+
+        let mut plot = Plot::new(("histogram_plot", view_id))
+            .clamp_grid(true)
+            .custom_x_axes(vec![
+                egui_plot::AxisHints::new_x()
+                    .min_thickness(min_axis_thickness)
+                    .formatter(|mark, _| re_format::format_f64(mark.value)),
+            ])
+            .custom_y_axes(vec![
+                egui_plot::AxisHints::new_y()
+                    .min_thickness(min_axis_thickness)
+                    .formatter(move |mark, _| format_count_axis(mark.value, log_scale)),
+            ])
+            .label_formatter(move |_name, value| {
+                format!(
+                    "{}\n{}",
+                    re_format::format_f64(value.x),
+                    format_count_axis(value.y, log_scale)
+                )
+            });
+
+        if *legend_visible.0 {
+            plot = plot.legend(
+                Legend::default()
+                    .position(legend_corner.into())
+                    .color_conflict_handling(ColorConflictHandling::PickFirst),
+            );
+        }
+
+        let mut plot_item_id_to_entity_path = HashMap::default();
+
+        let egui_plot::PlotResponse {
+            response,
+            hovered_plot_item,
+            ..
+        } = plot.show(ui, |plot_ui| {
+            for input in &inputs {
+                let Some(histogram) =
+                    input.histogram(&ctx.store_context.caches, bin_range, num_bins)
+                else {
+                    continue;
+                };
+
+                let fill = input.color.gamma_multiply(0.75).additive(); // make sure overlapping bars are obvious
+                let stroke_color = fill.linear_multiply(0.5);
+                let bin_width = histogram.bin_width();
+
+                let bars = histogram
+                    .counts
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, count)| **count > 0)
+                    .map(|(bin, &count)| {
+                        let bin_start = histogram.range.0 + bin as f64 * bin_width;
+                        Bar::new(histogram.bin_center(bin), bar_height(count, log_scale))
+                            .width(bin_width)
+                            .name(format!(
+                                "{}\n[{}, {}): {count}",
+                                input.entity_path,
+                                re_format::format_f64(bin_start),
+                                re_format::format_f64(bin_start + bin_width),
+                            ))
+                            .fill(fill)
+                            .stroke((1.0, stroke_color))
+                    })
+                    .collect();
+
+                let id = egui::Id::new(input.entity_path.hash());
+                plot_item_id_to_entity_path.insert(id, input.entity_path.clone());
+
+                plot_ui.bar_chart(
+                    BarChart::new(input.entity_path.to_string(), bars)
+                        .color(input.color)
+                        .element_formatter(Box::new(|bar, _| bar.name.clone()))
+                        .id(id),
+                );
+            }
+        });
+
+        // Interact with the plot items.
+        let hovered_data_result = hovered_plot_item
+            .and_then(|hovered_plot_item| plot_item_id_to_entity_path.get(&hovered_plot_item))
+            .map(|entity_path| {
+                re_viewer_context::Item::DataResult(query.view_id, entity_path.clone().into())
+            })
+            .or_else(|| {
+                if response.hovered() {
+                    Some(re_viewer_context::Item::View(query.view_id))
+                } else {
+                    None
+                }
+            });
+        if let Some(hovered) = hovered_data_result {
+            ctx.handle_select_hover_drag_interactions(&response, hovered, false);
+        }
+
+        Ok(())
+    }
+}
+
+/// The height of the bar of a bin with `count` values.
+///
+/// On a log scale, a bin with a single value is one unit high so that it can be told apart from an empty one.
+fn bar_height(count: u64, log_scale: bool) -> f64 {
+    if log_scale {
+        (count as f64).log10() + 1.0
+    } else {
+        count as f64
+    }
+}
+
+/// Inverse of [`bar_height`], for labeling the count axis.
+fn format_count_axis(height: f64, log_scale: bool) -> String {
+    let count = if log_scale {
+        10.0_f64.powf(height - 1.0)
+    } else {
+        height
+    };
+    re_format::format_f64(count)
+}
+
+impl TypedComponentFallbackProvider<BinCount> for HistogramView {
+    fn fallback_for(&self, _ctx: &QueryContext<'_>) -> BinCount {
+        DEFAULT_BIN_COUNT.into()
+    }
+}
+
+impl TypedComponentFallbackProvider<Range1D> for HistogramView {
+    fn fallback_for(&self, ctx: &QueryContext<'_>) -> Range1D {
+        ctx.view_state()
+            .as_any()
+            .downcast_ref::<HistogramViewState>()
+            .and_then(|state| state.value_range)
+            .map_or(Range1D::new(0.0, 1.0), |(min, max)| Range1D::new(min, max))
+    }
+}
+
+impl TypedComponentFallbackProvider<Corner2D> for HistogramView {
+    fn fallback_for(&self, _ctx: &QueryContext<'_>) -> Corner2D {
+        // Distributions tend to fall off towards the right, leaving room for the legend there.
+        Corner2D::RightTop
+    }
+}
+
+re_viewer_context::impl_component_fallback_provider!(HistogramView => [BinCount, Range1D, Corner2D]);
+
+#[test]
+fn test_help_view() {
+    re_viewer_context::test_context::TestContext::test_help_view(|ctx| HistogramView.help(ctx));
+}
+
+#[test]
+fn test_bar_height_roundtrip() {
+    for count in [1, 10, 1234] {
+        let height = bar_height(count, true);
+        assert_eq!(
+            format_count_axis(height, true),
+            re_format::format_f64(count as f64)
+        );
+    }
+    assert_eq!(bar_height(7, false), 7.0);
+}
@@ -0,0 +1,408 @@
+use std::sync::Arc;
+
+use re_chunk_store::{LatestAtQuery, RangeQuery, RowId};
+use re_entity_db::EntityPath;
+use re_log_types::{ResolvedTimeRange, hash::Hash64};
+use re_types::{
+    Archetype as _, ComponentDescriptor,
+    archetypes::{DepthImage, Image, Scalars, Tensor},
+    components::{ImageFormat, TensorData},
+    image::ImageKind,
+};
+use re_view::{
+    RangeResultsExt as _, latest_at_with_blueprint_resolved_data,
+    range_with_blueprint_resolved_data,
+};
+use re_viewer_context::{
+    Caches, DataResult, IdentifiedViewSystem, ImageInfo, ImageStatsCache, QueryRange,
+    TensorStatsCache, ValueHistogram, ViewContext, ViewContextCollection, ViewQuery,
+    ViewSystemExecutionError, VisualizerQueryInfo, VisualizerSystem, auto_color_for_entity_path,
+};
+
+/// The values of a single entity, shown as one histogram of the [`crate::HistogramView`].
+pub struct HistogramInput {
+    pub entity_path: EntityPath,
+    pub color: egui::Color32,
+    pub values: HistogramValues,
+
+    /// The range of all finite values, `None` if there are none.
+    pub value_range: Option<(f64, f64)>,
+}
+
+/// Where the values of a [`HistogramInput`] come from.
+pub enum HistogramValues {
+    /// Scalars over the visible time range.
+    ///
+    /// These change with the time cursor, so they're binned every frame rather than cached.
+    Scalars(Vec<f64>),
+
+    /// All elements of a tensor at the current time.
+    Tensor { row_id: RowId, tensor: TensorData },
+
+    /// All channel values of a (depth) image at the current time.
+    Image(ImageInfo),
+}
+
+impl HistogramInput {
+    /// Sorts the values into `num_bins` bins spread evenly over `range`.
+    ///
+    /// Returns `None` if the values can't be binned, e.g. for chroma subsampled images.
+    pub fn histogram(
+        &self,
+        caches: &Caches,
+        range: (f64, f64),
+        num_bins: usize,
+    ) -> Option<Arc<ValueHistogram>> {
+        match &self.values {
+            HistogramValues::Scalars(values) => Some(Arc::new(ValueHistogram::from_values(
+                values.iter().copied(),
+                range,
+                num_bins,
+            ))),
+            HistogramValues::Tensor { row_id, tensor } => {
+                Some(caches.entry(|c: &mut TensorStatsCache| {
+                    c.histogram(Hash64::hash(row_id), tensor, range, num_bins)
+                }))
+            }
+            HistogramValues::Image(image) => {
+                caches.entry(|c: &mut ImageStatsCache| c.histogram(image, range, num_bins))
+            }
+        }
+    }
+}
+
+// ---
+
+/// Collects the values of [`Scalars`] over the visible time range.
+#[derive(Default)]
+pub struct HistogramScalarsSystem {
+    pub inputs: Vec<HistogramInput>,
+}
+
+impl IdentifiedViewSystem for HistogramScalarsSystem {
+    fn identifier() -> re_viewer_context::ViewSystemIdentifier {
+        "HistogramScalars".into()
+    }
+}
+
+impl VisualizerSystem for HistogramScalarsSystem {
+    fn visualizer_query_info(&self) -> VisualizerQueryInfo {
+        VisualizerQueryInfo::from_archetype::<Scalars>()
+    }
+
+    fn execute(
+        &mut self,
+        ctx: &ViewContext<'_>,
+        view_query: &ViewQuery<'_>,
+        _context_systems: &ViewContextCollection,
+    ) -> Result<Vec<re_renderer::QueueableDrawData>, ViewSystemExecutionError> {
+        re_tracing::profile_function!();
+
+        for data_result in view_query.iter_visible_data_results(Self::identifier()) {
+            let time_range = match data_result.query_range() {
+                QueryRange::TimeRange(time_range) => {
+                    ResolvedTimeRange::from_relative_time_range(time_range, view_query.latest_at)
+                }
+                QueryRange::LatestAt => {
+                    ResolvedTimeRange::new(view_query.latest_at, view_query.latest_at)
+                }
+            };
+            let range_query = RangeQuery::new(view_query.timeline, time_range);
+
+            let results = range_with_blueprint_resolved_data(
+                ctx,
+                None,
+                &range_query,
+                data_result,
+                Scalars::all_components().iter(),
+            );
+            let Some(all_scalar_chunks) =
+                results.get_required_chunks(Scalars::descriptor_scalars())
+            else {
+                continue;
+            };
+
+            let values: Vec<f64> = all_scalar_chunks
+                .iter()
+                .flat_map(|chunk| chunk.iter_slices::<f64>())
+                .flat_map(|values| values.iter().copied())
+                .collect();
+
+            self.inputs.push(HistogramInput {
+                entity_path: data_result.entity_path.clone(),
+                color: auto_color_for_entity_path(&data_result.entity_path).into(),
+                value_range: finite_range(values.iter().copied()),
+                values: HistogramValues::Scalars(values),
+            });
+        }
+
+        Ok(Vec::new())
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn fallback_provider(&self) -> &dyn re_viewer_context::ComponentFallbackProvider {
+        self
+    }
+}
+
+re_viewer_context::impl_component_fallback_provider!(HistogramScalarsSystem => []);
+
+/// The range of all finite values, `None` if there are none.
+fn finite_range(values: impl Iterator<Item = f64>) -> Option<(f64, f64)> {
+    values
+        .filter(|value| value.is_finite())
+        .fold(None, |range, value| match range {
+            Some((min, max)) => Some((f64::min(min, value), f64::max(max, value))),
+            None => Some((value, value)),
+        })
+}
+
+// ---
+
+/// Collects the elements of the [`Tensor`] at the current time.
+#[derive(Default)]
+pub struct HistogramTensorSystem {
+    pub inputs: Vec<HistogramInput>,
+}
+
+impl IdentifiedViewSystem for HistogramTensorSystem {
+    fn identifier() -> re_viewer_context::ViewSystemIdentifier {
+        "HistogramTensor".into()
+    }
+}
+
+impl VisualizerSystem for HistogramTensorSystem {
+    fn visualizer_query_info(&self) -> VisualizerQueryInfo {
+        VisualizerQueryInfo::from_archetype::<Tensor>()
+    }
+
+    fn execute(
+        &mut self,
+        ctx: &ViewContext<'_>,
+        view_query: &ViewQuery<'_>,
+        _context_systems: &ViewContextCollection,
+    ) -> Result<Vec<re_renderer::QueueableDrawData>, ViewSystemExecutionError> {
+        re_tracing::profile_function!();
+
+        let timeline_query = LatestAtQuery::new(view_query.timeline, view_query.latest_at);
+        let timeline = view_query.timeline;
+
+        for data_result in view_query.iter_visible_data_results(Self::identifier()) {
+            let results = latest_at_with_blueprint_resolved_data(
+                ctx,
+                None,
+                &timeline_query,
+                data_result,
+                Tensor::all_components().iter(),
+                false,
+            );
+            let Some(all_tensor_chunks) = results.get_required_chunks(Tensor::descriptor_data())
+            else {
+                continue;
+            };
+
+            let latest_tensor = all_tensor_chunks
+                .iter()
+                .flat_map(|chunk| {
+                    chunk
+                        .iter_component_indices(&timeline)
+                        .zip(chunk.iter_component::<TensorData>())
+                })
+                .filter_map(|((_time, row_id), tensors)| Some((row_id, tensors.first()?.clone())))
+                .last();
+            let Some((row_id, tensor)) = latest_tensor else {
+                continue;
+            };
+
+            let stats = ctx
+                .viewer_ctx
+                .store_context
+                .caches
+                .entry(|c: &mut TensorStatsCache| c.entry(Hash64::hash(row_id), &tensor));
+
+            self.inputs.push(HistogramInput {
+                entity_path: data_result.entity_path.clone(),
+                color: auto_color_for_entity_path(&data_result.entity_path).into(),
+                value_range: stats.range.map(|_| stats.finite_range),
+                values: HistogramValues::Tensor { row_id, tensor },
+            });
+        }
+
+        Ok(Vec::new())
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn fallback_provider(&self) -> &dyn re_viewer_context::ComponentFallbackProvider {
+        self
+    }
+}
+
+re_viewer_context::impl_component_fallback_provider!(HistogramTensorSystem => []);
+
+// ---
+
+/// Collects the channel values of the [`Image`] at the current time.
+#[derive(Default)]
+pub struct HistogramImageSystem {
+    pub inputs: Vec<HistogramInput>,
+}
+
+impl IdentifiedViewSystem for HistogramImageSystem {
+    fn identifier() -> re_viewer_context::ViewSystemIdentifier {
+        "HistogramImage".into()
+    }
+}
+
+impl VisualizerSystem for HistogramImageSystem {
+    fn visualizer_query_info(&self) -> VisualizerQueryInfo {
+        VisualizerQueryInfo::from_archetype::<Image>()
+    }
+
+    fn execute(
+        &mut self,
+        ctx: &ViewContext<'_>,
+        view_query: &ViewQuery<'_>,
+        _context_systems: &ViewContextCollection,
+    ) -> Result<Vec<re_renderer::QueueableDrawData>, ViewSystemExecutionError> {
+        re_tracing::profile_function!();
+
+        for data_result in view_query.iter_visible_data_results(Self::identifier()) {
+            if let Some(input) = latest_image_input(
+                ctx,
+                view_query,
+                data_result,
+                &Image::all_components(),
+                &Image::descriptor_buffer(),
+                &Image::descriptor_format(),
+                ImageKind::Color,
+            ) {
+                self.inputs.push(input);
+            }
+        }
+
+        Ok(Vec::new())
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn fallback_provider(&self) -> &dyn re_viewer_context::ComponentFallbackProvider {
+        self
+    }
+}
+
+re_viewer_context::impl_component_fallback_provider!(HistogramImageSystem => []);
+
+/// Collects the depth values of the [`DepthImage`] at the current time.
+#[derive(Default)]
+pub struct HistogramDepthImageSystem {
+    pub inputs: Vec<HistogramInput>,
+}
+
+impl IdentifiedViewSystem for HistogramDepthImageSystem {
+    fn identifier() -> re_viewer_context::ViewSystemIdentifier {
+        "HistogramDepthImage".into()
+    }
+}
+
+impl VisualizerSystem for HistogramDepthImageSystem {
+    fn visualizer_query_info(&self) -> VisualizerQueryInfo {
+        VisualizerQueryInfo::from_archetype::<DepthImage>()
+    }
+
+    fn execute(
+        &mut self,
+        ctx: &ViewContext<'_>,
+        view_query: &ViewQuery<'_>,
+        _context_systems: &ViewContextCollection,
+    ) -> Result<Vec<re_renderer::QueueableDrawData>, ViewSystemExecutionError> {
+        re_tracing::profile_function!();
+
+        for data_result in view_query.iter_visible_data_results(Self::identifier()) {
+            if let Some(input) = latest_image_input(
+                ctx,
+                view_query,
+                data_result,
+                &DepthImage::all_components(),
+                &DepthImage::descriptor_buffer(),
+                &DepthImage::descriptor_format(),
+                ImageKind::Depth,
+            ) {
+                self.inputs.push(input);
+            }
+        }
+
+        Ok(Vec::new())
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn fallback_provider(&self) -> &dyn re_viewer_context::ComponentFallbackProvider {
+        self
+    }
+}
+
+re_viewer_context::impl_component_fallback_provider!(HistogramDepthImageSystem => []);
+
+/// Queries the latest image of an entity, shared by the color and depth image visualizers.
+fn latest_image_input(
+    ctx: &ViewContext<'_>,
+    view_query: &ViewQuery<'_>,
+    data_result: &DataResult,
+    all_components: &[ComponentDescriptor],
+    buffer_descr: &ComponentDescriptor,
+    format_descr: &ComponentDescriptor,
+    kind: ImageKind,
+) -> Option<HistogramInput> {
+    let timeline_query = LatestAtQuery::new(view_query.timeline, view_query.latest_at);
+    let timeline = view_query.timeline;
+
+    let results = latest_at_with_blueprint_resolved_data(
+        ctx,
+        None,
+        &timeline_query,
+        data_result,
+        all_components.iter(),
+        false,
+    );
+    let all_buffer_chunks = results.get_required_chunks(buffer_descr.clone())?;
+    let all_format_chunks = results.get_required_chunks(format_descr.clone())?;
+
+    let (row_id, buffer) = all_buffer_chunks
+        .iter()
+        .flat_map(|chunk| {
+            chunk
+                .iter_component_indices(&timeline)
+                .zip(chunk.iter_slices::<&[u8]>())
+        })
+        .filter_map(|((_time, row_id), buffers)| Some((row_id, buffers.first()?.clone())))
+        .last()?;
+    let format = all_format_chunks
+        .iter()
+        .flat_map(|chunk| chunk.iter_component::<ImageFormat>())
+        .filter_map(|formats| formats.first().copied())
+        .last()?;
+
+    let image = ImageInfo::from_stored_blob(row_id, buffer_descr, buffer.into(), format.0, kind);
+    let stats = ctx
+        .viewer_ctx
+        .store_context
+        .caches
+        .entry(|c: &mut ImageStatsCache| c.entry(&image));
+
+    Some(HistogramInput {
+        entity_path: data_result.entity_path.clone(),
+        color: auto_color_for_entity_path(&data_result.entity_path).into(),
+        value_range: stats.range.map(|_| stats.finite_range),
+        values: HistogramValues::Image(image),
+    })
+}
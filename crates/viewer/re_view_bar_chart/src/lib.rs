@@ -1,8 +1,11 @@
-//! Rerun bar chart View.
+//! Rerun bar chart Views.
 //!
-//! A View that shows a single bar chart.
+//! A View that shows a single bar chart, and one that shows histograms of scalars, tensors and images.
 
+mod histogram_view_class;
+mod histogram_visualizers;
 mod view_class;
 mod visualizer_system;
 
+pub use histogram_view_class::HistogramView;
 pub use view_class::BarChartView;
@@ -0,0 +1,491 @@
+//! Map tiles from a [`MapTileSource`], shown instead of the ones of the selected [`MapProvider`].
+//!
+//! [`MapTileSource`]: re_types::blueprint::components::MapTileSource
+//! [`MapProvider`]: re_types::blueprint::components::MapProvider
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+use parking_lot::Mutex;
+use walkers::{Texture, TextureWithUv, TileId, Tiles, sources::Attribution};
+use web_time::Instant;
+
+use re_log_types::hash::Hash64;
+
+use crate::pmtiles::{PmTiles, PmTilesError};
+
+/// At most this many tiles are downloaded at the same time.
+const MAX_PARALLEL_DOWNLOADS: usize = 6;
+
+/// At most this many tiles are kept in memory, the least recently used ones are dropped first.
+const MAX_CACHED_TILES: usize = 256;
+
+/// Tiles that couldn't be read or downloaded are tried again after this long.
+const RETRY_AFTER: Duration = Duration::from_secs(10);
+
+#[cfg(not(target_arch = "wasm32"))]
+const MBTILES_TILE_QUERY: &str =
+    "SELECT tile_data FROM tiles WHERE zoom_level = ?1 AND tile_column = ?2 AND tile_row = ?3";
+
+#[derive(thiserror::Error, Debug)]
+pub enum CustomTilesError {
+    #[error("failed to open {path:?}: {err}")]
+    Io { path: PathBuf, err: std::io::Error },
+
+    #[error(transparent)]
+    PmTiles(#[from] PmTilesError),
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[error(transparent)]
+    MbTiles(#[from] rusqlite::Error),
+
+    #[cfg(target_arch = "wasm32")]
+    #[error("MBTiles files can't be read on the web")]
+    MbTilesOnWeb,
+
+    #[error("failed to start loading tiles: {0}")]
+    SpawnThread(std::io::Error),
+}
+
+/// Where the tiles come from.
+enum TileReader {
+    /// An XYZ URL template, with downloaded tiles kept in an optional on-disk cache.
+    Url(UrlTiles),
+
+    #[cfg(not(target_arch = "wasm32"))]
+    MbTiles(rusqlite::Connection),
+
+    PmTiles(PmTiles<std::io::BufReader<std::fs::File>>),
+
+    /// A directory of `{z}/{x}/{y}.png` files.
+    Directory(PathBuf),
+}
+
+/// What the loader came up with for a requested tile.
+enum LoadedTile {
+    /// `None` if the source doesn't have this tile, or it couldn't be decoded.
+    Found(Option<Texture>),
+
+    /// The tile couldn't be read or downloaded, which may only be temporary.
+    Failed,
+
+    /// Too many downloads are in progress, ask again later.
+    Postponed,
+}
+
+/// Tiles finished by the loader, which are picked up by [`CustomTiles`] on its next use.
+type LoadedTiles = Arc<Mutex<Vec<(TileId, LoadedTile)>>>;
+
+enum TileState {
+    /// Requested from the loader, which hasn't finished yet.
+    Loading,
+
+    /// `None` if the source doesn't have this tile, or it couldn't be decoded.
+    Loaded(Option<Texture>),
+
+    /// Loading failed at the given time, and is tried again after [`RETRY_AFTER`].
+    Failed(Instant),
+}
+
+struct CachedTile {
+    state: TileState,
+
+    /// The [`CustomTiles::num_uses`] at which this tile was last used.
+    last_used: u64,
+}
+
+/// Tiles from a URL template, an MBTiles or PMTiles file, or a directory.
+///
+/// All reading, downloading and decoding happens in the background.
+pub struct CustomTiles {
+    loader: TileLoader,
+    loaded: LoadedTiles,
+
+    /// Bounded to [`MAX_CACHED_TILES`].
+    tiles: HashMap<TileId, CachedTile>,
+
+    /// Incremented on every use of a tile, for dropping the least recently used ones.
+    num_uses: u64,
+}
+
+impl CustomTiles {
+    /// Opens the given tile source, see [`re_types::blueprint::components::MapTileSource`] for the
+    /// supported kinds.
+    ///
+    /// Tiles downloaded from a URL are cached in a subdirectory of `cache_directory`, if any.
+    pub fn new(
+        source: &str,
+        cache_directory: Option<&Path>,
+        egui_ctx: egui::Context,
+    ) -> Result<Self, CustomTilesError> {
+        let source = source.trim();
+
+        let reader = if source.starts_with("http://") || source.starts_with("https://") {
+            TileReader::Url(UrlTiles {
+                template: source.to_owned(),
+                cache_directory: cache_directory.map(|cache_directory| {
+                    let source_hash = Hash64::hash(source).hash64();
+                    cache_directory
+                        .join("custom_tiles")
+                        .join(format!("{source_hash:016x}"))
+                }),
+                num_downloads: Default::default(),
+            })
+        } else {
+            let path = PathBuf::from(source.strip_prefix("file://").unwrap_or(source));
+            let extension = path
+                .extension()
+                .map(|extension| extension.to_string_lossy().to_lowercase());
+            match extension.as_deref() {
+                Some("mbtiles") => open_mbtiles(&path)?,
+                Some("pmtiles") => {
+                    let file = std::fs::File::open(&path)
+                        .map_err(|err| CustomTilesError::Io { path, err })?;
+                    TileReader::PmTiles(PmTiles::new(std::io::BufReader::new(file))?)
+                }
+                _ => {
+                    if let Err(err) = std::fs::read_dir(&path) {
+                        return Err(CustomTilesError::Io { path, err });
+                    }
+                    TileReader::Directory(path)
+                }
+            }
+        };
+
+        let loaded = LoadedTiles::default();
+        Ok(Self {
+            loader: TileLoader::new(reader, loaded.clone(), egui_ctx)?,
+            loaded,
+            tiles: HashMap::default(),
+            num_uses: 0,
+        })
+    }
+
+    /// Picks up the tiles the loader finished since the last call.
+    fn receive_loaded_tiles(&mut self) {
+        for (tile_id, loaded) in self.loaded.lock().drain(..) {
+            let state = match loaded {
+                LoadedTile::Found(texture) => TileState::Loaded(texture),
+                LoadedTile::Failed => TileState::Failed(Instant::now()),
+                LoadedTile::Postponed => {
+                    self.tiles.remove(&tile_id);
+                    continue;
+                }
+            };
+            if let Some(tile) = self.tiles.get_mut(&tile_id) {
+                tile.state = state;
+            }
+        }
+    }
+
+    fn texture(&mut self, tile_id: TileId) -> Option<Texture> {
+        self.num_uses += 1;
+
+        if let Some(tile) = self.tiles.get_mut(&tile_id) {
+            tile.last_used = self.num_uses;
+            match &tile.state {
+                TileState::Loading => return None,
+                TileState::Loaded(texture) => return texture.clone(),
+                TileState::Failed(failed_at) if failed_at.elapsed() < RETRY_AFTER => return None,
+                TileState::Failed(_) => {}
+            }
+        }
+
+        self.loader.request(tile_id);
+        self.tiles.insert(
+            tile_id,
+            CachedTile {
+                state: TileState::Loading,
+                last_used: self.num_uses,
+            },
+        );
+
+        if self.tiles.len() > MAX_CACHED_TILES {
+            let least_recently_used = self
+                .tiles
+                .iter()
+                .min_by_key(|(_, tile)| tile.last_used)
+                .map(|(tile_id, _)| *tile_id);
+            if let Some(least_recently_used) = least_recently_used {
+                self.tiles.remove(&least_recently_used);
+            }
+        }
+
+        None
+    }
+}
+
+impl Tiles for CustomTiles {
+    fn at(&mut self, tile_id: TileId) -> Option<TextureWithUv> {
+        self.receive_loaded_tiles();
+
+        // Tiles that aren't available (yet) are covered with the matching part of the closest
+        // coarser tile, which also makes it possible to zoom in further than the source goes.
+        (0..=tile_id.zoom.min(31)).find_map(|levels_up| {
+            let parent_id = TileId {
+                x: tile_id.x >> levels_up,
+                y: tile_id.y >> levels_up,
+                zoom: tile_id.zoom - levels_up,
+            };
+            let texture = self.texture(parent_id)?;
+
+            let scale = 1.0 / (1_u32 << levels_up) as f32;
+            let mask = (1_u32 << levels_up) - 1;
+            let min = egui::pos2(
+                (tile_id.x & mask) as f32 * scale,
+                (tile_id.y & mask) as f32 * scale,
+            );
+            Some(TextureWithUv {
+                texture,
+                uv: egui::Rect::from_min_size(min, egui::Vec2::splat(scale)),
+            })
+        })
+    }
+
+    fn attribution(&self) -> Attribution {
+        Attribution {
+            text: "Custom map tiles",
+            url: "",
+            logo_light: None,
+            logo_dark: None,
+        }
+    }
+
+    fn tile_size(&self) -> u32 {
+        256
+    }
+}
+
+/// Reads, downloads and decodes tiles off the UI thread.
+///
+/// Tiles are read one after the other on a thread of its own, which stops once the loader is
+/// dropped. Downloads run in parallel, see [`MAX_PARALLEL_DOWNLOADS`].
+#[cfg(not(target_arch = "wasm32"))]
+struct TileLoader {
+    requests: std::sync::mpsc::Sender<TileId>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl TileLoader {
+    fn new(
+        mut reader: TileReader,
+        loaded: LoadedTiles,
+        egui_ctx: egui::Context,
+    ) -> Result<Self, CustomTilesError> {
+        let (requests, requested) = std::sync::mpsc::channel();
+        std::thread::Builder::new()
+            .name("map_tile_loader".to_owned())
+            .spawn(move || {
+                while let Ok(tile_id) = requested.recv() {
+                    reader.load(tile_id, &loaded, &egui_ctx);
+                }
+            })
+            .map_err(CustomTilesError::SpawnThread)?;
+
+        Ok(Self { requests })
+    }
+
+    fn request(&self, tile_id: TileId) {
+        // Only fails if the thread is gone, in which case the tile stays loading.
+        self.requests.send(tile_id).ok();
+    }
+}
+
+/// There are no threads on the web, where tiles can only be downloaded, which happens in the
+/// background anyway.
+#[cfg(target_arch = "wasm32")]
+struct TileLoader {
+    reader: Mutex<TileReader>,
+    loaded: LoadedTiles,
+    egui_ctx: egui::Context,
+}
+
+#[cfg(target_arch = "wasm32")]
+impl TileLoader {
+    #[allow(clippy::unnecessary_wraps)] // Same signature as on native.
+    fn new(
+        reader: TileReader,
+        loaded: LoadedTiles,
+        egui_ctx: egui::Context,
+    ) -> Result<Self, CustomTilesError> {
+        Ok(Self {
+            reader: Mutex::new(reader),
+            loaded,
+            egui_ctx,
+        })
+    }
+
+    fn request(&self, tile_id: TileId) {
+        self.reader
+            .lock()
+            .load(tile_id, &self.loaded, &self.egui_ctx);
+    }
+}
+
+/// Decodes the tile, if any, and hands it over to [`CustomTiles`].
+fn finish_loading(
+    loaded: &LoadedTiles,
+    tile_id: TileId,
+    data: Result<Option<Vec<u8>>, String>,
+    egui_ctx: &egui::Context,
+) {
+    let tile = match data {
+        Ok(Some(bytes)) => LoadedTile::Found(
+            Texture::new(&bytes, egui_ctx)
+                .map_err(|err| re_log::warn_once!("Failed to decode map tile: {err}"))
+                .ok(),
+        ),
+        Ok(None) => LoadedTile::Found(None),
+        Err(err) => {
+            re_log::warn_once!("Failed to load map tile: {err}");
+            LoadedTile::Failed
+        }
+    };
+    loaded.lock().push((tile_id, tile));
+    egui_ctx.request_repaint();
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn open_mbtiles(path: &Path) -> Result<TileReader, CustomTilesError> {
+    use rusqlite::OpenFlags;
+
+    let connection = rusqlite::Connection::open_with_flags(
+        path,
+        OpenFlags::SQLITE_OPEN_READ_ONLY | OpenFlags::SQLITE_OPEN_NO_MUTEX,
+    )?;
+
+    // Fail early if this isn't an MBTiles file.
+    connection.prepare_cached(MBTILES_TILE_QUERY)?;
+
+    Ok(TileReader::MbTiles(connection))
+}
+
+#[cfg(target_arch = "wasm32")]
+fn open_mbtiles(_path: &Path) -> Result<TileReader, CustomTilesError> {
+    Err(CustomTilesError::MbTilesOnWeb)
+}
+
+impl TileReader {
+    fn load(&mut self, tile_id: TileId, loaded: &LoadedTiles, egui_ctx: &egui::Context) {
+        let TileId { x, y, zoom } = tile_id;
+
+        let data = match self {
+            Self::Url(url_tiles) => return url_tiles.load(tile_id, loaded, egui_ctx),
+
+            #[cfg(not(target_arch = "wasm32"))]
+            Self::MbTiles(connection) => {
+                use rusqlite::OptionalExtension as _;
+
+                // MBTiles count rows from the bottom.
+                let tile_row = (1_u32 << zoom).wrapping_sub(1).wrapping_sub(y);
+                connection
+                    .prepare_cached(MBTILES_TILE_QUERY)
+                    .and_then(|mut statement| {
+                        statement
+                            .query_row((zoom, x, tile_row), |row| row.get::<_, Vec<u8>>(0))
+                            .optional()
+                    })
+                    .map_err(|err| err.to_string())
+            }
+
+            Self::PmTiles(pmtiles) => pmtiles.tile(zoom, x, y).map_err(|err| err.to_string()),
+
+            Self::Directory(directory) => {
+                let path = directory
+                    .join(zoom.to_string())
+                    .join(x.to_string())
+                    .join(format!("{y}.png"));
+                match std::fs::read(path) {
+                    Ok(bytes) => Ok(Some(bytes)),
+                    Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+                    Err(err) => Err(err.to_string()),
+                }
+            }
+        };
+
+        finish_loading(loaded, tile_id, data, egui_ctx);
+    }
+}
+
+struct UrlTiles {
+    template: String,
+
+    /// Downloaded tiles are stored here as `{z}/{x}/{y}`, and never expire.
+    cache_directory: Option<PathBuf>,
+
+    num_downloads: Arc<AtomicUsize>,
+}
+
+impl UrlTiles {
+    fn load(&self, tile_id: TileId, loaded: &LoadedTiles, egui_ctx: &egui::Context) {
+        let cache_path = self.cache_path(tile_id);
+        if let Some(bytes) = cache_path
+            .as_ref()
+            .and_then(|cache_path| std::fs::read(cache_path).ok())
+        {
+            finish_loading(loaded, tile_id, Ok(Some(bytes)), egui_ctx);
+            return;
+        }
+
+        // Finished downloads repaint, which is when postponed tiles are asked for again.
+        if self.num_downloads.fetch_add(1, Ordering::Relaxed) >= MAX_PARALLEL_DOWNLOADS {
+            self.num_downloads.fetch_sub(1, Ordering::Relaxed);
+            loaded.lock().push((tile_id, LoadedTile::Postponed));
+            return;
+        }
+
+        let url = self
+            .template
+            .replace("{z}", &tile_id.zoom.to_string())
+            .replace("{x}", &tile_id.x.to_string())
+            .replace("{y}", &tile_id.y.to_string());
+        let template = self.template.clone();
+        let num_downloads = self.num_downloads.clone();
+        let loaded = loaded.clone();
+        let egui_ctx = egui_ctx.clone();
+
+        ehttp::fetch(ehttp::Request::get(url), move |response| {
+            num_downloads.fetch_sub(1, Ordering::Relaxed);
+
+            let data = match response {
+                Ok(response) if response.ok => Ok(Some(response.bytes)),
+                Ok(response) => Err(format!(
+                    "downloading from {template} failed: {} {}",
+                    response.status, response.status_text
+                )),
+                Err(err) => Err(format!("downloading from {template} failed: {err}")),
+            };
+
+            if let (Ok(Some(bytes)), Some(cache_path)) = (&data, &cache_path) {
+                if let Err(err) = write_to_cache(cache_path, bytes) {
+                    re_log::warn_once!("Failed to cache map tile: {err}");
+                }
+            }
+
+            finish_loading(&loaded, tile_id, data, &egui_ctx);
+        });
+    }
+
+    fn cache_path(&self, tile_id: TileId) -> Option<PathBuf> {
+        self.cache_directory.as_ref().map(|cache_directory| {
+            cache_directory
+                .join(tile_id.zoom.to_string())
+                .join(tile_id.x.to_string())
+                .join(tile_id.y.to_string())
+        })
+    }
+}
+
+fn write_to_cache(cache_path: &Path, bytes: &[u8]) -> std::io::Result<()> {
+    if let Some(parent) = cache_path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    // Write to a temporary file first, so that an interrupted write never leaves a broken tile behind.
+    let partial_path = cache_path.with_extension("partial");
+    std::fs::write(&partial_path, bytes)?;
+    std::fs::rename(partial_path, cache_path)
+}
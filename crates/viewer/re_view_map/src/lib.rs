@@ -2,8 +2,10 @@
 //!
 //! A View that shows geographic objects on a map.
 
+mod custom_tiles;
 mod map_overlays;
 mod map_view;
+mod pmtiles;
 mod visualizers;
 
 pub use map_view::MapView;
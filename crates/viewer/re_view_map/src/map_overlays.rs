@@ -20,6 +20,10 @@ pub fn acknowledgement_overlay(
         .inner_margin(egui::Margin::same(2))
         .show(&mut ui, |ui| {
             let text = egui::WidgetText::from(attribution.text).small();
-            ui.hyperlink_to(text, attribution.url);
+            if attribution.url.is_empty() {
+                ui.label(text);
+            } else {
+                ui.hyperlink_to(text, attribution.url);
+            }
         });
 }
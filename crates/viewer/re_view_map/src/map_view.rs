@@ -1,4 +1,6 @@
-use egui::{Context, Modifiers, NumExt as _, Rect, Response};
+use std::sync::Arc;
+
+use egui::{Context, Modifiers, NumExt as _, Rect, Response, emath::Rot2};
 use re_view::AnnotationSceneContext;
 use walkers::{HttpTiles, Map, MapMemory, Tiles};
 
@@ -9,9 +11,10 @@ use re_renderer::{RenderContext, ViewBuilder};
 use re_types::{
     View as _, ViewClassIdentifier,
     blueprint::{
-        archetypes::{MapBackground, MapZoom},
-        components::{MapProvider, ZoomLevel},
+        archetypes::{MapBackground, MapFollow, MapZoom},
+        components::{MapFollowMode, MapProvider, MapTileSource, ZoomLevel},
     },
+    components,
 };
 use re_ui::{Help, IconText, icons, list_item};
 use re_viewer_context::{
@@ -22,16 +25,32 @@ use re_viewer_context::{
 };
 use re_viewport_blueprint::ViewProperty;
 
+use crate::custom_tiles::CustomTiles;
 use crate::map_overlays;
-use crate::visualizers::{GeoLineStringsVisualizer, GeoPointsVisualizer, update_span};
+use crate::visualizers::{
+    GeoLineStringsVisualizer, GeoPointsVisualizer, GeoPolygonsVisualizer, MapProjector, update_span,
+};
 
 pub struct MapViewState {
-    tiles: Option<HttpTiles>,
+    tiles: Option<Box<dyn Tiles + Send + Sync>>,
     map_memory: MapMemory,
     selected_provider: MapProvider,
 
+    /// The custom tile source shown instead of the provider's tiles, if any.
+    selected_tile_source: Option<String>,
+
     last_center_position: walkers::Position,
 
+    /// The latest position of the followed entity as of the previous frame.
+    ///
+    /// Used to derive the heading when the followed entity has no position history in view.
+    last_followed_position: Option<walkers::Position>,
+
+    /// Clockwise rotation of the map, in radians, that keeps the followed entity heading up.
+    ///
+    /// Kept across frames so that the map doesn't snap back north whenever the entity stops.
+    follow_heading: f32,
+
     /// Because `re_renderer` can have varying, multiple frames of delay, we must keep track of the
     /// last picked results for when picking results is not available on a given frame.
     last_gpu_picking_result: Option<InstancePathHash>,
@@ -43,10 +62,13 @@ impl Default for MapViewState {
             tiles: None,
             map_memory: Default::default(),
             selected_provider: Default::default(),
+            selected_tile_source: None,
 
             // default to Rerun HQ whenever we have no data (either now or historically) to provide
             // a better location
             last_center_position: walkers::lat_lon(59.319224, 18.075514),
+            last_followed_position: None,
+            follow_heading: 0.0,
             last_gpu_picking_result: None,
         }
     }
@@ -58,16 +80,21 @@ impl MapViewState {
         &mut self,
         ctx: &ViewerContext<'_>,
         egui_ctx: &egui::Context,
-    ) -> Result<(&mut HttpTiles, &mut MapMemory), ViewSystemExecutionError> {
+    ) -> Result<(&mut (dyn Tiles + Send + Sync), &mut MapMemory), ViewSystemExecutionError> {
         if self.tiles.is_none() {
-            let tiles = get_tile_manager(ctx, self.selected_provider, egui_ctx);
+            let tiles = get_tile_manager(
+                ctx,
+                self.selected_provider,
+                self.selected_tile_source.as_deref(),
+                egui_ctx,
+            );
             self.tiles = Some(tiles);
         }
 
         // Now that tiles is guaranteed to be Some, unwrap is safe here.
         let tiles_ref = self
             .tiles
-            .as_mut()
+            .as_deref_mut()
             .ok_or(ViewSystemExecutionError::MapTilesError)?;
         Ok((tiles_ref, &mut self.map_memory))
     }
@@ -118,6 +145,7 @@ impl ViewClass for MapView {
     ) -> Result<(), ViewClassRegistryError> {
         system_registry.register_visualizer::<GeoPointsVisualizer>()?;
         system_registry.register_visualizer::<GeoLineStringsVisualizer>()?;
+        system_registry.register_visualizer::<GeoPolygonsVisualizer>()?;
 
         system_registry.register_context_system::<AnnotationSceneContext>()?;
 
@@ -152,6 +180,7 @@ impl ViewClass for MapView {
         let any_map_entity = [
             GeoPointsVisualizer::identifier(),
             GeoLineStringsVisualizer::identifier(),
+            GeoPolygonsVisualizer::identifier(),
         ]
         .iter()
         .any(|system_id| {
@@ -180,6 +209,7 @@ impl ViewClass for MapView {
             let ctx = self.view_context(ctx, view_id, state);
             re_view::view_property_ui::<MapZoom>(&ctx, ui, self);
             re_view::view_property_ui::<MapBackground>(&ctx, ui, self);
+            re_view::view_property_ui::<MapFollow>(&ctx, ui, self);
         });
 
         Ok(())
@@ -206,10 +236,17 @@ impl ViewClass for MapView {
             query.view_id,
         );
 
+        let map_follow = ViewProperty::from_archetype::<MapFollow>(
+            ctx.blueprint_db(),
+            ctx.blueprint_query,
+            query.view_id,
+        );
+
         let geo_points_visualizer = system_output.view_systems.get::<GeoPointsVisualizer>()?;
         let geo_line_strings_visualizers = system_output
             .view_systems
             .get::<GeoLineStringsVisualizer>()?;
+        let geo_polygons_visualizer = system_output.view_systems.get::<GeoPolygonsVisualizer>()?;
 
         //
         // Map Provider
@@ -221,9 +258,19 @@ impl ViewClass for MapView {
             self,
             &MapBackground::descriptor_provider(),
         )?;
-        if state.selected_provider != map_provider {
+        let follow_mode = map_follow.component_or_fallback::<MapFollowMode>(
+            &view_ctx,
+            self,
+            &MapFollow::descriptor_mode(),
+        )?;
+        let tile_source = map_background
+            .component_or_empty::<MapTileSource>(&MapBackground::descriptor_tile_source())?
+            .map(|tile_source| tile_source.as_str().trim().to_owned())
+            .filter(|tile_source| !tile_source.is_empty());
+        if state.selected_provider != map_provider || state.selected_tile_source != tile_source {
             state.tiles = None;
             state.selected_provider = map_provider;
+            state.selected_tile_source = tile_source;
         }
 
         //
@@ -233,20 +280,60 @@ impl ViewClass for MapView {
         // Rationale:
         // - `walkers` has an auto vs. manual pan state, switching to the latter upon
         //   user interaction. We let it keep track of that state.
-        // - The tracked location is the center of the lat/lon span of the geo objects.
+        // - The tracked location is the latest position of the followed entity if any, and the
+        //   center of the lat/lon span of the geo objects otherwise.
         // - When unset in the blueprint, the zoom level is computed from the geo objects and
         //   saved as is.
         // - Zoom computation: if multiple objects, fit them on screen, otherwise use 16.0.
         //
         // TODO(ab): show in UI and save in blueprint the auto vs. manual pan state (may require
         // changes in walkers
+        // - While following, the map is re-centered every frame, so manual panning is overridden.
+        //
         // TODO(#7884): support more elaborate auto-pan/zoom modes.
 
         let mut span = None;
         update_span(&mut span, geo_points_visualizer.span());
         update_span(&mut span, geo_line_strings_visualizers.span());
+        update_span(&mut span, geo_polygons_visualizer.span());
+
+        let followed_position = if follow_mode == MapFollowMode::Off {
+            None
+        } else {
+            let follow_entity: EntityPath = map_follow
+                .component_or_empty::<components::EntityPath>(&MapFollow::descriptor_entity())?
+                .map_or_else(
+                    || query.space_origin.clone(),
+                    |entity| entity.as_str().into(),
+                );
 
-        if let Some(span) = &span {
+            let mut trail = geo_points_visualizer.trail(&follow_entity);
+            if trail.is_empty() {
+                trail = geo_line_strings_visualizers.trail(&follow_entity);
+            }
+
+            let latest = trail.last().copied();
+            if let Some(latest) = latest {
+                let previous = trail
+                    .iter()
+                    .rev()
+                    .find(|position| **position != latest)
+                    .copied()
+                    .or(state.last_followed_position)
+                    .filter(|position| *position != latest);
+                if let Some(heading) =
+                    previous.and_then(|previous| heading_up_angle(previous, latest))
+                {
+                    state.follow_heading = heading;
+                }
+                state.last_followed_position = Some(latest);
+            }
+            latest
+        };
+
+        if let Some(followed_position) = followed_position {
+            state.last_center_position = followed_position;
+        } else if let Some(span) = &span {
             state.last_center_position = span.center();
         }
         let default_center_position = state.last_center_position;
@@ -274,17 +361,58 @@ impl ViewClass for MapView {
         // Map UI
         //
 
+        let rotation =
+            if follow_mode == MapFollowMode::PositionAndHeading && followed_position.is_some() {
+                Rot2::from_angle(state.follow_heading)
+            } else {
+                Rot2::IDENTITY
+            };
+
         let (tiles, map_memory) = state.ensure_and_get_mut_refs(ctx, ui.ctx())?;
         let attribution = tiles.attribution();
 
+        if followed_position.is_some() {
+            map_memory.follow_my_position();
+        }
+
+        // A rotated map must be drawn larger than the view for its tiles to cover the corners.
+        let map_rect = ui.available_rect_before_wrap();
+        let tiles_rect = if rotation == Rot2::IDENTITY {
+            map_rect
+        } else {
+            Rect::from_center_size(
+                map_rect.center(),
+                egui::Vec2::splat(map_rect.size().length()),
+            )
+        };
+
+        let mut map_ui = ui.new_child(egui::UiBuilder::new().max_rect(tiles_rect));
+        map_ui.set_clip_rect(map_rect.intersect(ui.clip_rect()));
+        let first_map_shape = map_ui.painter().add(egui::Shape::Noop);
         let some_tiles_manager: Option<&mut dyn Tiles> = Some(tiles);
-        let map_response = ui.add(Map::new(
+        let map_response = map_ui.add(Map::new(
             some_tiles_manager,
             map_memory,
             default_center_position,
         ));
-        let map_rect = map_response.rect;
-        let projector = walkers::Projector::new(map_rect, map_memory, default_center_position);
+        let last_map_shape = map_ui.painter().add(egui::Shape::Noop);
+        ui.advance_cursor_after_rect(map_rect);
+
+        if rotation != Rot2::IDENTITY {
+            rotate_shapes(
+                ui.ctx(),
+                map_ui.layer_id(),
+                first_map_shape.0..last_map_shape.0,
+                rotation,
+                map_rect.center(),
+            );
+        }
+
+        let projector = MapProjector::new(
+            walkers::Projector::new(tiles_rect, map_memory, default_center_position),
+            rotation,
+            map_rect.center(),
+        );
 
         if map_response.double_clicked() {
             map_memory.follow_my_position();
@@ -309,9 +437,18 @@ impl ViewClass for MapView {
         // Draw all objects using re_renderer
         //
 
+        // Polygon fills are painted with egui, below everything drawn by `re_renderer`.
+        geo_polygons_visualizer.paint_fills(&ui.painter_at(map_rect), &projector);
+
         let mut view_builder =
             create_view_builder(ctx.render_ctx(), ui.ctx(), map_rect, &query.highlights);
 
+        geo_polygons_visualizer.queue_draw_data(
+            ctx.render_ctx(),
+            &mut view_builder,
+            &projector,
+            &query.highlights,
+        )?;
         geo_line_strings_visualizers.queue_draw_data(
             ctx.render_ctx(),
             &mut view_builder,
@@ -397,6 +534,57 @@ fn create_view_builder(
     )
 }
 
+/// Clockwise map rotation, in radians, that makes the direction of travel from `from` to `to`
+/// point up on screen.
+///
+/// Returns `None` if both positions are the same.
+fn heading_up_angle(from: walkers::Position, to: walkers::Position) -> Option<f32> {
+    // Screen y grows downwards, like this flipped Web Mercator y.
+    fn mercator_y(latitude: f64) -> f64 {
+        -(std::f64::consts::FRAC_PI_4 + latitude.to_radians() / 2.0)
+            .tan()
+            .ln()
+    }
+
+    // Take the short way around the antimeridian.
+    let dx = ((to.x() - from.x() + 180.0).rem_euclid(360.0) - 180.0).to_radians();
+    let dy = mercator_y(to.y()) - mercator_y(from.y());
+
+    (dx != 0.0 || dy != 0.0).then(|| (-std::f64::consts::FRAC_PI_2 - dy.atan2(dx)) as f32)
+}
+
+/// Rotate the meshes among the given range of shapes of a layer around `origin`.
+///
+/// The map tiles are painted as meshes, so this is enough to rotate the map.
+fn rotate_shapes(
+    egui_ctx: &egui::Context,
+    layer_id: egui::LayerId,
+    shapes: std::ops::Range<usize>,
+    rotation: Rot2,
+    origin: egui::Pos2,
+) {
+    fn rotate_shape(shape: &mut egui::Shape, rotation: Rot2, origin: egui::Pos2) {
+        match shape {
+            egui::Shape::Mesh(mesh) => Arc::make_mut(mesh).rotate(rotation, origin),
+            egui::Shape::Vec(shapes) => {
+                for shape in shapes {
+                    rotate_shape(shape, rotation, origin);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    egui_ctx.graphics_mut(|graphics| {
+        let paint_list = graphics.entry(layer_id);
+        for idx in shapes {
+            paint_list.mutate_shape(egui::layers::ShapeIdx(idx), |clipped_shape| {
+                rotate_shape(&mut clipped_shape.shape, rotation, origin);
+            });
+        }
+    });
+}
+
 /// Handle picking and related ui interactions.
 #[allow(clippy::too_many_arguments)]
 fn handle_picking_and_ui_interactions(
@@ -522,16 +710,40 @@ fn http_options(_ctx: &ViewerContext<'_>) -> walkers::HttpOptions {
     options
 }
 
+/// Return the directory in which downloaded tiles of custom tile sources are kept.
+fn custom_tiles_cache_directory(_ctx: &ViewerContext<'_>) -> Option<std::path::PathBuf> {
+    #[cfg(not(target_arch = "wasm32"))]
+    let cache_directory = _ctx.app_options().cache_subdirectory("map_view");
+
+    #[cfg(target_arch = "wasm32")]
+    let cache_directory = None;
+
+    cache_directory
+}
+
 fn get_tile_manager(
     ctx: &ViewerContext<'_>,
     provider: MapProvider,
+    tile_source: Option<&str>,
     egui_ctx: &Context,
-) -> HttpTiles {
+) -> Box<dyn Tiles + Send + Sync> {
+    if let Some(tile_source) = tile_source {
+        let cache_directory = custom_tiles_cache_directory(ctx);
+        match CustomTiles::new(tile_source, cache_directory.as_deref(), egui_ctx.clone()) {
+            Ok(tiles) => return Box::new(tiles),
+            Err(err) => {
+                re_log::warn_once!(
+                    "Failed to open map tile source {tile_source:?}, using the map provider instead: {err}"
+                );
+            }
+        }
+    }
+
     let mapbox_access_token = ctx.app_options().mapbox_access_token().unwrap_or_default();
 
     let options = http_options(ctx);
 
-    match provider {
+    let tiles = match provider {
         MapProvider::OpenStreetMap => {
             HttpTiles::with_options(walkers::sources::OpenStreetMap, options, egui_ctx.clone())
         }
@@ -562,7 +774,8 @@ fn get_tile_manager(
             options,
             egui_ctx.clone(),
         ),
-    }
+    };
+    Box::new(tiles)
 }
 
 re_viewer_context::impl_component_fallback_provider!(MapView => []);
@@ -636,6 +849,21 @@ fn picking_gpu(
     }
 }
 
+#[test]
+fn test_heading_up_angle() {
+    let origin = walkers::lat_lon(46.0, 6.0);
+    let angle = |to| heading_up_angle(origin, to).unwrap();
+
+    // Heading north needs no rotation, heading east needs a quarter turn counter-clockwise.
+    assert!(angle(walkers::lat_lon(46.1, 6.0)).abs() < 1e-6);
+    assert!((angle(walkers::lat_lon(46.0, 6.1)) + std::f32::consts::FRAC_PI_2).abs() < 1e-6);
+    assert_eq!(heading_up_angle(origin, origin), None);
+
+    // Crossing the antimeridian eastwards is still heading east.
+    let east = heading_up_angle(walkers::lat_lon(0.0, 179.9), walkers::lat_lon(0.0, -179.9));
+    assert!((east.unwrap() + std::f32::consts::FRAC_PI_2).abs() < 1e-6);
+}
+
 #[test]
 fn test_help_view() {
     re_viewer_context::test_context::TestContext::test_help_view(|ctx| MapView.help(ctx));
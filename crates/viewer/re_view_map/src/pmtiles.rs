@@ -0,0 +1,351 @@
+//! Reading raster tiles out of a [PMTiles](https://github.com/protomaps/PMTiles) (version 3) archive.
+
+use std::collections::HashMap;
+use std::io::{Read, Seek, SeekFrom};
+
+const HEADER_LENGTH: usize = 127;
+
+/// Directories nest at most this deep, as per the specification.
+const MAX_DIRECTORY_DEPTH: usize = 4;
+
+#[derive(thiserror::Error, Debug)]
+pub enum PmTilesError {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+
+    #[error("not a PMTiles archive")]
+    InvalidMagic,
+
+    #[error("unsupported PMTiles version {0}, only version 3 is supported")]
+    UnsupportedVersion(u8),
+
+    #[error("unsupported compression {0}, only gzip is supported")]
+    UnsupportedCompression(u8),
+
+    #[error("the archive contains vector tiles, only raster tiles are supported")]
+    VectorTiles,
+
+    #[error("malformed directory")]
+    MalformedDirectory,
+
+    #[error("{length} bytes at offset {offset} are beyond the end of the archive")]
+    OutOfBounds { offset: u64, length: u64 },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Compression {
+    None,
+    Gzip,
+}
+
+impl Compression {
+    fn from_u8(value: u8) -> Result<Self, PmTilesError> {
+        match value {
+            // 0 is "unknown", which in practice means uncompressed.
+            0 | 1 => Ok(Self::None),
+            2 => Ok(Self::Gzip),
+            _ => Err(PmTilesError::UnsupportedCompression(value)),
+        }
+    }
+
+    fn decompress(self, bytes: Vec<u8>) -> std::io::Result<Vec<u8>> {
+        match self {
+            Self::None => Ok(bytes),
+            Self::Gzip => {
+                let mut decompressed = Vec::new();
+                flate2::read::GzDecoder::new(bytes.as_slice()).read_to_end(&mut decompressed)?;
+                Ok(decompressed)
+            }
+        }
+    }
+}
+
+/// A run of tiles, or a pointer to a leaf directory.
+#[derive(Clone, Copy, Debug)]
+struct Entry {
+    tile_id: u64,
+    offset: u64,
+    length: u64,
+
+    /// Number of consecutive tile ids sharing the same data, zero for leaf directories.
+    run_length: u64,
+}
+
+/// A PMTiles archive, from which individual tiles are read on demand.
+pub struct PmTiles<R> {
+    reader: R,
+
+    /// Size of the whole archive, which no read may go beyond.
+    archive_length: u64,
+
+    root_directory: Vec<Entry>,
+    leaf_directories_offset: u64,
+    tile_data_offset: u64,
+    internal_compression: Compression,
+    tile_compression: Compression,
+
+    /// Leaf directories that were read so far, by their offset.
+    leaf_directories: HashMap<u64, Vec<Entry>>,
+}
+
+impl<R: Read + Seek> PmTiles<R> {
+    /// Reads the header and root directory of the archive.
+    pub fn new(mut reader: R) -> Result<Self, PmTilesError> {
+        let archive_length = reader.seek(SeekFrom::End(0))?;
+
+        let mut header = [0; HEADER_LENGTH];
+        reader.seek(SeekFrom::Start(0))?;
+        reader.read_exact(&mut header)?;
+
+        if &header[0..7] != b"PMTiles" {
+            return Err(PmTilesError::InvalidMagic);
+        }
+        if header[7] != 3 {
+            return Err(PmTilesError::UnsupportedVersion(header[7]));
+        }
+
+        let u64_at = |offset: usize| {
+            let mut bytes = [0; 8];
+            bytes.copy_from_slice(&header[offset..offset + 8]);
+            u64::from_le_bytes(bytes)
+        };
+        let root_directory_offset = u64_at(8);
+        let root_directory_length = u64_at(16);
+        let leaf_directories_offset = u64_at(40);
+        let tile_data_offset = u64_at(56);
+        let internal_compression = Compression::from_u8(header[97])?;
+        let tile_compression = Compression::from_u8(header[98])?;
+        if header[99] == 1 {
+            return Err(PmTilesError::VectorTiles);
+        }
+
+        let mut pmtiles = Self {
+            reader,
+            archive_length,
+            root_directory: Vec::new(),
+            leaf_directories_offset,
+            tile_data_offset,
+            internal_compression,
+            tile_compression,
+            leaf_directories: HashMap::default(),
+        };
+        pmtiles.root_directory =
+            pmtiles.read_directory(root_directory_offset, root_directory_length)?;
+
+        Ok(pmtiles)
+    }
+
+    /// Returns the (decompressed) contents of the given tile, or `None` if the archive doesn't contain it.
+    pub fn tile(&mut self, zoom: u8, x: u32, y: u32) -> Result<Option<Vec<u8>>, PmTilesError> {
+        let tile_id = tile_id(zoom, x, y);
+
+        let mut entry = find_entry(&self.root_directory, tile_id);
+        for _ in 0..MAX_DIRECTORY_DEPTH {
+            let Some(found) = entry else {
+                return Ok(None);
+            };
+
+            if found.run_length > 0 {
+                let offset = self.tile_data_offset.saturating_add(found.offset);
+                let bytes = self.read_bytes(offset, found.length)?;
+                return Ok(Some(self.tile_compression.decompress(bytes)?));
+            }
+
+            let offset = self.leaf_directories_offset.saturating_add(found.offset);
+            if !self.leaf_directories.contains_key(&offset) {
+                let leaf_directory = self.read_directory(offset, found.length)?;
+                self.leaf_directories.insert(offset, leaf_directory);
+            }
+            entry = find_entry(&self.leaf_directories[&offset], tile_id);
+        }
+
+        Err(PmTilesError::MalformedDirectory)
+    }
+
+    fn read_bytes(&mut self, offset: u64, length: u64) -> Result<Vec<u8>, PmTilesError> {
+        // Offsets and lengths come from the archive itself, so don't trust them for allocating.
+        if offset
+            .checked_add(length)
+            .is_none_or(|end| end > self.archive_length)
+        {
+            return Err(PmTilesError::OutOfBounds { offset, length });
+        }
+
+        let mut bytes = vec![0; length as usize];
+        self.reader.seek(SeekFrom::Start(offset))?;
+        self.reader.read_exact(&mut bytes)?;
+        Ok(bytes)
+    }
+
+    fn read_directory(&mut self, offset: u64, length: u64) -> Result<Vec<Entry>, PmTilesError> {
+        let bytes = self.read_bytes(offset, length)?;
+        let bytes = self.internal_compression.decompress(bytes)?;
+        decode_directory(&bytes).ok_or(PmTilesError::MalformedDirectory)
+    }
+}
+
+/// The entry covering the given tile id, if any.
+///
+/// `entries` are sorted by tile id.
+fn find_entry(entries: &[Entry], tile_id: u64) -> Option<Entry> {
+    let index = entries
+        .partition_point(|entry| entry.tile_id <= tile_id)
+        .checked_sub(1)?;
+    let entry = entries[index];
+
+    let is_leaf_directory = entry.run_length == 0;
+    (is_leaf_directory || tile_id - entry.tile_id < entry.run_length).then_some(entry)
+}
+
+/// Decodes a directory, which stores each field of all entries one after the other as varints.
+fn decode_directory(mut bytes: &[u8]) -> Option<Vec<Entry>> {
+    let num_entries = read_varint(&mut bytes)? as usize;
+
+    // Every entry takes at least one byte per field, which guards against absurd allocations.
+    if num_entries > bytes.len() {
+        return None;
+    }
+
+    let mut entries = vec![
+        Entry {
+            tile_id: 0,
+            offset: 0,
+            length: 0,
+            run_length: 0,
+        };
+        num_entries
+    ];
+
+    let mut tile_id = 0_u64;
+    for entry in &mut entries {
+        tile_id = tile_id.checked_add(read_varint(&mut bytes)?)?;
+        entry.tile_id = tile_id;
+    }
+    for entry in &mut entries {
+        entry.run_length = read_varint(&mut bytes)?;
+    }
+    for entry in &mut entries {
+        entry.length = read_varint(&mut bytes)?;
+    }
+    let mut previous_end = None;
+    for entry in &mut entries {
+        // Zero means the data directly follows the previous entry's.
+        entry.offset = match (read_varint(&mut bytes)?, previous_end) {
+            (0, Some(previous_end)) => previous_end,
+            (offset, _) => offset.checked_sub(1)?,
+        };
+        previous_end = Some(entry.offset.checked_add(entry.length)?);
+    }
+
+    Some(entries)
+}
+
+fn read_varint(bytes: &mut &[u8]) -> Option<u64> {
+    let mut value = 0_u64;
+    for shift in (0..64).step_by(7) {
+        let (&byte, rest) = bytes.split_first()?;
+        *bytes = rest;
+        value |= u64::from(byte & 0x7f) << shift;
+        if byte & 0x80 == 0 {
+            return Some(value);
+        }
+    }
+    None
+}
+
+/// The id of a tile within the archive.
+///
+/// Tiles are numbered by zoom level first, then along a Hilbert curve within each zoom level.
+fn tile_id(zoom: u8, mut x: u32, mut y: u32) -> u64 {
+    // Number of tiles on all lower zoom levels, i.e. 1 + 4 + 16 + ...
+    let mut id = ((1_u64 << (2 * u64::from(zoom))) - 1) / 3;
+
+    for level in (0..zoom).rev() {
+        let size = 1_u32 << level;
+        let rx = u32::from(x & size != 0);
+        let ry = u32::from(y & size != 0);
+        id += u64::from(size) * u64::from(size) * u64::from((3 * rx) ^ ry);
+
+        // Rotate the quadrant, so that the curve stays continuous.
+        if ry == 0 {
+            if rx == 1 {
+                x = size.wrapping_sub(1).wrapping_sub(x);
+                y = size.wrapping_sub(1).wrapping_sub(y);
+            }
+            std::mem::swap(&mut x, &mut y);
+        }
+    }
+
+    id
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PmTiles, PmTilesError, tile_id};
+
+    #[test]
+    fn tile_ids() {
+        assert_eq!(tile_id(0, 0, 0), 0);
+        assert_eq!(tile_id(1, 0, 0), 1);
+        assert_eq!(tile_id(1, 0, 1), 2);
+        assert_eq!(tile_id(1, 1, 1), 3);
+        assert_eq!(tile_id(1, 1, 0), 4);
+        assert_eq!(tile_id(2, 0, 0), 5);
+        assert_eq!(tile_id(12, 3423, 1763), 19_078_479);
+    }
+
+    fn push_varint(bytes: &mut Vec<u8>, mut value: u64) {
+        while value >= 0x80 {
+            bytes.push((value as u8) | 0x80);
+            value >>= 7;
+        }
+        bytes.push(value as u8);
+    }
+
+    /// An uncompressed archive with a single root directory of the given varints.
+    fn archive(directory_values: &[u64], tile_data: &[u8]) -> Vec<u8> {
+        let mut directory = Vec::new();
+        for &value in directory_values {
+            push_varint(&mut directory, value);
+        }
+
+        let header_length = super::HEADER_LENGTH as u64;
+        let directory_length = directory.len() as u64;
+        let mut archive = vec![0; super::HEADER_LENGTH];
+        archive[0..7].copy_from_slice(b"PMTiles");
+        archive[7] = 3;
+        archive[8..16].copy_from_slice(&header_length.to_le_bytes());
+        archive[16..24].copy_from_slice(&directory_length.to_le_bytes());
+        archive[56..64].copy_from_slice(&(header_length + directory_length).to_le_bytes());
+        archive[97] = 1;
+        archive[98] = 1;
+        archive[99] = 2;
+        archive.extend_from_slice(&directory);
+        archive.extend_from_slice(tile_data);
+        archive
+    }
+
+    #[test]
+    fn read_tiles() {
+        // Tile 0 on its own, tiles 1 and 2 sharing the same data.
+        let archive = archive(&[2, 0, 1, 1, 2, 5, 6, 1, 0], b"firstsecond");
+
+        let mut pmtiles = PmTiles::new(std::io::Cursor::new(archive)).unwrap();
+        assert_eq!(pmtiles.tile(0, 0, 0).unwrap().unwrap(), b"first");
+        assert_eq!(pmtiles.tile(1, 0, 0).unwrap().unwrap(), b"second");
+        assert_eq!(pmtiles.tile(1, 0, 1).unwrap().unwrap(), b"second");
+        assert_eq!(pmtiles.tile(1, 1, 1).unwrap(), None);
+    }
+
+    #[test]
+    fn reject_tiles_beyond_the_archive() {
+        // A single tile claiming to be a terabyte long.
+        let archive = archive(&[1, 0, 1, 1 << 40, 1], b"tile");
+
+        let mut pmtiles = PmTiles::new(std::io::Cursor::new(archive)).unwrap();
+        assert!(matches!(
+            pmtiles.tile(0, 0, 0),
+            Err(PmTilesError::OutOfBounds { .. })
+        ));
+    }
+}
@@ -129,11 +129,24 @@ impl GeoLineStringsVisualizer {
         )
     }
 
+    /// The vertices of the last line string logged to the given entity or its descendants.
+    ///
+    /// Used to follow an entity, with the last vertex being its latest position.
+    pub fn trail(&self, entity_path: &EntityPath) -> Vec<walkers::Position> {
+        self.batches
+            .iter()
+            .filter(|(path, _)| path.starts_with(entity_path))
+            .filter_map(|(_, batch)| batch.lines.last())
+            .next_back()
+            .cloned()
+            .unwrap_or_default()
+    }
+
     pub fn queue_draw_data(
         &self,
         render_ctx: &re_renderer::RenderContext,
         view_builder: &mut re_renderer::ViewBuilder,
-        projector: &walkers::Projector,
+        projector: &super::MapProjector,
         highlight: &ViewHighlights,
     ) -> Result<(), LineDrawDataError> {
         let mut lines = re_renderer::LineDrawableBuilder::new(render_ctx);
@@ -143,11 +143,22 @@ impl GeoPointsVisualizer {
         )
     }
 
+    /// All positions logged to the given entity or its descendants, in data order.
+    ///
+    /// Used to follow an entity, with the last position being its latest one.
+    pub fn trail(&self, entity_path: &EntityPath) -> Vec<walkers::Position> {
+        self.batches
+            .iter()
+            .filter(|(path, _)| path.starts_with(entity_path))
+            .flat_map(|(_, batch)| batch.positions.iter().copied())
+            .collect()
+    }
+
     pub fn queue_draw_data(
         &self,
         render_ctx: &re_renderer::RenderContext,
         view_builder: &mut re_renderer::ViewBuilder,
-        projector: &walkers::Projector,
+        projector: &super::MapProjector,
         highlight: &ViewHighlights,
     ) -> Result<(), PointCloudDrawDataError> {
         let mut points = re_renderer::PointCloudBuilder::new(render_ctx);
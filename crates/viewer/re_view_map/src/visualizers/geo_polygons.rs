@@ -0,0 +1,405 @@
+use re_log_types::{EntityPath, Instance};
+use re_renderer::{
+    PickingLayerInstanceId,
+    renderer::{LineDrawDataError, LineStripFlags},
+};
+use re_types::{
+    archetypes::GeoPolygons,
+    components::{Color, Radius},
+};
+use re_view::{DataResultQuery as _, RangeResultsExt as _};
+use re_viewer_context::{
+    IdentifiedViewSystem, QueryContext, TypedComponentFallbackProvider, ViewContext,
+    ViewContextCollection, ViewHighlights, ViewQuery, ViewSystemExecutionError,
+    VisualizerQueryInfo, VisualizerSystem, auto_color_for_entity_path,
+};
+
+/// Opacity of the fill when no fill color is specified, relative to the outline color.
+const DEFAULT_FILL_OPACITY: f32 = 0.25;
+
+#[derive(Debug, Default)]
+struct GeoPolygonsBatch {
+    /// The vertices of each polygon, without repeating the first one at the end.
+    polygons: Vec<Vec<walkers::Position>>,
+
+    /// Triangles covering the area of each polygon, as indices into its vertices.
+    triangles: Vec<Vec<[u32; 3]>>,
+
+    radii: Vec<Radius>,
+    colors: Vec<re_renderer::Color32>,
+    fill_colors: Vec<re_renderer::Color32>,
+    instance_id: Vec<PickingLayerInstanceId>,
+}
+
+/// Visualizer for [`GeoPolygons`].
+#[derive(Default)]
+pub struct GeoPolygonsVisualizer {
+    batches: Vec<(EntityPath, GeoPolygonsBatch)>,
+}
+
+impl IdentifiedViewSystem for GeoPolygonsVisualizer {
+    fn identifier() -> re_viewer_context::ViewSystemIdentifier {
+        "GeoPolygons".into()
+    }
+}
+
+impl VisualizerSystem for GeoPolygonsVisualizer {
+    fn visualizer_query_info(&self) -> VisualizerQueryInfo {
+        VisualizerQueryInfo::from_archetype::<GeoPolygons>()
+    }
+
+    fn execute(
+        &mut self,
+        ctx: &ViewContext<'_>,
+        view_query: &ViewQuery<'_>,
+        _context_systems: &ViewContextCollection,
+    ) -> Result<Vec<re_renderer::QueueableDrawData>, ViewSystemExecutionError> {
+        for data_result in view_query.iter_visible_data_results(Self::identifier()) {
+            let results = data_result.query_archetype_with_history::<GeoPolygons>(ctx, view_query);
+
+            let mut batch_data = GeoPolygonsBatch::default();
+
+            // gather all relevant chunks
+            let timeline = view_query.timeline;
+            let all_polygons = results.iter_as(timeline, GeoPolygons::descriptor_polygons());
+            let all_colors = results.iter_as(timeline, GeoPolygons::descriptor_colors());
+            let all_radii = results.iter_as(timeline, GeoPolygons::descriptor_radii());
+            let all_fill_colors = results.iter_as(timeline, GeoPolygons::descriptor_fill_colors());
+
+            // fallback component values
+            let fallback_color: Color =
+                self.fallback_for(&ctx.query_context(data_result, &view_query.latest_at_query()));
+            let fallback_radius: Radius =
+                self.fallback_for(&ctx.query_context(data_result, &view_query.latest_at_query()));
+
+            // iterate over each chunk and find all relevant component slices
+            for (_index, polygons, colors, radii, fill_colors) in re_query::range_zip_1x3(
+                all_polygons.slice::<&[[f64; 2]]>(),
+                all_colors.slice::<u32>(),
+                all_radii.slice::<f32>(),
+                all_fill_colors.slice::<u32>(),
+            ) {
+                // required component
+                let polygons = polygons.as_slice();
+
+                // optional components
+                let colors = colors.unwrap_or(&[]);
+                let radii = radii.unwrap_or(&[]);
+                let fill_colors = fill_colors.unwrap_or(&[]);
+
+                // optional components values to be used for instance clamping semantics
+                let last_color = colors.last().copied().unwrap_or(fallback_color.0.0);
+                let last_radii = radii.last().copied().unwrap_or(fallback_radius.0.0);
+                let last_fill_color = fill_colors.last().copied();
+
+                // iterate over all instances
+                for (instance_index, (polygon, color, radius, fill_color)) in itertools::izip!(
+                    polygons,
+                    colors.iter().chain(std::iter::repeat(&last_color)),
+                    radii.iter().chain(std::iter::repeat(&last_radii)),
+                    fill_colors
+                        .iter()
+                        .copied()
+                        .map(Some)
+                        .chain(std::iter::repeat(last_fill_color)),
+                )
+                .enumerate()
+                {
+                    let mut vertices = polygon.as_slice();
+                    if vertices.len() > 1 && vertices.first() == vertices.last() {
+                        vertices = &vertices[..vertices.len() - 1];
+                    }
+
+                    // Triangulating in Web Mercator coordinates gives triangles that stay valid on
+                    // screen, where the projection only adds scaling, translation and rotation.
+                    let mercator = vertices
+                        .iter()
+                        .map(|[lat, lon]| {
+                            let y = (std::f64::consts::FRAC_PI_4 + lat.to_radians() / 2.0)
+                                .tan()
+                                .ln();
+                            [lon.to_radians(), y]
+                        })
+                        .collect::<Vec<_>>();
+
+                    let color: re_renderer::Color32 = Color::new(*color).into();
+                    let fill_color = fill_color.map_or_else(
+                        || color.gamma_multiply(DEFAULT_FILL_OPACITY),
+                        |fill_color| Color::new(fill_color).into(),
+                    );
+
+                    batch_data.polygons.push(
+                        vertices
+                            .iter()
+                            .map(|pos| walkers::lat_lon(pos[0], pos[1]))
+                            .collect(),
+                    );
+                    batch_data.triangles.push(triangulate(&mercator));
+                    batch_data.radii.push(Radius((*radius).into()));
+                    batch_data.colors.push(color);
+                    batch_data.fill_colors.push(fill_color);
+                    batch_data
+                        .instance_id
+                        .push(re_renderer::PickingLayerInstanceId(instance_index as _));
+                }
+            }
+
+            self.batches
+                .push((data_result.entity_path.clone(), batch_data));
+        }
+
+        Ok(Vec::new())
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn fallback_provider(&self) -> &dyn re_viewer_context::ComponentFallbackProvider {
+        self
+    }
+}
+
+impl GeoPolygonsVisualizer {
+    /// Compute the [`super::GeoSpan`] of all the polygons in the visualizer.
+    pub fn span(&self) -> Option<super::GeoSpan> {
+        super::GeoSpan::from_lat_long(
+            self.batches
+                .iter()
+                .flat_map(|(_, batch)| batch.polygons.iter())
+                .flatten()
+                .map(|pos| (pos.y(), pos.x())),
+        )
+    }
+
+    /// Paint the areas enclosed by the polygons.
+    ///
+    /// This uses egui rather than `re_renderer`, so it must happen before the outlines are drawn
+    /// for them to end up on top. Only the outlines can be hovered and selected.
+    pub fn paint_fills(&self, painter: &egui::Painter, projector: &super::MapProjector) {
+        let mut mesh = egui::Mesh::default();
+
+        for (_, batch) in &self.batches {
+            for (polygon, triangles, fill_color) in
+                itertools::izip!(&batch.polygons, &batch.triangles, &batch.fill_colors)
+            {
+                let first_vertex = mesh.vertices.len() as u32;
+                for pos in polygon {
+                    mesh.colored_vertex(projector.project(*pos), *fill_color);
+                }
+                for [a, b, c] in triangles {
+                    mesh.add_triangle(first_vertex + a, first_vertex + b, first_vertex + c);
+                }
+            }
+        }
+
+        if !mesh.is_empty() {
+            painter.add(egui::Shape::mesh(mesh));
+        }
+    }
+
+    pub fn queue_draw_data(
+        &self,
+        render_ctx: &re_renderer::RenderContext,
+        view_builder: &mut re_renderer::ViewBuilder,
+        projector: &super::MapProjector,
+        highlight: &ViewHighlights,
+    ) -> Result<(), LineDrawDataError> {
+        let mut lines = re_renderer::LineDrawableBuilder::new(render_ctx);
+        lines.radius_boost_in_ui_points_for_outlines(
+            re_view::SIZE_BOOST_IN_POINTS_FOR_LINE_OUTLINES,
+        );
+
+        for (entity_path, batch) in &self.batches {
+            let outline = highlight.entity_outline_mask(entity_path.hash());
+
+            let mut line_batch = lines
+                .batch(entity_path.to_string())
+                .picking_object_id(re_renderer::PickingLayerObjectId(entity_path.hash64()))
+                .outline_mask_ids(outline.overall);
+
+            for (polygon, radius, color, instance) in itertools::izip!(
+                &batch.polygons,
+                &batch.radii,
+                &batch.colors,
+                &batch.instance_id
+            ) {
+                line_batch
+                    .add_strip_2d(polygon.iter().chain(polygon.first()).map(|pos| {
+                        let ui_position = projector.project(*pos);
+                        glam::vec2(ui_position.x, ui_position.y)
+                    }))
+                    //TODO(#8013): we use the first vertex's latitude because `re_renderer` doesn't support per-vertex radii
+                    .radius(super::radius_to_size(
+                        *radius,
+                        projector,
+                        polygon
+                            .first()
+                            .copied()
+                            .unwrap_or(walkers::lat_lon(0.0, 0.0)),
+                    ))
+                    .flags(LineStripFlags::FLAGS_OUTWARD_EXTENDING_ROUND_CAPS)
+                    .color(*color)
+                    .picking_instance_id(*instance)
+                    .outline_mask_ids(outline.index_outline_mask(Instance::from(instance.0)));
+            }
+        }
+
+        view_builder.queue_draw(lines.into_draw_data()?);
+
+        Ok(())
+    }
+}
+
+/// Split a simple polygon into triangles by ear clipping.
+///
+/// Returns indices into `vertices`, which may be in either winding order. Self-intersecting
+/// polygons are only partially covered.
+fn triangulate(vertices: &[[f64; 2]]) -> Vec<[u32; 3]> {
+    fn cross(a: [f64; 2], b: [f64; 2], c: [f64; 2]) -> f64 {
+        (b[0] - a[0]) * (c[1] - a[1]) - (b[1] - a[1]) * (c[0] - a[0])
+    }
+
+    if vertices.len() < 3 {
+        return Vec::new();
+    }
+
+    // Twice the signed area, whose sign tells the winding order.
+    let doubled_area = (0..vertices.len())
+        .map(|i| {
+            let [x0, y0] = vertices[i];
+            let [x1, y1] = vertices[(i + 1) % vertices.len()];
+            x0 * y1 - x1 * y0
+        })
+        .sum::<f64>();
+    if doubled_area == 0.0 || !doubled_area.is_finite() {
+        return Vec::new();
+    }
+    let winding = doubled_area.signum();
+
+    let mut remaining = (0..vertices.len() as u32).collect::<Vec<_>>();
+    let mut triangles = Vec::with_capacity(vertices.len() - 2);
+
+    let mut index = 0;
+    let mut num_rejected = 0;
+    while remaining.len() > 3 && num_rejected < remaining.len() {
+        let len = remaining.len();
+        let prev = remaining[(index + len - 1) % len];
+        let current = remaining[index];
+        let next = remaining[(index + 1) % len];
+        let [a, b, c] = [prev, current, next].map(|i| vertices[i as usize]);
+
+        // An ear is a convex corner whose triangle contains no other vertex, not even on its edges.
+        let is_ear = cross(a, b, c) * winding > 0.0
+            && remaining.iter().all(|&other| {
+                let p = vertices[other as usize];
+                [a, b, c].contains(&p)
+                    || cross(a, b, p) * winding < 0.0
+                    || cross(b, c, p) * winding < 0.0
+                    || cross(c, a, p) * winding < 0.0
+            });
+
+        if is_ear {
+            triangles.push([prev, current, next]);
+            remaining.remove(index);
+            if index == remaining.len() {
+                index = 0;
+            }
+            num_rejected = 0;
+        } else {
+            index = (index + 1) % len;
+            num_rejected += 1;
+        }
+    }
+
+    if let [a, b, c] = remaining[..] {
+        triangles.push([a, b, c]);
+    }
+
+    triangles
+}
+
+impl TypedComponentFallbackProvider<Color> for GeoPolygonsVisualizer {
+    fn fallback_for(&self, ctx: &QueryContext<'_>) -> Color {
+        auto_color_for_entity_path(ctx.target_entity_path)
+    }
+}
+
+impl TypedComponentFallbackProvider<Radius> for GeoPolygonsVisualizer {
+    fn fallback_for(&self, _ctx: &QueryContext<'_>) -> Radius {
+        Radius::new_ui_points(2.0)
+    }
+}
+
+re_viewer_context::impl_component_fallback_provider!(GeoPolygonsVisualizer => [Color, Radius]);
+
+#[cfg(test)]
+mod tests {
+    use super::triangulate;
+
+    fn area(vertices: &[[f64; 2]]) -> f64 {
+        (0..vertices.len())
+            .map(|i| {
+                let [x0, y0] = vertices[i];
+                let [x1, y1] = vertices[(i + 1) % vertices.len()];
+                x0 * y1 - x1 * y0
+            })
+            .sum::<f64>()
+            .abs()
+            / 2.0
+    }
+
+    fn triangulated_area(vertices: &[[f64; 2]], triangles: &[[u32; 3]]) -> f64 {
+        triangles
+            .iter()
+            .map(|triangle| area(&triangle.map(|i| vertices[i as usize])))
+            .sum()
+    }
+
+    #[test]
+    fn triangulate_convex() {
+        let square = [[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]];
+        let triangles = triangulate(&square);
+        assert_eq!(triangles.len(), 2);
+        assert_eq!(triangulated_area(&square, &triangles), 1.0);
+    }
+
+    #[test]
+    fn triangulate_concave() {
+        let l_shape = vec![
+            [0.0, 0.0],
+            [2.0, 0.0],
+            [2.0, 1.0],
+            [1.0, 1.0],
+            [1.0, 2.0],
+            [0.0, 2.0],
+        ];
+        let u_shape = vec![
+            [0.0, 0.0],
+            [3.0, 0.0],
+            [3.0, 2.0],
+            [2.0, 2.0],
+            [2.0, 1.0],
+            [1.0, 1.0],
+            [1.0, 2.0],
+            [0.0, 2.0],
+        ];
+
+        // Both winding orders must work.
+        for (mut shape, expected_area) in [(l_shape, 3.0), (u_shape, 5.0)] {
+            for _ in 0..2 {
+                let triangles = triangulate(&shape);
+                assert_eq!(triangles.len(), shape.len() - 2);
+                assert_eq!(triangulated_area(&shape, &triangles), expected_area);
+                shape.reverse();
+            }
+        }
+    }
+
+    #[test]
+    fn triangulate_degenerate() {
+        assert!(triangulate(&[]).is_empty());
+        assert!(triangulate(&[[0.0, 0.0], [1.0, 1.0]]).is_empty());
+        assert!(triangulate(&[[0.0, 0.0], [1.0, 1.0], [2.0, 2.0]]).is_empty());
+    }
+}
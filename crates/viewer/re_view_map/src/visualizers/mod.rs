@@ -1,8 +1,43 @@
 mod geo_line_strings;
 mod geo_points;
+mod geo_polygons;
 
 pub use geo_line_strings::GeoLineStringsVisualizer;
 pub use geo_points::GeoPointsVisualizer;
+pub use geo_polygons::GeoPolygonsVisualizer;
+
+/// Projects geographic positions to the screen, like [`walkers::Projector`], but for a map that
+/// may be rotated around the center of the view.
+pub struct MapProjector {
+    projector: walkers::Projector,
+    rotation: egui::emath::Rot2,
+    center: egui::Pos2,
+}
+
+impl MapProjector {
+    pub fn new(
+        projector: walkers::Projector,
+        rotation: egui::emath::Rot2,
+        center: egui::Pos2,
+    ) -> Self {
+        Self {
+            projector,
+            rotation,
+            center,
+        }
+    }
+
+    /// Screen position of the given geographic position.
+    pub fn project(&self, position: walkers::Position) -> egui::Pos2 {
+        let unrotated = self.projector.project(position);
+        let unrotated = egui::pos2(unrotated.x, unrotated.y);
+        self.center + self.rotation * (unrotated - self.center)
+    }
+
+    pub fn scale_pixel_per_meter(&self, position: walkers::Position) -> f32 {
+        self.projector.scale_pixel_per_meter(position)
+    }
+}
 
 /// Helper to track an area span in latitude and longitude.
 #[derive(Debug, Clone)]
@@ -97,7 +132,7 @@ pub fn update_span(span: &mut Option<GeoSpan>, other: Option<GeoSpan>) {
 #[inline]
 pub fn radius_to_size(
     radius: re_types::components::Radius,
-    projector: &walkers::Projector,
+    projector: &MapProjector,
     position: walkers::Position,
 ) -> re_renderer::Size {
     re_renderer::Size(
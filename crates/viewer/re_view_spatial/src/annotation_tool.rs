@@ -0,0 +1,665 @@
+//! Drawing annotations on images in 2D views.
+//!
+//! Annotations are written to the annotation recording of the active recording, see
+//! [`re_viewer_context::annotation_recording_id`]. They are given in pixel coordinates of the
+//! annotated image and logged as children of the image's entity, so that they line up with the
+//! image when loaded alongside the annotated recording.
+//!
+//! Every annotation is logged to an entity of its own, so that annotations written from other
+//! views or in earlier sessions are never replaced.
+
+use re_chunk_store::{Chunk, RowId};
+use re_log_types::{EntityPath, TimeInt, TimePoint, Timeline};
+use re_types::{
+    AsComponents,
+    archetypes::{Boxes2D, LineStrips2D, Points2D},
+    components::ClassId,
+};
+use re_ui::{ContextExt as _, UiExt as _};
+use re_view::AnnotationSceneContext;
+use re_viewer_context::{
+    Item, SystemCommand, SystemCommandSender as _, ViewQuery, ViewerContext,
+    annotation_recording_id,
+};
+
+use crate::{
+    PickableRectSourceData, PickableTexturedRect,
+    picking::{PickingHitType, PickingResult},
+};
+
+/// The kind of shape drawn by the annotation tool.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AnnotationShape {
+    /// An axis-aligned box, drawn by clicking two opposite corners.
+    #[default]
+    Box,
+
+    /// A closed polygon, drawn by clicking its vertices.
+    Polygon,
+
+    /// A single point, drawn with a single click.
+    Keypoint,
+}
+
+impl AnnotationShape {
+    pub const ALL: [Self; 3] = [Self::Box, Self::Polygon, Self::Keypoint];
+
+    fn label(self) -> &'static str {
+        match self {
+            Self::Box => "Box",
+            Self::Polygon => "Polygon",
+            Self::Keypoint => "Keypoint",
+        }
+    }
+
+    /// Number of points after which the shape is complete, if it has a fixed number of points.
+    fn num_points(self) -> Option<usize> {
+        match self {
+            Self::Box => Some(2),
+            Self::Polygon => None,
+            Self::Keypoint => Some(1),
+        }
+    }
+
+    /// The entity an annotation of this shape on the given image is logged to.
+    ///
+    /// Named after the row the annotation is written with, which makes it unique.
+    fn entity_path(self, image: &EntityPath, row_id: RowId) -> EntityPath {
+        let name = match self {
+            Self::Box => "boxes",
+            Self::Polygon => "polygons",
+            Self::Keypoint => "keypoints",
+        };
+        image.join(&EntityPath::from(format!("annotations/{name}/{row_id}")))
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Annotation {
+    image: EntityPath,
+    timeline: Timeline,
+    time: TimeInt,
+    shape: AnnotationShape,
+    class_id: Option<ClassId>,
+
+    /// Corners, vertices or the keypoint, in pixel coordinates of the image.
+    points: Vec<glam::Vec2>,
+}
+
+/// The archetype an annotation is logged as.
+#[derive(Debug, PartialEq)]
+enum AnnotationArchetype {
+    Box(Boxes2D),
+    Polygon(LineStrips2D),
+    Keypoint(Points2D),
+}
+
+impl AnnotationArchetype {
+    fn as_components(&self) -> &dyn AsComponents {
+        match self {
+            Self::Box(boxes) => boxes,
+            Self::Polygon(strips) => strips,
+            Self::Keypoint(points) => points,
+        }
+    }
+}
+
+impl Annotation {
+    /// What this annotation is logged as, `None` if it doesn't have enough points yet.
+    fn archetype(&self) -> Option<AnnotationArchetype> {
+        let min_points = match self.shape {
+            AnnotationShape::Polygon => 3,
+            shape => shape.num_points().unwrap_or(1),
+        };
+        if self.points.len() < min_points {
+            return None;
+        }
+
+        Some(match self.shape {
+            AnnotationShape::Box => {
+                let (a, b) = (self.points[0], self.points[1]);
+                let (min, size) = (a.min(b), (b - a).abs());
+                let boxes = Boxes2D::from_mins_and_sizes([[min.x, min.y]], [[size.x, size.y]]);
+                AnnotationArchetype::Box(match self.class_id {
+                    Some(class_id) => boxes.with_class_ids([class_id]),
+                    None => boxes,
+                })
+            }
+            AnnotationShape::Polygon => {
+                let strips = LineStrips2D::new([self
+                    .points
+                    .iter()
+                    .chain(self.points.first())
+                    .map(|point| [point.x, point.y])
+                    .collect::<Vec<_>>()]);
+                AnnotationArchetype::Polygon(match self.class_id {
+                    Some(class_id) => strips.with_class_ids([class_id]),
+                    None => strips,
+                })
+            }
+            AnnotationShape::Keypoint => {
+                let points = Points2D::new([[self.points[0].x, self.points[0].y]]);
+                AnnotationArchetype::Keypoint(match self.class_id {
+                    Some(class_id) => points.with_class_ids([class_id]),
+                    None => points,
+                })
+            }
+        })
+    }
+}
+
+/// A class of the annotation context of the annotated image.
+#[derive(Clone, Debug)]
+struct AnnotationClass {
+    id: ClassId,
+    label: String,
+    color: Option<egui::Color32>,
+}
+
+/// State of the annotation tool of a 2D view.
+#[derive(Clone, Default)]
+pub struct AnnotationToolState {
+    /// Whether clicking images in the view draws annotations instead of selecting.
+    pub active: bool,
+
+    /// The shape drawn by the next clicks.
+    pub shape: AnnotationShape,
+
+    /// Class assigned to new annotations.
+    pub class_id: Option<ClassId>,
+
+    /// Classes of the annotation context of the image hovered last.
+    classes: Vec<AnnotationClass>,
+
+    /// The annotation being drawn.
+    pending: Option<Annotation>,
+
+    /// The hovered image and the hovered position on it, in pixel coordinates.
+    hovered: Option<(EntityPath, glam::Vec2)>,
+
+    /// Annotations written during this session, shown at the time they were made.
+    written: Vec<Annotation>,
+}
+
+impl AnnotationToolState {
+    /// Stops showing a hover preview, e.g. because the pointer left the view.
+    pub fn clear_hover(&mut self) {
+        self.hovered = None;
+    }
+
+    /// Draws annotations according to user input and writes completed ones.
+    pub fn handle_interaction<'a>(
+        &mut self,
+        ctx: &ViewerContext<'_>,
+        query: &ViewQuery<'_>,
+        response: &egui::Response,
+        picking_result: &PickingResult,
+        pickable_rects: impl Iterator<Item = &'a PickableTexturedRect>,
+        annotations: &AnnotationSceneContext,
+    ) {
+        let pickable_rects: Vec<_> = pickable_rects.collect();
+        self.hovered = picking_result
+            .hits
+            .iter()
+            .filter(|hit| hit.hit_type == PickingHitType::TexturedRect)
+            .find_map(|hit| {
+                let rect = pickable_rects.iter().find(|rect| {
+                    rect.ent_path.hash() == hit.instance_path_hash.entity_path_hash
+                        && !matches!(rect.source_data, PickableRectSourceData::ErrorPlaceholder)
+                })?;
+                Some((
+                    rect.ent_path.clone(),
+                    pixel_from_scene(ImagePlacement::from(*rect), hit.space_position),
+                ))
+            });
+
+        if let Some((image, _)) = &self.hovered {
+            self.update_classes(&annotations.0.find(image));
+        }
+
+        let timeline = *ctx.rec_cfg.time_ctrl.read().timeline();
+        let time = query.latest_at;
+
+        if response.clicked() {
+            if let Some((image, position)) = self.hovered.clone() {
+                // Start over when moving on to another image, time or shape.
+                let continues_pending = self.pending.as_ref().is_some_and(|pending| {
+                    pending.image == image
+                        && pending.timeline == timeline
+                        && pending.time == time
+                        && pending.shape == self.shape
+                });
+                if !continues_pending {
+                    self.pending = Some(Annotation {
+                        image,
+                        timeline,
+                        time,
+                        shape: self.shape,
+                        class_id: self.class_id,
+                        points: Vec::new(),
+                    });
+                }
+                let Some(pending) = &mut self.pending else {
+                    return;
+                };
+
+                // A double click shows up as two clicks at the same spot.
+                if pending.points.last() != Some(&position) {
+                    pending.points.push(position);
+                }
+                if pending.shape.num_points() == Some(pending.points.len()) {
+                    self.complete(ctx);
+                }
+            }
+        }
+
+        if !response.hovered() {
+            return;
+        }
+        let (complete, remove_last, discard) = response.ctx.input(|i| {
+            (
+                i.key_pressed(egui::Key::Enter),
+                i.key_pressed(egui::Key::Backspace),
+                i.key_pressed(egui::Key::Escape),
+            )
+        });
+        let drawing_polygon = self
+            .pending
+            .as_ref()
+            .is_some_and(|pending| pending.shape == AnnotationShape::Polygon);
+        if drawing_polygon && (complete || response.secondary_clicked()) {
+            self.complete(ctx);
+        } else if remove_last {
+            if let Some(pending) = &mut self.pending {
+                pending.points.pop();
+            }
+        } else if discard {
+            self.pending = None;
+        }
+    }
+
+    fn update_classes(&mut self, annotations: &re_viewer_context::Annotations) {
+        self.classes = annotations
+            .class_descriptions()
+            .into_iter()
+            .map(|description| {
+                let info = &description.info;
+                AnnotationClass {
+                    id: ClassId::from(info.id),
+                    label: info
+                        .label
+                        .as_ref()
+                        .map_or_else(String::new, |label| label.to_string()),
+                    color: info.color.map(|color| color.into()),
+                }
+            })
+            .collect();
+
+        if self
+            .class_id
+            .is_some_and(|class_id| !self.classes.iter().any(|class| class.id == class_id))
+        {
+            self.class_id = None;
+        }
+    }
+
+    /// Writes the pending annotation, if it is complete.
+    fn complete(&mut self, ctx: &ViewerContext<'_>) {
+        let Some(annotation) = self.pending.take() else {
+            return;
+        };
+        let Some(archetype) = annotation.archetype() else {
+            return;
+        };
+
+        let row_id = RowId::new();
+        let entity_path = annotation.shape.entity_path(&annotation.image, row_id);
+        let timepoint = TimePoint::from([(annotation.timeline, annotation.time)]);
+        self.written.push(annotation);
+
+        match Chunk::builder(entity_path)
+            .with_archetype(row_id, timepoint, archetype.as_components())
+            .build()
+        {
+            Ok(chunk) => {
+                ctx.command_sender()
+                    .send_system(SystemCommand::AppendToAnnotationRecording {
+                        annotated_recording: ctx.recording_id(),
+                        chunks: vec![chunk],
+                    })
+            }
+            Err(err) => re_log::error_once!("Failed to create chunk for annotations: {err}"),
+        }
+    }
+
+    /// Toggle, shape and class pickers, shown in the selection panel of the view.
+    pub fn selection_ui(&mut self, ctx: &ViewerContext<'_>, ui: &mut egui::Ui) {
+        ui.grid_left_hand_label("Annotate")
+            .on_hover_text("Draw annotations on images");
+        ui.vertical(|ui| {
+            if ui
+                .re_checkbox(&mut self.active, "Draw annotations")
+                .on_hover_text(
+                    "Click images in the view to draw the selected shape.\n\
+                    Right-click or press Enter to close a polygon, \
+                    Backspace removes the last point, Escape discards the shape.",
+                )
+                .changed()
+                && !self.active
+            {
+                self.pending = None;
+                self.hovered = None;
+            }
+
+            ui.selectable_toggle(|ui| {
+                for shape in AnnotationShape::ALL {
+                    ui.selectable_value(&mut self.shape, shape, shape.label());
+                }
+            });
+
+            let class_label = |class_id: Option<ClassId>| match class_id {
+                Some(class_id) => self
+                    .classes
+                    .iter()
+                    .find(|class| class.id == class_id)
+                    .filter(|class| !class.label.is_empty())
+                    .map_or_else(
+                        || format!("Class {}", class_id.0.0),
+                        |class| format!("{} ({})", class.label, class_id.0.0),
+                    ),
+                None => "No class".to_owned(),
+            };
+            let mut class_id = self.class_id;
+            egui::ComboBox::from_id_salt("annotation_class")
+                .selected_text(class_label(class_id))
+                .show_ui(ui, |ui| {
+                    ui.selectable_value(&mut class_id, None, class_label(None));
+                    for class in &self.classes {
+                        ui.selectable_value(
+                            &mut class_id,
+                            Some(class.id),
+                            class_label(Some(class.id)),
+                        );
+                    }
+                })
+                .response
+                .on_hover_text("Classes come from the annotation context of the hovered image");
+            self.class_id = class_id;
+
+            if !self.written.is_empty() {
+                ui.label(format!("{} annotations written", self.written.len()));
+                if ui
+                    .button("Select annotation recording")
+                    .on_hover_text(
+                        "The annotations are kept in a recording of their own.\n\
+                        Select it and save it to store the annotations as an .rrd file.",
+                    )
+                    .clicked()
+                {
+                    ctx.selection_state()
+                        .set_selection(Item::StoreId(annotation_recording_id(&ctx.recording_id())));
+                }
+            }
+        });
+        ui.end_row();
+    }
+
+    /// Egui shapes for the annotations written at the current time and the one being drawn.
+    ///
+    /// Annotations on images that aren't shown in the view are skipped.
+    pub fn shapes<'a>(
+        &self,
+        ui: &egui::Ui,
+        query: &ViewQuery<'_>,
+        pickable_rects: impl Iterator<Item = &'a PickableTexturedRect>,
+        ui_from_scene: egui::emath::RectTransform,
+    ) -> Vec<egui::Shape> {
+        let pickable_rects: Vec<_> = pickable_rects.collect();
+        let ui_from_pixel = |image: &EntityPath, point: glam::Vec2| {
+            let rect = pickable_rects.iter().find(|rect| rect.ent_path == *image)?;
+            let pos = scene_from_pixel(ImagePlacement::from(*rect), point);
+            Some(ui_from_scene.transform_pos(egui::pos2(pos.x, pos.y)))
+        };
+
+        let mut shapes = Vec::new();
+
+        let written = self.written.iter().filter(|annotation| {
+            *annotation.timeline.name() == query.timeline && annotation.time == query.latest_at
+        });
+        for annotation in written {
+            let color = self.class_color(ui, annotation.class_id);
+            annotation_shapes(annotation, color, false, &ui_from_pixel, &mut shapes);
+        }
+
+        if self.active {
+            if let Some(pending) = &self.pending {
+                let mut preview = pending.clone();
+                if let Some((image, position)) = &self.hovered {
+                    if *image == preview.image {
+                        preview.points.push(*position);
+                    }
+                }
+                let color = ui.ctx().hover_stroke().color;
+                annotation_shapes(&preview, color, true, &ui_from_pixel, &mut shapes);
+            } else if let Some((image, position)) = &self.hovered {
+                if let Some(pos) = ui_from_pixel(image, *position) {
+                    shapes.push(egui::Shape::circle_filled(
+                        pos,
+                        3.0,
+                        ui.ctx().hover_stroke().color,
+                    ));
+                }
+            }
+        }
+
+        shapes
+    }
+
+    fn class_color(&self, ui: &egui::Ui, class_id: Option<ClassId>) -> egui::Color32 {
+        class_id
+            .and_then(|class_id| self.classes.iter().find(|class| class.id == class_id))
+            .and_then(|class| class.color)
+            .unwrap_or_else(|| ui.ctx().selection_stroke().color)
+    }
+}
+
+/// Adds the outline and vertices of an annotation.
+///
+/// Pending annotations are drawn open, i.e. polygons aren't closed yet.
+fn annotation_shapes(
+    annotation: &Annotation,
+    color: egui::Color32,
+    pending: bool,
+    ui_from_pixel: &impl Fn(&EntityPath, glam::Vec2) -> Option<egui::Pos2>,
+    shapes: &mut Vec<egui::Shape>,
+) {
+    let stroke = egui::Stroke::new(2.0, color);
+    let points: Vec<egui::Pos2> = match (annotation.shape, annotation.points.as_slice()) {
+        (AnnotationShape::Box, [a, b]) => [
+            glam::vec2(a.x, a.y),
+            glam::vec2(b.x, a.y),
+            glam::vec2(b.x, b.y),
+            glam::vec2(a.x, b.y),
+        ]
+        .into_iter()
+        .filter_map(|point| ui_from_pixel(&annotation.image, point))
+        .collect(),
+        (_, points) => points
+            .iter()
+            .filter_map(|point| ui_from_pixel(&annotation.image, *point))
+            .collect(),
+    };
+
+    let closed = annotation.shape == AnnotationShape::Box
+        || (annotation.shape == AnnotationShape::Polygon && !pending);
+    if closed && points.len() > 2 {
+        shapes.push(egui::Shape::closed_line(points.clone(), stroke));
+    } else if points.len() > 1 {
+        shapes.push(egui::Shape::line(points.clone(), stroke));
+    }
+
+    if annotation.shape != AnnotationShape::Box || pending {
+        for point in points {
+            shapes.push(egui::Shape::circle_filled(point, 3.0, color));
+        }
+    }
+}
+
+/// Where an image is shown in the scene, and its size in pixels.
+#[derive(Clone, Copy, Debug)]
+struct ImagePlacement {
+    top_left_corner_position: glam::Vec3,
+    extent_u: glam::Vec3,
+    extent_v: glam::Vec3,
+    resolution: [u32; 2],
+}
+
+impl From<&PickableTexturedRect> for ImagePlacement {
+    fn from(rect: &PickableTexturedRect) -> Self {
+        Self {
+            top_left_corner_position: rect.textured_rect.top_left_corner_position,
+            extent_u: rect.textured_rect.extent_u,
+            extent_v: rect.textured_rect.extent_v,
+            resolution: rect.resolution(),
+        }
+    }
+}
+
+/// Position on an image in pixel coordinates, clamped to the image.
+fn pixel_from_scene(image: ImagePlacement, position: glam::Vec3) -> glam::Vec2 {
+    let [width, height] = image.resolution;
+    let from_top_left = position - image.top_left_corner_position;
+    let u = from_top_left.dot(image.extent_u) / image.extent_u.length_squared();
+    let v = from_top_left.dot(image.extent_v) / image.extent_v.length_squared();
+    glam::vec2(
+        u.clamp(0.0, 1.0) * width as f32,
+        v.clamp(0.0, 1.0) * height as f32,
+    )
+}
+
+/// Inverse of [`pixel_from_scene`].
+fn scene_from_pixel(image: ImagePlacement, point: glam::Vec2) -> glam::Vec3 {
+    let [width, height] = image.resolution;
+    image.top_left_corner_position
+        + image.extent_u * (point.x / width.max(1) as f32)
+        + image.extent_v * (point.y / height.max(1) as f32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn annotation(
+        shape: AnnotationShape,
+        class_id: Option<u16>,
+        points: &[[f32; 2]],
+    ) -> Annotation {
+        Annotation {
+            image: EntityPath::from("camera/image"),
+            timeline: Timeline::new_sequence("frame"),
+            time: TimeInt::new_temporal(42),
+            shape,
+            class_id: class_id.map(ClassId::from),
+            points: points.iter().copied().map(glam::Vec2::from).collect(),
+        }
+    }
+
+    #[test]
+    fn test_annotation_entity_paths() {
+        let image = EntityPath::from("camera/image");
+        let row_id = RowId::new();
+        assert_eq!(
+            AnnotationShape::Box.entity_path(&image, row_id),
+            EntityPath::from(format!("camera/image/annotations/boxes/{row_id}"))
+        );
+        assert_eq!(
+            AnnotationShape::Polygon.entity_path(&image, row_id),
+            EntityPath::from(format!("camera/image/annotations/polygons/{row_id}"))
+        );
+        assert_eq!(
+            AnnotationShape::Keypoint.entity_path(&image, row_id),
+            EntityPath::from(format!("camera/image/annotations/keypoints/{row_id}"))
+        );
+
+        // Annotations of the same shape on the same image never share an entity.
+        assert_ne!(
+            AnnotationShape::Box.entity_path(&image, RowId::new()),
+            AnnotationShape::Box.entity_path(&image, RowId::new())
+        );
+    }
+
+    #[test]
+    fn test_annotation_archetypes() {
+        // Corners can be clicked in any order.
+        assert_eq!(
+            annotation(AnnotationShape::Box, None, &[[30.0, 10.0], [10.0, 50.0]]).archetype(),
+            Some(AnnotationArchetype::Box(Boxes2D::from_mins_and_sizes(
+                [[10.0, 10.0]],
+                [[20.0, 40.0]]
+            )))
+        );
+
+        // Polygons are closed.
+        assert_eq!(
+            annotation(
+                AnnotationShape::Polygon,
+                Some(3),
+                &[[0.0, 0.0], [10.0, 0.0], [0.0, 10.0]]
+            )
+            .archetype(),
+            Some(AnnotationArchetype::Polygon(
+                LineStrips2D::new([[[0.0, 0.0], [10.0, 0.0], [0.0, 10.0], [0.0, 0.0]]])
+                    .with_class_ids([ClassId::from(3)])
+            ))
+        );
+
+        assert_eq!(
+            annotation(AnnotationShape::Keypoint, Some(0), &[[5.0, 6.0]]).archetype(),
+            Some(AnnotationArchetype::Keypoint(
+                Points2D::new([[5.0, 6.0]]).with_class_ids([ClassId::from(0)])
+            ))
+        );
+
+        // Not enough points yet.
+        assert_eq!(
+            annotation(AnnotationShape::Box, None, &[[30.0, 10.0]]).archetype(),
+            None
+        );
+        assert_eq!(
+            annotation(AnnotationShape::Polygon, None, &[[0.0, 0.0], [10.0, 0.0]]).archetype(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_pixel_scene_roundtrip() {
+        // A rotated image, whose pixels aren't square in the scene.
+        let image = ImagePlacement {
+            top_left_corner_position: glam::vec3(10.0, 20.0, 1.0),
+            extent_u: glam::vec3(3.0, 4.0, 0.0) * 40.0,
+            extent_v: glam::vec3(-4.0, 3.0, 0.0) * 30.0,
+            resolution: [640, 480],
+        };
+
+        for point in [
+            glam::vec2(0.0, 0.0),
+            glam::vec2(640.0, 480.0),
+            glam::vec2(320.5, 100.25),
+        ] {
+            let roundtrip = pixel_from_scene(image, scene_from_pixel(image, point));
+            assert!(
+                roundtrip.abs_diff_eq(point, 1e-3),
+                "{point} became {roundtrip}"
+            );
+        }
+
+        assert_eq!(
+            scene_from_pixel(image, glam::vec2(640.0, 0.0)),
+            glam::vec3(130.0, 180.0, 1.0)
+        );
+
+        // Positions off the image are clamped to its edge.
+        let outside = scene_from_pixel(image, glam::vec2(700.0, -10.0));
+        assert!(pixel_from_scene(image, outside).abs_diff_eq(glam::vec2(640.0, 0.0), 1e-3));
+    }
+}
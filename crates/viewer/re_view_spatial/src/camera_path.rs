@@ -0,0 +1,526 @@
+//! Keyframed fly-through paths for the eye of the 3D view.
+//!
+//! The keyframes are stored in the [`CameraPath`] view property, so they can be placed from the
+//! selection panel as well as logged from the SDK. Between keyframes, positions and look targets
+//! follow a cubic spline that comes to a smooth stop at the first and last keyframe.
+
+use glam::{Affine3A, Quat, Vec3};
+
+use re_log_types::{EntityPath, TimeInt, TimelineName};
+use re_renderer::{LineDrawableBuilder, Size};
+use re_types::{
+    DeserializationError,
+    blueprint::{
+        archetypes::CameraPath,
+        components::{Enabled, KeyframeTime, TimelineName as TimelineNameComponent},
+    },
+    components::{self, Position3D},
+};
+use re_ui::UiExt as _;
+use re_viewer_context::{ViewId, ViewerContext};
+use re_viewport_blueprint::ViewProperty;
+
+use crate::{eye::ViewEye, ui_3d::View3DState};
+
+/// Where the eye is, and what it looks at.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CameraPathPose {
+    pub position: Vec3,
+    pub look_target: Vec3,
+}
+
+impl CameraPathPose {
+    pub fn transformed(self, transform: &Affine3A) -> Self {
+        Self {
+            position: transform.transform_point3(self.position),
+            look_target: transform.transform_point3(self.look_target),
+        }
+    }
+
+    /// An orbital eye at this pose, orbiting around the look target.
+    ///
+    /// Returns `None` if the eye would look at its own position.
+    pub fn to_view_eye(self, eye_up: Vec3) -> Option<ViewEye> {
+        let orbit_radius = self.position.distance(self.look_target);
+        if !orbit_radius.is_finite() || orbit_radius <= f32::EPSILON {
+            return None;
+        }
+
+        // Looking straight along the up-axis leaves the roll undefined.
+        let forward = (self.look_target - self.position) / orbit_radius;
+        let look_at_up = if forward.cross(eye_up).length_squared() > 1e-6 {
+            eye_up
+        } else {
+            forward.any_orthogonal_vector()
+        };
+
+        Some(ViewEye::new_orbital(
+            self.look_target,
+            orbit_radius,
+            Quat::from_affine3(
+                &Affine3A::look_at_rh(self.position, self.look_target, look_at_up).inverse(),
+            ),
+            eye_up,
+        ))
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CameraKeyframe {
+    pub time: TimeInt,
+    pub pose: CameraPathPose,
+}
+
+/// The contents of the [`CameraPath`] view property of a 3D view.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct KeyframedCameraPath {
+    /// An unset [`CameraPath::enabled`] counts as enabled,
+    /// so that paths logged from the SDK are followed right away.
+    pub enabled: bool,
+
+    pub timeline: Option<TimelineName>,
+
+    /// Sorted by time, with at most one keyframe per time.
+    pub keyframes: Vec<CameraKeyframe>,
+
+    /// If set, the poses of all keyframes are in the space of this entity.
+    pub chase_entity: Option<EntityPath>,
+}
+
+impl KeyframedCameraPath {
+    pub fn load(ctx: &ViewerContext<'_>, view_id: ViewId) -> Result<Self, DeserializationError> {
+        let property = ViewProperty::from_archetype::<CameraPath>(
+            ctx.blueprint_db(),
+            ctx.blueprint_query,
+            view_id,
+        );
+
+        let enabled = property
+            .component_or_empty::<Enabled>(&CameraPath::descriptor_enabled())?
+            .is_none_or(|enabled| **enabled);
+        let timeline = property
+            .component_or_empty::<TimelineNameComponent>(&CameraPath::descriptor_timeline())?
+            .map(TimelineName::from);
+        let chase_entity = property
+            .component_or_empty::<components::EntityPath>(&CameraPath::descriptor_chase_entity())?
+            .map(|entity| entity.as_str().into());
+
+        let times =
+            property.component_array_or_empty::<KeyframeTime>(&CameraPath::descriptor_times())?;
+        let positions =
+            property.component_array_or_empty::<Position3D>(&CameraPath::descriptor_positions())?;
+        let look_targets = property
+            .component_array_or_empty::<Position3D>(&CameraPath::descriptor_look_targets())?;
+
+        let mut keyframes: Vec<CameraKeyframe> = itertools::izip!(times, positions, look_targets)
+            .map(|(time, position, look_target)| CameraKeyframe {
+                time: TimeInt::from(time.0),
+                pose: CameraPathPose {
+                    position: position.into(),
+                    look_target: look_target.into(),
+                },
+            })
+            .collect();
+        // The sort is stable, so the keyframe logged first wins if several share a time.
+        keyframes.sort_by_key(|keyframe| keyframe.time);
+        keyframes.dedup_by_key(|keyframe| keyframe.time);
+
+        Ok(Self {
+            enabled,
+            timeline,
+            keyframes,
+            chase_entity,
+        })
+    }
+
+    /// Replace the [`CameraPath`] of the view with this path.
+    pub fn save(&self, ctx: &ViewerContext<'_>, view_id: ViewId) {
+        let property = ViewProperty::from_archetype::<CameraPath>(
+            ctx.blueprint_db(),
+            ctx.blueprint_query,
+            view_id,
+        );
+
+        // Start out cleared, so that unset fields are removed from the blueprint.
+        let mut camera_path = CameraPath::clear_fields()
+            .with_enabled(self.enabled)
+            .with_times(self.keyframes.iter().map(|keyframe| keyframe.time))
+            .with_positions(
+                self.keyframes
+                    .iter()
+                    .map(|keyframe| Position3D::from(keyframe.pose.position)),
+            )
+            .with_look_targets(
+                self.keyframes
+                    .iter()
+                    .map(|keyframe| Position3D::from(keyframe.pose.look_target)),
+            );
+        if let Some(timeline) = &self.timeline {
+            camera_path = camera_path.with_timeline(timeline.as_str());
+        }
+        if let Some(chase_entity) = &self.chase_entity {
+            camera_path = camera_path
+                .with_chase_entity(components::EntityPath::from(chase_entity.to_string()));
+        }
+
+        ctx.save_blueprint_archetype(property.blueprint_store_path, &camera_path);
+    }
+
+    /// Whether the eye should follow this path while the given timeline is active.
+    pub fn is_active_on(&self, timeline: &TimelineName) -> bool {
+        self.enabled && self.timeline.as_ref() == Some(timeline) && !self.keyframes.is_empty()
+    }
+
+    /// Adds a keyframe, replacing any keyframe at the same time.
+    pub fn insert_keyframe(&mut self, keyframe: CameraKeyframe) {
+        match self
+            .keyframes
+            .binary_search_by_key(&keyframe.time, |keyframe| keyframe.time)
+        {
+            Ok(index) => self.keyframes[index] = keyframe,
+            Err(index) => self.keyframes.insert(index, keyframe),
+        }
+    }
+
+    /// The pose of the eye at the given time.
+    ///
+    /// Holds still before the first and after the last keyframe.
+    pub fn sample(&self, time: f64) -> Option<CameraPathPose> {
+        let first = self.keyframes.first()?;
+        let last = self.keyframes.last()?;
+        if time <= first.time.as_i64() as f64 {
+            return Some(first.pose);
+        }
+        if time >= last.time.as_i64() as f64 {
+            return Some(last.pose);
+        }
+
+        // `time` lies strictly between the first and the last keyframe,
+        // so there is a keyframe on either side of it.
+        let next = self
+            .keyframes
+            .partition_point(|keyframe| keyframe.time.as_i64() as f64 <= time);
+        let previous = next - 1;
+
+        let start_time = self.keyframes[previous].time.as_i64() as f64;
+        let end_time = self.keyframes[next].time.as_i64() as f64;
+        let duration = end_time - start_time;
+        let t = ((time - start_time) / duration) as f32;
+
+        let interpolate = |point: fn(&CameraPathPose) -> Vec3| {
+            let start_tangent = self.tangent(previous, point) * duration as f32;
+            let end_tangent = self.tangent(next, point) * duration as f32;
+            hermite(
+                point(&self.keyframes[previous].pose),
+                start_tangent,
+                point(&self.keyframes[next].pose),
+                end_tangent,
+                t,
+            )
+        };
+
+        Some(CameraPathPose {
+            position: interpolate(|pose| pose.position),
+            look_target: interpolate(|pose| pose.look_target),
+        })
+    }
+
+    /// Draws the path of the eye, and the direction it looks in at every keyframe.
+    pub fn add_lines(
+        &self,
+        line_builder: &mut LineDrawableBuilder<'_>,
+        reference_from_path: &Affine3A,
+        color: egui::Color32,
+    ) {
+        const SAMPLES_PER_SEGMENT: usize = 32;
+
+        let (Some(first), Some(last)) = (self.keyframes.first(), self.keyframes.last()) else {
+            return;
+        };
+        let start_time = first.time.as_i64() as f64;
+        let end_time = last.time.as_i64() as f64;
+        let num_samples = SAMPLES_PER_SEGMENT * (self.keyframes.len() - 1);
+
+        let strip: Vec<Vec3> = (0..=num_samples)
+            .filter_map(|sample| {
+                let t = sample as f64 / num_samples.max(1) as f64;
+                self.sample(start_time + t * (end_time - start_time))
+            })
+            .map(|pose| reference_from_path.transform_point3(pose.position))
+            .collect();
+
+        let mut batch = line_builder.batch("camera path");
+        if strip.len() >= 2 {
+            batch
+                .add_strip(strip.into_iter())
+                .radius(Size::new_ui_points(1.0))
+                .color(color);
+        }
+        batch
+            .add_segments(self.keyframes.iter().map(|keyframe| {
+                let pose = keyframe.pose.transformed(reference_from_path);
+                (pose.position, pose.look_target)
+            }))
+            .radius(Size::new_ui_points(0.5))
+            .color(color.gamma_multiply(0.5));
+    }
+
+    /// Catmull-Rom style tangent at a keyframe, per unit of time.
+    ///
+    /// The first and last keyframe have a zero tangent so the eye eases in and out of the path.
+    fn tangent(&self, index: usize, point: fn(&CameraPathPose) -> Vec3) -> Vec3 {
+        if index == 0 || index + 1 >= self.keyframes.len() {
+            return Vec3::ZERO;
+        }
+
+        let before = &self.keyframes[index - 1];
+        let after = &self.keyframes[index + 1];
+        let duration = (after.time.as_i64() - before.time.as_i64()) as f32;
+        (point(&after.pose) - point(&before.pose)) / duration
+    }
+}
+
+fn hermite(start: Vec3, start_tangent: Vec3, end: Vec3, end_tangent: Vec3, t: f32) -> Vec3 {
+    let t2 = t * t;
+    let t3 = t2 * t;
+    (2.0 * t3 - 3.0 * t2 + 1.0) * start
+        + (t3 - 2.0 * t2 + t) * start_tangent
+        + (-2.0 * t3 + 3.0 * t2) * end
+        + (t3 - t2) * end_tangent
+}
+
+/// Editor for the camera path in the selection panel.
+///
+/// Returns `true` if the path was changed and needs to be saved.
+pub fn camera_path_ui(
+    ctx: &ViewerContext<'_>,
+    ui: &mut egui::Ui,
+    state_3d: &View3DState,
+    space_origin: &EntityPath,
+    path: &mut KeyframedCameraPath,
+) -> bool {
+    let mut changed = false;
+
+    changed |= ui
+        .re_checkbox(&mut path.enabled, "Follow path")
+        .on_hover_text(
+            "Move the camera along the keyframes as the time cursor moves.\n\
+            Interacting with the view takes over the camera until the time changes.",
+        )
+        .changed();
+
+    let (current_timeline, current_time, time_type) = {
+        let time_ctrl = ctx.rec_cfg.time_ctrl.read();
+        (
+            *time_ctrl.timeline().name(),
+            time_ctrl.time_int(),
+            time_ctrl.time_type(),
+        )
+    };
+    let format_time = |time: TimeInt| time_type.format(time, ctx.app_options().timestamp_format);
+
+    match &path.timeline {
+        Some(timeline) if !path.keyframes.is_empty() => {
+            ui.label(format!(
+                "{} keyframes on timeline {timeline}",
+                path.keyframes.len()
+            ));
+        }
+        _ => {
+            ui.weak("No keyframes");
+        }
+    }
+
+    // Keyframes are stored relative to the chased entity, if there is one.
+    let chase_from_world = if path.chase_entity.is_some() {
+        state_3d
+            .camera_path_reference_from_chase
+            .map(|reference_from_chase| reference_from_chase.inverse())
+    } else {
+        Some(Affine3A::IDENTITY)
+    };
+
+    let disabled_reason = if path
+        .timeline
+        .is_some_and(|timeline| timeline != current_timeline)
+    {
+        Some("The keyframes of this path are on a different timeline")
+    } else if current_time.is_none() {
+        Some("There is no current time")
+    } else if chase_from_world.is_none() {
+        Some("The chased entity is not part of this view")
+    } else {
+        None
+    };
+
+    let add_response = ui
+        .add_enabled(
+            disabled_reason.is_none(),
+            egui::Button::new("Add keyframe at current time"),
+        )
+        .on_hover_text("Adds the current camera pose as a keyframe")
+        .on_disabled_hover_text(disabled_reason.unwrap_or_default());
+    if add_response.clicked() {
+        if let (Some(time), Some(chase_from_world), Some(view_eye)) =
+            (current_time, chase_from_world, state_3d.view_eye)
+        {
+            let pose = CameraPathPose {
+                position: view_eye.position(),
+                look_target: view_eye.look_target(),
+            };
+            path.timeline = Some(current_timeline);
+            path.insert_keyframe(CameraKeyframe {
+                time,
+                pose: pose.transformed(&chase_from_world),
+            });
+            changed = true;
+        }
+    }
+
+    let mut removed_keyframe = None;
+    for (index, keyframe) in path.keyframes.iter().enumerate() {
+        ui.horizontal(|ui| {
+            if ui
+                .button(format_time(keyframe.time))
+                .on_hover_text("Move the time cursor to this keyframe")
+                .clicked()
+            {
+                ctx.rec_cfg.time_ctrl.write().set_time(keyframe.time);
+            }
+            if ui
+                .small_icon_button(&re_ui::icons::REMOVE, "Remove keyframe")
+                .clicked()
+            {
+                removed_keyframe = Some(index);
+            }
+        });
+    }
+    if let Some(index) = removed_keyframe {
+        path.keyframes.remove(index);
+        changed = true;
+    }
+
+    if !path.keyframes.is_empty() && ui.button("Clear keyframes").clicked() {
+        path.keyframes.clear();
+        path.timeline = None;
+        changed = true;
+    }
+
+    let chase_label = |entity: Option<&EntityPath>| {
+        entity.map_or_else(|| "Nothing".to_owned(), |entity| entity.to_string())
+    };
+    let mut chase_entity = path.chase_entity.clone();
+    egui::ComboBox::from_id_salt("camera_path_chase_entity")
+        .selected_text(format!("Chase {}", chase_label(chase_entity.as_ref())))
+        .show_ui(ui, |ui| {
+            ui.selectable_value(&mut chase_entity, None, chase_label(None));
+            for entity in ctx.recording().entity_paths() {
+                if entity.starts_with(space_origin) && entity != space_origin {
+                    ui.selectable_value(
+                        &mut chase_entity,
+                        Some(entity.clone()),
+                        chase_label(Some(entity)),
+                    );
+                }
+            }
+        })
+        .response
+        .on_hover_text(
+            "Attach the path to an entity, e.g. to follow a vehicle.\n\
+            Keyframes are relative to the chased entity.",
+        );
+    if chase_entity != path.chase_entity {
+        path.chase_entity = chase_entity;
+        changed = true;
+    }
+
+    changed
+}
+
+#[cfg(test)]
+mod tests {
+    use glam::Vec3;
+    use re_log_types::TimeInt;
+
+    use super::{CameraKeyframe, CameraPathPose, KeyframedCameraPath};
+
+    fn keyframe(time: i64, x: f32) -> CameraKeyframe {
+        CameraKeyframe {
+            time: TimeInt::new_temporal(time),
+            pose: CameraPathPose {
+                position: Vec3::new(x, 0.0, 1.0),
+                look_target: Vec3::new(x, 1.0, 0.0),
+            },
+        }
+    }
+
+    #[test]
+    fn sample_camera_path() {
+        let mut path = KeyframedCameraPath::default();
+        assert_eq!(path.sample(0.0), None);
+
+        // Inserted out of order; the keyframe at 20 is replaced.
+        path.insert_keyframe(keyframe(20, 5.0));
+        path.insert_keyframe(keyframe(10, 0.0));
+        path.insert_keyframe(keyframe(30, 20.0));
+        path.insert_keyframe(keyframe(20, 10.0));
+        assert_eq!(
+            path.keyframes
+                .iter()
+                .map(|keyframe| keyframe.time.as_i64())
+                .collect::<Vec<_>>(),
+            vec![10, 20, 30]
+        );
+
+        // Holds still outside of the keyframes.
+        assert_eq!(path.sample(0.0), Some(keyframe(10, 0.0).pose));
+        assert_eq!(path.sample(100.0), Some(keyframe(30, 20.0).pose));
+
+        // Passes through every keyframe.
+        for keyframe in path.keyframes.clone() {
+            let pose = path.sample(keyframe.time.as_i64() as f64).unwrap();
+            assert!(pose.position.abs_diff_eq(keyframe.pose.position, 1e-5));
+            assert!(
+                pose.look_target
+                    .abs_diff_eq(keyframe.pose.look_target, 1e-5)
+            );
+        }
+
+        // Moves monotonically for monotonic keyframes.
+        let xs: Vec<f32> = (10..=30)
+            .map(|time| path.sample(time as f64).unwrap().position.x)
+            .collect();
+        assert!(xs.windows(2).all(|pair| pair[0] <= pair[1]));
+
+        // Eases symmetrically between the two keyframes of a two keyframe path.
+        path.keyframes.pop();
+        let halfway = path.sample(15.0).unwrap();
+        assert!(halfway.position.abs_diff_eq(Vec3::new(5.0, 0.0, 1.0), 1e-5));
+    }
+
+    #[test]
+    fn pose_to_view_eye() {
+        let pose = CameraPathPose {
+            position: Vec3::new(0.0, -4.0, 0.0),
+            look_target: Vec3::ZERO,
+        };
+        let eye = pose.to_view_eye(Vec3::Z).unwrap();
+        assert!(eye.position().abs_diff_eq(pose.position, 1e-5));
+        assert!(eye.look_target().abs_diff_eq(pose.look_target, 1e-5));
+
+        // Looking straight down still works.
+        let top_down = CameraPathPose {
+            position: Vec3::new(0.0, 0.0, 4.0),
+            look_target: Vec3::ZERO,
+        };
+        let eye = top_down.to_view_eye(Vec3::Z).unwrap();
+        assert!(eye.position().abs_diff_eq(top_down.position, 1e-5));
+
+        // An eye looking at itself has no direction.
+        let degenerate = CameraPathPose {
+            position: Vec3::ONE,
+            look_target: Vec3::ONE,
+        };
+        assert!(degenerate.to_view_eye(Vec3::Z).is_none());
+    }
+}
@@ -31,6 +31,7 @@ impl ViewContextSystem for EntityDepthOffsets {
                 archetypes::Arrows2D::descriptor_indicator(),
                 archetypes::Boxes2D::descriptor_indicator(),
                 archetypes::DepthImage::descriptor_indicator(),
+                archetypes::EncodedDepthImage::descriptor_indicator(),
                 archetypes::EncodedImage::descriptor_indicator(),
                 archetypes::Image::descriptor_indicator(),
                 archetypes::LineStrips2D::descriptor_indicator(),
@@ -99,6 +99,12 @@ impl TransformInfo {
         }
     }
 
+    /// The transform from the entity to the reference space, without per instance poses.
+    #[inline]
+    pub fn reference_from_entity(&self) -> glam::Affine3A {
+        self.reference_from_entity
+    }
+
     /// Returns reference from instance transforms.
     #[inline]
     pub fn reference_from_instances(
@@ -288,6 +288,16 @@ impl ViewEye {
         }
     }
 
+    /// The world-space point the eye looks at.
+    ///
+    /// In first person mode, this is the point the orbit center would be at.
+    pub fn look_target(&self) -> Vec3 {
+        match self.mode {
+            EyeMode::FirstPerson => self.center + self.orbit_radius * self.fwd(),
+            EyeMode::Orbital => self.center,
+        }
+    }
+
     /// Compute the actual speed depending on the [`EyeMode`].
     fn fallback_speed_for_mode(&self, bounding_boxes: &SceneBoundingBoxes) -> f32 {
         match self.mode {
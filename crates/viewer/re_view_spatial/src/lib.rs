@@ -5,10 +5,13 @@
 // TODO(#6330): remove unwrap()
 #![allow(clippy::unwrap_used)]
 
+mod annotation_tool;
+mod camera_path;
 mod contexts;
 mod eye;
 mod heuristics;
 mod max_image_dimension_subscriber;
+mod measurement;
 mod mesh_cache;
 mod mesh_loader;
 mod pickable_textured_rect;
@@ -16,10 +19,12 @@ mod picking;
 mod picking_ui;
 mod picking_ui_pixel;
 mod pinhole;
+mod point_cloud_lod;
 mod proc_mesh;
 mod scene_bounding_boxes;
 mod space_camera_3d;
 mod spatial_topology;
+mod splat_cache;
 mod ui;
 mod ui_2d;
 mod ui_3d;
@@ -28,6 +33,7 @@ mod view_2d_properties;
 mod view_3d;
 mod view_3d_properties;
 mod visualizers;
+mod volume_cache;
 
 mod transform_cache;
 
@@ -46,6 +52,7 @@ use re_types::{
     archetypes,
     blueprint::{archetypes::Background, components::BackgroundKind},
     components::{Color, ImageFormat, MediaType, Resolution},
+    image::ImageKind,
 };
 use re_viewport_blueprint::{ViewProperty, ViewPropertyQueryError};
 
@@ -87,28 +94,31 @@ fn resolution_of_image_at(
     }
 
     // Check for an encoded image.
-    if let Some(((_time, row_id), blob)) = entity_db
-        .latest_at_component::<re_types::components::Blob>(
-            entity_path,
-            query,
-            &archetypes::EncodedImage::descriptor_blob(),
-        )
-    {
+    let encoded_images = [
+        (
+            archetypes::EncodedImage::descriptor_blob(),
+            archetypes::EncodedImage::descriptor_media_type(),
+            ImageKind::Color,
+        ),
+        (
+            archetypes::EncodedDepthImage::descriptor_blob(),
+            archetypes::EncodedDepthImage::descriptor_media_type(),
+            ImageKind::Depth,
+        ),
+    ];
+    for (blob_descr, media_type_descr, image_kind) in encoded_images {
+        let Some(((_time, row_id), blob)) = entity_db
+            .latest_at_component::<re_types::components::Blob>(entity_path, query, &blob_descr)
+        else {
+            continue;
+        };
+
         let media_type = entity_db
-            .latest_at_component::<MediaType>(
-                entity_path,
-                query,
-                &archetypes::EncodedImage::descriptor_media_type(),
-            )
+            .latest_at_component::<MediaType>(entity_path, query, &media_type_descr)
             .map(|(_, c)| c);
 
         let image = ctx.store_context.caches.entry(|c: &mut ImageDecodeCache| {
-            c.entry(
-                row_id,
-                &archetypes::EncodedImage::descriptor_blob(),
-                &blob,
-                media_type.as_ref(),
-            )
+            c.entry(row_id, &blob_descr, &blob, media_type.as_ref(), image_kind)
         });
 
         if let Ok(image) = image {
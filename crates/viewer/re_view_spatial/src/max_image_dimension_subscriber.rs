@@ -17,6 +17,7 @@ bitflags::bitflags! {
         const DEPTH_IMAGE = 0b1000;
         const VIDEO_ASSET = 0b10000;
         const VIDEO_STREAM = 0b100000;
+        const ENCODED_DEPTH_IMAGE = 0b1000000;
     }
 }
 
@@ -125,7 +126,10 @@ impl PerStoreChunkSubscriber for MaxImageDimensionsStoreSubscriber {
                     (archetypes::DepthImage::name(), ImageTypes::DEPTH_IMAGE),
                     (archetypes::AssetVideo::name(), ImageTypes::VIDEO_ASSET),
                     (archetypes::VideoStream::name(), ImageTypes::VIDEO_STREAM),
-                    // TODO(#9046): handle encoded depth images.
+                    (
+                        archetypes::EncodedDepthImage::name(),
+                        ImageTypes::ENCODED_DEPTH_IMAGE,
+                    ),
                 ]
                 .iter()
                 .find_map(|(image_archetype_name, image_type)| {
@@ -221,8 +225,9 @@ fn try_size_from_blob(
 ) -> Option<[u32; 2]> {
     re_tracing::profile_function!();
 
-    // TODO(#9046): handle encoded depth images.
-    if archetype_name == archetypes::EncodedImage::name() {
+    if archetype_name == archetypes::EncodedImage::name()
+        || archetype_name == archetypes::EncodedDepthImage::name()
+    {
         re_tracing::profile_scope!("image");
 
         let media_type = components::MediaType::or_guess_from_data(media_type, blob);
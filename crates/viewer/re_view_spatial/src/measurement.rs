@@ -0,0 +1,380 @@
+//! Measuring distances and angles in spatial views.
+//!
+//! Measurements are placed by clicking in a view while the measurement mode is active.
+//! They live in the view state only, i.e. they are temporary annotations that are neither
+//! part of the recording nor of the blueprint.
+
+use re_format::format_f32;
+use re_ui::{ContextExt as _, UiExt as _};
+
+use crate::{
+    PickableTexturedRect,
+    picking::{PickingContext, PickingHitType, PickingResult},
+    view_kind::SpatialViewKind,
+};
+
+/// A polyline of measured points, given in the space of the view.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Measurement {
+    pub points: Vec<glam::Vec3>,
+}
+
+impl Measurement {
+    /// Iterates over all segments of the polyline.
+    pub fn segments(&self) -> impl Iterator<Item = (glam::Vec3, glam::Vec3)> + '_ {
+        self.points
+            .windows(2)
+            .map(|segment| (segment[0], segment[1]))
+    }
+
+    /// Sum of the lengths of all segments.
+    pub fn length(&self) -> f32 {
+        self.segments().map(|(a, b)| a.distance(b)).sum()
+    }
+
+    /// Angle between the two segments meeting at each inner point, in radians.
+    ///
+    /// Yields nothing for measurements with fewer than three points.
+    pub fn angles(&self) -> impl Iterator<Item = (glam::Vec3, f32)> + '_ {
+        self.points.windows(3).map(|corner| {
+            let [a, b, c] = [corner[0], corner[1], corner[2]];
+            (b, (a - b).angle_between(c - b))
+        })
+    }
+}
+
+/// State of the measurement tool of a spatial view.
+#[derive(Clone, Default)]
+pub struct MeasurementState {
+    /// Whether clicking the view places measurement points instead of selecting.
+    pub active: bool,
+
+    /// Finished measurements, shown until cleared.
+    pub kept: Vec<Measurement>,
+
+    /// The measurement currently being placed.
+    pub current: Measurement,
+
+    /// The point the next click would place, snapped to what is under the pointer.
+    pub hovered_point: Option<glam::Vec3>,
+}
+
+impl MeasurementState {
+    /// Places, removes and finishes measurement points according to user input.
+    pub fn handle_interaction(&mut self, response: &egui::Response, point: Option<glam::Vec3>) {
+        self.hovered_point = point;
+
+        if response.clicked() {
+            // A double click shows up as two clicks at the same spot.
+            if let Some(point) = point.filter(|point| self.current.points.last() != Some(point)) {
+                self.current.points.push(point);
+            }
+        }
+
+        let (finish, remove_last, discard) = response.ctx.input(|i| {
+            (
+                i.key_pressed(egui::Key::Enter),
+                i.key_pressed(egui::Key::Backspace),
+                i.key_pressed(egui::Key::Escape),
+            )
+        });
+        if !response.hovered() {
+            return;
+        }
+
+        if finish || response.secondary_clicked() {
+            self.finish();
+        } else if remove_last {
+            self.current.points.pop();
+        } else if discard {
+            self.current = Measurement::default();
+        }
+    }
+
+    /// Keeps the current measurement if it measures anything, and starts a new one.
+    pub fn finish(&mut self) {
+        let measurement = std::mem::take(&mut self.current);
+        if measurement.points.len() >= 2 {
+            self.kept.push(measurement);
+        }
+    }
+
+    /// Toggle and list of kept measurements, shown in the selection panel of the view.
+    pub fn selection_ui(&mut self, ui: &mut egui::Ui, spatial_kind: SpatialViewKind) {
+        ui.grid_left_hand_label("Measure")
+            .on_hover_text("Measure distances and angles in the view's units");
+        ui.vertical(|ui| {
+            if ui
+                .re_checkbox(&mut self.active, "Place measurement points")
+                .on_hover_text(
+                    "Click in the view to place points, snapping to the hovered object, \
+                    mesh surface or image pixel.\n\
+                    Right-click or press Enter to keep the measurement, \
+                    Backspace removes the last point, Escape discards the measurement.",
+                )
+                .changed()
+                && !self.active
+            {
+                self.finish();
+                self.hovered_point = None;
+            }
+
+            let mut removed = None;
+            for (index, measurement) in self.kept.iter().enumerate() {
+                ui.horizontal(|ui| {
+                    ui.label(format!(
+                        "#{} length {}",
+                        index + 1,
+                        format_f32(measurement.length())
+                    ))
+                    .on_hover_ui(|ui| measurement_details_ui(ui, measurement, spatial_kind));
+                    if ui
+                        .small_icon_button(&re_ui::icons::REMOVE, "Remove measurement")
+                        .clicked()
+                    {
+                        removed = Some(index);
+                    }
+                });
+            }
+            if let Some(index) = removed {
+                self.kept.remove(index);
+            }
+
+            if !self.kept.is_empty() && ui.button("Clear measurements").clicked() {
+                self.kept.clear();
+            }
+        });
+        ui.end_row();
+    }
+
+    /// Egui shapes for all measurements, including a preview of the one being placed.
+    ///
+    /// `ui_from_world` projects points of the view's space to ui coordinates and returns `None`
+    /// for points that can't be shown, e.g. because they are behind the camera.
+    pub fn shapes(
+        &self,
+        ui: &egui::Ui,
+        spatial_kind: SpatialViewKind,
+        ui_from_world: impl Fn(glam::Vec3) -> Option<egui::Pos2>,
+    ) -> Vec<egui::Shape> {
+        let mut shapes = Vec::new();
+
+        let kept_stroke = egui::Stroke::new(2.0, ui.ctx().selection_stroke().color);
+        for measurement in &self.kept {
+            polyline_shapes(
+                ui,
+                measurement,
+                kept_stroke,
+                false,
+                spatial_kind,
+                &ui_from_world,
+                &mut shapes,
+            );
+        }
+
+        if self.active {
+            let mut current = self.current.clone();
+            let has_preview = !current.points.is_empty() && self.hovered_point.is_some();
+            current.points.extend(self.hovered_point);
+            polyline_shapes(
+                ui,
+                &current,
+                egui::Stroke::new(2.0, ui.ctx().hover_stroke().color),
+                has_preview,
+                spatial_kind,
+                &ui_from_world,
+                &mut shapes,
+            );
+        }
+
+        shapes
+    }
+}
+
+/// Finds the point a click would place, snapping to what was picked.
+///
+/// Gpu picking hits lie on the surface of the picked object, which for depth images in 3D is the
+/// back-projected pixel. Image hits snap to the center of the hovered pixel.
+/// In 2D views, the pointer position is used if nothing was hit.
+pub fn snapped_point<'a>(
+    picking_context: &PickingContext,
+    picking_result: &PickingResult,
+    mut pickable_rects: impl Iterator<Item = &'a PickableTexturedRect>,
+    spatial_kind: SpatialViewKind,
+) -> Option<glam::Vec3> {
+    let hit_point = picking_result
+        .hits
+        .iter()
+        .find_map(|hit| match hit.hit_type {
+            PickingHitType::GpuPickingResult => Some(hit.space_position),
+            PickingHitType::TexturedRect => {
+                let rect = pickable_rects
+                    .find(|rect| rect.ent_path.hash() == hit.instance_path_hash.entity_path_hash)?;
+                Some(pixel_center(rect, hit.instance_path_hash.instance))
+            }
+            // Labels have no meaningful position in the scene.
+            PickingHitType::GuiOverlay => None,
+        });
+
+    hit_point.or_else(|| {
+        (spatial_kind == SpatialViewKind::TwoD)
+            .then(|| picking_context.pointer_in_camera_plane.extend(0.0))
+    })
+}
+
+/// Center of the given pixel of a textured rect, in the space of the view.
+fn pixel_center(rect: &PickableTexturedRect, instance: re_log_types::Instance) -> glam::Vec3 {
+    let textured_rect = &rect.textured_rect;
+    let [width, height] = textured_rect.colormapped_texture.width_height();
+    let [x, y] = instance.to_2d_image_coordinate(width.max(1));
+
+    textured_rect.top_left_corner_position
+        + textured_rect.extent_u * ((x as f32 + 0.5) / width.max(1) as f32)
+        + textured_rect.extent_v * ((y as f32 + 0.5) / height.max(1) as f32)
+}
+
+fn measurement_details_ui(
+    ui: &mut egui::Ui,
+    measurement: &Measurement,
+    spatial_kind: SpatialViewKind,
+) {
+    for (index, (a, b)) in measurement.segments().enumerate() {
+        ui.label(format!(
+            "Segment {}: {} ({})",
+            index + 1,
+            format_f32(a.distance(b)),
+            format_delta(b - a, spatial_kind)
+        ));
+    }
+    for (index, (_, angle)) in measurement.angles().enumerate() {
+        ui.label(format!("Angle {}: {:.1}°", index + 1, angle.to_degrees()));
+    }
+}
+
+fn format_delta(delta: glam::Vec3, spatial_kind: SpatialViewKind) -> String {
+    match spatial_kind {
+        SpatialViewKind::TwoD => {
+            format!("Δx {}, Δy {}", format_f32(delta.x), format_f32(delta.y))
+        }
+        SpatialViewKind::ThreeD => format!(
+            "Δx {}, Δy {}, Δz {}",
+            format_f32(delta.x),
+            format_f32(delta.y),
+            format_f32(delta.z)
+        ),
+    }
+}
+
+/// Adds the segments, points and labels of a measurement.
+///
+/// If `preview_last_segment` is set, the last segment is dashed and labeled with its per-axis
+/// delta as well.
+fn polyline_shapes(
+    ui: &egui::Ui,
+    measurement: &Measurement,
+    stroke: egui::Stroke,
+    preview_last_segment: bool,
+    spatial_kind: SpatialViewKind,
+    ui_from_world: &impl Fn(glam::Vec3) -> Option<egui::Pos2>,
+    shapes: &mut Vec<egui::Shape>,
+) {
+    let num_segments = measurement.points.len().saturating_sub(1);
+    let mut labels = Vec::new();
+
+    for (index, (a, b)) in measurement.segments().enumerate() {
+        let (Some(a_in_ui), Some(b_in_ui)) = (ui_from_world(a), ui_from_world(b)) else {
+            continue;
+        };
+
+        let mut text = format_f32(a.distance(b));
+        if preview_last_segment && index + 1 == num_segments {
+            shapes.extend(egui::Shape::dashed_line(
+                &[a_in_ui, b_in_ui],
+                stroke,
+                6.0,
+                4.0,
+            ));
+            text = format!("{text}\n{}", format_delta(b - a, spatial_kind));
+        } else {
+            shapes.push(egui::Shape::line_segment([a_in_ui, b_in_ui], stroke));
+        }
+        labels.push((a_in_ui.lerp(b_in_ui, 0.5), text));
+    }
+
+    for (corner, angle) in measurement.angles() {
+        if let Some(corner_in_ui) = ui_from_world(corner) {
+            labels.push((
+                corner_in_ui + egui::vec2(0.0, 16.0),
+                format!("{:.1}°", angle.to_degrees()),
+            ));
+        }
+    }
+
+    for point in &measurement.points {
+        if let Some(point_in_ui) = ui_from_world(*point) {
+            shapes.push(egui::Shape::circle_filled(point_in_ui, 3.0, stroke.color));
+        }
+    }
+
+    for (pos, text) in labels {
+        let text_color = ui.visuals().strong_text_color();
+        let galley = ui.fonts(|fonts| {
+            fonts.layout_no_wrap(text, egui::TextStyle::Body.resolve(ui.style()), text_color)
+        });
+        let text_rect = egui::Align2::CENTER_CENTER.anchor_size(pos, galley.size());
+        shapes.push(egui::Shape::rect_filled(
+            text_rect.expand(3.0),
+            3.0,
+            ui.visuals().widgets.inactive.bg_fill,
+        ));
+        shapes.push(egui::Shape::galley(text_rect.min, galley, text_color));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_measurement_length_and_angles() {
+        let measurement = Measurement {
+            points: vec![
+                glam::vec3(0.0, 0.0, 0.0),
+                glam::vec3(3.0, 0.0, 0.0),
+                glam::vec3(3.0, 4.0, 0.0),
+                glam::vec3(0.0, 0.0, 0.0),
+            ],
+        };
+
+        assert_eq!(measurement.length(), 12.0);
+
+        let angles: Vec<f32> = measurement
+            .angles()
+            .map(|(_, angle)| angle.to_degrees())
+            .collect();
+        assert_eq!(angles.len(), 2);
+        assert!((angles[0] - 90.0).abs() < 1e-4);
+        assert!((angles[1] - 3.0_f32.atan2(4.0).to_degrees()).abs() < 1e-4);
+
+        assert_eq!(Measurement::default().length(), 0.0);
+        assert_eq!(Measurement::default().angles().count(), 0);
+    }
+
+    #[test]
+    fn test_finish_keeps_only_actual_measurements() {
+        let mut state = MeasurementState::default();
+
+        state.current.points.push(glam::Vec3::ZERO);
+        state.finish();
+        assert!(state.kept.is_empty());
+        assert!(state.current.points.is_empty());
+
+        state
+            .current
+            .points
+            .extend([glam::Vec3::ZERO, glam::Vec3::X]);
+        state.finish();
+        assert_eq!(state.kept.len(), 1);
+        assert!(state.current.points.is_empty());
+    }
+}
@@ -37,6 +37,9 @@ pub struct LoadedMesh {
     // Can't do that right now because it's too hard to pass the render context through.
     pub mesh_instances: Vec<re_renderer::renderer::GpuMeshInstance>,
 
+    /// Set for assets that contain animations, see [`Self::animated_mesh_instances`].
+    animated_model: Option<re_renderer::AnimatedModel>,
+
     bbox: macaw::BoundingBox,
 }
 
@@ -89,13 +92,21 @@ impl LoadedMesh {
             }
         }
 
-        let bbox = cpu_model.calculate_bounding_box();
-        let mesh_instances = cpu_model.into_gpu_meshes(render_ctx)?;
+        let (bbox, mesh_instances, animated_model) = if cpu_model.has_animations() {
+            let animated_model = cpu_model.into_animated_model(render_ctx)?;
+            let bbox = animated_model.bounding_box();
+            let rest_pose = animated_model.mesh_instances(render_ctx, 0, 0.0)?;
+            (bbox, rest_pose, Some(animated_model))
+        } else {
+            let bbox = cpu_model.calculate_bounding_box();
+            (bbox, cpu_model.into_gpu_meshes(render_ctx)?, None)
+        };
 
         Ok(Self {
             name,
             bbox,
             mesh_instances,
+            animated_model,
         })
     }
 
@@ -202,9 +213,31 @@ impl LoadedMesh {
             name,
             bbox,
             mesh_instances,
+            animated_model: None,
         })
     }
 
+    /// Mesh instances posed at the given time (in seconds) of the asset's first animation.
+    ///
+    /// Only the first animation is played back, there's currently no way to pick another one.
+    ///
+    /// Returns `None` if the mesh isn't animated, in which case [`Self::mesh_instances`] should be used.
+    pub fn animated_mesh_instances(
+        &self,
+        render_ctx: &RenderContext,
+        animation_time: f32,
+    ) -> Option<Vec<re_renderer::renderer::GpuMeshInstance>> {
+        let animated_model = self.animated_model.as_ref()?;
+
+        match animated_model.mesh_instances(render_ctx, 0, animation_time) {
+            Ok(mesh_instances) => Some(mesh_instances),
+            Err(err) => {
+                re_log::error_once!("Failed to pose animated mesh {:?}: {err}", self.name);
+                None
+            }
+        }
+    }
+
     #[allow(dead_code)]
     pub fn name(&self) -> &str {
         &self.name
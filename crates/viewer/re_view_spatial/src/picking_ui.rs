@@ -19,7 +19,10 @@ use crate::{
     picking_ui_pixel::{PickedPixelInfo, textured_rect_hover_ui},
     ui::SpatialViewState,
     view_kind::SpatialViewKind,
-    visualizers::{CamerasVisualizer, DepthImageVisualizer, SpatialViewVisualizerData},
+    visualizers::{
+        CamerasVisualizer, DepthImageVisualizer, EncodedDepthImageVisualizer,
+        SpatialViewVisualizerData,
+    },
 };
 
 #[allow(clippy::too_many_arguments)]
@@ -76,6 +79,31 @@ pub fn picking(
     );
     state.previous_picking_result = Some(picking_result.clone());
 
+    // While annotating, clicks on images draw annotations instead of selecting.
+    if spatial_kind == SpatialViewKind::TwoD && state.annotation_tool.active {
+        state.annotation_tool.handle_interaction(
+            ctx,
+            query,
+            &response,
+            &picking_result,
+            iter_pickable_rects(&system_output.view_systems),
+            annotations,
+        );
+        return Ok(response.on_hover_cursor(egui::CursorIcon::Crosshair));
+    }
+
+    // While measuring, clicks place measurement points instead of selecting.
+    if state.measurements.active {
+        let point = crate::measurement::snapped_point(
+            picking_context,
+            &picking_result,
+            iter_pickable_rects(&system_output.view_systems),
+            spatial_kind,
+        );
+        state.measurements.handle_interaction(&response, point);
+        return Ok(response.on_hover_cursor(egui::CursorIcon::Crosshair));
+    }
+
     let mut hovered_image_items = Vec::new();
     let mut hovered_non_image_items = Vec::new();
 
@@ -230,7 +258,7 @@ pub fn picking(
     Ok(response)
 }
 
-fn iter_pickable_rects(
+pub(crate) fn iter_pickable_rects(
     visualizers: &VisualizerCollection,
 ) -> impl Iterator<Item = &PickableTexturedRect> {
     visualizers
@@ -249,6 +277,10 @@ fn get_pixel_picking_info(
         .view_systems
         .get::<DepthImageVisualizer>()
         .ok();
+    let encoded_depth_visualizer_output = system_output
+        .view_systems
+        .get::<EncodedDepthImageVisualizer>()
+        .ok();
 
     if hit.hit_type == PickingHitType::TexturedRect {
         iter_pickable_rects(&system_output.view_systems)
@@ -272,12 +304,19 @@ fn get_pixel_picking_info(
                     pixel_coordinates,
                 })
             })
-    } else if let Some((depth_image, depth_meter, texture)) =
-        depth_visualizer_output.and_then(|depth_images| {
+    } else if let Some((depth_image, depth_meter, texture)) = depth_visualizer_output
+        .and_then(|depth_images| {
             depth_images
                 .depth_cloud_entities
                 .get(&hit.instance_path_hash.entity_path_hash)
         })
+        .or_else(|| {
+            encoded_depth_visualizer_output.and_then(|depth_images| {
+                depth_images
+                    .depth_cloud_entities
+                    .get(&hit.instance_path_hash.entity_path_hash)
+            })
+        })
     {
         let pixel_coordinates = hit
             .instance_path_hash
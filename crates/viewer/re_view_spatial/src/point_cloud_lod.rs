@@ -0,0 +1,439 @@
+//! Level of detail for very large point clouds.
+//!
+//! Points are sorted into an octree in which every node holds a spatially uniform sample of the
+//! points in its subtree that weren't already sampled by one of its ancestors (additive refinement).
+//! Drawing a node therefore adds detail to everything drawn for its ancestors.
+//! Each frame, nodes are refined coarse to fine until they are dense enough on screen
+//! or the point budget is used up.
+//!
+//! The octree only ever stores indices into the original points, so that picking & highlighting
+//! can keep referring to the instance indices as logged.
+
+use std::{
+    cmp::Reverse,
+    collections::BinaryHeap,
+    ops::Range,
+    sync::{Arc, OnceLock},
+};
+
+use ahash::{HashMap, HashSet};
+use itertools::Either;
+use ordered_float::OrderedFloat;
+
+use re_chunk_store::{ChunkStoreEvent, RowId};
+use re_types::archetypes::Points3D;
+use re_viewer_context::Cache;
+
+// ---
+
+/// Point clouds with fewer points than this are always drawn in full.
+pub const LOD_MIN_NUM_POINTS: usize = 1 << 20;
+
+/// Upper bound for the number of points drawn per point cloud.
+const POINT_BUDGET: usize = 2_000_000;
+
+/// Nodes are refined until the distance between their points is at most this many pixels on screen.
+const MAX_SCREEN_SPACE_ERROR_IN_PIXELS: f32 = 2.0;
+
+/// Number of grid cells along each axis used for sampling a node's points.
+///
+/// Determines the spacing of a node's points relative to its size.
+const SAMPLING_GRID_RESOLUTION: usize = 64;
+
+/// Nodes with fewer points than this aren't split up any further.
+const MAX_POINTS_PER_LEAF: usize = 16_384;
+
+/// Guards against runaway subdivision of many points at (almost) the same position.
+const MAX_DEPTH: u32 = 20;
+
+/// Where a point cloud is seen from, in the point cloud's local space.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LodView {
+    pub eye_position: glam::Vec3,
+
+    /// Pixels covered by an angle of one radian at the center of the screen.
+    pub pixels_per_radian: f32,
+}
+
+impl LodView {
+    fn projected_size_in_pixels(&self, size: f32, bbox: &macaw::BoundingBox) -> f32 {
+        let closest = self.eye_position.clamp(bbox.min, bbox.max);
+        let distance = self.eye_position.distance(closest);
+        if distance <= f32::EPSILON {
+            f32::INFINITY
+        } else {
+            size / distance * self.pixels_per_radian
+        }
+    }
+}
+
+struct LodNode {
+    bbox: macaw::BoundingBox,
+
+    /// Typical distance between the points of this node, zero for leaves which hold all their points.
+    spacing: f32,
+
+    /// This node's points in [`PointCloudLod::point_indices`].
+    points: Range<u32>,
+
+    children: Vec<u32>,
+}
+
+/// Octree over the points of a point cloud.
+pub struct PointCloudLod {
+    /// Nodes of the octree, the root is the first one.
+    nodes: Vec<LodNode>,
+
+    /// Indices of the original points, grouped by node.
+    point_indices: Vec<u32>,
+}
+
+impl PointCloudLod {
+    pub fn new(positions: &[glam::Vec3]) -> Self {
+        re_tracing::profile_function!();
+
+        let mut lod = Self {
+            nodes: Vec::new(),
+            point_indices: Vec::with_capacity(positions.len()),
+        };
+        if positions.is_empty() {
+            return lod;
+        }
+
+        // Cubic cells keep the spacing of the points independent of the direction.
+        let bbox = macaw::BoundingBox::from_points(positions.iter().copied());
+        let half_size = 0.5 * bbox.size().max_element().max(f32::EPSILON);
+        let root_bbox =
+            macaw::BoundingBox::from_center_size(bbox.center(), glam::Vec3::splat(2.0 * half_size));
+
+        let mut occupied_cells = vec![false; SAMPLING_GRID_RESOLUTION.pow(3)];
+        lod.build_node(
+            positions,
+            (0..positions.len() as u32).collect(),
+            root_bbox,
+            0,
+            &mut occupied_cells,
+        );
+
+        lod
+    }
+
+    /// Adds a node for the given points and recursively all its children.
+    fn build_node(
+        &mut self,
+        positions: &[glam::Vec3],
+        mut indices: Vec<u32>,
+        bbox: macaw::BoundingBox,
+        depth: u32,
+        occupied_cells: &mut [bool],
+    ) -> u32 {
+        let node_index = self.nodes.len() as u32;
+        let points_start = self.point_indices.len() as u32;
+
+        if indices.len() <= MAX_POINTS_PER_LEAF || depth >= MAX_DEPTH {
+            self.point_indices.extend_from_slice(&indices);
+            self.nodes.push(LodNode {
+                bbox,
+                spacing: 0.0,
+                points: points_start..self.point_indices.len() as u32,
+                children: Vec::new(),
+            });
+            return node_index;
+        }
+
+        // Keep the first point in each cell of a grid spanning the node.
+        let cell_size = bbox.size().x / SAMPLING_GRID_RESOLUTION as f32;
+        let cell_index = |position: glam::Vec3| {
+            let max_cell = (SAMPLING_GRID_RESOLUTION - 1) as f32;
+            let cell = ((position - bbox.min) / cell_size)
+                .clamp(glam::Vec3::ZERO, glam::Vec3::splat(max_cell));
+            cell.x as usize
+                + SAMPLING_GRID_RESOLUTION
+                    * (cell.y as usize + SAMPLING_GRID_RESOLUTION * cell.z as usize)
+        };
+        indices.retain(|&index| {
+            let cell = &mut occupied_cells[cell_index(positions[index as usize])];
+            if *cell {
+                true
+            } else {
+                *cell = true;
+                self.point_indices.push(index);
+                false
+            }
+        });
+        let points = points_start..self.point_indices.len() as u32;
+        for &index in &self.point_indices[points.start as usize..points.end as usize] {
+            occupied_cells[cell_index(positions[index as usize])] = false;
+        }
+
+        self.nodes.push(LodNode {
+            bbox,
+            spacing: cell_size,
+            points,
+            children: Vec::new(),
+        });
+
+        // Everything that wasn't sampled goes into the octants.
+        let center = bbox.center();
+        let mut octants: [Vec<u32>; 8] = Default::default();
+        for index in indices {
+            let position = positions[index as usize];
+            let octant = (position.x >= center.x) as usize
+                | ((position.y >= center.y) as usize) << 1
+                | ((position.z >= center.z) as usize) << 2;
+            octants[octant].push(index);
+        }
+
+        let children = octants
+            .into_iter()
+            .enumerate()
+            .filter(|(_, indices)| !indices.is_empty())
+            .map(|(octant, indices)| {
+                let upper_half =
+                    glam::BVec3::new(octant & 1 != 0, octant & 2 != 0, octant & 4 != 0);
+                let corner = glam::Vec3::select(upper_half, center, bbox.min);
+                let child_bbox = macaw::BoundingBox::from_min_size(corner, 0.5 * bbox.size());
+                self.build_node(positions, indices, child_bbox, depth + 1, occupied_cells)
+            })
+            .collect();
+        self.nodes[node_index as usize].children = children;
+
+        node_index
+    }
+
+    /// Number of points in the point cloud.
+    pub fn num_points(&self) -> usize {
+        self.point_indices.len()
+    }
+
+    /// Selects the points to draw for the given view, as indices into the original points.
+    ///
+    /// Without a view, the coarsest points up to the point budget are returned.
+    pub fn select(&self, view: Option<&LodView>) -> Vec<u32> {
+        re_tracing::profile_function!();
+
+        let Some(root) = self.nodes.first() else {
+            return Vec::new();
+        };
+
+        let priority = |node: &LodNode| match view {
+            Some(view) => view.projected_size_in_pixels(node.spacing, &node.bbox),
+            None => node.spacing,
+        };
+
+        // Coarsest nodes (i.e. those with the largest error on screen) first.
+        let mut candidates = BinaryHeap::new();
+        candidates.push((OrderedFloat(priority(root)), Reverse(0u32)));
+
+        let mut selected = Vec::new();
+        while let Some((OrderedFloat(error), Reverse(node_index))) = candidates.pop() {
+            let node = &self.nodes[node_index as usize];
+            let points = &self.point_indices[node.points.start as usize..node.points.end as usize];
+            if selected.len() + points.len() > POINT_BUDGET {
+                break;
+            }
+            selected.extend_from_slice(points);
+
+            let needs_refinement = view.is_none() || error > MAX_SCREEN_SPACE_ERROR_IN_PIXELS;
+            if needs_refinement {
+                for &child_index in &node.children {
+                    let child = &self.nodes[child_index as usize];
+                    candidates.push((OrderedFloat(priority(child)), Reverse(child_index)));
+                }
+            }
+        }
+
+        selected
+    }
+}
+
+/// Evenly spaced subset of a point cloud, shown until its octree is ready.
+fn strided_subset(num_points: usize) -> Vec<u32> {
+    let stride = num_points.div_ceil(POINT_BUDGET).max(1);
+    (0..num_points as u32).step_by(stride).collect()
+}
+
+struct CachedPointCloudLod {
+    bbox: macaw::BoundingBox,
+
+    /// Filled in by a background task once the octree is built.
+    lod: Arc<OnceLock<PointCloudLod>>,
+
+    /// What's drawn while the octree is being built.
+    strided_subset: Option<Arc<[u32]>>,
+
+    /// Last selection from the octree, together with the view it was made for.
+    last_selection: Option<(Option<LodView>, Arc<[u32]>)>,
+}
+
+/// Caches the octrees of large point clouds, keyed by the [`RowId`] of their positions.
+#[derive(Default)]
+pub struct PointCloudLodCache(HashMap<RowId, CachedPointCloudLod>);
+
+impl PointCloudLodCache {
+    /// Returns the bounding box of the point cloud and the indices of the points to draw.
+    ///
+    /// Starts building the octree if this point cloud hasn't been seen before.
+    /// `on_ready` is called from the background task once the octree is done.
+    pub fn select(
+        &mut self,
+        row_id: RowId,
+        positions: &[glam::Vec3],
+        view: Option<LodView>,
+        on_ready: impl FnOnce() + Send + 'static,
+    ) -> (macaw::BoundingBox, Arc<[u32]>) {
+        let entry = self.0.entry(row_id).or_insert_with(|| {
+            re_tracing::profile_scope!("start building point cloud lod");
+
+            let lod = Arc::new(OnceLock::new());
+            build_in_background(positions.to_vec(), lod.clone(), on_ready);
+
+            CachedPointCloudLod {
+                bbox: macaw::BoundingBox::from_points(positions.iter().copied()),
+                lod,
+                strided_subset: None,
+                last_selection: None,
+            }
+        });
+
+        let Some(lod) = entry.lod.get() else {
+            let subset = entry
+                .strided_subset
+                .get_or_insert_with(|| strided_subset(positions.len()).into());
+            return (entry.bbox, subset.clone());
+        };
+        entry.strided_subset = None;
+
+        if let Some((last_view, selection)) = &entry.last_selection {
+            if *last_view == view {
+                return (entry.bbox, selection.clone());
+            }
+        }
+
+        let selection: Arc<[u32]> = lod.select(view.as_ref()).into();
+        entry.last_selection = Some((view, selection.clone()));
+        (entry.bbox, selection)
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn build_in_background(
+    positions: Vec<glam::Vec3>,
+    lod: Arc<OnceLock<PointCloudLod>>,
+    on_ready: impl FnOnce() + Send + 'static,
+) {
+    let result = std::thread::Builder::new()
+        .name("point_cloud_lod".to_owned())
+        .spawn(move || {
+            lod.get_or_init(|| PointCloudLod::new(&positions));
+            on_ready();
+        });
+    if let Err(err) = result {
+        re_log::warn_once!(
+            "Failed to spawn thread for building point cloud level of detail: {err}"
+        );
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn build_in_background(
+    positions: Vec<glam::Vec3>,
+    lod: Arc<OnceLock<PointCloudLod>>,
+    on_ready: impl FnOnce() + Send + 'static,
+) {
+    // There are no threads on the web.
+    lod.get_or_init(|| PointCloudLod::new(&positions));
+    on_ready();
+}
+
+impl Cache for PointCloudLodCache {
+    fn purge_memory(&mut self) {
+        self.0.clear();
+    }
+
+    fn on_store_events(&mut self, events: &[ChunkStoreEvent]) {
+        re_tracing::profile_function!();
+
+        let row_ids_removed: HashSet<RowId> = events
+            .iter()
+            .flat_map(|event| {
+                let is_deletion = || event.kind == re_chunk_store::ChunkStoreDiffKind::Deletion;
+                let contains_positions = || {
+                    event
+                        .chunk
+                        .components()
+                        .contains_component(&Points3D::descriptor_positions())
+                };
+
+                if is_deletion() && contains_positions() {
+                    Either::Left(event.chunk.row_ids())
+                } else {
+                    Either::Right(std::iter::empty())
+                }
+            })
+            .collect();
+
+        self.0
+            .retain(|row_id, _lod| !row_ids_removed.contains(row_id));
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid_points(num_per_axis: usize) -> Vec<glam::Vec3> {
+        let mut positions = Vec::with_capacity(num_per_axis.pow(3));
+        for z in 0..num_per_axis {
+            for y in 0..num_per_axis {
+                for x in 0..num_per_axis {
+                    positions.push(glam::vec3(x as f32, y as f32, z as f32));
+                }
+            }
+        }
+        positions
+    }
+
+    #[test]
+    fn octree_contains_every_point_once() {
+        let positions = grid_points(50);
+        let lod = PointCloudLod::new(&positions);
+        assert!(lod.nodes.len() > 1, "expected the points to be split up");
+
+        let mut indices = lod.point_indices.clone();
+        indices.sort_unstable();
+        assert!(indices.iter().copied().eq(0..positions.len() as u32));
+
+        // Without a view, everything within the budget is selected.
+        let mut selected = lod.select(None);
+        selected.sort_unstable();
+        assert_eq!(selected, indices);
+    }
+
+    #[test]
+    fn distant_views_select_fewer_points() {
+        let positions = grid_points(50);
+        let lod = PointCloudLod::new(&positions);
+
+        let near = LodView {
+            eye_position: glam::vec3(25.0, 25.0, -10.0),
+            pixels_per_radian: 1000.0,
+        };
+        let far = LodView {
+            eye_position: glam::vec3(25.0, 25.0, -10_000.0),
+            ..near
+        };
+
+        let selected_near = lod.select(Some(&near));
+        let selected_far = lod.select(Some(&far));
+        assert!(selected_far.len() < selected_near.len());
+        assert!(!selected_far.is_empty());
+
+        let unique: HashSet<u32> = selected_near.iter().copied().collect();
+        assert_eq!(unique.len(), selected_near.len());
+    }
+}
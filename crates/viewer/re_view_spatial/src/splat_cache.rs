@@ -0,0 +1,211 @@
+use ahash::{HashMap, HashSet};
+
+use itertools::Either;
+use re_chunk_store::{ChunkStoreEvent, RowId};
+use re_entity_db::VersionedInstancePathHash;
+use re_log_types::hash::Hash64;
+use re_renderer::{
+    RenderContext,
+    renderer::{GaussianSplat, GaussianSplatCloud, GaussianSplatDrawOrder},
+};
+use re_types::archetypes::GaussianSplats3D;
+use re_viewer_context::Cache;
+
+// ----------------------------------------------------------------------------
+
+/// Key used for caching [`CachedSplatCloud`]s.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct SplatCacheKey {
+    pub versioned_instance_path_hash: VersionedInstancePathHash,
+    pub query_result_hash: Hash64,
+}
+
+/// Position & viewing direction a draw order was sorted for, in the cloud's local space.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SortEye {
+    pub position: glam::Vec3,
+    pub forward: glam::Vec3,
+}
+
+impl SortEye {
+    /// Transforms an eye from world space into the space of a cloud placed at `world_from_obj`.
+    ///
+    /// The forward direction is transformed such that depths along it are ordered the same way
+    /// as the world space depths of the transformed splats, which also holds for non-uniform scaling.
+    pub fn in_obj_space(
+        world_from_obj: glam::Affine3A,
+        position_in_world: glam::Vec3,
+        forward_in_world: glam::Vec3,
+    ) -> Self {
+        Self {
+            position: world_from_obj.inverse().transform_point3(position_in_world),
+            forward: world_from_obj
+                .matrix3
+                .transpose()
+                .mul_vec3(forward_in_world),
+        }
+    }
+
+    fn is_close_to(&self, other: &Self) -> bool {
+        const MAX_DIFF: f32 = 1e-5;
+        self.position.abs_diff_eq(other.position, MAX_DIFF)
+            && self.forward.abs_diff_eq(other.forward, MAX_DIFF)
+    }
+}
+
+/// A splat cloud uploaded to the GPU, together with the draw orders of all poses it is shown at.
+pub struct CachedSplatCloud {
+    pub cloud: GaussianSplatCloud,
+    pub bbox: macaw::BoundingBox,
+
+    /// Last draw order for every pose, together with the eye it was sorted for.
+    ///
+    /// `None` eyes stand for the unsorted order used until a camera is known.
+    draw_orders: Vec<(Option<SortEye>, GaussianSplatDrawOrder)>,
+}
+
+impl CachedSplatCloud {
+    /// Returns the draw order for the cloud's `pose_index`th pose, re-sorting only if the eye moved.
+    pub fn draw_order(
+        &mut self,
+        render_ctx: &RenderContext,
+        pose_index: usize,
+        eye: Option<SortEye>,
+    ) -> Option<GaussianSplatDrawOrder> {
+        if let Some((sorted_for, draw_order)) = self.draw_orders.get(pose_index) {
+            let up_to_date = match (sorted_for, &eye) {
+                (Some(sorted_for), Some(eye)) => sorted_for.is_close_to(eye),
+                (None, None) => true,
+                _ => false,
+            };
+            if up_to_date {
+                return Some(draw_order.clone());
+            }
+        }
+
+        let splat_indices = match &eye {
+            Some(eye) => self
+                .cloud
+                .draw_order_back_to_front(eye.position, eye.forward),
+            None => (0..self.cloud.len() as u32).collect(),
+        };
+        let draw_order = match GaussianSplatDrawOrder::new(render_ctx, &splat_indices) {
+            Ok(draw_order) => draw_order,
+            Err(err) => {
+                re_log::warn_once!("Failed to upload splat draw order: {err}");
+                return None;
+            }
+        };
+
+        if pose_index < self.draw_orders.len() {
+            self.draw_orders[pose_index] = (eye, draw_order.clone());
+        } else {
+            // Poses are always visited in order, so this appends the next one.
+            self.draw_orders.push((eye, draw_order.clone()));
+        }
+
+        Some(draw_order)
+    }
+}
+
+/// Caches splat clouds based on their [`SplatCacheKey`].
+///
+/// `None` entries mean that uploading failed, so we don't try again every frame.
+#[derive(Default)]
+pub struct SplatCache(HashMap<RowId, HashMap<SplatCacheKey, Option<CachedSplatCloud>>>);
+
+impl SplatCache {
+    pub fn entry(
+        &mut self,
+        name: &str,
+        key: SplatCacheKey,
+        splats: impl FnOnce() -> Vec<GaussianSplat>,
+        render_ctx: &RenderContext,
+    ) -> Option<&mut CachedSplatCloud> {
+        self.0
+            .entry(key.versioned_instance_path_hash.row_id)
+            .or_default()
+            .entry(key)
+            .or_insert_with(|| {
+                re_tracing::profile_scope!("upload splats");
+
+                let splats = splats();
+                match GaussianSplatCloud::new(render_ctx, &name.into(), &splats) {
+                    Ok(cloud) => Some(CachedSplatCloud {
+                        bbox: cloud.bbox(),
+                        cloud,
+                        draw_orders: Vec::new(),
+                    }),
+                    Err(err) => {
+                        re_log::warn!("Failed to upload splats {name:?}: {err}");
+                        None
+                    }
+                }
+            })
+            .as_mut()
+    }
+}
+
+impl Cache for SplatCache {
+    fn purge_memory(&mut self) {
+        self.0.clear();
+    }
+
+    fn on_store_events(&mut self, events: &[ChunkStoreEvent]) {
+        re_tracing::profile_function!();
+
+        let row_ids_removed: HashSet<RowId> = events
+            .iter()
+            .flat_map(|event| {
+                let is_deletion = || event.kind == re_chunk_store::ChunkStoreDiffKind::Deletion;
+                let contains_splat_centers = || {
+                    event
+                        .chunk
+                        .components()
+                        .contains_component(&GaussianSplats3D::descriptor_centers())
+                };
+
+                if is_deletion() && contains_splat_centers() {
+                    Either::Left(event.chunk.row_ids())
+                } else {
+                    Either::Right(std::iter::empty())
+                }
+            })
+            .collect();
+
+        self.0
+            .retain(|row_id, _per_key| !row_ids_removed.contains(row_id));
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sort_eye_preserves_depth_order_under_scaling() {
+        let world_from_obj = glam::Affine3A::from_scale_rotation_translation(
+            glam::vec3(1.0, 4.0, 0.5),
+            glam::Quat::from_rotation_y(0.3),
+            glam::vec3(1.0, 2.0, 3.0),
+        );
+        let eye_position = glam::vec3(-2.0, 1.0, 8.0);
+        let eye_forward = glam::vec3(0.3, -0.2, -1.0);
+        let eye = SortEye::in_obj_space(world_from_obj, eye_position, eye_forward);
+
+        for center in [
+            glam::vec3(0.0, 0.0, 0.0),
+            glam::vec3(1.0, -1.0, 2.0),
+            glam::vec3(-3.0, 0.5, 1.0),
+        ] {
+            let depth_in_world =
+                (world_from_obj.transform_point3(center) - eye_position).dot(eye_forward);
+            let depth_in_obj = (center - eye.position).dot(eye.forward);
+            assert!((depth_in_world - depth_in_obj).abs() < 1e-4);
+        }
+    }
+}
@@ -11,7 +11,9 @@ use re_viewer_context::{HoverHighlight, ImageInfo, SelectionHighlight, ViewHighl
 
 use crate::{
     Pinhole,
+    annotation_tool::AnnotationToolState,
     eye::EyeMode,
+    measurement::MeasurementState,
     pickable_textured_rect::PickableRectSourceData,
     picking::{PickableUiRect, PickingResult},
     scene_bounding_boxes::SceneBoundingBoxes,
@@ -63,6 +65,12 @@ pub struct SpatialViewState {
     pub pinhole_at_origin: Option<Pinhole>,
 
     pub visual_bounds_2d: Option<VisualBounds2D>,
+
+    /// Distance and angle measurements placed in this view.
+    pub measurements: MeasurementState,
+
+    /// Annotations drawn on images in this view.
+    pub annotation_tool: AnnotationToolState,
 }
 
 impl ViewState for SpatialViewState {
@@ -253,6 +253,8 @@ impl SpatialView2D {
             )?;
         } else {
             state.previous_picking_result = None;
+            state.measurements.hovered_point = None;
+            state.annotation_tool.clear_hover();
         }
         let view_ctx = self.view_context(ctx, query.view_id, state); // Recreate view state to handle context editing during picking.
 
@@ -307,6 +309,17 @@ impl SpatialView2D {
         // Add egui-rendered labels on top of everything else:
         painter.extend(label_shapes);
 
+        painter.extend(state.measurements.shapes(ui, SpatialViewKind::TwoD, |pos| {
+            Some(ui_from_scene.transform_pos(pos2(pos.x, pos.y)))
+        }));
+
+        painter.extend(state.annotation_tool.shapes(
+            ui,
+            query,
+            crate::picking_ui::iter_pickable_rects(&system_output.view_systems),
+            ui_from_scene,
+        ));
+
         Ok(())
     }
 }
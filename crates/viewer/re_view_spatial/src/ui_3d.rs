@@ -3,7 +3,7 @@ use glam::{Affine3A, Quat, Vec3};
 use web_time::Instant;
 
 use macaw::BoundingBox;
-use re_log_types::EntityPath;
+use re_log_types::{EntityPath, TimeReal};
 use re_renderer::{
     LineDrawableBuilder, Size,
     view_builder::{Projection, TargetConfiguration, ViewBuilder},
@@ -29,6 +29,8 @@ use re_viewport_blueprint::ViewProperty;
 
 use crate::{
     SpatialView3D,
+    camera_path::{CameraPathPose, KeyframedCameraPath},
+    contexts::TransformTreeContext,
     scene_bounding_boxes::SceneBoundingBoxes,
     space_camera_3d::SpaceCamera3D,
     transform_cache::query_view_coordinates_at_closest_ancestor,
@@ -59,10 +61,23 @@ pub struct View3DState {
 
     eye_interpolation: Option<EyeInterpolation>,
 
+    /// The time at which the user took over the eye from the camera path.
+    ///
+    /// The eye follows the path again as soon as the time cursor moves.
+    camera_path_taken_over_at: Option<TimeReal>,
+
+    /// Last known transform from the entity chased by the camera path to the space of the view.
+    pub camera_path_reference_from_chase: Option<Affine3A>,
+
     /// Last known view coordinates.
     /// Used to detect changes in view coordinates, in which case we reset the camera eye.
     pub scene_view_coordinates: Option<ViewCoordinates>,
 
+    /// Resolution the view was last drawn at.
+    ///
+    /// Used by visualizers that adapt their level of detail to the screen.
+    pub viewport_resolution_in_pixels: Option<[u32; 2]>,
+
     // options:
     spin: bool,
     pub show_axes: bool,
@@ -81,7 +96,10 @@ impl Default for View3DState {
             tracked_entity: None,
             camera_before_tracked_entity: None,
             eye_interpolation: Default::default(),
+            camera_path_taken_over_at: None,
+            camera_path_reference_from_chase: None,
             scene_view_coordinates: None,
+            viewport_resolution_in_pixels: None,
             spin: false,
             show_axes: false,
             show_bbox: false,
@@ -116,6 +134,7 @@ impl View3DState {
         bounding_boxes: &SceneBoundingBoxes,
         space_cameras: &[SpaceCamera3D],
         scene_view_coordinates: Option<ViewCoordinates>,
+        camera_path: Option<(TimeReal, CameraPathPose)>,
     ) -> ViewEye {
         // If the user has not interacted with the eye-camera yet, continue to
         // interpolate to the new default eye. This gives much better robustness
@@ -156,6 +175,21 @@ impl View3DState {
             .view_eye
             .get_or_insert_with(|| default_eye(&bounding_boxes.current, scene_view_coordinates));
 
+        // The camera path has the last word, unless the user took over at the current time.
+        if let Some((time, pose)) = camera_path {
+            if self.camera_path_taken_over_at != Some(time) {
+                self.camera_path_taken_over_at = None;
+                if let Some(mut path_eye) = pose.to_view_eye(view_eye.eye_up().unwrap_or(Vec3::Z)) {
+                    path_eye.set_mode(view_eye.mode());
+                    *view_eye = path_eye;
+                }
+                self.eye_interpolation = None;
+                self.tracked_entity = None;
+                self.camera_before_tracked_entity = None;
+                self.spin = false;
+            }
+        }
+
         if self.spin {
             view_eye.rotate(egui::vec2(
                 -response.ctx.input(|i| i.stable_dt).at_most(0.1) * 150.0,
@@ -203,6 +237,9 @@ impl View3DState {
             self.eye_interpolation = None;
             self.tracked_entity = None;
             self.camera_before_tracked_entity = None;
+            if let Some((time, _)) = camera_path {
+                self.camera_path_taken_over_at = Some(time);
+            }
         }
 
         *view_eye
@@ -443,11 +480,41 @@ impl SpatialView3D {
             return Ok(()); // protect against problems with zero-sized views
         }
 
+        // Keyframes of a chase cam path are relative to the chased entity.
+        let camera_path = KeyframedCameraPath::load(ctx, query.view_id)?;
+        let transforms = system_output
+            .context_systems
+            .get::<TransformTreeContext>()?;
+        state.state_3d.camera_path_reference_from_chase = camera_path
+            .chase_entity
+            .as_ref()
+            .and_then(|entity| transforms.transform_info_for_entity(entity.hash()))
+            .map(|transform_info| transform_info.reference_from_entity());
+        let reference_from_camera_path = if camera_path.chase_entity.is_some() {
+            state.state_3d.camera_path_reference_from_chase
+        } else {
+            Some(Affine3A::IDENTITY)
+        };
+        let camera_path_pose = reference_from_camera_path
+            .filter(|_| camera_path.is_active_on(&query.timeline))
+            .and_then(|reference_from_camera_path| {
+                // Unlike `query.latest_at`, this is not rounded, which keeps playback smooth.
+                let time = ctx
+                    .rec_cfg
+                    .time_ctrl
+                    .read()
+                    .time()
+                    .unwrap_or_else(|| query.latest_at.into());
+                let pose = camera_path.sample(time.as_f64())?;
+                Some((time, pose.transformed(&reference_from_camera_path)))
+            });
+
         let view_eye = state.state_3d.update_eye(
             &response,
             &state.bounding_boxes,
             space_cameras,
             scene_view_coordinates,
+            camera_path_pose,
         );
         let eye = view_eye.to_eye();
 
@@ -457,6 +524,7 @@ impl SpatialView3D {
         if resolution_in_pixel[0] == 0 || resolution_in_pixel[1] == 0 {
             return Ok(());
         }
+        state.state_3d.viewport_resolution_in_pixels = Some(resolution_in_pixel);
 
         let target_config = TargetConfiguration {
             name: query.space_origin.to_string().into(),
@@ -543,6 +611,7 @@ impl SpatialView3D {
             )?;
         } else {
             state.previous_picking_result = None;
+            state.measurements.hovered_point = None;
         }
 
         // Track focused entity if any.
@@ -653,6 +722,17 @@ impl SpatialView3D {
                 });
         }
 
+        // Show the camera path while it can be edited in the selection panel.
+        if let Some(reference_from_camera_path) = reference_from_camera_path {
+            if ctx.selection().is_view_the_only_selected(&query.view_id) {
+                camera_path.add_lines(
+                    &mut line_builder,
+                    &reference_from_camera_path,
+                    ui.tokens().frustum_color,
+                );
+            }
+        }
+
         show_orbit_eye_center(
             ui.ctx(),
             &mut state.state_3d,
@@ -709,6 +789,17 @@ impl SpatialView3D {
         let painter = ui.painter().with_clip_rect(ui.max_rect());
         painter.extend(label_shapes);
 
+        let ui_from_world = eye.ui_from_world(ui_rect);
+        painter.extend(
+            state
+                .measurements
+                .shapes(ui, SpatialViewKind::ThreeD, |pos| {
+                    let pos_in_ui = ui_from_world * pos.extend(1.0);
+                    (pos_in_ui.w > 0.0)
+                        .then(|| egui::pos2(pos_in_ui.x / pos_in_ui.w, pos_in_ui.y / pos_in_ui.w))
+                }),
+        );
+
         Ok(())
     }
 
@@ -251,6 +251,16 @@ impl ViewClass for SpatialView2D {
         // TODO(andreas): list_item'ify the rest
         ui.selection_grid("spatial_settings_ui").show(ui, |ui| {
             state.bounding_box_ui(ui, SpatialViewKind::TwoD);
+            state.measurements.selection_ui(ui, SpatialViewKind::TwoD);
+
+            // Clicks either place measurement points or draw annotations, not both.
+            let was_annotating = state.annotation_tool.active;
+            state.annotation_tool.selection_ui(ctx, ui);
+            if state.annotation_tool.active && !was_annotating {
+                state.measurements.active = false;
+            } else if state.measurements.active {
+                state.annotation_tool.active = false;
+            }
         });
 
         re_ui::list_item::list_item_scope(ui, "spatial_view2d_selection_ui", |ui| {
@@ -307,7 +317,8 @@ impl NonNestedImageCounts {
             + dims.image_types.contains(ImageTypes::ENCODED_IMAGE) as usize
             + dims.image_types.contains(ImageTypes::VIDEO_ASSET) as usize
             + dims.image_types.contains(ImageTypes::VIDEO_STREAM) as usize;
-        self.depth += dims.image_types.contains(ImageTypes::DEPTH_IMAGE) as usize;
+        self.depth += dims.image_types.contains(ImageTypes::DEPTH_IMAGE) as usize
+            + dims.image_types.contains(ImageTypes::ENCODED_DEPTH_IMAGE) as usize;
         self.segmentation += dims.image_types.contains(ImageTypes::SEGMENTATION_IMAGE) as usize;
     }
 }
@@ -21,6 +21,7 @@ use re_viewport_blueprint::ViewProperty;
 use crate::transform_cache::query_view_coordinates;
 use crate::visualizers::{AxisLengthDetector, CamerasVisualizer, Transform3DArrowsVisualizer};
 use crate::{
+    camera_path::{KeyframedCameraPath, camera_path_ui},
     contexts::register_spatial_contexts,
     heuristics::default_visualized_entities_for_visualizer_kind,
     spatial_topology::{HeuristicHints, SpatialTopology, SubSpaceConnectionFlags},
@@ -376,6 +377,9 @@ impl ViewClass for SpatialView3D {
         let scene_view_coordinates =
             query_view_coordinates(space_origin, ctx.recording(), &ctx.current_query());
 
+        let mut camera_path = KeyframedCameraPath::load(ctx, view_id)?;
+        let mut camera_path_changed = false;
+
         // TODO(andreas): list_item'ify the rest
         ui.selection_grid("spatial_settings_ui").show(ui, |ui| {
             ui.grid_left_hand_label("Camera")
@@ -385,6 +389,14 @@ impl ViewClass for SpatialView3D {
             });
             ui.end_row();
 
+            ui.grid_left_hand_label("Camera path")
+                .on_hover_text("Keyframes for the camera to fly along as the time cursor moves");
+            ui.vertical(|ui| {
+                camera_path_changed =
+                    camera_path_ui(ctx, ui, &state.state_3d, space_origin, &mut camera_path);
+            });
+            ui.end_row();
+
             ui.grid_left_hand_label("Coordinates")
                 .on_hover_text("The world coordinate system used for this view");
             ui.vertical(|ui| {
@@ -420,8 +432,13 @@ impl ViewClass for SpatialView3D {
             ui.end_row();
 
             state.bounding_box_ui(ui, SpatialViewKind::ThreeD);
+            state.measurements.selection_ui(ui, SpatialViewKind::ThreeD);
         });
 
+        if camera_path_changed {
+            camera_path.save(ctx, view_id);
+        }
+
         re_ui::list_item::list_item_scope(ui, "spatial_view3d_selection_ui", |ui| {
             let view_ctx = self.view_context(ctx, view_id, state);
             view_property_ui::<Background>(&view_ctx, ui, self);
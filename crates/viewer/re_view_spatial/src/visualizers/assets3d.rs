@@ -1,7 +1,7 @@
 use arrow::buffer::ScalarBuffer;
 
 use re_chunk_store::RowId;
-use re_log_types::{Instance, TimeInt, hash::Hash64};
+use re_log_types::{Instance, TimeInt, TimeType, hash::Hash64};
 use re_renderer::renderer::GpuMeshInstance;
 use re_types::{Archetype as _, ArrowString, archetypes::Asset3D, components::AlbedoFactor};
 use re_viewer_context::{
@@ -80,13 +80,19 @@ impl Asset3DVisualizer {
             if let Some(mesh) = mesh {
                 re_tracing::profile_scope!("mesh instances");
 
+                let animated_mesh_instances = animation_time(ctx, data.index.0)
+                    .and_then(|time| mesh.animated_mesh_instances(ctx.render_ctx(), time));
+                let mesh_instances = animated_mesh_instances
+                    .as_deref()
+                    .unwrap_or(&mesh.mesh_instances);
+
                 // Let's draw the mesh once for every instance transform.
                 // TODO(#7026): This a rare form of hybrid joining.
                 for &world_from_pose in ent_context
                     .transform_info
                     .reference_from_instances(Asset3D::name())
                 {
-                    instances.extend(mesh.mesh_instances.iter().map(move |mesh_instance| {
+                    instances.extend(mesh_instances.iter().map(move |mesh_instance| {
                         let pose_from_mesh = mesh_instance.world_from_mesh;
                         let world_from_mesh = world_from_pose * pose_from_mesh;
 
@@ -109,6 +115,27 @@ impl Asset3DVisualizer {
     }
 }
 
+/// Time in seconds at which animated assets are posed.
+///
+/// The recording's timeline drives the animation: animation time is the time elapsed since the
+/// asset was logged, or since the start of the timeline for static assets.
+/// Sequence timelines have no notion of seconds, so assets stay in their initial pose there.
+fn animation_time(ctx: &QueryContext<'_>, asset_time: TimeInt) -> Option<f32> {
+    let timeline = ctx.query.timeline();
+    match ctx.recording().timeline_type(&timeline) {
+        TimeType::Sequence => None,
+        TimeType::DurationNs | TimeType::TimestampNs => {
+            let start = if asset_time.is_static() {
+                ctx.recording().time_range_for(&timeline)?.min()
+            } else {
+                asset_time
+            };
+            let elapsed_nanos = ctx.query.at().as_i64().saturating_sub(start.as_i64());
+            Some((elapsed_nanos.max(0) as f64 * 1e-9) as f32)
+        }
+    }
+}
+
 impl IdentifiedViewSystem for Asset3DVisualizer {
     fn identifier() -> re_viewer_context::ViewSystemIdentifier {
         "Asset3D".into()
@@ -4,7 +4,7 @@ use re_entity_db::EntityPath;
 use re_log_types::EntityPathHash;
 use re_renderer::renderer::{ColormappedTexture, DepthCloud, DepthClouds};
 use re_types::{
-    Archetype as _,
+    Archetype as _, ArchetypeName,
     archetypes::DepthImage,
     components::{
         self, Colormap, DepthMeter, DrawOrder, FillRatio, ImageBuffer, ImageFormat, ValueRange,
@@ -43,123 +43,129 @@ impl Default for DepthImageVisualizer {
     }
 }
 
-struct DepthImageComponentData {
-    image: ImageInfo,
-    depth_meter: Option<DepthMeter>,
-    fill_ratio: Option<FillRatio>,
-    colormap: Option<Colormap>,
-    value_range: Option<[f64; 2]>,
+pub(super) struct DepthImageComponentData {
+    pub image: ImageInfo,
+    pub depth_meter: Option<DepthMeter>,
+    pub fill_ratio: Option<FillRatio>,
+    pub colormap: Option<Colormap>,
+    pub value_range: Option<[f64; 2]>,
 }
 
-impl DepthImageVisualizer {
-    fn process_depth_image_data(
-        &mut self,
-        ctx: &QueryContext<'_>,
-        depth_clouds: &mut Vec<DepthCloud>,
-        ent_context: &SpatialSceneEntityContext<'_>,
-        images: impl Iterator<Item = DepthImageComponentData>,
-    ) {
-        let is_3d_view = ent_context.view_class_identifier == SpatialView3D::identifier();
-
-        let entity_path = ctx.target_entity_path;
-
-        for data in images {
-            let DepthImageComponentData {
-                image,
-                depth_meter,
-                fill_ratio,
-                colormap,
-                value_range,
-            } = data;
-
-            let depth_meter = depth_meter.unwrap_or_else(|| self.fallback_for(ctx));
-
-            // All depth images must have a colormap:
-            let colormap = colormap.unwrap_or_else(|| self.fallback_for(ctx));
-            let value_range = value_range
-                .map(|r| [r[0] as f32, r[1] as f32])
-                .unwrap_or_else(|| {
-                    // Don't use fallback provider since it has to query information we already have.
-                    let image_stats = ctx
-                        .store_ctx()
-                        .caches
-                        .entry(|c: &mut ImageStatsCache| c.entry(&image));
-                    ColormapWithRange::default_range_for_depth_images(&image_stats)
-                });
-            let colormap_with_range = ColormapWithRange {
-                colormap,
-                value_range,
-            };
+/// Depth image processing shared with [`super::EncodedDepthImageVisualizer`].
+///
+/// Each image is either shown as a depth cloud (3D views under a pinhole) or as a colormapped
+/// textured rect.
+pub(super) fn process_depth_image_data(
+    ctx: &QueryContext<'_>,
+    data: &mut SpatialViewVisualizerData,
+    depth_cloud_entities: &mut IntMap<EntityPathHash, (ImageInfo, DepthMeter, ColormappedTexture)>,
+    depth_clouds: &mut Vec<DepthCloud>,
+    ent_context: &SpatialSceneEntityContext<'_>,
+    images: impl Iterator<Item = DepthImageComponentData>,
+    archetype_name: ArchetypeName,
+) {
+    let is_3d_view = ent_context.view_class_identifier == SpatialView3D::identifier();
+
+    let entity_path = ctx.target_entity_path;
+
+    for image_data in images {
+        let DepthImageComponentData {
+            image,
+            depth_meter,
+            fill_ratio,
+            colormap,
+            value_range,
+        } = image_data;
+
+        let depth_meter = depth_meter.unwrap_or_else(|| default_depth_meter(&image.format));
+
+        // All depth images must have a colormap:
+        let colormap = colormap.unwrap_or(ColormapWithRange::DEFAULT_DEPTH_COLORMAP);
+        let value_range = value_range
+            .map(|r| [r[0] as f32, r[1] as f32])
+            .unwrap_or_else(|| {
+                // Don't use fallback provider since it has to query information we already have.
+                let image_stats = ctx
+                    .store_ctx()
+                    .caches
+                    .entry(|c: &mut ImageStatsCache| c.entry(&image));
+                ColormapWithRange::default_range_for_depth_images(&image_stats)
+            });
+        let colormap_with_range = ColormapWithRange {
+            colormap,
+            value_range,
+        };
 
-            // First try to create a textured rect for this image.
-            // Even if we end up only showing a depth cloud,
-            // we still need most of this for ui interaction which still shows the image!
-            let Some(textured_rect) = textured_rect_from_image(
-                ctx.viewer_ctx(),
-                entity_path,
-                ent_context,
-                &image,
-                Some(&colormap_with_range),
-                re_renderer::Rgba::WHITE,
-                DepthImage::name(),
-            ) else {
-                // If we can't create a textured rect from this, we don't have to bother with clouds either.
-                return;
-            };
+        // First try to create a textured rect for this image.
+        // Even if we end up only showing a depth cloud,
+        // we still need most of this for ui interaction which still shows the image!
+        let Some(textured_rect) = textured_rect_from_image(
+            ctx.viewer_ctx(),
+            entity_path,
+            ent_context,
+            &image,
+            Some(&colormap_with_range),
+            re_renderer::Rgba::WHITE,
+            archetype_name,
+        ) else {
+            // If we can't create a textured rect from this, we don't have to bother with clouds either.
+            return;
+        };
 
-            if is_3d_view {
-                if let Some(twod_in_threed_info) = &ent_context.transform_info.twod_in_threed_info {
-                    let fill_ratio = fill_ratio.unwrap_or_default();
-
-                    // NOTE: we don't pass in `world_from_obj` because this corresponds to the
-                    // transform of the projection plane, which is of no use to us here.
-                    // What we want are the extrinsics of the depth camera!
-                    match Self::process_entity_view_as_depth_cloud(
-                        ctx,
-                        ent_context,
-                        entity_path,
-                        twod_in_threed_info,
-                        depth_meter,
-                        fill_ratio,
-                        &textured_rect.colormapped_texture,
-                    ) {
-                        Ok(cloud) => {
-                            self.data.add_bounding_box(
-                                entity_path.hash(),
-                                cloud.world_space_bbox(),
-                                glam::Affine3A::IDENTITY,
-                            );
-                            self.depth_cloud_entities.insert(
-                                entity_path.hash(),
-                                (image, depth_meter, textured_rect.colormapped_texture),
-                            );
-                            depth_clouds.push(cloud);
-
-                            // Skip creating a textured rect.
-                            return;
-                        }
-                        Err(err) => {
-                            re_log::warn_once!("{err}");
-                        }
+        if is_3d_view {
+            if let Some(twod_in_threed_info) = &ent_context.transform_info.twod_in_threed_info {
+                let fill_ratio = fill_ratio.unwrap_or_default();
+
+                // NOTE: we don't pass in `world_from_obj` because this corresponds to the
+                // transform of the projection plane, which is of no use to us here.
+                // What we want are the extrinsics of the depth camera!
+                match DepthImageVisualizer::process_entity_view_as_depth_cloud(
+                    ctx,
+                    ent_context,
+                    entity_path,
+                    twod_in_threed_info,
+                    depth_meter,
+                    fill_ratio,
+                    &textured_rect.colormapped_texture,
+                ) {
+                    Ok(cloud) => {
+                        data.add_bounding_box(
+                            entity_path.hash(),
+                            cloud.world_space_bbox(),
+                            glam::Affine3A::IDENTITY,
+                        );
+                        depth_cloud_entities.insert(
+                            entity_path.hash(),
+                            (image, depth_meter, textured_rect.colormapped_texture),
+                        );
+                        depth_clouds.push(cloud);
+
+                        // Skip creating a textured rect.
+                        return;
                     }
-                };
-            }
+                    Err(err) => {
+                        re_log::warn_once!("{err}");
+                    }
+                }
+            };
+        }
 
-            self.data.add_pickable_rect(
-                PickableTexturedRect {
-                    ent_path: entity_path.clone(),
-                    textured_rect,
-                    source_data: PickableRectSourceData::Image {
-                        image,
-                        depth_meter: Some(depth_meter),
-                    },
+        data.add_pickable_rect(
+            PickableTexturedRect {
+                ent_path: entity_path.clone(),
+                textured_rect,
+                source_data: PickableRectSourceData::Image {
+                    image,
+                    depth_meter: Some(depth_meter),
                 },
-                ent_context.view_class_identifier,
-            );
-        }
+            },
+            ent_context.view_class_identifier,
+        );
     }
+}
 
-    fn process_entity_view_as_depth_cloud(
+impl DepthImageVisualizer {
+    pub(super) fn process_entity_view_as_depth_cloud(
         ctx: &QueryContext<'_>,
         ent_context: &SpatialSceneEntityContext<'_>,
         ent_path: &EntityPath,
@@ -316,38 +322,21 @@ impl VisualizerSystem for DepthImageVisualizer {
                     },
                 );
 
-                self.process_depth_image_data(ctx, &mut depth_clouds, spatial_ctx, &mut data);
+                process_depth_image_data(
+                    ctx,
+                    &mut self.data,
+                    &mut self.depth_cloud_entities,
+                    &mut depth_clouds,
+                    spatial_ctx,
+                    &mut data,
+                    DepthImage::name(),
+                );
 
                 Ok(())
             },
         )?;
 
-        let mut draw_data_list = Vec::new();
-
-        match re_renderer::renderer::DepthCloudDrawData::new(
-            ctx.viewer_ctx.render_ctx(),
-            &DepthClouds {
-                clouds: depth_clouds,
-                radius_boost_in_ui_points_for_outlines:
-                    re_view::SIZE_BOOST_IN_POINTS_FOR_POINT_OUTLINES,
-            },
-        ) {
-            Ok(draw_data) => {
-                draw_data_list.push(draw_data.into());
-            }
-            Err(err) => {
-                re_log::error_once!(
-                    "Failed to create depth cloud draw data from depth images: {err}"
-                );
-            }
-        }
-
-        draw_data_list.push(PickableTexturedRect::to_draw_data(
-            ctx.viewer_ctx.render_ctx(),
-            &self.data.pickable_rects,
-        )?);
-
-        Ok(draw_data_list)
+        depth_images_draw_data(ctx, depth_clouds, &self.data)
     }
 
     fn data(&self) -> Option<&dyn std::any::Any> {
@@ -428,6 +417,43 @@ impl TypedComponentFallbackProvider<DepthMeter> for DepthImageVisualizer {
 
 re_viewer_context::impl_component_fallback_provider!(DepthImageVisualizer => [Colormap, ValueRange, DepthMeter, DrawOrder]);
 
-fn first_copied<T: Copy>(slice: Option<&[T]>) -> Option<T> {
+/// Draw data for the depth clouds and the remaining textured rects of a depth image visualizer.
+pub(super) fn depth_images_draw_data(
+    ctx: &ViewContext<'_>,
+    depth_clouds: Vec<DepthCloud>,
+    data: &SpatialViewVisualizerData,
+) -> Result<Vec<re_renderer::QueueableDrawData>, ViewSystemExecutionError> {
+    let mut draw_data_list = Vec::new();
+
+    match re_renderer::renderer::DepthCloudDrawData::new(
+        ctx.viewer_ctx.render_ctx(),
+        &DepthClouds {
+            clouds: depth_clouds,
+            radius_boost_in_ui_points_for_outlines:
+                re_view::SIZE_BOOST_IN_POINTS_FOR_POINT_OUTLINES,
+        },
+    ) {
+        Ok(draw_data) => {
+            draw_data_list.push(draw_data.into());
+        }
+        Err(err) => {
+            re_log::error_once!("Failed to create depth cloud draw data from depth images: {err}");
+        }
+    }
+
+    draw_data_list.push(PickableTexturedRect::to_draw_data(
+        ctx.viewer_ctx.render_ctx(),
+        &data.pickable_rects,
+    )?);
+
+    Ok(draw_data_list)
+}
+
+/// Meter used when none was logged: float images are assumed to be in meters, integer ones in millimeters.
+pub(super) fn default_depth_meter(format: &re_types::datatypes::ImageFormat) -> DepthMeter {
+    if format.is_float() { 1.0 } else { 1000.0 }.into()
+}
+
+pub(super) fn first_copied<T: Copy>(slice: Option<&[T]>) -> Option<T> {
     slice.and_then(|element| element.first()).copied()
 }
@@ -0,0 +1,247 @@
+use nohash_hasher::IntMap;
+
+use re_log_types::EntityPathHash;
+use re_renderer::renderer::ColormappedTexture;
+use re_types::{
+    Archetype as _,
+    archetypes::EncodedDepthImage,
+    components::{Blob, Colormap, DepthMeter, DrawOrder, MediaType, ValueRange},
+    image::ImageKind,
+};
+use re_viewer_context::{
+    ColormapWithRange, IdentifiedViewSystem, ImageDecodeCache, ImageInfo, ImageStatsCache,
+    MaybeVisualizableEntities, QueryContext, TypedComponentFallbackProvider, ViewContext,
+    ViewContextCollection, ViewQuery, ViewSystemExecutionError, VisualizableEntities,
+    VisualizableFilterContext, VisualizerQueryInfo, VisualizerSystem,
+};
+
+use crate::{view_kind::SpatialViewKind, visualizers::filter_visualizable_2d_entities};
+
+use super::{
+    SpatialViewVisualizerData,
+    depth_images::{
+        DepthImageComponentData, default_depth_meter, depth_images_draw_data, first_copied,
+        process_depth_image_data,
+    },
+};
+
+/// Decodes [`EncodedDepthImage`]s and shows them like regular depth images.
+pub struct EncodedDepthImageVisualizer {
+    pub data: SpatialViewVisualizerData,
+
+    /// Expose image infos for depth clouds - we need this for picking interaction.
+    pub depth_cloud_entities: IntMap<EntityPathHash, (ImageInfo, DepthMeter, ColormappedTexture)>,
+}
+
+impl Default for EncodedDepthImageVisualizer {
+    fn default() -> Self {
+        Self {
+            data: SpatialViewVisualizerData::new(Some(SpatialViewKind::TwoD)),
+            depth_cloud_entities: IntMap::default(),
+        }
+    }
+}
+
+impl IdentifiedViewSystem for EncodedDepthImageVisualizer {
+    fn identifier() -> re_viewer_context::ViewSystemIdentifier {
+        "EncodedDepthImage".into()
+    }
+}
+
+impl VisualizerSystem for EncodedDepthImageVisualizer {
+    fn visualizer_query_info(&self) -> VisualizerQueryInfo {
+        VisualizerQueryInfo::from_archetype::<EncodedDepthImage>()
+    }
+
+    fn filter_visualizable_entities(
+        &self,
+        entities: MaybeVisualizableEntities,
+        context: &dyn VisualizableFilterContext,
+    ) -> VisualizableEntities {
+        re_tracing::profile_function!();
+        filter_visualizable_2d_entities(entities, context)
+    }
+
+    fn execute(
+        &mut self,
+        ctx: &ViewContext<'_>,
+        view_query: &ViewQuery<'_>,
+        context_systems: &ViewContextCollection,
+    ) -> Result<Vec<re_renderer::QueueableDrawData>, ViewSystemExecutionError> {
+        let mut depth_clouds = Vec::new();
+
+        use super::entity_iterator::{iter_slices, process_archetype};
+        process_archetype::<Self, EncodedDepthImage, _>(
+            ctx,
+            view_query,
+            context_systems,
+            |ctx, spatial_ctx, results| {
+                use re_view::RangeResultsExt as _;
+
+                let entity_path = ctx.target_entity_path;
+
+                let Some(all_blob_chunks) =
+                    results.get_required_chunks(EncodedDepthImage::descriptor_blob())
+                else {
+                    return Ok(());
+                };
+
+                let timeline = ctx.query.timeline();
+                let all_blobs_indexed = iter_slices::<&[u8]>(&all_blob_chunks, timeline);
+                let all_media_types =
+                    results.iter_as(timeline, EncodedDepthImage::descriptor_media_type());
+                let all_colormaps =
+                    results.iter_as(timeline, EncodedDepthImage::descriptor_colormap());
+                let all_value_ranges =
+                    results.iter_as(timeline, EncodedDepthImage::descriptor_depth_range());
+                let all_depth_meters =
+                    results.iter_as(timeline, EncodedDepthImage::descriptor_meter());
+                let all_fill_ratios =
+                    results.iter_as(timeline, EncodedDepthImage::descriptor_point_fill_ratio());
+
+                let mut data = re_query::range_zip_1x5(
+                    all_blobs_indexed,
+                    all_media_types.slice::<String>(),
+                    all_colormaps.slice::<u8>(),
+                    all_value_ranges.slice::<[f64; 2]>(),
+                    all_depth_meters.slice::<f32>(),
+                    all_fill_ratios.slice::<f32>(),
+                )
+                .filter_map(
+                    |(
+                        (_time, row_id),
+                        blobs,
+                        media_types,
+                        colormap,
+                        value_range,
+                        depth_meter,
+                        fill_ratio,
+                    )| {
+                        let blob = blobs.first()?;
+                        let media_type = media_types
+                            .and_then(|media_types| media_types.first().cloned())
+                            .map(|media_type| MediaType(media_type.into()));
+
+                        let image = ctx.store_ctx().caches.entry(|c: &mut ImageDecodeCache| {
+                            c.entry(
+                                row_id,
+                                &EncodedDepthImage::descriptor_blob(),
+                                blob,
+                                media_type.as_ref(),
+                                ImageKind::Depth,
+                            )
+                        });
+                        let image = match image {
+                            Ok(image) => image,
+                            Err(err) => {
+                                re_log::warn_once!(
+                                    "Failed to decode EncodedDepthImage at path {entity_path}: {err}"
+                                );
+                                return None;
+                            }
+                        };
+
+                        Some(DepthImageComponentData {
+                            image,
+                            depth_meter: first_copied(depth_meter).map(Into::into),
+                            fill_ratio: first_copied(fill_ratio).map(Into::into),
+                            colormap: first_copied(colormap).and_then(Colormap::from_u8),
+                            value_range: first_copied(value_range),
+                        })
+                    },
+                );
+
+                process_depth_image_data(
+                    ctx,
+                    &mut self.data,
+                    &mut self.depth_cloud_entities,
+                    &mut depth_clouds,
+                    spatial_ctx,
+                    &mut data,
+                    EncodedDepthImage::name(),
+                );
+
+                Ok(())
+            },
+        )?;
+
+        depth_images_draw_data(ctx, depth_clouds, &self.data)
+    }
+
+    fn data(&self) -> Option<&dyn std::any::Any> {
+        Some(self.data.as_any())
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn fallback_provider(&self) -> &dyn re_viewer_context::ComponentFallbackProvider {
+        self
+    }
+}
+
+/// Decodes the latest blob of the queried entity, used by fallbacks that depend on the image contents.
+fn latest_decoded_image(ctx: &QueryContext<'_>) -> Option<ImageInfo> {
+    let ((_time, row_id), blob) = ctx.recording().latest_at_component::<Blob>(
+        ctx.target_entity_path,
+        ctx.query,
+        &EncodedDepthImage::descriptor_blob(),
+    )?;
+    let media_type = ctx
+        .recording()
+        .latest_at_component::<MediaType>(
+            ctx.target_entity_path,
+            ctx.query,
+            &EncodedDepthImage::descriptor_media_type(),
+        )
+        .map(|(_, media_type)| media_type);
+
+    ctx.store_ctx()
+        .caches
+        .entry(|c: &mut ImageDecodeCache| {
+            c.entry(
+                row_id,
+                &EncodedDepthImage::descriptor_blob(),
+                &blob,
+                media_type.as_ref(),
+                ImageKind::Depth,
+            )
+        })
+        .ok()
+}
+
+impl TypedComponentFallbackProvider<DrawOrder> for EncodedDepthImageVisualizer {
+    fn fallback_for(&self, _ctx: &QueryContext<'_>) -> DrawOrder {
+        DrawOrder::DEFAULT_DEPTH_IMAGE
+    }
+}
+
+impl TypedComponentFallbackProvider<ValueRange> for EncodedDepthImageVisualizer {
+    fn fallback_for(&self, ctx: &QueryContext<'_>) -> ValueRange {
+        if let Some(image) = latest_decoded_image(ctx) {
+            let cache = ctx.store_ctx().caches;
+            let image_stats = cache.entry(|c: &mut ImageStatsCache| c.entry(&image));
+            let default_range = ColormapWithRange::default_range_for_depth_images(&image_stats);
+            return [default_range[0] as f64, default_range[1] as f64].into();
+        }
+
+        [0.0, f64::MAX].into()
+    }
+}
+
+impl TypedComponentFallbackProvider<Colormap> for EncodedDepthImageVisualizer {
+    fn fallback_for(&self, _ctx: &QueryContext<'_>) -> Colormap {
+        ColormapWithRange::DEFAULT_DEPTH_COLORMAP
+    }
+}
+
+impl TypedComponentFallbackProvider<DepthMeter> for EncodedDepthImageVisualizer {
+    fn fallback_for(&self, ctx: &QueryContext<'_>) -> DepthMeter {
+        // Unlike `DepthImage`, the datatype is only known after decoding.
+        latest_decoded_image(ctx)
+            .map_or_else(|| 1000.0.into(), |image| default_depth_meter(&image.format))
+    }
+}
+
+re_viewer_context::impl_component_fallback_provider!(EncodedDepthImageVisualizer => [Colormap, ValueRange, DepthMeter, DrawOrder]);
@@ -0,0 +1,282 @@
+use re_chunk_store::RowId;
+use re_log_types::{Instance, TimeInt, hash::Hash64};
+use re_renderer::{
+    PickingLayerObjectId,
+    renderer::{GaussianSplat, GaussianSplatDrawData, GaussianSplats},
+};
+use re_types::{
+    Archetype as _,
+    archetypes::GaussianSplats3D,
+    components::{Color, Opacity},
+};
+use re_viewer_context::{
+    IdentifiedViewSystem, MaybeVisualizableEntities, QueryContext, TypedComponentFallbackProvider,
+    ViewContext, ViewContextCollection, ViewQuery, ViewSystemExecutionError, VisualizableEntities,
+    VisualizableFilterContext, VisualizerQueryInfo, VisualizerSystem, auto_color_for_entity_path,
+};
+
+use super::{SpatialViewVisualizerData, filter_visualizable_3d_entities};
+
+use crate::{
+    SpatialViewState,
+    contexts::SpatialSceneEntityContext,
+    splat_cache::{SortEye, SplatCache, SplatCacheKey},
+    view_kind::SpatialViewKind,
+};
+
+// ---
+
+pub struct GaussianSplats3DVisualizer(SpatialViewVisualizerData);
+
+impl Default for GaussianSplats3DVisualizer {
+    fn default() -> Self {
+        Self(SpatialViewVisualizerData::new(Some(
+            SpatialViewKind::ThreeD,
+        )))
+    }
+}
+
+struct GaussianSplats3DComponentData<'a> {
+    index: (TimeInt, RowId),
+    query_result_hash: Hash64,
+
+    // Point of views
+    centers: &'a [[f32; 3]],
+
+    // Clamped to edge
+    scales: &'a [[f32; 3]],
+    quaternions: &'a [[f32; 4]],
+    colors: &'a [Color],
+    opacities: &'a [Opacity],
+}
+
+/// Combines the components of a splat cloud, repeating the last value of shorter ones.
+fn splats_from_components(
+    data: &GaussianSplats3DComponentData<'_>,
+    fallback_color: Color,
+) -> Vec<GaussianSplat> {
+    use super::entity_iterator::clamped_or;
+
+    re_tracing::profile_function!();
+
+    let unit_scale = [1.0; 3];
+    let identity_quaternion = glam::Quat::IDENTITY.to_array();
+    let fully_opaque = Opacity::from(1.0);
+
+    let scales = clamped_or(data.scales, &unit_scale);
+    let quaternions = clamped_or(data.quaternions, &identity_quaternion);
+    let colors = clamped_or(data.colors, &fallback_color);
+    let opacities = clamped_or(data.opacities, &fully_opaque);
+
+    itertools::izip!(data.centers, scales, quaternions, colors, opacities)
+        .map(
+            |(center, scale, quaternion, color, opacity)| GaussianSplat {
+                center: glam::Vec3::from(*center),
+                scale: glam::Vec3::from(*scale),
+                rotation: glam::Quat::from_array(*quaternion),
+                color: (*color).into(),
+                opacity: opacity.0.0.clamp(0.0, 1.0),
+            },
+        )
+        .collect()
+}
+
+// NOTE: Do not put profile scopes in these methods. They are called for all entities and all
+// timestamps within a time range -- it's _a lot_.
+impl GaussianSplats3DVisualizer {
+    fn process_data<'a>(
+        &mut self,
+        ctx: &QueryContext<'_>,
+        clouds: &mut Vec<GaussianSplats>,
+        ent_context: &SpatialSceneEntityContext<'_>,
+        eye_in_world: Option<(glam::Vec3, glam::Vec3)>,
+        data: impl Iterator<Item = GaussianSplats3DComponentData<'a>>,
+    ) {
+        let entity_path = ctx.target_entity_path;
+        let render_ctx = ctx.render_ctx();
+        let fallback_color: Color = Fallback.fallback_for(ctx);
+
+        for data in data {
+            let primary_row_id = data.index.1;
+            let outline_mask_ids = ent_context.highlight.index_outline_mask(Instance::ALL);
+            let world_from_poses = ent_context
+                .transform_info
+                .reference_from_instances(GaussianSplats3D::name());
+
+            let key = SplatCacheKey {
+                versioned_instance_path_hash: re_entity_db::InstancePathHash::entity_all(
+                    entity_path,
+                )
+                .versioned(primary_row_id),
+                query_result_hash: data.query_result_hash,
+            };
+
+            // Sorting depends on where the cloud is placed, so every pose gets its own draw order.
+            let splats = ctx.store_ctx().caches.entry(|c: &mut SplatCache| {
+                let cached = c.entry(
+                    &entity_path.to_string(),
+                    key,
+                    || splats_from_components(&data, fallback_color),
+                    render_ctx,
+                )?;
+
+                let mut placed = Vec::with_capacity(world_from_poses.len());
+                for (pose_index, &world_from_pose) in world_from_poses.iter().enumerate() {
+                    let eye = eye_in_world.map(|(position, forward)| {
+                        SortEye::in_obj_space(world_from_pose, position, forward)
+                    });
+                    let draw_order = cached.draw_order(render_ctx, pose_index, eye)?;
+                    placed.push((world_from_pose, draw_order));
+                }
+
+                Some((cached.cloud.clone(), cached.bbox, placed))
+            });
+            let Some((cloud, bbox, placed)) = splats else {
+                continue;
+            };
+            if cloud.is_empty() {
+                continue;
+            }
+
+            for (world_from_pose, draw_order) in placed {
+                clouds.push(GaussianSplats {
+                    cloud: cloud.clone(),
+                    draw_order,
+                    world_from_obj: world_from_pose,
+                    outline_mask_ids,
+                    picking_object_id: PickingLayerObjectId(entity_path.hash64()),
+                });
+
+                self.0
+                    .add_bounding_box(entity_path.hash(), bbox, world_from_pose);
+            }
+        }
+    }
+}
+
+impl IdentifiedViewSystem for GaussianSplats3DVisualizer {
+    fn identifier() -> re_viewer_context::ViewSystemIdentifier {
+        "GaussianSplats3D".into()
+    }
+}
+
+impl VisualizerSystem for GaussianSplats3DVisualizer {
+    fn visualizer_query_info(&self) -> VisualizerQueryInfo {
+        VisualizerQueryInfo::from_archetype::<GaussianSplats3D>()
+    }
+
+    fn filter_visualizable_entities(
+        &self,
+        entities: MaybeVisualizableEntities,
+        context: &dyn VisualizableFilterContext,
+    ) -> VisualizableEntities {
+        re_tracing::profile_function!();
+        filter_visualizable_3d_entities(entities, context)
+    }
+
+    fn execute(
+        &mut self,
+        ctx: &ViewContext<'_>,
+        view_query: &ViewQuery<'_>,
+        context_systems: &ViewContextCollection,
+    ) -> Result<Vec<re_renderer::QueueableDrawData>, ViewSystemExecutionError> {
+        let mut clouds = Vec::new();
+
+        // Splats are sorted for the camera of the last frame, which is all we know at this point.
+        // Until the first frame has been shown, they are drawn in the order they were logged.
+        let eye_in_world = ctx
+            .view_state
+            .as_any()
+            .downcast_ref::<SpatialViewState>()
+            .and_then(|state| state.state_3d.view_eye)
+            .map(|view_eye| {
+                let eye = view_eye.to_eye();
+                (eye.pos_in_world(), eye.forward_in_world())
+            });
+
+        use super::entity_iterator::{iter_slices, process_archetype};
+        process_archetype::<Self, GaussianSplats3D, _>(
+            ctx,
+            view_query,
+            context_systems,
+            |ctx, spatial_ctx, results| {
+                use re_view::RangeResultsExt as _;
+
+                let Some(all_center_chunks) =
+                    results.get_required_chunks(GaussianSplats3D::descriptor_centers())
+                else {
+                    return Ok(());
+                };
+
+                let timeline = ctx.query.timeline();
+                let all_centers_indexed = iter_slices::<[f32; 3]>(&all_center_chunks, timeline);
+                let all_scales = results.iter_as(timeline, GaussianSplats3D::descriptor_scales());
+                let all_quaternions =
+                    results.iter_as(timeline, GaussianSplats3D::descriptor_quaternions());
+                let all_colors = results.iter_as(timeline, GaussianSplats3D::descriptor_colors());
+                let all_opacities =
+                    results.iter_as(timeline, GaussianSplats3D::descriptor_opacities());
+
+                let query_result_hash = results.query_result_hash();
+
+                let data = re_query::range_zip_1x4(
+                    all_centers_indexed,
+                    all_scales.slice::<[f32; 3]>(),
+                    all_quaternions.slice::<[f32; 4]>(),
+                    all_colors.slice::<u32>(),
+                    all_opacities.slice::<f32>(),
+                )
+                .filter_map(
+                    |(index, centers, scales, quaternions, colors, opacities)| {
+                        // Scales are required: without them, there is nothing to draw.
+                        let scales = scales.filter(|scales| !scales.is_empty())?;
+                        Some(GaussianSplats3DComponentData {
+                            index,
+                            query_result_hash,
+                            centers,
+                            scales,
+                            quaternions: quaternions.unwrap_or_default(),
+                            colors: colors.map_or(&[], |colors| bytemuck::cast_slice(colors)),
+                            opacities: opacities
+                                .map_or(&[], |opacities| bytemuck::cast_slice(opacities)),
+                        })
+                    },
+                );
+
+                self.process_data(ctx, &mut clouds, spatial_ctx, eye_in_world, data);
+
+                Ok(())
+            },
+        )?;
+
+        if clouds.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        Ok(vec![
+            GaussianSplatDrawData::new(ctx.viewer_ctx.render_ctx(), &clouds).into(),
+        ])
+    }
+
+    fn data(&self) -> Option<&dyn std::any::Any> {
+        Some(self.0.as_any())
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn fallback_provider(&self) -> &dyn re_viewer_context::ComponentFallbackProvider {
+        &Fallback
+    }
+}
+
+struct Fallback;
+
+impl TypedComponentFallbackProvider<Color> for Fallback {
+    fn fallback_for(&self, ctx: &QueryContext<'_>) -> Color {
+        auto_color_for_entity_path(ctx.target_entity_path)
+    }
+}
+
+re_viewer_context::impl_component_fallback_provider!(Fallback => [Color]);
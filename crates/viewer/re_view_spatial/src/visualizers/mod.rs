@@ -10,7 +10,9 @@ mod capsules3d;
 mod cylinders3d;
 mod depth_images;
 mod ellipsoids;
+mod encoded_depth_image;
 mod encoded_image;
+mod gaussian_splats;
 mod images;
 mod lines2d;
 mod lines3d;
@@ -18,12 +20,14 @@ mod meshes;
 mod points2d;
 mod points3d;
 mod segmentation_images;
+mod tensor_volumes;
 mod transform3d_arrows;
 mod utilities;
 mod video;
 
 pub use cameras::CamerasVisualizer;
 pub use depth_images::DepthImageVisualizer;
+pub use encoded_depth_image::EncodedDepthImageVisualizer;
 use re_types::{ComponentDescriptor, archetypes};
 pub use transform3d_arrows::{AxisLengthDetector, Transform3DArrowsVisualizer, add_axis_arrows};
 pub use utilities::{
@@ -76,6 +80,7 @@ pub fn register_2d_spatial_visualizers(
     system_registry.register_visualizer::<boxes2d::Boxes2DVisualizer>()?;
     system_registry.register_visualizer::<boxes3d::Boxes3DVisualizer>()?;
     system_registry.register_visualizer::<depth_images::DepthImageVisualizer>()?;
+    system_registry.register_visualizer::<encoded_depth_image::EncodedDepthImageVisualizer>()?;
     system_registry.register_visualizer::<encoded_image::EncodedImageVisualizer>()?;
     system_registry.register_visualizer::<images::ImageVisualizer>()?;
     system_registry.register_visualizer::<lines2d::Lines2DVisualizer>()?;
@@ -104,7 +109,9 @@ pub fn register_3d_spatial_visualizers(
     system_registry.register_visualizer::<cylinders3d::Cylinders3DVisualizer>()?;
     system_registry.register_visualizer::<depth_images::DepthImageVisualizer>()?;
     system_registry.register_visualizer::<ellipsoids::Ellipsoids3DVisualizer>()?;
+    system_registry.register_visualizer::<encoded_depth_image::EncodedDepthImageVisualizer>()?;
     system_registry.register_visualizer::<encoded_image::EncodedImageVisualizer>()?;
+    system_registry.register_visualizer::<gaussian_splats::GaussianSplats3DVisualizer>()?;
     system_registry.register_visualizer::<images::ImageVisualizer>()?;
     system_registry.register_visualizer::<lines2d::Lines2DVisualizer>()?;
     system_registry.register_visualizer::<lines3d::Lines3DVisualizer>()?;
@@ -112,6 +119,8 @@ pub fn register_3d_spatial_visualizers(
     system_registry.register_visualizer::<points2d::Points2DVisualizer>()?;
     system_registry.register_visualizer::<points3d::Points3DVisualizer>()?;
     system_registry.register_visualizer::<segmentation_images::SegmentationImageVisualizer>()?;
+    system_registry.register_visualizer::<tensor_volumes::TensorIsoSurfaceVisualizer>()?;
+    system_registry.register_visualizer::<tensor_volumes::TensorVolumeVisualizer>()?;
     system_registry.register_visualizer::<transform3d_arrows::AxisLengthDetector>()?;
     system_registry.register_visualizer::<transform3d_arrows::Transform3DArrowsVisualizer>()?;
     system_registry.register_visualizer::<video::VideoFrameReferenceVisualizer>()?;
@@ -136,6 +145,10 @@ pub fn visualizers_processing_draw_order()
             depth_images::DepthImageVisualizer::identifier(),
             archetypes::DepthImage::descriptor_draw_order(),
         ),
+        (
+            encoded_depth_image::EncodedDepthImageVisualizer::identifier(),
+            archetypes::EncodedDepthImage::descriptor_draw_order(),
+        ),
         (
             encoded_image::EncodedImageVisualizer::identifier(),
             archetypes::EncodedImage::descriptor_draw_order(),
@@ -1,5 +1,7 @@
 use itertools::Itertools as _;
 
+use re_chunk_store::RowId;
+use re_log_types::TimeInt;
 use re_renderer::{LineDrawableBuilder, PickingLayerInstanceId, PointCloudBuilder};
 use re_types::{
     Archetype as _, ArrowString,
@@ -14,7 +16,10 @@ use re_viewer_context::{
 };
 
 use crate::{
+    SpatialViewState,
     contexts::SpatialSceneEntityContext,
+    eye::Eye,
+    point_cloud_lod::{LOD_MIN_NUM_POINTS, LodView, PointCloudLodCache},
     view_kind::SpatialViewKind,
     visualizers::{load_keypoint_connections, process_radius_slice},
 };
@@ -39,6 +44,8 @@ impl Default for Points3DVisualizer {
 }
 
 struct Points3DComponentData<'a> {
+    index: (TimeInt, RowId),
+
     // Point of views
     positions: &'a [Position3D],
 
@@ -53,9 +60,51 @@ struct Points3DComponentData<'a> {
     show_labels: Option<ShowLabels>,
 }
 
+impl Points3DComponentData<'_> {
+    /// Picks out the given points, repeating the last value of shorter components like drawing would.
+    fn gather(&self, indices: &[u32]) -> Points3DSubset {
+        fn gather<T: Clone>(values: &[T], indices: &[u32]) -> Vec<T> {
+            let Some(last) = values.last() else {
+                return Vec::new();
+            };
+            indices
+                .iter()
+                .map(|&index| values.get(index as usize).unwrap_or(last).clone())
+                .collect()
+        }
+
+        Points3DSubset {
+            positions: gather(self.positions, indices),
+            colors: gather(self.colors, indices),
+            radii: gather(self.radii, indices),
+            labels: gather(&self.labels, indices),
+            keypoint_ids: gather(self.keypoint_ids, indices),
+            class_ids: gather(self.class_ids, indices),
+        }
+    }
+}
+
+/// Owned components of a subset of the points of a [`Points3DComponentData`].
+struct Points3DSubset {
+    positions: Vec<Position3D>,
+    colors: Vec<Color>,
+    radii: Vec<Radius>,
+    labels: Vec<ArrowString>,
+    keypoint_ids: Vec<KeypointId>,
+    class_ids: Vec<ClassId>,
+}
+
+/// Position of the eye in the world & how many pixels it sees per radian, as of the last frame.
+#[derive(Clone, Copy)]
+struct LodEye {
+    position_in_world: glam::Vec3,
+    pixels_per_radian: f32,
+}
+
 // NOTE: Do not put profile scopes in these methods. They are called for all entities and all
 // timestamps within a time range -- it's _a lot_.
 impl Points3DVisualizer {
+    #[allow(clippy::too_many_arguments)]
     fn process_data<'a>(
         &mut self,
         ctx: &QueryContext<'_>,
@@ -63,19 +112,66 @@ impl Points3DVisualizer {
         line_builder: &mut LineDrawableBuilder<'_>,
         query: &ViewQuery<'_>,
         ent_context: &SpatialSceneEntityContext<'_>,
+        lod_eye: Option<LodEye>,
         data: impl Iterator<Item = Points3DComponentData<'a>>,
     ) -> Result<(), ViewSystemExecutionError> {
         let entity_path = ctx.target_entity_path;
 
         for data in data {
-            let num_instances = data.positions.len();
-            if num_instances == 0 {
+            if data.positions.is_empty() {
                 continue;
             }
 
-            let picking_ids = (0..num_instances)
-                .map(|i| PickingLayerInstanceId(i as _))
-                .collect_vec();
+            let world_from_obj = ent_context
+                .transform_info
+                .single_entity_transform_required(entity_path, Points3D::name());
+
+            // Very large point clouds only get a subset of their points drawn, depending on the view.
+            // Picking ids stay the original instance indices.
+            let lod = (data.positions.len() >= LOD_MIN_NUM_POINTS).then(|| {
+                let view = lod_eye.map(|eye| LodView {
+                    eye_position: world_from_obj
+                        .inverse()
+                        .transform_point3(eye.position_in_world),
+                    pixels_per_radian: eye.pixels_per_radian,
+                });
+                let egui_ctx = ctx.egui_ctx().clone();
+                ctx.store_ctx().caches.entry(|c: &mut PointCloudLodCache| {
+                    c.select(
+                        data.index.1,
+                        bytemuck::cast_slice(data.positions),
+                        view,
+                        move || egui_ctx.request_repaint(),
+                    )
+                })
+            });
+            let mut subset = lod
+                .as_ref()
+                .map(|(_bbox, selection)| data.gather(selection));
+            let data = match &mut subset {
+                Some(subset) => Points3DComponentData {
+                    index: data.index,
+                    labels: std::mem::take(&mut subset.labels),
+                    positions: &subset.positions,
+                    colors: &subset.colors,
+                    radii: &subset.radii,
+                    keypoint_ids: &subset.keypoint_ids,
+                    class_ids: &subset.class_ids,
+                    show_labels: data.show_labels,
+                },
+                None => data,
+            };
+            let num_instances = data.positions.len();
+
+            let picking_ids = match &lod {
+                Some((_bbox, selection)) => selection
+                    .iter()
+                    .map(|&index| PickingLayerInstanceId(index as _))
+                    .collect_vec(),
+                None => (0..num_instances)
+                    .map(|i| PickingLayerInstanceId(i as _))
+                    .collect_vec(),
+            };
 
             let (annotation_infos, keypoints) = process_annotation_and_keypoint_slices(
                 query.latest_at,
@@ -95,10 +191,6 @@ impl Points3DVisualizer {
             let colors =
                 process_color_slice(ctx, self, num_instances, &annotation_infos, data.colors);
 
-            let world_from_obj = ent_context
-                .transform_info
-                .single_entity_transform_required(entity_path, Points3D::name());
-
             {
                 let point_batch = point_builder
                     .batch(entity_path.to_string())
@@ -112,9 +204,14 @@ impl Points3DVisualizer {
                 // Determine if there's any sub-ranges that need extra highlighting.
                 {
                     for (highlighted_key, instance_mask_ids) in &ent_context.highlight.instances {
-                        let highlighted_point_index = (highlighted_key.get()
-                            < num_instances as u64)
-                            .then_some(highlighted_key.get());
+                        let highlighted_point_index = match &lod {
+                            Some((_bbox, selection)) => selection
+                                .iter()
+                                .position(|&index| index as u64 == highlighted_key.get())
+                                .map(|position| position as u64),
+                            None => (highlighted_key.get() < num_instances as u64)
+                                .then_some(highlighted_key.get()),
+                        };
                         if let Some(highlighted_point_index) = highlighted_point_index {
                             point_range_builder = point_range_builder
                                 .push_additional_outline_mask_ids_for_range(
@@ -127,7 +224,10 @@ impl Points3DVisualizer {
                 }
             }
 
-            let obj_space_bounding_box = macaw::BoundingBox::from_points(positions.iter().copied());
+            let obj_space_bounding_box = match &lod {
+                Some((bbox, _selection)) => *bbox,
+                None => macaw::BoundingBox::from_points(positions.iter().copied()),
+            };
             self.data
                 .add_bounding_box(entity_path.hash(), obj_space_bounding_box, world_from_obj);
 
@@ -196,6 +296,21 @@ impl VisualizerSystem for Points3DVisualizer {
             re_view::SIZE_BOOST_IN_POINTS_FOR_POINT_OUTLINES,
         );
 
+        // Level of detail is based on the last frame's eye, the current one isn't known yet.
+        let lod_eye = ctx
+            .view_state
+            .as_any()
+            .downcast_ref::<SpatialViewState>()
+            .and_then(|state| {
+                let eye = state.state_3d.view_eye?.to_eye();
+                let [_width, height] = state.state_3d.viewport_resolution_in_pixels?;
+                let fov_y = eye.fov_y.unwrap_or(Eye::DEFAULT_FOV_Y);
+                Some(LodEye {
+                    position_in_world: eye.pos_in_world(),
+                    pixels_per_radian: height as f32 / (2.0 * (0.5 * fov_y).tan()),
+                })
+            });
+
         use super::entity_iterator::{iter_slices, process_archetype};
         process_archetype::<Self, Points3D, _>(
             ctx,
@@ -243,7 +358,7 @@ impl VisualizerSystem for Points3DVisualizer {
                 )
                 .map(
                     |(
-                        _index,
+                        index,
                         positions,
                         colors,
                         radii,
@@ -253,6 +368,7 @@ impl VisualizerSystem for Points3DVisualizer {
                         show_labels,
                     )| {
                         Points3DComponentData {
+                            index,
                             positions: bytemuck::cast_slice(positions),
                             colors: colors.map_or(&[], |colors| bytemuck::cast_slice(colors)),
                             radii: radii.map_or(&[], |radii| bytemuck::cast_slice(radii)),
@@ -274,6 +390,7 @@ impl VisualizerSystem for Points3DVisualizer {
                     &mut line_builder,
                     view_query,
                     spatial_ctx,
+                    lod_eye,
                     data,
                 )
             },
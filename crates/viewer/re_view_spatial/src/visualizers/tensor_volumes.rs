@@ -0,0 +1,381 @@
+use re_log_types::{EntityPathHash, hash::Hash64};
+use re_renderer::{
+    PickingLayerObjectId,
+    renderer::{Volume, VolumeDrawData, VolumeRenderMode},
+};
+use re_types::{
+    Archetype as _, ComponentDescriptor,
+    archetypes::Tensor,
+    blueprint::archetypes::TensorVolume,
+    components::{Colormap, Opacity, Scalar, TensorData, ValueRange},
+};
+use re_view::{HybridLatestAtResults, latest_at_with_blueprint_resolved_data};
+use re_viewer_context::{
+    IdentifiedViewSystem, MaybeVisualizableEntities, QueryContext, TensorStatsCache,
+    TypedComponentFallbackProvider, ViewContext, ViewContextCollection, ViewQuery,
+    ViewSystemExecutionError, VisualizableEntities, VisualizableFilterContext, VisualizerQueryInfo,
+    VisualizerSystem, gpu_bridge::colormap_to_re_renderer,
+};
+
+use super::{SpatialViewVisualizerData, filter_visualizable_3d_entities};
+
+use crate::{
+    contexts::TransformTreeContext, view_kind::SpatialViewKind, volume_cache::VolumeCache,
+};
+
+// ---
+
+/// How many samples are taken per voxel along each ray.
+const SAMPLES_PER_VOXEL: f32 = 2.0;
+
+/// Query info for tensors shown as volumes.
+///
+/// The tensor itself comes from the [`Tensor`] archetype,
+/// all other settings from the [`TensorVolume`] blueprint archetype.
+///
+/// There are deliberately no indicators: tensors are shown in the tensor view by default
+/// and only end up in a 3D view if a volume visualizer is picked explicitly.
+fn volume_query_info(extra: ComponentDescriptor) -> VisualizerQueryInfo {
+    let mut query_info = VisualizerQueryInfo::from_archetype::<Tensor>();
+    query_info.indicators = Default::default();
+    query_info
+        .queried
+        .extend([TensorVolume::descriptor_colormap(), extra]);
+    query_info
+}
+
+/// Shows three-dimensional tensors as a semi-transparent volume.
+pub struct TensorVolumeVisualizer(SpatialViewVisualizerData);
+
+impl Default for TensorVolumeVisualizer {
+    fn default() -> Self {
+        Self(SpatialViewVisualizerData::new(Some(
+            SpatialViewKind::ThreeD,
+        )))
+    }
+}
+
+impl IdentifiedViewSystem for TensorVolumeVisualizer {
+    fn identifier() -> re_viewer_context::ViewSystemIdentifier {
+        "TensorVolume".into()
+    }
+}
+
+impl VisualizerSystem for TensorVolumeVisualizer {
+    fn visualizer_query_info(&self) -> VisualizerQueryInfo {
+        volume_query_info(TensorVolume::descriptor_opacity())
+    }
+
+    fn filter_visualizable_entities(
+        &self,
+        entities: MaybeVisualizableEntities,
+        context: &dyn VisualizableFilterContext,
+    ) -> VisualizableEntities {
+        re_tracing::profile_function!();
+        filter_visualizable_3d_entities(entities, context)
+    }
+
+    fn execute(
+        &mut self,
+        ctx: &ViewContext<'_>,
+        view_query: &ViewQuery<'_>,
+        context_systems: &ViewContextCollection,
+    ) -> Result<Vec<re_renderer::QueueableDrawData>, ViewSystemExecutionError> {
+        let query_info = self.visualizer_query_info();
+        let volumes = collect_volumes::<Self, _>(
+            ctx,
+            view_query,
+            context_systems,
+            &query_info,
+            self,
+            |results, _value_range| {
+                let opacity = results
+                    .get_mono_with_fallback::<Opacity>(&TensorVolume::descriptor_opacity(), self);
+                VolumeRenderMode::Accumulate {
+                    opacity: opacity.0.0,
+                }
+            },
+        )?;
+
+        Ok(finish_volumes(&mut self.0, ctx, volumes))
+    }
+
+    fn data(&self) -> Option<&dyn std::any::Any> {
+        Some(self.0.as_any())
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn fallback_provider(&self) -> &dyn re_viewer_context::ComponentFallbackProvider {
+        self
+    }
+}
+
+/// Shows the iso-surface of three-dimensional tensors at a given value.
+pub struct TensorIsoSurfaceVisualizer(SpatialViewVisualizerData);
+
+impl Default for TensorIsoSurfaceVisualizer {
+    fn default() -> Self {
+        Self(SpatialViewVisualizerData::new(Some(
+            SpatialViewKind::ThreeD,
+        )))
+    }
+}
+
+impl IdentifiedViewSystem for TensorIsoSurfaceVisualizer {
+    fn identifier() -> re_viewer_context::ViewSystemIdentifier {
+        "TensorIsoSurface".into()
+    }
+}
+
+impl VisualizerSystem for TensorIsoSurfaceVisualizer {
+    fn visualizer_query_info(&self) -> VisualizerQueryInfo {
+        volume_query_info(TensorVolume::descriptor_iso_value())
+    }
+
+    fn filter_visualizable_entities(
+        &self,
+        entities: MaybeVisualizableEntities,
+        context: &dyn VisualizableFilterContext,
+    ) -> VisualizableEntities {
+        re_tracing::profile_function!();
+        filter_visualizable_3d_entities(entities, context)
+    }
+
+    fn execute(
+        &mut self,
+        ctx: &ViewContext<'_>,
+        view_query: &ViewQuery<'_>,
+        context_systems: &ViewContextCollection,
+    ) -> Result<Vec<re_renderer::QueueableDrawData>, ViewSystemExecutionError> {
+        let query_info = self.visualizer_query_info();
+        let volumes = collect_volumes::<Self, _>(
+            ctx,
+            view_query,
+            context_systems,
+            &query_info,
+            self,
+            |results, value_range| {
+                // The fallback depends on the value range, which may itself be overridden.
+                let iso_value = results
+                    .get_mono::<Scalar>(&TensorVolume::descriptor_iso_value())
+                    .map_or_else(|| range_center(value_range), |iso_value| iso_value.0.0);
+                VolumeRenderMode::IsoSurface {
+                    iso_value: iso_value as f32,
+                }
+            },
+        )?;
+
+        Ok(finish_volumes(&mut self.0, ctx, volumes))
+    }
+
+    fn data(&self) -> Option<&dyn std::any::Any> {
+        Some(self.0.as_any())
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn fallback_provider(&self) -> &dyn re_viewer_context::ComponentFallbackProvider {
+        self
+    }
+}
+
+// ---
+
+/// Adds the bounding boxes of all volumes and creates their draw data.
+fn finish_volumes(
+    data: &mut SpatialViewVisualizerData,
+    ctx: &ViewContext<'_>,
+    volumes: Vec<(Volume, EntityPathHash, glam::Affine3A)>,
+) -> Vec<re_renderer::QueueableDrawData> {
+    if volumes.is_empty() {
+        return Vec::new();
+    }
+
+    let mut gpu_volumes = Vec::with_capacity(volumes.len());
+    for (volume, entity_path_hash, world_from_entity) in volumes {
+        let dimensions = volume.texture.dimensions().as_vec3();
+        data.add_bounding_box(
+            entity_path_hash,
+            macaw::BoundingBox::from_min_max(glam::Vec3::ZERO, dimensions),
+            world_from_entity,
+        );
+        gpu_volumes.push(volume);
+    }
+
+    vec![VolumeDrawData::new(ctx.viewer_ctx.render_ctx(), &gpu_volumes).into()]
+}
+
+/// Queries all visible tensors of a visualizer and turns them into [`Volume`]s,
+/// together with their entity and the transform from their entity to the world.
+///
+/// A tensor of shape `[x, y, z]` spans one unit per voxel in its entity's space.
+fn collect_volumes<System, Fallbacks>(
+    ctx: &ViewContext<'_>,
+    view_query: &ViewQuery<'_>,
+    context_systems: &ViewContextCollection,
+    query_info: &VisualizerQueryInfo,
+    fallback_provider: &Fallbacks,
+    mode: impl Fn(&HybridLatestAtResults<'_>, ValueRange) -> VolumeRenderMode,
+) -> Result<Vec<(Volume, EntityPathHash, glam::Affine3A)>, ViewSystemExecutionError>
+where
+    System: IdentifiedViewSystem,
+    Fallbacks:
+        TypedComponentFallbackProvider<Colormap> + TypedComponentFallbackProvider<ValueRange>,
+{
+    re_tracing::profile_function!();
+
+    let transforms = context_systems.get::<TransformTreeContext>()?;
+    let latest_at_query = view_query.latest_at_query();
+    let render_ctx = ctx.viewer_ctx.render_ctx();
+
+    let mut volumes = Vec::new();
+
+    for data_result in view_query.iter_visible_data_results(System::identifier()) {
+        let entity_path = &data_result.entity_path;
+        let Some(transform_info) = transforms.transform_info_for_entity(entity_path.hash()) else {
+            continue;
+        };
+
+        let results = latest_at_with_blueprint_resolved_data(
+            ctx,
+            None,
+            &latest_at_query,
+            data_result,
+            query_info.queried.iter(),
+            false,
+        );
+
+        let Some(tensor) = results.get_required_mono::<TensorData>(&Tensor::descriptor_data())
+        else {
+            continue;
+        };
+        let Some(tensor_row_id) = results.results.component_row_id(&Tensor::descriptor_data())
+        else {
+            continue;
+        };
+
+        let Some(texture) = ctx
+            .viewer_ctx
+            .store_context
+            .caches
+            .entry(|c: &mut VolumeCache| {
+                c.entry(
+                    &entity_path.to_string(),
+                    tensor_row_id,
+                    &tensor.0,
+                    render_ctx,
+                )
+            })
+        else {
+            continue;
+        };
+
+        let value_range = results.get_mono_with_fallback::<ValueRange>(
+            &Tensor::descriptor_value_range(),
+            fallback_provider,
+        );
+        let colormap = results.get_mono_with_fallback::<Colormap>(
+            &TensorVolume::descriptor_colormap(),
+            fallback_provider,
+        );
+        let mode = mode(&results, value_range);
+
+        let outline_mask_id = view_query
+            .highlights
+            .entity_outline_mask(entity_path.hash())
+            .overall;
+        let entity_from_volume = glam::Affine3A::from_scale(texture.dimensions().as_vec3());
+
+        for &world_from_entity in transform_info.reference_from_instances(Tensor::name()) {
+            volumes.push((
+                Volume {
+                    world_from_volume: world_from_entity * entity_from_volume,
+                    texture: texture.clone(),
+                    value_range: [value_range.start() as f32, value_range.end() as f32],
+                    colormap: colormap_to_re_renderer(colormap),
+                    mode,
+                    samples_per_voxel: SAMPLES_PER_VOXEL,
+                    outline_mask_id,
+                    picking_object_id: PickingLayerObjectId(entity_path.hash64()),
+                },
+                entity_path.hash(),
+                world_from_entity,
+            ));
+        }
+    }
+
+    Ok(volumes)
+}
+
+// ---
+
+fn range_center(range: ValueRange) -> f64 {
+    0.5 * (range.start() + range.end())
+}
+
+fn value_range_fallback(ctx: &QueryContext<'_>) -> ValueRange {
+    let Some(((_time, row_id), tensor)) = ctx.recording().latest_at_component::<TensorData>(
+        ctx.target_entity_path,
+        ctx.query,
+        &Tensor::descriptor_data(),
+    ) else {
+        return ValueRange::new(0.0, 1.0);
+    };
+
+    let tensor_stats = ctx
+        .store_ctx()
+        .caches
+        .entry(|c: &mut TensorStatsCache| c.entry(Hash64::hash(row_id), &tensor));
+    let (min, max) = tensor_stats.finite_range;
+    if min == max {
+        // Don't let a uniform volume explode the colormapping.
+        ValueRange::new(min - 1.0, max + 1.0)
+    } else {
+        ValueRange::new(min, max)
+    }
+}
+
+impl TypedComponentFallbackProvider<ValueRange> for TensorVolumeVisualizer {
+    fn fallback_for(&self, ctx: &QueryContext<'_>) -> ValueRange {
+        value_range_fallback(ctx)
+    }
+}
+
+impl TypedComponentFallbackProvider<ValueRange> for TensorIsoSurfaceVisualizer {
+    fn fallback_for(&self, ctx: &QueryContext<'_>) -> ValueRange {
+        value_range_fallback(ctx)
+    }
+}
+
+impl TypedComponentFallbackProvider<Colormap> for TensorVolumeVisualizer {
+    fn fallback_for(&self, _ctx: &QueryContext<'_>) -> Colormap {
+        Colormap::Viridis
+    }
+}
+
+impl TypedComponentFallbackProvider<Colormap> for TensorIsoSurfaceVisualizer {
+    fn fallback_for(&self, _ctx: &QueryContext<'_>) -> Colormap {
+        Colormap::Viridis
+    }
+}
+
+impl TypedComponentFallbackProvider<Opacity> for TensorVolumeVisualizer {
+    fn fallback_for(&self, _ctx: &QueryContext<'_>) -> Opacity {
+        // Opacity applies per voxel, so even small values add up quickly.
+        0.05.into()
+    }
+}
+
+impl TypedComponentFallbackProvider<Scalar> for TensorIsoSurfaceVisualizer {
+    fn fallback_for(&self, ctx: &QueryContext<'_>) -> Scalar {
+        range_center(value_range_fallback(ctx)).into()
+    }
+}
+
+re_viewer_context::impl_component_fallback_provider!(TensorVolumeVisualizer => [Colormap, Opacity, ValueRange]);
+re_viewer_context::impl_component_fallback_provider!(TensorIsoSurfaceVisualizer => [Colormap, Scalar, ValueRange]);
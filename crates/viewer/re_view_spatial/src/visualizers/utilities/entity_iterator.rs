@@ -1,8 +1,12 @@
+use re_chunk_store::LatestAtQuery;
 use re_log_types::{TimeInt, TimelineName};
 use re_types::Archetype;
-use re_view::{AnnotationSceneContext, ChunksWithDescriptor, DataResultQuery as _, HybridResults};
+use re_view::{
+    AnnotationSceneContext, ChunksWithDescriptor, DataResultQuery as _, HybridResults,
+    query_archetype_with_history,
+};
 use re_viewer_context::{
-    IdentifiedViewSystem, QueryContext, ViewContext, ViewContextCollection, ViewQuery,
+    DataResult, IdentifiedViewSystem, QueryContext, ViewContext, ViewContextCollection, ViewQuery,
     ViewSystemExecutionError,
 };
 
@@ -105,15 +109,12 @@ where
 
     let system_identifier = System::identifier();
 
-    for data_result in query.iter_visible_data_results(system_identifier) {
-        let Some(transform_info) =
-            transforms.transform_info_for_entity(data_result.entity_path.hash())
-        else {
-            continue;
-        };
+    let entity_context = |data_result: &DataResult| {
+        let transform_info =
+            transforms.transform_info_for_entity(data_result.entity_path.hash())?;
 
         let depth_offset_key = (system_identifier, data_result.entity_path.hash());
-        let entity_context = SpatialSceneEntityContext {
+        Some(SpatialSceneEntityContext {
             transform_info,
             depth_offset: depth_offsets
                 .per_entity_and_visualizer
@@ -125,6 +126,12 @@ where
                 .highlights
                 .entity_outline_mask(data_result.entity_path.hash()),
             view_class_identifier: context_systems.view_class_identifier(),
+        })
+    };
+
+    for data_result in query.iter_visible_data_results(system_identifier) {
+        let Some(entity_context) = entity_context(data_result) else {
+            continue;
         };
 
         let results = data_result.query_archetype_with_history::<A>(ctx, query);
@@ -138,6 +145,42 @@ where
         }
     }
 
+    // Draw the same entities from the compared recording on top, placed like their counterparts
+    // in the active recording.
+    ctx.with_compared_recording(|ctx, comparison| -> Result<(), ViewSystemExecutionError> {
+        let latest_at = LatestAtQuery::new(
+            query.timeline,
+            comparison.to_compared_time(&query.timeline, query.latest_at),
+        );
+
+        for data_result in query.iter_visible_data_results(system_identifier) {
+            if !ctx.recording().is_known_entity(&data_result.entity_path) {
+                continue;
+            }
+            let Some(entity_context) = entity_context(data_result) else {
+                continue;
+            };
+
+            let results = query_archetype_with_history(
+                ctx,
+                &query.timeline,
+                latest_at.at(),
+                data_result.query_range(),
+                A::all_components().iter(),
+                data_result,
+            );
+
+            let mut query_ctx = ctx.query_context(data_result, &latest_at);
+            query_ctx.archetype_name = Some(A::name());
+
+            re_tracing::profile_scope!(format!("{} (compared)", data_result.entity_path));
+            fun(&query_ctx, &entity_context, &results)?;
+        }
+
+        Ok(())
+    })
+    .transpose()?;
+
     Ok(())
 }
 
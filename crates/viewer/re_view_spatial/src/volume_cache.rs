@@ -0,0 +1,179 @@
+use ahash::{HashMap, HashSet};
+
+use itertools::Either;
+use re_chunk_store::{ChunkStoreEvent, RowId};
+use re_renderer::{RenderContext, renderer::VolumeTexture};
+use re_types::{
+    archetypes::Tensor,
+    datatypes::{TensorBuffer, TensorData},
+};
+use re_viewer_context::Cache;
+
+// ----------------------------------------------------------------------------
+
+/// Caches the GPU volumes of three-dimensional tensors, keyed by the [`RowId`] of their [`TensorData`].
+///
+/// `None` entries mean that the tensor can't be shown as a volume, so we don't try again every frame.
+#[derive(Default)]
+pub struct VolumeCache(HashMap<RowId, Option<VolumeTexture>>);
+
+impl VolumeCache {
+    pub fn entry(
+        &mut self,
+        name: &str,
+        tensor_row_id: RowId,
+        tensor: &TensorData,
+        render_ctx: &RenderContext,
+    ) -> Option<VolumeTexture> {
+        self.0
+            .entry(tensor_row_id)
+            .or_insert_with(|| {
+                re_tracing::profile_scope!("upload volume");
+
+                let Some((dimensions, values)) = volume_values_from_tensor(tensor) else {
+                    re_log::warn_once!(
+                        "Can't show tensor {name:?} with shape {:?} as a volume, it needs exactly three dimensions larger than one.",
+                        tensor.shape()
+                    );
+                    return None;
+                };
+
+                match VolumeTexture::new(render_ctx, name, dimensions, &values) {
+                    Ok(texture) => Some(texture),
+                    Err(err) => {
+                        re_log::warn!("Failed to upload volume {name:?}: {err}");
+                        None
+                    }
+                }
+            })
+            .clone()
+    }
+}
+
+/// Converts a tensor with exactly three non-trivial dimensions into a dense volume of floats.
+///
+/// The tensor's dimensions map to the volume's x, y & z axes in order,
+/// i.e. `tensor[x, y, z]` is the voxel at `(x, y, z)`.
+/// The returned values are laid out with x varying fastest.
+fn volume_values_from_tensor(tensor: &TensorData) -> Option<(glam::UVec3, Vec<f32>)> {
+    let shape: Vec<u64> = tensor
+        .shape()
+        .iter()
+        .copied()
+        .filter(|&size| size > 1)
+        .collect();
+    let [size_x, size_y, size_z] = shape.as_slice() else {
+        return None;
+    };
+    let dimensions = glam::UVec3::new(
+        u32::try_from(*size_x).ok()?,
+        u32::try_from(*size_y).ok()?,
+        u32::try_from(*size_z).ok()?,
+    );
+
+    let row_major: Vec<f32> = match &tensor.buffer {
+        TensorBuffer::U8(buf) => buf.iter().map(|&v| v as f32).collect(),
+        TensorBuffer::U16(buf) => buf.iter().map(|&v| v as f32).collect(),
+        TensorBuffer::U32(buf) => buf.iter().map(|&v| v as f32).collect(),
+        TensorBuffer::U64(buf) => buf.iter().map(|&v| v as f32).collect(),
+        TensorBuffer::I8(buf) => buf.iter().map(|&v| v as f32).collect(),
+        TensorBuffer::I16(buf) => buf.iter().map(|&v| v as f32).collect(),
+        TensorBuffer::I32(buf) => buf.iter().map(|&v| v as f32).collect(),
+        TensorBuffer::I64(buf) => buf.iter().map(|&v| v as f32).collect(),
+        TensorBuffer::F16(buf) => buf.iter().map(|v| v.to_f32()).collect(),
+        TensorBuffer::F32(buf) => buf.to_vec(),
+        TensorBuffer::F64(buf) => buf.iter().map(|&v| v as f32).collect(),
+    };
+
+    let (nx, ny, nz) = (
+        dimensions.x as usize,
+        dimensions.y as usize,
+        dimensions.z as usize,
+    );
+    if row_major.len() != nx * ny * nz {
+        return None;
+    }
+
+    // Tensors are row-major, i.e. the *last* dimension varies fastest.
+    let mut values = vec![0.0; row_major.len()];
+    for x in 0..nx {
+        for y in 0..ny {
+            for z in 0..nz {
+                values[x + nx * (y + ny * z)] = row_major[(x * ny + y) * nz + z];
+            }
+        }
+    }
+
+    Some((dimensions, values))
+}
+
+impl Cache for VolumeCache {
+    fn purge_memory(&mut self) {
+        self.0.clear();
+    }
+
+    fn on_store_events(&mut self, events: &[ChunkStoreEvent]) {
+        re_tracing::profile_function!();
+
+        let row_ids_removed: HashSet<RowId> = events
+            .iter()
+            .flat_map(|event| {
+                let is_deletion = || event.kind == re_chunk_store::ChunkStoreDiffKind::Deletion;
+                let contains_tensor_data = || {
+                    event
+                        .chunk
+                        .components()
+                        .contains_component(&Tensor::descriptor_data())
+                };
+
+                if is_deletion() && contains_tensor_data() {
+                    Either::Left(event.chunk.row_ids())
+                } else {
+                    Either::Right(std::iter::empty())
+                }
+            })
+            .collect();
+
+        self.0
+            .retain(|row_id, _volume| !row_ids_removed.contains(row_id));
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn volume_values_are_x_fastest() {
+        let shape = [2u64, 3, 4];
+        let values: Vec<f32> = (0..24).map(|i| i as f32).collect();
+        let tensor = TensorData::new(shape.to_vec(), TensorBuffer::F32(values.into()));
+
+        let (dimensions, volume) = volume_values_from_tensor(&tensor).unwrap();
+        assert_eq!(dimensions, glam::UVec3::new(2, 3, 4));
+
+        for x in 0..2 {
+            for y in 0..3 {
+                for z in 0..4 {
+                    let expected = tensor.get(&[x, y, z]).unwrap().as_f64() as f32;
+                    let idx = x as usize + 2 * (y as usize + 3 * z as usize);
+                    assert_eq!(volume[idx], expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn volume_ignores_unit_dimensions() {
+        let tensor = TensorData::new(vec![1, 2, 1, 2, 2], TensorBuffer::U8(vec![0; 8].into()));
+        let (dimensions, _) = volume_values_from_tensor(&tensor).unwrap();
+        assert_eq!(dimensions, glam::UVec3::new(2, 2, 2));
+
+        let image = TensorData::new(vec![4, 4], TensorBuffer::U8(vec![0; 16].into()));
+        assert!(volume_values_from_tensor(&image).is_none());
+    }
+}
@@ -426,8 +426,19 @@ fn table_ui(
                     if let Some(color) = entry.color {
                         text = text.color(color);
                     }
+                    if entry.is_compared {
+                        let color = entry
+                            .color
+                            .map_or_else(|| ui.visuals().text_color(), Into::into);
+                        if let Some(comparison) = ctx.comparison() {
+                            text = text.color(comparison.settings.apply_to_color(color));
+                        }
+                    }
 
-                    ui.label(text);
+                    let response = ui.label(text);
+                    if entry.is_compared {
+                        response.on_hover_text("From the compared recording");
+                    }
                 });
             });
         });
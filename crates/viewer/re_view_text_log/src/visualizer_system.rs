@@ -1,7 +1,7 @@
 use itertools::izip;
 use re_chunk_store::ResolvedTimeRange;
 use re_entity_db::EntityPath;
-use re_log_types::{TimeInt, TimePoint};
+use re_log_types::{TimeCell, TimeInt, TimePoint};
 use re_query::{clamped_zip_1x2, range_zip_1x2};
 use re_types::{
     Archetype as _,
@@ -10,8 +10,8 @@ use re_types::{
 };
 use re_view::{RangeResultsExt as _, range_with_blueprint_resolved_data};
 use re_viewer_context::{
-    IdentifiedViewSystem, ViewContext, ViewContextCollection, ViewQuery, ViewSystemExecutionError,
-    VisualizerQueryInfo, VisualizerSystem,
+    IdentifiedViewSystem, RecordingComparison, ViewContext, ViewContextCollection, ViewQuery,
+    ViewSystemExecutionError, VisualizerQueryInfo, VisualizerSystem,
 };
 
 #[derive(Debug, Clone)]
@@ -22,6 +22,9 @@ pub struct Entry {
     pub color: Option<Color>,
     pub body: Text,
     pub level: Option<TextLogLevel>,
+
+    /// Does this entry come from the compared recording rather than the active one?
+    pub is_compared: bool,
 }
 
 /// A text scene, with everything needed to render it.
@@ -54,9 +57,15 @@ impl VisualizerSystem for TextLogSystem {
                 .keep_extra_timelines(true);
 
         for data_result in view_query.iter_visible_data_results(Self::identifier()) {
-            self.process_entity(ctx, &query, data_result);
+            self.process_entity(ctx, &query, data_result, None);
         }
 
+        ctx.with_compared_recording(|ctx, comparison| {
+            for data_result in view_query.iter_visible_data_results(Self::identifier()) {
+                self.process_entity(ctx, &query, data_result, Some(comparison));
+            }
+        });
+
         {
             // Sort by currently selected timeline
             re_tracing::profile_scope!("sort");
@@ -81,6 +90,7 @@ impl TextLogSystem {
         ctx: &ViewContext<'_>,
         query: &re_chunk_store::RangeQuery,
         data_result: &re_viewer_context::DataResult,
+        comparison: Option<&RecordingComparison>,
     ) {
         re_tracing::profile_function!();
 
@@ -116,7 +126,19 @@ impl TextLogSystem {
 
         let all_frames = izip!(all_timepoints, all_frames);
 
-        for (timepoint, ((data_time, _row_id), bodies, levels, colors)) in all_frames {
+        for (mut timepoint, ((mut data_time, _row_id), bodies, levels, colors)) in all_frames {
+            if let Some(comparison) = comparison {
+                // Line the entry up with the active recording's entries.
+                data_time = comparison.from_compared_time(&timeline, data_time);
+                timepoint = timepoint
+                    .iter()
+                    .map(|(timeline, cell)| {
+                        let time = comparison.from_compared_time(timeline, (*cell).into());
+                        (*timeline, TimeCell::new(cell.typ, time.as_i64()))
+                    })
+                    .collect();
+            }
+
             let levels = levels.as_deref().unwrap_or(&[]).iter().cloned().map(Some);
             let colors = colors
                 .unwrap_or(&[])
@@ -139,6 +161,7 @@ impl TextLogSystem {
                     color,
                     body: text.clone().into(),
                     level: level.clone().map(Into::into),
+                    is_compared: comparison.is_some(),
                 });
             }
         }
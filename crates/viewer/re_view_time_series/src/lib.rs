@@ -1,6 +1,7 @@
 //! Rerun time series View
 //!
-//! A View that shows plots over Rerun timelines.
+//! Views that show plots over Rerun timelines, of scalars against each other, and of how the
+//! frequencies of a scalar signal change over time.
 
 // TODO(#6330): remove unwrap()
 #![allow(clippy::unwrap_used)]
@@ -9,13 +10,19 @@ mod aggregation;
 mod line_visualizer_system;
 mod point_visualizer_system;
 mod series_query;
+mod spectrogram_view_class;
+mod spectrogram_visualizer_system;
+mod stft;
 mod util;
 mod view_class;
+mod xy_view_class;
 
 use re_types::components::{AggregationPolicy, MarkerShape};
 use re_viewer_context::external::re_entity_db::InstancePath;
 
+pub use spectrogram_view_class::SpectrogramView;
 pub use view_class::TimeSeriesView;
+pub use xy_view_class::XYPlotView;
 
 /// Computes a deterministic, globally unique ID for the plot based on the ID of the view
 /// itself.
@@ -80,6 +87,11 @@ pub struct PlotSeries {
     /// Id used for this series in the egui plot view.
     pub id: egui::Id,
 
+    /// Whether this series was loaded from the compared recording rather than the active one.
+    ///
+    /// See [`crate::util::to_compared_series`].
+    pub is_compared: bool,
+
     /// Whether the individual series is visible.
     ///
     /// If this is false, [`PlotSeries::points`] is allowed to be empty.
@@ -1,7 +1,7 @@
 use itertools::Itertools as _;
 
 use re_chunk_store::{RangeQuery, RowId};
-use re_log_types::{EntityPath, TimeInt};
+use re_log_types::{EntityPath, ResolvedTimeRange, TimeInt};
 use re_types::{
     Archetype as _,
     archetypes::{self},
@@ -19,7 +19,9 @@ use crate::series_query::{
     allocate_plot_points, collect_colors, collect_radius_ui, collect_scalars, collect_series_name,
     collect_series_visibility, determine_num_series,
 };
-use crate::util::{determine_time_per_pixel, determine_time_range, points_to_series};
+use crate::util::{
+    determine_time_per_pixel, determine_time_range, points_to_series, to_compared_series,
+};
 use crate::view_class::TimeSeriesViewState;
 use crate::{PlotPoint, PlotPointAttrs, PlotSeries, PlotSeriesKind};
 
@@ -172,15 +174,54 @@ impl SeriesLinesSystem {
     ) {
         re_tracing::profile_function!();
 
-        let current_query = ctx.current_query();
-        let query_ctx = ctx.query_context(data_result, &current_query);
-
         let time_offset = ctx
             .view_state
             .downcast_ref::<TimeSeriesViewState>()
             .map_or(0, |state| state.time_offset);
         let time_range =
             determine_time_range(view_query.latest_at, time_offset, data_result, plot_mem);
+
+        self.load_series_in_range(
+            ctx,
+            view_query,
+            time_range,
+            time_per_pixel,
+            data_result,
+            all_series,
+        );
+
+        ctx.with_compared_recording(|ctx, comparison| {
+            let mut compared_series = Vec::new();
+            self.load_series_in_range(
+                ctx,
+                view_query,
+                comparison.to_compared_range(&view_query.timeline, time_range),
+                time_per_pixel,
+                data_result,
+                &mut compared_series,
+            );
+            all_series.extend(
+                compared_series
+                    .into_iter()
+                    .map(|series| to_compared_series(series, &view_query.timeline, comparison)),
+            );
+        });
+    }
+
+    fn load_series_in_range(
+        &self,
+        ctx: &ViewContext<'_>,
+        view_query: &ViewQuery<'_>,
+        time_range: ResolvedTimeRange,
+        time_per_pixel: f64,
+        data_result: &re_viewer_context::DataResult,
+        all_series: &mut Vec<PlotSeries>,
+    ) {
+        re_tracing::profile_function!();
+
+        let current_query = ctx.current_query();
+        let query_ctx = ctx.query_context(data_result, &current_query);
+
         {
             use re_view::RangeResultsExt as _;
 
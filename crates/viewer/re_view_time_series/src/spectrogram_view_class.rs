@@ -0,0 +1,593 @@
+use std::sync::Arc;
+
+use egui::ahash::HashMap;
+use egui_plot::{Plot, PlotImage, PlotPoint};
+
+use re_chunk_store::TimeType;
+use re_log_types::{EntityPath, hash::Hash64};
+use re_types::{
+    View as _, ViewClassIdentifier,
+    blueprint::{
+        archetypes::{SpectrogramWindow, TensorScalarMapping},
+        components::{HopLength, WindowFunction, WindowLength},
+    },
+    components::{Colormap, GammaCorrection, MagnificationFilter},
+    datatypes::TimeRange,
+};
+use re_ui::{Help, IconText, MouseButtonText, UiExt as _, icons, list_item};
+use re_view::{
+    controls::{MOVE_TIME_CURSOR_BUTTON, SELECTION_RECT_ZOOM_BUTTON},
+    view_property_ui,
+};
+use re_viewer_context::{
+    QueryContext, QueryRange, SystemExecutionOutput, TypedComponentFallbackProvider, ViewClass,
+    ViewClassExt as _, ViewClassRegistryError, ViewId, ViewQuery, ViewSpawnHeuristics, ViewState,
+    ViewStateExt as _, ViewSystemExecutionError, ViewerContext,
+    external::re_entity_db::InstancePath, gpu_bridge::colormap_to_re_renderer,
+};
+use re_viewport_blueprint::ViewProperty;
+
+use crate::{
+    spectrogram_visualizer_system::SpectrogramSystem,
+    stft::Spectrogram,
+    view_class::{format_scalar_axis, nanos_grid_spacer, round_nanos_to_start_of_day},
+};
+
+/// Window length used unless the blueprint specifies one.
+const DEFAULT_WINDOW_LENGTH: u64 = 256;
+
+/// Upper limit for the window and hop length, matching what the UI allows.
+///
+/// Values set from an SDK aren't limited, and would otherwise allocate huge buffers.
+const MAX_WINDOW_LENGTH: u64 = 65536;
+
+/// The window and hop length that are actually used, limited to sane values.
+fn clamp_window(window_length: WindowLength, hop_length: HopLength) -> (usize, usize) {
+    (
+        window_length.0.0.clamp(2, MAX_WINDOW_LENGTH) as usize,
+        hop_length.0.0.clamp(1, MAX_WINDOW_LENGTH) as usize,
+    )
+}
+
+/// How far below the loudest frequency of a spectrogram the colormap reaches.
+///
+/// Anything quieter than that is drawn with the lowest color of the colormap.
+const DYNAMIC_RANGE_DB: f32 = 80.0;
+
+/// The spectrogram of an entity, together with its texture.
+struct CachedSpectrogram {
+    /// Hash of the samples and all settings the spectrogram and texture were computed with.
+    key: Hash64,
+
+    spectrogram: Arc<Spectrogram>,
+    texture: egui::TextureHandle,
+}
+
+#[derive(Default)]
+pub struct SpectrogramViewState {
+    /// Transforming a signal is too expensive to do every frame,
+    /// so the spectrograms are only recomputed when their samples or settings change.
+    spectrograms: HashMap<EntityPath, CachedSpectrogram>,
+}
+
+impl ViewState for SpectrogramViewState {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+/// Shows how the frequency content of scalar signals changes over time.
+///
+/// Every entity gets a plot of its own, with time along the X axis and frequency along the Y axis.
+#[derive(Default)]
+pub struct SpectrogramView;
+
+type ViewType = re_types::blueprint::views::SpectrogramView;
+
+impl ViewClass for SpectrogramView {
+    fn identifier() -> ViewClassIdentifier {
+        ViewType::identifier()
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Spectrogram"
+    }
+
+    fn icon(&self) -> &'static re_ui::Icon {
+        &re_ui::icons::VIEW_TENSOR
+    }
+
+    fn help(&self, os: egui::os::OperatingSystem) -> Help {
+        let egui::InputOptions { zoom_modifier, .. } = egui::InputOptions::default(); // This is OK, since we don't allow the user to change these modifiers.
+
+        Help::new("Spectrogram view")
+            .docs_link("https://rerun.io/docs/reference/types/views/spectrogram_view")
+            .control("Pan", (icons::LEFT_MOUSE_CLICK, "+", "drag"))
+            .control(
+                "Zoom",
+                IconText::from_modifiers_and(os, zoom_modifier, icons::SCROLL),
+            )
+            .control(
+                "Zoom to selection",
+                (MouseButtonText(SELECTION_RECT_ZOOM_BUTTON), "+", "drag"),
+            )
+            .control("Move time cursor", MouseButtonText(MOVE_TIME_CURSOR_BUTTON))
+            .control("Reset view", ("double", icons::LEFT_MOUSE_CLICK))
+    }
+
+    fn on_register(
+        &self,
+        system_registry: &mut re_viewer_context::ViewSystemRegistrator<'_>,
+    ) -> Result<(), ViewClassRegistryError> {
+        system_registry.register_visualizer::<SpectrogramSystem>()
+    }
+
+    fn new_state(&self) -> Box<dyn ViewState> {
+        Box::<SpectrogramViewState>::default()
+    }
+
+    fn preferred_tile_aspect_ratio(&self, _state: &dyn ViewState) -> Option<f32> {
+        None
+    }
+
+    fn layout_priority(&self) -> re_viewer_context::ViewClassLayoutPriority {
+        re_viewer_context::ViewClassLayoutPriority::Low
+    }
+
+    fn supports_visible_time_range(&self) -> bool {
+        true
+    }
+
+    fn default_query_range(&self, _view_state: &dyn ViewState) -> QueryRange {
+        QueryRange::TimeRange(TimeRange::EVERYTHING)
+    }
+
+    fn spawn_heuristics(
+        &self,
+        _ctx: &ViewerContext<'_>,
+        _include_entity: &dyn Fn(&EntityPath) -> bool,
+    ) -> ViewSpawnHeuristics {
+        // Most scalars aren't signals whose frequencies mean anything,
+        // so these views are only ever created by the user.
+        ViewSpawnHeuristics::empty()
+    }
+
+    fn selection_ui(
+        &self,
+        ctx: &ViewerContext<'_>,
+        ui: &mut egui::Ui,
+        state: &mut dyn ViewState,
+        _space_origin: &EntityPath,
+        view_id: ViewId,
+    ) -> Result<(), ViewSystemExecutionError> {
+        list_item::list_item_scope(ui, "spectrogram_selection_ui", |ui| {
+            let ctx = self.view_context(ctx, view_id, state);
+            view_property_ui::<SpectrogramWindow>(&ctx, ui, self);
+            view_property_ui::<TensorScalarMapping>(&ctx, ui, self);
+        });
+
+        Ok(())
+    }
+
+    fn ui(
+        &self,
+        ctx: &ViewerContext<'_>,
+        ui: &mut egui::Ui,
+        state: &mut dyn ViewState,
+        query: &ViewQuery<'_>,
+        system_output: SystemExecutionOutput,
+    ) -> Result<(), ViewSystemExecutionError> {
+        re_tracing::profile_function!();
+
+        let blueprint_db = ctx.blueprint_db();
+        let view_id = query.view_id;
+
+        let view_ctx = self.view_context(ctx, view_id, state);
+        let window = ViewProperty::from_archetype::<SpectrogramWindow>(
+            blueprint_db,
+            ctx.blueprint_query,
+            view_id,
+        );
+        let window_length = window.component_or_fallback::<WindowLength>(
+            &view_ctx,
+            self,
+            &SpectrogramWindow::descriptor_length(),
+        )?;
+        let hop_length = window.component_or_fallback::<HopLength>(
+            &view_ctx,
+            self,
+            &SpectrogramWindow::descriptor_hop(),
+        )?;
+        let (window_length, hop_length) = clamp_window(window_length, hop_length);
+        let window_function = window.component_or_fallback::<WindowFunction>(
+            &view_ctx,
+            self,
+            &SpectrogramWindow::descriptor_function(),
+        )?;
+
+        let scalar_mapping = ViewProperty::from_archetype::<TensorScalarMapping>(
+            blueprint_db,
+            ctx.blueprint_query,
+            view_id,
+        );
+        let colormap = scalar_mapping.component_or_fallback::<Colormap>(
+            &view_ctx,
+            self,
+            &TensorScalarMapping::descriptor_colormap(),
+        )?;
+        let gamma = scalar_mapping.component_or_fallback::<GammaCorrection>(
+            &view_ctx,
+            self,
+            &TensorScalarMapping::descriptor_gamma(),
+        )?;
+        let mag_filter = scalar_mapping.component_or_fallback::<MagnificationFilter>(
+            &view_ctx,
+            self,
+            &TensorScalarMapping::descriptor_mag_filter(),
+        )?;
+
+        let state = state.downcast_mut::<SpectrogramViewState>()?;
+
+        let (current_time, time_type, timeline) = {
+            // Avoid holding the lock for long
+            let time_ctrl = ctx.rec_cfg.time_ctrl.read();
+            (
+                time_ctrl.time_i64(),
+                time_ctrl.time_type(),
+                *time_ctrl.timeline(),
+            )
+        };
+
+        let inputs = &system_output
+            .view_systems
+            .get::<SpectrogramSystem>()?
+            .inputs;
+        if inputs.is_empty() {
+            return Ok(());
+        }
+
+        let texture_options = egui::TextureOptions {
+            magnification: match mag_filter {
+                MagnificationFilter::Nearest => egui::TextureFilter::Nearest,
+                MagnificationFilter::Linear => egui::TextureFilter::Linear,
+            },
+            ..egui::TextureOptions::LINEAR
+        };
+        let max_texture_side = ui.ctx().input(|i| i.max_texture_side);
+
+        let mut spectrograms = HashMap::default();
+        for input in inputs {
+            let key = Hash64::hash((
+                input.samples_hash,
+                window_length,
+                hop_length,
+                window_function,
+                colormap,
+                gamma.0.0.to_bits(),
+                mag_filter,
+            ));
+
+            let cached = match state.spectrograms.remove(&input.entity_path) {
+                Some(cached) if cached.key == key => cached,
+                _ => {
+                    let Some(spectrogram) = Spectrogram::compute(
+                        &input.samples,
+                        window_length,
+                        hop_length,
+                        window_function,
+                    ) else {
+                        continue;
+                    };
+                    let image =
+                        spectrogram_image(&spectrogram, colormap, *gamma.0, max_texture_side);
+                    let texture = ui.ctx().load_texture(
+                        format!("spectrogram_{}", input.entity_path),
+                        image,
+                        texture_options,
+                    );
+                    CachedSpectrogram {
+                        key,
+                        spectrogram: Arc::new(spectrogram),
+                        texture,
+                    }
+                }
+            };
+            spectrograms.insert(input.entity_path.clone(), cached);
+        }
+        // Entities that left the view are dropped along with their textures.
+        state.spectrograms = spectrograms;
+
+        if state.spectrograms.is_empty() {
+            ui.centered_and_justified(|ui| {
+                ui.weak("Not enough samples for a single window");
+            });
+            return Ok(());
+        }
+
+        // Use the earliest spectrogram as offset to avoid precision issues with large times,
+        // same as the time series view.
+        let min_time = state
+            .spectrograms
+            .values()
+            .filter_map(|cached| cached.spectrogram.times.first().copied())
+            .min()
+            .unwrap_or(0);
+        let (time_offset, frequency_scale, frequency_unit) = match timeline.typ() {
+            TimeType::Sequence => (min_time, 1.0, "cycles/step"),
+            TimeType::TimestampNs | TimeType::DurationNs => {
+                (round_nanos_to_start_of_day(min_time), 1e9, "Hz")
+            }
+        };
+
+        let timestamp_format = ctx.app_options().timestamp_format;
+        let timeline_name = timeline.name().to_string();
+        let min_axis_thickness = ui.tokens().small_icon_size.y;
+        let canvas_size = ui.available_size();
+        let num_plots = state.spectrograms.len() as f32;
+        let plot_height = (canvas_size.y - ui.spacing().item_spacing.y * num_plots) / num_plots;
+
+        for input in inputs {
+            let Some(cached) = state.spectrograms.get(&input.entity_path) else {
+                continue;
+            };
+            let spectrogram = Arc::clone(&cached.spectrogram);
+
+            let mut plot = Plot::new(("spectrogram", view_id, &input.entity_path))
+                .height(plot_height.max(0.0))
+                .y_axis_label(format!("{} [{frequency_unit}]", input.entity_path))
+                .link_axis(egui::Id::new(("spectrogram", view_id)), [true, false])
+                // Sharing the cursor with time series views of the same timeline:
+                .link_cursor(timeline.name().as_str(), [true, false])
+                .custom_x_axes(vec![
+                    egui_plot::AxisHints::new_x()
+                        .min_thickness(min_axis_thickness)
+                        .formatter(move |time, _| {
+                            re_log_types::TimeCell::new(
+                                time_type,
+                                (time.value as i64).saturating_add(time_offset),
+                            )
+                            .format_compact(timestamp_format)
+                        }),
+                ])
+                .custom_y_axes(vec![
+                    egui_plot::AxisHints::new_y()
+                        .min_thickness(min_axis_thickness)
+                        .formatter(move |mark, _| format_scalar_axis(mark)),
+                ]);
+
+            let hovered_spectrogram = Arc::clone(&spectrogram);
+            let timeline_name = timeline_name.clone();
+            plot = plot.label_formatter(move |_, value| {
+                let time = (value.x as i64).saturating_add(time_offset);
+                let frequency = value.y / frequency_scale;
+                let mut label = format!(
+                    "{timeline_name}: {}\nfrequency: {} {frequency_unit}",
+                    time_type.format(re_log_types::TimeInt::new_temporal(time), timestamp_format),
+                    re_format::format_f64(value.y),
+                );
+                if let Some(db) = magnitude_at(&hovered_spectrogram, time, frequency) {
+                    label.push_str(&format!("\nmagnitude: {db:.1} dB"));
+                }
+                label
+            });
+
+            match timeline.typ() {
+                TimeType::Sequence => {}
+                TimeType::DurationNs | TimeType::TimestampNs => {
+                    plot =
+                        plot.x_grid_spacer(move |spacer| nanos_grid_spacer(canvas_size, &spacer));
+                }
+            }
+
+            let texture_id = cached.texture.id();
+            let egui_plot::PlotResponse {
+                response,
+                transform,
+                ..
+            } = plot.show(ui, |plot_ui| {
+                if plot_ui.response().secondary_clicked() {
+                    if let Some(pointer) = plot_ui.pointer_coordinate() {
+                        let mut time_ctrl = ctx.rec_cfg.time_ctrl.write();
+                        time_ctrl.set_timeline_and_time(timeline, pointer.x as i64 + time_offset);
+                        time_ctrl.pause();
+                    }
+                }
+
+                let (Some(first_time), Some(last_time)) =
+                    (spectrogram.times.first(), spectrogram.times.last())
+                else {
+                    return;
+                };
+                // Every window is a column centered on its time,
+                // and every frequency bin a row centered on its frequency.
+                let start = (first_time - time_offset) as f64 - 0.5 * spectrogram.window_spacing();
+                let end = (last_time - time_offset) as f64 + 0.5 * spectrogram.window_spacing();
+                let bin_spacing = spectrogram.bin_spacing() * frequency_scale;
+                let nyquist_frequency = spectrogram.nyquist_frequency() * frequency_scale;
+                plot_ui.image(PlotImage::new(
+                    input.entity_path.to_string(),
+                    texture_id,
+                    PlotPoint::new(0.5 * (start + end), 0.5 * nyquist_frequency),
+                    egui::vec2(
+                        (end - start) as f32,
+                        (nyquist_frequency + bin_spacing) as f32,
+                    ),
+                ));
+            });
+
+            ctx.handle_select_hover_drag_interactions(
+                &response,
+                re_viewer_context::Item::DataResult(
+                    view_id,
+                    InstancePath::entity_all(input.entity_path.clone()),
+                ),
+                false,
+            );
+
+            time_cursor_ui(ctx, ui, &response, &transform, current_time, time_offset);
+        }
+
+        Ok(())
+    }
+}
+
+/// Draws the time cursor over a plot, and lets the user drag it around.
+fn time_cursor_ui(
+    ctx: &ViewerContext<'_>,
+    ui: &egui::Ui,
+    response: &egui::Response,
+    transform: &egui_plot::PlotTransform,
+    current_time: Option<i64>,
+    time_offset: i64,
+) {
+    // Only display the time cursor when it's actually above the plot area.
+    let Some(mut time_x) = current_time
+        .map(|current_time| current_time.saturating_sub(time_offset) as f64)
+        .filter(|&x| transform.bounds().min()[0] <= x && x <= transform.bounds().max()[0])
+        .map(|x| transform.position_from_point(&PlotPoint::new(x, 0.0)).x)
+    else {
+        return;
+    };
+
+    let interact_radius = ui.style().interaction.resize_grab_radius_side;
+    let line_rect = egui::Rect::from_x_y_ranges(time_x..=time_x, response.rect.y_range())
+        .expand(interact_radius);
+
+    let time_drag_id = response.id.with("time_drag");
+    let drag_response = ui
+        .interact(line_rect, time_drag_id, egui::Sense::drag())
+        .on_hover_and_drag_cursor(egui::CursorIcon::ResizeHorizontal);
+
+    if drag_response.dragged() {
+        if let Some(pointer_pos) = ui.input(|i| i.pointer.hover_pos()) {
+            let new_offset_time = transform.value_from_position(pointer_pos).x;
+
+            // Avoid frame-delay:
+            time_x = pointer_pos.x;
+
+            let mut time_ctrl = ctx.rec_cfg.time_ctrl.write();
+            time_ctrl.set_time(time_offset + new_offset_time.round() as i64);
+            time_ctrl.pause();
+        }
+    }
+
+    ui.paint_time_cursor(
+        ui.painter(),
+        &drag_response,
+        time_x,
+        response.rect.y_range(),
+    );
+}
+
+/// The magnitude in decibels of the window and frequency bin closest to the given time and frequency.
+///
+/// `frequency` is in cycles per unit of the timeline.
+fn magnitude_at(spectrogram: &Spectrogram, time: i64, frequency: f64) -> Option<f32> {
+    let half_window = (0.5 * spectrogram.window_spacing()) as i64;
+    let first = *spectrogram.times.first()?;
+    let last = *spectrogram.times.last()?;
+    if time < first - half_window || last + half_window < time || frequency < 0.0 {
+        return None;
+    }
+
+    let window = spectrogram
+        .times
+        .partition_point(|&window_time| window_time + half_window < time)
+        .min(spectrogram.times.len() - 1);
+    let bin = (frequency / spectrogram.bin_spacing()).round() as usize;
+    spectrogram.window(window).get(bin).copied()
+}
+
+/// Colors every window and frequency bin of the spectrogram by its magnitude.
+///
+/// Time goes from left to right and frequency from bottom to top.
+/// Spectrograms with more windows or bins than fit into a texture are reduced by keeping
+/// the loudest magnitude of each group of neighbors, so that short bursts don't disappear.
+fn spectrogram_image(
+    spectrogram: &Spectrogram,
+    colormap: Colormap,
+    gamma: f32,
+    max_texture_side: usize,
+) -> egui::ColorImage {
+    re_tracing::profile_function!();
+
+    let num_windows = spectrogram.times.len();
+    let num_bins = spectrogram.num_bins;
+    let windows_per_column = num_windows.div_ceil(max_texture_side).max(1);
+    let bins_per_row = num_bins.div_ceil(max_texture_side).max(1);
+    let width = num_windows.div_ceil(windows_per_column);
+    let height = num_bins.div_ceil(bins_per_row);
+
+    let colormap = colormap_to_re_renderer(colormap);
+    let max_db = spectrogram.max_db();
+
+    let mut rgba = Vec::with_capacity(width * height * 4);
+    for row in 0..height {
+        let bins = (height - 1 - row) * bins_per_row..((height - row) * bins_per_row).min(num_bins);
+        for column in 0..width {
+            let windows =
+                column * windows_per_column..((column + 1) * windows_per_column).min(num_windows);
+            let db = windows
+                .flat_map(|window| spectrogram.window(window)[bins.clone()].iter().copied())
+                .fold(f32::NEG_INFINITY, f32::max);
+            let t = (1.0 + (db - max_db) / DYNAMIC_RANGE_DB).clamp(0.0, 1.0);
+            rgba.extend(re_renderer::colormap_srgb(colormap, t.powf(gamma)));
+        }
+    }
+
+    egui::ColorImage::from_rgba_unmultiplied([width, height], &rgba)
+}
+
+impl TypedComponentFallbackProvider<WindowLength> for SpectrogramView {
+    fn fallback_for(&self, _ctx: &QueryContext<'_>) -> WindowLength {
+        DEFAULT_WINDOW_LENGTH.into()
+    }
+}
+
+impl TypedComponentFallbackProvider<HopLength> for SpectrogramView {
+    fn fallback_for(&self, ctx: &QueryContext<'_>) -> HopLength {
+        // A quarter of the window, as rounded up by the transform.
+        let window_length = ViewProperty::from_archetype::<SpectrogramWindow>(
+            ctx.view_ctx.blueprint_db(),
+            ctx.view_ctx.blueprint_query(),
+            ctx.view_ctx.view_id,
+        )
+        .component_or_fallback::<WindowLength>(
+            ctx.view_ctx,
+            self,
+            &SpectrogramWindow::descriptor_length(),
+        )
+        .map_or(DEFAULT_WINDOW_LENGTH, |length| {
+            length.0.0.clamp(2, MAX_WINDOW_LENGTH)
+        });
+        (window_length.next_power_of_two() / 4).max(1).into()
+    }
+}
+
+impl TypedComponentFallbackProvider<Colormap> for SpectrogramView {
+    fn fallback_for(&self, _ctx: &QueryContext<'_>) -> Colormap {
+        // Dark for silence and bright for loud frequencies reads naturally.
+        Colormap::Inferno
+    }
+}
+
+re_viewer_context::impl_component_fallback_provider!(SpectrogramView => [WindowLength, HopLength, Colormap]);
+
+#[test]
+fn test_help_view() {
+    re_viewer_context::test_context::TestContext::test_help_view(|ctx| SpectrogramView.help(ctx));
+}
+
+#[test]
+fn test_clamp_window() {
+    assert_eq!(clamp_window(1024_u64.into(), 256_u64.into()), (1024, 256));
+    assert_eq!(clamp_window(0_u64.into(), 0_u64.into()), (2, 1));
+    assert_eq!(
+        clamp_window(u64::MAX.into(), u64::MAX.into()),
+        (MAX_WINDOW_LENGTH as usize, MAX_WINDOW_LENGTH as usize)
+    );
+}
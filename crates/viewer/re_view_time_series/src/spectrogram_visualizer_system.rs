@@ -0,0 +1,115 @@
+use re_chunk_store::RangeQuery;
+use re_log_types::{EntityPath, ResolvedTimeRange, hash::Hash64};
+use re_types::{Archetype as _, archetypes::Scalars};
+use re_view::{RangeResultsExt as _, range_with_blueprint_resolved_data};
+use re_viewer_context::{
+    IdentifiedViewSystem, QueryRange, ViewContext, ViewContextCollection, ViewQuery,
+    ViewSystemExecutionError, VisualizerQueryInfo, VisualizerSystem,
+};
+
+/// The signal of a single entity, shown as one spectrogram of the [`crate::SpectrogramView`].
+pub struct SpectrogramInput {
+    pub entity_path: EntityPath,
+
+    /// `(time, value)` of the first scalar of every row, sorted by time.
+    pub samples: Vec<(i64, f64)>,
+
+    /// Changes whenever [`Self::samples`] do, without having to compare all of them.
+    pub samples_hash: Hash64,
+}
+
+/// Collects the first scalar of every row of [`Scalars`] over the visible time range.
+#[derive(Default)]
+pub struct SpectrogramSystem {
+    pub inputs: Vec<SpectrogramInput>,
+}
+
+impl IdentifiedViewSystem for SpectrogramSystem {
+    fn identifier() -> re_viewer_context::ViewSystemIdentifier {
+        "Spectrogram".into()
+    }
+}
+
+impl VisualizerSystem for SpectrogramSystem {
+    fn visualizer_query_info(&self) -> VisualizerQueryInfo {
+        VisualizerQueryInfo::from_archetype::<Scalars>()
+    }
+
+    fn execute(
+        &mut self,
+        ctx: &ViewContext<'_>,
+        view_query: &ViewQuery<'_>,
+        _context_systems: &ViewContextCollection,
+    ) -> Result<Vec<re_renderer::QueueableDrawData>, ViewSystemExecutionError> {
+        re_tracing::profile_function!();
+
+        for data_result in view_query.iter_visible_data_results(Self::identifier()) {
+            let time_range = match data_result.query_range() {
+                QueryRange::TimeRange(time_range) => {
+                    ResolvedTimeRange::from_relative_time_range(time_range, view_query.latest_at)
+                }
+                QueryRange::LatestAt => {
+                    ResolvedTimeRange::new(view_query.latest_at, view_query.latest_at)
+                }
+            };
+            let range_query = RangeQuery::new(view_query.timeline, time_range);
+
+            let results = range_with_blueprint_resolved_data(
+                ctx,
+                None,
+                &range_query,
+                data_result,
+                Scalars::all_components().iter(),
+            );
+            let Some(all_scalar_chunks) =
+                results.get_required_chunks(Scalars::descriptor_scalars())
+            else {
+                continue;
+            };
+
+            // Rows without any scalar are skipped rather than treated as silence.
+            let mut samples: Vec<(i64, f64)> = all_scalar_chunks
+                .iter()
+                .flat_map(|chunk| {
+                    itertools::izip!(
+                        chunk.iter_component_indices(range_query.timeline()),
+                        chunk.iter_slices::<f64>()
+                    )
+                })
+                .filter_map(|((time, _), values)| Some((time.as_i64(), *values.first()?)))
+                .collect();
+            samples.sort_by_key(|(time, _)| *time);
+
+            // Chunks never change once they're in the store, so their ids together with the
+            // sliced range identify the samples.
+            let samples_hash = Hash64::hash((
+                all_scalar_chunks
+                    .chunks
+                    .iter()
+                    .map(|chunk| chunk.id())
+                    .collect::<Vec<_>>(),
+                samples.len(),
+                samples.first().map(|(time, _)| *time),
+                samples.last().map(|(time, _)| *time),
+            ));
+
+            self.inputs.push(SpectrogramInput {
+                entity_path: data_result.entity_path.clone(),
+                samples,
+                samples_hash,
+            });
+        }
+
+        Ok(Vec::new())
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn fallback_provider(&self) -> &dyn re_viewer_context::ComponentFallbackProvider {
+        self
+    }
+}
+
+re_viewer_context::impl_component_fallback_provider!(SpectrogramSystem => []);
@@ -0,0 +1,290 @@
+//! Short-time Fourier transform of scalar signals, as shown by the [`crate::SpectrogramView`].
+
+use re_types::blueprint::components::WindowFunction;
+
+/// Magnitudes below this are clamped before being converted to decibels, so silence stays finite.
+const MIN_MAGNITUDE: f64 = 1e-10;
+
+/// The magnitudes of the frequencies in consecutive windows of a signal.
+#[derive(Clone, Debug)]
+pub struct Spectrogram {
+    /// The time of the center sample of each window, in ascending order.
+    pub times: Vec<i64>,
+
+    /// The number of frequency bins of each window, from zero up to the Nyquist frequency.
+    pub num_bins: usize,
+
+    /// The magnitude of each frequency bin in decibels, relative to a sine wave with an amplitude of one.
+    ///
+    /// One window after the other, `times.len() * num_bins` values in total.
+    pub magnitudes_db: Vec<f32>,
+
+    /// The number of samples each window spans, always a power of two.
+    pub window_length: usize,
+
+    /// The number of samples between the starts of two consecutive windows.
+    pub hop_length: usize,
+
+    /// The time between two samples, in units of the timeline.
+    ///
+    /// This is the median gap between consecutive samples, so that a few dropped samples
+    /// don't throw off the frequency axis.
+    pub sample_period: f64,
+}
+
+impl Spectrogram {
+    /// Splits the signal into windows of `window_length` samples, rounded up to the next power of two,
+    /// and computes the spectrum of each.
+    ///
+    /// `samples` are `(time, value)` pairs sorted by time.
+    /// Returns `None` if there aren't enough samples for a single window.
+    pub fn compute(
+        samples: &[(i64, f64)],
+        window_length: usize,
+        hop_length: usize,
+        window_function: WindowFunction,
+    ) -> Option<Self> {
+        re_tracing::profile_function!();
+
+        let window_length = window_length.max(2).next_power_of_two();
+        let hop_length = hop_length.max(1);
+        if samples.len() < window_length {
+            return None;
+        }
+        let sample_period = median_sample_period(samples)?;
+
+        let weights = window_weights(window_function, window_length);
+        let weight_sum: f64 = weights.iter().sum();
+        let twiddles = twiddles(window_length);
+
+        let num_bins = window_length / 2 + 1;
+        let num_windows = (samples.len() - window_length) / hop_length + 1;
+
+        let mut times = Vec::with_capacity(num_windows);
+        let mut magnitudes_db = Vec::with_capacity(num_windows * num_bins);
+        let mut re = vec![0.0; window_length];
+        let mut im = vec![0.0; window_length];
+
+        for start in (0..num_windows).map(|i| i * hop_length) {
+            let window = &samples[start..start + window_length];
+            times.push(window[window_length / 2].0);
+
+            for ((re, im), ((_, value), weight)) in
+                re.iter_mut().zip(&mut im).zip(window.iter().zip(&weights))
+            {
+                *re = if value.is_finite() {
+                    value * weight
+                } else {
+                    0.0
+                };
+                *im = 0.0;
+            }
+            fft(&mut re, &mut im, &twiddles);
+
+            // Every frequency but zero and Nyquist also has a mirrored negative counterpart,
+            // which carries the other half of its amplitude.
+            magnitudes_db.extend((0..num_bins).map(|bin| {
+                let scale = if bin == 0 || bin == window_length / 2 {
+                    1.0
+                } else {
+                    2.0
+                };
+                let magnitude = scale * re[bin].hypot(im[bin]) / weight_sum;
+                (20.0 * magnitude.max(MIN_MAGNITUDE).log10()) as f32
+            }));
+        }
+
+        Some(Self {
+            times,
+            num_bins,
+            magnitudes_db,
+            window_length,
+            hop_length,
+            sample_period,
+        })
+    }
+
+    /// The magnitudes of all frequency bins of the given window, in decibels.
+    #[inline]
+    pub fn window(&self, index: usize) -> &[f32] {
+        &self.magnitudes_db[index * self.num_bins..(index + 1) * self.num_bins]
+    }
+
+    /// The frequency between two neighboring bins, in cycles per unit of the timeline.
+    #[inline]
+    pub fn bin_spacing(&self) -> f64 {
+        1.0 / (self.window_length as f64 * self.sample_period)
+    }
+
+    /// The highest frequency that can be told apart, in cycles per unit of the timeline.
+    #[inline]
+    pub fn nyquist_frequency(&self) -> f64 {
+        0.5 / self.sample_period
+    }
+
+    /// The time between two consecutive windows, in units of the timeline.
+    #[inline]
+    pub fn window_spacing(&self) -> f64 {
+        self.hop_length as f64 * self.sample_period
+    }
+
+    /// The loudest magnitude of all windows, in decibels.
+    pub fn max_db(&self) -> f32 {
+        self.magnitudes_db
+            .iter()
+            .copied()
+            .fold(f32::NEG_INFINITY, f32::max)
+    }
+}
+
+/// The median of the positive gaps between consecutive sample times.
+fn median_sample_period(samples: &[(i64, f64)]) -> Option<f64> {
+    let mut gaps: Vec<i64> = samples
+        .windows(2)
+        .map(|pair| pair[1].0.saturating_sub(pair[0].0))
+        .filter(|&gap| gap > 0)
+        .collect();
+    if gaps.is_empty() {
+        return None;
+    }
+    let middle = gaps.len() / 2;
+    let (_, median, _) = gaps.select_nth_unstable(middle);
+    Some(*median as f64)
+}
+
+/// The weight of each sample of a window.
+///
+/// These are the periodic variants of the windows, as is usual for spectral analysis.
+fn window_weights(window_function: WindowFunction, window_length: usize) -> Vec<f64> {
+    (0..window_length)
+        .map(|i| {
+            let phase = std::f64::consts::TAU * i as f64 / window_length as f64;
+            match window_function {
+                WindowFunction::Rectangular => 1.0,
+                WindowFunction::Hann => 0.5 - 0.5 * phase.cos(),
+                WindowFunction::Hamming => 0.54 - 0.46 * phase.cos(),
+                WindowFunction::Blackman => 0.42 - 0.5 * phase.cos() + 0.08 * (2.0 * phase).cos(),
+            }
+        })
+        .collect()
+}
+
+/// `(cos, sin)` of the twiddle factors `e^(-2πik/n)` for the first half of an `n` point FFT.
+fn twiddles(n: usize) -> Vec<(f64, f64)> {
+    (0..n / 2)
+        .map(|k| {
+            let (sin, cos) = (-std::f64::consts::TAU * k as f64 / n as f64).sin_cos();
+            (cos, sin)
+        })
+        .collect()
+}
+
+/// In-place iterative radix-2 FFT.
+///
+/// The length of `re` and `im` has to be a power of two, and `twiddles` has to come from [`twiddles`] for that length.
+fn fft(re: &mut [f64], im: &mut [f64], twiddles: &[(f64, f64)]) {
+    let n = re.len();
+    debug_assert!(n.is_power_of_two());
+    debug_assert_eq!(im.len(), n);
+    debug_assert_eq!(twiddles.len(), n / 2);
+
+    // Bit-reversal permutation.
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            re.swap(i, j);
+            im.swap(i, j);
+        }
+    }
+
+    // Butterflies, doubling the transform size with every pass.
+    let mut size = 2;
+    while size <= n {
+        let half = size / 2;
+        let twiddle_step = n / size;
+        for start in (0..n).step_by(size) {
+            for k in 0..half {
+                let (cos, sin) = twiddles[k * twiddle_step];
+                let (a, b) = (start + k, start + k + half);
+                let t_re = re[b] * cos - im[b] * sin;
+                let t_im = re[b] * sin + im[b] * cos;
+                re[b] = re[a] - t_re;
+                im[b] = im[a] - t_im;
+                re[a] += t_re;
+                im[a] += t_im;
+            }
+        }
+        size *= 2;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use re_types::blueprint::components::WindowFunction;
+
+    use super::Spectrogram;
+
+    fn sine(num_samples: usize, cycles_per_sample: f64, sample_period: i64) -> Vec<(i64, f64)> {
+        (0..num_samples)
+            .map(|i| {
+                let phase = std::f64::consts::TAU * cycles_per_sample * i as f64;
+                (i as i64 * sample_period, phase.sin())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn sine_peaks_in_its_bin() {
+        // Exactly 8 cycles per 64 sample window.
+        let samples = sine(256, 8.0 / 64.0, 10);
+        let spectrogram = Spectrogram::compute(&samples, 64, 16, WindowFunction::Hann).unwrap();
+
+        assert_eq!(spectrogram.num_bins, 33);
+        assert_eq!(spectrogram.times.len(), (256 - 64) / 16 + 1);
+        assert_eq!(spectrogram.times[0], 32 * 10);
+        assert_eq!(spectrogram.sample_period, 10.0);
+        assert_eq!(spectrogram.nyquist_frequency(), 0.05);
+
+        for index in 0..spectrogram.times.len() {
+            let window = spectrogram.window(index);
+            let (peak, peak_db) = window
+                .iter()
+                .enumerate()
+                .max_by(|(_, a), (_, b)| a.total_cmp(b))
+                .unwrap();
+            assert_eq!(peak, 8);
+            assert!(
+                peak_db.abs() < 0.01,
+                "unit sine should be at 0 dB, got {peak_db}"
+            );
+            assert!(window[20] < -100.0);
+        }
+    }
+
+    #[test]
+    fn rounds_window_length_up() {
+        let samples = sine(100, 0.1, 1);
+        let spectrogram =
+            Spectrogram::compute(&samples, 50, 25, WindowFunction::Rectangular).unwrap();
+        assert_eq!(spectrogram.window_length, 64);
+        assert_eq!(spectrogram.times.len(), 2);
+
+        assert!(Spectrogram::compute(&samples, 128, 25, WindowFunction::Rectangular).is_none());
+    }
+
+    #[test]
+    fn sample_period_ignores_gaps() {
+        let mut samples = sine(64, 0.1, 5);
+        // Drop a sample, the period should still be the usual gap.
+        samples.remove(10);
+        samples.push((64 * 5, 0.0));
+        let spectrogram = Spectrogram::compute(&samples, 64, 64, WindowFunction::Hamming).unwrap();
+        assert_eq!(spectrogram.sample_period, 5.0);
+    }
+}
@@ -1,9 +1,11 @@
-use re_log_types::ResolvedTimeRange;
+use re_log_types::{ResolvedTimeRange, TimeInt, TimelineName};
 use re_types::{
     components::AggregationPolicy,
     datatypes::{TimeRange, TimeRangeBoundary},
 };
-use re_viewer_context::{ViewQuery, ViewerContext, external::re_entity_db::InstancePath};
+use re_viewer_context::{
+    RecordingComparison, ViewQuery, ViewerContext, external::re_entity_db::InstancePath,
+};
 
 use crate::{
     PlotPoint, PlotSeries, PlotSeriesKind, ScatterAttrs,
@@ -112,6 +114,7 @@ pub fn points_to_series(
         all_series.push(PlotSeries {
             visible,
             id: egui::Id::new(&instance_path),
+            is_compared: false,
             label: series_label,
             color: points[0].attrs.color,
             radius_ui: points[0].attrs.radius_ui,
@@ -136,6 +139,33 @@ pub fn points_to_series(
     }
 }
 
+/// Moves a series loaded from the compared recording to the active recording's time, and sets
+/// it apart from the active recording's own series.
+pub fn to_compared_series(
+    mut series: PlotSeries,
+    timeline: &TimelineName,
+    comparison: &RecordingComparison,
+) -> PlotSeries {
+    let shift = |time: i64| {
+        comparison
+            .from_compared_time(timeline, TimeInt::new_temporal(time))
+            .as_i64()
+    };
+
+    for (time, _) in &mut series.points {
+        *time = shift(*time);
+    }
+    series.min_time = shift(series.min_time);
+
+    // Same instance path as its active counterpart, but it's a different series for the plot.
+    series.id = series.id.with("compared");
+    series.is_compared = true;
+    series.label = format!("{} (compared)", series.label);
+    series.color = comparison.apply_to_color(series.color);
+
+    series
+}
+
 /// Apply the given aggregation to the provided points.
 pub fn apply_aggregation(
     aggregator: AggregationPolicy,
@@ -224,6 +254,7 @@ fn add_series_runs(
     let mut series: PlotSeries = PlotSeries {
         visible,
         id,
+        is_compared: false,
         label: series_label.clone(),
         color: attrs.color,
         radius_ui: attrs.radius_ui,
@@ -250,6 +281,7 @@ fn add_series_runs(
                 PlotSeries {
                     visible,
                     id,
+                    is_compared: false,
                     label: series_label.clone(),
                     color: attrs.color,
                     radius_ui: attrs.radius_ui,
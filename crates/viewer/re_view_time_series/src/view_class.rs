@@ -485,7 +485,7 @@ impl ViewClass for TimeSeriesView {
             .custom_y_axes(vec![
                 egui_plot::AxisHints::new_y()
                     .min_thickness(min_axis_thickness)
-                    .formatter(move |mark, _| format_y_axis(mark)),
+                    .formatter(move |mark, _| format_scalar_axis(mark)),
             ])
             .label_formatter(move |name, value| {
                 let name = if name.is_empty() { "y" } else { name };
@@ -683,7 +683,7 @@ impl ViewClass for TimeSeriesView {
     }
 }
 
-fn set_plot_visibility_from_store(
+pub(crate) fn set_plot_visibility_from_store(
     egui_ctx: &egui::Context,
     plot_series_from_store: &[&crate::PlotSeries],
     plot_id: egui::Id,
@@ -700,7 +700,7 @@ fn set_plot_visibility_from_store(
     }
 }
 
-fn update_series_visibility_overrides_from_plot(
+pub(crate) fn update_series_visibility_overrides_from_plot(
     ctx: &ViewerContext<'_>,
     query: &ViewQuery<'_>,
     all_plot_series: &[&crate::PlotSeries],
@@ -847,7 +847,7 @@ fn add_series_to_plot(
     }
 }
 
-fn format_y_axis(mark: egui_plot::GridMark) -> String {
+pub(crate) fn format_scalar_axis(mark: egui_plot::GridMark) -> String {
     // Example: If the step to the next tick is `0.01`, we should use 2 decimals of precision:
     let num_decimals = -mark.step_size.log10().round() as usize;
 
@@ -856,7 +856,7 @@ fn format_y_axis(mark: egui_plot::GridMark) -> String {
         .format(mark.value)
 }
 
-fn nanos_grid_spacer(
+pub(crate) fn nanos_grid_spacer(
     canvas_size: egui::Vec2,
     input: &egui_plot::GridInput,
 ) -> Vec<egui_plot::GridMark> {
@@ -910,7 +910,7 @@ fn nanos_grid_spacer(
     marks
 }
 
-fn round_nanos_to_start_of_day(ns: i64) -> i64 {
+pub(crate) fn round_nanos_to_start_of_day(ns: i64) -> i64 {
     let nanos_per_day = 24 * 60 * 60 * 1_000_000_000;
     (ns.saturating_add(nanos_per_day / 2)) / nanos_per_day * nanos_per_day
 }
@@ -935,7 +935,7 @@ impl TypedComponentFallbackProvider<Range1D> for TimeSeriesView {
 }
 
 /// Make sure the range is finite and positive, or `egui_plot` might be buggy.
-fn make_range_sane(y_range: Range1D) -> Range1D {
+pub(crate) fn make_range_sane(y_range: Range1D) -> Range1D {
     let (mut start, mut end) = (y_range.start(), y_range.end());
 
     if !start.is_finite() {
@@ -0,0 +1,895 @@
+use egui::ahash::HashMap;
+use egui_plot::{ColorConflictHandling, Legend, Line, Plot, Points};
+use itertools::Itertools as _;
+
+use re_log_types::EntityPath;
+use re_types::{
+    Archetype as _, Component as _, View as _, ViewClassIdentifier,
+    archetypes::Scalars,
+    blueprint::{
+        archetypes::{PlotLegend, ScalarAxis, XAxis},
+        components::{ComponentColumnSelector, Corner2D, LockRangeDuringZoom},
+    },
+    components::{MarkerShape, Range1D, Visible},
+    datatypes::TimeRange,
+};
+use re_ui::{Help, IconText, MouseButtonText, UiExt as _, icons, list_item};
+use re_view::{controls::SELECTION_RECT_ZOOM_BUTTON, view_property_ui};
+use re_viewer_context::{
+    ComponentFallbackProvider, IndicatedEntities, MaybeVisualizableEntities, PerVisualizer,
+    QueryRange, SmallVisualizerSet, SystemExecutionOutput, TypedComponentFallbackProvider,
+    ViewClass, ViewClassExt as _, ViewClassRegistryError, ViewContext, ViewHighlights, ViewId,
+    ViewQuery, ViewSpawnHeuristics, ViewState, ViewStateExt as _, ViewSystemExecutionError,
+    ViewerContext, VisualizableEntities, external::re_entity_db::InstancePath,
+};
+use re_viewport_blueprint::ViewProperty;
+
+use crate::{
+    PlotSeries, PlotSeriesKind,
+    line_visualizer_system::SeriesLinesSystem,
+    point_visualizer_system::SeriesPointsSystem,
+    view_class::{
+        TimeSeriesView, format_scalar_axis, make_range_sane, set_plot_visibility_from_store,
+        update_series_visibility_overrides_from_plot,
+    },
+};
+
+/// Points are faded according to their time, in this many steps.
+const NUM_TIME_SHADES: usize = 8;
+
+/// How much of its color the oldest point in the plot keeps.
+const OLDEST_SHADE: f32 = 0.2;
+
+// ---
+
+#[derive(Clone)]
+pub struct XYPlotViewState {
+    /// The range of the X values currently on screen.
+    x_range: Range1D,
+
+    /// The range of the Y values currently on screen.
+    y_range: Range1D,
+
+    /// Entities with scalars in this view, any of which can be plotted along the X axis.
+    ///
+    /// The first one is the default X axis source.
+    x_source_candidates: Vec<EntityPath>,
+
+    /// Whether to reset the plot bounds next frame.
+    reset_bounds_next_frame: bool,
+}
+
+impl Default for XYPlotViewState {
+    fn default() -> Self {
+        Self {
+            x_range: [0.0, 0.0].into(),
+            y_range: [0.0, 0.0].into(),
+            x_source_candidates: Vec::new(),
+            reset_bounds_next_frame: false,
+        }
+    }
+}
+
+impl ViewState for XYPlotViewState {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+/// Plots scalars against each other, rather than against time.
+///
+/// Uses the same visualizers as the [`TimeSeriesView`]: every series is loaded over the visible
+/// time range, and each sample is then paired with the latest X axis sample at or before its time.
+#[derive(Default)]
+pub struct XYPlotView;
+
+type ViewType = re_types::blueprint::views::XYPlotView;
+
+impl ViewClass for XYPlotView {
+    fn identifier() -> ViewClassIdentifier {
+        ViewType::identifier()
+    }
+
+    fn display_name(&self) -> &'static str {
+        "XY plot"
+    }
+
+    fn icon(&self) -> &'static re_ui::Icon {
+        &re_ui::icons::VIEW_TIMESERIES
+    }
+
+    fn help(&self, os: egui::os::OperatingSystem) -> Help {
+        let egui::InputOptions { zoom_modifier, .. } = egui::InputOptions::default(); // This is OK, since we don't allow the user to change these modifiers.
+
+        Help::new("XY plot view")
+            .docs_link("https://rerun.io/docs/reference/types/views/xy_plot_view")
+            .control("Pan", (icons::LEFT_MOUSE_CLICK, "+", "drag"))
+            .control(
+                "Zoom",
+                IconText::from_modifiers_and(os, zoom_modifier, icons::SCROLL),
+            )
+            .control(
+                "Zoom to selection",
+                (MouseButtonText(SELECTION_RECT_ZOOM_BUTTON), "+", "drag"),
+            )
+            .control("Reset view", ("double", icons::LEFT_MOUSE_CLICK))
+            .control_separator()
+            .control("Hide/show series", (icons::LEFT_MOUSE_CLICK, "legend"))
+    }
+
+    fn on_register(
+        &self,
+        system_registry: &mut re_viewer_context::ViewSystemRegistrator<'_>,
+    ) -> Result<(), ViewClassRegistryError> {
+        system_registry.register_visualizer::<SeriesLinesSystem>()?;
+        system_registry.register_visualizer::<SeriesPointsSystem>()?;
+        Ok(())
+    }
+
+    fn new_state(&self) -> Box<dyn ViewState> {
+        Box::<XYPlotViewState>::default()
+    }
+
+    fn preferred_tile_aspect_ratio(&self, _state: &dyn ViewState) -> Option<f32> {
+        Some(1.0)
+    }
+
+    fn layout_priority(&self) -> re_viewer_context::ViewClassLayoutPriority {
+        re_viewer_context::ViewClassLayoutPriority::Low
+    }
+
+    fn supports_visible_time_range(&self) -> bool {
+        true
+    }
+
+    fn default_query_range(&self, _view_state: &dyn ViewState) -> QueryRange {
+        QueryRange::TimeRange(TimeRange::EVERYTHING)
+    }
+
+    fn selection_ui(
+        &self,
+        ctx: &ViewerContext<'_>,
+        ui: &mut egui::Ui,
+        state: &mut dyn ViewState,
+        _space_origin: &EntityPath,
+        view_id: ViewId,
+    ) -> Result<(), ViewSystemExecutionError> {
+        let state = state.downcast_mut::<XYPlotViewState>()?;
+
+        list_item::list_item_scope(ui, "xy_plot_selection_ui", |ui| {
+            let ctx = self.view_context(ctx, view_id, state);
+            view_property_ui::<PlotLegend>(&ctx, ui, self);
+            view_property_ui_x_axis(&ctx, ui, self, &state.x_source_candidates);
+            view_property_ui::<ScalarAxis>(&ctx, ui, self);
+        });
+
+        Ok(())
+    }
+
+    fn spawn_heuristics(
+        &self,
+        _ctx: &ViewerContext<'_>,
+        _include_entity: &dyn Fn(&EntityPath) -> bool,
+    ) -> ViewSpawnHeuristics {
+        // Which scalars make sense to plot against each other can't be guessed,
+        // so these views are only ever created by the user.
+        ViewSpawnHeuristics::empty()
+    }
+
+    fn choose_default_visualizers(
+        &self,
+        entity_path: &EntityPath,
+        maybe_visualizable_entities_per_visualizer: &PerVisualizer<MaybeVisualizableEntities>,
+        visualizable_entities_per_visualizer: &PerVisualizer<VisualizableEntities>,
+        indicated_entities_per_visualizer: &PerVisualizer<IndicatedEntities>,
+    ) -> SmallVisualizerSet {
+        TimeSeriesView.choose_default_visualizers(
+            entity_path,
+            maybe_visualizable_entities_per_visualizer,
+            visualizable_entities_per_visualizer,
+            indicated_entities_per_visualizer,
+        )
+    }
+
+    fn ui(
+        &self,
+        ctx: &ViewerContext<'_>,
+        ui: &mut egui::Ui,
+        state: &mut dyn ViewState,
+        query: &ViewQuery<'_>,
+        system_output: SystemExecutionOutput,
+    ) -> Result<(), ViewSystemExecutionError> {
+        re_tracing::profile_function!();
+
+        let state = state.downcast_mut::<XYPlotViewState>()?;
+
+        let blueprint_db = ctx.blueprint_db();
+        let view_id = query.view_id;
+
+        let view_ctx = self.view_context(ctx, view_id, state);
+        let plot_legend =
+            ViewProperty::from_archetype::<PlotLegend>(blueprint_db, ctx.blueprint_query, view_id);
+        let legend_visible = plot_legend.component_or_fallback::<Visible>(
+            &view_ctx,
+            self,
+            &PlotLegend::descriptor_visible(),
+        )?;
+        let legend_corner = plot_legend.component_or_fallback::<Corner2D>(
+            &view_ctx,
+            self,
+            &PlotLegend::descriptor_corner(),
+        )?;
+
+        let x_axis =
+            ViewProperty::from_archetype::<XAxis>(blueprint_db, ctx.blueprint_query, view_id);
+        let x_source = x_axis.component_or_fallback::<ComponentColumnSelector>(
+            &view_ctx,
+            self,
+            &XAxis::descriptor_source(),
+        )?;
+        let x_source = x_source.0.entity_path();
+        let x_range = make_range_sane(x_axis.component_or_fallback::<Range1D>(
+            &view_ctx,
+            self,
+            &XAxis::descriptor_range(),
+        )?);
+        let x_zoom_lock = x_axis.component_or_fallback::<LockRangeDuringZoom>(
+            &view_ctx,
+            self,
+            &XAxis::descriptor_zoom_lock(),
+        )?;
+
+        let y_axis =
+            ViewProperty::from_archetype::<ScalarAxis>(blueprint_db, ctx.blueprint_query, view_id);
+        let y_range = make_range_sane(y_axis.component_or_fallback::<Range1D>(
+            &view_ctx,
+            self,
+            &ScalarAxis::descriptor_range(),
+        )?);
+        let y_zoom_lock = y_axis.component_or_fallback::<LockRangeDuringZoom>(
+            &view_ctx,
+            self,
+            &ScalarAxis::descriptor_zoom_lock(),
+        )?;
+
+        let line_series = system_output.view_systems.get::<SeriesLinesSystem>()?;
+        let point_series = system_output.view_systems.get::<SeriesPointsSystem>()?;
+
+        let all_plot_series: Vec<_> = std::iter::empty()
+            .chain(line_series.all_series.iter())
+            .chain(point_series.all_series.iter())
+            .collect();
+
+        state.x_source_candidates = all_plot_series
+            .iter()
+            .filter(|series| !series.is_compared)
+            .map(|series| series.instance_path.entity_path.clone())
+            .sorted()
+            .dedup()
+            .collect();
+
+        let x_label = x_source
+            .last()
+            .map_or_else(|| x_source.to_string(), |part| part.ui_string());
+        let xy_series = pair_with_x_axis(&all_plot_series, &x_source);
+
+        let plotted_series = xy_series.iter().map(|xy| xy.series).collect_vec();
+
+        let plot_item_id_to_instance_path: HashMap<egui::Id, InstancePath> = plotted_series
+            .iter()
+            .map(|series| (series.id, series.instance_path.clone()))
+            .collect();
+
+        let current_time = ctx.rec_cfg.time_ctrl.read().time_i64();
+
+        let plot_id = egui::Id::new(("xy_plot", view_id));
+
+        // We don't want to allow scrolling along an axis that is locked, or else the view
+        // "bounces" when we scroll and then reset to the locked range.
+        if x_zoom_lock.0.0 {
+            ui.input_mut(|i| i.smooth_scroll_delta.x = 0.0);
+        }
+        if y_zoom_lock.0.0 {
+            ui.input_mut(|i| i.smooth_scroll_delta.y = 0.0);
+        }
+
+        set_plot_visibility_from_store(ui.ctx(), &plotted_series, plot_id);
+
+        let min_axis_thickness = ui.tokens().small_icon_size.y;
+
+        let label_x = x_label.clone();
+        let mut plot = Plot::new(plot_id)
+            .id(plot_id)
+            .auto_bounds([false, false])
+            .allow_zoom([!x_zoom_lock.0.0, !y_zoom_lock.0.0])
+            .x_axis_label(x_label)
+            .custom_x_axes(vec![
+                egui_plot::AxisHints::new_x()
+                    .min_thickness(min_axis_thickness)
+                    .formatter(move |mark, _| format_scalar_axis(mark)),
+            ])
+            .custom_y_axes(vec![
+                egui_plot::AxisHints::new_y()
+                    .min_thickness(min_axis_thickness)
+                    .formatter(move |mark, _| format_scalar_axis(mark)),
+            ])
+            .label_formatter(move |name, value| {
+                let name = if name.is_empty() { "y" } else { name };
+                format!(
+                    "{label_x}: {}\n{name}: {}",
+                    re_format::format_f64(value.x),
+                    re_format::format_f64(value.y)
+                )
+            });
+
+        if state.reset_bounds_next_frame {
+            plot = plot.reset();
+        }
+
+        if *legend_visible.0 {
+            // The newest, least faded, part of each series is added last.
+            plot = plot.legend(
+                Legend::default()
+                    .position(legend_corner.into())
+                    .color_conflict_handling(ColorConflictHandling::PickLast),
+            );
+        }
+
+        let mut plot_double_clicked = false;
+        let egui_plot::PlotResponse {
+            inner: _,
+            response,
+            transform,
+            hovered_plot_item,
+        } = plot.show(ui, |plot_ui| {
+            plot_double_clicked = plot_ui.response().double_clicked();
+
+            // Let the user pick both ranges from the blueprint:
+            plot_ui.set_plot_bounds_x(x_range);
+            plot_ui.set_plot_bounds_y(y_range);
+
+            state.reset_bounds_next_frame = false;
+
+            add_series_to_plot(
+                plot_ui,
+                &query.highlights,
+                &xy_series,
+                current_time,
+                &mut state.x_range,
+                &mut state.y_range,
+            );
+        });
+
+        // Interact with the plot items (lines, scatters, etc.)
+        let hovered_data_result = hovered_plot_item
+            .and_then(|hovered_plot_item| plot_item_id_to_instance_path.get(&hovered_plot_item))
+            .map(|instance_path| {
+                re_viewer_context::Item::DataResult(query.view_id, instance_path.clone())
+            });
+        if let Some(hovered) = hovered_data_result.clone().or_else(|| {
+            if response.hovered() {
+                Some(re_viewer_context::Item::View(query.view_id))
+            } else {
+                None
+            }
+        }) {
+            ctx.handle_select_hover_drag_interactions(&response, hovered, false);
+        }
+
+        // Can determine whether we're resetting only now since we need to know whether there's a plot item hovered.
+        let is_resetting = plot_double_clicked && hovered_data_result.is_none();
+
+        // Sync visibility of hidden items with the blueprint (user can hide items via the legend).
+        update_series_visibility_overrides_from_plot(
+            ctx,
+            query,
+            &plotted_series,
+            ui.ctx(),
+            plot_id,
+        );
+
+        // Write new ranges if they have changed.
+        let bounds = transform.bounds();
+        let new_x_range = Range1D::new(bounds.min()[0], bounds.max()[0]);
+        let new_y_range = Range1D::new(bounds.min()[1], bounds.max()[1]);
+        if is_resetting {
+            x_axis.reset_blueprint_component(ctx, XAxis::descriptor_range());
+            y_axis.reset_blueprint_component(ctx, ScalarAxis::descriptor_range());
+            state.reset_bounds_next_frame = true;
+            ui.ctx().request_repaint(); // Make sure we get another frame with the reset actually applied.
+        } else {
+            if new_x_range != x_range {
+                x_axis.save_blueprint_component(ctx, &XAxis::descriptor_range(), &new_x_range);
+                ui.ctx().request_repaint();
+            }
+            if new_y_range != y_range {
+                y_axis.save_blueprint_component(ctx, &ScalarAxis::descriptor_range(), &new_y_range);
+                ui.ctx().request_repaint();
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// A series plotted against the X axis.
+struct XYSeries<'a> {
+    series: &'a PlotSeries,
+
+    /// `(time, x, y)`, sorted by time.
+    points: Vec<(i64, f64, f64)>,
+}
+
+/// Is this the series plotted along the X axis, i.e. the first series of the X axis entity?
+fn is_x_source(series: &PlotSeries, x_source: &EntityPath) -> bool {
+    series.instance_path.entity_path == *x_source
+        && series
+            .instance_path
+            .instance
+            .specific_index()
+            .is_none_or(|index| index.get() == 0)
+}
+
+/// Pairs every sample of every series with the latest X axis sample at or before its time.
+///
+/// The compared recording's series are paired with the compared recording's X axis.
+fn pair_with_x_axis<'a>(
+    all_plot_series: &[&'a PlotSeries],
+    x_source: &EntityPath,
+) -> Vec<XYSeries<'a>> {
+    re_tracing::profile_function!();
+
+    let x_samples = |compared: bool| {
+        let mut samples = all_plot_series
+            .iter()
+            .filter(|series| {
+                is_x_source(series, x_source)
+                    && series.is_compared == compared
+                    && series.kind != PlotSeriesKind::Clear
+            })
+            .flat_map(|series| series.points.iter().copied())
+            .collect_vec();
+        samples.sort_by_key(|(time, _)| *time);
+        samples
+    };
+    let x_samples = [x_samples(false), x_samples(true)];
+
+    all_plot_series
+        .iter()
+        .filter(|series| !is_x_source(series, x_source) && series.kind != PlotSeriesKind::Clear)
+        .map(|&series| {
+            let x_samples = &x_samples[series.is_compared as usize];
+            let points = series
+                .points
+                .iter()
+                .filter_map(|&(time, y)| {
+                    let num_before = x_samples.partition_point(|(x_time, _)| *x_time <= time);
+                    let (_, x) = x_samples.get(num_before.checked_sub(1)?)?;
+                    Some((time, *x, y))
+                })
+                .collect();
+            XYSeries { series, points }
+        })
+        .collect()
+}
+
+/// Which of the [`NUM_TIME_SHADES`] shades a point at `time` gets, from `0` for the oldest to
+/// `NUM_TIME_SHADES - 1` for the newest.
+fn time_shade(time: i64, (min_time, max_time): (i64, i64)) -> usize {
+    if max_time <= min_time {
+        return NUM_TIME_SHADES - 1;
+    }
+    let t = (time - min_time) as f64 / (max_time - min_time) as f64;
+    ((t * NUM_TIME_SHADES as f64) as usize).min(NUM_TIME_SHADES - 1)
+}
+
+fn shaded_color(color: egui::Color32, shade: usize) -> egui::Color32 {
+    let t = shade as f32 / (NUM_TIME_SHADES - 1) as f32;
+    color.gamma_multiply(egui::lerp(OLDEST_SHADE..=1.0, t))
+}
+
+/// Breaks points sorted by time up into runs of the same shade, from oldest to newest.
+fn shade_runs(
+    points: &[(i64, f64, f64)],
+    time_range: (i64, i64),
+) -> Vec<(std::ops::Range<usize>, usize)> {
+    let mut runs = Vec::new();
+    let mut run_start = 0;
+    while run_start < points.len() {
+        let shade = time_shade(points[run_start].0, time_range);
+        let run_end = points[run_start..]
+            .iter()
+            .position(|(time, _, _)| time_shade(*time, time_range) != shade)
+            .map_or(points.len(), |len| run_start + len);
+        runs.push((run_start..run_end, shade));
+        run_start = run_end;
+    }
+    runs
+}
+
+fn add_series_to_plot(
+    plot_ui: &mut egui_plot::PlotUi<'_>,
+    highlights: &ViewHighlights,
+    all_xy_series: &[XYSeries<'_>],
+    current_time: Option<i64>,
+    x_range: &mut Range1D,
+    y_range: &mut Range1D,
+) {
+    re_tracing::profile_function!();
+
+    *x_range.start_mut() = f64::INFINITY;
+    *x_range.end_mut() = f64::NEG_INFINITY;
+    *y_range.start_mut() = f64::INFINITY;
+    *y_range.end_mut() = f64::NEG_INFINITY;
+
+    let visible_points = || {
+        all_xy_series
+            .iter()
+            .filter(|xy| xy.series.visible)
+            .flat_map(|xy| xy.points.iter())
+    };
+    let (min_time, max_time) = visible_points()
+        .map(|(time, _, _)| *time)
+        .minmax()
+        .into_option()
+        .unwrap_or_default();
+    for (_, x, y) in visible_points() {
+        *x_range.start_mut() = x_range.start().min(*x);
+        *x_range.end_mut() = x_range.end().max(*x);
+        *y_range.start_mut() = y_range.start().min(*y);
+        *y_range.end_mut() = y_range.end().max(*y);
+    }
+
+    for xy in all_xy_series {
+        let series = xy.series;
+
+        let highlight = highlights
+            .entity_highlight(series.instance_path.entity_path.hash())
+            .index_highlight(series.instance_path.instance)
+            .any();
+
+        if !series.visible || xy.points.is_empty() {
+            // TODO(emilk/egui_plot#92): Note we still need to produce a series, so it shows up in the legend.
+            // As of writing, egui_plot gets confused if this is an empty series, so
+            // we still add a single point (but don't have it influence the plot ranges!)
+            if let Some((_, x, y)) = xy.points.first() {
+                plot_ui.points(
+                    Points::new(&series.label, vec![[*x, *y]])
+                        .color(series.color)
+                        .id(series.id),
+                );
+            }
+            continue;
+        }
+
+        for (run, shade) in shade_runs(&xy.points, (min_time, max_time)) {
+            let color = shaded_color(series.color, shade);
+            match series.kind {
+                PlotSeriesKind::Continuous => {
+                    // Lines also go to the first point of the next run, so they stay continuous.
+                    let points = xy.points[run.start..(run.end + 1).min(xy.points.len())]
+                        .iter()
+                        .map(|(_, x, y)| [*x, *y])
+                        .collect_vec();
+                    plot_ui.line(
+                        Line::new(&series.label, points)
+                            .color(color)
+                            .width(2.0 * series.radius_ui)
+                            .highlight(highlight)
+                            .id(series.id),
+                    );
+                }
+                PlotSeriesKind::Scatter(scatter_attrs) => {
+                    let points = xy.points[run]
+                        .iter()
+                        .map(|(_, x, y)| [*x, *y])
+                        .collect_vec();
+                    plot_ui.points(
+                        Points::new(&series.label, points)
+                            .color(color)
+                            .radius(series.radius_ui)
+                            .shape(scatter_attrs.marker.into())
+                            .highlight(highlight)
+                            .id(series.id),
+                    );
+                }
+                // Break up the chart. At some point we might want something fancier.
+                PlotSeriesKind::Clear => {}
+            }
+        }
+
+        // Mark where the series is at the time cursor.
+        if let Some(current_time) = current_time {
+            let num_before = xy
+                .points
+                .partition_point(|(time, _, _)| *time <= current_time);
+            if let Some((_, x, y)) = num_before.checked_sub(1).map(|i| xy.points[i]) {
+                plot_ui.points(
+                    Points::new("", vec![[x, y]])
+                        .color(series.color)
+                        .radius(series.radius_ui + 3.0)
+                        .shape(MarkerShape::Circle.into())
+                        .filled(false),
+                );
+            }
+        }
+    }
+}
+
+/// Like [`view_property_ui`] for [`XAxis`], but picks the source from the entities in the view.
+fn view_property_ui_x_axis(
+    ctx: &ViewContext<'_>,
+    ui: &mut egui::Ui,
+    fallback_provider: &dyn ComponentFallbackProvider,
+    x_source_candidates: &[EntityPath],
+) {
+    let property = ViewProperty::from_archetype::<XAxis>(
+        ctx.blueprint_db(),
+        ctx.blueprint_query(),
+        ctx.view_id,
+    );
+    let reflection = ctx.viewer_ctx.reflection();
+    let Some(reflection) = reflection.archetypes.get(&property.archetype_name) else {
+        ui.error_label(format!(
+            "Missing reflection data for archetype {:?}.",
+            property.archetype_name
+        ));
+        return;
+    };
+
+    let query_ctx = property.query_context(ctx);
+    let sub_prop_ui = |ui: &mut egui::Ui| {
+        for field in &reflection.fields {
+            if field.component_name == ComponentColumnSelector::name() {
+                re_view::view_property_component_ui_custom(
+                    &query_ctx,
+                    ui,
+                    &property,
+                    field.display_name,
+                    field,
+                    &|ui| {
+                        let Ok(source) = property.component_or_fallback::<ComponentColumnSelector>(
+                            ctx,
+                            fallback_provider,
+                            &XAxis::descriptor_source(),
+                        ) else {
+                            ui.error_label("Failed to query X axis source");
+                            return;
+                        };
+                        let mut current_source = source.0.entity_path();
+                        let previous_source = current_source.clone();
+
+                        egui::ComboBox::from_id_salt("xy_plot_x_source")
+                            .selected_text(current_source.to_string())
+                            .show_ui(ui, |ui| {
+                                for entity_path in x_source_candidates {
+                                    ui.selectable_value(
+                                        &mut current_source,
+                                        entity_path.clone(),
+                                        entity_path.to_string(),
+                                    );
+                                }
+                            });
+
+                        if current_source != previous_source {
+                            property.save_blueprint_component(
+                                ctx.viewer_ctx,
+                                &XAxis::descriptor_source(),
+                                &x_source_selector(&current_source),
+                            );
+                        }
+                    },
+                    None, // No multiline editor.
+                );
+            } else {
+                re_view::view_property_component_ui(
+                    &query_ctx,
+                    ui,
+                    &property,
+                    field.display_name,
+                    field,
+                    fallback_provider,
+                );
+            }
+        }
+    };
+
+    ui.list_item()
+        .interactive(false)
+        .show_hierarchical_with_children(
+            ui,
+            ui.make_persistent_id(property.archetype_name.full_name()),
+            true,
+            list_item::LabelContent::new(reflection.display_name),
+            sub_prop_ui,
+        );
+}
+
+/// Selects the scalars of the given entity.
+fn x_source_selector(entity_path: &EntityPath) -> ComponentColumnSelector {
+    ComponentColumnSelector::new(entity_path, Scalars::descriptor_scalars().display_name())
+}
+
+impl TypedComponentFallbackProvider<Corner2D> for XYPlotView {
+    fn fallback_for(&self, _ctx: &re_viewer_context::QueryContext<'_>) -> Corner2D {
+        Corner2D::RightBottom
+    }
+}
+
+impl TypedComponentFallbackProvider<ComponentColumnSelector> for XYPlotView {
+    fn fallback_for(&self, ctx: &re_viewer_context::QueryContext<'_>) -> ComponentColumnSelector {
+        ctx.view_state()
+            .as_any()
+            .downcast_ref::<XYPlotViewState>()
+            .and_then(|s| s.x_source_candidates.first())
+            .map(x_source_selector)
+            .unwrap_or_default()
+    }
+}
+
+impl TypedComponentFallbackProvider<Range1D> for XYPlotView {
+    fn fallback_for(&self, ctx: &re_viewer_context::QueryContext<'_>) -> Range1D {
+        let is_x_axis = ctx.archetype_name == Some(XAxis::name());
+        ctx.view_state()
+            .as_any()
+            .downcast_ref::<XYPlotViewState>()
+            .map(|s| make_range_sane(if is_x_axis { s.x_range } else { s.y_range }))
+            .unwrap_or_default()
+    }
+}
+
+re_viewer_context::impl_component_fallback_provider!(XYPlotView => [Corner2D, ComponentColumnSelector, Range1D]);
+
+#[test]
+fn test_help_view() {
+    re_viewer_context::test_context::TestContext::test_help_view(|ctx| XYPlotView.help(ctx));
+}
+
+#[cfg(test)]
+mod tests {
+    use re_types::components::AggregationPolicy;
+
+    use super::*;
+
+    fn series(
+        instance_path: InstancePath,
+        is_compared: bool,
+        kind: PlotSeriesKind,
+        points: &[(i64, f64)],
+    ) -> PlotSeries {
+        PlotSeries {
+            id: egui::Id::new(&instance_path).with(is_compared),
+            is_compared,
+            instance_path,
+            visible: true,
+            label: String::new(),
+            color: egui::Color32::WHITE,
+            radius_ui: 1.0,
+            kind,
+            points: points.to_vec(),
+            min_time: points.first().map_or(0, |(time, _)| *time),
+            aggregator: AggregationPolicy::Off,
+            aggregation_factor: 1.0,
+        }
+    }
+
+    fn xy_points(all_xy_series: &[XYSeries<'_>]) -> Vec<(String, Vec<(i64, f64, f64)>)> {
+        all_xy_series
+            .iter()
+            .map(|xy| (xy.series.instance_path.to_string(), xy.points.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn test_pair_with_x_axis() {
+        let x_source = EntityPath::from("x");
+        let continuous = PlotSeriesKind::Continuous;
+
+        let all_plot_series = [
+            series(
+                x_source.clone().into(),
+                false,
+                continuous,
+                &[(0, 10.0), (2, 20.0)],
+            ),
+            series(x_source.clone().into(), true, continuous, &[(1, 100.0)]),
+            // Another instance of the X axis entity is a regular series.
+            series(
+                InstancePath::instance(x_source.clone(), 1.into()),
+                false,
+                continuous,
+                &[(1, 7.0)],
+            ),
+            series(
+                EntityPath::from("y").into(),
+                false,
+                continuous,
+                &[(-1, 1.0), (0, 2.0), (1, 3.0), (2, 4.0), (3, 5.0)],
+            ),
+            series(
+                EntityPath::from("y").into(),
+                true,
+                continuous,
+                &[(0, 6.0), (1, 8.0)],
+            ),
+            series(
+                EntityPath::from("y").into(),
+                false,
+                PlotSeriesKind::Clear,
+                &[(5, 0.0)],
+            ),
+        ];
+        let all_plot_series = all_plot_series.iter().collect_vec();
+
+        let xy_series = pair_with_x_axis(&all_plot_series, &x_source);
+        assert_eq!(
+            xy_points(&xy_series),
+            [
+                ("/x[1]".to_owned(), vec![(1, 10.0, 7.0)]),
+                (
+                    "/y".to_owned(),
+                    vec![
+                        (0, 10.0, 2.0),
+                        (1, 10.0, 3.0),
+                        (2, 20.0, 4.0),
+                        (3, 20.0, 5.0)
+                    ],
+                ),
+                // Paired with the compared recording's X axis.
+                ("/y".to_owned(), vec![(1, 100.0, 8.0)]),
+            ]
+        );
+        assert!(xy_series[2].series.is_compared);
+    }
+
+    #[test]
+    fn test_pair_with_missing_x_axis() {
+        let y = series(
+            EntityPath::from("y").into(),
+            false,
+            PlotSeriesKind::Continuous,
+            &[(0, 1.0)],
+        );
+        let xy_series = pair_with_x_axis(&[&y], &EntityPath::from("x"));
+        assert_eq!(xy_points(&xy_series), [("/y".to_owned(), vec![])]);
+    }
+
+    #[test]
+    fn test_time_shade() {
+        let newest = NUM_TIME_SHADES - 1;
+
+        assert_eq!(time_shade(0, (0, 80)), 0);
+        assert_eq!(time_shade(9, (0, 80)), 0);
+        assert_eq!(time_shade(10, (0, 80)), 1);
+        assert_eq!(time_shade(45, (0, 80)), 4);
+        assert_eq!(time_shade(80, (0, 80)), newest);
+        assert_eq!(time_shade(-10, (-10, 70)), 0);
+
+        // A single point in time is all new.
+        assert_eq!(time_shade(5, (5, 5)), newest);
+
+        assert_eq!(
+            shaded_color(egui::Color32::WHITE, newest),
+            egui::Color32::WHITE
+        );
+        assert_eq!(
+            shaded_color(egui::Color32::WHITE, 0),
+            egui::Color32::WHITE.gamma_multiply(OLDEST_SHADE)
+        );
+    }
+
+    #[test]
+    fn test_shade_runs() {
+        let points = [0, 5, 10, 11, 50, 80].map(|time| (time, 0.0, 0.0)).to_vec();
+        assert_eq!(
+            shade_runs(&points, (0, 80)),
+            [(0..2, 0), (2..4, 1), (4..5, 5), (5..6, NUM_TIME_SHADES - 1)]
+        );
+
+        assert!(shade_runs(&[], (0, 80)).is_empty());
+    }
+}
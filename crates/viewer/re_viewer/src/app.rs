@@ -481,6 +481,10 @@ impl App {
                 store_hub.remove(&entry);
             }
 
+            SystemCommand::SetRecordingComparison(comparison) => {
+                store_hub.set_recording_comparison(comparison);
+            }
+
             SystemCommand::CloseAllEntries => {
                 store_hub.clear_entries();
 
@@ -621,6 +625,45 @@ impl App {
                 }
             }
 
+            SystemCommand::AppendToAnnotationRecording {
+                annotated_recording,
+                chunks,
+            } => {
+                let Some(application_id) = store_hub
+                    .store_bundle()
+                    .get(&annotated_recording)
+                    .and_then(|db| db.app_id().cloned())
+                else {
+                    re_log::warn_once!(
+                        "Can't annotate recording {annotated_recording:?}: it no longer exists"
+                    );
+                    return;
+                };
+
+                let store_id = re_viewer_context::annotation_recording_id(&annotated_recording);
+                let db = store_hub.entity_db_mut(&store_id);
+
+                // Without store info, the annotation recording wouldn't show up and couldn't be saved.
+                if db.store_info().is_none() {
+                    db.set_store_info(re_log_types::SetStoreInfo {
+                        row_id: *re_chunk::RowId::new(),
+                        info: re_log_types::StoreInfo {
+                            application_id,
+                            store_id: store_id.clone(),
+                            cloned_from: None,
+                            store_source: re_log_types::StoreSource::Viewer,
+                            store_version: Some(CrateVersion::LOCAL),
+                        },
+                    });
+                }
+
+                for chunk in chunks {
+                    if let Err(err) = db.add_chunk(&Arc::new(chunk)) {
+                        re_log::warn_once!("Failed to append annotations: {err}");
+                    }
+                }
+            }
+
             SystemCommand::UndoBlueprint { blueprint_id } => {
                 let blueprint_db = store_hub.entity_db_mut(&blueprint_id);
                 self.state
@@ -1934,6 +1977,19 @@ impl App {
                     },
                     path,
                 )));
+                continue;
+            }
+
+            // Links don't come with any contents, just the url.
+            if re_data_loader::is_http_uri(&file.name) {
+                command_sender.send_system(SystemCommand::LoadDataSource(DataSource::from_uri(
+                    FileSource::DragAndDrop {
+                        recommended_application_id: active_application_id.clone(),
+                        recommended_recording_id: active_recording_id.clone(),
+                        force_store_info,
+                    },
+                    file.name,
+                )));
             }
         }
     }
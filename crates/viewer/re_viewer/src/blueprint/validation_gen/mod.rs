@@ -9,6 +9,8 @@ pub use re_types::blueprint::components::ApplyLatestAt;
 pub use re_types::blueprint::components::AutoLayout;
 pub use re_types::blueprint::components::AutoViews;
 pub use re_types::blueprint::components::BackgroundKind;
+pub use re_types::blueprint::components::BinCount;
+pub use re_types::blueprint::components::BookmarkTime;
 pub use re_types::blueprint::components::ColumnShare;
 pub use re_types::blueprint::components::ComponentColumnSelector;
 pub use re_types::blueprint::components::ContainerKind;
@@ -21,10 +23,14 @@ pub use re_types::blueprint::components::ForceIterations;
 pub use re_types::blueprint::components::ForceStrength;
 pub use re_types::blueprint::components::GridColumns;
 pub use re_types::blueprint::components::GridSpacing;
+pub use re_types::blueprint::components::HopLength;
 pub use re_types::blueprint::components::IncludedContent;
+pub use re_types::blueprint::components::KeyframeTime;
 pub use re_types::blueprint::components::LinkAxis;
 pub use re_types::blueprint::components::LockRangeDuringZoom;
+pub use re_types::blueprint::components::MapFollowMode;
 pub use re_types::blueprint::components::MapProvider;
+pub use re_types::blueprint::components::MapTileSource;
 pub use re_types::blueprint::components::NearClipPlane;
 pub use re_types::blueprint::components::PanelState;
 pub use re_types::blueprint::components::QueryExpression;
@@ -41,6 +47,8 @@ pub use re_types::blueprint::components::ViewerRecommendationHash;
 pub use re_types::blueprint::components::VisibleTimeRange;
 pub use re_types::blueprint::components::VisualBounds2D;
 pub use re_types::blueprint::components::VisualizerOverride;
+pub use re_types::blueprint::components::WindowFunction;
+pub use re_types::blueprint::components::WindowLength;
 pub use re_types::blueprint::components::ZoomLevel;
 
 /// Because blueprints are both read and written the schema must match what
@@ -52,6 +60,8 @@ pub fn is_valid_blueprint(blueprint: &EntityDb) -> bool {
         && validate_component::<AutoLayout>(blueprint)
         && validate_component::<AutoViews>(blueprint)
         && validate_component::<BackgroundKind>(blueprint)
+        && validate_component::<BinCount>(blueprint)
+        && validate_component::<BookmarkTime>(blueprint)
         && validate_component::<ColumnShare>(blueprint)
         && validate_component::<ComponentColumnSelector>(blueprint)
         && validate_component::<ContainerKind>(blueprint)
@@ -64,10 +74,14 @@ pub fn is_valid_blueprint(blueprint: &EntityDb) -> bool {
         && validate_component::<ForceStrength>(blueprint)
         && validate_component::<GridColumns>(blueprint)
         && validate_component::<GridSpacing>(blueprint)
+        && validate_component::<HopLength>(blueprint)
         && validate_component::<IncludedContent>(blueprint)
+        && validate_component::<KeyframeTime>(blueprint)
         && validate_component::<LinkAxis>(blueprint)
         && validate_component::<LockRangeDuringZoom>(blueprint)
+        && validate_component::<MapFollowMode>(blueprint)
         && validate_component::<MapProvider>(blueprint)
+        && validate_component::<MapTileSource>(blueprint)
         && validate_component::<NearClipPlane>(blueprint)
         && validate_component::<PanelState>(blueprint)
         && validate_component::<QueryExpression>(blueprint)
@@ -84,5 +98,7 @@ pub fn is_valid_blueprint(blueprint: &EntityDb) -> bool {
         && validate_component::<VisibleTimeRange>(blueprint)
         && validate_component::<VisualBounds2D>(blueprint)
         && validate_component::<VisualizerOverride>(blueprint)
+        && validate_component::<WindowFunction>(blueprint)
+        && validate_component::<WindowLength>(blueprint)
         && validate_component::<ZoomLevel>(blueprint)
 }
@@ -13,6 +13,7 @@ fn populate_view_class_registry_with_builtin(
 ) -> Result<(), ViewClassRegistryError> {
     re_tracing::profile_function!();
     view_class_registry.add_class::<re_view_bar_chart::BarChartView>()?;
+    view_class_registry.add_class::<re_view_bar_chart::HistogramView>()?;
     view_class_registry.add_class::<re_view_dataframe::DataframeView>()?;
     view_class_registry.add_class::<re_view_graph::GraphView>()?;
     #[cfg(feature = "map_view")]
@@ -23,6 +24,8 @@ fn populate_view_class_registry_with_builtin(
     view_class_registry.add_class::<re_view_text_document::TextDocumentView>()?;
     view_class_registry.add_class::<re_view_text_log::TextView>()?;
     view_class_registry.add_class::<re_view_time_series::TimeSeriesView>()?;
+    view_class_registry.add_class::<re_view_time_series::XYPlotView>()?;
+    view_class_registry.add_class::<re_view_time_series::SpectrogramView>()?;
 
     Ok(())
 }
@@ -0,0 +1,471 @@
+//! Rendering the viewer offscreen, without a window.
+//!
+//! This drives a regular [`App`] frame by frame and reads back the final image,
+//! which makes it possible to turn recordings & blueprints into images & videos in batch jobs.
+
+use std::time::{Duration, Instant};
+
+use re_chunk::{TimeInt, Timeline, TimelineName};
+use re_log_types::{LogMsg, ResolvedTimeRange};
+use re_types::blueprint::components::PanelState;
+use re_viewer_context::{
+    AsyncRuntimeHandle, CommandSender, SystemCommand, SystemCommandSender as _, command_channel,
+};
+
+use crate::{App, AppEnvironment, StartupOptions, app_blueprint::PanelStateOverrides};
+
+/// Settings for a [`HeadlessViewer`].
+#[derive(Clone, Debug)]
+pub struct HeadlessOptions {
+    /// Size of the rendered image in logical points.
+    pub size_in_points: [f32; 2],
+
+    /// Number of physical pixels per logical point.
+    pub pixels_per_point: f32,
+
+    /// Forces wgpu backend to use the specified graphics API, e.g. `vulkan` or `gl`.
+    pub force_wgpu_backend: Option<String>,
+
+    /// Prefer software rasterizers (lavapipe, llvmpipe, WARP, …) over any GPU.
+    ///
+    /// Useful on machines without a GPU, and for output that doesn't depend on the graphics hardware.
+    pub prefer_software_adapter: bool,
+
+    /// Show the blueprint, selection & time panels instead of only the viewport.
+    pub show_panels: bool,
+
+    /// How many frames to run before reading back an image.
+    ///
+    /// Some content (e.g. video frames or textures) is only ready a few frames after it was first requested.
+    pub settle_frames: usize,
+}
+
+impl Default for HeadlessOptions {
+    fn default() -> Self {
+        Self {
+            size_in_points: [1280.0, 720.0],
+            pixels_per_point: 1.0,
+            force_wgpu_backend: None,
+            prefer_software_adapter: false,
+            show_panels: false,
+            settle_frames: 4,
+        }
+    }
+}
+
+/// A viewer that renders into an offscreen texture instead of a window.
+///
+/// Runs the [`App`] on a plain [`egui::Context`] and paints its output with [`egui_wgpu`],
+/// the same way the native integration does, except that the target is a texture we read back.
+pub struct HeadlessViewer {
+    app: App,
+    egui_ctx: egui::Context,
+    frame: eframe::Frame,
+    render_state: egui_wgpu::RenderState,
+
+    size_in_points: egui::Vec2,
+    pixels_per_point: f32,
+    start_time: Instant,
+
+    /// Shapes of the most recent frame, painted by [`Self::render`].
+    shapes: Vec<egui::epaint::ClippedShape>,
+
+    /// Textures egui asked us to free once the most recent frame is painted.
+    textures_to_free: Vec<egui::TextureId>,
+
+    command_sender: CommandSender,
+    settle_frames: usize,
+}
+
+impl HeadlessViewer {
+    pub fn new(
+        main_thread_token: crate::MainThreadToken,
+        build_info: re_build_info::BuildInfo,
+        app_env: &AppEnvironment,
+        options: HeadlessOptions,
+        async_runtime: AsyncRuntimeHandle,
+    ) -> anyhow::Result<Self> {
+        re_tracing::profile_function!();
+
+        let HeadlessOptions {
+            size_in_points,
+            pixels_per_point,
+            force_wgpu_backend,
+            prefer_software_adapter,
+            show_panels,
+            settle_frames,
+        } = options;
+
+        let render_state =
+            create_render_state(force_wgpu_backend.as_deref(), prefer_software_adapter)?;
+
+        let panel_state_overrides = if show_panels {
+            PanelStateOverrides {
+                top: Some(PanelState::Hidden),
+                ..Default::default()
+            }
+        } else {
+            PanelStateOverrides {
+                top: Some(PanelState::Hidden),
+                blueprint: Some(PanelState::Hidden),
+                selection: Some(PanelState::Hidden),
+                time: Some(PanelState::Hidden),
+            }
+        };
+
+        let startup_options = StartupOptions {
+            persist_state: false,
+            hide_welcome_screen: true,
+            force_wgpu_backend,
+            panel_state_overrides,
+            ..Default::default()
+        };
+
+        let (command_sender, command_receiver) = command_channel();
+
+        let egui_ctx = egui::Context::default();
+        re_ui::apply_style_and_install_loaders(&egui_ctx);
+
+        // eframe has no public way of creating these outside of its own integrations,
+        // except for the constructors meant for running apps in tests. They are just as
+        // suitable for driving an app without a window.
+        let mut creation_context = eframe::CreationContext::_new_kittest(egui_ctx.clone());
+        creation_context.wgpu_render_state = Some(render_state.clone());
+        let mut frame = eframe::Frame::_new_kittest();
+        frame.wgpu_render_state = Some(render_state.clone());
+
+        let app = App::with_commands(
+            main_thread_token,
+            build_info,
+            app_env,
+            startup_options,
+            &creation_context,
+            None,
+            async_runtime,
+            (command_sender.clone(), command_receiver),
+        );
+
+        Ok(Self {
+            app,
+            egui_ctx,
+            frame,
+            render_state,
+            size_in_points: size_in_points.into(),
+            pixels_per_point,
+            start_time: Instant::now(),
+            shapes: Vec::new(),
+            textures_to_free: Vec::new(),
+            command_sender,
+            settle_frames,
+        })
+    }
+
+    /// Add a stream of log messages, e.g. from a recording or a blueprint file.
+    pub fn add_log_receiver(&mut self, rx: re_smart_channel::Receiver<LogMsg>) {
+        self.app.add_log_receiver(rx);
+    }
+
+    /// Run frames until all log receivers have disconnected and all their messages were ingested.
+    pub fn wait_for_data(&mut self, timeout: Duration) -> anyhow::Result<()> {
+        re_tracing::profile_function!();
+
+        let start = Instant::now();
+        while !self.app.msg_receive_set().is_empty() {
+            anyhow::ensure!(
+                start.elapsed() < timeout,
+                "Data did not finish loading within {} seconds",
+                timeout.as_secs()
+            );
+            self.step();
+        }
+
+        // Blueprint activation and view heuristics run a frame after the data arrived.
+        self.run_steps(self.settle_frames);
+
+        Ok(())
+    }
+
+    /// All timelines of the active recording.
+    pub fn timelines(&self) -> Vec<Timeline> {
+        self.app
+            .recording_db()
+            .map(|db| db.timelines().into_values().collect())
+            .unwrap_or_default()
+    }
+
+    /// The time range covered by the active recording on the given timeline.
+    pub fn time_range(&self, timeline: &TimelineName) -> Option<ResolvedTimeRange> {
+        self.app.recording_db()?.time_range_for(timeline)
+    }
+
+    /// Move the time cursor of the active recording and pause playback.
+    pub fn set_time(&mut self, timeline: Timeline, time: TimeInt) -> anyhow::Result<()> {
+        let rec_id = self
+            .app
+            .recording_db()
+            .map(|db| db.store_id().clone())
+            .ok_or_else(|| anyhow::anyhow!("No recording loaded"))?;
+
+        self.command_sender
+            .send_system(SystemCommand::SetActiveTime {
+                rec_id,
+                timeline,
+                time: Some(time),
+            });
+
+        Ok(())
+    }
+
+    /// Render the current state of the viewer.
+    pub fn render(&mut self) -> anyhow::Result<image::RgbaImage> {
+        re_tracing::profile_function!();
+
+        self.run_steps(self.settle_frames);
+        self.paint()
+    }
+
+    fn run_steps(&mut self, num_steps: usize) {
+        for _ in 0..num_steps {
+            self.step();
+        }
+    }
+
+    /// Run a single frame of the app, without painting it.
+    fn step(&mut self) {
+        re_tracing::profile_function!();
+
+        let mut raw_input = egui::RawInput {
+            screen_rect: Some(egui::Rect::from_min_size(
+                egui::Pos2::ZERO,
+                self.size_in_points,
+            )),
+            time: Some(self.start_time.elapsed().as_secs_f64()),
+            ..Default::default()
+        };
+        raw_input
+            .viewports
+            .entry(egui::ViewportId::ROOT)
+            .or_default()
+            .native_pixels_per_point = Some(self.pixels_per_point);
+
+        let Self {
+            app,
+            egui_ctx,
+            frame,
+            ..
+        } = self;
+        let output = egui_ctx.run(raw_input, |egui_ctx| {
+            eframe::App::update(app, egui_ctx, frame);
+        });
+
+        // The previous frame is never going to be painted anymore.
+        let mut renderer = self.render_state.renderer.write();
+        for texture_id in self.textures_to_free.drain(..) {
+            renderer.free_texture(&texture_id);
+        }
+        for (texture_id, image_delta) in &output.textures_delta.set {
+            renderer.update_texture(
+                &self.render_state.device,
+                &self.render_state.queue,
+                *texture_id,
+                image_delta,
+            );
+        }
+
+        self.shapes = output.shapes;
+        self.textures_to_free = output.textures_delta.free;
+    }
+
+    /// Paint the most recent frame into a new texture and read it back.
+    fn paint(&mut self) -> anyhow::Result<image::RgbaImage> {
+        re_tracing::profile_function!();
+
+        let egui_wgpu::RenderState {
+            device,
+            queue,
+            renderer,
+            target_format,
+            ..
+        } = &self.render_state;
+
+        let pixels_per_point = self.egui_ctx.pixels_per_point();
+        let size_in_pixels = (self.size_in_points * pixels_per_point).round();
+        let screen_descriptor = egui_wgpu::ScreenDescriptor {
+            size_in_pixels: [size_in_pixels.x as u32, size_in_pixels.y as u32],
+            pixels_per_point,
+        };
+        let extent = wgpu::Extent3d {
+            width: screen_descriptor.size_in_pixels[0],
+            height: screen_descriptor.size_in_pixels[1],
+            depth_or_array_layers: 1,
+        };
+
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("headless viewer target"),
+            size: extent,
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: *target_format,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC,
+            view_formats: &[],
+        });
+
+        let buffer_info = re_renderer::Texture2DBufferInfo::new(*target_format, extent);
+        let readback_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("headless viewer readback"),
+            size: buffer_info.buffer_size_padded,
+            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+            mapped_at_creation: false,
+        });
+
+        let clipped_primitives = self
+            .egui_ctx
+            .tessellate(std::mem::take(&mut self.shapes), pixels_per_point);
+
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("headless viewer"),
+        });
+        let mut renderer = renderer.write();
+
+        // This is where the views prepare their re_renderer work, see `re_renderer::RenderContext`.
+        let callback_command_buffers = renderer.update_buffers(
+            device,
+            queue,
+            &mut encoder,
+            &clipped_primitives,
+            &screen_descriptor,
+        );
+
+        {
+            let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+            let render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("headless viewer"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: &view,
+                    resolve_target: None,
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
+                        store: wgpu::StoreOp::Store,
+                    },
+                })],
+                depth_stencil_attachment: None,
+                timestamp_writes: None,
+                occlusion_query_set: None,
+            });
+            renderer.render(
+                &mut render_pass.forget_lifetime(),
+                &clipped_primitives,
+                &screen_descriptor,
+            );
+        }
+
+        encoder.copy_texture_to_buffer(
+            texture.as_image_copy(),
+            wgpu::TexelCopyBufferInfo {
+                buffer: &readback_buffer,
+                layout: wgpu::TexelCopyBufferLayout {
+                    offset: 0,
+                    bytes_per_row: Some(buffer_info.bytes_per_row_padded),
+                    rows_per_image: None,
+                },
+            },
+            extent,
+        );
+
+        queue.submit(
+            callback_command_buffers
+                .into_iter()
+                .chain(std::iter::once(encoder.finish())),
+        );
+
+        let buffer_slice = readback_buffer.slice(..);
+        let (tx, rx) = std::sync::mpsc::channel();
+        buffer_slice.map_async(wgpu::MapMode::Read, move |result| {
+            tx.send(result).ok();
+        });
+        device.poll(wgpu::PollType::Wait)?;
+        rx.recv()??;
+
+        let mut pixels = buffer_info
+            .remove_padding(&buffer_slice.get_mapped_range())
+            .into_owned();
+        readback_buffer.unmap();
+
+        match target_format {
+            wgpu::TextureFormat::Rgba8Unorm | wgpu::TextureFormat::Rgba8UnormSrgb => {}
+            wgpu::TextureFormat::Bgra8Unorm | wgpu::TextureFormat::Bgra8UnormSrgb => {
+                for pixel in pixels.chunks_exact_mut(4) {
+                    pixel.swap(0, 2);
+                }
+            }
+            format => anyhow::bail!("Can't read back images of format {format:?}"),
+        }
+
+        image::RgbaImage::from_raw(extent.width, extent.height, pixels)
+            .ok_or_else(|| anyhow::anyhow!("Read back image has an unexpected size"))
+    }
+}
+
+/// Set up an [`egui_wgpu::RenderState`] that isn't tied to any surface, with a [`re_renderer::RenderContext`].
+fn create_render_state(
+    force_wgpu_backend: Option<&str>,
+    prefer_software_adapter: bool,
+) -> anyhow::Result<egui_wgpu::RenderState> {
+    re_tracing::profile_function!();
+
+    let instance = wgpu::Instance::new(&re_renderer::device_caps::instance_descriptor(
+        force_wgpu_backend,
+    ));
+    let adapter =
+        re_renderer::device_caps::select_headless_adapter(&instance, prefer_software_adapter)
+            .ok_or_else(|| anyhow::anyhow!("No graphics adapter found"))?;
+    let device_caps = re_renderer::device_caps::DeviceCaps::from_adapter(&adapter)?;
+    let (device, queue) =
+        pollster::block_on(adapter.request_device(&device_caps.device_descriptor()))?;
+
+    let config = egui_wgpu::WgpuConfiguration {
+        wgpu_setup: egui_wgpu::WgpuSetupExisting {
+            instance: instance.clone(),
+            adapter: adapter.clone(),
+            device: device.clone(),
+            queue: queue.clone(),
+        }
+        .into(),
+
+        // We never present to a surface.
+        present_mode: wgpu::PresentMode::Immediate,
+        desired_maximum_frame_latency: None,
+        on_surface_error: std::sync::Arc::new(|_| egui_wgpu::SurfaceErrorAction::SkipFrame),
+    };
+
+    // The views do their own MSAA & depth and composite into egui's target,
+    // same as for the native viewer.
+    let msaa_samples = 1;
+    let depth_format = None;
+    let dithering = true;
+
+    let render_state = pollster::block_on(egui_wgpu::RenderState::create(
+        &config,
+        &instance,
+        None,
+        depth_format,
+        msaa_samples,
+        dithering,
+    ))?;
+
+    // Put the renderer into paint callback resources, so views can access it.
+    render_state
+        .renderer
+        .write()
+        .callback_resources
+        .insert(re_renderer::RenderContext::new(
+            &adapter,
+            device,
+            queue,
+            render_state.target_format,
+            re_renderer::RenderConfig::best_for_device_caps,
+        )?);
+
+    Ok(render_state)
+}
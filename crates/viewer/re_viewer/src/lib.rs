@@ -43,7 +43,7 @@ pub use re_viewer_context::{
 
 pub mod external {
     pub use parking_lot;
-    pub use {eframe, egui};
+    pub use {eframe, egui, image};
     pub use {
         re_chunk, re_chunk::external::*, re_chunk_store, re_chunk_store::external::*, re_data_ui,
         re_entity_db, re_log, re_log_types, re_memory, re_renderer, re_smart_channel, re_types,
@@ -60,6 +60,9 @@ pub mod native;
 #[cfg(not(target_arch = "wasm32"))]
 pub use native::{run_native_app, run_native_viewer_with_messages};
 
+#[cfg(all(not(target_arch = "wasm32"), feature = "headless"))]
+pub mod headless;
+
 // ----------------------------------------------------------------------------
 // When compiling for web:
 
@@ -87,6 +87,9 @@ enum EndpointCategory {
     /// Could be a link to either an `.rrd` recording or a `.rbl` blueprint.
     HttpRrd(String),
 
+    /// A remote file of any other format our data loaders support, e.g. `http://foo.com/bar.mp4`.
+    HttpFile(String),
+
     /// gRPC Rerun Data Platform URL, e.g. `rerun://ip:port/recording/1234`
     RerunGrpcStream(re_uri::RedapUri),
 
@@ -102,6 +105,10 @@ impl EndpointCategory {
 
         if uri.starts_with("web_event:") {
             Self::WebEventListener(uri)
+        } else if let re_data_source::DataSource::FileHttpUrl(_, uri) =
+            re_data_source::DataSource::from_uri(re_log_types::FileSource::Uri, uri.clone())
+        {
+            Self::HttpFile(uri)
         } else {
             // if uri.starts_with("http") || uri.ends_with(".rrd") || uri.ends_with(".rbl") {
             Self::HttpRrd(uri)
@@ -131,6 +138,27 @@ pub fn url_to_receiver(
             ),
         ),
 
+        EndpointCategory::HttpFile(url) => {
+            let (tx, rx) = re_smart_channel::smart_channel(
+                re_smart_channel::SmartMessageSource::File(url.clone().into()),
+                re_smart_channel::SmartChannelSource::File(url.clone().into()),
+            );
+            let settings = re_data_loader::DataLoaderSettings::recommended(
+                re_log_types::StoreId::random(re_log_types::StoreKind::Recording),
+            );
+            if let Err(err) = re_data_loader::load_from_uri(
+                &settings,
+                re_log_types::FileSource::Uri,
+                &url,
+                &tx,
+                Some(ui_waker),
+            ) {
+                re_log::error!("Failed to load {url}: {err}");
+                return None;
+            }
+            Some(rx)
+        }
+
         EndpointCategory::RerunGrpcStream(re_uri::RedapUri::DatasetData(uri)) => {
             let on_cmd = Box::new(move |cmd| match cmd {
                 re_grpc_client::Command::SetLoopSelection {
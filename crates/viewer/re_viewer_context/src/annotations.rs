@@ -49,6 +49,17 @@ impl Annotations {
         }
     }
 
+    /// All class descriptions, ordered by class id.
+    pub fn class_descriptions(&self) -> Vec<&ClassDescription> {
+        let mut descriptions: Vec<_> = self
+            .class_map
+            .values()
+            .map(|cached| &cached.class_description)
+            .collect();
+        descriptions.sort_by_key(|description| description.info.id);
+        descriptions
+    }
+
     #[inline]
     pub fn row_id(&self) -> RowId {
         self.row_id
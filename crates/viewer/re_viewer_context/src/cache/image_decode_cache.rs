@@ -22,7 +22,7 @@ struct DecodedImageResult {
     last_use_generation: u64,
 }
 
-/// Caches the results of decoding [`re_types::archetypes::EncodedImage`].
+/// Caches the results of decoding [`re_types::archetypes::EncodedImage`] and [`re_types::archetypes::EncodedDepthImage`].
 #[derive(Default)]
 pub struct ImageDecodeCache {
     cache: HashMap<StoredBlobCacheKey, HashMap<Hash64, DecodedImageResult>>,
@@ -37,12 +37,15 @@ impl ImageDecodeCache {
     /// The `RowId`, if available, may be used to generate the cache key.
     /// NOTE: images are never batched atm (they are mono-archetypes),
     /// so we don't need the instance id here.
+    ///
+    /// Depth images are decoded to a single channel, see [`ImageBuffer::from_dynamic_depth_image`].
     pub fn entry(
         &mut self,
         blob_row_id: RowId,
         blob_component_descriptor: &ComponentDescriptor,
         image_bytes: &[u8],
         media_type: Option<&MediaType>,
+        image_kind: ImageKind,
     ) -> Result<ImageInfo, ImageLoadError> {
         re_tracing::profile_function!();
 
@@ -56,7 +59,7 @@ impl ImageDecodeCache {
             return Err(ImageLoadError::UnrecognizedMimeType);
         };
 
-        let inner_key = Hash64::hash(&media_type);
+        let inner_key = Hash64::hash((&media_type, image_kind));
 
         // The descriptor should always be the one in the encoded image archetype, but in the future
         // we may allow overrides such that it is sourced from somewhere else.
@@ -73,6 +76,7 @@ impl ImageDecodeCache {
                     blob_component_descriptor,
                     image_bytes,
                     media_type.as_str(),
+                    image_kind,
                 );
                 let memory_used = result.as_ref().map_or(0, |image| image.buffer.len() as u64);
                 self.memory_used += memory_used;
@@ -92,6 +96,7 @@ fn decode_image(
     blob_component_descriptor: &ComponentDescriptor,
     image_bytes: &[u8],
     media_type: &str,
+    image_kind: ImageKind,
 ) -> Result<ImageInfo, ImageLoadError> {
     re_tracing::profile_function!();
 
@@ -105,14 +110,19 @@ fn decode_image(
 
     let dynamic_image = reader.decode()?;
 
-    let (buffer, format) = ImageBuffer::from_dynamic_image(dynamic_image)?;
+    let (buffer, format) = match image_kind {
+        ImageKind::Depth => ImageBuffer::from_dynamic_depth_image(dynamic_image)?,
+        ImageKind::Color | ImageKind::Segmentation => {
+            ImageBuffer::from_dynamic_image(dynamic_image)?
+        }
+    };
 
     Ok(ImageInfo::from_stored_blob(
         blob_row_id,
         blob_component_descriptor,
         buffer.0,
         format.0,
-        ImageKind::Color,
+        image_kind,
     ))
 }
 
@@ -172,3 +182,41 @@ impl Cache for ImageDecodeCache {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use re_types::{archetypes::EncodedDepthImage, datatypes::ChannelDatatype};
+
+    use super::*;
+
+    #[test]
+    fn decode_exr_without_media_type() {
+        let depth = image::Rgb32FImage::from_fn(4, 2, |x, y| image::Rgb([(x + 4 * y) as f32; 3]));
+        let mut exr_bytes = Vec::new();
+        image::DynamicImage::ImageRgb32F(depth)
+            .write_to(
+                &mut std::io::Cursor::new(&mut exr_bytes),
+                image::ImageFormat::OpenExr,
+            )
+            .unwrap();
+        assert_eq!(
+            MediaType::guess_from_data(&exr_bytes),
+            Some(MediaType::exr())
+        );
+
+        let image = ImageDecodeCache::default()
+            .entry(
+                RowId::new(),
+                &EncodedDepthImage::descriptor_blob(),
+                &exr_bytes,
+                None,
+                ImageKind::Depth,
+            )
+            .unwrap();
+
+        assert_eq!([image.format.width, image.format.height], [4, 2]);
+        assert_eq!(image.format.datatype(), ChannelDatatype::F32);
+        let depths: &[f32] = bytemuck::cast_slice(&image.buffer[..]);
+        assert_eq!(depths, (0..8).map(|d| d as f32).collect::<Vec<_>>());
+    }
+}
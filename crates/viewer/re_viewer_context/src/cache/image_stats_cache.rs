@@ -1,27 +1,54 @@
+use std::sync::Arc;
+
 use ahash::{HashMap, HashSet};
 use itertools::Either;
 
 use re_chunk_store::ChunkStoreEvent;
 use re_types::{Component as _, components, image::ImageKind};
 
-use crate::{Cache, ImageInfo, ImageStats, image_info::StoredBlobCacheKey};
+use crate::{Cache, ImageInfo, ImageStats, ValueHistogram, image_info::StoredBlobCacheKey};
+
+use super::tensor_stats_cache::HistogramCache;
 
-// Caches image stats (use e.g. `RowId` to generate cache key).
+// Caches image stats and histograms (use e.g. `RowId` to generate cache key).
 #[derive(Default)]
-pub struct ImageStatsCache(HashMap<(StoredBlobCacheKey, ImageKind), ImageStats>);
+pub struct ImageStatsCache {
+    stats: HashMap<(StoredBlobCacheKey, ImageKind), ImageStats>,
+    histograms: HistogramCache<(StoredBlobCacheKey, ImageKind), Option<Arc<ValueHistogram>>>,
+}
 
 impl ImageStatsCache {
     pub fn entry(&mut self, image: &ImageInfo) -> ImageStats {
         *self
-            .0
+            .stats
             .entry((image.buffer_content_hash, image.kind))
             .or_insert_with(|| ImageStats::from_image(image))
     }
+
+    /// The histogram of all channel values of an image, see [`ValueHistogram::from_image`].
+    pub fn histogram(
+        &mut self,
+        image: &ImageInfo,
+        range: (f64, f64),
+        num_bins: usize,
+    ) -> Option<Arc<ValueHistogram>> {
+        self.histograms.entry(
+            (image.buffer_content_hash, image.kind),
+            range,
+            num_bins,
+            || ValueHistogram::from_image(image, range, num_bins).map(Arc::new),
+        )
+    }
 }
 
 impl Cache for ImageStatsCache {
+    fn begin_frame(&mut self, _renderer_active_frame_idx: u64) {
+        self.histograms.begin_frame();
+    }
+
     fn purge_memory(&mut self) {
         // Purging the image stats is not worth it - these are very small objects!
+        self.histograms.clear();
     }
 
     fn on_store_events(&mut self, events: &[ChunkStoreEvent]) {
@@ -49,8 +76,10 @@ impl Cache for ImageStatsCache {
             })
             .collect();
 
-        self.0
+        self.stats
             .retain(|cache_key, _per_key| !cache_key_removed.contains(cache_key));
+        self.histograms
+            .retain(|cache_key| !cache_key_removed.contains(cache_key));
     }
 
     fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
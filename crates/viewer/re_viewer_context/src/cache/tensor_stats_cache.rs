@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use ahash::{HashMap, HashSet};
 use itertools::Either;
 
@@ -5,13 +7,83 @@ use re_chunk_store::ChunkStoreEvent;
 use re_log_types::hash::Hash64;
 use re_types::{archetypes::Tensor, datatypes::TensorData};
 
-use crate::{Cache, TensorStats};
+use crate::{Cache, TensorStats, ValueHistogram};
 
-/// Caches tensor stats.
+/// Caches tensor stats and histograms.
 ///
 /// Use [`re_types_core::RowId`] as cache key when available.
 #[derive(Default)]
-pub struct TensorStatsCache(HashMap<Hash64, TensorStats>);
+pub struct TensorStatsCache {
+    stats: HashMap<Hash64, TensorStats>,
+
+    histograms: HistogramCache<Hash64, Arc<ValueHistogram>>,
+}
+
+/// The range (as bits) and bin count of a cached histogram.
+type HistogramBinning = ([u64; 2], usize);
+
+/// Histograms, keyed by their source and the requested binning.
+///
+/// The binning usually follows the data shown next to the histogram, so it can change every
+/// frame. Histograms that weren't used during the last frame are dropped in
+/// [`Self::begin_frame`] rather than piling up.
+pub(super) struct HistogramCache<K, V> {
+    histograms: HashMap<(K, HistogramBinning), CachedHistogram<V>>,
+}
+
+struct CachedHistogram<V> {
+    histogram: V,
+    used_this_frame: bool,
+}
+
+impl<K, V> Default for HistogramCache<K, V> {
+    fn default() -> Self {
+        Self {
+            histograms: Default::default(),
+        }
+    }
+}
+
+impl<K: Eq + std::hash::Hash, V: Clone> HistogramCache<K, V> {
+    pub fn entry(
+        &mut self,
+        key: K,
+        range: (f64, f64),
+        num_bins: usize,
+        compute: impl FnOnce() -> V,
+    ) -> V {
+        let binning = ([range.0.to_bits(), range.1.to_bits()], num_bins);
+        let entry = self
+            .histograms
+            .entry((key, binning))
+            .or_insert_with(|| CachedHistogram {
+                histogram: compute(),
+                used_this_frame: true,
+            });
+        entry.used_this_frame = true;
+        entry.histogram.clone()
+    }
+
+    pub fn begin_frame(&mut self) {
+        self.histograms.retain(|_, cached| cached.used_this_frame);
+        for cached in self.histograms.values_mut() {
+            cached.used_this_frame = false;
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.histograms.clear();
+    }
+
+    pub fn retain(&mut self, mut keep: impl FnMut(&K) -> bool) {
+        self.histograms.retain(|(key, _), _| keep(key));
+    }
+
+    #[cfg(test)]
+    fn len(&self) -> usize {
+        self.histograms.len()
+    }
+}
 
 impl TensorStatsCache {
     /// The `RowId` of the `TensorData` may be used as a cache key.
@@ -19,15 +91,35 @@ impl TensorStatsCache {
     /// so we don't need the instance id here.
     pub fn entry(&mut self, tensor_cache_key: Hash64, tensor: &TensorData) -> TensorStats {
         *self
-            .0
+            .stats
             .entry(tensor_cache_key)
             .or_insert_with(|| TensorStats::from_tensor(tensor))
     }
+
+    /// The histogram of all elements of a tensor, see [`ValueHistogram::from_tensor`].
+    pub fn histogram(
+        &mut self,
+        tensor_cache_key: Hash64,
+        tensor: &TensorData,
+        range: (f64, f64),
+        num_bins: usize,
+    ) -> Arc<ValueHistogram> {
+        self.histograms
+            .entry(tensor_cache_key, range, num_bins, || {
+                Arc::new(ValueHistogram::from_tensor(tensor, range, num_bins))
+            })
+    }
 }
 
 impl Cache for TensorStatsCache {
+    fn begin_frame(&mut self, _renderer_active_frame_idx: u64) {
+        self.histograms.begin_frame();
+    }
+
     fn purge_memory(&mut self) {
         // Purging the tensor stats is not worth it - these are very small objects!
+        // Histograms on the other hand are recomputed whenever the binning changes.
+        self.histograms.clear();
     }
 
     fn on_store_events(&mut self, events: &[ChunkStoreEvent]) {
@@ -52,11 +144,56 @@ impl Cache for TensorStatsCache {
             })
             .collect();
 
-        self.0
+        self.stats
             .retain(|cache_key, _per_key| !cache_keys.contains(cache_key));
+        self.histograms
+            .retain(|cache_key| !cache_keys.contains(cache_key));
     }
 
     fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::HistogramCache;
+
+    #[test]
+    fn histogram_cache_drops_unused_binnings() {
+        let mut cache = HistogramCache::<u64, usize>::default();
+
+        // A range that changes every frame, e.g. because it follows a growing scalar series.
+        for frame in 0..10 {
+            cache.begin_frame();
+            assert_eq!(
+                cache.entry(0, (0.0, frame as f64 + 1.0), 16, || frame),
+                frame
+            );
+            assert_eq!(cache.entry(1, (0.0, 1.0), 16, || frame), 0);
+        }
+        // Only what was used during this and the previous frame is still around.
+        assert_eq!(cache.len(), 3);
+
+        // Unchanged binnings are computed once.
+        cache.begin_frame();
+        assert_eq!(cache.entry(0, (0.0, 10.0), 16, || 100), 9);
+
+        // Not used last frame.
+        cache.begin_frame();
+        cache.begin_frame();
+        assert_eq!(cache.len(), 0);
+    }
+
+    #[test]
+    fn histogram_cache_retain() {
+        let mut cache = HistogramCache::<u64, usize>::default();
+        cache.entry(0, (0.0, 1.0), 16, || 0);
+        cache.entry(0, (0.0, 1.0), 32, || 1);
+        cache.entry(1, (0.0, 1.0), 16, || 2);
+
+        cache.retain(|key| *key != 0);
+        assert_eq!(cache.len(), 1);
+        assert_eq!(cache.entry(1, (0.0, 1.0), 16, || 3), 2);
+    }
+}
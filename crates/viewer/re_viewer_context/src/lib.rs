@@ -64,10 +64,10 @@ pub use self::{
         ItemContext, SelectionChange, SelectionHighlight,
     },
     storage_context::StorageContext,
-    store_context::StoreContext,
+    store_context::{ComparedRecording, StoreContext},
     store_hub::StoreHub,
     tables::{TableStore, TableStores},
-    tensor::{ImageStats, TensorStats},
+    tensor::{ImageStats, TensorStats, ValueHistogram},
     time_control::{Looping, PlayState, TimeControl, TimeControlResponse, TimeView},
     typed_entity_collections::{
         IndicatedEntities, MaybeVisualizableEntities, PerVisualizer, VisualizableEntities,
@@ -1,7 +1,7 @@
 use re_entity_db::EntityDb;
 use re_log_types::{ApplicationId, StoreId};
 
-use crate::Caches;
+use crate::{Caches, RecordingComparison};
 
 /// The current Blueprint and Recording being displayed by the viewer
 pub struct StoreContext<'a> {
@@ -24,10 +24,60 @@ pub struct StoreContext<'a> {
 
     /// Should we enable the heuristics during this frame?
     pub should_enable_heuristics: bool,
+
+    /// The recording that is compared against the active one, if any.
+    pub comparison: Option<ComparedRecording<'a>>,
+
+    /// Is [`Self::recording`] the compared recording rather than the active one?
+    ///
+    /// Only ever true for the contexts returned by [`Self::compared`].
+    pub is_compared_recording: bool,
+}
+
+/// A recording shown alongside the active one, see [`RecordingComparison`].
+#[derive(Clone, Copy)]
+pub struct ComparedRecording<'a> {
+    pub recording: &'a EntityDb,
+
+    /// Things that need caching, for the compared recording.
+    pub caches: &'a Caches,
+
+    pub settings: &'a RecordingComparison,
 }
 
-impl StoreContext<'_> {
+impl<'a> StoreContext<'a> {
     pub fn is_active(&self, store_id: &StoreId) -> bool {
         self.recording.store_id() == *store_id || self.blueprint.store_id() == *store_id
     }
+
+    /// The same context, but querying the compared recording instead of the active one.
+    ///
+    /// Returns `None` if there's nothing to compare against.
+    pub fn compared(&self) -> Option<Self> {
+        let comparison = self.comparison.filter(|_| !self.is_compared_recording)?;
+
+        Some(Self {
+            app_id: self.app_id.clone(),
+            blueprint: self.blueprint,
+            default_blueprint: self.default_blueprint,
+            recording: comparison.recording,
+            caches: comparison.caches,
+            should_enable_heuristics: false,
+            comparison: Some(comparison),
+            is_compared_recording: true,
+        })
+    }
+
+    /// The color to draw data from [`Self::recording`] with, given the color it was logged with.
+    ///
+    /// Data from the compared recording gets tinted and faded, see [`RecordingComparison`].
+    #[inline]
+    pub fn recording_color(&self, color: egui::Color32) -> egui::Color32 {
+        match self.comparison {
+            Some(comparison) if self.is_compared_recording => {
+                comparison.settings.apply_to_color(color)
+            }
+            _ => color,
+        }
+    }
 }
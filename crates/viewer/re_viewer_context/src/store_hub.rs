@@ -8,12 +8,15 @@ use re_chunk_store::{
     GarbageCollectionTarget,
 };
 use re_entity_db::{EntityDb, StoreBundle};
-use re_global_context::RecordingOrTable;
+use re_global_context::{RecordingComparison, RecordingOrTable};
 use re_log_types::{ApplicationId, ResolvedTimeRange, StoreId, StoreKind, TableId};
 use re_query::CachesStats;
 use re_types::{archetypes, components::Timestamp};
 
-use crate::{BlueprintUndoState, Caches, StorageContext, StoreContext, TableStore, TableStores};
+use crate::{
+    BlueprintUndoState, Caches, ComparedRecording, StorageContext, StoreContext, TableStore,
+    TableStores,
+};
 
 /// Interface for accessing all blueprints and recordings
 ///
@@ -44,6 +47,9 @@ pub struct StoreHub {
     active_recording_or_table: Option<RecordingOrTable>,
     active_application_id: Option<ApplicationId>,
 
+    /// A second recording shown alongside the active one, if any.
+    recording_comparison: Option<RecordingComparison>,
+
     default_blueprint_by_app_id: HashMap<ApplicationId, StoreId>,
     active_blueprint_by_app_id: HashMap<ApplicationId, StoreId>,
 
@@ -153,6 +159,7 @@ impl StoreHub {
             persistence,
             active_recording_or_table: None,
             active_application_id: None,
+            recording_comparison: None,
 
             default_blueprint_by_app_id,
             active_blueprint_by_app_id: Default::default(),
@@ -257,6 +264,18 @@ impl StoreHub {
             let should_enable_heuristics = self.should_enable_heuristics_by_app_id.remove(&app_id);
             let caches = self.active_caches();
 
+            let comparison = self.recording_comparison.as_ref().and_then(|settings| {
+                // Comparing a recording with itself would only show everything twice.
+                if recording.is_some_and(|recording| recording.store_id() == settings.store_id) {
+                    return None;
+                }
+                Some(ComparedRecording {
+                    recording: self.store_bundle.get(&settings.store_id)?,
+                    caches: self.caches_per_recording.get(&settings.store_id)?,
+                    settings,
+                })
+            });
+
             Some(StoreContext {
                 app_id,
                 blueprint: active_blueprint,
@@ -264,6 +283,8 @@ impl StoreHub {
                 recording: recording.unwrap_or(&EMPTY_ENTITY_DB),
                 caches: caches.unwrap_or(&EMPTY_CACHES),
                 should_enable_heuristics,
+                comparison,
+                is_compared_recording: false,
             })
         };
 
@@ -300,6 +321,9 @@ impl StoreHub {
 
     fn remove_store(&mut self, store_id: &StoreId) {
         _ = self.caches_per_recording.remove(store_id);
+        if self.comparison_recording_id() == Some(store_id) {
+            self.recording_comparison = None;
+        }
         let removed_store = self.store_bundle.remove(store_id);
 
         let Some(removed_store) = removed_store else {
@@ -373,6 +397,7 @@ impl StoreHub {
             .retain(|store_id, _| store_ids_retained.contains(store_id));
 
         self.table_stores.clear();
+        self.recording_comparison = None;
         self.active_application_id = Some(Self::welcome_screen_app_id());
     }
 
@@ -530,6 +555,46 @@ impl StoreHub {
         }
     }
 
+    // ---------------------
+    // Recording comparison
+
+    /// The recording that is compared against the active one, if any.
+    #[inline]
+    pub fn comparison_recording_id(&self) -> Option<&StoreId> {
+        self.recording_comparison
+            .as_ref()
+            .map(|comparison| &comparison.store_id)
+    }
+
+    /// How the compared recording is shown, if there is one.
+    #[inline]
+    pub fn recording_comparison(&self) -> Option<&RecordingComparison> {
+        self.recording_comparison.as_ref()
+    }
+
+    /// Start or stop comparing a recording with the active one.
+    ///
+    /// The compared recording is only shown while it is not itself the active recording.
+    pub fn set_recording_comparison(&mut self, comparison: Option<RecordingComparison>) {
+        if let Some(comparison) = &comparison {
+            if comparison.store_id.kind != StoreKind::Recording {
+                re_log::debug!(
+                    "Tried to compare the blueprint {} with the active recording.",
+                    comparison.store_id
+                );
+                return;
+            }
+
+            // Like the active recording, the compared one needs caches of its own.
+            _ = self
+                .caches_per_recording
+                .entry(comparison.store_id.clone())
+                .or_default();
+        }
+
+        self.recording_comparison = comparison;
+    }
+
     // ---------------------
     // Default blueprint
 
@@ -811,7 +876,11 @@ impl StoreHub {
 
     /// See `re_viewer_context::Cache::begin_frame`.
     pub fn begin_frame(&mut self, renderer_active_frame_idx: u64) {
-        if let Some(store_id) = self.active_recording_id().cloned() {
+        let store_ids = [
+            self.active_recording_id().cloned(),
+            self.comparison_recording_id().cloned(),
+        ];
+        for store_id in store_ids.into_iter().flatten() {
             if let Some(caches) = self.caches_per_recording.get_mut(&store_id) {
                 caches.begin_frame(renderer_active_frame_idx);
             }
@@ -2,6 +2,8 @@
 
 mod image_stats;
 mod tensor_stats;
+mod value_histogram;
 
 pub use image_stats::ImageStats;
 pub use tensor_stats::TensorStats;
+pub use value_histogram::ValueHistogram;
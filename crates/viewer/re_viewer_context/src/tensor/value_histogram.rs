@@ -0,0 +1,196 @@
+use re_types::datatypes::{ChannelDatatype, TensorBuffer, TensorData};
+
+use crate::ImageInfo;
+
+/// How many elements of a tensor, image or signal fall into each of a number of evenly spaced bins.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ValueHistogram {
+    /// The value range covered by the bins, `min..=max`.
+    ///
+    /// Values outside of this range, as well as `NaN`s, are not counted.
+    pub range: (f64, f64),
+
+    /// The number of values in each bin, from the lowest to the highest bin.
+    pub counts: Vec<u64>,
+}
+
+impl ValueHistogram {
+    /// Bins `values` into `num_bins` bins spread evenly over `range`.
+    ///
+    /// An empty range is widened so that its single value ends up in the middle of the bins.
+    pub fn from_values(
+        values: impl Iterator<Item = f64>,
+        range: (f64, f64),
+        num_bins: usize,
+    ) -> Self {
+        re_tracing::profile_function!();
+
+        let (mut min, mut max) = range;
+        if max <= min {
+            min -= 0.5;
+            max = min + 1.0;
+        }
+
+        let num_bins = num_bins.max(1);
+        let mut counts = vec![0; num_bins];
+        let bins_per_unit = num_bins as f64 / (max - min);
+
+        for value in values {
+            if !(min..=max).contains(&value) {
+                continue;
+            }
+            // The maximum belongs to the last bin rather than one past it.
+            let bin = (((value - min) * bins_per_unit) as usize).min(num_bins - 1);
+            counts[bin] += 1;
+        }
+
+        Self {
+            range: (min, max),
+            counts,
+        }
+    }
+
+    /// Bins all elements of a tensor.
+    pub fn from_tensor(tensor: &TensorData, range: (f64, f64), num_bins: usize) -> Self {
+        match &tensor.buffer {
+            TensorBuffer::U8(data) => {
+                Self::from_values(data.iter().map(|&v| v as f64), range, num_bins)
+            }
+            TensorBuffer::U16(data) => {
+                Self::from_values(data.iter().map(|&v| v as f64), range, num_bins)
+            }
+            TensorBuffer::U32(data) => {
+                Self::from_values(data.iter().map(|&v| v as f64), range, num_bins)
+            }
+            TensorBuffer::U64(data) => {
+                Self::from_values(data.iter().map(|&v| v as f64), range, num_bins)
+            }
+            TensorBuffer::I8(data) => {
+                Self::from_values(data.iter().map(|&v| v as f64), range, num_bins)
+            }
+            TensorBuffer::I16(data) => {
+                Self::from_values(data.iter().map(|&v| v as f64), range, num_bins)
+            }
+            TensorBuffer::I32(data) => {
+                Self::from_values(data.iter().map(|&v| v as f64), range, num_bins)
+            }
+            TensorBuffer::I64(data) => {
+                Self::from_values(data.iter().map(|&v| v as f64), range, num_bins)
+            }
+            TensorBuffer::F16(data) => {
+                Self::from_values(data.iter().map(|v| v.to_f64()), range, num_bins)
+            }
+            TensorBuffer::F32(data) => {
+                Self::from_values(data.iter().map(|&v| v as f64), range, num_bins)
+            }
+            TensorBuffer::F64(data) => Self::from_values(data.iter().copied(), range, num_bins),
+        }
+    }
+
+    /// Bins all channel values of an image.
+    ///
+    /// Returns `None` for images with a pixel format, since their raw bytes aren't channel values.
+    pub fn from_image(image: &ImageInfo, range: (f64, f64), num_bins: usize) -> Option<Self> {
+        if image.format.pixel_format.is_some() {
+            return None;
+        }
+
+        // TODO(#6008): support stride
+        Some(match image.format.datatype() {
+            ChannelDatatype::U8 => Self::from_values(
+                image.to_slice::<u8>().iter().map(|&v| v as f64),
+                range,
+                num_bins,
+            ),
+            ChannelDatatype::U16 => Self::from_values(
+                image.to_slice::<u16>().iter().map(|&v| v as f64),
+                range,
+                num_bins,
+            ),
+            ChannelDatatype::U32 => Self::from_values(
+                image.to_slice::<u32>().iter().map(|&v| v as f64),
+                range,
+                num_bins,
+            ),
+            ChannelDatatype::U64 => Self::from_values(
+                image.to_slice::<u64>().iter().map(|&v| v as f64),
+                range,
+                num_bins,
+            ),
+            ChannelDatatype::I8 => Self::from_values(
+                image.to_slice::<i8>().iter().map(|&v| v as f64),
+                range,
+                num_bins,
+            ),
+            ChannelDatatype::I16 => Self::from_values(
+                image.to_slice::<i16>().iter().map(|&v| v as f64),
+                range,
+                num_bins,
+            ),
+            ChannelDatatype::I32 => Self::from_values(
+                image.to_slice::<i32>().iter().map(|&v| v as f64),
+                range,
+                num_bins,
+            ),
+            ChannelDatatype::I64 => Self::from_values(
+                image.to_slice::<i64>().iter().map(|&v| v as f64),
+                range,
+                num_bins,
+            ),
+            ChannelDatatype::F16 => Self::from_values(
+                image.to_slice::<half::f16>().iter().map(|v| v.to_f64()),
+                range,
+                num_bins,
+            ),
+            ChannelDatatype::F32 => Self::from_values(
+                image.to_slice::<f32>().iter().map(|&v| v as f64),
+                range,
+                num_bins,
+            ),
+            ChannelDatatype::F64 => {
+                Self::from_values(image.to_slice::<f64>().iter().copied(), range, num_bins)
+            }
+        })
+    }
+
+    /// The width of a single bin.
+    #[inline]
+    pub fn bin_width(&self) -> f64 {
+        (self.range.1 - self.range.0) / self.counts.len() as f64
+    }
+
+    /// The value at the center of the bin with the given index.
+    #[inline]
+    pub fn bin_center(&self, bin: usize) -> f64 {
+        self.range.0 + (bin as f64 + 0.5) * self.bin_width()
+    }
+
+    /// The total number of values that were binned.
+    pub fn total_count(&self) -> u64 {
+        self.counts.iter().sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ValueHistogram;
+
+    #[test]
+    fn bins_values() {
+        let values = [0.0, 0.1, 0.5, 0.99, 1.0, 2.0, -1.0, f64::NAN];
+        let histogram = ValueHistogram::from_values(values.into_iter(), (0.0, 1.0), 4);
+
+        // Values outside of the range and NaNs are ignored, the maximum lands in the last bin.
+        assert_eq!(histogram.counts, vec![2, 0, 1, 2]);
+        assert_eq!(histogram.total_count(), 5);
+        assert_eq!(histogram.bin_width(), 0.25);
+        assert_eq!(histogram.bin_center(0), 0.125);
+    }
+
+    #[test]
+    fn empty_range() {
+        let histogram = ValueHistogram::from_values([3.0, 3.0].into_iter(), (3.0, 3.0), 3);
+        assert_eq!(histogram.range, (2.5, 3.5));
+        assert_eq!(histogram.counts, vec![0, 2, 0]);
+    }
+}
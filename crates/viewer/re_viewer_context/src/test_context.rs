@@ -376,6 +376,8 @@ impl TestContext {
             recording: &self.recording_store,
             caches: &Default::default(),
             should_enable_heuristics: false,
+            comparison: None,
+            is_compared_recording: false,
         };
 
         let indicated_entities_per_visualizer = self
@@ -572,7 +574,8 @@ impl TestContext {
                 | SystemCommand::UndoBlueprint { .. }
                 | SystemCommand::RedoBlueprint { .. }
                 | SystemCommand::CloseAllEntries
-                | SystemCommand::SetLoopSelection { .. } => handled = false,
+                | SystemCommand::SetLoopSelection { .. }
+                | SystemCommand::AppendToAnnotationRecording { .. } => handled = false,
 
                 #[cfg(debug_assertions)]
                 SystemCommand::EnableInspectBlueprintTimeline(_) => handled = false,
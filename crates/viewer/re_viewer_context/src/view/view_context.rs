@@ -81,6 +81,33 @@ impl<'a> ViewContext<'a> {
         self.viewer_ctx.recording_id()
     }
 
+    /// Runs `f` with a view context that queries the compared recording instead of the active one.
+    ///
+    /// Returns `None` without calling `f` if no recording is being compared.
+    /// Times need to be converted with [`crate::RecordingComparison::to_compared_time`] before
+    /// querying the compared recording.
+    pub fn with_compared_recording<R>(
+        &self,
+        f: impl FnOnce(&ViewContext<'_>, &crate::RecordingComparison) -> R,
+    ) -> Option<R> {
+        let store_context = self.viewer_ctx.store_context.compared()?;
+        let settings = store_context.comparison?.settings;
+
+        Some(
+            self.viewer_ctx
+                .with_store_context(&store_context, |viewer_ctx| {
+                    let view_ctx = ViewContext {
+                        viewer_ctx,
+                        view_id: self.view_id,
+                        view_class_identifier: self.view_class_identifier,
+                        view_state: self.view_state,
+                        query_result: self.query_result,
+                    };
+                    f(&view_ctx, settings)
+                }),
+        )
+    }
+
     /// Returns the current selection.
     #[inline]
     pub fn selection(&self) -> &crate::ItemCollection {
@@ -156,6 +156,59 @@ impl ViewerContext<'_> {
         self.store_context.recording.store_id()
     }
 
+    /// The recording that is compared against the active one, if any.
+    #[inline]
+    pub fn comparison(&self) -> Option<&crate::ComparedRecording<'_>> {
+        self.store_context.comparison.as_ref()
+    }
+
+    /// Runs `f` with a copy of this context that uses `store_context` instead.
+    ///
+    /// Everything else, including the time control of the active recording, is shared.
+    pub fn with_store_context<R>(
+        &self,
+        store_context: &StoreContext<'_>,
+        f: impl FnOnce(&ViewerContext<'_>) -> R,
+    ) -> R {
+        let GlobalContext {
+            app_options,
+            reflection,
+            egui_ctx,
+            render_ctx,
+            command_sender,
+            connection_registry,
+        } = self.global_context;
+
+        let ctx = ViewerContext {
+            global_context: GlobalContext {
+                app_options,
+                reflection,
+                egui_ctx,
+                render_ctx,
+                command_sender,
+                connection_registry,
+            },
+            storage_context: self.storage_context,
+            view_class_registry: self.view_class_registry,
+            component_ui_registry: self.component_ui_registry,
+            maybe_visualizable_entities_per_visualizer: self
+                .maybe_visualizable_entities_per_visualizer,
+            indicated_entities_per_visualizer: self.indicated_entities_per_visualizer,
+            query_results: self.query_results,
+            rec_cfg: self.rec_cfg,
+            blueprint_cfg: self.blueprint_cfg,
+            blueprint_query: self.blueprint_query,
+            selection_state: self.selection_state,
+            focused_item: self.focused_item,
+            drag_and_drop_manager: self.drag_and_drop_manager,
+            active_redap_entry: self.active_redap_entry,
+            active_table_id: self.active_table_id,
+            store_context,
+        };
+
+        f(&ctx)
+    }
+
     /// Returns the current selection.
     pub fn selection(&self) -> &ItemCollection {
         self.selection_state.selected_items()
@@ -755,6 +755,8 @@ mod tests {
             recording: &test_ctx.recording_store,
             caches: &Default::default(),
             should_enable_heuristics: false,
+            comparison: None,
+            is_compared_recording: false,
         };
 
         let mut query_result = view.contents.execute_query(
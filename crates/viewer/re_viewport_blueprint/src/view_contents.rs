@@ -693,6 +693,8 @@ mod tests {
             recording: &recording,
             caches: &Default::default(),
             should_enable_heuristics: false,
+            comparison: None,
+            is_compared_recording: false,
         };
 
         struct Scenario {
@@ -15,9 +15,12 @@
 #include "archetypes/cylinders3d.hpp"
 #include "archetypes/depth_image.hpp"
 #include "archetypes/ellipsoids3d.hpp"
+#include "archetypes/encoded_depth_image.hpp"
 #include "archetypes/encoded_image.hpp"
+#include "archetypes/gaussian_splats3d.hpp"
 #include "archetypes/geo_line_strings.hpp"
 #include "archetypes/geo_points.hpp"
+#include "archetypes/geo_polygons.hpp"
 #include "archetypes/graph_edges.hpp"
 #include "archetypes/graph_nodes.hpp"
 #include "archetypes/image.hpp"
@@ -0,0 +1,355 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/archetypes/encoded_depth_image.fbs".
+
+#pragma once
+
+#include "../collection.hpp"
+#include "../component_batch.hpp"
+#include "../component_column.hpp"
+#include "../components/blob.hpp"
+#include "../components/colormap.hpp"
+#include "../components/depth_meter.hpp"
+#include "../components/draw_order.hpp"
+#include "../components/fill_ratio.hpp"
+#include "../components/media_type.hpp"
+#include "../components/value_range.hpp"
+#include "../indicator_component.hpp"
+#include "../result.hpp"
+
+#include <cstdint>
+#include <filesystem>
+#include <optional>
+#include <utility>
+#include <vector>
+
+namespace rerun::archetypes {
+    /// **Archetype**: A depth image encoded as e.g. a 16-bit PNG or an EXR.
+    ///
+    /// Each pixel corresponds to a depth value in units specified by `components::DepthMeter`.
+    /// The decoded image must have a single channel.
+    ///
+    /// Rerun also supports uncompressed depth images with the `archetypes::DepthImage`.
+    ///
+    /// ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    struct EncodedDepthImage {
+        /// The encoded content of some image file, e.g. a PNG or EXR.
+        std::optional<ComponentBatch> blob;
+
+        /// The Media Type of the asset.
+        ///
+        /// Supported values:
+        /// * `image/png`
+        /// * `image/x-exr`
+        ///
+        /// If omitted, the viewer will try to guess from the data blob.
+        /// If it cannot guess, it won't be able to render the asset.
+        std::optional<ComponentBatch> media_type;
+
+        /// An optional floating point value that specifies how long a meter is in the native depth units.
+        ///
+        /// For instance: with a 16-bit PNG, perhaps meter=1000 which would mean you have millimeter precision
+        /// and a range of up to ~65 meters (2^16 / 1000).
+        ///
+        /// Note that the only effect on 2D views is the physical depth values shown when hovering the image.
+        /// In 3D views on the other hand, this affects where the points of the point cloud are placed.
+        std::optional<ComponentBatch> meter;
+
+        /// Colormap to use for rendering the depth image.
+        ///
+        /// If not set, the depth image will be rendered using the Turbo colormap.
+        std::optional<ComponentBatch> colormap;
+
+        /// The expected range of depth values.
+        ///
+        /// This is typically the expected range of valid values.
+        /// Everything outside of the range is clamped to the range for the purpose of colormpaping.
+        /// Note that point clouds generated from this image will still display all points, regardless of this range.
+        ///
+        /// If not specified, the range will be automatically estimated from the data.
+        std::optional<ComponentBatch> depth_range;
+
+        /// Scale the radii of the points in the point cloud generated from this image.
+        ///
+        /// A fill ratio of 1.0 (the default) means that each point is as big as to touch the center of its neighbor
+        /// if it is at the same depth, leaving no gaps.
+        /// A fill ratio of 0.5 means that each point touches the edge of its neighbor if it has the same depth.
+        ///
+        /// TODO(#6744): This applies only to 3D views!
+        std::optional<ComponentBatch> point_fill_ratio;
+
+        /// An optional floating point value that specifies the 2D drawing order, used only if the depth image is shown as a 2D image.
+        ///
+        /// Objects with higher values are drawn on top of those with lower values.
+        /// Defaults to `-20.0`.
+        std::optional<ComponentBatch> draw_order;
+
+      public:
+        static constexpr const char IndicatorComponentName[] =
+            "rerun.components.EncodedDepthImageIndicator";
+
+        /// Indicator component, used to identify the archetype when converting to a list of components.
+        using IndicatorComponent = rerun::components::IndicatorComponent<IndicatorComponentName>;
+        /// The name of the archetype as used in `ComponentDescriptor`s.
+        static constexpr const char ArchetypeName[] = "rerun.archetypes.EncodedDepthImage";
+
+        /// `ComponentDescriptor` for the `blob` field.
+        static constexpr auto Descriptor_blob = ComponentDescriptor(
+            ArchetypeName, "blob", Loggable<rerun::components::Blob>::ComponentName
+        );
+        /// `ComponentDescriptor` for the `media_type` field.
+        static constexpr auto Descriptor_media_type = ComponentDescriptor(
+            ArchetypeName, "media_type", Loggable<rerun::components::MediaType>::ComponentName
+        );
+        /// `ComponentDescriptor` for the `meter` field.
+        static constexpr auto Descriptor_meter = ComponentDescriptor(
+            ArchetypeName, "meter", Loggable<rerun::components::DepthMeter>::ComponentName
+        );
+        /// `ComponentDescriptor` for the `colormap` field.
+        static constexpr auto Descriptor_colormap = ComponentDescriptor(
+            ArchetypeName, "colormap", Loggable<rerun::components::Colormap>::ComponentName
+        );
+        /// `ComponentDescriptor` for the `depth_range` field.
+        static constexpr auto Descriptor_depth_range = ComponentDescriptor(
+            ArchetypeName, "depth_range", Loggable<rerun::components::ValueRange>::ComponentName
+        );
+        /// `ComponentDescriptor` for the `point_fill_ratio` field.
+        static constexpr auto Descriptor_point_fill_ratio = ComponentDescriptor(
+            ArchetypeName, "point_fill_ratio", Loggable<rerun::components::FillRatio>::ComponentName
+        );
+        /// `ComponentDescriptor` for the `draw_order` field.
+        static constexpr auto Descriptor_draw_order = ComponentDescriptor(
+            ArchetypeName, "draw_order", Loggable<rerun::components::DrawOrder>::ComponentName
+        );
+
+      public: // START of extensions from encoded_depth_image_ext.cpp:
+        /// Create a new `EncodedDepthImage` from a file on disk, e.g. a 16-bit PNG or an EXR.
+        static Result<EncodedDepthImage> from_file(const std::filesystem::path& filepath);
+
+        /// Create a new `EncodedDepthImage` from an encoded 16-bit PNG or EXR.
+        ///
+        /// If no `MediaType` is specified, the Rerun Viewer will try to guess one from the data
+        /// at render-time. If it can't, rendering will fail with an error.
+        static EncodedDepthImage from_bytes(
+            rerun::Collection<uint8_t> image_contents,
+            std::optional<rerun::components::MediaType> media_type = {}
+        ) {
+            auto encoded_depth_image = EncodedDepthImage().with_blob(image_contents);
+            if (media_type.has_value()) {
+                return std::move(encoded_depth_image).with_media_type(media_type.value());
+            }
+            return encoded_depth_image;
+        }
+
+        
+
+        // END of extensions from encoded_depth_image_ext.cpp, start of generated code:
+
+      public:
+        EncodedDepthImage() = default;
+        EncodedDepthImage(EncodedDepthImage&& other) = default;
+        EncodedDepthImage(const EncodedDepthImage& other) = default;
+        EncodedDepthImage& operator=(const EncodedDepthImage& other) = default;
+        EncodedDepthImage& operator=(EncodedDepthImage&& other) = default;
+
+        /// Update only some specific fields of a `EncodedDepthImage`.
+        static EncodedDepthImage update_fields() {
+            return EncodedDepthImage();
+        }
+
+        /// Clear all the fields of a `EncodedDepthImage`.
+        static EncodedDepthImage clear_fields();
+
+        /// The encoded content of some image file, e.g. a PNG or EXR.
+        EncodedDepthImage with_blob(const rerun::components::Blob& _blob) && {
+            blob = ComponentBatch::from_loggable(_blob, Descriptor_blob).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// This method makes it possible to pack multiple `blob` in a single component batch.
+        ///
+        /// This only makes sense when used in conjunction with `columns`. `with_blob` should
+        /// be used when logging a single row's worth of data.
+        EncodedDepthImage with_many_blob(const Collection<rerun::components::Blob>& _blob) && {
+            blob = ComponentBatch::from_loggable(_blob, Descriptor_blob).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// The Media Type of the asset.
+        ///
+        /// Supported values:
+        /// * `image/png`
+        /// * `image/x-exr`
+        ///
+        /// If omitted, the viewer will try to guess from the data blob.
+        /// If it cannot guess, it won't be able to render the asset.
+        EncodedDepthImage with_media_type(const rerun::components::MediaType& _media_type) && {
+            media_type =
+                ComponentBatch::from_loggable(_media_type, Descriptor_media_type).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// This method makes it possible to pack multiple `media_type` in a single component batch.
+        ///
+        /// This only makes sense when used in conjunction with `columns`. `with_media_type` should
+        /// be used when logging a single row's worth of data.
+        EncodedDepthImage with_many_media_type(
+            const Collection<rerun::components::MediaType>& _media_type
+        ) && {
+            media_type =
+                ComponentBatch::from_loggable(_media_type, Descriptor_media_type).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// An optional floating point value that specifies how long a meter is in the native depth units.
+        ///
+        /// For instance: with a 16-bit PNG, perhaps meter=1000 which would mean you have millimeter precision
+        /// and a range of up to ~65 meters (2^16 / 1000).
+        ///
+        /// Note that the only effect on 2D views is the physical depth values shown when hovering the image.
+        /// In 3D views on the other hand, this affects where the points of the point cloud are placed.
+        EncodedDepthImage with_meter(const rerun::components::DepthMeter& _meter) && {
+            meter = ComponentBatch::from_loggable(_meter, Descriptor_meter).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// This method makes it possible to pack multiple `meter` in a single component batch.
+        ///
+        /// This only makes sense when used in conjunction with `columns`. `with_meter` should
+        /// be used when logging a single row's worth of data.
+        EncodedDepthImage with_many_meter(
+            const Collection<rerun::components::DepthMeter>& _meter
+        ) && {
+            meter = ComponentBatch::from_loggable(_meter, Descriptor_meter).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Colormap to use for rendering the depth image.
+        ///
+        /// If not set, the depth image will be rendered using the Turbo colormap.
+        EncodedDepthImage with_colormap(const rerun::components::Colormap& _colormap) && {
+            colormap =
+                ComponentBatch::from_loggable(_colormap, Descriptor_colormap).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// This method makes it possible to pack multiple `colormap` in a single component batch.
+        ///
+        /// This only makes sense when used in conjunction with `columns`. `with_colormap` should
+        /// be used when logging a single row's worth of data.
+        EncodedDepthImage with_many_colormap(
+            const Collection<rerun::components::Colormap>& _colormap
+        ) && {
+            colormap =
+                ComponentBatch::from_loggable(_colormap, Descriptor_colormap).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// The expected range of depth values.
+        ///
+        /// This is typically the expected range of valid values.
+        /// Everything outside of the range is clamped to the range for the purpose of colormpaping.
+        /// Note that point clouds generated from this image will still display all points, regardless of this range.
+        ///
+        /// If not specified, the range will be automatically estimated from the data.
+        EncodedDepthImage with_depth_range(const rerun::components::ValueRange& _depth_range) && {
+            depth_range = ComponentBatch::from_loggable(_depth_range, Descriptor_depth_range)
+                              .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// This method makes it possible to pack multiple `depth_range` in a single component batch.
+        ///
+        /// This only makes sense when used in conjunction with `columns`. `with_depth_range` should
+        /// be used when logging a single row's worth of data.
+        EncodedDepthImage with_many_depth_range(
+            const Collection<rerun::components::ValueRange>& _depth_range
+        ) && {
+            depth_range = ComponentBatch::from_loggable(_depth_range, Descriptor_depth_range)
+                              .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Scale the radii of the points in the point cloud generated from this image.
+        ///
+        /// A fill ratio of 1.0 (the default) means that each point is as big as to touch the center of its neighbor
+        /// if it is at the same depth, leaving no gaps.
+        /// A fill ratio of 0.5 means that each point touches the edge of its neighbor if it has the same depth.
+        ///
+        /// TODO(#6744): This applies only to 3D views!
+        EncodedDepthImage with_point_fill_ratio(
+            const rerun::components::FillRatio& _point_fill_ratio
+        ) && {
+            point_fill_ratio =
+                ComponentBatch::from_loggable(_point_fill_ratio, Descriptor_point_fill_ratio)
+                    .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// This method makes it possible to pack multiple `point_fill_ratio` in a single component batch.
+        ///
+        /// This only makes sense when used in conjunction with `columns`. `with_point_fill_ratio` should
+        /// be used when logging a single row's worth of data.
+        EncodedDepthImage with_many_point_fill_ratio(
+            const Collection<rerun::components::FillRatio>& _point_fill_ratio
+        ) && {
+            point_fill_ratio =
+                ComponentBatch::from_loggable(_point_fill_ratio, Descriptor_point_fill_ratio)
+                    .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// An optional floating point value that specifies the 2D drawing order, used only if the depth image is shown as a 2D image.
+        ///
+        /// Objects with higher values are drawn on top of those with lower values.
+        /// Defaults to `-20.0`.
+        EncodedDepthImage with_draw_order(const rerun::components::DrawOrder& _draw_order) && {
+            draw_order =
+                ComponentBatch::from_loggable(_draw_order, Descriptor_draw_order).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// This method makes it possible to pack multiple `draw_order` in a single component batch.
+        ///
+        /// This only makes sense when used in conjunction with `columns`. `with_draw_order` should
+        /// be used when logging a single row's worth of data.
+        EncodedDepthImage with_many_draw_order(
+            const Collection<rerun::components::DrawOrder>& _draw_order
+        ) && {
+            draw_order =
+                ComponentBatch::from_loggable(_draw_order, Descriptor_draw_order).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Partitions the component data into multiple sub-batches.
+        ///
+        /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
+        /// instead, via `ComponentBatch::partitioned`.
+        ///
+        /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+        ///
+        /// The specified `lengths` must sum to the total length of the component batch.
+        Collection<ComponentColumn> columns(const Collection<uint32_t>& lengths_);
+
+        /// Partitions the component data into unit-length sub-batches.
+        ///
+        /// This is semantically similar to calling `columns` with `std::vector<uint32_t>(n, 1)`,
+        /// where `n` is automatically guessed.
+        Collection<ComponentColumn> columns();
+    };
+
+} // namespace rerun::archetypes
+
+namespace rerun {
+    /// \private
+    template <typename T>
+    struct AsComponents;
+
+    /// \private
+    template <>
+    struct AsComponents<archetypes::EncodedDepthImage> {
+        /// Serialize all set component batches.
+        static Result<Collection<ComponentBatch>> as_batches(
+            const archetypes::EncodedDepthImage& archetype
+        );
+    };
+} // namespace rerun
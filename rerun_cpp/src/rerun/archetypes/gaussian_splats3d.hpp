@@ -0,0 +1,186 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/archetypes/gaussian_splats3d.fbs".
+
+#pragma once
+
+#include "../collection.hpp"
+#include "../component_batch.hpp"
+#include "../component_column.hpp"
+#include "../components/color.hpp"
+#include "../components/opacity.hpp"
+#include "../components/position3d.hpp"
+#include "../components/rotation_quat.hpp"
+#include "../components/scale3d.hpp"
+#include "../indicator_component.hpp"
+#include "../result.hpp"
+
+#include <cstdint>
+#include <optional>
+#include <utility>
+#include <vector>
+
+namespace rerun::archetypes {
+    /// **Archetype**: A cloud of 3D Gaussian splats, as produced by Gaussian splatting reconstructions.
+    ///
+    /// Each splat is an anisotropic 3D Gaussian, defined by its center, the standard deviations
+    /// along its three local axes and the rotation of those axes.
+    /// Together, scales and rotations describe the covariance of the Gaussian.
+    ///
+    /// Splats are rendered as camera-facing quads, sorted back-to-front and alpha-blended.
+    /// Unlike `archetypes::Ellipsoids3D`, this scales to millions of instances.
+    ///
+    /// Spherical harmonics are not supported yet: only the view-independent base color is shown.
+    /// Loaders convert the zeroth-order coefficients to `components::Color`.
+    ///
+    /// ⚠ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    struct GaussianSplats3D {
+        /// The center of each splat.
+        std::optional<ComponentBatch> centers;
+
+        /// The standard deviation of each splat along its three local axes.
+        std::optional<ComponentBatch> scales;
+
+        /// The orientation of each splat's local axes.
+        ///
+        /// If not specified, the local axes align with the axes of the entity's coordinate system.
+        std::optional<ComponentBatch> quaternions;
+
+        /// The base color of each splat.
+        ///
+        /// The alpha channel is ignored, use `components::Opacity` instead.
+        std::optional<ComponentBatch> colors;
+
+        /// The opacity of each splat at its center, in the range `[0, 1]`.
+        ///
+        /// If not specified, splats are fully opaque at their centers.
+        std::optional<ComponentBatch> opacities;
+
+      public:
+        static constexpr const char IndicatorComponentName[] =
+            "rerun.components.GaussianSplats3DIndicator";
+
+        /// Indicator component, used to identify the archetype when converting to a list of components.
+        using IndicatorComponent = rerun::components::IndicatorComponent<IndicatorComponentName>;
+        /// The name of the archetype as used in `ComponentDescriptor`s.
+        static constexpr const char ArchetypeName[] = "rerun.archetypes.GaussianSplats3D";
+
+        /// `ComponentDescriptor` for the `centers` field.
+        static constexpr auto Descriptor_centers = ComponentDescriptor(
+            ArchetypeName, "centers", Loggable<rerun::components::Position3D>::ComponentName
+        );
+        /// `ComponentDescriptor` for the `scales` field.
+        static constexpr auto Descriptor_scales = ComponentDescriptor(
+            ArchetypeName, "scales", Loggable<rerun::components::Scale3D>::ComponentName
+        );
+        /// `ComponentDescriptor` for the `quaternions` field.
+        static constexpr auto Descriptor_quaternions = ComponentDescriptor(
+            ArchetypeName, "quaternions", Loggable<rerun::components::RotationQuat>::ComponentName
+        );
+        /// `ComponentDescriptor` for the `colors` field.
+        static constexpr auto Descriptor_colors = ComponentDescriptor(
+            ArchetypeName, "colors", Loggable<rerun::components::Color>::ComponentName
+        );
+        /// `ComponentDescriptor` for the `opacities` field.
+        static constexpr auto Descriptor_opacities = ComponentDescriptor(
+            ArchetypeName, "opacities", Loggable<rerun::components::Opacity>::ComponentName
+        );
+
+      public:
+        GaussianSplats3D() = default;
+        GaussianSplats3D(GaussianSplats3D&& other) = default;
+        GaussianSplats3D(const GaussianSplats3D& other) = default;
+        GaussianSplats3D& operator=(const GaussianSplats3D& other) = default;
+        GaussianSplats3D& operator=(GaussianSplats3D&& other) = default;
+
+        explicit GaussianSplats3D(
+            Collection<rerun::components::Position3D> _centers,
+            Collection<rerun::components::Scale3D> _scales
+        )
+            : centers(ComponentBatch::from_loggable(std::move(_centers), Descriptor_centers)
+                          .value_or_throw()),
+              scales(ComponentBatch::from_loggable(std::move(_scales), Descriptor_scales)
+                         .value_or_throw()) {}
+
+        /// Update only some specific fields of a `GaussianSplats3D`.
+        static GaussianSplats3D update_fields() {
+            return GaussianSplats3D();
+        }
+
+        /// Clear all the fields of a `GaussianSplats3D`.
+        static GaussianSplats3D clear_fields();
+
+        /// The center of each splat.
+        GaussianSplats3D with_centers(const Collection<rerun::components::Position3D>& _centers
+        ) && {
+            centers = ComponentBatch::from_loggable(_centers, Descriptor_centers).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// The standard deviation of each splat along its three local axes.
+        GaussianSplats3D with_scales(const Collection<rerun::components::Scale3D>& _scales) && {
+            scales = ComponentBatch::from_loggable(_scales, Descriptor_scales).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// The orientation of each splat's local axes.
+        ///
+        /// If not specified, the local axes align with the axes of the entity's coordinate system.
+        GaussianSplats3D with_quaternions(
+            const Collection<rerun::components::RotationQuat>& _quaternions
+        ) && {
+            quaternions =
+                ComponentBatch::from_loggable(_quaternions, Descriptor_quaternions).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// The base color of each splat.
+        ///
+        /// The alpha channel is ignored, use `components::Opacity` instead.
+        GaussianSplats3D with_colors(const Collection<rerun::components::Color>& _colors) && {
+            colors = ComponentBatch::from_loggable(_colors, Descriptor_colors).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// The opacity of each splat at its center, in the range `[0, 1]`.
+        ///
+        /// If not specified, splats are fully opaque at their centers.
+        GaussianSplats3D with_opacities(const Collection<rerun::components::Opacity>& _opacities
+        ) && {
+            opacities =
+                ComponentBatch::from_loggable(_opacities, Descriptor_opacities).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Partitions the component data into multiple sub-batches.
+        ///
+        /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
+        /// instead, via `ComponentBatch::partitioned`.
+        ///
+        /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+        ///
+        /// The specified `lengths` must sum to the total length of the component batch.
+        Collection<ComponentColumn> columns(const Collection<uint32_t>& lengths_);
+
+        /// Partitions the component data into unit-length sub-batches.
+        ///
+        /// This is semantically similar to calling `columns` with `std::vector<uint32_t>(n, 1)`,
+        /// where `n` is automatically guessed.
+        Collection<ComponentColumn> columns();
+    };
+
+} // namespace rerun::archetypes
+
+namespace rerun {
+    /// \private
+    template <typename T>
+    struct AsComponents;
+
+    /// \private
+    template <>
+    struct AsComponents<archetypes::GaussianSplats3D> {
+        /// Serialize all set component batches.
+        static Result<Collection<ComponentBatch>> as_batches(
+            const archetypes::GaussianSplats3D& archetype
+        );
+    };
+} // namespace rerun
@@ -0,0 +1,92 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/archetypes/geo_polygons.fbs".
+
+#include "geo_polygons.hpp"
+
+#include "../collection_adapter_builtins.hpp"
+
+namespace rerun::archetypes {
+    GeoPolygons GeoPolygons::clear_fields() {
+        auto archetype = GeoPolygons();
+        archetype.polygons =
+            ComponentBatch::empty<rerun::components::GeoPolygon>(Descriptor_polygons)
+                .value_or_throw();
+        archetype.radii =
+            ComponentBatch::empty<rerun::components::Radius>(Descriptor_radii).value_or_throw();
+        archetype.colors =
+            ComponentBatch::empty<rerun::components::Color>(Descriptor_colors).value_or_throw();
+        archetype.fill_colors =
+            ComponentBatch::empty<rerun::components::Color>(Descriptor_fill_colors)
+                .value_or_throw();
+        return archetype;
+    }
+
+    Collection<ComponentColumn> GeoPolygons::columns(const Collection<uint32_t>& lengths_) {
+        std::vector<ComponentColumn> columns;
+        columns.reserve(5);
+        if (polygons.has_value()) {
+            columns.push_back(polygons.value().partitioned(lengths_).value_or_throw());
+        }
+        if (radii.has_value()) {
+            columns.push_back(radii.value().partitioned(lengths_).value_or_throw());
+        }
+        if (colors.has_value()) {
+            columns.push_back(colors.value().partitioned(lengths_).value_or_throw());
+        }
+        if (fill_colors.has_value()) {
+            columns.push_back(fill_colors.value().partitioned(lengths_).value_or_throw());
+        }
+        columns.push_back(
+            ComponentColumn::from_indicators<GeoPolygons>(static_cast<uint32_t>(lengths_.size()))
+                .value_or_throw()
+        );
+        return columns;
+    }
+
+    Collection<ComponentColumn> GeoPolygons::columns() {
+        if (polygons.has_value()) {
+            return columns(std::vector<uint32_t>(polygons.value().length(), 1));
+        }
+        if (radii.has_value()) {
+            return columns(std::vector<uint32_t>(radii.value().length(), 1));
+        }
+        if (colors.has_value()) {
+            return columns(std::vector<uint32_t>(colors.value().length(), 1));
+        }
+        if (fill_colors.has_value()) {
+            return columns(std::vector<uint32_t>(fill_colors.value().length(), 1));
+        }
+        return Collection<ComponentColumn>();
+    }
+} // namespace rerun::archetypes
+
+namespace rerun {
+
+    Result<Collection<ComponentBatch>> AsComponents<archetypes::GeoPolygons>::as_batches(
+        const archetypes::GeoPolygons& archetype
+    ) {
+        using namespace archetypes;
+        std::vector<ComponentBatch> cells;
+        cells.reserve(5);
+
+        if (archetype.polygons.has_value()) {
+            cells.push_back(archetype.polygons.value());
+        }
+        if (archetype.radii.has_value()) {
+            cells.push_back(archetype.radii.value());
+        }
+        if (archetype.colors.has_value()) {
+            cells.push_back(archetype.colors.value());
+        }
+        if (archetype.fill_colors.has_value()) {
+            cells.push_back(archetype.fill_colors.value());
+        }
+        {
+            auto result = ComponentBatch::from_indicator<GeoPolygons>();
+            RR_RETURN_NOT_OK(result.error);
+            cells.emplace_back(std::move(result.value));
+        }
+
+        return rerun::take_ownership(std::move(cells));
+    }
+} // namespace rerun
@@ -0,0 +1,152 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/archetypes/geo_polygons.fbs".
+
+#pragma once
+
+#include "../collection.hpp"
+#include "../component_batch.hpp"
+#include "../component_column.hpp"
+#include "../components/color.hpp"
+#include "../components/geo_polygon.hpp"
+#include "../components/radius.hpp"
+#include "../indicator_component.hpp"
+#include "../result.hpp"
+
+#include <cstdint>
+#include <optional>
+#include <utility>
+#include <vector>
+
+namespace rerun::archetypes {
+    /// **Archetype**: Geospatial polygons with positions expressed in [EPSG:4326](https://epsg.io/4326) altitude and longitude (North/East-positive degrees), and optional fill and outline colors.
+    ///
+    /// Useful for geofences, coverage areas and other regions on a map.
+    ///
+    /// ⚠ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    struct GeoPolygons {
+        /// The polygons, expressed in [EPSG:4326](https://epsg.io/4326) coordinates (North/East-positive degrees).
+        std::optional<ComponentBatch> polygons;
+
+        /// Optional radii for the outlines of the polygons.
+        ///
+        /// *Note*: scene units radiii are interpreted as meters. Currently, the display scale only considers the latitude of
+        /// the first vertex of each polygon (see [this issue](https://github.com/rerun-io/rerun/issues/8013)).
+        std::optional<ComponentBatch> radii;
+
+        /// Optional colors for the outlines of the polygons.
+        std::optional<ComponentBatch> colors;
+
+        /// Optional colors for the area enclosed by the polygons.
+        ///
+        /// If not specified, the outline color is used with reduced opacity.
+        std::optional<ComponentBatch> fill_colors;
+
+      public:
+        static constexpr const char IndicatorComponentName[] =
+            "rerun.components.GeoPolygonsIndicator";
+
+        /// Indicator component, used to identify the archetype when converting to a list of components.
+        using IndicatorComponent = rerun::components::IndicatorComponent<IndicatorComponentName>;
+        /// The name of the archetype as used in `ComponentDescriptor`s.
+        static constexpr const char ArchetypeName[] = "rerun.archetypes.GeoPolygons";
+
+        /// `ComponentDescriptor` for the `polygons` field.
+        static constexpr auto Descriptor_polygons = ComponentDescriptor(
+            ArchetypeName, "polygons", Loggable<rerun::components::GeoPolygon>::ComponentName
+        );
+        /// `ComponentDescriptor` for the `radii` field.
+        static constexpr auto Descriptor_radii = ComponentDescriptor(
+            ArchetypeName, "radii", Loggable<rerun::components::Radius>::ComponentName
+        );
+        /// `ComponentDescriptor` for the `colors` field.
+        static constexpr auto Descriptor_colors = ComponentDescriptor(
+            ArchetypeName, "colors", Loggable<rerun::components::Color>::ComponentName
+        );
+        /// `ComponentDescriptor` for the `fill_colors` field.
+        static constexpr auto Descriptor_fill_colors = ComponentDescriptor(
+            ArchetypeName, "fill_colors", Loggable<rerun::components::Color>::ComponentName
+        );
+
+      public:
+        GeoPolygons() = default;
+        GeoPolygons(GeoPolygons&& other) = default;
+        GeoPolygons(const GeoPolygons& other) = default;
+        GeoPolygons& operator=(const GeoPolygons& other) = default;
+        GeoPolygons& operator=(GeoPolygons&& other) = default;
+
+        explicit GeoPolygons(Collection<rerun::components::GeoPolygon> _polygons)
+            : polygons(ComponentBatch::from_loggable(std::move(_polygons), Descriptor_polygons)
+                           .value_or_throw()) {}
+
+        /// Update only some specific fields of a `GeoPolygons`.
+        static GeoPolygons update_fields() {
+            return GeoPolygons();
+        }
+
+        /// Clear all the fields of a `GeoPolygons`.
+        static GeoPolygons clear_fields();
+
+        /// The polygons, expressed in [EPSG:4326](https://epsg.io/4326) coordinates (North/East-positive degrees).
+        GeoPolygons with_polygons(const Collection<rerun::components::GeoPolygon>& _polygons) && {
+            polygons =
+                ComponentBatch::from_loggable(_polygons, Descriptor_polygons).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Optional radii for the outlines of the polygons.
+        ///
+        /// *Note*: scene units radiii are interpreted as meters. Currently, the display scale only considers the latitude of
+        /// the first vertex of each polygon (see [this issue](https://github.com/rerun-io/rerun/issues/8013)).
+        GeoPolygons with_radii(const Collection<rerun::components::Radius>& _radii) && {
+            radii = ComponentBatch::from_loggable(_radii, Descriptor_radii).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Optional colors for the outlines of the polygons.
+        GeoPolygons with_colors(const Collection<rerun::components::Color>& _colors) && {
+            colors = ComponentBatch::from_loggable(_colors, Descriptor_colors).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Optional colors for the area enclosed by the polygons.
+        ///
+        /// If not specified, the outline color is used with reduced opacity.
+        GeoPolygons with_fill_colors(const Collection<rerun::components::Color>& _fill_colors) && {
+            fill_colors = ComponentBatch::from_loggable(_fill_colors, Descriptor_fill_colors)
+                              .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Partitions the component data into multiple sub-batches.
+        ///
+        /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
+        /// instead, via `ComponentBatch::partitioned`.
+        ///
+        /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+        ///
+        /// The specified `lengths` must sum to the total length of the component batch.
+        Collection<ComponentColumn> columns(const Collection<uint32_t>& lengths_);
+
+        /// Partitions the component data into unit-length sub-batches.
+        ///
+        /// This is semantically similar to calling `columns` with `std::vector<uint32_t>(n, 1)`,
+        /// where `n` is automatically guessed.
+        Collection<ComponentColumn> columns();
+    };
+
+} // namespace rerun::archetypes
+
+namespace rerun {
+    /// \private
+    template <typename T>
+    struct AsComponents;
+
+    /// \private
+    template <>
+    struct AsComponents<archetypes::GeoPolygons> {
+        /// Serialize all set component batches.
+        static Result<Collection<ComponentBatch>> as_batches(
+            const archetypes::GeoPolygons& archetype
+        );
+    };
+} // namespace rerun
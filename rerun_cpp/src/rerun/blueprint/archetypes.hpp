@@ -3,6 +3,7 @@
 #pragma once
 
 #include "blueprint/archetypes/background.hpp"
+#include "blueprint/archetypes/camera_path.hpp"
 #include "blueprint/archetypes/container_blueprint.hpp"
 #include "blueprint/archetypes/dataframe_query.hpp"
 #include "blueprint/archetypes/entity_behavior.hpp"
@@ -11,20 +12,26 @@
 #include "blueprint/archetypes/force_link.hpp"
 #include "blueprint/archetypes/force_many_body.hpp"
 #include "blueprint/archetypes/force_position.hpp"
+#include "blueprint/archetypes/histogram_bins.hpp"
 #include "blueprint/archetypes/line_grid3d.hpp"
 #include "blueprint/archetypes/map_background.hpp"
+#include "blueprint/archetypes/map_follow.hpp"
 #include "blueprint/archetypes/map_zoom.hpp"
 #include "blueprint/archetypes/near_clip_plane.hpp"
 #include "blueprint/archetypes/panel_blueprint.hpp"
 #include "blueprint/archetypes/plot_legend.hpp"
 #include "blueprint/archetypes/scalar_axis.hpp"
+#include "blueprint/archetypes/spectrogram_window.hpp"
 #include "blueprint/archetypes/tensor_scalar_mapping.hpp"
 #include "blueprint/archetypes/tensor_slice_selection.hpp"
 #include "blueprint/archetypes/tensor_view_fit.hpp"
+#include "blueprint/archetypes/tensor_volume.hpp"
 #include "blueprint/archetypes/time_axis.hpp"
+#include "blueprint/archetypes/timeline_bookmarks.hpp"
 #include "blueprint/archetypes/view_blueprint.hpp"
 #include "blueprint/archetypes/view_contents.hpp"
 #include "blueprint/archetypes/viewport_blueprint.hpp"
 #include "blueprint/archetypes/visible_time_ranges.hpp"
 #include "blueprint/archetypes/visual_bounds2d.hpp"
 #include "blueprint/archetypes/visualizer_overrides.hpp"
+#include "blueprint/archetypes/x_axis.hpp"
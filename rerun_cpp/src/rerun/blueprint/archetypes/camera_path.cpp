@@ -0,0 +1,118 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/camera_path.fbs".
+
+#include "camera_path.hpp"
+
+#include "../../collection_adapter_builtins.hpp"
+
+namespace rerun::blueprint::archetypes {
+    CameraPath CameraPath::clear_fields() {
+        auto archetype = CameraPath();
+        archetype.enabled =
+            ComponentBatch::empty<rerun::blueprint::components::Enabled>(Descriptor_enabled)
+                .value_or_throw();
+        archetype.timeline =
+            ComponentBatch::empty<rerun::blueprint::components::TimelineName>(Descriptor_timeline)
+                .value_or_throw();
+        archetype.times =
+            ComponentBatch::empty<rerun::blueprint::components::KeyframeTime>(Descriptor_times)
+                .value_or_throw();
+        archetype.positions =
+            ComponentBatch::empty<rerun::components::Position3D>(Descriptor_positions)
+                .value_or_throw();
+        archetype.look_targets =
+            ComponentBatch::empty<rerun::components::Position3D>(Descriptor_look_targets)
+                .value_or_throw();
+        archetype.chase_entity =
+            ComponentBatch::empty<rerun::components::EntityPath>(Descriptor_chase_entity)
+                .value_or_throw();
+        return archetype;
+    }
+
+    Collection<ComponentColumn> CameraPath::columns(const Collection<uint32_t>& lengths_) {
+        std::vector<ComponentColumn> columns;
+        columns.reserve(7);
+        if (enabled.has_value()) {
+            columns.push_back(enabled.value().partitioned(lengths_).value_or_throw());
+        }
+        if (timeline.has_value()) {
+            columns.push_back(timeline.value().partitioned(lengths_).value_or_throw());
+        }
+        if (times.has_value()) {
+            columns.push_back(times.value().partitioned(lengths_).value_or_throw());
+        }
+        if (positions.has_value()) {
+            columns.push_back(positions.value().partitioned(lengths_).value_or_throw());
+        }
+        if (look_targets.has_value()) {
+            columns.push_back(look_targets.value().partitioned(lengths_).value_or_throw());
+        }
+        if (chase_entity.has_value()) {
+            columns.push_back(chase_entity.value().partitioned(lengths_).value_or_throw());
+        }
+        columns.push_back(
+            ComponentColumn::from_indicators<CameraPath>(static_cast<uint32_t>(lengths_.size()))
+                .value_or_throw()
+        );
+        return columns;
+    }
+
+    Collection<ComponentColumn> CameraPath::columns() {
+        if (enabled.has_value()) {
+            return columns(std::vector<uint32_t>(enabled.value().length(), 1));
+        }
+        if (timeline.has_value()) {
+            return columns(std::vector<uint32_t>(timeline.value().length(), 1));
+        }
+        if (times.has_value()) {
+            return columns(std::vector<uint32_t>(times.value().length(), 1));
+        }
+        if (positions.has_value()) {
+            return columns(std::vector<uint32_t>(positions.value().length(), 1));
+        }
+        if (look_targets.has_value()) {
+            return columns(std::vector<uint32_t>(look_targets.value().length(), 1));
+        }
+        if (chase_entity.has_value()) {
+            return columns(std::vector<uint32_t>(chase_entity.value().length(), 1));
+        }
+        return Collection<ComponentColumn>();
+    }
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+
+    Result<Collection<ComponentBatch>> AsComponents<blueprint::archetypes::CameraPath>::as_batches(
+        const blueprint::archetypes::CameraPath& archetype
+    ) {
+        using namespace blueprint::archetypes;
+        std::vector<ComponentBatch> cells;
+        cells.reserve(7);
+
+        if (archetype.enabled.has_value()) {
+            cells.push_back(archetype.enabled.value());
+        }
+        if (archetype.timeline.has_value()) {
+            cells.push_back(archetype.timeline.value());
+        }
+        if (archetype.times.has_value()) {
+            cells.push_back(archetype.times.value());
+        }
+        if (archetype.positions.has_value()) {
+            cells.push_back(archetype.positions.value());
+        }
+        if (archetype.look_targets.has_value()) {
+            cells.push_back(archetype.look_targets.value());
+        }
+        if (archetype.chase_entity.has_value()) {
+            cells.push_back(archetype.chase_entity.value());
+        }
+        {
+            auto result = ComponentBatch::from_indicator<CameraPath>();
+            RR_RETURN_NOT_OK(result.error);
+            cells.emplace_back(std::move(result.value));
+        }
+
+        return rerun::take_ownership(std::move(cells));
+    }
+} // namespace rerun
@@ -0,0 +1,185 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/camera_path.fbs".
+
+#pragma once
+
+#include "../../blueprint/components/enabled.hpp"
+#include "../../blueprint/components/keyframe_time.hpp"
+#include "../../blueprint/components/timeline_name.hpp"
+#include "../../collection.hpp"
+#include "../../component_batch.hpp"
+#include "../../component_column.hpp"
+#include "../../components/entity_path.hpp"
+#include "../../components/position3d.hpp"
+#include "../../indicator_component.hpp"
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <optional>
+#include <utility>
+#include <vector>
+
+namespace rerun::blueprint::archetypes {
+    /// **Archetype**: A keyframed path for the eye of a 3D view.
+    ///
+    /// When enabled, the eye is interpolated between the keyframes as the time cursor moves along
+    /// the timeline, both when scrubbing and during playback.
+    /// Before the first and after the last keyframe the eye holds still.
+    ///
+    /// The n-th keyframe is at `times[n]`, where the eye is at `positions[n]` looking at `look_targets[n]`.
+    ///
+    /// ⚠ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    ///
+    struct CameraPath {
+        /// Whether the eye of the view follows the path. Defaults to true.
+        std::optional<ComponentBatch> enabled;
+
+        /// The timeline along which the keyframes are placed.
+        std::optional<ComponentBatch> timeline;
+
+        /// The time of each keyframe.
+        std::optional<ComponentBatch> times;
+
+        /// Where the eye is at each keyframe.
+        std::optional<ComponentBatch> positions;
+
+        /// What the eye looks at, at each keyframe.
+        std::optional<ComponentBatch> look_targets;
+
+        /// An entity the path is attached to, e.g. a vehicle followed by a chase cam.
+        ///
+        /// If set, positions and look targets are in the space of this entity rather than that of the view.
+        std::optional<ComponentBatch> chase_entity;
+
+      public:
+        static constexpr const char IndicatorComponentName[] =
+            "rerun.blueprint.components.CameraPathIndicator";
+
+        /// Indicator component, used to identify the archetype when converting to a list of components.
+        using IndicatorComponent = rerun::components::IndicatorComponent<IndicatorComponentName>;
+        /// The name of the archetype as used in `ComponentDescriptor`s.
+        static constexpr const char ArchetypeName[] = "rerun.blueprint.archetypes.CameraPath";
+
+        /// `ComponentDescriptor` for the `enabled` field.
+        static constexpr auto Descriptor_enabled = ComponentDescriptor(
+            ArchetypeName, "enabled", Loggable<rerun::blueprint::components::Enabled>::ComponentName
+        );
+        /// `ComponentDescriptor` for the `timeline` field.
+        static constexpr auto Descriptor_timeline = ComponentDescriptor(
+            ArchetypeName, "timeline",
+            Loggable<rerun::blueprint::components::TimelineName>::ComponentName
+        );
+        /// `ComponentDescriptor` for the `times` field.
+        static constexpr auto Descriptor_times = ComponentDescriptor(
+            ArchetypeName, "times",
+            Loggable<rerun::blueprint::components::KeyframeTime>::ComponentName
+        );
+        /// `ComponentDescriptor` for the `positions` field.
+        static constexpr auto Descriptor_positions = ComponentDescriptor(
+            ArchetypeName, "positions", Loggable<rerun::components::Position3D>::ComponentName
+        );
+        /// `ComponentDescriptor` for the `look_targets` field.
+        static constexpr auto Descriptor_look_targets = ComponentDescriptor(
+            ArchetypeName, "look_targets", Loggable<rerun::components::Position3D>::ComponentName
+        );
+        /// `ComponentDescriptor` for the `chase_entity` field.
+        static constexpr auto Descriptor_chase_entity = ComponentDescriptor(
+            ArchetypeName, "chase_entity", Loggable<rerun::components::EntityPath>::ComponentName
+        );
+
+      public:
+        CameraPath() = default;
+        CameraPath(CameraPath&& other) = default;
+        CameraPath(const CameraPath& other) = default;
+        CameraPath& operator=(const CameraPath& other) = default;
+        CameraPath& operator=(CameraPath&& other) = default;
+
+        /// Update only some specific fields of a `CameraPath`.
+        static CameraPath update_fields() {
+            return CameraPath();
+        }
+
+        /// Clear all the fields of a `CameraPath`.
+        static CameraPath clear_fields();
+
+        /// Whether the eye of the view follows the path. Defaults to true.
+        CameraPath with_enabled(const rerun::blueprint::components::Enabled& _enabled) && {
+            enabled = ComponentBatch::from_loggable(_enabled, Descriptor_enabled).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// The timeline along which the keyframes are placed.
+        CameraPath with_timeline(const rerun::blueprint::components::TimelineName& _timeline) && {
+            timeline =
+                ComponentBatch::from_loggable(_timeline, Descriptor_timeline).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// The time of each keyframe.
+        CameraPath with_times(
+            const Collection<rerun::blueprint::components::KeyframeTime>& _times
+        ) && {
+            times = ComponentBatch::from_loggable(_times, Descriptor_times).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Where the eye is at each keyframe.
+        CameraPath with_positions(const Collection<rerun::components::Position3D>& _positions) && {
+            positions =
+                ComponentBatch::from_loggable(_positions, Descriptor_positions).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// What the eye looks at, at each keyframe.
+        CameraPath with_look_targets(
+            const Collection<rerun::components::Position3D>& _look_targets
+        ) && {
+            look_targets =
+                ComponentBatch::from_loggable(_look_targets, Descriptor_look_targets)
+                    .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// An entity the path is attached to, e.g. a vehicle followed by a chase cam.
+        ///
+        /// If set, positions and look targets are in the space of this entity rather than that of the view.
+        CameraPath with_chase_entity(const rerun::components::EntityPath& _chase_entity) && {
+            chase_entity =
+                ComponentBatch::from_loggable(_chase_entity, Descriptor_chase_entity)
+                    .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Partitions the component data into multiple sub-batches.
+        ///
+        /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
+        /// instead, via `ComponentBatch::partitioned`.
+        ///
+        /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+        ///
+        /// The specified `lengths` must sum to the total length of the component batch.
+        Collection<ComponentColumn> columns(const Collection<uint32_t>& lengths_);
+
+        /// Partitions the component data into unit-length sub-batches.
+        ///
+        /// This is semantically similar to calling `columns` with `std::vector<uint32_t>(n, 1)`,
+        /// where `n` is automatically guessed.
+        Collection<ComponentColumn> columns();
+    };
+
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+    /// \private
+    template <typename T>
+    struct AsComponents;
+
+    /// \private
+    template <>
+    struct AsComponents<blueprint::archetypes::CameraPath> {
+        /// Serialize all set component batches.
+        static Result<Collection<ComponentBatch>> as_batches(
+            const blueprint::archetypes::CameraPath& archetype
+        );
+    };
+} // namespace rerun
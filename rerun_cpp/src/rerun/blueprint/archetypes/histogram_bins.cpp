@@ -0,0 +1,82 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/histogram_bins.fbs".
+
+#include "histogram_bins.hpp"
+
+#include "../../collection_adapter_builtins.hpp"
+
+namespace rerun::blueprint::archetypes {
+    HistogramBins HistogramBins::clear_fields() {
+        auto archetype = HistogramBins();
+        archetype.bin_count =
+            ComponentBatch::empty<rerun::blueprint::components::BinCount>(Descriptor_bin_count)
+                .value_or_throw();
+        archetype.range =
+            ComponentBatch::empty<rerun::components::Range1D>(Descriptor_range).value_or_throw();
+        archetype.log_scale =
+            ComponentBatch::empty<rerun::blueprint::components::Enabled>(Descriptor_log_scale)
+                .value_or_throw();
+        return archetype;
+    }
+
+    Collection<ComponentColumn> HistogramBins::columns(const Collection<uint32_t>& lengths_) {
+        std::vector<ComponentColumn> columns;
+        columns.reserve(4);
+        if (bin_count.has_value()) {
+            columns.push_back(bin_count.value().partitioned(lengths_).value_or_throw());
+        }
+        if (range.has_value()) {
+            columns.push_back(range.value().partitioned(lengths_).value_or_throw());
+        }
+        if (log_scale.has_value()) {
+            columns.push_back(log_scale.value().partitioned(lengths_).value_or_throw());
+        }
+        columns.push_back(
+            ComponentColumn::from_indicators<HistogramBins>(static_cast<uint32_t>(lengths_.size()))
+                .value_or_throw()
+        );
+        return columns;
+    }
+
+    Collection<ComponentColumn> HistogramBins::columns() {
+        if (bin_count.has_value()) {
+            return columns(std::vector<uint32_t>(bin_count.value().length(), 1));
+        }
+        if (range.has_value()) {
+            return columns(std::vector<uint32_t>(range.value().length(), 1));
+        }
+        if (log_scale.has_value()) {
+            return columns(std::vector<uint32_t>(log_scale.value().length(), 1));
+        }
+        return Collection<ComponentColumn>();
+    }
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+
+    Result<Collection<ComponentBatch>>
+        AsComponents<blueprint::archetypes::HistogramBins>::as_batches(
+            const blueprint::archetypes::HistogramBins& archetype
+        ) {
+        using namespace blueprint::archetypes;
+        std::vector<ComponentBatch> cells;
+        cells.reserve(4);
+
+        if (archetype.bin_count.has_value()) {
+            cells.push_back(archetype.bin_count.value());
+        }
+        if (archetype.range.has_value()) {
+            cells.push_back(archetype.range.value());
+        }
+        if (archetype.log_scale.has_value()) {
+            cells.push_back(archetype.log_scale.value());
+        }
+        {
+            auto result = ComponentBatch::from_indicator<HistogramBins>();
+            RR_RETURN_NOT_OK(result.error);
+            cells.emplace_back(std::move(result.value));
+        }
+
+        return rerun::take_ownership(std::move(cells));
+    }
+} // namespace rerun
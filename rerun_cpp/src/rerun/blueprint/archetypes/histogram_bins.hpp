@@ -0,0 +1,140 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/histogram_bins.fbs".
+
+#pragma once
+
+#include "../../blueprint/components/bin_count.hpp"
+#include "../../blueprint/components/enabled.hpp"
+#include "../../collection.hpp"
+#include "../../component_batch.hpp"
+#include "../../component_column.hpp"
+#include "../../components/range1d.hpp"
+#include "../../indicator_component.hpp"
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <optional>
+#include <utility>
+#include <vector>
+
+namespace rerun::blueprint::archetypes {
+    /// **Archetype**: Configuration for how a histogram sorts values into bins, and how it shows their counts.
+    ///
+    /// ⚠ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    ///
+    struct HistogramBins {
+        /// How many bins the value range is divided into.
+        ///
+        /// Defaults to 64.
+        std::optional<ComponentBatch> bin_count;
+
+        /// The range of values that is divided into bins.
+        ///
+        /// Values outside of it aren't counted.
+        /// If unset, the range spans all values shown in the view.
+        std::optional<ComponentBatch> range;
+
+        /// Whether the bin counts are shown on a logarithmic scale.
+        ///
+        /// Defaults to false.
+        std::optional<ComponentBatch> log_scale;
+
+      public:
+        static constexpr const char IndicatorComponentName[] =
+            "rerun.blueprint.components.HistogramBinsIndicator";
+
+        /// Indicator component, used to identify the archetype when converting to a list of components.
+        using IndicatorComponent = rerun::components::IndicatorComponent<IndicatorComponentName>;
+        /// The name of the archetype as used in `ComponentDescriptor`s.
+        static constexpr const char ArchetypeName[] = "rerun.blueprint.archetypes.HistogramBins";
+
+        /// `ComponentDescriptor` for the `bin_count` field.
+        static constexpr auto Descriptor_bin_count = ComponentDescriptor(
+            ArchetypeName, "bin_count",
+            Loggable<rerun::blueprint::components::BinCount>::ComponentName
+        );
+        /// `ComponentDescriptor` for the `range` field.
+        static constexpr auto Descriptor_range = ComponentDescriptor(
+            ArchetypeName, "range", Loggable<rerun::components::Range1D>::ComponentName
+        );
+        /// `ComponentDescriptor` for the `log_scale` field.
+        static constexpr auto Descriptor_log_scale = ComponentDescriptor(
+            ArchetypeName, "log_scale",
+            Loggable<rerun::blueprint::components::Enabled>::ComponentName
+        );
+
+      public:
+        HistogramBins() = default;
+        HistogramBins(HistogramBins&& other) = default;
+        HistogramBins(const HistogramBins& other) = default;
+        HistogramBins& operator=(const HistogramBins& other) = default;
+        HistogramBins& operator=(HistogramBins&& other) = default;
+
+        /// Update only some specific fields of a `HistogramBins`.
+        static HistogramBins update_fields() {
+            return HistogramBins();
+        }
+
+        /// Clear all the fields of a `HistogramBins`.
+        static HistogramBins clear_fields();
+
+        /// How many bins the value range is divided into.
+        ///
+        /// Defaults to 64.
+        HistogramBins with_bin_count(const rerun::blueprint::components::BinCount& _bin_count) && {
+            bin_count =
+                ComponentBatch::from_loggable(_bin_count, Descriptor_bin_count).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// The range of values that is divided into bins.
+        ///
+        /// Values outside of it aren't counted.
+        /// If unset, the range spans all values shown in the view.
+        HistogramBins with_range(const rerun::components::Range1D& _range) && {
+            range = ComponentBatch::from_loggable(_range, Descriptor_range).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Whether the bin counts are shown on a logarithmic scale.
+        ///
+        /// Defaults to false.
+        HistogramBins with_log_scale(const rerun::blueprint::components::Enabled& _log_scale) && {
+            log_scale =
+                ComponentBatch::from_loggable(_log_scale, Descriptor_log_scale).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Partitions the component data into multiple sub-batches.
+        ///
+        /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
+        /// instead, via `ComponentBatch::partitioned`.
+        ///
+        /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+        ///
+        /// The specified `lengths` must sum to the total length of the component batch.
+        Collection<ComponentColumn> columns(const Collection<uint32_t>& lengths_);
+
+        /// Partitions the component data into unit-length sub-batches.
+        ///
+        /// This is semantically similar to calling `columns` with `std::vector<uint32_t>(n, 1)`,
+        /// where `n` is automatically guessed.
+        Collection<ComponentColumn> columns();
+    };
+
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+    /// \private
+    template <typename T>
+    struct AsComponents;
+
+    /// \private
+    template <>
+    struct AsComponents<blueprint::archetypes::HistogramBins> {
+        /// Serialize all set component batches.
+        static Result<Collection<ComponentBatch>> as_batches(
+            const blueprint::archetypes::HistogramBins& archetype
+        );
+    };
+} // namespace rerun
@@ -11,15 +11,22 @@ namespace rerun::blueprint::archetypes {
         archetype.provider =
             ComponentBatch::empty<rerun::blueprint::components::MapProvider>(Descriptor_provider)
                 .value_or_throw();
+        archetype.tile_source = ComponentBatch::empty<rerun::blueprint::components::MapTileSource>(
+                                    Descriptor_tile_source
+        )
+                                    .value_or_throw();
         return archetype;
     }
 
     Collection<ComponentColumn> MapBackground::columns(const Collection<uint32_t>& lengths_) {
         std::vector<ComponentColumn> columns;
-        columns.reserve(2);
+        columns.reserve(3);
         if (provider.has_value()) {
             columns.push_back(provider.value().partitioned(lengths_).value_or_throw());
         }
+        if (tile_source.has_value()) {
+            columns.push_back(tile_source.value().partitioned(lengths_).value_or_throw());
+        }
         columns.push_back(
             ComponentColumn::from_indicators<MapBackground>(static_cast<uint32_t>(lengths_.size()))
                 .value_or_throw()
@@ -31,6 +38,9 @@ namespace rerun::blueprint::archetypes {
         if (provider.has_value()) {
             return columns(std::vector<uint32_t>(provider.value().length(), 1));
         }
+        if (tile_source.has_value()) {
+            return columns(std::vector<uint32_t>(tile_source.value().length(), 1));
+        }
         return Collection<ComponentColumn>();
     }
 } // namespace rerun::blueprint::archetypes
@@ -43,11 +53,14 @@ namespace rerun {
         ) {
         using namespace blueprint::archetypes;
         std::vector<ComponentBatch> cells;
-        cells.reserve(2);
+        cells.reserve(3);
 
         if (archetype.provider.has_value()) {
             cells.push_back(archetype.provider.value());
         }
+        if (archetype.tile_source.has_value()) {
+            cells.push_back(archetype.tile_source.value());
+        }
         {
             auto result = ComponentBatch::from_indicator<MapBackground>();
             RR_RETURN_NOT_OK(result.error);
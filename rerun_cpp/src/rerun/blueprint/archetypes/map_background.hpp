@@ -4,6 +4,7 @@
 #pragma once
 
 #include "../../blueprint/components/map_provider.hpp"
+#include "../../blueprint/components/map_tile_source.hpp"
 #include "../../collection.hpp"
 #include "../../component_batch.hpp"
 #include "../../component_column.hpp"
@@ -26,6 +27,12 @@ namespace rerun::blueprint::archetypes {
         /// **Note**: Requires a Mapbox API key in the `RERUN_MAPBOX_ACCESS_TOKEN` environment variable.
         std::optional<ComponentBatch> provider;
 
+        /// Custom map tiles to show instead of the provider's.
+        ///
+        /// Tiles downloaded from a URL are kept in an on-disk cache, so areas that were viewed once
+        /// remain available without network access.
+        std::optional<ComponentBatch> tile_source;
+
       public:
         static constexpr const char IndicatorComponentName[] =
             "rerun.blueprint.components.MapBackgroundIndicator";
@@ -40,6 +47,11 @@ namespace rerun::blueprint::archetypes {
             ArchetypeName, "provider",
             Loggable<rerun::blueprint::components::MapProvider>::ComponentName
         );
+        /// `ComponentDescriptor` for the `tile_source` field.
+        static constexpr auto Descriptor_tile_source = ComponentDescriptor(
+            ArchetypeName, "tile_source",
+            Loggable<rerun::blueprint::components::MapTileSource>::ComponentName
+        );
 
       public:
         MapBackground() = default;
@@ -69,6 +81,18 @@ namespace rerun::blueprint::archetypes {
             return std::move(*this);
         }
 
+        /// Custom map tiles to show instead of the provider's.
+        ///
+        /// Tiles downloaded from a URL are kept in an on-disk cache, so areas that were viewed once
+        /// remain available without network access.
+        MapBackground with_tile_source(
+            const rerun::blueprint::components::MapTileSource& _tile_source
+        ) && {
+            tile_source = ComponentBatch::from_loggable(_tile_source, Descriptor_tile_source)
+                              .value_or_throw();
+            return std::move(*this);
+        }
+
         /// Partitions the component data into multiple sub-batches.
         ///
         /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
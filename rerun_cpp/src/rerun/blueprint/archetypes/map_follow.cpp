@@ -0,0 +1,70 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/map_follow.fbs".
+
+#include "map_follow.hpp"
+
+#include "../../collection_adapter_builtins.hpp"
+
+namespace rerun::blueprint::archetypes {
+    MapFollow MapFollow::clear_fields() {
+        auto archetype = MapFollow();
+        archetype.mode =
+            ComponentBatch::empty<rerun::blueprint::components::MapFollowMode>(Descriptor_mode)
+                .value_or_throw();
+        archetype.entity =
+            ComponentBatch::empty<rerun::components::EntityPath>(Descriptor_entity)
+                .value_or_throw();
+        return archetype;
+    }
+
+    Collection<ComponentColumn> MapFollow::columns(const Collection<uint32_t>& lengths_) {
+        std::vector<ComponentColumn> columns;
+        columns.reserve(3);
+        if (mode.has_value()) {
+            columns.push_back(mode.value().partitioned(lengths_).value_or_throw());
+        }
+        if (entity.has_value()) {
+            columns.push_back(entity.value().partitioned(lengths_).value_or_throw());
+        }
+        columns.push_back(
+            ComponentColumn::from_indicators<MapFollow>(static_cast<uint32_t>(lengths_.size()))
+                .value_or_throw()
+        );
+        return columns;
+    }
+
+    Collection<ComponentColumn> MapFollow::columns() {
+        if (mode.has_value()) {
+            return columns(std::vector<uint32_t>(mode.value().length(), 1));
+        }
+        if (entity.has_value()) {
+            return columns(std::vector<uint32_t>(entity.value().length(), 1));
+        }
+        return Collection<ComponentColumn>();
+    }
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+
+    Result<Collection<ComponentBatch>> AsComponents<blueprint::archetypes::MapFollow>::as_batches(
+        const blueprint::archetypes::MapFollow& archetype
+    ) {
+        using namespace blueprint::archetypes;
+        std::vector<ComponentBatch> cells;
+        cells.reserve(3);
+
+        if (archetype.mode.has_value()) {
+            cells.push_back(archetype.mode.value());
+        }
+        if (archetype.entity.has_value()) {
+            cells.push_back(archetype.entity.value());
+        }
+        {
+            auto result = ComponentBatch::from_indicator<MapFollow>();
+            RR_RETURN_NOT_OK(result.error);
+            cells.emplace_back(std::move(result.value));
+        }
+
+        return rerun::take_ownership(std::move(cells));
+    }
+} // namespace rerun
@@ -0,0 +1,121 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/map_follow.fbs".
+
+#pragma once
+
+#include "../../blueprint/components/map_follow_mode.hpp"
+#include "../../collection.hpp"
+#include "../../component_batch.hpp"
+#include "../../component_column.hpp"
+#include "../../components/entity_path.hpp"
+#include "../../indicator_component.hpp"
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <optional>
+#include <utility>
+#include <vector>
+
+namespace rerun::blueprint::archetypes {
+    /// **Archetype**: Configuration of the map view camera following an entity.
+    ///
+    /// ⚠ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    ///
+    struct MapFollow {
+        /// Whether and how the map follows the entity.
+        std::optional<ComponentBatch> mode;
+
+        /// The entity to follow.
+        ///
+        /// Its latest geospatial position is used, taken from either `GeoPoints` or the last vertex
+        /// of `GeoLineStrings`.
+        /// If not set, the origin of the view is followed.
+        std::optional<ComponentBatch> entity;
+
+      public:
+        static constexpr const char IndicatorComponentName[] =
+            "rerun.blueprint.components.MapFollowIndicator";
+
+        /// Indicator component, used to identify the archetype when converting to a list of components.
+        using IndicatorComponent = rerun::components::IndicatorComponent<IndicatorComponentName>;
+        /// The name of the archetype as used in `ComponentDescriptor`s.
+        static constexpr const char ArchetypeName[] = "rerun.blueprint.archetypes.MapFollow";
+
+        /// `ComponentDescriptor` for the `mode` field.
+        static constexpr auto Descriptor_mode = ComponentDescriptor(
+            ArchetypeName, "mode",
+            Loggable<rerun::blueprint::components::MapFollowMode>::ComponentName
+        );
+        /// `ComponentDescriptor` for the `entity` field.
+        static constexpr auto Descriptor_entity = ComponentDescriptor(
+            ArchetypeName, "entity", Loggable<rerun::components::EntityPath>::ComponentName
+        );
+
+      public:
+        MapFollow() = default;
+        MapFollow(MapFollow&& other) = default;
+        MapFollow(const MapFollow& other) = default;
+        MapFollow& operator=(const MapFollow& other) = default;
+        MapFollow& operator=(MapFollow&& other) = default;
+
+        explicit MapFollow(rerun::blueprint::components::MapFollowMode _mode)
+            : mode(ComponentBatch::from_loggable(std::move(_mode), Descriptor_mode).value_or_throw()
+              ) {}
+
+        /// Update only some specific fields of a `MapFollow`.
+        static MapFollow update_fields() {
+            return MapFollow();
+        }
+
+        /// Clear all the fields of a `MapFollow`.
+        static MapFollow clear_fields();
+
+        /// Whether and how the map follows the entity.
+        MapFollow with_mode(const rerun::blueprint::components::MapFollowMode& _mode) && {
+            mode = ComponentBatch::from_loggable(_mode, Descriptor_mode).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// The entity to follow.
+        ///
+        /// Its latest geospatial position is used, taken from either `GeoPoints` or the last vertex
+        /// of `GeoLineStrings`.
+        /// If not set, the origin of the view is followed.
+        MapFollow with_entity(const rerun::components::EntityPath& _entity) && {
+            entity = ComponentBatch::from_loggable(_entity, Descriptor_entity).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Partitions the component data into multiple sub-batches.
+        ///
+        /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
+        /// instead, via `ComponentBatch::partitioned`.
+        ///
+        /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+        ///
+        /// The specified `lengths` must sum to the total length of the component batch.
+        Collection<ComponentColumn> columns(const Collection<uint32_t>& lengths_);
+
+        /// Partitions the component data into unit-length sub-batches.
+        ///
+        /// This is semantically similar to calling `columns` with `std::vector<uint32_t>(n, 1)`,
+        /// where `n` is automatically guessed.
+        Collection<ComponentColumn> columns();
+    };
+
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+    /// \private
+    template <typename T>
+    struct AsComponents;
+
+    /// \private
+    template <>
+    struct AsComponents<blueprint::archetypes::MapFollow> {
+        /// Serialize all set component batches.
+        static Result<Collection<ComponentBatch>> as_batches(
+            const blueprint::archetypes::MapFollow& archetype
+        );
+    };
+} // namespace rerun
@@ -0,0 +1,83 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/spectrogram_window.fbs".
+
+#include "spectrogram_window.hpp"
+
+#include "../../collection_adapter_builtins.hpp"
+
+namespace rerun::blueprint::archetypes {
+    SpectrogramWindow SpectrogramWindow::clear_fields() {
+        auto archetype = SpectrogramWindow();
+        archetype.length =
+            ComponentBatch::empty<rerun::blueprint::components::WindowLength>(Descriptor_length)
+                .value_or_throw();
+        archetype.hop =
+            ComponentBatch::empty<rerun::blueprint::components::HopLength>(Descriptor_hop)
+                .value_or_throw();
+        archetype.function =
+            ComponentBatch::empty<rerun::blueprint::components::WindowFunction>(Descriptor_function)
+                .value_or_throw();
+        return archetype;
+    }
+
+    Collection<ComponentColumn> SpectrogramWindow::columns(const Collection<uint32_t>& lengths_) {
+        std::vector<ComponentColumn> columns;
+        columns.reserve(4);
+        if (length.has_value()) {
+            columns.push_back(length.value().partitioned(lengths_).value_or_throw());
+        }
+        if (hop.has_value()) {
+            columns.push_back(hop.value().partitioned(lengths_).value_or_throw());
+        }
+        if (function.has_value()) {
+            columns.push_back(function.value().partitioned(lengths_).value_or_throw());
+        }
+        columns.push_back(ComponentColumn::from_indicators<SpectrogramWindow>(
+                              static_cast<uint32_t>(lengths_.size())
+        )
+                              .value_or_throw());
+        return columns;
+    }
+
+    Collection<ComponentColumn> SpectrogramWindow::columns() {
+        if (length.has_value()) {
+            return columns(std::vector<uint32_t>(length.value().length(), 1));
+        }
+        if (hop.has_value()) {
+            return columns(std::vector<uint32_t>(hop.value().length(), 1));
+        }
+        if (function.has_value()) {
+            return columns(std::vector<uint32_t>(function.value().length(), 1));
+        }
+        return Collection<ComponentColumn>();
+    }
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+
+    Result<Collection<ComponentBatch>>
+        AsComponents<blueprint::archetypes::SpectrogramWindow>::as_batches(
+            const blueprint::archetypes::SpectrogramWindow& archetype
+        ) {
+        using namespace blueprint::archetypes;
+        std::vector<ComponentBatch> cells;
+        cells.reserve(4);
+
+        if (archetype.length.has_value()) {
+            cells.push_back(archetype.length.value());
+        }
+        if (archetype.hop.has_value()) {
+            cells.push_back(archetype.hop.value());
+        }
+        if (archetype.function.has_value()) {
+            cells.push_back(archetype.function.value());
+        }
+        {
+            auto result = ComponentBatch::from_indicator<SpectrogramWindow>();
+            RR_RETURN_NOT_OK(result.error);
+            cells.emplace_back(std::move(result.value));
+        }
+
+        return rerun::take_ownership(std::move(cells));
+    }
+} // namespace rerun
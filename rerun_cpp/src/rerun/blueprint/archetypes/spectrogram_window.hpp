@@ -0,0 +1,141 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/spectrogram_window.fbs".
+
+#pragma once
+
+#include "../../blueprint/components/hop_length.hpp"
+#include "../../blueprint/components/window_function.hpp"
+#include "../../blueprint/components/window_length.hpp"
+#include "../../collection.hpp"
+#include "../../component_batch.hpp"
+#include "../../component_column.hpp"
+#include "../../indicator_component.hpp"
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <optional>
+#include <utility>
+#include <vector>
+
+namespace rerun::blueprint::archetypes {
+    /// **Archetype**: Configuration for how a spectrogram splits a signal into windows and weights their samples.
+    ///
+    /// ⚠ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    ///
+    struct SpectrogramWindow {
+        /// How many samples each window spans.
+        ///
+        /// Rounded up to the next power of two. Defaults to 256.
+        std::optional<ComponentBatch> length;
+
+        /// How many samples a window advances by between two columns of the spectrogram.
+        ///
+        /// Defaults to a quarter of the window length.
+        std::optional<ComponentBatch> hop;
+
+        /// The function the samples of each window are weighted with.
+        ///
+        /// Defaults to a Hann window.
+        std::optional<ComponentBatch> function;
+
+      public:
+        static constexpr const char IndicatorComponentName[] =
+            "rerun.blueprint.components.SpectrogramWindowIndicator";
+
+        /// Indicator component, used to identify the archetype when converting to a list of components.
+        using IndicatorComponent = rerun::components::IndicatorComponent<IndicatorComponentName>;
+        /// The name of the archetype as used in `ComponentDescriptor`s.
+        static constexpr const char ArchetypeName[] =
+            "rerun.blueprint.archetypes.SpectrogramWindow";
+
+        /// `ComponentDescriptor` for the `length` field.
+        static constexpr auto Descriptor_length = ComponentDescriptor(
+            ArchetypeName, "length",
+            Loggable<rerun::blueprint::components::WindowLength>::ComponentName
+        );
+        /// `ComponentDescriptor` for the `hop` field.
+        static constexpr auto Descriptor_hop = ComponentDescriptor(
+            ArchetypeName, "hop", Loggable<rerun::blueprint::components::HopLength>::ComponentName
+        );
+        /// `ComponentDescriptor` for the `function` field.
+        static constexpr auto Descriptor_function = ComponentDescriptor(
+            ArchetypeName, "function",
+            Loggable<rerun::blueprint::components::WindowFunction>::ComponentName
+        );
+
+      public:
+        SpectrogramWindow() = default;
+        SpectrogramWindow(SpectrogramWindow&& other) = default;
+        SpectrogramWindow(const SpectrogramWindow& other) = default;
+        SpectrogramWindow& operator=(const SpectrogramWindow& other) = default;
+        SpectrogramWindow& operator=(SpectrogramWindow&& other) = default;
+
+        /// Update only some specific fields of a `SpectrogramWindow`.
+        static SpectrogramWindow update_fields() {
+            return SpectrogramWindow();
+        }
+
+        /// Clear all the fields of a `SpectrogramWindow`.
+        static SpectrogramWindow clear_fields();
+
+        /// How many samples each window spans.
+        ///
+        /// Rounded up to the next power of two. Defaults to 256.
+        SpectrogramWindow with_length(const rerun::blueprint::components::WindowLength& _length
+        ) && {
+            length = ComponentBatch::from_loggable(_length, Descriptor_length).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// How many samples a window advances by between two columns of the spectrogram.
+        ///
+        /// Defaults to a quarter of the window length.
+        SpectrogramWindow with_hop(const rerun::blueprint::components::HopLength& _hop) && {
+            hop = ComponentBatch::from_loggable(_hop, Descriptor_hop).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// The function the samples of each window are weighted with.
+        ///
+        /// Defaults to a Hann window.
+        SpectrogramWindow with_function(
+            const rerun::blueprint::components::WindowFunction& _function
+        ) && {
+            function =
+                ComponentBatch::from_loggable(_function, Descriptor_function).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Partitions the component data into multiple sub-batches.
+        ///
+        /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
+        /// instead, via `ComponentBatch::partitioned`.
+        ///
+        /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+        ///
+        /// The specified `lengths` must sum to the total length of the component batch.
+        Collection<ComponentColumn> columns(const Collection<uint32_t>& lengths_);
+
+        /// Partitions the component data into unit-length sub-batches.
+        ///
+        /// This is semantically similar to calling `columns` with `std::vector<uint32_t>(n, 1)`,
+        /// where `n` is automatically guessed.
+        Collection<ComponentColumn> columns();
+    };
+
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+    /// \private
+    template <typename T>
+    struct AsComponents;
+
+    /// \private
+    template <>
+    struct AsComponents<blueprint::archetypes::SpectrogramWindow> {
+        /// Serialize all set component batches.
+        static Result<Collection<ComponentBatch>> as_batches(
+            const blueprint::archetypes::SpectrogramWindow& archetype
+        );
+    };
+} // namespace rerun
@@ -0,0 +1,138 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/tensor_volume.fbs".
+
+#pragma once
+
+#include "../../collection.hpp"
+#include "../../component_batch.hpp"
+#include "../../component_column.hpp"
+#include "../../components/colormap.hpp"
+#include "../../components/opacity.hpp"
+#include "../../components/scalar.hpp"
+#include "../../indicator_component.hpp"
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <optional>
+#include <utility>
+#include <vector>
+
+namespace rerun::blueprint::archetypes {
+    /// **Archetype**: Configuration for how three-dimensional tensors are shown as volumes or iso-surfaces in 3D views.
+    ///
+    /// ⚠ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    ///
+    struct TensorVolume {
+        /// The colormap applied to the tensor's values.
+        ///
+        /// Defaults to viridis.
+        std::optional<ComponentBatch> colormap;
+
+        /// Opacity contributed by each voxel when shown as a volume.
+        ///
+        /// Since it accumulates along every ray, even small values can make the volume opaque.
+        /// Defaults to 0.05.
+        std::optional<ComponentBatch> opacity;
+
+        /// The value at which the iso-surface is extracted when shown as an iso-surface.
+        ///
+        /// Defaults to the center of the tensor's value range.
+        std::optional<ComponentBatch> iso_value;
+
+      public:
+        static constexpr const char IndicatorComponentName[] =
+            "rerun.blueprint.components.TensorVolumeIndicator";
+
+        /// Indicator component, used to identify the archetype when converting to a list of components.
+        using IndicatorComponent = rerun::components::IndicatorComponent<IndicatorComponentName>;
+        /// The name of the archetype as used in `ComponentDescriptor`s.
+        static constexpr const char ArchetypeName[] = "rerun.blueprint.archetypes.TensorVolume";
+
+        /// `ComponentDescriptor` for the `colormap` field.
+        static constexpr auto Descriptor_colormap = ComponentDescriptor(
+            ArchetypeName, "colormap", Loggable<rerun::components::Colormap>::ComponentName
+        );
+        /// `ComponentDescriptor` for the `opacity` field.
+        static constexpr auto Descriptor_opacity = ComponentDescriptor(
+            ArchetypeName, "opacity", Loggable<rerun::components::Opacity>::ComponentName
+        );
+        /// `ComponentDescriptor` for the `iso_value` field.
+        static constexpr auto Descriptor_iso_value = ComponentDescriptor(
+            ArchetypeName, "iso_value", Loggable<rerun::components::Scalar>::ComponentName
+        );
+
+      public:
+        TensorVolume() = default;
+        TensorVolume(TensorVolume&& other) = default;
+        TensorVolume(const TensorVolume& other) = default;
+        TensorVolume& operator=(const TensorVolume& other) = default;
+        TensorVolume& operator=(TensorVolume&& other) = default;
+
+        /// Update only some specific fields of a `TensorVolume`.
+        static TensorVolume update_fields() {
+            return TensorVolume();
+        }
+
+        /// Clear all the fields of a `TensorVolume`.
+        static TensorVolume clear_fields();
+
+        /// The colormap applied to the tensor's values.
+        ///
+        /// Defaults to viridis.
+        TensorVolume with_colormap(const rerun::components::Colormap& _colormap) && {
+            colormap =
+                ComponentBatch::from_loggable(_colormap, Descriptor_colormap).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Opacity contributed by each voxel when shown as a volume.
+        ///
+        /// Since it accumulates along every ray, even small values can make the volume opaque.
+        /// Defaults to 0.05.
+        TensorVolume with_opacity(const rerun::components::Opacity& _opacity) && {
+            opacity = ComponentBatch::from_loggable(_opacity, Descriptor_opacity).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// The value at which the iso-surface is extracted when shown as an iso-surface.
+        ///
+        /// Defaults to the center of the tensor's value range.
+        TensorVolume with_iso_value(const rerun::components::Scalar& _iso_value) && {
+            iso_value =
+                ComponentBatch::from_loggable(_iso_value, Descriptor_iso_value).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Partitions the component data into multiple sub-batches.
+        ///
+        /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
+        /// instead, via `ComponentBatch::partitioned`.
+        ///
+        /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+        ///
+        /// The specified `lengths` must sum to the total length of the component batch.
+        Collection<ComponentColumn> columns(const Collection<uint32_t>& lengths_);
+
+        /// Partitions the component data into unit-length sub-batches.
+        ///
+        /// This is semantically similar to calling `columns` with `std::vector<uint32_t>(n, 1)`,
+        /// where `n` is automatically guessed.
+        Collection<ComponentColumn> columns();
+    };
+
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+    /// \private
+    template <typename T>
+    struct AsComponents;
+
+    /// \private
+    template <>
+    struct AsComponents<blueprint::archetypes::TensorVolume> {
+        /// Serialize all set component batches.
+        static Result<Collection<ComponentBatch>> as_batches(
+            const blueprint::archetypes::TensorVolume& archetype
+        );
+    };
+} // namespace rerun
@@ -0,0 +1,82 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/timeline_bookmarks.fbs".
+
+#include "timeline_bookmarks.hpp"
+
+#include "../../collection_adapter_builtins.hpp"
+
+namespace rerun::blueprint::archetypes {
+    TimelineBookmarks TimelineBookmarks::clear_fields() {
+        auto archetype = TimelineBookmarks();
+        archetype.timelines =
+            ComponentBatch::empty<rerun::blueprint::components::TimelineName>(Descriptor_timelines)
+                .value_or_throw();
+        archetype.times =
+            ComponentBatch::empty<rerun::blueprint::components::BookmarkTime>(Descriptor_times)
+                .value_or_throw();
+        archetype.names =
+            ComponentBatch::empty<rerun::components::Name>(Descriptor_names).value_or_throw();
+        return archetype;
+    }
+
+    Collection<ComponentColumn> TimelineBookmarks::columns(const Collection<uint32_t>& lengths_) {
+        std::vector<ComponentColumn> columns;
+        columns.reserve(4);
+        if (timelines.has_value()) {
+            columns.push_back(timelines.value().partitioned(lengths_).value_or_throw());
+        }
+        if (times.has_value()) {
+            columns.push_back(times.value().partitioned(lengths_).value_or_throw());
+        }
+        if (names.has_value()) {
+            columns.push_back(names.value().partitioned(lengths_).value_or_throw());
+        }
+        columns.push_back(ComponentColumn::from_indicators<TimelineBookmarks>(
+                              static_cast<uint32_t>(lengths_.size())
+        )
+                              .value_or_throw());
+        return columns;
+    }
+
+    Collection<ComponentColumn> TimelineBookmarks::columns() {
+        if (timelines.has_value()) {
+            return columns(std::vector<uint32_t>(timelines.value().length(), 1));
+        }
+        if (times.has_value()) {
+            return columns(std::vector<uint32_t>(times.value().length(), 1));
+        }
+        if (names.has_value()) {
+            return columns(std::vector<uint32_t>(names.value().length(), 1));
+        }
+        return Collection<ComponentColumn>();
+    }
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+
+    Result<Collection<ComponentBatch>>
+        AsComponents<blueprint::archetypes::TimelineBookmarks>::as_batches(
+            const blueprint::archetypes::TimelineBookmarks& archetype
+        ) {
+        using namespace blueprint::archetypes;
+        std::vector<ComponentBatch> cells;
+        cells.reserve(4);
+
+        if (archetype.timelines.has_value()) {
+            cells.push_back(archetype.timelines.value());
+        }
+        if (archetype.times.has_value()) {
+            cells.push_back(archetype.times.value());
+        }
+        if (archetype.names.has_value()) {
+            cells.push_back(archetype.names.value());
+        }
+        {
+            auto result = ComponentBatch::from_indicator<TimelineBookmarks>();
+            RR_RETURN_NOT_OK(result.error);
+            cells.emplace_back(std::move(result.value));
+        }
+
+        return rerun::take_ownership(std::move(cells));
+    }
+} // namespace rerun
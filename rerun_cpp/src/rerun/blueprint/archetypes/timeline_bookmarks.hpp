@@ -0,0 +1,132 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/timeline_bookmarks.fbs".
+
+#pragma once
+
+#include "../../blueprint/components/bookmark_time.hpp"
+#include "../../blueprint/components/timeline_name.hpp"
+#include "../../collection.hpp"
+#include "../../component_batch.hpp"
+#include "../../component_column.hpp"
+#include "../../components/name.hpp"
+#include "../../indicator_component.hpp"
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <optional>
+#include <utility>
+#include <vector>
+
+namespace rerun::blueprint::archetypes {
+    /// **Archetype**: Named moments on the timelines of a recording, shown in the time panel.
+    ///
+    /// The n-th bookmark is at `times[n]` on the timeline `timelines[n]`, and is called `names[n]`.
+    ///
+    /// ⚠ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    ///
+    struct TimelineBookmarks {
+        /// The timeline of each bookmark.
+        std::optional<ComponentBatch> timelines;
+
+        /// The time of each bookmark on its timeline.
+        std::optional<ComponentBatch> times;
+
+        /// The name of each bookmark.
+        std::optional<ComponentBatch> names;
+
+      public:
+        static constexpr const char IndicatorComponentName[] =
+            "rerun.blueprint.components.TimelineBookmarksIndicator";
+
+        /// Indicator component, used to identify the archetype when converting to a list of components.
+        using IndicatorComponent = rerun::components::IndicatorComponent<IndicatorComponentName>;
+        /// The name of the archetype as used in `ComponentDescriptor`s.
+        static constexpr const char ArchetypeName[] =
+            "rerun.blueprint.archetypes.TimelineBookmarks";
+
+        /// `ComponentDescriptor` for the `timelines` field.
+        static constexpr auto Descriptor_timelines = ComponentDescriptor(
+            ArchetypeName, "timelines",
+            Loggable<rerun::blueprint::components::TimelineName>::ComponentName
+        );
+        /// `ComponentDescriptor` for the `times` field.
+        static constexpr auto Descriptor_times = ComponentDescriptor(
+            ArchetypeName, "times",
+            Loggable<rerun::blueprint::components::BookmarkTime>::ComponentName
+        );
+        /// `ComponentDescriptor` for the `names` field.
+        static constexpr auto Descriptor_names = ComponentDescriptor(
+            ArchetypeName, "names", Loggable<rerun::components::Name>::ComponentName
+        );
+
+      public:
+        TimelineBookmarks() = default;
+        TimelineBookmarks(TimelineBookmarks&& other) = default;
+        TimelineBookmarks(const TimelineBookmarks& other) = default;
+        TimelineBookmarks& operator=(const TimelineBookmarks& other) = default;
+        TimelineBookmarks& operator=(TimelineBookmarks&& other) = default;
+
+        /// Update only some specific fields of a `TimelineBookmarks`.
+        static TimelineBookmarks update_fields() {
+            return TimelineBookmarks();
+        }
+
+        /// Clear all the fields of a `TimelineBookmarks`.
+        static TimelineBookmarks clear_fields();
+
+        /// The timeline of each bookmark.
+        TimelineBookmarks with_timelines(
+            const Collection<rerun::blueprint::components::TimelineName>& _timelines
+        ) && {
+            timelines =
+                ComponentBatch::from_loggable(_timelines, Descriptor_timelines).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// The time of each bookmark on its timeline.
+        TimelineBookmarks with_times(
+            const Collection<rerun::blueprint::components::BookmarkTime>& _times
+        ) && {
+            times = ComponentBatch::from_loggable(_times, Descriptor_times).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// The name of each bookmark.
+        TimelineBookmarks with_names(const Collection<rerun::components::Name>& _names) && {
+            names = ComponentBatch::from_loggable(_names, Descriptor_names).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Partitions the component data into multiple sub-batches.
+        ///
+        /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
+        /// instead, via `ComponentBatch::partitioned`.
+        ///
+        /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+        ///
+        /// The specified `lengths` must sum to the total length of the component batch.
+        Collection<ComponentColumn> columns(const Collection<uint32_t>& lengths_);
+
+        /// Partitions the component data into unit-length sub-batches.
+        ///
+        /// This is semantically similar to calling `columns` with `std::vector<uint32_t>(n, 1)`,
+        /// where `n` is automatically guessed.
+        Collection<ComponentColumn> columns();
+    };
+
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+    /// \private
+    template <typename T>
+    struct AsComponents;
+
+    /// \private
+    template <>
+    struct AsComponents<blueprint::archetypes::TimelineBookmarks> {
+        /// Serialize all set component batches.
+        static Result<Collection<ComponentBatch>> as_batches(
+            const blueprint::archetypes::TimelineBookmarks& archetype
+        );
+    };
+} // namespace rerun
@@ -0,0 +1,85 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/x_axis.fbs".
+
+#include "x_axis.hpp"
+
+#include "../../collection_adapter_builtins.hpp"
+
+namespace rerun::blueprint::archetypes {
+    XAxis XAxis::clear_fields() {
+        auto archetype = XAxis();
+        archetype.source =
+            ComponentBatch::empty<rerun::blueprint::components::ComponentColumnSelector>(
+                Descriptor_source
+            )
+                .value_or_throw();
+        archetype.range =
+            ComponentBatch::empty<rerun::components::Range1D>(Descriptor_range).value_or_throw();
+        archetype.zoom_lock =
+            ComponentBatch::empty<rerun::blueprint::components::LockRangeDuringZoom>(
+                Descriptor_zoom_lock
+            )
+                .value_or_throw();
+        return archetype;
+    }
+
+    Collection<ComponentColumn> XAxis::columns(const Collection<uint32_t>& lengths_) {
+        std::vector<ComponentColumn> columns;
+        columns.reserve(4);
+        if (source.has_value()) {
+            columns.push_back(source.value().partitioned(lengths_).value_or_throw());
+        }
+        if (range.has_value()) {
+            columns.push_back(range.value().partitioned(lengths_).value_or_throw());
+        }
+        if (zoom_lock.has_value()) {
+            columns.push_back(zoom_lock.value().partitioned(lengths_).value_or_throw());
+        }
+        columns.push_back(
+            ComponentColumn::from_indicators<XAxis>(static_cast<uint32_t>(lengths_.size()))
+                .value_or_throw()
+        );
+        return columns;
+    }
+
+    Collection<ComponentColumn> XAxis::columns() {
+        if (source.has_value()) {
+            return columns(std::vector<uint32_t>(source.value().length(), 1));
+        }
+        if (range.has_value()) {
+            return columns(std::vector<uint32_t>(range.value().length(), 1));
+        }
+        if (zoom_lock.has_value()) {
+            return columns(std::vector<uint32_t>(zoom_lock.value().length(), 1));
+        }
+        return Collection<ComponentColumn>();
+    }
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+
+    Result<Collection<ComponentBatch>> AsComponents<blueprint::archetypes::XAxis>::as_batches(
+        const blueprint::archetypes::XAxis& archetype
+    ) {
+        using namespace blueprint::archetypes;
+        std::vector<ComponentBatch> cells;
+        cells.reserve(4);
+
+        if (archetype.source.has_value()) {
+            cells.push_back(archetype.source.value());
+        }
+        if (archetype.range.has_value()) {
+            cells.push_back(archetype.range.value());
+        }
+        if (archetype.zoom_lock.has_value()) {
+            cells.push_back(archetype.zoom_lock.value());
+        }
+        {
+            auto result = ComponentBatch::from_indicator<XAxis>();
+            RR_RETURN_NOT_OK(result.error);
+            cells.emplace_back(std::move(result.value));
+        }
+
+        return rerun::take_ownership(std::move(cells));
+    }
+} // namespace rerun
@@ -7,6 +7,8 @@
 #include "blueprint/components/auto_layout.hpp"
 #include "blueprint/components/auto_views.hpp"
 #include "blueprint/components/background_kind.hpp"
+#include "blueprint/components/bin_count.hpp"
+#include "blueprint/components/bookmark_time.hpp"
 #include "blueprint/components/column_share.hpp"
 #include "blueprint/components/component_column_selector.hpp"
 #include "blueprint/components/container_kind.hpp"
@@ -19,10 +21,14 @@
 #include "blueprint/components/force_strength.hpp"
 #include "blueprint/components/grid_columns.hpp"
 #include "blueprint/components/grid_spacing.hpp"
+#include "blueprint/components/hop_length.hpp"
 #include "blueprint/components/included_content.hpp"
+#include "blueprint/components/keyframe_time.hpp"
 #include "blueprint/components/link_axis.hpp"
 #include "blueprint/components/lock_range_during_zoom.hpp"
+#include "blueprint/components/map_follow_mode.hpp"
 #include "blueprint/components/map_provider.hpp"
+#include "blueprint/components/map_tile_source.hpp"
 #include "blueprint/components/near_clip_plane.hpp"
 #include "blueprint/components/panel_state.hpp"
 #include "blueprint/components/query_expression.hpp"
@@ -39,4 +45,6 @@
 #include "blueprint/components/visible_time_range.hpp"
 #include "blueprint/components/visual_bounds2d.hpp"
 #include "blueprint/components/visualizer_override.hpp"
+#include "blueprint/components/window_function.hpp"
+#include "blueprint/components/window_length.hpp"
 #include "blueprint/components/zoom_level.hpp"
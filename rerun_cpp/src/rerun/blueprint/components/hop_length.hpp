@@ -0,0 +1,76 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/components/hop_length.fbs".
+
+#pragma once
+
+#include "../../datatypes/uint64.hpp"
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <memory>
+
+namespace rerun::blueprint::components {
+    /// **Component**: The number of samples between the starts of two consecutive analysis windows.
+    ///
+    /// ⚠ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    ///
+    struct HopLength {
+        rerun::datatypes::UInt64 length;
+
+      public:
+        HopLength() = default;
+
+        HopLength(rerun::datatypes::UInt64 length_) : length(length_) {}
+
+        HopLength& operator=(rerun::datatypes::UInt64 length_) {
+            length = length_;
+            return *this;
+        }
+
+        HopLength(uint64_t value_) : length(value_) {}
+
+        HopLength& operator=(uint64_t value_) {
+            length = value_;
+            return *this;
+        }
+
+        /// Cast to the underlying UInt64 datatype
+        operator rerun::datatypes::UInt64() const {
+            return length;
+        }
+    };
+} // namespace rerun::blueprint::components
+
+namespace rerun {
+    static_assert(sizeof(rerun::datatypes::UInt64) == sizeof(blueprint::components::HopLength));
+
+    /// \private
+    template <>
+    struct Loggable<blueprint::components::HopLength> {
+        static constexpr std::string_view ComponentName = "rerun.blueprint.components.HopLength";
+
+        /// Returns the arrow data type this type corresponds to.
+        static const std::shared_ptr<arrow::DataType>& arrow_datatype() {
+            return Loggable<rerun::datatypes::UInt64>::arrow_datatype();
+        }
+
+        /// Serializes an array of `rerun::blueprint:: components::HopLength` into an arrow array.
+        static Result<std::shared_ptr<arrow::Array>> to_arrow(
+            const blueprint::components::HopLength* instances, size_t num_instances
+        ) {
+            if (num_instances == 0) {
+                return Loggable<rerun::datatypes::UInt64>::to_arrow(nullptr, 0);
+            } else if (instances == nullptr) {
+                return rerun::Error(
+                    ErrorCode::UnexpectedNullArgument,
+                    "Passed array instances is null when num_elements> 0."
+                );
+            } else {
+                return Loggable<rerun::datatypes::UInt64>::to_arrow(
+                    &instances->length,
+                    num_instances
+                );
+            }
+        }
+    };
+} // namespace rerun
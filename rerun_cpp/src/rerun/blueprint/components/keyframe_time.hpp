@@ -0,0 +1,76 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/components/keyframe_time.fbs".
+
+#pragma once
+
+#include "../../datatypes/time_int.hpp"
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <memory>
+
+namespace rerun::blueprint::components {
+    /// **Component**: The time of a keyframe on its timeline.
+    ///
+    /// ⚠ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    ///
+    struct KeyframeTime {
+        rerun::datatypes::TimeInt time;
+
+      public:
+        KeyframeTime() = default;
+
+        KeyframeTime(rerun::datatypes::TimeInt time_) : time(time_) {}
+
+        KeyframeTime& operator=(rerun::datatypes::TimeInt time_) {
+            time = time_;
+            return *this;
+        }
+
+        KeyframeTime(int64_t value_) : time(value_) {}
+
+        KeyframeTime& operator=(int64_t value_) {
+            time = value_;
+            return *this;
+        }
+
+        /// Cast to the underlying TimeInt datatype
+        operator rerun::datatypes::TimeInt() const {
+            return time;
+        }
+    };
+} // namespace rerun::blueprint::components
+
+namespace rerun {
+    static_assert(sizeof(rerun::datatypes::TimeInt) == sizeof(blueprint::components::KeyframeTime));
+
+    /// \private
+    template <>
+    struct Loggable<blueprint::components::KeyframeTime> {
+        static constexpr std::string_view ComponentName = "rerun.blueprint.components.KeyframeTime";
+
+        /// Returns the arrow data type this type corresponds to.
+        static const std::shared_ptr<arrow::DataType>& arrow_datatype() {
+            return Loggable<rerun::datatypes::TimeInt>::arrow_datatype();
+        }
+
+        /// Serializes an array of `rerun::blueprint:: components::KeyframeTime` into an arrow array.
+        static Result<std::shared_ptr<arrow::Array>> to_arrow(
+            const blueprint::components::KeyframeTime* instances, size_t num_instances
+        ) {
+            if (num_instances == 0) {
+                return Loggable<rerun::datatypes::TimeInt>::to_arrow(nullptr, 0);
+            } else if (instances == nullptr) {
+                return rerun::Error(
+                    ErrorCode::UnexpectedNullArgument,
+                    "Passed array instances is null when num_elements> 0."
+                );
+            } else {
+                return Loggable<rerun::datatypes::TimeInt>::to_arrow(
+                    &instances->time,
+                    num_instances
+                );
+            }
+        }
+    };
+} // namespace rerun
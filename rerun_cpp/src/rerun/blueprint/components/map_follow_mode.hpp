@@ -0,0 +1,63 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/components/map_follow_mode.fbs".
+
+#pragma once
+
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <memory>
+
+namespace arrow {
+    /// \private
+    template <typename T>
+    class NumericBuilder;
+
+    class Array;
+    class DataType;
+    class UInt8Type;
+    using UInt8Builder = NumericBuilder<UInt8Type>;
+} // namespace arrow
+
+namespace rerun::blueprint::components {
+    /// **Component**: How the map view follows an entity.
+    enum class MapFollowMode : uint8_t {
+
+        /// The map is only moved by the user, or to fit the data.
+        Off = 1,
+
+        /// The map is kept centered on the latest position of the followed entity.
+        Position = 2,
+
+        /// Like `Position`, and the map is rotated so that the followed entity's direction of travel points up.
+        ///
+        /// The direction of travel is derived from the two latest distinct positions.
+        PositionAndHeading = 3,
+    };
+} // namespace rerun::blueprint::components
+
+namespace rerun {
+    template <typename T>
+    struct Loggable;
+
+    /// \private
+    template <>
+    struct Loggable<blueprint::components::MapFollowMode> {
+        static constexpr std::string_view ComponentName =
+            "rerun.blueprint.components.MapFollowMode";
+
+        /// Returns the arrow data type this type corresponds to.
+        static const std::shared_ptr<arrow::DataType>& arrow_datatype();
+
+        /// Serializes an array of `rerun::blueprint:: components::MapFollowMode` into an arrow array.
+        static Result<std::shared_ptr<arrow::Array>> to_arrow(
+            const blueprint::components::MapFollowMode* instances, size_t num_instances
+        );
+
+        /// Fills an arrow array builder with an array of this type.
+        static rerun::Error fill_arrow_array_builder(
+            arrow::UInt8Builder* builder, const blueprint::components::MapFollowMode* elements,
+            size_t num_elements
+        );
+    };
+} // namespace rerun
@@ -0,0 +1,85 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/components/map_tile_source.fbs".
+
+#pragma once
+
+#include "../../datatypes/utf8.hpp"
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <memory>
+#include <string>
+#include <utility>
+
+namespace rerun::blueprint::components {
+    /// **Component**: A custom source of map tiles, used instead of one of the built-in map providers.
+    ///
+    /// This is one of:
+    /// * An XYZ tile URL template, e.g. `https://tiles.example.com/{z}/{x}/{y}.png`.
+    ///   `{z}` is replaced by the zoom level, `{x}` and `{y}` by the tile's column and row.
+    /// * The path to an MBTiles or PMTiles file containing raster tiles.
+    /// * The path to a directory of raster tiles laid out as `{z}/{x}/{y}.png`.
+    ///
+    /// ⚠ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    ///
+    struct MapTileSource {
+        rerun::datatypes::Utf8 source;
+
+      public:
+        MapTileSource() = default;
+
+        MapTileSource(rerun::datatypes::Utf8 source_) : source(std::move(source_)) {}
+
+        MapTileSource& operator=(rerun::datatypes::Utf8 source_) {
+            source = std::move(source_);
+            return *this;
+        }
+
+        MapTileSource(std::string value_) : source(std::move(value_)) {}
+
+        MapTileSource& operator=(std::string value_) {
+            source = std::move(value_);
+            return *this;
+        }
+
+        /// Cast to the underlying Utf8 datatype
+        operator rerun::datatypes::Utf8() const {
+            return source;
+        }
+    };
+} // namespace rerun::blueprint::components
+
+namespace rerun {
+    static_assert(sizeof(rerun::datatypes::Utf8) == sizeof(blueprint::components::MapTileSource));
+
+    /// \private
+    template <>
+    struct Loggable<blueprint::components::MapTileSource> {
+        static constexpr std::string_view ComponentName =
+            "rerun.blueprint.components.MapTileSource";
+
+        /// Returns the arrow data type this type corresponds to.
+        static const std::shared_ptr<arrow::DataType>& arrow_datatype() {
+            return Loggable<rerun::datatypes::Utf8>::arrow_datatype();
+        }
+
+        /// Serializes an array of `rerun::blueprint:: components::MapTileSource` into an arrow array.
+        static Result<std::shared_ptr<arrow::Array>> to_arrow(
+            const blueprint::components::MapTileSource* instances, size_t num_instances
+        ) {
+            if (num_instances == 0) {
+                return Loggable<rerun::datatypes::Utf8>::to_arrow(nullptr, 0);
+            } else if (instances == nullptr) {
+                return rerun::Error(
+                    ErrorCode::UnexpectedNullArgument,
+                    "Passed array instances is null when num_elements> 0."
+                );
+            } else {
+                return Loggable<rerun::datatypes::Utf8>::to_arrow(
+                    &instances->source,
+                    num_instances
+                );
+            }
+        }
+    };
+} // namespace rerun
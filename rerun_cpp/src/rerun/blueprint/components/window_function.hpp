@@ -0,0 +1,70 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/components/window_function.fbs".
+
+#pragma once
+
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <memory>
+
+namespace arrow {
+    /// \private
+    template <typename T>
+    class NumericBuilder;
+
+    class Array;
+    class DataType;
+    class UInt8Type;
+    using UInt8Builder = NumericBuilder<UInt8Type>;
+} // namespace arrow
+
+namespace rerun::blueprint::components {
+    /// **Component**: Tapers a window of samples before its spectrum is computed, trading frequency resolution for less spectral leakage.
+    enum class WindowFunction : uint8_t {
+
+        /// No tapering, all samples are weighted equally.
+        ///
+        /// Gives the sharpest peaks, but the most leakage between frequencies.
+        Rectangular = 1,
+
+        /// Raised cosine that falls to zero at both ends of the window.
+        ///
+        /// A good default for most signals.
+        Hann = 2,
+
+        /// Raised cosine that stays slightly above zero at the ends of the window.
+        ///
+        /// Suppresses the frequencies closest to a peak better than `Hann`, but those far away less so.
+        Hamming = 3,
+
+        /// Sum of cosines with very low leakage, at the cost of wider peaks.
+        Blackman = 4,
+    };
+} // namespace rerun::blueprint::components
+
+namespace rerun {
+    template <typename T>
+    struct Loggable;
+
+    /// \private
+    template <>
+    struct Loggable<blueprint::components::WindowFunction> {
+        static constexpr std::string_view ComponentName =
+            "rerun.blueprint.components.WindowFunction";
+
+        /// Returns the arrow data type this type corresponds to.
+        static const std::shared_ptr<arrow::DataType>& arrow_datatype();
+
+        /// Serializes an array of `rerun::blueprint:: components::WindowFunction` into an arrow array.
+        static Result<std::shared_ptr<arrow::Array>> to_arrow(
+            const blueprint::components::WindowFunction* instances, size_t num_instances
+        );
+
+        /// Fills an arrow array builder with an array of this type.
+        static rerun::Error fill_arrow_array_builder(
+            arrow::UInt8Builder* builder, const blueprint::components::WindowFunction* elements,
+            size_t num_elements
+        );
+    };
+} // namespace rerun
@@ -0,0 +1,21 @@
+#include "geo_polygon.hpp"
+
+// #define EDIT_EXTENSION
+
+namespace rerun {
+    namespace components {
+
+#ifdef EDIT_EXTENSION
+        // <CODEGEN_COPY_TO_HEADER>
+
+        /// Creates a new GeoPolygon object based on [EPSG:4326](https://epsg.io/4326) latitude and longitude (North/East-positive degrees).
+        static GeoPolygon from_lat_lon(Collection<datatypes::DVec2D> lat_lon_) {
+            GeoPolygon polygon;
+            polygon.lat_lon = std::move(lat_lon_);
+            return polygon;
+        }
+
+        // </CODEGEN_COPY_TO_HEADER>
+#endif
+    } // namespace components
+} // namespace rerun
@@ -54,6 +54,11 @@ namespace rerun::components {
             return "image/png";
         }
 
+        /// [OpenEXR image](https://en.wikipedia.org/wiki/OpenEXR): `image/x-exr`.
+        static MediaType exr() {
+            return "image/x-exr";
+        }
+
         // ------------------------------------------------
         // Meshes:
 
@@ -58,6 +58,11 @@ namespace rerun {
                 return "image/png";
             }
 
+            /// [OpenEXR image](https://en.wikipedia.org/wiki/OpenEXR): `image/x-exr`.
+            static MediaType exr() {
+                return "image/x-exr";
+            }
+
             // ------------------------------------------------
             // Meshes:
 
@@ -117,6 +122,8 @@ namespace rerun {
                 return rerun::components::MediaType::jpeg();
             } else if (ext == ".png") {
                 return rerun::components::MediaType::png();
+            } else if (ext == ".exr") {
+                return rerun::components::MediaType::exr();
             }
 
             // 3D Models
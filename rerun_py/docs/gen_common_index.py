@@ -179,6 +179,7 @@ SECTION_TABLE: Final[list[Section]] = [
         class_list=[
             "archetypes.DepthImage",
             "archetypes.Image",
+            "archetypes.EncodedDepthImage",
             "archetypes.EncodedImage",
             "archetypes.SegmentationImage",
         ],
@@ -214,6 +215,7 @@ SECTION_TABLE: Final[list[Section]] = [
             "archetypes.Capsules3D",
             "archetypes.Cylinders3D",
             "archetypes.Ellipsoids3D",
+            "archetypes.GaussianSplats3D",
             "archetypes.LineStrips2D",
             "archetypes.LineStrips3D",
             "archetypes.Mesh3D",
@@ -227,6 +229,7 @@ SECTION_TABLE: Final[list[Section]] = [
         class_list=[
             "archetypes.GeoLineStrings",
             "archetypes.GeoPoints",
+            "archetypes.GeoPolygons",
         ],
         gen_page=False,
     ),
@@ -305,12 +308,15 @@ SECTION_TABLE: Final[list[Section]] = [
             "Tabs",
             "View",
             "BarChartView",
+            "HistogramView",
             "Spatial2DView",
             "Spatial3DView",
+            "SpectrogramView",
             "TensorView",
             "TextDocumentView",
             "TextLogView",
             "TimeSeriesView",
+            "XYPlotView",
             "BlueprintPanel",
             "SelectionPanel",
             "TimePanel",
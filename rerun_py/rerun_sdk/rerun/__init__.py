@@ -80,9 +80,12 @@ from .archetypes import (
     Cylinders3D as Cylinders3D,
     DepthImage as DepthImage,
     Ellipsoids3D as Ellipsoids3D,
+    EncodedDepthImage as EncodedDepthImage,
     EncodedImage as EncodedImage,
+    GaussianSplats3D as GaussianSplats3D,
     GeoLineStrings as GeoLineStrings,
     GeoPoints as GeoPoints,
+    GeoPolygons as GeoPolygons,
     GraphEdges as GraphEdges,
     GraphNodes as GraphNodes,
     Image as Image,
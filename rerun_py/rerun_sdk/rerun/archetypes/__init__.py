@@ -15,9 +15,12 @@ from .clear import Clear
 from .cylinders3d import Cylinders3D
 from .depth_image import DepthImage
 from .ellipsoids3d import Ellipsoids3D
+from .encoded_depth_image import EncodedDepthImage
 from .encoded_image import EncodedImage
+from .gaussian_splats3d import GaussianSplats3D
 from .geo_line_strings import GeoLineStrings
 from .geo_points import GeoPoints
+from .geo_polygons import GeoPolygons
 from .graph_edges import GraphEdges
 from .graph_nodes import GraphNodes
 from .image import Image
@@ -55,9 +58,12 @@ __all__ = [
     "Cylinders3D",
     "DepthImage",
     "Ellipsoids3D",
+    "EncodedDepthImage",
     "EncodedImage",
+    "GaussianSplats3D",
     "GeoLineStrings",
     "GeoPoints",
+    "GeoPolygons",
     "GraphEdges",
     "GraphNodes",
     "Image",
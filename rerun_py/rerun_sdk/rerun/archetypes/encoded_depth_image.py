@@ -0,0 +1,368 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/archetypes/encoded_depth_image.fbs".
+
+# You can extend this class by creating a "EncodedDepthImageExt" class in "encoded_depth_image_ext.py".
+
+from __future__ import annotations
+
+import numpy as np
+import pyarrow as pa
+from attrs import define, field
+
+from .. import components, datatypes
+from .._baseclasses import (
+    Archetype,
+    ComponentColumnList,
+)
+from ..error_utils import catch_and_log_exceptions
+from .encoded_depth_image_ext import EncodedDepthImageExt
+
+__all__ = ["EncodedDepthImage"]
+
+
+@define(str=False, repr=False, init=False)
+class EncodedDepthImage(EncodedDepthImageExt, Archetype):
+    """
+    **Archetype**: A depth image encoded as e.g. a 16-bit PNG or an EXR.
+
+    Each pixel corresponds to a depth value in units specified by [`components.DepthMeter`][rerun.components.DepthMeter].
+    The decoded image must have a single channel.
+
+    Rerun also supports uncompressed depth images with the [`archetypes.DepthImage`][rerun.archetypes.DepthImage].
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    # __init__ can be found in encoded_depth_image_ext.py
+
+    def __attrs_clear__(self) -> None:
+        """Convenience method for calling `__attrs_init__` with all `None`s."""
+        self.__attrs_init__(
+            blob=None,
+            media_type=None,
+            meter=None,
+            colormap=None,
+            depth_range=None,
+            point_fill_ratio=None,
+            draw_order=None,
+        )
+
+    @classmethod
+    def _clear(cls) -> EncodedDepthImage:
+        """Produce an empty EncodedDepthImage, bypassing `__init__`."""
+        inst = cls.__new__(cls)
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def from_fields(
+        cls,
+        *,
+        clear_unset: bool = False,
+        blob: datatypes.BlobLike | None = None,
+        media_type: datatypes.Utf8Like | None = None,
+        meter: datatypes.Float32Like | None = None,
+        colormap: components.ColormapLike | None = None,
+        depth_range: datatypes.Range1DLike | None = None,
+        point_fill_ratio: datatypes.Float32Like | None = None,
+        draw_order: datatypes.Float32Like | None = None,
+    ) -> EncodedDepthImage:
+        """
+        Update only some specific fields of a `EncodedDepthImage`.
+
+        Parameters
+        ----------
+        clear_unset:
+            If true, all unspecified fields will be explicitly cleared.
+        blob:
+            The encoded content of some image file, e.g. a PNG or EXR.
+        media_type:
+            The Media Type of the asset.
+
+            Supported values:
+            * `image/png`
+            * `image/x-exr`
+
+            If omitted, the viewer will try to guess from the data blob.
+            If it cannot guess, it won't be able to render the asset.
+        meter:
+            An optional floating point value that specifies how long a meter is in the native depth units.
+
+            For instance: with a 16-bit PNG, perhaps meter=1000 which would mean you have millimeter precision
+            and a range of up to ~65 meters (2^16 / 1000).
+
+            Note that the only effect on 2D views is the physical depth values shown when hovering the image.
+            In 3D views on the other hand, this affects where the points of the point cloud are placed.
+        colormap:
+            Colormap to use for rendering the depth image.
+
+            If not set, the depth image will be rendered using the Turbo colormap.
+        depth_range:
+            The expected range of depth values.
+
+            This is typically the expected range of valid values.
+            Everything outside of the range is clamped to the range for the purpose of colormpaping.
+            Note that point clouds generated from this image will still display all points, regardless of this range.
+
+            If not specified, the range will be automatically estimated from the data.
+        point_fill_ratio:
+            Scale the radii of the points in the point cloud generated from this image.
+
+            A fill ratio of 1.0 (the default) means that each point is as big as to touch the center of its neighbor
+            if it is at the same depth, leaving no gaps.
+            A fill ratio of 0.5 means that each point touches the edge of its neighbor if it has the same depth.
+
+            TODO(#6744): This applies only to 3D views!
+        draw_order:
+            An optional floating point value that specifies the 2D drawing order, used only if the depth image is shown as a 2D image.
+
+            Objects with higher values are drawn on top of those with lower values.
+            Defaults to `-20.0`.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            kwargs = {
+                "blob": blob,
+                "media_type": media_type,
+                "meter": meter,
+                "colormap": colormap,
+                "depth_range": depth_range,
+                "point_fill_ratio": point_fill_ratio,
+                "draw_order": draw_order,
+            }
+
+            if clear_unset:
+                kwargs = {k: v if v is not None else [] for k, v in kwargs.items()}  # type: ignore[misc]
+
+            inst.__attrs_init__(**kwargs)
+            return inst
+
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def cleared(cls) -> EncodedDepthImage:
+        """Clear all the fields of a `EncodedDepthImage`."""
+        return cls.from_fields(clear_unset=True)
+
+    @classmethod
+    def columns(
+        cls,
+        *,
+        blob: datatypes.BlobArrayLike | None = None,
+        media_type: datatypes.Utf8ArrayLike | None = None,
+        meter: datatypes.Float32ArrayLike | None = None,
+        colormap: components.ColormapArrayLike | None = None,
+        depth_range: datatypes.Range1DArrayLike | None = None,
+        point_fill_ratio: datatypes.Float32ArrayLike | None = None,
+        draw_order: datatypes.Float32ArrayLike | None = None,
+    ) -> ComponentColumnList:
+        """
+        Construct a new column-oriented component bundle.
+
+        This makes it possible to use `rr.send_columns` to send columnar data directly into Rerun.
+
+        The returned columns will be partitioned into unit-length sub-batches by default.
+        Use `ComponentColumnList.partition` to repartition the data as needed.
+
+        Parameters
+        ----------
+        blob:
+            The encoded content of some image file, e.g. a PNG or EXR.
+        media_type:
+            The Media Type of the asset.
+
+            Supported values:
+            * `image/png`
+            * `image/x-exr`
+
+            If omitted, the viewer will try to guess from the data blob.
+            If it cannot guess, it won't be able to render the asset.
+        meter:
+            An optional floating point value that specifies how long a meter is in the native depth units.
+
+            For instance: with a 16-bit PNG, perhaps meter=1000 which would mean you have millimeter precision
+            and a range of up to ~65 meters (2^16 / 1000).
+
+            Note that the only effect on 2D views is the physical depth values shown when hovering the image.
+            In 3D views on the other hand, this affects where the points of the point cloud are placed.
+        colormap:
+            Colormap to use for rendering the depth image.
+
+            If not set, the depth image will be rendered using the Turbo colormap.
+        depth_range:
+            The expected range of depth values.
+
+            This is typically the expected range of valid values.
+            Everything outside of the range is clamped to the range for the purpose of colormpaping.
+            Note that point clouds generated from this image will still display all points, regardless of this range.
+
+            If not specified, the range will be automatically estimated from the data.
+        point_fill_ratio:
+            Scale the radii of the points in the point cloud generated from this image.
+
+            A fill ratio of 1.0 (the default) means that each point is as big as to touch the center of its neighbor
+            if it is at the same depth, leaving no gaps.
+            A fill ratio of 0.5 means that each point touches the edge of its neighbor if it has the same depth.
+
+            TODO(#6744): This applies only to 3D views!
+        draw_order:
+            An optional floating point value that specifies the 2D drawing order, used only if the depth image is shown as a 2D image.
+
+            Objects with higher values are drawn on top of those with lower values.
+            Defaults to `-20.0`.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            inst.__attrs_init__(
+                blob=blob,
+                media_type=media_type,
+                meter=meter,
+                colormap=colormap,
+                depth_range=depth_range,
+                point_fill_ratio=point_fill_ratio,
+                draw_order=draw_order,
+            )
+
+        batches = inst.as_component_batches(include_indicators=False)
+        if len(batches) == 0:
+            return ComponentColumnList([])
+
+        kwargs = {
+            "blob": blob,
+            "media_type": media_type,
+            "meter": meter,
+            "colormap": colormap,
+            "depth_range": depth_range,
+            "point_fill_ratio": point_fill_ratio,
+            "draw_order": draw_order,
+        }
+        columns = []
+
+        for batch in batches:
+            arrow_array = batch.as_arrow_array()
+
+            # For primitive arrays and fixed size list arrays, we infer partition size from the input shape.
+            if pa.types.is_primitive(arrow_array.type) or pa.types.is_fixed_size_list(arrow_array.type):
+                param = kwargs[batch.component_descriptor().archetype_field_name]  # type: ignore[index]
+                shape = np.shape(param)  # type: ignore[arg-type]
+                elem_flat_len = int(np.prod(shape[1:])) if len(shape) > 1 else 1  # type: ignore[redundant-expr,misc]
+
+                if pa.types.is_fixed_size_list(arrow_array.type) and arrow_array.type.list_size == elem_flat_len:
+                    # If the product of the last dimensions of the shape are equal to the size of the fixed size list array,
+                    # we have `num_rows` single element batches (each element is a fixed sized list).
+                    # (This should have been already validated by conversion to the arrow_array)
+                    batch_length = 1
+                else:
+                    batch_length = shape[1] if len(shape) > 1 else 1  # type: ignore[redundant-expr,misc]
+
+                num_rows = shape[0] if len(shape) >= 1 else 1  # type: ignore[redundant-expr,misc]
+                sizes = batch_length * np.ones(num_rows)
+            else:
+                # For non-primitive types, default to partitioning each element separately.
+                sizes = np.ones(len(arrow_array))
+
+            columns.append(batch.partition(sizes))
+
+        indicator_column = cls.indicator().partition(np.zeros(len(sizes)))
+        return ComponentColumnList([indicator_column] + columns)
+
+    blob: components.BlobBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.BlobBatch._converter,  # type: ignore[misc]
+    )
+    # The encoded content of some image file, e.g. a PNG or EXR.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    media_type: components.MediaTypeBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.MediaTypeBatch._converter,  # type: ignore[misc]
+    )
+    # The Media Type of the asset.
+    #
+    # Supported values:
+    # * `image/png`
+    # * `image/x-exr`
+    #
+    # If omitted, the viewer will try to guess from the data blob.
+    # If it cannot guess, it won't be able to render the asset.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    meter: components.DepthMeterBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.DepthMeterBatch._converter,  # type: ignore[misc]
+    )
+    # An optional floating point value that specifies how long a meter is in the native depth units.
+    #
+    # For instance: with a 16-bit PNG, perhaps meter=1000 which would mean you have millimeter precision
+    # and a range of up to ~65 meters (2^16 / 1000).
+    #
+    # Note that the only effect on 2D views is the physical depth values shown when hovering the image.
+    # In 3D views on the other hand, this affects where the points of the point cloud are placed.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    colormap: components.ColormapBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.ColormapBatch._converter,  # type: ignore[misc]
+    )
+    # Colormap to use for rendering the depth image.
+    #
+    # If not set, the depth image will be rendered using the Turbo colormap.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    depth_range: components.ValueRangeBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.ValueRangeBatch._converter,  # type: ignore[misc]
+    )
+    # The expected range of depth values.
+    #
+    # This is typically the expected range of valid values.
+    # Everything outside of the range is clamped to the range for the purpose of colormpaping.
+    # Note that point clouds generated from this image will still display all points, regardless of this range.
+    #
+    # If not specified, the range will be automatically estimated from the data.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    point_fill_ratio: components.FillRatioBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.FillRatioBatch._converter,  # type: ignore[misc]
+    )
+    # Scale the radii of the points in the point cloud generated from this image.
+    #
+    # A fill ratio of 1.0 (the default) means that each point is as big as to touch the center of its neighbor
+    # if it is at the same depth, leaving no gaps.
+    # A fill ratio of 0.5 means that each point touches the edge of its neighbor if it has the same depth.
+    #
+    # TODO(#6744): This applies only to 3D views!
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    draw_order: components.DrawOrderBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.DrawOrderBatch._converter,  # type: ignore[misc]
+    )
+    # An optional floating point value that specifies the 2D drawing order, used only if the depth image is shown as a 2D image.
+    #
+    # Objects with higher values are drawn on top of those with lower values.
+    # Defaults to `-20.0`.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    __str__ = Archetype.__str__
+    __repr__ = Archetype.__repr__  # type: ignore[assignment]
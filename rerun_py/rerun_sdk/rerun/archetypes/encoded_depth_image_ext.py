@@ -0,0 +1,97 @@
+from __future__ import annotations
+
+import pathlib
+from typing import IO, TYPE_CHECKING, Any
+
+from .. import datatypes
+from ..error_utils import catch_and_log_exceptions
+
+if TYPE_CHECKING:
+    from ..components import ColormapLike
+    from ..datatypes import Float32Like, Range1DLike
+
+
+class EncodedDepthImageExt:
+    """Extension for [EncodedDepthImage][rerun.archetypes.EncodedDepthImage]."""
+
+    def __init__(
+        self: Any,
+        *,
+        path: str | pathlib.Path | None = None,
+        contents: bytes | IO[bytes] | datatypes.BlobLike | None = None,
+        media_type: datatypes.Utf8Like | None = None,
+        meter: Float32Like | None = None,
+        colormap: ColormapLike | None = None,
+        depth_range: Range1DLike | None = None,
+        point_fill_ratio: Float32Like | None = None,
+        draw_order: Float32Like | None = None,
+    ) -> None:
+        """
+        Create a new instance of the EncodedDepthImage archetype.
+
+        Parameters
+        ----------
+        path:
+            A path to an file stored on the local filesystem. Mutually
+            exclusive with `contents`.
+
+        contents:
+            The contents of the file. Can be a BufferedReader, BytesIO, or
+            bytes. Mutually exclusive with `path`.
+
+        media_type:
+            The Media Type of the asset.
+
+            For instance:
+             * `image/png`
+             * `image/x-exr`
+
+            If omitted, it will be guessed from the `path` (if any),
+            or the viewer will try to guess from the contents (magic header).
+
+        meter:
+            How long a meter is in the native depth units, e.g. 1000 for a
+            16-bit PNG storing millimeters.
+
+        colormap:
+            Colormap to use for rendering the depth image.
+
+        depth_range:
+            The expected range of depth values.
+
+        point_fill_ratio:
+            Scale the radii of the points in the point cloud generated from this image.
+
+        draw_order:
+            An optional floating point value that specifies the 2D drawing
+            order. Objects with higher values are drawn on top of those with
+            lower values.
+
+        """
+
+        from ..components import MediaType
+
+        with catch_and_log_exceptions(context=self.__class__.__name__):
+            if (path is None) == (contents is None):
+                raise ValueError("Must provide exactly one of 'path' or 'contents'")
+
+            if path is None:
+                blob = contents
+            else:
+                blob = pathlib.Path(path).read_bytes()
+
+                if media_type is None:
+                    media_type = MediaType.guess_from_path(path)
+
+            self.__attrs_init__(
+                blob=blob,
+                media_type=media_type,
+                meter=meter,
+                colormap=colormap,
+                depth_range=depth_range,
+                point_fill_ratio=point_fill_ratio,
+                draw_order=draw_order,
+            )
+            return
+
+        self.__attrs_clear__()
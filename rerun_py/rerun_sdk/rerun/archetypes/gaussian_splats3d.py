@@ -0,0 +1,303 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/archetypes/gaussian_splats3d.fbs".
+
+# You can extend this class by creating a "GaussianSplats3DExt" class in "gaussian_splats3d_ext.py".
+
+from __future__ import annotations
+
+from typing import Any
+
+import numpy as np
+import pyarrow as pa
+from attrs import define, field
+
+from .. import components, datatypes
+from .._baseclasses import (
+    Archetype,
+    ComponentColumnList,
+)
+from ..error_utils import catch_and_log_exceptions
+
+__all__ = ["GaussianSplats3D"]
+
+
+@define(str=False, repr=False, init=False)
+class GaussianSplats3D(Archetype):
+    """
+    **Archetype**: A cloud of 3D Gaussian splats, as produced by Gaussian splatting reconstructions.
+
+    Each splat is an anisotropic 3D Gaussian, defined by its center, the standard deviations
+    along its three local axes and the rotation of those axes.
+    Together, scales and rotations describe the covariance of the Gaussian.
+
+    Splats are rendered as camera-facing quads, sorted back-to-front and alpha-blended.
+    Unlike [`archetypes.Ellipsoids3D`][rerun.archetypes.Ellipsoids3D], this scales to millions of instances.
+
+    Spherical harmonics are not supported yet: only the view-independent base color is shown.
+    Loaders convert the zeroth-order coefficients to [`components.Color`][rerun.components.Color].
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    def __init__(
+        self: Any,
+        centers: datatypes.Vec3DArrayLike,
+        scales: datatypes.Vec3DArrayLike,
+        *,
+        quaternions: datatypes.QuaternionArrayLike | None = None,
+        colors: datatypes.Rgba32ArrayLike | None = None,
+        opacities: datatypes.Float32ArrayLike | None = None,
+    ) -> None:
+        """
+        Create a new instance of the GaussianSplats3D archetype.
+
+        Parameters
+        ----------
+        centers:
+            The center of each splat.
+        scales:
+            The standard deviation of each splat along its three local axes.
+        quaternions:
+            The orientation of each splat's local axes.
+
+            If not specified, the local axes align with the axes of the entity's coordinate system.
+        colors:
+            The base color of each splat.
+
+            The alpha channel is ignored, use [`components.Opacity`][rerun.components.Opacity] instead.
+        opacities:
+            The opacity of each splat at its center, in the range `[0, 1]`.
+
+            If not specified, splats are fully opaque at their centers.
+
+        """
+
+        # You can define your own __init__ function as a member of GaussianSplats3DExt in gaussian_splats3d_ext.py
+        with catch_and_log_exceptions(context=self.__class__.__name__):
+            self.__attrs_init__(
+                centers=centers, scales=scales, quaternions=quaternions, colors=colors, opacities=opacities
+            )
+            return
+        self.__attrs_clear__()
+
+    def __attrs_clear__(self) -> None:
+        """Convenience method for calling `__attrs_init__` with all `None`s."""
+        self.__attrs_init__(
+            centers=None,
+            scales=None,
+            quaternions=None,
+            colors=None,
+            opacities=None,
+        )
+
+    @classmethod
+    def _clear(cls) -> GaussianSplats3D:
+        """Produce an empty GaussianSplats3D, bypassing `__init__`."""
+        inst = cls.__new__(cls)
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def from_fields(
+        cls,
+        *,
+        clear_unset: bool = False,
+        centers: datatypes.Vec3DArrayLike | None = None,
+        scales: datatypes.Vec3DArrayLike | None = None,
+        quaternions: datatypes.QuaternionArrayLike | None = None,
+        colors: datatypes.Rgba32ArrayLike | None = None,
+        opacities: datatypes.Float32ArrayLike | None = None,
+    ) -> GaussianSplats3D:
+        """
+        Update only some specific fields of a `GaussianSplats3D`.
+
+        Parameters
+        ----------
+        clear_unset:
+            If true, all unspecified fields will be explicitly cleared.
+        centers:
+            The center of each splat.
+        scales:
+            The standard deviation of each splat along its three local axes.
+        quaternions:
+            The orientation of each splat's local axes.
+
+            If not specified, the local axes align with the axes of the entity's coordinate system.
+        colors:
+            The base color of each splat.
+
+            The alpha channel is ignored, use [`components.Opacity`][rerun.components.Opacity] instead.
+        opacities:
+            The opacity of each splat at its center, in the range `[0, 1]`.
+
+            If not specified, splats are fully opaque at their centers.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            kwargs = {
+                "centers": centers,
+                "scales": scales,
+                "quaternions": quaternions,
+                "colors": colors,
+                "opacities": opacities,
+            }
+
+            if clear_unset:
+                kwargs = {k: v if v is not None else [] for k, v in kwargs.items()}  # type: ignore[misc]
+
+            inst.__attrs_init__(**kwargs)
+            return inst
+
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def cleared(cls) -> GaussianSplats3D:
+        """Clear all the fields of a `GaussianSplats3D`."""
+        return cls.from_fields(clear_unset=True)
+
+    @classmethod
+    def columns(
+        cls,
+        *,
+        centers: datatypes.Vec3DArrayLike | None = None,
+        scales: datatypes.Vec3DArrayLike | None = None,
+        quaternions: datatypes.QuaternionArrayLike | None = None,
+        colors: datatypes.Rgba32ArrayLike | None = None,
+        opacities: datatypes.Float32ArrayLike | None = None,
+    ) -> ComponentColumnList:
+        """
+        Construct a new column-oriented component bundle.
+
+        This makes it possible to use `rr.send_columns` to send columnar data directly into Rerun.
+
+        The returned columns will be partitioned into unit-length sub-batches by default.
+        Use `ComponentColumnList.partition` to repartition the data as needed.
+
+        Parameters
+        ----------
+        centers:
+            The center of each splat.
+        scales:
+            The standard deviation of each splat along its three local axes.
+        quaternions:
+            The orientation of each splat's local axes.
+
+            If not specified, the local axes align with the axes of the entity's coordinate system.
+        colors:
+            The base color of each splat.
+
+            The alpha channel is ignored, use [`components.Opacity`][rerun.components.Opacity] instead.
+        opacities:
+            The opacity of each splat at its center, in the range `[0, 1]`.
+
+            If not specified, splats are fully opaque at their centers.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            inst.__attrs_init__(
+                centers=centers,
+                scales=scales,
+                quaternions=quaternions,
+                colors=colors,
+                opacities=opacities,
+            )
+
+        batches = inst.as_component_batches(include_indicators=False)
+        if len(batches) == 0:
+            return ComponentColumnList([])
+
+        kwargs = {
+            "centers": centers,
+            "scales": scales,
+            "quaternions": quaternions,
+            "colors": colors,
+            "opacities": opacities,
+        }
+        columns = []
+
+        for batch in batches:
+            arrow_array = batch.as_arrow_array()
+
+            # For primitive arrays and fixed size list arrays, we infer partition size from the input shape.
+            if pa.types.is_primitive(arrow_array.type) or pa.types.is_fixed_size_list(arrow_array.type):
+                param = kwargs[batch.component_descriptor().archetype_field_name]  # type: ignore[index]
+                shape = np.shape(param)  # type: ignore[arg-type]
+                elem_flat_len = int(np.prod(shape[1:])) if len(shape) > 1 else 1  # type: ignore[redundant-expr,misc]
+
+                if pa.types.is_fixed_size_list(arrow_array.type) and arrow_array.type.list_size == elem_flat_len:
+                    # If the product of the last dimensions of the shape are equal to the size of the fixed size list array,
+                    # we have `num_rows` single element batches (each element is a fixed sized list).
+                    # (This should have been already validated by conversion to the arrow_array)
+                    batch_length = 1
+                else:
+                    batch_length = shape[1] if len(shape) > 1 else 1  # type: ignore[redundant-expr,misc]
+
+                num_rows = shape[0] if len(shape) >= 1 else 1  # type: ignore[redundant-expr,misc]
+                sizes = batch_length * np.ones(num_rows)
+            else:
+                # For non-primitive types, default to partitioning each element separately.
+                sizes = np.ones(len(arrow_array))
+
+            columns.append(batch.partition(sizes))
+
+        indicator_column = cls.indicator().partition(np.zeros(len(sizes)))
+        return ComponentColumnList([indicator_column] + columns)
+
+    centers: components.Position3DBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.Position3DBatch._converter,  # type: ignore[misc]
+    )
+    # The center of each splat.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    scales: components.Scale3DBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.Scale3DBatch._converter,  # type: ignore[misc]
+    )
+    # The standard deviation of each splat along its three local axes.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    quaternions: components.RotationQuatBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.RotationQuatBatch._converter,  # type: ignore[misc]
+    )
+    # The orientation of each splat's local axes.
+    #
+    # If not specified, the local axes align with the axes of the entity's coordinate system.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    colors: components.ColorBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.ColorBatch._converter,  # type: ignore[misc]
+    )
+    # The base color of each splat.
+    #
+    # The alpha channel is ignored, use [`components.Opacity`][rerun.components.Opacity] instead.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    opacities: components.OpacityBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.OpacityBatch._converter,  # type: ignore[misc]
+    )
+    # The opacity of each splat at its center, in the range `[0, 1]`.
+    #
+    # If not specified, splats are fully opaque at their centers.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    __str__ = Archetype.__str__
+    __repr__ = Archetype.__repr__  # type: ignore[assignment]
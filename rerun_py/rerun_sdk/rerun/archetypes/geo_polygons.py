@@ -0,0 +1,246 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/archetypes/geo_polygons.fbs".
+
+# You can extend this class by creating a "GeoPolygonsExt" class in "geo_polygons_ext.py".
+
+from __future__ import annotations
+
+import numpy as np
+import pyarrow as pa
+from attrs import define, field
+
+from .. import components, datatypes
+from .._baseclasses import (
+    Archetype,
+    ComponentColumnList,
+)
+from ..error_utils import catch_and_log_exceptions
+from .geo_polygons_ext import GeoPolygonsExt
+
+__all__ = ["GeoPolygons"]
+
+
+@define(str=False, repr=False, init=False)
+class GeoPolygons(GeoPolygonsExt, Archetype):
+    """
+    **Archetype**: Geospatial polygons with positions expressed in [EPSG:4326](https://epsg.io/4326) altitude and longitude (North/East-positive degrees), and optional fill and outline colors.
+
+    Useful for geofences, coverage areas and other regions on a map.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    # __init__ can be found in geo_polygons_ext.py
+
+    def __attrs_clear__(self) -> None:
+        """Convenience method for calling `__attrs_init__` with all `None`s."""
+        self.__attrs_init__(
+            polygons=None,
+            radii=None,
+            colors=None,
+            fill_colors=None,
+        )
+
+    @classmethod
+    def _clear(cls) -> GeoPolygons:
+        """Produce an empty GeoPolygons, bypassing `__init__`."""
+        inst = cls.__new__(cls)
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def from_fields(
+        cls,
+        *,
+        clear_unset: bool = False,
+        polygons: components.GeoPolygonArrayLike | None = None,
+        radii: datatypes.Float32ArrayLike | None = None,
+        colors: datatypes.Rgba32ArrayLike | None = None,
+        fill_colors: datatypes.Rgba32ArrayLike | None = None,
+    ) -> GeoPolygons:
+        """
+        Update only some specific fields of a `GeoPolygons`.
+
+        Parameters
+        ----------
+        clear_unset:
+            If true, all unspecified fields will be explicitly cleared.
+        polygons:
+            The polygons, expressed in [EPSG:4326](https://epsg.io/4326) coordinates (North/East-positive degrees).
+        radii:
+            Optional radii for the outlines of the polygons.
+
+            *Note*: scene units radiii are interpreted as meters. Currently, the display scale only considers the latitude of
+            the first vertex of each polygon (see [this issue](https://github.com/rerun-io/rerun/issues/8013)).
+        colors:
+            Optional colors for the outlines of the polygons.
+
+            The colors are interpreted as RGB or RGBA in sRGB gamma-space,
+            As either 0-1 floats or 0-255 integers, with separate alpha.
+        fill_colors:
+            Optional colors for the area enclosed by the polygons.
+
+            If not specified, the outline color is used with reduced opacity.
+
+            The colors are interpreted as RGB or RGBA in sRGB gamma-space,
+            As either 0-1 floats or 0-255 integers, with separate alpha.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            kwargs = {
+                "polygons": polygons,
+                "radii": radii,
+                "colors": colors,
+                "fill_colors": fill_colors,
+            }
+
+            if clear_unset:
+                kwargs = {k: v if v is not None else [] for k, v in kwargs.items()}  # type: ignore[misc]
+
+            inst.__attrs_init__(**kwargs)
+            return inst
+
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def cleared(cls) -> GeoPolygons:
+        """Clear all the fields of a `GeoPolygons`."""
+        return cls.from_fields(clear_unset=True)
+
+    @classmethod
+    def columns(
+        cls,
+        *,
+        polygons: components.GeoPolygonArrayLike | None = None,
+        radii: datatypes.Float32ArrayLike | None = None,
+        colors: datatypes.Rgba32ArrayLike | None = None,
+        fill_colors: datatypes.Rgba32ArrayLike | None = None,
+    ) -> ComponentColumnList:
+        """
+        Construct a new column-oriented component bundle.
+
+        This makes it possible to use `rr.send_columns` to send columnar data directly into Rerun.
+
+        The returned columns will be partitioned into unit-length sub-batches by default.
+        Use `ComponentColumnList.partition` to repartition the data as needed.
+
+        Parameters
+        ----------
+        polygons:
+            The polygons, expressed in [EPSG:4326](https://epsg.io/4326) coordinates (North/East-positive degrees).
+        radii:
+            Optional radii for the outlines of the polygons.
+
+            *Note*: scene units radiii are interpreted as meters. Currently, the display scale only considers the latitude of
+            the first vertex of each polygon (see [this issue](https://github.com/rerun-io/rerun/issues/8013)).
+        colors:
+            Optional colors for the outlines of the polygons.
+
+            The colors are interpreted as RGB or RGBA in sRGB gamma-space,
+            As either 0-1 floats or 0-255 integers, with separate alpha.
+        fill_colors:
+            Optional colors for the area enclosed by the polygons.
+
+            If not specified, the outline color is used with reduced opacity.
+
+            The colors are interpreted as RGB or RGBA in sRGB gamma-space,
+            As either 0-1 floats or 0-255 integers, with separate alpha.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            inst.__attrs_init__(
+                polygons=polygons,
+                radii=radii,
+                colors=colors,
+                fill_colors=fill_colors,
+            )
+
+        batches = inst.as_component_batches(include_indicators=False)
+        if len(batches) == 0:
+            return ComponentColumnList([])
+
+        kwargs = {"polygons": polygons, "radii": radii, "colors": colors, "fill_colors": fill_colors}
+        columns = []
+
+        for batch in batches:
+            arrow_array = batch.as_arrow_array()
+
+            # For primitive arrays and fixed size list arrays, we infer partition size from the input shape.
+            if pa.types.is_primitive(arrow_array.type) or pa.types.is_fixed_size_list(arrow_array.type):
+                param = kwargs[batch.component_descriptor().archetype_field_name]  # type: ignore[index]
+                shape = np.shape(param)  # type: ignore[arg-type]
+                elem_flat_len = int(np.prod(shape[1:])) if len(shape) > 1 else 1  # type: ignore[redundant-expr,misc]
+
+                if pa.types.is_fixed_size_list(arrow_array.type) and arrow_array.type.list_size == elem_flat_len:
+                    # If the product of the last dimensions of the shape are equal to the size of the fixed size list array,
+                    # we have `num_rows` single element batches (each element is a fixed sized list).
+                    # (This should have been already validated by conversion to the arrow_array)
+                    batch_length = 1
+                else:
+                    batch_length = shape[1] if len(shape) > 1 else 1  # type: ignore[redundant-expr,misc]
+
+                num_rows = shape[0] if len(shape) >= 1 else 1  # type: ignore[redundant-expr,misc]
+                sizes = batch_length * np.ones(num_rows)
+            else:
+                # For non-primitive types, default to partitioning each element separately.
+                sizes = np.ones(len(arrow_array))
+
+            columns.append(batch.partition(sizes))
+
+        indicator_column = cls.indicator().partition(np.zeros(len(sizes)))
+        return ComponentColumnList([indicator_column] + columns)
+
+    polygons: components.GeoPolygonBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.GeoPolygonBatch._converter,  # type: ignore[misc]
+    )
+    # The polygons, expressed in [EPSG:4326](https://epsg.io/4326) coordinates (North/East-positive degrees).
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    radii: components.RadiusBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.RadiusBatch._converter,  # type: ignore[misc]
+    )
+    # Optional radii for the outlines of the polygons.
+    #
+    # *Note*: scene units radiii are interpreted as meters. Currently, the display scale only considers the latitude of
+    # the first vertex of each polygon (see [this issue](https://github.com/rerun-io/rerun/issues/8013)).
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    colors: components.ColorBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.ColorBatch._converter,  # type: ignore[misc]
+    )
+    # Optional colors for the outlines of the polygons.
+    #
+    # The colors are interpreted as RGB or RGBA in sRGB gamma-space,
+    # As either 0-1 floats or 0-255 integers, with separate alpha.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    fill_colors: components.ColorBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.ColorBatch._converter,  # type: ignore[misc]
+    )
+    # Optional colors for the area enclosed by the polygons.
+    #
+    # If not specified, the outline color is used with reduced opacity.
+    #
+    # The colors are interpreted as RGB or RGBA in sRGB gamma-space,
+    # As either 0-1 floats or 0-255 integers, with separate alpha.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    __str__ = Archetype.__str__
+    __repr__ = Archetype.__repr__  # type: ignore[assignment]
@@ -0,0 +1,45 @@
+from __future__ import annotations
+
+from typing import Any
+
+from .. import components, datatypes
+from ..error_utils import catch_and_log_exceptions
+
+
+class GeoPolygonsExt:
+    """Extension for [GeoPolygons][rerun.archetypes.GeoPolygons]."""
+
+    def __init__(
+        self: Any,
+        *,
+        lat_lon: components.GeoPolygonArrayLike,
+        radii: datatypes.Float32ArrayLike | None = None,
+        colors: datatypes.Rgba32ArrayLike | None = None,
+        fill_colors: datatypes.Rgba32ArrayLike | None = None,
+    ) -> None:
+        """
+        Create a new instance of the GeoPolygons archetype.
+
+        Parameters
+        ----------
+        lat_lon:
+            The polygons, expressed in [EPSG:4326](https://epsg.io/4326) coordinates (North/East-positive degrees).
+        radii:
+            Optional radii for the outlines of the polygons.
+        colors:
+            Optional colors for the outlines of the polygons.
+
+            The colors are interpreted as RGB or RGBA in sRGB gamma-space,
+            As either 0-1 floats or 0-255 integers, with separate alpha.
+        fill_colors:
+            Optional colors for the area enclosed by the polygons.
+
+            Defaults to the outline color with reduced opacity.
+
+        """
+
+        # You can define your own __init__ function as a member of GeoPolygonsExt in geo_polygons_ext.py
+        with catch_and_log_exceptions(context=self.__class__.__name__):
+            self.__attrs_init__(polygons=lat_lon, radii=radii, colors=colors, fill_colors=fill_colors)
+            return
+        self.__attrs_clear__()
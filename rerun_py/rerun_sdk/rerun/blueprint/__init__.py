@@ -30,20 +30,28 @@ from .api import (
 )
 from .archetypes import (
     Background as Background,
+    CameraPath as CameraPath,
     EntityBehavior as EntityBehavior,
+    HistogramBins as HistogramBins,
+    MapFollow as MapFollow,
     PlotLegend as PlotLegend,
     ScalarAxis as ScalarAxis,
+    SpectrogramWindow as SpectrogramWindow,
     TensorScalarMapping as TensorScalarMapping,
     TensorSliceSelection as TensorSliceSelection,
+    TensorVolume as TensorVolume,
     VisibleTimeRanges as VisibleTimeRanges,
     VisualBounds2D as VisualBounds2D,
     VisualizerOverrides as VisualizerOverrides,
+    XAxis as XAxis,
 )
 from .components import (
     BackgroundKind as BackgroundKind,
     Corner2D as Corner2D,
     LockRangeDuringZoom as LockRangeDuringZoom,
+    MapFollowMode as MapFollowMode,
     MapProvider as MapProvider,
+    WindowFunction as WindowFunction,
 )
 from .containers import (
     Grid as Grid,
@@ -55,11 +63,14 @@ from .views import (
     BarChartView as BarChartView,
     DataframeView as DataframeView,
     GraphView as GraphView,
+    HistogramView as HistogramView,
     MapView as MapView,
     Spatial2DView as Spatial2DView,
     Spatial3DView as Spatial3DView,
+    SpectrogramView as SpectrogramView,
     TensorView as TensorView,
     TextDocumentView as TextDocumentView,
     TextLogView as TextLogView,
     TimeSeriesView as TimeSeriesView,
+    XYPlotView as XYPlotView,
 )
@@ -25,12 +25,15 @@ class View:
     Consider using one of the subclasses instead of this class directly:
 
     - [rerun.blueprint.BarChartView][]
+    - [rerun.blueprint.HistogramView][]
     - [rerun.blueprint.Spatial2DView][]
     - [rerun.blueprint.Spatial3DView][]
+    - [rerun.blueprint.SpectrogramView][]
     - [rerun.blueprint.TensorView][]
     - [rerun.blueprint.TextDocumentView][]
     - [rerun.blueprint.TextLogView][]
     - [rerun.blueprint.TimeSeriesView][]
+    - [rerun.blueprint.XYPlotView][]
 
     These are ergonomic helpers on top of [rerun.blueprint.archetypes.ViewBlueprint][].
     """
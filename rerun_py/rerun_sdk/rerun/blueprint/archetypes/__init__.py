@@ -3,6 +3,7 @@
 from __future__ import annotations
 
 from .background import Background
+from .camera_path import CameraPath
 from .container_blueprint import ContainerBlueprint
 from .dataframe_query import DataframeQuery
 from .entity_behavior import EntityBehavior
@@ -11,26 +12,33 @@ from .force_collision_radius import ForceCollisionRadius
 from .force_link import ForceLink
 from .force_many_body import ForceManyBody
 from .force_position import ForcePosition
+from .histogram_bins import HistogramBins
 from .line_grid3d import LineGrid3D
 from .map_background import MapBackground
+from .map_follow import MapFollow
 from .map_zoom import MapZoom
 from .near_clip_plane import NearClipPlane
 from .panel_blueprint import PanelBlueprint
 from .plot_legend import PlotLegend
 from .scalar_axis import ScalarAxis
+from .spectrogram_window import SpectrogramWindow
 from .tensor_scalar_mapping import TensorScalarMapping
 from .tensor_slice_selection import TensorSliceSelection
 from .tensor_view_fit import TensorViewFit
+from .tensor_volume import TensorVolume
 from .time_axis import TimeAxis
+from .timeline_bookmarks import TimelineBookmarks
 from .view_blueprint import ViewBlueprint
 from .view_contents import ViewContents
 from .viewport_blueprint import ViewportBlueprint
 from .visible_time_ranges import VisibleTimeRanges
 from .visual_bounds2d import VisualBounds2D
 from .visualizer_overrides import VisualizerOverrides
+from .x_axis import XAxis
 
 __all__ = [
     "Background",
+    "CameraPath",
     "ContainerBlueprint",
     "DataframeQuery",
     "EntityBehavior",
@@ -39,21 +47,27 @@ __all__ = [
     "ForceLink",
     "ForceManyBody",
     "ForcePosition",
+    "HistogramBins",
     "LineGrid3D",
     "MapBackground",
+    "MapFollow",
     "MapZoom",
     "NearClipPlane",
     "PanelBlueprint",
     "PlotLegend",
     "ScalarAxis",
+    "SpectrogramWindow",
     "TensorScalarMapping",
     "TensorSliceSelection",
     "TensorViewFit",
+    "TensorVolume",
     "TimeAxis",
+    "TimelineBookmarks",
     "ViewBlueprint",
     "ViewContents",
     "ViewportBlueprint",
     "VisibleTimeRanges",
     "VisualBounds2D",
     "VisualizerOverrides",
+    "XAxis",
 ]
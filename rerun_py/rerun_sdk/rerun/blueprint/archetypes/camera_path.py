@@ -0,0 +1,217 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/camera_path.fbs".
+
+# You can extend this class by creating a "CameraPathExt" class in "camera_path_ext.py".
+
+from __future__ import annotations
+
+from typing import Any
+
+from attrs import define, field
+
+from ... import components, datatypes
+from ..._baseclasses import (
+    Archetype,
+)
+from ...blueprint import components as blueprint_components
+from ...error_utils import catch_and_log_exceptions
+
+__all__ = ["CameraPath"]
+
+
+@define(str=False, repr=False, init=False)
+class CameraPath(Archetype):
+    """
+    **Archetype**: A keyframed path for the eye of a 3D view.
+
+    When enabled, the eye is interpolated between the keyframes as the time cursor moves along
+    the timeline, both when scrubbing and during playback.
+    Before the first and after the last keyframe the eye holds still.
+
+    The n-th keyframe is at `times[n]`, where the eye is at `positions[n]` looking at `look_targets[n]`.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    def __init__(
+        self: Any,
+        *,
+        enabled: datatypes.BoolLike | None = None,
+        timeline: datatypes.Utf8Like | None = None,
+        times: datatypes.TimeIntArrayLike | None = None,
+        positions: datatypes.Vec3DArrayLike | None = None,
+        look_targets: datatypes.Vec3DArrayLike | None = None,
+        chase_entity: datatypes.EntityPathLike | None = None,
+    ) -> None:
+        """
+        Create a new instance of the CameraPath archetype.
+
+        Parameters
+        ----------
+        enabled:
+            Whether the eye of the view follows the path. Defaults to true.
+        timeline:
+            The timeline along which the keyframes are placed.
+        times:
+            The time of each keyframe.
+        positions:
+            Where the eye is at each keyframe.
+        look_targets:
+            What the eye looks at, at each keyframe.
+        chase_entity:
+            An entity the path is attached to, e.g. a vehicle followed by a chase cam.
+
+            If set, positions and look targets are in the space of this entity rather than that of the view.
+
+        """
+
+        # You can define your own __init__ function as a member of CameraPathExt in camera_path_ext.py
+        with catch_and_log_exceptions(context=self.__class__.__name__):
+            self.__attrs_init__(
+                enabled=enabled,
+                timeline=timeline,
+                times=times,
+                positions=positions,
+                look_targets=look_targets,
+                chase_entity=chase_entity,
+            )
+            return
+        self.__attrs_clear__()
+
+    def __attrs_clear__(self) -> None:
+        """Convenience method for calling `__attrs_init__` with all `None`s."""
+        self.__attrs_init__(
+            enabled=None,
+            timeline=None,
+            times=None,
+            positions=None,
+            look_targets=None,
+            chase_entity=None,
+        )
+
+    @classmethod
+    def _clear(cls) -> CameraPath:
+        """Produce an empty CameraPath, bypassing `__init__`."""
+        inst = cls.__new__(cls)
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def from_fields(
+        cls,
+        *,
+        clear_unset: bool = False,
+        enabled: datatypes.BoolLike | None = None,
+        timeline: datatypes.Utf8Like | None = None,
+        times: datatypes.TimeIntArrayLike | None = None,
+        positions: datatypes.Vec3DArrayLike | None = None,
+        look_targets: datatypes.Vec3DArrayLike | None = None,
+        chase_entity: datatypes.EntityPathLike | None = None,
+    ) -> CameraPath:
+        """
+        Update only some specific fields of a `CameraPath`.
+
+        Parameters
+        ----------
+        clear_unset:
+            If true, all unspecified fields will be explicitly cleared.
+        enabled:
+            Whether the eye of the view follows the path. Defaults to true.
+        timeline:
+            The timeline along which the keyframes are placed.
+        times:
+            The time of each keyframe.
+        positions:
+            Where the eye is at each keyframe.
+        look_targets:
+            What the eye looks at, at each keyframe.
+        chase_entity:
+            An entity the path is attached to, e.g. a vehicle followed by a chase cam.
+
+            If set, positions and look targets are in the space of this entity rather than that of the view.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            kwargs = {
+                "enabled": enabled,
+                "timeline": timeline,
+                "times": times,
+                "positions": positions,
+                "look_targets": look_targets,
+                "chase_entity": chase_entity,
+            }
+
+            if clear_unset:
+                kwargs = {k: v if v is not None else [] for k, v in kwargs.items()}  # type: ignore[misc]
+
+            inst.__attrs_init__(**kwargs)
+            return inst
+
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def cleared(cls) -> CameraPath:
+        """Clear all the fields of a `CameraPath`."""
+        return cls.from_fields(clear_unset=True)
+
+    enabled: blueprint_components.EnabledBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=blueprint_components.EnabledBatch._converter,  # type: ignore[misc]
+    )
+    # Whether the eye of the view follows the path. Defaults to true.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    timeline: blueprint_components.TimelineNameBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=blueprint_components.TimelineNameBatch._converter,  # type: ignore[misc]
+    )
+    # The timeline along which the keyframes are placed.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    times: blueprint_components.KeyframeTimeBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=blueprint_components.KeyframeTimeBatch._converter,  # type: ignore[misc]
+    )
+    # The time of each keyframe.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    positions: components.Position3DBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.Position3DBatch._converter,  # type: ignore[misc]
+    )
+    # Where the eye is at each keyframe.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    look_targets: components.Position3DBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.Position3DBatch._converter,  # type: ignore[misc]
+    )
+    # What the eye looks at, at each keyframe.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    chase_entity: components.EntityPathBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.EntityPathBatch._converter,  # type: ignore[misc]
+    )
+    # An entity the path is attached to, e.g. a vehicle followed by a chase cam.
+    #
+    # If set, positions and look targets are in the space of this entity rather than that of the view.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    __str__ = Archetype.__str__
+    __repr__ = Archetype.__repr__  # type: ignore[assignment]
@@ -0,0 +1,168 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/histogram_bins.fbs".
+
+# You can extend this class by creating a "HistogramBinsExt" class in "histogram_bins_ext.py".
+
+from __future__ import annotations
+
+from typing import Any
+
+from attrs import define, field
+
+from ... import components, datatypes
+from ..._baseclasses import (
+    Archetype,
+)
+from ...blueprint import components as blueprint_components
+from ...error_utils import catch_and_log_exceptions
+
+__all__ = ["HistogramBins"]
+
+
+@define(str=False, repr=False, init=False)
+class HistogramBins(Archetype):
+    """
+    **Archetype**: Configuration for how a histogram sorts values into bins, and how it shows their counts.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    def __init__(
+        self: Any,
+        *,
+        bin_count: datatypes.UInt64Like | None = None,
+        range: datatypes.Range1DLike | None = None,
+        log_scale: datatypes.BoolLike | None = None,
+    ) -> None:
+        """
+        Create a new instance of the HistogramBins archetype.
+
+        Parameters
+        ----------
+        bin_count:
+            How many bins the value range is divided into.
+
+            Defaults to 64.
+        range:
+            The range of values that is divided into bins.
+
+            Values outside of it aren't counted.
+            If unset, the range spans all values shown in the view.
+        log_scale:
+            Whether the bin counts are shown on a logarithmic scale.
+
+            Defaults to false.
+
+        """
+
+        # You can define your own __init__ function as a member of HistogramBinsExt in histogram_bins_ext.py
+        with catch_and_log_exceptions(context=self.__class__.__name__):
+            self.__attrs_init__(bin_count=bin_count, range=range, log_scale=log_scale)
+            return
+        self.__attrs_clear__()
+
+    def __attrs_clear__(self) -> None:
+        """Convenience method for calling `__attrs_init__` with all `None`s."""
+        self.__attrs_init__(
+            bin_count=None,
+            range=None,
+            log_scale=None,
+        )
+
+    @classmethod
+    def _clear(cls) -> HistogramBins:
+        """Produce an empty HistogramBins, bypassing `__init__`."""
+        inst = cls.__new__(cls)
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def from_fields(
+        cls,
+        *,
+        clear_unset: bool = False,
+        bin_count: datatypes.UInt64Like | None = None,
+        range: datatypes.Range1DLike | None = None,
+        log_scale: datatypes.BoolLike | None = None,
+    ) -> HistogramBins:
+        """
+        Update only some specific fields of a `HistogramBins`.
+
+        Parameters
+        ----------
+        clear_unset:
+            If true, all unspecified fields will be explicitly cleared.
+        bin_count:
+            How many bins the value range is divided into.
+
+            Defaults to 64.
+        range:
+            The range of values that is divided into bins.
+
+            Values outside of it aren't counted.
+            If unset, the range spans all values shown in the view.
+        log_scale:
+            Whether the bin counts are shown on a logarithmic scale.
+
+            Defaults to false.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            kwargs = {
+                "bin_count": bin_count,
+                "range": range,
+                "log_scale": log_scale,
+            }
+
+            if clear_unset:
+                kwargs = {k: v if v is not None else [] for k, v in kwargs.items()}  # type: ignore[misc]
+
+            inst.__attrs_init__(**kwargs)
+            return inst
+
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def cleared(cls) -> HistogramBins:
+        """Clear all the fields of a `HistogramBins`."""
+        return cls.from_fields(clear_unset=True)
+
+    bin_count: blueprint_components.BinCountBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=blueprint_components.BinCountBatch._converter,  # type: ignore[misc]
+    )
+    # How many bins the value range is divided into.
+    #
+    # Defaults to 64.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    range: components.Range1DBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.Range1DBatch._converter,  # type: ignore[misc]
+    )
+    # The range of values that is divided into bins.
+    #
+    # Values outside of it aren't counted.
+    # If unset, the range spans all values shown in the view.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    log_scale: blueprint_components.EnabledBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=blueprint_components.EnabledBatch._converter,  # type: ignore[misc]
+    )
+    # Whether the bin counts are shown on a logarithmic scale.
+    #
+    # Defaults to false.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    __str__ = Archetype.__str__
+    __repr__ = Archetype.__repr__  # type: ignore[assignment]
@@ -9,6 +9,7 @@ from typing import Any
 
 from attrs import define, field
 
+from ... import datatypes
 from ..._baseclasses import (
     Archetype,
 )
@@ -26,7 +27,9 @@ class MapBackground(Archetype):
     ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
     """
 
-    def __init__(self: Any, provider: blueprint_components.MapProviderLike) -> None:
+    def __init__(
+        self: Any, provider: blueprint_components.MapProviderLike, *, tile_source: datatypes.Utf8Like | None = None
+    ) -> None:
         """
         Create a new instance of the MapBackground archetype.
 
@@ -36,12 +39,17 @@ class MapBackground(Archetype):
             Map provider and style to use.
 
             **Note**: Requires a Mapbox API key in the `RERUN_MAPBOX_ACCESS_TOKEN` environment variable.
+        tile_source:
+            Custom map tiles to show instead of the provider's.
+
+            Tiles downloaded from a URL are kept in an on-disk cache, so areas that were viewed once
+            remain available without network access.
 
         """
 
         # You can define your own __init__ function as a member of MapBackgroundExt in map_background_ext.py
         with catch_and_log_exceptions(context=self.__class__.__name__):
-            self.__attrs_init__(provider=provider)
+            self.__attrs_init__(provider=provider, tile_source=tile_source)
             return
         self.__attrs_clear__()
 
@@ -49,6 +57,7 @@ class MapBackground(Archetype):
         """Convenience method for calling `__attrs_init__` with all `None`s."""
         self.__attrs_init__(
             provider=None,
+            tile_source=None,
         )
 
     @classmethod
@@ -64,6 +73,7 @@ class MapBackground(Archetype):
         *,
         clear_unset: bool = False,
         provider: blueprint_components.MapProviderLike | None = None,
+        tile_source: datatypes.Utf8Like | None = None,
     ) -> MapBackground:
         """
         Update only some specific fields of a `MapBackground`.
@@ -76,6 +86,11 @@ class MapBackground(Archetype):
             Map provider and style to use.
 
             **Note**: Requires a Mapbox API key in the `RERUN_MAPBOX_ACCESS_TOKEN` environment variable.
+        tile_source:
+            Custom map tiles to show instead of the provider's.
+
+            Tiles downloaded from a URL are kept in an on-disk cache, so areas that were viewed once
+            remain available without network access.
 
         """
 
@@ -83,6 +98,7 @@ class MapBackground(Archetype):
         with catch_and_log_exceptions(context=cls.__name__):
             kwargs = {
                 "provider": provider,
+                "tile_source": tile_source,
             }
 
             if clear_unset:
@@ -110,5 +126,17 @@ class MapBackground(Archetype):
     #
     # (Docstring intentionally commented out to hide this field from the docs)
 
+    tile_source: blueprint_components.MapTileSourceBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=blueprint_components.MapTileSourceBatch._converter,  # type: ignore[misc]
+    )
+    # Custom map tiles to show instead of the provider's.
+    #
+    # Tiles downloaded from a URL are kept in an on-disk cache, so areas that were viewed once
+    # remain available without network access.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
     __str__ = Archetype.__str__
     __repr__ = Archetype.__repr__  # type: ignore[assignment]
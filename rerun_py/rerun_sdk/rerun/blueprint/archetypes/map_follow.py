@@ -0,0 +1,139 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/map_follow.fbs".
+
+# You can extend this class by creating a "MapFollowExt" class in "map_follow_ext.py".
+
+from __future__ import annotations
+
+from typing import Any
+
+from attrs import define, field
+
+from ... import components, datatypes
+from ..._baseclasses import (
+    Archetype,
+)
+from ...blueprint import components as blueprint_components
+from ...error_utils import catch_and_log_exceptions
+
+__all__ = ["MapFollow"]
+
+
+@define(str=False, repr=False, init=False)
+class MapFollow(Archetype):
+    """
+    **Archetype**: Configuration of the map view camera following an entity.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    def __init__(
+        self: Any, mode: blueprint_components.MapFollowModeLike, *, entity: datatypes.EntityPathLike | None = None
+    ) -> None:
+        """
+        Create a new instance of the MapFollow archetype.
+
+        Parameters
+        ----------
+        mode:
+            Whether and how the map follows the entity.
+        entity:
+            The entity to follow.
+
+            Its latest geospatial position is used, taken from either `GeoPoints` or the last vertex
+            of `GeoLineStrings`.
+            If not set, the origin of the view is followed.
+
+        """
+
+        # You can define your own __init__ function as a member of MapFollowExt in map_follow_ext.py
+        with catch_and_log_exceptions(context=self.__class__.__name__):
+            self.__attrs_init__(mode=mode, entity=entity)
+            return
+        self.__attrs_clear__()
+
+    def __attrs_clear__(self) -> None:
+        """Convenience method for calling `__attrs_init__` with all `None`s."""
+        self.__attrs_init__(
+            mode=None,
+            entity=None,
+        )
+
+    @classmethod
+    def _clear(cls) -> MapFollow:
+        """Produce an empty MapFollow, bypassing `__init__`."""
+        inst = cls.__new__(cls)
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def from_fields(
+        cls,
+        *,
+        clear_unset: bool = False,
+        mode: blueprint_components.MapFollowModeLike | None = None,
+        entity: datatypes.EntityPathLike | None = None,
+    ) -> MapFollow:
+        """
+        Update only some specific fields of a `MapFollow`.
+
+        Parameters
+        ----------
+        clear_unset:
+            If true, all unspecified fields will be explicitly cleared.
+        mode:
+            Whether and how the map follows the entity.
+        entity:
+            The entity to follow.
+
+            Its latest geospatial position is used, taken from either `GeoPoints` or the last vertex
+            of `GeoLineStrings`.
+            If not set, the origin of the view is followed.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            kwargs = {
+                "mode": mode,
+                "entity": entity,
+            }
+
+            if clear_unset:
+                kwargs = {k: v if v is not None else [] for k, v in kwargs.items()}  # type: ignore[misc]
+
+            inst.__attrs_init__(**kwargs)
+            return inst
+
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def cleared(cls) -> MapFollow:
+        """Clear all the fields of a `MapFollow`."""
+        return cls.from_fields(clear_unset=True)
+
+    mode: blueprint_components.MapFollowModeBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=blueprint_components.MapFollowModeBatch._converter,  # type: ignore[misc]
+    )
+    # Whether and how the map follows the entity.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    entity: components.EntityPathBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.EntityPathBatch._converter,  # type: ignore[misc]
+    )
+    # The entity to follow.
+    #
+    # Its latest geospatial position is used, taken from either `GeoPoints` or the last vertex
+    # of `GeoLineStrings`.
+    # If not set, the origin of the view is followed.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    __str__ = Archetype.__str__
+    __repr__ = Archetype.__repr__  # type: ignore[assignment]
@@ -0,0 +1,165 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/spectrogram_window.fbs".
+
+# You can extend this class by creating a "SpectrogramWindowExt" class in "spectrogram_window_ext.py".
+
+from __future__ import annotations
+
+from typing import Any
+
+from attrs import define, field
+
+from ... import datatypes
+from ..._baseclasses import (
+    Archetype,
+)
+from ...blueprint import components as blueprint_components
+from ...error_utils import catch_and_log_exceptions
+
+__all__ = ["SpectrogramWindow"]
+
+
+@define(str=False, repr=False, init=False)
+class SpectrogramWindow(Archetype):
+    """
+    **Archetype**: Configuration for how a spectrogram splits a signal into windows and weights their samples.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    def __init__(
+        self: Any,
+        *,
+        length: datatypes.UInt64Like | None = None,
+        hop: datatypes.UInt64Like | None = None,
+        function: blueprint_components.WindowFunctionLike | None = None,
+    ) -> None:
+        """
+        Create a new instance of the SpectrogramWindow archetype.
+
+        Parameters
+        ----------
+        length:
+            How many samples each window spans.
+
+            Rounded up to the next power of two. Defaults to 256.
+        hop:
+            How many samples a window advances by between two columns of the spectrogram.
+
+            Defaults to a quarter of the window length.
+        function:
+            The function the samples of each window are weighted with.
+
+            Defaults to a Hann window.
+
+        """
+
+        # You can define your own __init__ function as a member of SpectrogramWindowExt in spectrogram_window_ext.py
+        with catch_and_log_exceptions(context=self.__class__.__name__):
+            self.__attrs_init__(length=length, hop=hop, function=function)
+            return
+        self.__attrs_clear__()
+
+    def __attrs_clear__(self) -> None:
+        """Convenience method for calling `__attrs_init__` with all `None`s."""
+        self.__attrs_init__(
+            length=None,
+            hop=None,
+            function=None,
+        )
+
+    @classmethod
+    def _clear(cls) -> SpectrogramWindow:
+        """Produce an empty SpectrogramWindow, bypassing `__init__`."""
+        inst = cls.__new__(cls)
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def from_fields(
+        cls,
+        *,
+        clear_unset: bool = False,
+        length: datatypes.UInt64Like | None = None,
+        hop: datatypes.UInt64Like | None = None,
+        function: blueprint_components.WindowFunctionLike | None = None,
+    ) -> SpectrogramWindow:
+        """
+        Update only some specific fields of a `SpectrogramWindow`.
+
+        Parameters
+        ----------
+        clear_unset:
+            If true, all unspecified fields will be explicitly cleared.
+        length:
+            How many samples each window spans.
+
+            Rounded up to the next power of two. Defaults to 256.
+        hop:
+            How many samples a window advances by between two columns of the spectrogram.
+
+            Defaults to a quarter of the window length.
+        function:
+            The function the samples of each window are weighted with.
+
+            Defaults to a Hann window.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            kwargs = {
+                "length": length,
+                "hop": hop,
+                "function": function,
+            }
+
+            if clear_unset:
+                kwargs = {k: v if v is not None else [] for k, v in kwargs.items()}  # type: ignore[misc]
+
+            inst.__attrs_init__(**kwargs)
+            return inst
+
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def cleared(cls) -> SpectrogramWindow:
+        """Clear all the fields of a `SpectrogramWindow`."""
+        return cls.from_fields(clear_unset=True)
+
+    length: blueprint_components.WindowLengthBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=blueprint_components.WindowLengthBatch._converter,  # type: ignore[misc]
+    )
+    # How many samples each window spans.
+    #
+    # Rounded up to the next power of two. Defaults to 256.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    hop: blueprint_components.HopLengthBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=blueprint_components.HopLengthBatch._converter,  # type: ignore[misc]
+    )
+    # How many samples a window advances by between two columns of the spectrogram.
+    #
+    # Defaults to a quarter of the window length.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    function: blueprint_components.WindowFunctionBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=blueprint_components.WindowFunctionBatch._converter,  # type: ignore[misc]
+    )
+    # The function the samples of each window are weighted with.
+    #
+    # Defaults to a Hann window.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    __str__ = Archetype.__str__
+    __repr__ = Archetype.__repr__  # type: ignore[assignment]
@@ -0,0 +1,167 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/tensor_volume.fbs".
+
+# You can extend this class by creating a "TensorVolumeExt" class in "tensor_volume_ext.py".
+
+from __future__ import annotations
+
+from typing import Any
+
+from attrs import define, field
+
+from ... import components, datatypes
+from ..._baseclasses import (
+    Archetype,
+)
+from ...error_utils import catch_and_log_exceptions
+
+__all__ = ["TensorVolume"]
+
+
+@define(str=False, repr=False, init=False)
+class TensorVolume(Archetype):
+    """
+    **Archetype**: Configuration for how three-dimensional tensors are shown as volumes or iso-surfaces in 3D views.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    def __init__(
+        self: Any,
+        *,
+        colormap: components.ColormapLike | None = None,
+        opacity: datatypes.Float32Like | None = None,
+        iso_value: datatypes.Float64Like | None = None,
+    ) -> None:
+        """
+        Create a new instance of the TensorVolume archetype.
+
+        Parameters
+        ----------
+        colormap:
+            The colormap applied to the tensor's values.
+
+            Defaults to viridis.
+        opacity:
+            Opacity contributed by each voxel when shown as a volume.
+
+            Since it accumulates along every ray, even small values can make the volume opaque.
+            Defaults to 0.05.
+        iso_value:
+            The value at which the iso-surface is extracted when shown as an iso-surface.
+
+            Defaults to the center of the tensor's value range.
+
+        """
+
+        # You can define your own __init__ function as a member of TensorVolumeExt in tensor_volume_ext.py
+        with catch_and_log_exceptions(context=self.__class__.__name__):
+            self.__attrs_init__(colormap=colormap, opacity=opacity, iso_value=iso_value)
+            return
+        self.__attrs_clear__()
+
+    def __attrs_clear__(self) -> None:
+        """Convenience method for calling `__attrs_init__` with all `None`s."""
+        self.__attrs_init__(
+            colormap=None,
+            opacity=None,
+            iso_value=None,
+        )
+
+    @classmethod
+    def _clear(cls) -> TensorVolume:
+        """Produce an empty TensorVolume, bypassing `__init__`."""
+        inst = cls.__new__(cls)
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def from_fields(
+        cls,
+        *,
+        clear_unset: bool = False,
+        colormap: components.ColormapLike | None = None,
+        opacity: datatypes.Float32Like | None = None,
+        iso_value: datatypes.Float64Like | None = None,
+    ) -> TensorVolume:
+        """
+        Update only some specific fields of a `TensorVolume`.
+
+        Parameters
+        ----------
+        clear_unset:
+            If true, all unspecified fields will be explicitly cleared.
+        colormap:
+            The colormap applied to the tensor's values.
+
+            Defaults to viridis.
+        opacity:
+            Opacity contributed by each voxel when shown as a volume.
+
+            Since it accumulates along every ray, even small values can make the volume opaque.
+            Defaults to 0.05.
+        iso_value:
+            The value at which the iso-surface is extracted when shown as an iso-surface.
+
+            Defaults to the center of the tensor's value range.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            kwargs = {
+                "colormap": colormap,
+                "opacity": opacity,
+                "iso_value": iso_value,
+            }
+
+            if clear_unset:
+                kwargs = {k: v if v is not None else [] for k, v in kwargs.items()}  # type: ignore[misc]
+
+            inst.__attrs_init__(**kwargs)
+            return inst
+
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def cleared(cls) -> TensorVolume:
+        """Clear all the fields of a `TensorVolume`."""
+        return cls.from_fields(clear_unset=True)
+
+    colormap: components.ColormapBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.ColormapBatch._converter,  # type: ignore[misc]
+    )
+    # The colormap applied to the tensor's values.
+    #
+    # Defaults to viridis.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    opacity: components.OpacityBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.OpacityBatch._converter,  # type: ignore[misc]
+    )
+    # Opacity contributed by each voxel when shown as a volume.
+    #
+    # Since it accumulates along every ray, even small values can make the volume opaque.
+    # Defaults to 0.05.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    iso_value: components.ScalarBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.ScalarBatch._converter,  # type: ignore[misc]
+    )
+    # The value at which the iso-surface is extracted when shown as an iso-surface.
+    #
+    # Defaults to the center of the tensor's value range.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    __str__ = Archetype.__str__
+    __repr__ = Archetype.__repr__  # type: ignore[assignment]
@@ -7,6 +7,8 @@ from .apply_latest_at import ApplyLatestAt, ApplyLatestAtBatch
 from .auto_layout import AutoLayout, AutoLayoutBatch
 from .auto_views import AutoViews, AutoViewsBatch
 from .background_kind import BackgroundKind, BackgroundKindArrayLike, BackgroundKindBatch, BackgroundKindLike
+from .bin_count import BinCount, BinCountBatch
+from .bookmark_time import BookmarkTime, BookmarkTimeBatch
 from .column_share import ColumnShare, ColumnShareBatch
 from .component_column_selector import ComponentColumnSelector, ComponentColumnSelectorBatch
 from .container_kind import ContainerKind, ContainerKindArrayLike, ContainerKindBatch, ContainerKindLike
@@ -19,10 +21,14 @@ from .force_iterations import ForceIterations, ForceIterationsBatch
 from .force_strength import ForceStrength, ForceStrengthBatch
 from .grid_columns import GridColumns, GridColumnsBatch
 from .grid_spacing import GridSpacing, GridSpacingBatch
+from .hop_length import HopLength, HopLengthBatch
 from .included_content import IncludedContent, IncludedContentBatch
+from .keyframe_time import KeyframeTime, KeyframeTimeBatch
 from .link_axis import LinkAxis, LinkAxisArrayLike, LinkAxisBatch, LinkAxisLike
 from .lock_range_during_zoom import LockRangeDuringZoom, LockRangeDuringZoomBatch
+from .map_follow_mode import MapFollowMode, MapFollowModeArrayLike, MapFollowModeBatch, MapFollowModeLike
 from .map_provider import MapProvider, MapProviderArrayLike, MapProviderBatch, MapProviderLike
+from .map_tile_source import MapTileSource, MapTileSourceBatch
 from .near_clip_plane import NearClipPlane, NearClipPlaneBatch
 from .panel_state import PanelState, PanelStateArrayLike, PanelStateBatch, PanelStateLike
 from .query_expression import QueryExpression, QueryExpressionBatch
@@ -39,6 +45,8 @@ from .viewer_recommendation_hash import ViewerRecommendationHash, ViewerRecommen
 from .visible_time_range import VisibleTimeRange, VisibleTimeRangeBatch
 from .visual_bounds2d import VisualBounds2D, VisualBounds2DBatch
 from .visualizer_override import VisualizerOverride, VisualizerOverrideBatch
+from .window_function import WindowFunction, WindowFunctionArrayLike, WindowFunctionBatch, WindowFunctionLike
+from .window_length import WindowLength, WindowLengthBatch
 from .zoom_level import ZoomLevel, ZoomLevelBatch
 
 __all__ = [
@@ -54,6 +62,10 @@ __all__ = [
     "BackgroundKindArrayLike",
     "BackgroundKindBatch",
     "BackgroundKindLike",
+    "BinCount",
+    "BinCountBatch",
+    "BookmarkTime",
+    "BookmarkTimeBatch",
     "ColumnShare",
     "ColumnShareBatch",
     "ComponentColumnSelector",
@@ -82,18 +94,28 @@ __all__ = [
     "GridColumnsBatch",
     "GridSpacing",
     "GridSpacingBatch",
+    "HopLength",
+    "HopLengthBatch",
     "IncludedContent",
     "IncludedContentBatch",
+    "KeyframeTime",
+    "KeyframeTimeBatch",
     "LinkAxis",
     "LinkAxisArrayLike",
     "LinkAxisBatch",
     "LinkAxisLike",
     "LockRangeDuringZoom",
     "LockRangeDuringZoomBatch",
+    "MapFollowMode",
+    "MapFollowModeArrayLike",
+    "MapFollowModeBatch",
+    "MapFollowModeLike",
     "MapProvider",
     "MapProviderArrayLike",
     "MapProviderBatch",
     "MapProviderLike",
+    "MapTileSource",
+    "MapTileSourceBatch",
     "NearClipPlane",
     "NearClipPlaneBatch",
     "PanelState",
@@ -130,6 +152,12 @@ __all__ = [
     "VisualBounds2DBatch",
     "VisualizerOverride",
     "VisualizerOverrideBatch",
+    "WindowFunction",
+    "WindowFunctionArrayLike",
+    "WindowFunctionBatch",
+    "WindowFunctionLike",
+    "WindowLength",
+    "WindowLengthBatch",
     "ZoomLevel",
     "ZoomLevelBatch",
 ]
@@ -0,0 +1,35 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/components/bin_count.fbs".
+
+# You can extend this class by creating a "BinCountExt" class in "bin_count_ext.py".
+
+from __future__ import annotations
+
+from ... import datatypes
+from ..._baseclasses import (
+    ComponentBatchMixin,
+    ComponentMixin,
+)
+
+__all__ = ["BinCount", "BinCountBatch"]
+
+
+class BinCount(datatypes.UInt64, ComponentMixin):
+    """
+    **Component**: The number of bins a histogram sorts its values into.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    _BATCH_TYPE = None
+    # You can define your own __init__ function as a member of BinCountExt in bin_count_ext.py
+
+    # Note: there are no fields here because BinCount delegates to datatypes.UInt64
+
+
+class BinCountBatch(datatypes.UInt64Batch, ComponentBatchMixin):
+    _COMPONENT_NAME: str = "rerun.blueprint.components.BinCount"
+
+
+# This is patched in late to avoid circular dependencies.
+BinCount._BATCH_TYPE = BinCountBatch  # type: ignore[assignment]
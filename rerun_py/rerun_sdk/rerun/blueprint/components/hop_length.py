@@ -0,0 +1,35 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/components/hop_length.fbs".
+
+# You can extend this class by creating a "HopLengthExt" class in "hop_length_ext.py".
+
+from __future__ import annotations
+
+from ... import datatypes
+from ..._baseclasses import (
+    ComponentBatchMixin,
+    ComponentMixin,
+)
+
+__all__ = ["HopLength", "HopLengthBatch"]
+
+
+class HopLength(datatypes.UInt64, ComponentMixin):
+    """
+    **Component**: The number of samples between the starts of two consecutive analysis windows.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    _BATCH_TYPE = None
+    # You can define your own __init__ function as a member of HopLengthExt in hop_length_ext.py
+
+    # Note: there are no fields here because HopLength delegates to datatypes.UInt64
+
+
+class HopLengthBatch(datatypes.UInt64Batch, ComponentBatchMixin):
+    _COMPONENT_NAME: str = "rerun.blueprint.components.HopLength"
+
+
+# This is patched in late to avoid circular dependencies.
+HopLength._BATCH_TYPE = HopLengthBatch  # type: ignore[assignment]
@@ -0,0 +1,35 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/components/keyframe_time.fbs".
+
+# You can extend this class by creating a "KeyframeTimeExt" class in "keyframe_time_ext.py".
+
+from __future__ import annotations
+
+from ... import datatypes
+from ..._baseclasses import (
+    ComponentBatchMixin,
+    ComponentMixin,
+)
+
+__all__ = ["KeyframeTime", "KeyframeTimeBatch"]
+
+
+class KeyframeTime(datatypes.TimeInt, ComponentMixin):
+    """
+    **Component**: The time of a keyframe on its timeline.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    _BATCH_TYPE = None
+    # You can define your own __init__ function as a member of KeyframeTimeExt in keyframe_time_ext.py
+
+    # Note: there are no fields here because KeyframeTime delegates to datatypes.TimeInt
+
+
+class KeyframeTimeBatch(datatypes.TimeIntBatch, ComponentBatchMixin):
+    _COMPONENT_NAME: str = "rerun.blueprint.components.KeyframeTime"
+
+
+# This is patched in late to avoid circular dependencies.
+KeyframeTime._BATCH_TYPE = KeyframeTimeBatch  # type: ignore[assignment]
@@ -0,0 +1,80 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/components/map_follow_mode.fbs".
+
+# You can extend this class by creating a "MapFollowModeExt" class in "map_follow_mode_ext.py".
+
+from __future__ import annotations
+
+from collections.abc import Sequence
+from typing import Literal, Union
+
+import pyarrow as pa
+
+from ..._baseclasses import (
+    BaseBatch,
+    ComponentBatchMixin,
+)
+
+__all__ = ["MapFollowMode", "MapFollowModeArrayLike", "MapFollowModeBatch", "MapFollowModeLike"]
+
+
+from enum import Enum
+
+
+class MapFollowMode(Enum):
+    """**Component**: How the map view follows an entity."""
+
+    Off = 1
+    """The map is only moved by the user, or to fit the data."""
+
+    Position = 2
+    """The map is kept centered on the latest position of the followed entity."""
+
+    PositionAndHeading = 3
+    """
+    Like `Position`, and the map is rotated so that the followed entity's direction of travel points up.
+
+    The direction of travel is derived from the two latest distinct positions.
+    """
+
+    @classmethod
+    def auto(cls, val: str | int | MapFollowMode) -> MapFollowMode:
+        """Best-effort converter, including a case-insensitive string matcher."""
+        if isinstance(val, MapFollowMode):
+            return val
+        if isinstance(val, int):
+            return cls(val)
+        try:
+            return cls[val]
+        except KeyError:
+            val_lower = val.lower()
+            for variant in cls:
+                if variant.name.lower() == val_lower:
+                    return variant
+        raise ValueError(f"Cannot convert {val} to {cls.__name__}")
+
+    def __str__(self) -> str:
+        """Returns the variant name."""
+        return self.name
+
+
+MapFollowModeLike = Union[
+    MapFollowMode,
+    Literal["Off", "Position", "PositionAndHeading", "off", "position", "positionandheading"],
+    int,
+]
+MapFollowModeArrayLike = Union[MapFollowModeLike, Sequence[MapFollowModeLike]]
+
+
+class MapFollowModeBatch(BaseBatch[MapFollowModeArrayLike], ComponentBatchMixin):
+    _ARROW_DATATYPE = pa.uint8()
+    _COMPONENT_NAME: str = "rerun.blueprint.components.MapFollowMode"
+
+    @staticmethod
+    def _native_to_pa_array(data: MapFollowModeArrayLike, data_type: pa.DataType) -> pa.Array:
+        if isinstance(data, (MapFollowMode, int, str)):
+            data = [data]
+
+        pa_data = [MapFollowMode.auto(v).value if v is not None else None for v in data]  # type: ignore[redundant-expr]
+
+        return pa.array(pa_data, type=data_type)
@@ -0,0 +1,41 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/components/map_tile_source.fbs".
+
+# You can extend this class by creating a "MapTileSourceExt" class in "map_tile_source_ext.py".
+
+from __future__ import annotations
+
+from ... import datatypes
+from ..._baseclasses import (
+    ComponentBatchMixin,
+    ComponentMixin,
+)
+
+__all__ = ["MapTileSource", "MapTileSourceBatch"]
+
+
+class MapTileSource(datatypes.Utf8, ComponentMixin):
+    """
+    **Component**: A custom source of map tiles, used instead of one of the built-in map providers.
+
+    This is one of:
+    * An XYZ tile URL template, e.g. `https://tiles.example.com/{z}/{x}/{y}.png`.
+      `{z}` is replaced by the zoom level, `{x}` and `{y}` by the tile's column and row.
+    * The path to an MBTiles or PMTiles file containing raster tiles.
+    * The path to a directory of raster tiles laid out as `{z}/{x}/{y}.png`.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    _BATCH_TYPE = None
+    # You can define your own __init__ function as a member of MapTileSourceExt in map_tile_source_ext.py
+
+    # Note: there are no fields here because MapTileSource delegates to datatypes.Utf8
+
+
+class MapTileSourceBatch(datatypes.Utf8Batch, ComponentBatchMixin):
+    _COMPONENT_NAME: str = "rerun.blueprint.components.MapTileSource"
+
+
+# This is patched in late to avoid circular dependencies.
+MapTileSource._BATCH_TYPE = MapTileSourceBatch  # type: ignore[assignment]
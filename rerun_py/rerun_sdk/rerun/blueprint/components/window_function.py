@@ -0,0 +1,91 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/components/window_function.fbs".
+
+# You can extend this class by creating a "WindowFunctionExt" class in "window_function_ext.py".
+
+from __future__ import annotations
+
+from collections.abc import Sequence
+from typing import Literal, Union
+
+import pyarrow as pa
+
+from ..._baseclasses import (
+    BaseBatch,
+    ComponentBatchMixin,
+)
+
+__all__ = ["WindowFunction", "WindowFunctionArrayLike", "WindowFunctionBatch", "WindowFunctionLike"]
+
+
+from enum import Enum
+
+
+class WindowFunction(Enum):
+    """**Component**: Tapers a window of samples before its spectrum is computed, trading frequency resolution for less spectral leakage."""
+
+    Rectangular = 1
+    """
+    No tapering, all samples are weighted equally.
+
+    Gives the sharpest peaks, but the most leakage between frequencies.
+    """
+
+    Hann = 2
+    """
+    Raised cosine that falls to zero at both ends of the window.
+
+    A good default for most signals.
+    """
+
+    Hamming = 3
+    """
+    Raised cosine that stays slightly above zero at the ends of the window.
+
+    Suppresses the frequencies closest to a peak better than `Hann`, but those far away less so.
+    """
+
+    Blackman = 4
+    """Sum of cosines with very low leakage, at the cost of wider peaks."""
+
+    @classmethod
+    def auto(cls, val: str | int | WindowFunction) -> WindowFunction:
+        """Best-effort converter, including a case-insensitive string matcher."""
+        if isinstance(val, WindowFunction):
+            return val
+        if isinstance(val, int):
+            return cls(val)
+        try:
+            return cls[val]
+        except KeyError:
+            val_lower = val.lower()
+            for variant in cls:
+                if variant.name.lower() == val_lower:
+                    return variant
+        raise ValueError(f"Cannot convert {val} to {cls.__name__}")
+
+    def __str__(self) -> str:
+        """Returns the variant name."""
+        return self.name
+
+
+WindowFunctionLike = Union[
+    WindowFunction,
+    Literal["Blackman", "Hamming", "Hann", "Rectangular", "blackman", "hamming", "hann", "rectangular"],
+    int,
+]
+WindowFunctionArrayLike = Union[WindowFunctionLike, Sequence[WindowFunctionLike]]
+
+
+class WindowFunctionBatch(BaseBatch[WindowFunctionArrayLike], ComponentBatchMixin):
+    _ARROW_DATATYPE = pa.uint8()
+    _COMPONENT_NAME: str = "rerun.blueprint.components.WindowFunction"
+
+    @staticmethod
+    def _native_to_pa_array(data: WindowFunctionArrayLike, data_type: pa.DataType) -> pa.Array:
+        if isinstance(data, (WindowFunction, int, str)):
+            data = [data]
+
+        pa_data = [WindowFunction.auto(v).value if v is not None else None for v in data]  # type: ignore[redundant-expr]
+
+        return pa.array(pa_data, type=data_type)
@@ -0,0 +1,35 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/components/window_length.fbs".
+
+# You can extend this class by creating a "WindowLengthExt" class in "window_length_ext.py".
+
+from __future__ import annotations
+
+from ... import datatypes
+from ..._baseclasses import (
+    ComponentBatchMixin,
+    ComponentMixin,
+)
+
+__all__ = ["WindowLength", "WindowLengthBatch"]
+
+
+class WindowLength(datatypes.UInt64, ComponentMixin):
+    """
+    **Component**: The number of consecutive samples that are analyzed together, e.g. by a short-time Fourier transform.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    _BATCH_TYPE = None
+    # You can define your own __init__ function as a member of WindowLengthExt in window_length_ext.py
+
+    # Note: there are no fields here because WindowLength delegates to datatypes.UInt64
+
+
+class WindowLengthBatch(datatypes.UInt64Batch, ComponentBatchMixin):
+    _COMPONENT_NAME: str = "rerun.blueprint.components.WindowLength"
+
+
+# This is patched in late to avoid circular dependencies.
+WindowLength._BATCH_TYPE = WindowLengthBatch  # type: ignore[assignment]
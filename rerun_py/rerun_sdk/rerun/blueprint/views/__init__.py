@@ -5,23 +5,29 @@ from __future__ import annotations
 from .bar_chart_view import BarChartView
 from .dataframe_view import DataframeView
 from .graph_view import GraphView
+from .histogram_view import HistogramView
 from .map_view import MapView
 from .spatial2d_view import Spatial2DView
 from .spatial3d_view import Spatial3DView
+from .spectrogram_view import SpectrogramView
 from .tensor_view import TensorView
 from .text_document_view import TextDocumentView
 from .text_log_view import TextLogView
 from .time_series_view import TimeSeriesView
+from .xy_plot_view import XYPlotView
 
 __all__ = [
     "BarChartView",
     "DataframeView",
     "GraphView",
+    "HistogramView",
     "MapView",
     "Spatial2DView",
     "Spatial3DView",
+    "SpectrogramView",
     "TensorView",
     "TextDocumentView",
     "TextLogView",
     "TimeSeriesView",
+    "XYPlotView",
 ]
@@ -76,6 +76,7 @@ class MapView(View):
         | None = None,
         zoom: blueprint_archetypes.MapZoom | datatypes.Float64Like | None = None,
         background: blueprint_archetypes.MapBackground | blueprint_components.MapProviderLike | None = None,
+        follow: blueprint_archetypes.MapFollow | blueprint_components.MapFollowModeLike | None = None,
     ) -> None:
         """
         Construct a blueprint for a new MapView view.
@@ -116,6 +117,8 @@ class MapView(View):
             Configures the zoom level of the map view.
         background:
             Configuration for the background map of the map view.
+        follow:
+            Whether the map follows an entity, and how.
 
         """
 
@@ -130,6 +133,11 @@ class MapView(View):
                 background = blueprint_archetypes.MapBackground(background)
             properties["MapBackground"] = background
 
+        if follow is not None:
+            if not isinstance(follow, blueprint_archetypes.MapFollow):
+                follow = blueprint_archetypes.MapFollow(follow)
+            properties["MapFollow"] = follow
+
         super().__init__(
             class_identifier="Map",
             origin=origin,
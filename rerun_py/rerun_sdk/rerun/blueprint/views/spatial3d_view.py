@@ -96,6 +96,7 @@ class Spatial3DView(View):
         | blueprint_components.BackgroundKindLike
         | None = None,
         line_grid: blueprint_archetypes.LineGrid3D | None = None,
+        camera_path: blueprint_archetypes.CameraPath | None = None,
         time_ranges: blueprint_archetypes.VisibleTimeRanges
         | datatypes.VisibleTimeRangeLike
         | Sequence[datatypes.VisibleTimeRangeLike]
@@ -140,6 +141,8 @@ class Spatial3DView(View):
             Configuration for the background of the view.
         line_grid:
             Configuration for the 3D line grid.
+        camera_path:
+            A keyframed path for the eye of the view to follow.
         time_ranges:
             Configures which range on each timeline is shown by this view (unless specified differently per entity).
 
@@ -159,6 +162,11 @@ class Spatial3DView(View):
                 line_grid = blueprint_archetypes.LineGrid3D(line_grid)
             properties["LineGrid3D"] = line_grid
 
+        if camera_path is not None:
+            if not isinstance(camera_path, blueprint_archetypes.CameraPath):
+                camera_path = blueprint_archetypes.CameraPath(camera_path)
+            properties["CameraPath"] = camera_path
+
         if time_ranges is not None:
             if not isinstance(time_ranges, blueprint_archetypes.VisibleTimeRanges):
                 time_ranges = blueprint_archetypes.VisibleTimeRanges(time_ranges)
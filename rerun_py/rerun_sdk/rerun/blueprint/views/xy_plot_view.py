@@ -0,0 +1,131 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/views/xy_plot.fbs".
+
+from __future__ import annotations
+
+from collections.abc import Iterable, Mapping, Sequence
+
+from ..._baseclasses import (
+    DescribedComponentBatch,
+)
+
+__all__ = ["XYPlotView"]
+
+
+from ... import datatypes
+from ..._baseclasses import AsComponents
+from ...datatypes import EntityPathLike, Utf8Like
+from .. import archetypes as blueprint_archetypes, components as blueprint_components
+from ..api import View, ViewContentsLike
+
+
+class XYPlotView(View):
+    """
+    **View**: A view that plots one scalar against another over the visible time range, for use with [`archetypes.Scalars`][rerun.archetypes.Scalars].
+
+    Each point is colored by its time, from faded for the oldest to full color for the newest.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    def __init__(
+        self,
+        *,
+        origin: EntityPathLike = "/",
+        contents: ViewContentsLike = "$origin/**",
+        name: Utf8Like | None = None,
+        visible: datatypes.BoolLike | None = None,
+        defaults: Iterable[AsComponents | Iterable[DescribedComponentBatch]] | None = None,
+        overrides: Mapping[
+            EntityPathLike,
+            AsComponents | Iterable[DescribedComponentBatch | AsComponents | Iterable[DescribedComponentBatch]],
+        ]
+        | None = None,
+        axis_x: blueprint_archetypes.XAxis | None = None,
+        axis_y: blueprint_archetypes.ScalarAxis | None = None,
+        plot_legend: blueprint_archetypes.PlotLegend | blueprint_components.Corner2D | None = None,
+        time_ranges: blueprint_archetypes.VisibleTimeRanges
+        | datatypes.VisibleTimeRangeLike
+        | Sequence[datatypes.VisibleTimeRangeLike]
+        | None = None,
+    ) -> None:
+        """
+        Construct a blueprint for a new XYPlotView view.
+
+        Parameters
+        ----------
+        origin:
+            The `EntityPath` to use as the origin of this view.
+            All other entities will be transformed to be displayed relative to this origin.
+        contents:
+            The contents of the view specified as a query expression.
+            This is either a single expression, or a list of multiple expressions.
+            See [rerun.blueprint.archetypes.ViewContents][].
+        name:
+            The display name of the view.
+        visible:
+            Whether this view is visible.
+
+            Defaults to true if not specified.
+        defaults:
+            List of archetypes or (described) component batches to add to the view.
+            When an archetype in the view is missing a component included in this set,
+            the value of default will be used instead of the normal fallback for the visualizer.
+
+            Note that an archetype's required components typically don't have any effect.
+            It is recommended to use the archetype's `from_fields` method instead and only specify the fields that you need.
+        overrides:
+            Dictionary of overrides to apply to the view. The key is the path to the entity where the override
+            should be applied. The value is a list of archetypes or (described) component batches to apply to the entity.
+
+            It is recommended to use the archetype's `from_fields` method instead and only specify the fields that you need.
+
+            Important note: the path must be a fully qualified entity path starting at the root. The override paths
+            do not yet support `$origin` relative paths or glob expressions.
+            This will be addressed in <https://github.com/rerun-io/rerun/issues/6673>.
+
+        axis_x:
+            Configures the horizontal axis of the plot, including which scalar is plotted along it.
+        axis_y:
+            Configures the vertical axis of the plot.
+        plot_legend:
+            Configures the legend of the plot.
+        time_ranges:
+            Configures which range on each timeline is shown by this view (unless specified differently per entity).
+
+            If not specified, the default is to show the entire timeline.
+            If a timeline is specified more than once, the first entry will be used.
+
+        """
+
+        properties: dict[str, AsComponents] = {}
+        if axis_x is not None:
+            if not isinstance(axis_x, blueprint_archetypes.XAxis):
+                axis_x = blueprint_archetypes.XAxis(axis_x)
+            properties["XAxis"] = axis_x
+
+        if axis_y is not None:
+            if not isinstance(axis_y, blueprint_archetypes.ScalarAxis):
+                axis_y = blueprint_archetypes.ScalarAxis(axis_y)
+            properties["ScalarAxis"] = axis_y
+
+        if plot_legend is not None:
+            if not isinstance(plot_legend, blueprint_archetypes.PlotLegend):
+                plot_legend = blueprint_archetypes.PlotLegend(plot_legend)
+            properties["PlotLegend"] = plot_legend
+
+        if time_ranges is not None:
+            if not isinstance(time_ranges, blueprint_archetypes.VisibleTimeRanges):
+                time_ranges = blueprint_archetypes.VisibleTimeRanges(time_ranges)
+            properties["VisibleTimeRanges"] = time_ranges
+
+        super().__init__(
+            class_identifier="XYPlot",
+            origin=origin,
+            contents=contents,
+            name=name,
+            visible=visible,
+            properties=properties,
+            defaults=defaults,
+            overrides=overrides,
+        )
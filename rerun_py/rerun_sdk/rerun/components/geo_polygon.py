@@ -0,0 +1,67 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/components/geo_polygon.fbs".
+
+# You can extend this class by creating a "GeoPolygonExt" class in "geo_polygon_ext.py".
+
+from __future__ import annotations
+
+from collections.abc import Sequence
+from typing import TYPE_CHECKING, Any, Union
+
+import numpy as np
+import numpy.typing as npt
+import pyarrow as pa
+from attrs import define, field
+
+from .. import datatypes
+from .._baseclasses import (
+    BaseBatch,
+    ComponentBatchMixin,
+    ComponentMixin,
+)
+from .geo_polygon_ext import GeoPolygonExt
+
+__all__ = ["GeoPolygon", "GeoPolygonArrayLike", "GeoPolygonBatch", "GeoPolygonLike"]
+
+
+@define(init=False)
+class GeoPolygon(GeoPolygonExt, ComponentMixin):
+    """
+    **Component**: A geospatial polygon expressed in [EPSG:4326](https://epsg.io/4326) latitude and longitude (North/East-positive degrees).
+
+    The vertices describe the outer boundary of the polygon, which is implicitly closed.
+    Holes are not supported.
+    """
+
+    _BATCH_TYPE = None
+    # __init__ can be found in geo_polygon_ext.py
+
+    lat_lon: list[datatypes.DVec2D] = field()
+
+
+if TYPE_CHECKING:
+    GeoPolygonLike = Union[GeoPolygon, datatypes.DVec2DArrayLike, npt.NDArray[np.float64]]
+else:
+    GeoPolygonLike = Any
+
+GeoPolygonArrayLike = Union[GeoPolygon, Sequence[GeoPolygonLike], npt.NDArray[np.float64]]
+
+
+class GeoPolygonBatch(BaseBatch[GeoPolygonArrayLike], ComponentBatchMixin):
+    _ARROW_DATATYPE = pa.list_(
+        pa.field(
+            "item",
+            pa.list_(pa.field("item", pa.float64(), nullable=False, metadata={}), 2),
+            nullable=False,
+            metadata={},
+        )
+    )
+    _COMPONENT_NAME: str = "rerun.components.GeoPolygon"
+
+    @staticmethod
+    def _native_to_pa_array(data: GeoPolygonArrayLike, data_type: pa.DataType) -> pa.Array:
+        return GeoPolygonExt.native_to_pa_array_override(data, data_type)
+
+
+# This is patched in late to avoid circular dependencies.
+GeoPolygon._BATCH_TYPE = GeoPolygonBatch  # type: ignore[assignment]
@@ -36,6 +36,11 @@ class MediaTypeExt:
     <https://www.iana.org/assignments/media-types/image/png>
     """
 
+    EXR: MediaType = None  # type: ignore[assignment]
+    """
+    [OpenEXR image](https://en.wikipedia.org/wiki/OpenEXR): `image/x-exr`.
+    """
+
     # --------------------------
     # Meshes:
 
@@ -85,6 +90,7 @@ class MediaTypeExt:
 
         cls.JPEG = cls("image/jpeg")
         cls.PNG = cls("image/png")
+        cls.EXR = cls("image/x-exr")
 
         cls.GLB = cls("model/gltf-binary")
         cls.GLTF = cls("model/gltf+json")
@@ -104,6 +110,8 @@ class MediaTypeExt:
             return MediaType.JPEG
         elif ext == ".png":
             return MediaType.PNG
+        elif ext == ".exr":
+            return MediaType.EXR
 
         # 3D Models
         if ext == ".glb":
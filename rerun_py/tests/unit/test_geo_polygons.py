@@ -0,0 +1,91 @@
+from __future__ import annotations
+
+import itertools
+from typing import Any, Optional, cast
+
+import numpy as np
+import rerun as rr
+from rerun.components import (
+    GeoPolygonArrayLike,
+    GeoPolygonBatch,
+)
+from rerun.datatypes import Float32ArrayLike, Rgba32ArrayLike
+
+from .common_arrays import (
+    colors_arrays,
+    colors_expected,
+    none_empty_or_value,
+    radii_arrays,
+    radii_expected,
+)
+
+geo_polygons_arrays: list[GeoPolygonArrayLike] = [
+    [],
+    np.array([]),
+    [
+        [[0, 0], [0, 1], [1, 1], [1, 0]],
+        [[2, 2], [3, 4], [4, 2]],
+    ],
+    [
+        np.array([[0, 0], [0, 1], [1, 1], [1, 0]], dtype=np.float64),
+        np.array([[2, 2], [3, 4], [4, 2]], dtype=np.float64),
+    ],
+]
+
+
+def geo_polygons_expected(obj: Any) -> Any:
+    expected = none_empty_or_value(
+        obj,
+        [
+            [[0, 0], [0, 1], [1, 1], [1, 0]],
+            [[2, 2], [3, 4], [4, 2]],
+        ],
+    )
+
+    return GeoPolygonBatch(expected)
+
+
+def test_geo_polygons() -> None:
+    all_arrays = itertools.zip_longest(
+        geo_polygons_arrays,
+        radii_arrays,
+        colors_arrays,
+        colors_arrays,
+    )
+
+    for polygons, radii, colors, fill_colors in all_arrays:
+        polygons = polygons if polygons is not None else geo_polygons_arrays[-1]
+
+        # make Pyright happy as it's apparently not able to track typing info through zip_longest
+        polygons = cast(GeoPolygonArrayLike, polygons)
+        radii = cast(Optional[Float32ArrayLike], radii)
+        colors = cast(Optional[Rgba32ArrayLike], colors)
+        fill_colors = cast(Optional[Rgba32ArrayLike], fill_colors)
+
+        print(
+            f"rr.GeoPolygons(\n    lat_lon={polygons}\n    radii={radii!r}\n    colors={colors!r}\n"
+            f"    fill_colors={fill_colors!r}\n)"
+        )
+        arch = rr.GeoPolygons(
+            lat_lon=polygons,
+            radii=radii,
+            colors=colors,
+            fill_colors=fill_colors,
+        )
+        print(f"{arch}\n")
+
+        assert arch.polygons == geo_polygons_expected(polygons)
+        assert arch.radii == radii_expected(radii)
+        assert arch.colors == colors_expected(colors)
+        assert arch.fill_colors == colors_expected(fill_colors)
+
+
+def test_geo_polygons_single_polygon() -> None:
+    reference = rr.GeoPolygons(lat_lon=[rr.components.GeoPolygon(lat_lon=[[0, 0], [0, 1], [1, 1]])])
+    assert reference.polygons is not None and len(reference.polygons) == 1
+    assert reference == rr.GeoPolygons(lat_lon=[[[0, 0], [0, 1], [1, 1]]])
+    assert reference == rr.GeoPolygons(lat_lon=[[0, 0], [0, 1], [1, 1]])
+
+
+if __name__ == "__main__":
+    test_geo_polygons()
@@ -0,0 +1,61 @@
+from __future__ import annotations
+
+import itertools
+from typing import Optional, cast
+
+import numpy as np
+import rerun as rr
+import rerun.blueprint as rrb
+
+from .common_arrays import none_empty_or_value
+
+
+def test_histogram_bins() -> None:
+    rr.set_strict_mode(True)
+
+    bin_counts = [
+        32,
+        rrb.components.BinCount(32),
+        None,
+    ]
+    # All from 42.1337 to 1337.42, but expressed differently
+    ranges = [
+        (42.1337, 1337.42),
+        [42.1337, 1337.42],
+        np.array([42.1337, 1337.42]),
+        rr.components.Range1D([42.1337, 1337.42]),
+        None,
+    ]
+    log_scales = [
+        True,
+        False,
+    ]
+
+    all_arrays = itertools.zip_longest(
+        bin_counts,
+        ranges,
+        log_scales,
+    )
+
+    for bin_count, range, log_scale in all_arrays:
+        bin_count = cast(Optional[rr.datatypes.UInt64Like], bin_count)
+        range = cast(Optional[rr.datatypes.Range1DLike], range)
+        log_scale = cast(Optional[rr.datatypes.Bool], log_scale)
+
+        print(
+            f"rrb.HistogramBins(\n"
+            f"    bin_count={bin_count!r}\n"  #
+            f"    range={range!r}\n"
+            f"    log_scale={log_scale!r}\n"
+            f")",
+        )
+        arch = rrb.HistogramBins(
+            bin_count=bin_count,
+            range=range,
+            log_scale=log_scale,
+        )
+        print(f"{arch}\n")
+
+        assert arch.bin_count == rrb.components.BinCountBatch._converter(bin_count)
+        assert arch.range == rr.components.Range1DBatch._converter(none_empty_or_value(range, [42.1337, 1337.42]))
+        assert arch.log_scale == rrb.components.EnabledBatch._converter(log_scale)
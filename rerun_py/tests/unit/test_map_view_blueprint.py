@@ -25,3 +25,37 @@ def test_map_view_blueprint() -> None:
 
     # assert bp1 == bp2 == bp3 == bp4
     assert_blueprint_contents_are_equal(bp1, bp2, bp3, bp4)
+
+
+def test_map_view_blueprint_tile_source() -> None:
+    """A custom tile source can be given as a plain string or as a component."""
+
+    bp1 = rrb.MapView(
+        origin="point",
+        name="MapView",
+        background=rrb.archetypes.MapBackground("openstreetmap", tile_source="/data/tiles.mbtiles"),
+    )
+    bp2 = rrb.MapView(
+        origin="point",
+        name="MapView",
+        background=rrb.archetypes.MapBackground(
+            rrb.MapProvider.OpenStreetMap,
+            tile_source=rrb.components.MapTileSource("/data/tiles.mbtiles"),
+        ),
+    )
+
+    assert_blueprint_contents_are_equal(bp1, bp2)
+
+
+def test_map_view_blueprint_follow() -> None:
+    """Follow modes can be given as a plain string, an enum variant, or an archetype."""
+
+    bp1 = rrb.MapView(origin="boat", name="MapView", follow="positionandheading")
+    bp2 = rrb.MapView(origin="boat", name="MapView", follow=rrb.MapFollowMode.PositionAndHeading)
+    bp3 = rrb.MapView(
+        origin="boat",
+        name="MapView",
+        follow=rrb.archetypes.MapFollow(rrb.MapFollowMode.PositionAndHeading),
+    )
+
+    assert_blueprint_contents_are_equal(bp1, bp2, bp3)
@@ -0,0 +1,57 @@
+from __future__ import annotations
+
+import itertools
+from typing import Optional, cast
+
+import rerun as rr
+import rerun.blueprint as rrb
+
+
+def test_spectrogram_window() -> None:
+    rr.set_strict_mode(True)
+
+    lengths = [
+        512,
+        rrb.components.WindowLength(512),
+        None,
+    ]
+    hops = [
+        128,
+        rrb.components.HopLength(128),
+        None,
+    ]
+    functions = [
+        rrb.WindowFunction.Blackman,
+        "Blackman",
+        "blackman",
+        None,
+    ]
+
+    all_arrays = itertools.zip_longest(
+        lengths,
+        hops,
+        functions,
+    )
+
+    for length, hop, function in all_arrays:
+        length = cast(Optional[rr.datatypes.UInt64Like], length)
+        hop = cast(Optional[rr.datatypes.UInt64Like], hop)
+        function = cast(Optional[rrb.components.WindowFunctionLike], function)
+
+        print(
+            f"rrb.SpectrogramWindow(\n"
+            f"    length={length!r}\n"  #
+            f"    hop={hop!r}\n"
+            f"    function={function!r}\n"
+            f")",
+        )
+        arch = rrb.SpectrogramWindow(
+            length=length,
+            hop=hop,
+            function=function,
+        )
+        print(f"{arch}\n")
+
+        assert arch.length == rrb.components.WindowLengthBatch._converter(length)
+        assert arch.hop == rrb.components.HopLengthBatch._converter(hop)
+        assert arch.function == rrb.components.WindowFunctionBatch._converter(function)
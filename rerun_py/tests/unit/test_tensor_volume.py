@@ -0,0 +1,56 @@
+from __future__ import annotations
+
+import itertools
+from typing import Optional, cast
+
+import rerun as rr
+import rerun.blueprint as rrb
+
+
+def test_tensor_volume() -> None:
+    rr.set_strict_mode(True)
+
+    colormaps = [
+        "viridis",
+        rr.components.Colormap.Viridis,
+        None,
+    ]
+    opacities = [
+        0.1,
+        rr.components.Opacity(0.1),
+        None,
+    ]
+    iso_values = [
+        0.5,
+        rr.components.Scalar(0.5),
+        None,
+    ]
+
+    all_arrays = itertools.zip_longest(
+        colormaps,
+        opacities,
+        iso_values,
+    )
+
+    for colormap, opacity, iso_value in all_arrays:
+        colormap = cast(Optional[rr.components.ColormapLike], colormap)
+        opacity = cast(Optional[rr.datatypes.Float32Like], opacity)
+        iso_value = cast(Optional[rr.datatypes.Float64Like], iso_value)
+
+        print(
+            f"rrb.TensorVolume(\n"
+            f"    colormap={colormap!r}\n"  #
+            f"    opacity={opacity!r}\n"
+            f"    iso_value={iso_value!r}\n"
+            f")",
+        )
+        arch = rrb.TensorVolume(
+            colormap=colormap,
+            opacity=opacity,
+            iso_value=iso_value,
+        )
+        print(f"{arch}\n")
+
+        assert arch.colormap == rr.components.ColormapBatch._converter(colormap)
+        assert arch.opacity == rr.components.OpacityBatch._converter(opacity)
+        assert arch.iso_value == rr.components.ScalarBatch._converter(iso_value)